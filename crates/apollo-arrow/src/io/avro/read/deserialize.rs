@@ -49,6 +49,21 @@ fn make_mutable(
                     capacity,
                 )) as Box<dyn MutableArray>
             },
+            ArrowDataType::Map(entries, _) => {
+                let ArrowDataType::Struct(fields) = entries.dtype() else {
+                    unreachable!()
+                };
+                let values = fields
+                    .iter()
+                    .map(|field| make_mutable(field.dtype(), None, 0))
+                    .collect::<ApolloResult<Vec<_>>>()?;
+                let values = DynMutableStructArray::new(values, entries.dtype().clone());
+                Box::new(DynMutableMapArray::new_from(
+                    values,
+                    dtype.clone(),
+                    capacity,
+                )) as Box<dyn MutableArray>
+            },
             ArrowDataType::FixedSizeBinary(size) => {
                 Box::new(MutableFixedSizeBinaryArray::with_capacity(*size, capacity))
                     as Box<dyn MutableArray>
@@ -76,6 +91,17 @@ fn is_union_null_first(avro_field: &AvroSchema) -> bool {
     }
 }
 
+fn map_value_schema(avro_field: &AvroSchema) -> &AvroSchema {
+    match avro_field {
+        AvroSchema::Map(inner) => inner.as_ref(),
+        AvroSchema::Union(u) => match &u.as_slice() {
+            &[AvroSchema::Map(inner), _] | &[_, AvroSchema::Map(inner)] => inner.as_ref(),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+}
+
 fn deserialize_item<'a>(
     array: &mut dyn MutableArray,
     is_nullable: bool,
@@ -144,6 +170,44 @@ fn deserialize_value<'a>(
             }
             array.try_push_valid()?;
         },
+        ArrowDataType::Map(entries, _) => {
+            let ArrowDataType::Struct(entry_fields) = entries.dtype() else {
+                unreachable!()
+            };
+            let is_nullable = entry_fields[1].is_nullable;
+            let avro_inner = map_value_schema(avro_field);
+
+            let array = array
+                .as_mut_any()
+                .downcast_mut::<DynMutableMapArray>()
+                .unwrap();
+            // Maps are encoded as a series of blocks, like arrays.
+            loop {
+                let len = util::zigzag_i64(&mut block)?;
+                let len = if len < 0 {
+                    let _ = util::zigzag_i64(&mut block)?;
+
+                    -len
+                } else {
+                    len
+                };
+
+                if len == 0 {
+                    break;
+                }
+
+                // Each item is a string key followed by a value encoded per the map's value schema.
+                let entries = array.mut_values();
+                for _ in 0..len {
+                    block =
+                        deserialize_value(entries.mut_values(0), &AvroSchema::String(None), block)?;
+                    block =
+                        deserialize_item(entries.mut_values(1), is_nullable, avro_inner, block)?;
+                    entries.try_push_valid()?;
+                }
+            }
+            array.try_push_valid()?;
+        },
         ArrowDataType::Struct(inner_fields) => {
             let fields = match avro_field {
                 AvroSchema::Record(Record { fields, .. }) => fields,
@@ -377,6 +441,44 @@ fn skip_item<'a>(
                 }
             }
         },
+        ArrowDataType::Map(entries, _) => {
+            let ArrowDataType::Struct(entry_fields) = entries.dtype() else {
+                unreachable!()
+            };
+            let avro_inner = map_value_schema(avro_field);
+
+            loop {
+                let len = util::zigzag_i64(&mut block)?;
+                let (len, bytes) = if len < 0 {
+                    let bytes = util::zigzag_i64(&mut block)?;
+
+                    (-len, Some(bytes))
+                } else {
+                    (len, None)
+                };
+
+                let bytes: Option<usize> = bytes
+                    .map(|bytes| {
+                        bytes
+                            .try_into()
+                            .map_err(|_| apollo_err!(oos = "Avro block size negative or too large"))
+                    })
+                    .transpose()?;
+
+                if len == 0 {
+                    break;
+                }
+
+                if let Some(bytes) = bytes {
+                    block = &block[bytes..];
+                } else {
+                    for _ in 0..len {
+                        block = skip_item(&entry_fields[0], &AvroSchema::String(None), block)?;
+                        block = skip_item(&entry_fields[1], avro_inner, block)?;
+                    }
+                }
+            }
+        },
         ArrowDataType::Struct(inner_fields) => {
             let fields = match avro_field {
                 AvroSchema::Record(Record { fields, .. }) => fields,
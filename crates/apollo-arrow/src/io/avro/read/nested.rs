@@ -120,6 +120,118 @@ impl<O: Offset> MutableArray for DynMutableListArray<O> {
     }
 }
 
+/// Auxiliary struct
+#[derive(Debug)]
+pub struct DynMutableMapArray {
+    dtype: ArrowDataType,
+    offsets: Offsets<i32>,
+    values: DynMutableStructArray,
+    validity: Option<MutableBitmap>,
+}
+
+impl DynMutableMapArray {
+    pub fn new_from(values: DynMutableStructArray, dtype: ArrowDataType, capacity: usize) -> Self {
+        assert_eq!(values.len(), 0);
+        MapArray::get_field(&dtype);
+        Self {
+            dtype,
+            offsets: Offsets::<i32>::with_capacity(capacity),
+            values,
+            validity: None,
+        }
+    }
+
+    /// The `{key, value}` entries
+    pub fn mut_values(&mut self) -> &mut DynMutableStructArray {
+        &mut self.values
+    }
+
+    #[inline]
+    pub fn try_push_valid(&mut self) -> ApolloResult<()> {
+        let total_length = self.values.len();
+        let offset = self.offsets.last().to_usize();
+        let length = total_length
+            .checked_sub(offset)
+            .ok_or_else(|| apollo_err!(ComputeError: "overflow"))?;
+
+        self.offsets.try_push(length)?;
+        if let Some(validity) = &mut self.validity {
+            validity.push(true)
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.offsets.extend_constant(1);
+        match &mut self.validity {
+            Some(validity) => validity.push(false),
+            None => self.init_validity(),
+        }
+    }
+
+    fn init_validity(&mut self) {
+        let len = self.offsets.len_proxy();
+
+        let mut validity = MutableBitmap::new();
+        validity.extend_constant(len, true);
+        validity.set(len - 1, false);
+        self.validity = Some(validity)
+    }
+
+    fn to_map_array(&mut self) -> MapArray {
+        MapArray::new(
+            self.dtype.clone(),
+            std::mem::take(&mut self.offsets).into(),
+            self.values.as_box(),
+            std::mem::take(&mut self.validity).map(|x| x.into()),
+        )
+    }
+}
+
+impl MutableArray for DynMutableMapArray {
+    fn len(&self) -> usize {
+        self.offsets.len_proxy()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        self.validity.as_ref()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        self.to_map_array().boxed()
+    }
+
+    fn as_arc(&mut self) -> std::sync::Arc<dyn Array> {
+        self.to_map_array().arced()
+    }
+
+    fn dtype(&self) -> &ArrowDataType {
+        &self.dtype
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.push_null()
+    }
+
+    fn reserve(&mut self, _: usize) {
+        todo!();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        todo!();
+    }
+}
+
 #[derive(Debug)]
 pub struct FixedItemsUtf8Dictionary {
     dtype: ArrowDataType,
@@ -40,7 +40,7 @@ pub fn infer_schema(record: &Record) -> ApolloResult<ArrowSchema> {
 fn schema_to_field(
     schema: &AvroSchema,
     name: Option<&str>,
-    props: Metadata,
+    mut props: Metadata,
 ) -> ApolloResult<Field> {
     let mut nullable = false;
     let dtype = match schema {
@@ -93,7 +93,22 @@ fn schema_to_field(
             Some("item"), // default name for list items
             Metadata::default(),
         )?)),
-        AvroSchema::Map(_) => todo!("Avro maps are mapped to MapArrays"),
+        AvroSchema::Map(value_schema) => {
+            // Avro map keys are always strings.
+            let key = Field::new(PlSmallStr::from_static("key"), ArrowDataType::Utf8, false);
+            let value = schema_to_field(value_schema, Some("value"), Metadata::default())?;
+            let entries = Field::new(
+                PlSmallStr::from_static("entries"),
+                ArrowDataType::Struct(vec![key, value]),
+                false,
+            );
+            // Avro maps are always read as a map, not as a list of their entries.
+            props.insert(
+                PlSmallStr::from_static(PL_KEY),
+                PlSmallStr::from_static(MAINTAIN_PL_TYPE),
+            );
+            ArrowDataType::Map(Box::new(entries), false)
+        },
         AvroSchema::Union(schemas) => {
             // If there are only two variants and one of them is null, set the other type as the field data type
             let has_nullable = schemas.iter().any(|x| x == &AvroSchema::Null);
@@ -103,7 +118,11 @@ fn schema_to_field(
                     .iter()
                     .find(|&schema| !matches!(schema, AvroSchema::Null))
                 {
-                    schema_to_field(schema, None, Metadata::default())?.dtype
+                    let field = schema_to_field(schema, None, Metadata::default())?;
+                    if let Some(metadata) = field.metadata {
+                        props.extend(metadata.iter().map(|(k, v)| (k.clone(), v.clone())));
+                    }
+                    field.dtype
                 } else {
                     apollo_bail!(nyi = "Can't read avro union {schema:?}");
                 }
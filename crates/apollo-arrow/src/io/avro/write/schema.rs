@@ -66,6 +66,28 @@ fn _type_to_schema(dtype: &ArrowDataType, name_counter: &mut i32) -> ApolloResul
                 name_counter,
             )?))
         },
+        ArrowDataType::Map(entries, _) => {
+            let ArrowDataType::Struct(fields) = entries.dtype.to_storage() else {
+                apollo_bail!(nyi = "write map with entries {:?} to avro", entries.dtype)
+            };
+            let [key, value] = fields.as_slice() else {
+                apollo_bail!(nyi = "write map with entries {:?} to avro", entries.dtype)
+            };
+            if !matches!(
+                key.dtype.to_storage(),
+                ArrowDataType::Utf8 | ArrowDataType::LargeUtf8
+            ) {
+                apollo_bail!(
+                    nyi = "write map with {:?} keys to avro, keys must be strings",
+                    key.dtype
+                )
+            }
+            AvroSchema::Map(Box::new(type_to_schema(
+                &value.dtype,
+                value.is_nullable,
+                name_counter,
+            )?))
+        },
         ArrowDataType::Struct(fields) => AvroSchema::Record(Record::new(
             _get_field_name(name_counter),
             fields
@@ -153,6 +153,79 @@ fn list_optional<'a, O: Offset>(array: &'a ListArray<O>, schema: &AvroSchema) ->
     ))
 }
 
+fn map_entries_serializers<'a>(
+    array: &'a MapArray,
+    schema: &AvroSchema,
+) -> (BoxSerializer<'a>, BoxSerializer<'a>) {
+    let entries = array
+        .field()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+    let keys = new_serializer(entries.values()[0].as_ref(), &AvroSchema::String(None));
+    let values = new_serializer(entries.values()[1].as_ref(), schema);
+    (keys, values)
+}
+
+fn map_required<'a>(array: &'a MapArray, schema: &AvroSchema) -> BoxSerializer<'a> {
+    let (mut keys, mut values) = map_entries_serializers(array, schema);
+    let lengths = array
+        .offsets()
+        .buffer()
+        .windows(2)
+        .map(|w| (w[1] - w[0]) as i64);
+
+    Box::new(BufStreamingIterator::new(
+        lengths,
+        move |length, buf| {
+            encode::zigzag_encode(length, buf).unwrap();
+            if length > 0 {
+                for _ in 0..length {
+                    buf.extend_from_slice(keys.next().unwrap());
+                    buf.extend_from_slice(values.next().unwrap());
+                }
+                encode::zigzag_encode(0, buf).unwrap();
+            }
+        },
+        vec![],
+    ))
+}
+
+fn map_optional<'a>(array: &'a MapArray, schema: &AvroSchema) -> BoxSerializer<'a> {
+    let (mut keys, mut values) = map_entries_serializers(array, schema);
+    let lengths = array
+        .offsets()
+        .buffer()
+        .windows(2)
+        .map(|w| (w[1] - w[0]) as i64)
+        .zip((0..array.len()).map(|i| array.is_valid(i)));
+
+    Box::new(BufStreamingIterator::new(
+        lengths,
+        move |(length, is_valid), buf| {
+            if is_valid {
+                buf.push(IS_VALID);
+                encode::zigzag_encode(length, buf).unwrap();
+                if length > 0 {
+                    for _ in 0..length {
+                        buf.extend_from_slice(keys.next().unwrap());
+                        buf.extend_from_slice(values.next().unwrap());
+                    }
+                    encode::zigzag_encode(0, buf).unwrap();
+                }
+            } else {
+                buf.push(IS_NULL);
+                // A null map may still span entries, skip them.
+                for _ in 0..length {
+                    let _ = keys.next().unwrap();
+                    let _ = values.next().unwrap();
+                }
+            }
+        },
+        vec![],
+    ))
+}
+
 fn struct_required<'a>(array: &'a StructArray, schema: &Record) -> BoxSerializer<'a> {
     let schemas = schema.fields.iter().map(|x| &x.schema);
     let mut inner = array
@@ -482,6 +555,17 @@ pub fn new_serializer<'a>(array: &'a dyn Array, schema: &AvroSchema) -> BoxSeria
             };
             list_optional::<i64>(array.as_any().downcast_ref().unwrap(), schema)
         },
+        (PhysicalType::Map, AvroSchema::Map(schema)) => {
+            map_required(array.as_any().downcast_ref().unwrap(), schema.as_ref())
+        },
+        (PhysicalType::Map, AvroSchema::Union(inner)) => {
+            let schema = if let AvroSchema::Map(schema) = &inner[1] {
+                schema.as_ref()
+            } else {
+                unreachable!("The schema declaration does not match the deserialization")
+            };
+            map_optional(array.as_any().downcast_ref().unwrap(), schema)
+        },
         (PhysicalType::Struct, AvroSchema::Record(inner)) => {
             struct_required(array.as_any().downcast_ref().unwrap(), inner)
         },
@@ -504,6 +588,7 @@ pub fn can_serialize(dtype: &ArrowDataType) -> bool {
         List(inner) => return can_serialize(&inner.dtype),
         LargeList(inner) => return can_serialize(&inner.dtype),
         Struct(inner) => return inner.iter().all(|inner| can_serialize(&inner.dtype)),
+        Map(inner, _) => return can_serialize(&inner.dtype),
         _ => {},
     };
 
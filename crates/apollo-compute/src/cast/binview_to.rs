@@ -8,7 +8,7 @@ use arrow::types::NativeType;
 use bytemuck::cast_slice_mut;
 use chrono::Datelike;
 use num_traits::FromBytes;
-use apollo_error::{ApolloResult, apollo_err};
+use apollo_error::{ApolloResult, apollo_bail, apollo_err};
 
 use super::CastOptionsImpl;
 use super::binary_to::Parse;
@@ -52,6 +52,32 @@ pub(super) fn view_to_binary<O: Offset>(array: &BinaryViewArray) -> BinaryArray<
     out.with_validity(array.validity().cloned())
 }
 
+/// Cast [`BinaryViewArray`] to a [`FixedSizeBinaryArray`] of values of `size` bytes.
+///
+/// Null slots are zero-filled. Errors if a valid value doesn't have exactly `size` bytes.
+pub(super) fn binview_to_fixed_size_binary(
+    from: &BinaryViewArray,
+    size: usize,
+) -> ApolloResult<FixedSizeBinaryArray> {
+    let mut values = Vec::with_capacity(from.len() * size);
+    for opt in from.iter() {
+        match opt {
+            Some(v) if v.len() == size => values.extend_from_slice(v),
+            Some(v) => {
+                apollo_bail!(InvalidOperation:
+                    "cannot cast binary value of {} bytes to FixedSizeBinary({size})", v.len()
+                )
+            },
+            None => values.resize(values.len() + size, 0),
+        }
+    }
+    Ok(FixedSizeBinaryArray::new(
+        ArrowDataType::FixedSizeBinary(size),
+        values.into(),
+        from.validity().cloned(),
+    ))
+}
+
 pub fn utf8view_to_utf8<O: Offset>(array: &Utf8ViewArray) -> Utf8Array<O> {
     let array = array.to_binview();
     let out = view_to_binary::<O>(&array);
@@ -475,6 +475,11 @@ pub fn cast(
                 let bin_array = view_to_binary::<i64>(array.as_any().downcast_ref().unwrap());
                 Ok(binary_to_list(&bin_array, to_type.clone()).boxed())
             },
+            FixedSizeBinary(size) => binview_to::binview_to_fixed_size_binary(
+                array.as_any().downcast_ref().unwrap(),
+                *size,
+            )
+            .map(|arr| arr.boxed()),
             _ => apollo_bail!(InvalidOperation:
                 "casting from {from_type:?} to {to_type:?} not supported",
             ),
@@ -0,0 +1,287 @@
+//! # T-Digest
+//!
+//! `tdigest` module contains an implementation of the merging t-digest, a mergeable sketch for
+//! estimating quantiles, so that approximate quantiles can be computed in a single pass and
+//! combined across partitions of the data.
+//!
+//! The implementation follows "Computing Extremely Accurate Quantiles Using t-Digests", Ted
+//! Dunning and Otmar Ertl, arXiv:1902.04023, using the `k1` scale function. Values are buffered
+//! and merged into the centroids in batches.
+//!
+//! # Examples
+//!
+//! ```
+//!     # use apollo_compute::tdigest::*;
+//!     let mut digest = TDigest::new(100);
+//!     for i in 0..=100 {
+//!         digest.add(i as f64);
+//!     }
+//!
+//!     assert_eq!(digest.quantile(0.5), Some(50.0));
+//! ```
+
+use std::f64::consts::PI;
+
+/// The number of values that are buffered, relative to the compression, before they are merged
+/// into the centroids.
+const BUFFER_FACTOR: usize = 5;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+impl Centroid {
+    fn merge(&mut self, other: &Centroid) {
+        self.weight += other.weight;
+        self.mean += (other.mean - self.mean) * other.weight / self.weight;
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TDigest {
+    compression: f64,
+    /// Merged centroids, sorted by their mean.
+    centroids: Vec<Centroid>,
+    /// Centroids that are not yet merged, in any order.
+    buffer: Vec<Centroid>,
+    min: f64,
+    max: f64,
+}
+
+impl TDigest {
+    /// Creates a new, empty t-digest.
+    ///
+    /// The `compression` bounds the number of centroids that are kept: a larger compression
+    /// gives more accurate quantiles at the cost of memory. A digest holds no more than about
+    /// `compression` centroids.
+    pub fn new(compression: u32) -> Self {
+        Self {
+            compression: compression.max(1) as f64,
+            centroids: Vec::new(),
+            buffer: Vec::new(),
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Adds a value to the t-digest, NaN values are ignored.
+    pub fn add(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.buffer.push(Centroid {
+            mean: value,
+            weight: 1.0,
+        });
+        self.maybe_compress();
+    }
+
+    /// Merge the other [`TDigest`] into this one.
+    pub fn merge(&mut self, other: &TDigest) {
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.buffer.extend_from_slice(&other.centroids);
+        self.buffer.extend_from_slice(&other.buffer);
+        self.maybe_compress();
+    }
+
+    /// Returns whether no values were added to the t-digest.
+    pub fn is_empty(&self) -> bool {
+        self.centroids.is_empty() && self.buffer.is_empty()
+    }
+
+    /// Estimate the `quantile` of the values added to the t-digest.
+    ///
+    /// The estimate interpolates linearly between the centroids, which makes it exact as long as
+    /// no values were merged into the same centroid. Returns `None` if the digest is empty.
+    pub fn quantile(&mut self, quantile: f64) -> Option<f64> {
+        self.compress();
+        let first = self.centroids.first()?;
+        let count = self.centroids.iter().map(|c| c.weight).sum::<f64>();
+        let rank = quantile.clamp(0.0, 1.0) * (count - 1.0);
+        // The extreme values are tracked exactly, but may have been merged into other centroids.
+        if rank <= 0.0 {
+            return Some(self.min);
+        }
+        if rank >= count - 1.0 {
+            return Some(self.max);
+        }
+
+        // The rank of the center of a centroid, with the smallest value at rank 0.
+        let center = |weight_before: f64, c: &Centroid| weight_before + (c.weight - 1.0) / 2.0;
+        let interpolate = |(r0, v0): (f64, f64), (r1, v1): (f64, f64)| {
+            if r1 <= r0 {
+                v0
+            } else {
+                v0 + (v1 - v0) * (rank - r0) / (r1 - r0)
+            }
+        };
+
+        let first_center = center(0.0, first);
+        if rank <= first_center {
+            return Some(interpolate((0.0, self.min), (first_center, first.mean)));
+        }
+        let mut weight_before = first.weight;
+        let mut prev = (first_center, first.mean);
+        for c in &self.centroids[1..] {
+            let next = (center(weight_before, c), c.mean);
+            if rank <= next.0 {
+                return Some(interpolate(prev, next));
+            }
+            weight_before += c.weight;
+            prev = next;
+        }
+        Some(interpolate(prev, (count - 1.0, self.max)))
+    }
+
+    fn maybe_compress(&mut self) {
+        if self.buffer.len() >= BUFFER_FACTOR * self.compression as usize {
+            self.compress();
+        }
+    }
+
+    /// The `k1` scale function, which maps a quantile to the index of the centroid it belongs to.
+    fn k(&self, q: f64) -> f64 {
+        self.compression / (2.0 * PI) * (2.0 * q - 1.0).asin()
+    }
+
+    /// The largest quantile that can be merged into a centroid starting at quantile `q`.
+    fn q_limit(&self, q: f64) -> f64 {
+        let k = self.k(q) + 1.0;
+        if k >= self.compression / 4.0 {
+            1.0
+        } else {
+            ((k * 2.0 * PI / self.compression).sin() + 1.0) / 2.0
+        }
+    }
+
+    /// Merge the buffered values into the centroids.
+    fn compress(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+        let mut all = std::mem::take(&mut self.buffer);
+        all.append(&mut self.centroids);
+        all.sort_unstable_by(|a, b| a.mean.total_cmp(&b.mean));
+        let total = all.iter().map(|c| c.weight).sum::<f64>();
+
+        let mut all = all.into_iter();
+        let mut current = all.next().unwrap();
+        let mut weight_before = 0.0;
+        let mut q_limit = self.q_limit(0.0);
+        for c in all {
+            if (weight_before + current.weight + c.weight) / total <= q_limit {
+                current.merge(&c);
+            } else {
+                weight_before += current.weight;
+                self.centroids.push(current);
+                q_limit = self.q_limit(weight_before / total);
+                current = c;
+            }
+        }
+        self.centroids.push(current);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TDigest;
+
+    /// The exact quantile of the sorted `values`, interpolating linearly between them.
+    fn exact_quantile(values: &[f64], q: f64) -> f64 {
+        let rank = q * (values.len() - 1) as f64;
+        let (lo, hi) = (rank.floor() as usize, rank.ceil() as usize);
+        values[lo] + (values[hi] - values[lo]) * (rank - lo as f64)
+    }
+
+    fn assert_rank_error(digest: &mut TDigest, values: &[f64], max_error: f64) {
+        for q in [0.001, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 0.999] {
+            let estimate = digest.quantile(q).unwrap();
+            let rank = values.partition_point(|v| *v < estimate) as f64 / values.len() as f64;
+            assert!(
+                (rank - q).abs() <= max_error,
+                "quantile {q} estimated as {estimate}, which has rank {rank}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut digest = TDigest::new(100);
+        assert!(digest.is_empty());
+        assert_eq!(digest.quantile(0.5), None);
+
+        digest.add(f64::NAN);
+        assert_eq!(digest.quantile(0.5), None);
+    }
+
+    #[test]
+    fn test_exact_when_uncompressed() {
+        let values = [3.0, -1.0, 7.5, 2.0, 2.0, 10.0, 0.5];
+        let mut digest = TDigest::new(100);
+        for v in values {
+            digest.add(v);
+        }
+        let mut sorted = values.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        for q in [0.0, 0.1, 0.25, 0.5, 0.6, 0.9, 1.0] {
+            assert_eq!(digest.quantile(q), Some(exact_quantile(&sorted, q)));
+        }
+    }
+
+    #[test]
+    fn test_single_value() {
+        let mut digest = TDigest::new(10);
+        for _ in 0..1000 {
+            digest.add(4.0);
+        }
+        assert_eq!(digest.quantile(0.0), Some(4.0));
+        assert_eq!(digest.quantile(0.3), Some(4.0));
+        assert_eq!(digest.quantile(1.0), Some(4.0));
+    }
+
+    #[test]
+    fn test_accuracy() {
+        // A deterministic permutation of 0..100_000.
+        let n = 100_000;
+        let values = (0..n).map(|i| ((i * 7_919) % n) as f64).collect::<Vec<_>>();
+        let mut digest = TDigest::new(100);
+        for v in &values {
+            digest.add(*v);
+        }
+        assert!(digest.centroids.len() <= 100);
+        assert_eq!(digest.quantile(0.0), Some(0.0));
+        assert_eq!(digest.quantile(1.0), Some((n - 1) as f64));
+
+        let mut sorted = values;
+        sorted.sort_by(f64::total_cmp);
+        assert_rank_error(&mut digest, &sorted, 0.01);
+    }
+
+    #[test]
+    fn test_merge() {
+        let n = 50_000;
+        let values = (0..n)
+            .map(|i| ((i as f64) * 0.37).sin() * 100.0)
+            .collect::<Vec<_>>();
+
+        let mut digest = TDigest::new(200);
+        for chunk in values.chunks(1_000) {
+            let mut partial = TDigest::new(200);
+            for v in chunk {
+                partial.add(*v);
+            }
+            digest.merge(&partial);
+        }
+
+        let mut sorted = values;
+        sorted.sort_by(f64::total_cmp);
+        assert_eq!(digest.quantile(0.0), Some(sorted[0]));
+        assert_eq!(digest.quantile(1.0), Some(sorted[n - 1]));
+        assert_rank_error(&mut digest, &sorted, 0.005);
+    }
+}
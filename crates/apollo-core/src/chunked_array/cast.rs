@@ -114,6 +114,8 @@ fn cast_impl_inner(
         Time => out.into_time(),
         #[cfg(feature = "dtype-decimal")]
         Decimal(precision, scale) => out.into_decimal(*precision, *scale)?,
+        #[cfg(feature = "dtype-interval")]
+        Interval => out.i128()?.clone().into_interval().into_series(),
         _ => out,
     };
 
@@ -54,6 +54,12 @@ impl LogicalType for DurationChunked {
                 };
                 Ok(out.into_duration(to_unit).into_series())
             },
+            // An interval without months and days packs to its nanoseconds.
+            #[cfg(feature = "dtype-interval")]
+            Interval => {
+                let ns = self.cast_time_unit(Nanoseconds).phys.cast(&Int128)?;
+                Ok(ns.i128()?.clone().into_interval().into_series())
+            },
             dt if dt.is_primitive_numeric() => self.phys.cast_with_options(dtype, cast_options),
             dt => {
                 apollo_bail!(
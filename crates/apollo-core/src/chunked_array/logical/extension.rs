@@ -1,3 +1,6 @@
+use std::borrow::Cow;
+use std::sync::OnceLock;
+
 use crate::chunked_array::cast::CastOptions;
 use crate::prelude::*;
 
@@ -5,6 +8,7 @@ use crate::prelude::*;
 pub struct ExtensionChunked {
     dtype: DataType,
     storage: Series,
+    comparison_key: OnceLock<Option<Series>>,
 }
 
 impl ExtensionChunked {
@@ -14,7 +18,17 @@ impl ExtensionChunked {
             "can't nest extension types"
         );
         let dtype = DataType::Extension(typ, Box::new(storage.dtype().clone()));
-        Self { dtype, storage }
+        Self {
+            dtype,
+            storage,
+            comparison_key: OnceLock::new(),
+        }
+    }
+
+    /// Create from `storage`, checking that it holds valid values for `typ`.
+    pub fn try_from_storage(typ: ExtensionTypeInstance, storage: Series) -> ApolloResult<Self> {
+        typ.0.validate(&storage)?;
+        Ok(Self::from_storage(typ, storage))
     }
 
     pub fn name(&self) -> &PlSmallStr {
@@ -22,6 +36,9 @@ impl ExtensionChunked {
     }
 
     pub fn rename(&mut self, name: PlSmallStr) {
+        if let Some(Some(key)) = self.comparison_key.get_mut() {
+            key.rename(name.clone());
+        }
         self.storage.rename(name);
     }
 
@@ -45,6 +62,7 @@ impl ExtensionChunked {
     }
 
     pub fn storage_mut(&mut self) -> &mut Series {
+        self.comparison_key.take();
         &mut self.storage
     }
 
@@ -60,15 +78,63 @@ impl ExtensionChunked {
         self.storage.is_empty()
     }
 
+    /// The key defining equality, hashing and ordering of the values, if the
+    /// extension type defines one.
+    pub fn custom_comparison_key(&self) -> ApolloResult<Option<&Series>> {
+        if let Some(key) = self.comparison_key.get() {
+            return Ok(key.as_ref());
+        }
+        let key = self.extension_type().0.comparison_key(&self.storage)?;
+        if let Some(key) = &key {
+            apollo_ensure!(
+                key.len() == self.storage.len(),
+                ComputeError: "comparison key of extension type '{}' has length {}, expected {}",
+                self.extension_type().name(), key.len(), self.storage.len()
+            );
+        }
+        let key = key.map(|key| key.with_name(self.storage.name().clone()));
+        // Another thread may have computed the key in the meantime, either is fine.
+        let _ = self.comparison_key.set(key);
+        Ok(self.comparison_key.get().unwrap().as_ref())
+    }
+
+    /// The key defining equality, hashing and ordering of the values. This is
+    /// the storage unless the extension type defines a custom comparison key.
+    pub fn comparison_key(&self) -> ApolloResult<&Series> {
+        Ok(self.custom_comparison_key()?.unwrap_or(&self.storage))
+    }
+
     pub fn get_any_value(&self, i: usize) -> ApolloResult<AnyValue<'_>> {
         self.storage().get(i)
     }
 
+    /// Get the value at index `i` formatted as a string.
+    pub fn str_value(&self, i: usize) -> ApolloResult<Cow<'_, str>> {
+        let v = self.storage().get(i)?;
+        if !v.is_null() {
+            if let Some(out) = self.extension_type().0.fmt_value(&v) {
+                return Ok(Cow::Owned(out));
+            }
+        }
+        Ok(v.str_value())
+    }
+
     pub fn cast_with_options(
         &self,
         dtype: &DataType,
-        _options: CastOptions,
+        options: CastOptions,
     ) -> ApolloResult<Series> {
-        apollo_bail!(ComputeError: "cannot cast extension types to {dtype:?}")
+        let typ = self.extension_type();
+        match typ.0.cast_to(&self.storage, dtype, options)? {
+            Some(out) => {
+                apollo_ensure!(
+                    out.dtype() == dtype,
+                    ComputeError: "cast of extension type '{}' to {} returned dtype {}",
+                    typ.name(), dtype, out.dtype()
+                );
+                Ok(out)
+            },
+            None => self.storage.cast_with_options(dtype, options),
+        }
     }
 }
@@ -0,0 +1,123 @@
+use super::*;
+use crate::fmt::fmt_interval_string;
+use crate::prelude::arity::unary_elementwise;
+use crate::prelude::*;
+
+pub type IntervalChunked = Logical<IntervalType, Int128Type>;
+
+/// Pack an interval into its physical `i128` representation.
+///
+/// The packing is linear, so adding, subtracting and negating the physical
+/// values acts component-wise on the months, days and nanoseconds.
+#[inline]
+pub fn interval_to_i128(v: months_days_ns) -> i128 {
+    ((v.months() as i128) << 96)
+        .wrapping_add((v.days() as i128) << 64)
+        .wrapping_add(v.ns() as i128)
+}
+
+/// Unpack the physical `i128` representation of an interval.
+#[inline]
+pub fn i128_to_interval(v: i128) -> months_days_ns {
+    let ns = v as i64;
+    let rest = v.wrapping_sub(ns as i128) >> 64;
+    let days = rest as i32;
+    let months = (rest.wrapping_sub(days as i128) >> 32) as i32;
+    months_days_ns::new(months, days, ns)
+}
+
+impl Int128Chunked {
+    pub fn into_interval(self) -> IntervalChunked {
+        // SAFETY: no invalid states.
+        unsafe { IntervalChunked::new_logical(self, DataType::Interval) }
+    }
+}
+
+impl IntervalChunked {
+    /// Construct a new [`IntervalChunked`] from an iterator over optional intervals.
+    pub fn from_intervals_options<I: IntoIterator<Item = Option<months_days_ns>>>(
+        name: PlSmallStr,
+        v: I,
+    ) -> Self {
+        let vals = v.into_iter().map(|opt| opt.map(interval_to_i128));
+        Int128Chunked::from_iter_options(name, vals).into_interval()
+    }
+
+    /// Get the months, days and nanoseconds of every interval.
+    pub fn components(&self) -> (Int32Chunked, Int32Chunked, Int64Chunked) {
+        let months = self
+            .phys
+            .apply_nonnull_values_generic(DataType::Int32, |v| i128_to_interval(v).months());
+        let days = self
+            .phys
+            .apply_nonnull_values_generic(DataType::Int32, |v| i128_to_interval(v).days());
+        let ns = self
+            .phys
+            .apply_nonnull_values_generic(DataType::Int64, |v| i128_to_interval(v).ns());
+        (months, days, ns)
+    }
+}
+
+impl LogicalType for IntervalChunked {
+    fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    fn get_any_value(&self, i: usize) -> ApolloResult<AnyValue<'_>> {
+        apollo_ensure!(i < self.len(), oob = i, self.len());
+        Ok(unsafe { self.get_any_value_unchecked(i) })
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        match self.phys.get_unchecked(i) {
+            Some(v) => AnyValue::Interval(i128_to_interval(v)),
+            None => AnyValue::Null,
+        }
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> ApolloResult<Series> {
+        use DataType::*;
+        match dtype {
+            Interval => Ok(self.clone().into_series()),
+            Duration(tu) => {
+                // Months don't have a fixed length, so only intervals without a
+                // month part can be represented as a duration.
+                let out: Int64Chunked = unary_elementwise(&self.phys, |opt_v| {
+                    opt_v.and_then(|v| {
+                        AnyValue::Interval(i128_to_interval(v))
+                            .strict_cast(dtype)?
+                            .extract::<i64>()
+                    })
+                });
+                if cast_options.is_strict() {
+                    let failures = out.null_count() - self.null_count();
+                    apollo_ensure!(
+                        failures == 0,
+                        InvalidOperation: "conversion from `interval` to `{}` failed for {} values: intervals with a month part have no fixed length",
+                        dtype, failures
+                    );
+                }
+                Ok(out.into_duration(*tu).into_series())
+            },
+            String => Ok(self
+                .phys
+                .apply_into_string_amortized(|v, buf| {
+                    fmt_interval_string(buf, i128_to_interval(v))
+                        .expect("failed to format interval")
+                })
+                .into_series()),
+            Int128 => Ok(self.phys.clone().into_series()),
+            dt => {
+                apollo_bail!(
+                    InvalidOperation:
+                    "casting from {:?} to {:?} not supported",
+                    self.dtype(), dt
+                )
+            },
+        }
+    }
+}
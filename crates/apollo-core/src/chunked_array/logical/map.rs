@@ -0,0 +1,113 @@
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+
+/// A column of maps, stored as a list of `{key, value}` structs per row.
+#[derive(Clone)]
+pub struct MapChunked {
+    dtype: DataType,
+    storage: Series,
+}
+
+impl MapChunked {
+    /// # Panics
+    /// Panics if `storage` is not a list of `{key, value}` structs.
+    pub fn from_storage(storage: Series) -> Self {
+        let DataType::List(entries) = storage.dtype() else {
+            panic!("map storage must be a list, got {:?}", storage.dtype())
+        };
+        let DataType::Struct(fields) = entries.as_ref() else {
+            panic!("map entries must be a struct, got {entries:?}")
+        };
+        assert!(
+            fields.len() == 2
+                && fields[0].name() == &MAP_KEY_NAME
+                && fields[1].name() == &MAP_VALUE_NAME,
+            "map entries must have a `key` and a `value` field"
+        );
+        let dtype = DataType::Map(
+            Box::new(fields[0].dtype().clone()),
+            Box::new(fields[1].dtype().clone()),
+        );
+        Self { dtype, storage }
+    }
+
+    pub fn name(&self) -> &PlSmallStr {
+        self.storage.name()
+    }
+
+    pub fn rename(&mut self, name: PlSmallStr) {
+        self.storage.rename(name);
+    }
+
+    pub fn field(&self) -> Field {
+        Field::new(self.storage.name().clone(), self.dtype.clone())
+    }
+
+    pub fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    pub fn key_dtype(&self) -> &DataType {
+        match &self.dtype {
+            DataType::Map(key, _) => key,
+            _ => unreachable!("MapChunked must have DataType::Map"),
+        }
+    }
+
+    pub fn value_dtype(&self) -> &DataType {
+        match &self.dtype {
+            DataType::Map(_, value) => value,
+            _ => unreachable!("MapChunked must have DataType::Map"),
+        }
+    }
+
+    /// The entries of the maps as a list of `{key, value}` structs.
+    pub fn storage(&self) -> &Series {
+        &self.storage
+    }
+
+    pub fn storage_mut(&mut self) -> &mut Series {
+        &mut self.storage
+    }
+
+    pub fn into_storage(self) -> Series {
+        self.storage
+    }
+
+    /// The entries of the maps as a [`ListChunked`] of `{key, value}` structs.
+    pub fn entries(&self) -> &ListChunked {
+        self.storage.list().unwrap()
+    }
+
+    /// The `{key, value}` structs of all maps, ignoring the offsets of the rows.
+    pub fn entries_flat(&self) -> StructChunked {
+        self.entries().get_inner().struct_().unwrap().clone()
+    }
+
+    pub fn len(&self) -> usize {
+        self.storage.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.storage.is_empty()
+    }
+
+    pub fn get_any_value(&self, i: usize) -> ApolloResult<AnyValue<'_>> {
+        self.storage().get(i)
+    }
+
+    pub fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        options: CastOptions,
+    ) -> ApolloResult<Series> {
+        match dtype {
+            DataType::Map(key, value) => Ok(self
+                .storage
+                .cast_with_options(&DataType::map_storage_dtype(key, value), options)?
+                .into_map()),
+            DataType::List(_) => self.storage.cast_with_options(dtype, options),
+            _ => apollo_bail!(InvalidOperation: "cannot cast {} to {dtype}", self.dtype),
+        }
+    }
+}
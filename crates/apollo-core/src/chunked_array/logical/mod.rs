@@ -22,12 +22,24 @@ pub use duration::*;
 mod extension;
 #[cfg(feature = "dtype-extension")]
 pub use extension::*;
+#[cfg(feature = "dtype-interval")]
+mod interval;
+#[cfg(feature = "dtype-interval")]
+pub use interval::*;
+#[cfg(feature = "dtype-map")]
+mod map;
+#[cfg(feature = "dtype-map")]
+pub use map::*;
 #[cfg(feature = "dtype-time")]
 mod time;
+#[cfg(feature = "dtype-union")]
+mod union;
 use std::marker::PhantomData;
 
 #[cfg(feature = "dtype-time")]
 pub use time::*;
+#[cfg(feature = "dtype-union")]
+pub use union::*;
 
 use crate::chunked_array::cast::CastOptions;
 use crate::prelude::*;
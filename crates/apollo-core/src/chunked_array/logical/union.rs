@@ -0,0 +1,156 @@
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+
+/// A column of tagged unions, stored as a struct of the tag and one field per member.
+///
+/// The tag holds the index of the member selected in each row, the other members are null in
+/// that row.
+#[derive(Clone)]
+pub struct UnionChunked {
+    dtype: DataType,
+    storage: Series,
+}
+
+impl UnionChunked {
+    /// # Panics
+    /// Panics if `dtype` is not a union or `storage` is not of its storage dtype.
+    pub fn from_storage(storage: Series, dtype: DataType) -> Self {
+        let DataType::Union(fields, _) = &dtype else {
+            panic!("expected a union dtype, got {dtype:?}")
+        };
+        assert_eq!(
+            storage.dtype(),
+            &DataType::union_storage_dtype(fields),
+            "union storage does not match its dtype"
+        );
+        Self { dtype, storage }
+    }
+
+    pub fn name(&self) -> &PlSmallStr {
+        self.storage.name()
+    }
+
+    pub fn rename(&mut self, name: PlSmallStr) {
+        self.storage.rename(name);
+    }
+
+    pub fn field(&self) -> Field {
+        Field::new(self.storage.name().clone(), self.dtype.clone())
+    }
+
+    pub fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    /// The member fields of the union.
+    pub fn members(&self) -> &[Field] {
+        match &self.dtype {
+            DataType::Union(fields, _) => fields,
+            _ => unreachable!("UnionChunked must have DataType::Union"),
+        }
+    }
+
+    pub fn layout(&self) -> &UnionLayout {
+        match &self.dtype {
+            DataType::Union(_, layout) => layout,
+            _ => unreachable!("UnionChunked must have DataType::Union"),
+        }
+    }
+
+    /// The tag and members as a struct.
+    pub fn storage(&self) -> &Series {
+        &self.storage
+    }
+
+    pub fn storage_mut(&mut self) -> &mut Series {
+        &mut self.storage
+    }
+
+    pub fn into_storage(self) -> Series {
+        self.storage
+    }
+
+    pub fn len(&self) -> usize {
+        self.storage.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.storage.is_empty()
+    }
+
+    /// The index of the member selected in each row, null for null rows.
+    pub fn member_indices(&self) -> Int8Chunked {
+        let ca = self.storage.struct_().unwrap();
+        let tag = ca.fields_as_series()[0].i8().unwrap().clone();
+        if !ca.has_nulls() {
+            return tag;
+        }
+        let nulls = Int8Chunked::full_null(PlSmallStr::EMPTY, tag.len());
+        tag.zip_with(&ca.is_not_null(), &nulls).unwrap()
+    }
+
+    /// The arrow type id of the member selected in each row, null for null rows.
+    pub fn type_ids(&self) -> Int8Chunked {
+        let indices = self.member_indices();
+        let out = match &self.layout().type_ids {
+            Some(ids) => indices.apply_values(|i| ids[i as usize] as i8),
+            None => indices,
+        };
+        out.with_name(self.name().clone())
+    }
+
+    /// The values of the member `name`, null in the rows where another member is selected.
+    pub fn member(&self, name: &str) -> ApolloResult<Series> {
+        let Some(idx) = self.members().iter().position(|fld| fld.name() == name) else {
+            apollo_bail!(StructFieldNotFound: "{name}")
+        };
+        let values = &self.storage.struct_()?.fields_as_series()[idx + 1];
+        let selected: BooleanChunked = self
+            .member_indices()
+            .iter()
+            .map(|tag| tag == Some(idx as i8))
+            .collect_ca(PlSmallStr::EMPTY);
+        let nulls = Series::full_null(PlSmallStr::EMPTY, values.len(), values.dtype());
+        Ok(values
+            .zip_with_same_type(&selected, &nulls)?
+            .with_name(self.members()[idx].name().clone()))
+    }
+
+    /// The value of the member selected in row `i`.
+    pub fn get_any_value(&self, i: usize) -> ApolloResult<AnyValue<'_>> {
+        if self.storage.get(i)?.is_null() {
+            return Ok(AnyValue::Null);
+        }
+        let fields = self.storage.struct_()?.fields_as_series();
+        let AnyValue::Int8(tag) = fields[0].get(i)? else {
+            return Ok(AnyValue::Null);
+        };
+        Ok(fields[tag as usize + 1].get(i)?.into_static())
+    }
+
+    pub fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        options: CastOptions,
+    ) -> ApolloResult<Series> {
+        match dtype {
+            DataType::Union(fields, _) => {
+                apollo_ensure!(
+                    fields.len() == self.members().len()
+                        && fields
+                            .iter()
+                            .zip(self.members())
+                            .all(|(l, r)| l.name() == r.name()),
+                    InvalidOperation: "cannot cast {} to {dtype}, the members differ", self.dtype
+                );
+                // Keep the layout, the target is usually given without one.
+                let dtype = DataType::Union(fields.clone(), self.layout().clone());
+                Ok(self
+                    .storage
+                    .cast_with_options(&DataType::union_storage_dtype(fields), options)?
+                    .into_union(dtype))
+            },
+            _ => apollo_bail!(InvalidOperation: "cannot cast {} to {dtype}", self.dtype),
+        }
+    }
+}
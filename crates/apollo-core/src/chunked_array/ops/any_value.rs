@@ -134,8 +134,41 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
             let v = arr.value_unchecked(idx);
             AnyValue::Decimal(v, *precision, *scale)
         },
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => {
+            let arr = &*(arr as *const dyn Array as *const Int128Array);
+            let v = arr.value_unchecked(idx);
+            AnyValue::Interval(i128_to_interval(v))
+        },
         #[cfg(feature = "dtype-extension")]
         DataType::Extension(typ, storage) => arr_to_any_value(arr, idx, storage),
+        #[cfg(feature = "dtype-map")]
+        DataType::Map(key, value) => {
+            let v: ArrayRef = downcast!(LargeListArray);
+            let entries = DataType::map_entries_dtype(key, value);
+            let s = Series::from_chunks_and_dtype_unchecked(
+                PlSmallStr::EMPTY,
+                vec![v],
+                &entries.to_physical(),
+            )
+            .from_physical_unchecked(&entries)
+            .unwrap();
+            AnyValue::List(s)
+        },
+        #[cfg(feature = "dtype-union")]
+        DataType::Union(fields, _) => {
+            // The value of a union is the value of its selected member.
+            let arr = &*(arr as *const dyn Array as *const StructArray);
+            let tags = arr.values()[0]
+                .as_any()
+                .downcast_ref::<Int8Array>()
+                .unwrap();
+            if tags.is_null(idx) {
+                return AnyValue::Null;
+            }
+            let tag = tags.value_unchecked(idx) as usize;
+            arr_to_any_value(&*arr.values()[tag + 1], idx, fields[tag].dtype())
+        },
         #[cfg(feature = "object")]
         DataType::Object(_) => {
             use crate::chunked_array::object::registry::get_object_array_getter;
@@ -97,6 +97,8 @@ pub fn get_row_encoding_context(dtype: &DataType) -> Option<RowEncodingContext>
         | DataType::Date
         | DataType::Datetime(_, _)
         | DataType::Duration(_) => None,
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => None,
 
         #[cfg(feature = "dtype-categorical")]
         DataType::Categorical(_, mapping) | DataType::Enum(_, mapping) => {
@@ -148,6 +150,14 @@ pub fn get_row_encoding_context(dtype: &DataType) -> Option<RowEncodingContext>
         },
         #[cfg(feature = "dtype-extension")]
         DataType::Extension(_, storage) => get_row_encoding_context(storage),
+        #[cfg(feature = "dtype-map")]
+        DataType::Map(key, value) => {
+            get_row_encoding_context(&DataType::map_storage_dtype(key, value))
+        },
+        #[cfg(feature = "dtype-union")]
+        DataType::Union(fields, _) => {
+            get_row_encoding_context(&DataType::union_storage_dtype(fields))
+        },
     }
 }
 
@@ -175,7 +185,8 @@ pub fn _get_rows_encoded_unordered(by: &[Column]) -> ApolloResult<RowsEncoded> {
             .trim_lists_to_normalized_offsets()
             .map_or(Cow::Borrowed(by), Cow::Owned);
         let by = by.propagate_nulls().map_or(by, Cow::Owned);
-        let by = by.as_materialized_series();
+        // Extension types may define their own equality and ordering.
+        let by = by.as_materialized_series().to_comparison_key()?;
         let arr = by.to_physical_repr().rechunk().chunks()[0].to_boxed();
         let opt = RowEncodingOptions::new_unsorted();
         let ctxt = get_row_encoding_context(by.dtype());
@@ -210,7 +221,8 @@ pub fn _get_rows_encoded(
             .trim_lists_to_normalized_offsets()
             .map_or(Cow::Borrowed(by), Cow::Owned);
         let by = by.propagate_nulls().map_or(by, Cow::Owned);
-        let by = by.as_materialized_series();
+        // Extension types may define their own equality and ordering.
+        let by = by.as_materialized_series().to_comparison_key()?;
         let arr = by.to_physical_repr().rechunk().chunks()[0].to_boxed();
         let opt = RowEncodingOptions::new_sorted(*desc, *null_last);
         let ctxt = get_row_encoding_context(by.dtype());
@@ -41,7 +41,11 @@ pub fn _arg_bottom_k(
 
     // Don't go into row encoding.
     if by_column.len() == 1 && sort_options.limit.is_some() && !sort_options.maintain_order {
-        return Ok(NoNull::new(by_column[0].arg_sort((&*sort_options).into())));
+        return Ok(NoNull::new(
+            by_column[0]
+                .to_comparison_key()?
+                .arg_sort((&*sort_options).into()),
+        ));
     }
 
     let encoded = _get_rows_encoded(
@@ -32,6 +32,10 @@ pub(crate) fn arg_sort_multiple_impl<T: NullOrderCmp + Send + Copy>(
     debug_assert_eq!(descending.len() - 1, by.len());
     debug_assert_eq!(nulls_last.len() - 1, by.len());
 
+    let by = by
+        .iter()
+        .map(|c| c.to_comparison_key())
+        .collect::<ApolloResult<Vec<_>>>()?;
     let compare_inner: Vec<_> = by
         .iter()
         .map(|c| c.into_total_ord_inner())
@@ -824,7 +824,7 @@ pub fn arg_sort(columns: &[Column], mut sort_options: SortMultipleOptions) -> Ap
     assert!(!columns.is_empty());
 
     if let [c] = columns {
-        Ok(c.arg_sort(SortOptions {
+        Ok(c.to_comparison_key()?.arg_sort(SortOptions {
             descending: sort_options.descending[0],
             nulls_last: sort_options.nulls_last[0],
             multithreaded: sort_options.multithreaded,
@@ -102,6 +102,12 @@ enum SerializableDataType {
         metadata: Option<String>,
         storage: Box<SerializableDataType>,
     },
+    #[cfg(feature = "dtype-map")]
+    Map(Box<SerializableDataType>, Box<SerializableDataType>),
+    #[cfg(feature = "dtype-interval")]
+    Interval,
+    #[cfg(feature = "dtype-union")]
+    Union(Vec<Field>, UnionLayout),
 }
 
 impl From<&DataType> for SerializableDataType {
@@ -160,6 +166,15 @@ impl From<&DataType> for SerializableDataType {
                 metadata: typ.serialize_metadata().map(|s| s.into_owned()),
                 storage: Box::new(SerializableDataType::from(storage.as_ref())),
             },
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => Self::Map(
+                Box::new(key.as_ref().into()),
+                Box::new(value.as_ref().into()),
+            ),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Self::Interval,
+            #[cfg(feature = "dtype-union")]
+            Union(flds, layout) => Self::Union(flds.clone(), layout.clone()),
         }
     }
 }
@@ -234,6 +249,12 @@ impl From<SerializableDataType> for DataType {
                 );
                 Self::Extension(ext_type, Box::new(storage))
             },
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => Self::Map(Box::new((*key).into()), Box::new((*value).into())),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Self::Interval,
+            #[cfg(feature = "dtype-union")]
+            Union(flds, layout) => Self::Union(flds, layout),
         }
     }
 }
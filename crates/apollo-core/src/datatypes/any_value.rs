@@ -114,6 +114,9 @@ pub enum AnyValue<'a> {
     /// A 128-bit fixed point decimal number with a precision and scale.
     #[cfg(feature = "dtype-decimal")]
     Decimal(i128, usize, usize),
+    /// A calendar interval of months, days and nanoseconds.
+    #[cfg(feature = "dtype-interval")]
+    Interval(months_days_ns),
 }
 
 impl AnyValue<'static> {
@@ -130,6 +133,8 @@ impl AnyValue<'static> {
             },
             #[cfg(feature = "dtype-duration")]
             DataType::Duration(unit) => AnyValue::Duration(0, *unit),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => AnyValue::Interval(months_days_ns::default()),
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(p, s) => AnyValue::Decimal(0, *p, *s),
             _ => AnyValue::Null,
@@ -177,6 +182,8 @@ impl AnyValue<'static> {
                 feature_gated!("dtype-duration", AV::Duration(0, *time_unit))
             },
             DT::Time => feature_gated!("dtype-time", AV::Time(0)),
+            #[cfg(feature = "dtype-interval")]
+            DT::Interval => AV::Interval(months_days_ns::default()),
             #[cfg(feature = "dtype-array")]
             DT::Array(inner_dtype, width) => {
                 let inner_value =
@@ -220,6 +227,17 @@ impl AnyValue<'static> {
             DT::Extension(_typ, storage) => {
                 AnyValue::default_value(storage, numeric_to_one, num_list_values)
             },
+            // Repeating a default key would not give a valid map.
+            #[cfg(feature = "dtype-map")]
+            DT::Map(key, value) => AV::List(Series::new_empty(
+                PlSmallStr::EMPTY,
+                &DT::map_entries_dtype(key, value),
+            )),
+            #[cfg(feature = "dtype-union")]
+            DT::Union(fields, _) => match fields.first() {
+                Some(fld) => AnyValue::default_value(fld.dtype(), numeric_to_one, num_list_values),
+                None => AV::Null,
+            },
             DT::Unknown(_) => unreachable!(),
         }
     }
@@ -277,6 +295,8 @@ impl<'a> AnyValue<'a> {
             StructOwned(payload) => DataType::Struct(payload.1.clone()),
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, p, s) => DataType::Decimal(*p, *s),
+            #[cfg(feature = "dtype-interval")]
+            Interval(_) => DataType::Interval,
             #[cfg(feature = "object")]
             Object(o) => DataType::Object(o.type_name()),
             #[cfg(feature = "object")]
@@ -625,6 +645,32 @@ impl<'a> AnyValue<'a> {
                 *tu_r,
             ),
 
+            // to interval
+            #[cfg(feature = "dtype-interval")]
+            (AnyValue::Duration(v, tu), DataType::Interval) => {
+                let ns = match tu {
+                    TimeUnit::Nanoseconds => *v,
+                    TimeUnit::Microseconds => v.checked_mul(1_000)?,
+                    TimeUnit::Milliseconds => v.checked_mul(1_000_000)?,
+                };
+                AnyValue::Interval(months_days_ns::new(0, 0, ns))
+            },
+            // Only intervals without a month part have a fixed length.
+            #[cfg(feature = "dtype-interval")]
+            (AnyValue::Interval(v), DataType::Duration(tu)) if v.months() == 0 => {
+                let ns = (v.days() as i64)
+                    .checked_mul(NS_IN_DAY)?
+                    .checked_add(v.ns())?;
+                AnyValue::Duration(
+                    match tu {
+                        TimeUnit::Nanoseconds => ns,
+                        TimeUnit::Microseconds => ns / 1_000,
+                        TimeUnit::Milliseconds => ns / 1_000_000,
+                    },
+                    *tu,
+                )
+            },
+
             #[cfg(feature = "dtype-decimal")]
             (av, DataType::Decimal(p, s)) if av.is_integer() => {
                 let int = av.try_extract::<i128>().ok()?;
@@ -765,6 +811,8 @@ impl<'a> AnyValue<'a> {
 
             #[cfg(feature = "dtype-decimal")]
             Self::Decimal(v, _, _) => Self::Int128(v),
+            #[cfg(feature = "dtype-interval")]
+            Self::Interval(v) => Self::Int128(interval_to_i128(v)),
         }
     }
 
@@ -887,6 +935,8 @@ impl AnyValue<'_> {
                 s.hash(state);
                 p.hash(state);
             },
+            #[cfg(feature = "dtype-interval")]
+            Interval(v) => v.hash(state),
             Null => {},
         }
     }
@@ -1028,6 +1078,12 @@ impl<'a> AnyValue<'a> {
 
                 Decimal(l + r, *lp, *ls)
             },
+            #[cfg(feature = "dtype-interval")]
+            (Interval(l), Interval(r)) => Interval(months_days_ns::new(
+                l.months() + r.months(),
+                l.days() + r.days(),
+                l.ns() + r.ns(),
+            )),
             _ => unimplemented!(),
         }
     }
@@ -1108,6 +1164,8 @@ impl<'a> AnyValue<'a> {
             },
             #[cfg(feature = "dtype-decimal")]
             Decimal(val, s, p) => Decimal(val, s, p),
+            #[cfg(feature = "dtype-interval")]
+            Interval(v) => Interval(v),
             #[cfg(feature = "dtype-categorical")]
             Categorical(cat, map) => CategoricalOwned(cat, map.clone()),
             #[cfg(feature = "dtype-categorical")]
@@ -1269,6 +1327,8 @@ impl AnyValue<'_> {
             },
             #[cfg(feature = "dtype-duration")]
             (Duration(l, tu_l), Duration(r, tu_r)) => l == r && tu_l == tu_r,
+            #[cfg(feature = "dtype-interval")]
+            (Interval(l), Interval(r)) => l == r,
 
             #[cfg(feature = "dtype-struct")]
             (StructOwned(l), StructOwned(r)) => struct_eq_missing(
@@ -1411,6 +1471,8 @@ impl PartialOrd for AnyValue<'_> {
 
                 lt.partial_cmp(rt)
             },
+            #[cfg(feature = "dtype-interval")]
+            (Interval(l), Interval(r)) => l.partial_cmp(r),
             #[cfg(feature = "dtype-time")]
             (Time(l), Time(r)) => l.partial_cmp(r),
             #[cfg(feature = "dtype-categorical")]
@@ -4,6 +4,10 @@ use arrow::datatypes::{
     DTYPE_CATEGORICAL_NEW, DTYPE_ENUM_VALUES_LEGACY, DTYPE_ENUM_VALUES_NEW, MAINTAIN_PL_TYPE,
     Metadata, PL_KEY,
 };
+#[cfg(feature = "dtype-union")]
+use arrow::datatypes::{UnionMode, UnionType};
+#[cfg(feature = "dtype-union")]
+use apollo_utils::format_pl_smallstr;
 #[cfg(feature = "dtype-array")]
 use apollo_utils::format_tuple;
 use apollo_utils::itertools::Itertools;
@@ -18,6 +22,38 @@ use crate::chunked_array::object::registry::get_object_physical_type;
 pub use crate::datatypes::extension::ExtensionTypeInstance;
 use crate::utils::materialize_dyn_int;
 
+/// Name of the key field of the entries of a [`DataType::Map`].
+#[cfg(feature = "dtype-map")]
+pub const MAP_KEY_NAME: PlSmallStr = PlSmallStr::from_static("key");
+/// Name of the value field of the entries of a [`DataType::Map`].
+#[cfg(feature = "dtype-map")]
+pub const MAP_VALUE_NAME: PlSmallStr = PlSmallStr::from_static("value");
+/// Name of the entries field of an arrow map.
+#[cfg(feature = "dtype-map")]
+pub const MAP_ENTRIES_NAME: PlSmallStr = PlSmallStr::from_static("entries");
+/// Name of the field holding the member index of each row in the storage of a
+/// [`DataType::Union`].
+#[cfg(feature = "dtype-union")]
+pub const UNION_TAG_NAME: PlSmallStr = PlSmallStr::from_static("tag");
+
+/// The arrow layout of a [`DataType::Union`].
+///
+/// This does not influence how the union is stored, it is kept so the union is exported with the
+/// same layout as it was imported with.
+#[cfg(feature = "dtype-union")]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    any(feature = "serde", feature = "serde-lazy"),
+    derive(Serialize, Deserialize)
+)]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct UnionLayout {
+    /// Whether the members only hold the values of the rows they are selected in.
+    pub dense: bool,
+    /// The arrow type id of each member, if these are not `0..n`.
+    pub type_ids: Option<Vec<i32>>,
+}
+
 pub trait MetaDataExt: IntoMetadata {
     fn pl_enum_metadata(&self) -> Option<&str> {
         let md = self.into_metadata_ref();
@@ -118,6 +154,9 @@ pub enum DataType {
     Datetime(TimeUnit, Option<TimeZone>),
     /// 64-bit integer representing difference between times in milliseconds or nanoseconds
     Duration(TimeUnit),
+    /// A calendar interval of months, days and nanoseconds, backed by a signed 128-bit integer.
+    #[cfg(feature = "dtype-interval")]
+    Interval,
     /// A 64-bit time representing the elapsed time since midnight in nanoseconds
     Time,
     /// A nested list with a fixed size in each row
@@ -139,6 +178,13 @@ pub enum DataType {
     Struct(Vec<Field>),
     #[cfg(feature = "dtype-extension")]
     Extension(ExtensionTypeInstance, Box<DataType>),
+    /// A map from keys to values, stored as a list of `{key, value}` structs in each row.
+    #[cfg(feature = "dtype-map")]
+    Map(Box<DataType>, Box<DataType>),
+    /// Each row holds a value of one of the member fields, stored as a struct of a tag with the
+    /// member index and one field per member.
+    #[cfg(feature = "dtype-union")]
+    Union(Vec<Field>, UnionLayout),
     // some logical types we cannot know statically, e.g. Datetime
     Unknown(UnknownKind),
 }
@@ -184,6 +230,13 @@ impl PartialEq for DataType {
                 (Array(left_inner, left_width), Array(right_inner, right_width)) => {
                     left_width == right_width && left_inner == right_inner
                 },
+                #[cfg(feature = "dtype-map")]
+                (Map(left_key, left_value), Map(right_key, right_value)) => {
+                    left_key == right_key && left_value == right_value
+                },
+                // The layout only matters for export.
+                #[cfg(feature = "dtype-union")]
+                (Union(left_fields, _), Union(right_fields, _)) => left_fields == right_fields,
                 (Unknown(l), Unknown(r)) => match (l, r) {
                     (UnknownKind::Int(_), UnknownKind::Int(_)) => true,
                     _ => l == r,
@@ -228,6 +281,18 @@ impl DataType {
                 let formatted_dtype = inner_dtype.pretty_format();
                 format!("array[{}, {}]", formatted_dtype, size)
             },
+            #[cfg(feature = "dtype-map")]
+            Self::Map(key, value) => {
+                format!("map[{}, {}]", key.pretty_format(), value.pretty_format())
+            },
+            #[cfg(feature = "dtype-union")]
+            Self::Union(fields, _) => format!(
+                "union[{}]",
+                fields
+                    .iter()
+                    .map(|fld| format!("{}: {}", fld.name(), fld.dtype().pretty_format()))
+                    .join(", ")
+            ),
             _ => {
                 format!("{}", self)
             },
@@ -269,12 +334,43 @@ impl DataType {
         ])
     }
 
+    /// The dtype of the entries of a [`DataType::Map`] with the given key and value dtypes.
+    #[cfg(feature = "dtype-map")]
+    pub fn map_entries_dtype(key: &DataType, value: &DataType) -> Self {
+        DataType::Struct(vec![
+            Field::new(MAP_KEY_NAME, key.clone()),
+            Field::new(MAP_VALUE_NAME, value.clone()),
+        ])
+    }
+
+    /// The dtype a [`DataType::Map`] is stored as, a list of its entries.
+    #[cfg(feature = "dtype-map")]
+    pub fn map_storage_dtype(key: &DataType, value: &DataType) -> Self {
+        DataType::List(Box::new(Self::map_entries_dtype(key, value)))
+    }
+
+    /// The dtype a [`DataType::Union`] with the given members is stored as, a struct of the tag
+    /// followed by the members named by their index.
+    #[cfg(feature = "dtype-union")]
+    pub fn union_storage_dtype(fields: &[Field]) -> Self {
+        let tag = Field::new(UNION_TAG_NAME, DataType::Int8);
+        let members = fields
+            .iter()
+            .enumerate()
+            .map(|(i, fld)| Field::new(format_pl_smallstr!("{i}"), fld.dtype().clone()));
+        DataType::Struct(std::iter::once(tag).chain(members).collect())
+    }
+
     /// Check if the whole dtype is known.
     pub fn is_known(&self) -> bool {
         match self {
             DataType::List(inner) => inner.is_known(),
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner, _) => inner.is_known(),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => key.is_known() && value.is_known(),
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(fields, _) => fields.iter().all(|fld| fld.dtype.is_known()),
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => fields.iter().all(|fld| fld.dtype.is_known()),
             DataType::Unknown(_) => false,
@@ -316,6 +412,23 @@ impl DataType {
                     })
                     .try_collect_vec()?,
             )),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => Ok(DataType::Map(
+                Box::new(key.materialize_unknown(allow_unknown)?),
+                Box::new(value.materialize_unknown(allow_unknown)?),
+            )),
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(fields, layout) => Ok(DataType::Union(
+                fields
+                    .iter()
+                    .map(|f| {
+                        f.dtype()
+                            .materialize_unknown(allow_unknown)
+                            .map(|dtype| Field::new(f.name().clone(), dtype))
+                    })
+                    .try_collect_vec()?,
+                layout.clone(),
+            )),
             _ => Ok(self),
         }
     }
@@ -422,6 +535,19 @@ impl DataType {
             },
             #[cfg(feature = "dtype-extension")]
             Extension(ext, storage) => Extension(ext, Box::new(storage.map_leaves(f))),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => Map(Box::new(key.map_leaves(f)), Box::new(value.map_leaves(f))),
+            #[cfg(feature = "dtype-union")]
+            Union(fields, layout) => Union(
+                fields
+                    .into_iter()
+                    .map(|fld| {
+                        let name = fld.name().clone();
+                        Field::new(name, fld.dtype.map_leaves(f))
+                    })
+                    .collect(),
+                layout,
+            ),
             _ => f(self),
         }
     }
@@ -483,6 +609,28 @@ impl DataType {
 
                 true
             },
+            #[cfg(feature = "dtype-map")]
+            (D::Map(l_key, l_value), D::Map(r_key, r_value)) => {
+                l_key.can_cast_to(r_key)? && l_value.can_cast_to(r_value)?
+            },
+            #[cfg(feature = "dtype-map")]
+            (D::Map(key, value), D::List(_)) => D::map_storage_dtype(key, value).can_cast_to(to)?,
+            #[cfg(feature = "dtype-map")]
+            (D::List(_), D::Map(key, value)) => {
+                self.can_cast_to(&D::map_storage_dtype(key, value))?
+            },
+            #[cfg(feature = "dtype-union")]
+            (D::Union(l_fields, _), D::Union(r_fields, _)) => {
+                if l_fields.len() != r_fields.len() {
+                    return Some(false);
+                }
+                for (l, r) in l_fields.iter().zip(r_fields) {
+                    if l.name() != r.name() || !l.dtype().can_cast_to(r.dtype())? {
+                        return Some(false);
+                    }
+                }
+                true
+            },
 
             // @NOTE: we are being conversative
             _ => return None,
@@ -504,6 +652,8 @@ impl DataType {
             Time => Int64,
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => Int128,
+            #[cfg(feature = "dtype-interval")]
+            Interval => Int128,
             #[cfg(feature = "dtype-categorical")]
             Categorical(cats, _) => cats.physical().dtype(),
             #[cfg(feature = "dtype-categorical")]
@@ -521,6 +671,15 @@ impl DataType {
             },
             #[cfg(feature = "dtype-extension")]
             Extension(_, storage) => storage.to_physical(),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => Self::map_storage_dtype(&key.to_physical(), &value.to_physical()),
+            #[cfg(feature = "dtype-union")]
+            Union(fields, _) => Self::union_storage_dtype(
+                &fields
+                    .iter()
+                    .map(|fld| Field::new(fld.name().clone(), fld.dtype().to_physical()))
+                    .collect::<Vec<_>>(),
+            ),
             _ => self.clone(),
         }
     }
@@ -596,6 +755,10 @@ impl DataType {
             DataType::Struct(_) => true,
             #[cfg(feature = "dtype-extension")]
             DataType::Extension(_, storage) => storage.is_nested(),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => true,
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(_, _) => true,
             _ => false,
         }
     }
@@ -612,6 +775,30 @@ impl DataType {
         }
     }
 
+    /// Check if this [`DataType`] is a map.
+    pub fn is_map(&self) -> bool {
+        #[cfg(feature = "dtype-map")]
+        {
+            matches!(self, DataType::Map(_, _))
+        }
+        #[cfg(not(feature = "dtype-map"))]
+        {
+            false
+        }
+    }
+
+    /// Check if this [`DataType`] is a union.
+    pub fn is_union(&self) -> bool {
+        #[cfg(feature = "dtype-union")]
+        {
+            matches!(self, DataType::Union(_, _))
+        }
+        #[cfg(not(feature = "dtype-union"))]
+        {
+            false
+        }
+    }
+
     pub fn is_binary(&self) -> bool {
         matches!(self, DataType::Binary)
     }
@@ -627,6 +814,17 @@ impl DataType {
         matches!(self, DataType::Duration(..))
     }
 
+    pub fn is_interval(&self) -> bool {
+        #[cfg(feature = "dtype-interval")]
+        {
+            matches!(self, DataType::Interval)
+        }
+        #[cfg(not(feature = "dtype-interval"))]
+        {
+            false
+        }
+    }
+
     pub fn is_object(&self) -> bool {
         #[cfg(feature = "object")]
         {
@@ -651,6 +849,10 @@ impl DataType {
             Array(inner, _) => inner.contains_views(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().any(|field| field.dtype.contains_views()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_views() || value.contains_views(),
+            #[cfg(feature = "dtype-union")]
+            Union(fields, _) => fields.iter().any(|field| field.dtype.contains_views()),
             _ => false,
         }
     }
@@ -667,6 +869,12 @@ impl DataType {
             Struct(fields) => fields
                 .iter()
                 .any(|field| field.dtype.contains_categoricals()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_categoricals() || value.contains_categoricals(),
+            #[cfg(feature = "dtype-union")]
+            Union(fields, _) => fields
+                .iter()
+                .any(|field| field.dtype.contains_categoricals()),
             _ => false,
         }
     }
@@ -681,6 +889,10 @@ impl DataType {
             Array(inner, _) => inner.contains_enums(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().any(|field| field.dtype.contains_enums()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_enums() || value.contains_enums(),
+            #[cfg(feature = "dtype-union")]
+            Union(fields, _) => fields.iter().any(|field| field.dtype.contains_enums()),
             _ => false,
         }
     }
@@ -695,6 +907,10 @@ impl DataType {
             Array(inner, _) => inner.contains_objects(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().any(|field| field.dtype.contains_objects()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_objects() || value.contains_objects(),
+            #[cfg(feature = "dtype-union")]
+            Union(fields, _) => fields.iter().any(|field| field.dtype.contains_objects()),
             _ => false,
         }
     }
@@ -703,12 +919,18 @@ impl DataType {
         use DataType as D;
         match self {
             D::List(_) => true,
+            #[cfg(feature = "dtype-map")]
+            D::Map(_, _) => true,
             #[cfg(feature = "dtype-array")]
             D::Array(inner, _) => inner.contains_list_recursive(),
             #[cfg(feature = "dtype-struct")]
             D::Struct(fields) => fields
                 .iter()
                 .any(|field| field.dtype.contains_list_recursive()),
+            #[cfg(feature = "dtype-union")]
+            D::Union(fields, _) => fields
+                .iter()
+                .any(|field| field.dtype.contains_list_recursive()),
             _ => false,
         }
     }
@@ -722,6 +944,10 @@ impl DataType {
             D::Array(inner, _) => inner.contains_unknown(),
             #[cfg(feature = "dtype-struct")]
             D::Struct(fields) => fields.iter().any(|field| field.dtype.contains_unknown()),
+            #[cfg(feature = "dtype-map")]
+            D::Map(key, value) => key.contains_unknown() || value.contains_unknown(),
+            #[cfg(feature = "dtype-union")]
+            D::Union(fields, _) => fields.iter().any(|field| field.dtype.contains_unknown()),
             _ => false,
         }
     }
@@ -832,6 +1058,18 @@ impl DataType {
         }
     }
 
+    /// Check if this is the canonical `arrow.json` extension type.
+    pub fn is_json(&self) -> bool {
+        #[cfg(feature = "dtype-extension")]
+        {
+            matches!(self, DataType::Extension(typ, _) if typ.name() == extension::JSON_EXTENSION_NAME)
+        }
+        #[cfg(not(feature = "dtype-extension"))]
+        {
+            false
+        }
+    }
+
     /// Convert to an Arrow Field.
     pub fn to_arrow_field(&self, name: PlSmallStr, compat_level: CompatLevel) -> ArrowField {
         let metadata = match self {
@@ -874,6 +1112,12 @@ impl DataType {
                 PlSmallStr::from_static(PL_KEY),
                 PlSmallStr::from_static(MAINTAIN_PL_TYPE),
             )])),
+            // Other writers' maps are read as a list of entries, only ours are read back as a map.
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => Some(BTreeMap::from([(
+                PlSmallStr::from_static(PL_KEY),
+                PlSmallStr::from_static(MAINTAIN_PL_TYPE),
+            )])),
             _ => None,
         };
 
@@ -985,6 +1229,10 @@ impl DataType {
                 tz.as_deref().cloned(),
             )),
             Duration(unit) => Ok(ArrowDataType::Duration(unit.to_arrow())),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Ok(ArrowDataType::Interval(
+                arrow::datatypes::IntervalUnit::MonthDayNano,
+            )),
             Time => Ok(ArrowDataType::Time64(ArrowTimeUnit::Nanosecond)),
             #[cfg(feature = "dtype-array")]
             Array(dt, width) => Ok(ArrowDataType::FixedSizeList(
@@ -1030,10 +1278,33 @@ impl DataType {
             Extension(typ, inner) => Ok(ArrowDataType::Extension(Box::new(
                 arrow::datatypes::ExtensionType {
                     name: typ.name().into(),
-                    inner: inner.try_to_arrow(compat_level)?,
+                    inner: match typ.0.arrow_storage_dtype() {
+                        Some(arrow_storage) => arrow_storage,
+                        None => inner.try_to_arrow(compat_level)?,
+                    },
                     metadata: typ.serialize_metadata().map(|m| m.into()),
                 },
             ))),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => {
+                let entries = ArrowDataType::Struct(vec![
+                    ArrowField::new(MAP_KEY_NAME, key.try_to_arrow(compat_level)?, false),
+                    ArrowField::new(MAP_VALUE_NAME, value.try_to_arrow(compat_level)?, true),
+                ]);
+                Ok(ArrowDataType::Map(
+                    Box::new(ArrowField::new(MAP_ENTRIES_NAME, entries, false)),
+                    false,
+                ))
+            },
+            #[cfg(feature = "dtype-union")]
+            Union(fields, layout) => Ok(ArrowDataType::Union(Box::new(UnionType {
+                fields: fields
+                    .iter()
+                    .map(|fld| fld.to_arrow(compat_level))
+                    .collect(),
+                ids: layout.type_ids.clone(),
+                mode: UnionMode::sparse(!layout.dense),
+            }))),
             Unknown(kind) => {
                 let dt = match kind {
                     UnknownKind::Any => ArrowDataType::Unknown,
@@ -1085,6 +1356,21 @@ impl DataType {
                 }
                 Ok(must_cast)
             },
+            #[cfg(feature = "dtype-map")]
+            (DataType::Map(lk, lv), DataType::Map(rk, rv)) => {
+                Ok(lk.matches_schema_type(rk)? | lv.matches_schema_type(rv)?)
+            },
+            #[cfg(feature = "dtype-union")]
+            (DataType::Union(l, _), DataType::Union(r, _)) => {
+                if l.len() != r.len() {
+                    apollo_bail!(SchemaMismatch: "unions have different number of members: {} vs {}", l.len(), r.len());
+                }
+                let mut must_cast = false;
+                for (l, r) in l.iter().zip(r.iter()) {
+                    must_cast |= l.dtype.matches_schema_type(&r.dtype)?;
+                }
+                Ok(must_cast)
+            },
             (DataType::Null, DataType::Null) => Ok(false),
             #[cfg(feature = "dtype-decimal")]
             (DataType::Decimal(p1, s1), DataType::Decimal(p2, s2)) => Ok((p1, s1) != (p2, s2)),
@@ -1191,6 +1477,8 @@ impl Display for DataType {
             DataType::Datetime(tu, None) => return write!(f, "datetime[{tu}]"),
             DataType::Datetime(tu, Some(tz)) => return write!(f, "datetime[{tu}, {tz}]"),
             DataType::Duration(tu) => return write!(f, "duration[{tu}]"),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => "interval",
             DataType::Time => "time",
             #[cfg(feature = "dtype-array")]
             DataType::Array(_, _) => {
@@ -1215,6 +1503,10 @@ impl Display for DataType {
             DataType::Struct(fields) => return write!(f, "struct[{}]", fields.len()),
             #[cfg(feature = "dtype-extension")]
             DataType::Extension(typ, _) => return write!(f, "ext[{}]", typ.0.dyn_display()),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => return write!(f, "map[{key}, {value}]"),
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(fields, _) => return write!(f, "union[{}]", fields.len()),
             DataType::Unknown(kind) => match kind {
                 UnknownKind::Any => "unknown",
                 UnknownKind::Int(_) => "dyn int",
@@ -1250,6 +1542,8 @@ impl std::fmt::Debug for DataType {
             Date => write!(f, "Date"),
             Time => write!(f, "Time"),
             Duration(unit) => write!(f, "Duration('{unit}')"),
+            #[cfg(feature = "dtype-interval")]
+            Interval => write!(f, "Interval"),
             Datetime(unit, opt_tz) => {
                 if let Some(tz) = opt_tz {
                     write!(f, "Datetime('{unit}', '{tz}')")
@@ -1299,6 +1593,19 @@ impl std::fmt::Debug for DataType {
             Null => write!(f, "Null"),
             #[cfg(feature = "dtype-extension")]
             Extension(typ, inner) => write!(f, "Extension({}, {inner:?})", typ.0.dyn_debug()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => write!(f, "Map({key:?}, {value:?})"),
+            #[cfg(feature = "dtype-union")]
+            Union(fields, _) => {
+                write!(f, "Union({{")?;
+                for (i, field) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "'{}': {:?}", field.name(), field.dtype())?;
+                }
+                write!(f, "}})")
+            },
             Unknown(kind) => write!(f, "Unknown({kind:?})"),
         }
     }
@@ -1337,6 +1644,11 @@ pub fn merge_dtypes(left: &DataType, right: &DataType) -> ApolloResult<DataType>
             let merged = merge_dtypes(inner_l, inner_r)?;
             Array(Box::new(merged), *width_l)
         },
+        #[cfg(feature = "dtype-map")]
+        (Map(key_l, value_l), Map(key_r, value_r)) => Map(
+            Box::new(merge_dtypes(key_l, key_r)?),
+            Box::new(merge_dtypes(value_l, value_r)?),
+        ),
         (left, right) if left == right => left.clone(),
         _ => apollo_bail!(ComputeError: "unable to merge datatypes"),
     })
@@ -1370,6 +1682,23 @@ fn collect_nested_types(
                 collect_nested_types(field.dtype(), result, include_compound_types);
             }
         },
+        #[cfg(feature = "dtype-map")]
+        DataType::Map(key, value) => {
+            if include_compound_types {
+                result.insert(dtype.clone());
+            }
+            collect_nested_types(key, result, include_compound_types);
+            collect_nested_types(value, result, include_compound_types);
+        },
+        #[cfg(feature = "dtype-union")]
+        DataType::Union(fields, _) => {
+            if include_compound_types {
+                result.insert(dtype.clone());
+            }
+            for field in fields {
+                collect_nested_types(field.dtype(), result, include_compound_types);
+            }
+        },
         _ => {
             result.insert(dtype.clone());
         },
@@ -0,0 +1,95 @@
+use std::any::Any;
+use std::borrow::Cow;
+
+use apollo_error::{ApolloResult, apollo_bail};
+
+use super::{ExtensionTypeFactory, ExtensionTypeImpl, ExtensionTypeInstance};
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+
+pub const JSON_EXTENSION_NAME: &str = "arrow.json";
+
+/// The canonical `arrow.json` extension type.
+///
+/// Every non-null value is a valid JSON document stored as a UTF-8 string.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct JsonExtensionType;
+
+impl JsonExtensionType {
+    /// The `arrow.json` extension [`DataType`].
+    pub fn dtype() -> DataType {
+        DataType::Extension(
+            ExtensionTypeInstance(Box::new(Self)),
+            Box::new(DataType::String),
+        )
+    }
+}
+
+pub(super) struct JsonExtensionFactory;
+
+impl ExtensionTypeFactory for JsonExtensionFactory {
+    fn create_type_instance(
+        &self,
+        _name: &str,
+        _storage: &DataType,
+        _metadata: Option<&str>,
+    ) -> Box<dyn ExtensionTypeImpl> {
+        Box::new(JsonExtensionType)
+    }
+}
+
+fn is_valid_json(s: &str) -> bool {
+    serde_json::from_str::<serde_json::Value>(s).is_ok()
+}
+
+impl ExtensionTypeImpl for JsonExtensionType {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(JSON_EXTENSION_NAME)
+    }
+
+    fn serialize_metadata(&self) -> Option<Cow<'_, str>> {
+        None
+    }
+
+    fn dyn_clone(&self) -> Box<dyn ExtensionTypeImpl> {
+        Box::new(Self)
+    }
+
+    fn dyn_eq(&self, other: &dyn ExtensionTypeImpl) -> bool {
+        (other as &dyn Any).is::<Self>()
+    }
+
+    fn dyn_hash(&self) -> u64 {
+        // All instances are equal.
+        0
+    }
+
+    fn dyn_display(&self) -> Cow<'_, str> {
+        Cow::Borrowed("json")
+    }
+
+    fn dyn_debug(&self) -> Cow<'_, str> {
+        Cow::Borrowed("JSON")
+    }
+
+    fn cast_from(&self, s: &Series, options: CastOptions) -> ApolloResult<Option<Series>> {
+        // A strict cast is checked by `validate`, a non-strict one nulls out invalid documents.
+        if options.is_strict() || !s.dtype().is_string() {
+            return Ok(None);
+        }
+        let out = s
+            .str()?
+            .apply(|opt_v| opt_v.filter(|v| is_valid_json(v)).map(Cow::Borrowed));
+        Ok(Some(out.into_series()))
+    }
+
+    fn validate(&self, storage: &Series) -> ApolloResult<()> {
+        let Ok(ca) = storage.str() else {
+            apollo_bail!(SchemaMismatch: "'{JSON_EXTENSION_NAME}' requires String storage, got {}", storage.dtype())
+        };
+        if let Some(v) = ca.iter().flatten().find(|v| !is_valid_json(v)) {
+            apollo_bail!(ComputeError: "invalid JSON for '{JSON_EXTENSION_NAME}': '{v}'")
+        }
+        Ok(())
+    }
+}
@@ -3,17 +3,27 @@ use std::borrow::Cow;
 use std::fmt::{Debug, Display};
 use std::hash::{Hash, Hasher};
 
-use crate::datatypes::DataType;
+use apollo_error::ApolloResult;
+use arrow::datatypes::ArrowDataType;
+
+use crate::chunked_array::cast::CastOptions;
+use crate::datatypes::{AnyValue, DataType};
+use crate::series::Series;
 
 mod generic;
+mod json;
 mod registry;
+mod uuid;
 
 use generic::GenericExtensionType;
+pub use json::{JSON_EXTENSION_NAME, JsonExtensionType};
 pub use registry::{
     UnknownExtensionTypeBehavior, get_extension_type_or_generic, get_extension_type_or_storage,
     register_extension_type, set_unknown_extension_type_behavior, unregister_extension_type,
 };
 
+pub use self::uuid::{UUID_EXTENSION_NAME, UuidExtensionType, UuidVersion, generate_uuids};
+
 pub trait ExtensionTypeFactory: 'static + Send + Sync {
     fn create_type_instance(
         &self,
@@ -44,6 +54,63 @@ pub trait ExtensionTypeImpl: 'static + Send + Sync + Any {
     /// Should be a more verbose string representation, useful for debugging, in TitleCase,
     /// for example: String, Decimal(10, 2).
     fn dyn_debug(&self) -> Cow<'_, str>;
+
+    // The hooks below are optional and let values of the extension type behave
+    // differently from their storage. By default values behave like their storage.
+
+    /// Format a single non-null value for display.
+    ///
+    /// `value` is the value in its storage representation. Returning `None` formats
+    /// it like the storage type would.
+    fn fmt_value(&self, value: &AnyValue<'_>) -> Option<String> {
+        let _ = value;
+        None
+    }
+
+    /// Cast the `storage` of a Series of this extension type to `dtype`.
+    ///
+    /// Returning `None` casts the storage to `dtype` instead.
+    fn cast_to(
+        &self,
+        storage: &Series,
+        dtype: &DataType,
+        options: CastOptions,
+    ) -> ApolloResult<Option<Series>> {
+        let _ = (storage, dtype, options);
+        Ok(None)
+    }
+
+    /// Cast a Series of another dtype to the storage of this extension type.
+    ///
+    /// Returning `None` casts `s` to the storage dtype instead.
+    fn cast_from(&self, s: &Series, options: CastOptions) -> ApolloResult<Option<Series>> {
+        let _ = (s, options);
+        Ok(None)
+    }
+
+    /// Compute the key that defines equality, hashing and ordering of the values.
+    ///
+    /// The key must have the same length as `storage` and is used when comparing,
+    /// sorting, grouping and joining. Returning `None` uses the storage itself.
+    fn comparison_key(&self, storage: &Series) -> ApolloResult<Option<Series>> {
+        let _ = storage;
+        Ok(None)
+    }
+
+    /// Check that `storage` holds valid values for this extension type.
+    ///
+    /// Called when a Series of this type is constructed from its storage, for
+    /// example through `ext.to`, a cast or when reading a file.
+    fn validate(&self, storage: &Series) -> ApolloResult<()> {
+        let _ = storage;
+        Ok(())
+    }
+
+    /// The Arrow type the storage is exported as, if it differs from the Arrow type of the
+    /// storage dtype. For example `FixedSizeBinary(16)` for `Binary` storage of 16-byte values.
+    fn arrow_storage_dtype(&self) -> Option<ArrowDataType> {
+        None
+    }
 }
 
 #[repr(transparent)]
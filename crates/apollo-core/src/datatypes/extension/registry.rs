@@ -6,6 +6,8 @@ use apollo_error::{ApolloResult, apollo_bail, apollo_err, apollo_warn};
 use apollo_utils::aliases::{InitHashMaps, PlHashMap};
 use apollo_utils::pl_str::PlSmallStr;
 
+use super::json::{JSON_EXTENSION_NAME, JsonExtensionFactory};
+use super::uuid::{UUID_EXTENSION_NAME, UuidExtensionFactory};
 use super::{ExtensionTypeFactory, ExtensionTypeInstance};
 use crate::prelude::{DataType, APOLLO_OBJECT_EXTENSION_NAME};
 
@@ -89,6 +91,14 @@ static REGISTRY: LazyLock<RwLock<PlHashMap<PlSmallStr, Option<Arc<dyn ExtensionT
     LazyLock::new(|| {
         let mut m = PlHashMap::new();
         m.insert(PlSmallStr::from_static(APOLLO_OBJECT_EXTENSION_NAME), None);
+        m.insert(
+            PlSmallStr::from_static(UUID_EXTENSION_NAME),
+            Some(Arc::new(UuidExtensionFactory) as _),
+        );
+        m.insert(
+            PlSmallStr::from_static(JSON_EXTENSION_NAME),
+            Some(Arc::new(JsonExtensionFactory) as _),
+        );
         RwLock::new(m)
     });
 
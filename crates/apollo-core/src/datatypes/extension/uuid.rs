@@ -0,0 +1,173 @@
+use std::any::Any;
+use std::borrow::Cow;
+
+use apollo_error::{ApolloResult, apollo_bail, apollo_err};
+use apollo_utils::pl_str::PlSmallStr;
+use arrow::datatypes::ArrowDataType;
+#[cfg(feature = "serde-lazy")]
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{ExtensionTypeFactory, ExtensionTypeImpl, ExtensionTypeInstance};
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+
+pub const UUID_EXTENSION_NAME: &str = "arrow.uuid";
+
+const UUID_BYTE_WIDTH: usize = 16;
+
+/// The UUID version to generate.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum UuidVersion {
+    /// Random.
+    #[default]
+    V4,
+    /// Unix timestamp in milliseconds followed by random bits, sortable by creation time.
+    V7,
+}
+
+/// The canonical `arrow.uuid` extension type.
+///
+/// Values are stored as 16 bytes in RFC 9562 (big-endian) order, so ordering the storage
+/// orders the UUIDs like their textual form. The storage is exported as `FixedSizeBinary(16)`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UuidExtensionType;
+
+impl UuidExtensionType {
+    /// The `arrow.uuid` extension [`DataType`].
+    pub fn dtype() -> DataType {
+        DataType::Extension(
+            ExtensionTypeInstance(Box::new(Self)),
+            Box::new(DataType::Binary),
+        )
+    }
+}
+
+pub(super) struct UuidExtensionFactory;
+
+impl ExtensionTypeFactory for UuidExtensionFactory {
+    fn create_type_instance(
+        &self,
+        _name: &str,
+        _storage: &DataType,
+        _metadata: Option<&str>,
+    ) -> Box<dyn ExtensionTypeImpl> {
+        Box::new(UuidExtensionType)
+    }
+}
+
+impl ExtensionTypeImpl for UuidExtensionType {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(UUID_EXTENSION_NAME)
+    }
+
+    fn serialize_metadata(&self) -> Option<Cow<'_, str>> {
+        None
+    }
+
+    fn dyn_clone(&self) -> Box<dyn ExtensionTypeImpl> {
+        Box::new(Self)
+    }
+
+    fn dyn_eq(&self, other: &dyn ExtensionTypeImpl) -> bool {
+        (other as &dyn Any).is::<Self>()
+    }
+
+    fn dyn_hash(&self) -> u64 {
+        // All instances are equal.
+        0
+    }
+
+    fn dyn_display(&self) -> Cow<'_, str> {
+        Cow::Borrowed("uuid")
+    }
+
+    fn dyn_debug(&self) -> Cow<'_, str> {
+        Cow::Borrowed("UUID")
+    }
+
+    fn fmt_value(&self, value: &AnyValue<'_>) -> Option<String> {
+        let bytes = match value {
+            AnyValue::Binary(b) => *b,
+            AnyValue::BinaryOwned(b) => b.as_slice(),
+            _ => return None,
+        };
+        Uuid::from_slice(bytes)
+            .ok()
+            .map(|u| u.hyphenated().to_string())
+    }
+
+    fn cast_to(
+        &self,
+        storage: &Series,
+        dtype: &DataType,
+        options: CastOptions,
+    ) -> ApolloResult<Option<Series>> {
+        if !dtype.is_string() {
+            return Ok(None);
+        }
+        let out = storage
+            .binary()?
+            .iter()
+            .map(|opt_b| match opt_b.map(|b| (b, Uuid::from_slice(b))) {
+                Some((_, Ok(u))) => Ok(Some(u.hyphenated().to_string())),
+                Some((b, Err(_))) if options.is_strict() => Err(apollo_err!(
+                    InvalidOperation: "invalid UUID of {} bytes, expected {UUID_BYTE_WIDTH}",
+                    b.len()
+                )),
+                _ => Ok(None),
+            })
+            .collect::<ApolloResult<StringChunked>>()?;
+        Ok(Some(out.with_name(storage.name().clone()).into_series()))
+    }
+
+    fn cast_from(&self, s: &Series, options: CastOptions) -> ApolloResult<Option<Series>> {
+        if !s.dtype().is_string() {
+            return Ok(None);
+        }
+        let out = s
+            .str()?
+            .iter()
+            .map(|opt_v| match opt_v.map(|v| (v, Uuid::try_parse(v))) {
+                Some((_, Ok(u))) => Ok(Some(u.into_bytes())),
+                Some((v, Err(_))) if options.is_strict() => {
+                    Err(apollo_err!(InvalidOperation: "invalid UUID string: '{v}'"))
+                },
+                _ => Ok(None),
+            })
+            .collect::<ApolloResult<BinaryChunked>>()?;
+        Ok(Some(out.with_name(s.name().clone()).into_series()))
+    }
+
+    fn validate(&self, storage: &Series) -> ApolloResult<()> {
+        let Ok(ca) = storage.binary() else {
+            apollo_bail!(SchemaMismatch: "'{UUID_EXTENSION_NAME}' requires Binary storage, got {}", storage.dtype())
+        };
+        if let Some(b) = ca.iter().flatten().find(|b| b.len() != UUID_BYTE_WIDTH) {
+            apollo_bail!(
+                ComputeError: "'{UUID_EXTENSION_NAME}' values must be {UUID_BYTE_WIDTH} bytes, got a value of {} bytes",
+                b.len()
+            )
+        }
+        Ok(())
+    }
+
+    fn arrow_storage_dtype(&self) -> Option<ArrowDataType> {
+        Some(ArrowDataType::FixedSizeBinary(UUID_BYTE_WIDTH))
+    }
+}
+
+/// Generate `n` new UUIDs of the given `version`.
+pub fn generate_uuids(name: PlSmallStr, n: usize, version: UuidVersion) -> Series {
+    // `now_v7` keeps the UUIDs generated within a process monotonic.
+    let new_uuid = match version {
+        UuidVersion::V4 => Uuid::new_v4,
+        UuidVersion::V7 => Uuid::now_v7,
+    };
+    let storage: BinaryChunked = (0..n).map(|_| Some(new_uuid().into_bytes())).collect();
+    let storage = storage.with_name(name).into_series();
+    ExtensionChunked::from_storage(ExtensionTypeInstance(Box::new(UuidExtensionType)), storage)
+        .into_series()
+}
@@ -257,6 +257,14 @@ impl DataType {
             ArrowDataType::Struct(_) => {
                 panic!("activate the 'dtype-struct' feature to handle struct data types")
             },
+            #[cfg(feature = "dtype-union")]
+            ArrowDataType::Union(union) => DataType::Union(
+                union.fields.iter().map(|fld| fld.into()).collect(),
+                UnionLayout {
+                    dense: union.mode.is_dense(),
+                    type_ids: union.ids.clone(),
+                },
+            ),
             ArrowDataType::Extension(ext) if ext.name.as_str() == APOLLO_OBJECT_EXTENSION_NAME => {
                 #[cfg(feature = "object")]
                 {
@@ -292,9 +300,22 @@ impl DataType {
             },
             ArrowDataType::LargeBinary | ArrowDataType::Binary => DataType::Binary,
             ArrowDataType::FixedSizeBinary(_) => DataType::Binary,
+            #[cfg(feature = "dtype-map")]
+            ArrowDataType::Map(inner, _is_sorted) if md.is_some_and(|md| md.maintain_type()) => {
+                match Self::from_arrow_field(inner) {
+                    DataType::Struct(fields) if fields.len() == 2 => DataType::Map(
+                        Box::new(fields[0].dtype().clone()),
+                        Box::new(fields[1].dtype().clone()),
+                    ),
+                    entries => DataType::List(Box::new(entries)),
+                }
+            },
             ArrowDataType::Map(inner, _is_sorted) => {
                 DataType::List(Self::from_arrow_field(inner).boxed())
             },
+            #[cfg(feature = "dtype-interval")]
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano) => DataType::Interval,
+            #[cfg(not(feature = "dtype-interval"))]
             ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
                 check_allow_importing_interval_as_struct("month_day_nano_interval").unwrap();
                 feature_gated!("dtype-struct", DataType::_month_days_ns_struct_type())
@@ -37,6 +37,8 @@ use arrow::datatypes::IntegerType;
 pub use arrow::datatypes::reshape::*;
 pub use arrow::datatypes::{ArrowDataType, TimeUnit as ArrowTimeUnit};
 use arrow::types::NativeType;
+#[cfg(feature = "dtype-interval")]
+pub use arrow::types::months_days_ns;
 use bytemuck::Zeroable;
 pub use dtype::*;
 pub use field::*;
@@ -235,6 +237,8 @@ impl_apollo_datatype!(BooleanType, DataType::Boolean, BooleanArray, 'a, bool, bo
 impl_apollo_datatype!(DecimalType, unimplemented!(), PrimitiveArray<i128>, 'a, i128, i128, i128, FalseT);
 impl_apollo_datatype!(DatetimeType, unimplemented!(), PrimitiveArray<i64>, 'a, i64, i64, i64, FalseT);
 impl_apollo_datatype!(DurationType, unimplemented!(), PrimitiveArray<i64>, 'a, i64, i64, i64, FalseT);
+#[cfg(feature = "dtype-interval")]
+impl_apollo_datatype!(IntervalType, DataType::Interval, PrimitiveArray<i128>, 'a, i128, i128, i128, FalseT);
 impl_apollo_datatype!(CategoricalType, unimplemented!(), PrimitiveArray<u32>, 'a, u32, u32, u32, FalseT);
 impl_apollo_datatype!(DateType, DataType::Date, PrimitiveArray<i32>, 'a, i32, i32, i32, FalseT);
 impl_apollo_datatype!(TimeType, DataType::Time, PrimitiveArray<i64>, 'a, i64, i64, i64, FalseT);
@@ -218,6 +218,33 @@ macro_rules! format_array {
     }};
 }
 
+/// Values of an extension Series, formatted by its extension type where it
+/// defines a formatting for them.
+#[cfg(feature = "dtype-extension")]
+struct ExtensionValues<'a>(&'a ExtensionChunked);
+
+#[cfg(feature = "dtype-extension")]
+impl ExtensionValues<'_> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+
+    fn get_any_value(&self, i: usize) -> ApolloResult<AnyValue<'_>> {
+        let v = self.0.get_any_value(i)?;
+        if v.is_null() {
+            return Ok(v);
+        }
+        Ok(match self.0.extension_type().0.fmt_value(&v) {
+            Some(out) => AnyValue::StringOwned(out.into()),
+            None => v,
+        })
+    }
+}
+
 #[cfg(feature = "object")]
 fn format_object_array(
     f: &mut Formatter<'_>,
@@ -398,6 +425,16 @@ impl Debug for Series {
                 let dt = format!("{}", self.dtype());
                 format_array!(f, self.duration().unwrap(), &dt, self.name(), "Series")
             },
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => {
+                format_array!(
+                    f,
+                    self.interval().unwrap(),
+                    "interval",
+                    self.name(),
+                    "Series"
+                )
+            },
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(_, _) => {
                 let dt = format!("{}", self.dtype());
@@ -453,7 +490,18 @@ impl Debug for Series {
             #[cfg(feature = "dtype-extension")]
             DataType::Extension(_, _) => {
                 let dt = format!("{}", self.dtype());
-                format_array!(f, self.ext().unwrap(), &dt, self.name(), "Series")
+                let values = ExtensionValues(self.ext().unwrap());
+                format_array!(f, values, &dt, self.name(), "Series")
+            },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => {
+                let dt = format!("{}", self.dtype());
+                format_array!(f, self.map_().unwrap(), &dt, self.name(), "Series")
+            },
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(_, _) => {
+                let dt = format!("{}", self.dtype());
+                format_array!(f, self.union().unwrap(), &dt, self.name(), "Series")
             },
             dt => panic!("{dt:?} not impl"),
         }
@@ -1070,6 +1118,30 @@ pub fn fmt_duration_string<W: Write>(f: &mut W, v: i64, unit: TimeUnit) -> fmt::
     Ok(())
 }
 
+#[cfg(feature = "dtype-interval")]
+pub fn fmt_interval_string<W: Write>(f: &mut W, v: months_days_ns) -> fmt::Result {
+    // write the calendar parts first and the exact remainder like a
+    // duration, eg: "1mo 2d 3h 15m"
+    let mut buffer = itoa::Buffer::new();
+    let mut sep = "";
+    if v.months() != 0 {
+        f.write_str(buffer.format(v.months()))?;
+        f.write_str("mo")?;
+        sep = " ";
+    }
+    if v.days() != 0 {
+        f.write_str(sep)?;
+        f.write_str(buffer.format(v.days()))?;
+        f.write_char('d')?;
+        sep = " ";
+    }
+    if v.ns() != 0 || sep.is_empty() {
+        f.write_str(sep)?;
+        fmt_duration_string(f, v.ns(), TimeUnit::Nanoseconds)?;
+    }
+    Ok(())
+}
+
 #[cfg(feature = "dtype-duration")]
 pub fn iso_duration_string(s: &mut String, mut v: i64, unit: TimeUnit) {
     if v == 0 {
@@ -1196,6 +1268,8 @@ impl Display for AnyValue<'_> {
             },
             #[cfg(feature = "dtype-duration")]
             AnyValue::Duration(v, tu) => fmt_duration_string(f, *v, *tu),
+            #[cfg(feature = "dtype-interval")]
+            AnyValue::Interval(v) => fmt_interval_string(f, *v),
             #[cfg(feature = "dtype-time")]
             AnyValue::Time(_) => {
                 let nt: chrono::NaiveTime = self.into();
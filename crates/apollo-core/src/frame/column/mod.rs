@@ -427,10 +427,22 @@ impl Column {
     pub fn struct_(&self) -> ApolloResult<&StructChunked> {
         self.as_materialized_series().struct_()
     }
+    #[cfg(feature = "dtype-map")]
+    pub fn map_(&self) -> ApolloResult<&MapChunked> {
+        self.as_materialized_series().map_()
+    }
+    #[cfg(feature = "dtype-union")]
+    pub fn union(&self) -> ApolloResult<&UnionChunked> {
+        self.as_materialized_series().union()
+    }
     #[cfg(feature = "dtype-decimal")]
     pub fn decimal(&self) -> ApolloResult<&DecimalChunked> {
         self.as_materialized_series().decimal()
     }
+    #[cfg(feature = "dtype-interval")]
+    pub fn interval(&self) -> ApolloResult<&IntervalChunked> {
+        self.as_materialized_series().interval()
+    }
     #[cfg(feature = "dtype-array")]
     pub fn array(&self) -> ApolloResult<&ArrayChunked> {
         self.as_materialized_series().array()
@@ -556,6 +568,18 @@ impl Column {
             .into_owned()
             .into()
     }
+    /// If the Column is an Extension type, return the Column defining equality,
+    /// hashing and ordering of its values. Otherwise, return itself.
+    pub fn to_comparison_key(&self) -> ApolloResult<Column> {
+        if !self.dtype().is_extension() {
+            return Ok(self.clone());
+        }
+        Ok(self
+            .as_materialized_series()
+            .to_comparison_key()?
+            .clone()
+            .into())
+    }
     /// # Safety
     ///
     /// This can lead to invalid memory access in downstream code.
@@ -1498,6 +1522,10 @@ impl Column {
     }
 
     pub(crate) fn str_value(&self, index: usize) -> ApolloResult<Cow<'_, str>> {
+        #[cfg(feature = "dtype-extension")]
+        if self.dtype().is_extension() {
+            return self.as_materialized_series().str_value(index);
+        }
         Ok(self.get(index)?.str_value())
     }
 
@@ -17,6 +17,12 @@ fn get_exploded(
         DataType::List(_) => series.list().unwrap().explode_and_offsets(options),
         #[cfg(feature = "dtype-array")]
         DataType::Array(_, _) => series.array().unwrap().explode_and_offsets(options),
+        #[cfg(feature = "dtype-map")]
+        DataType::Map(_, _) => series
+            .map_()
+            .unwrap()
+            .entries()
+            .explode_and_offsets(options),
         _ => apollo_bail!(opq = explode, series.dtype()),
     }
 }
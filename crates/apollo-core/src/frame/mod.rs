@@ -1507,7 +1507,7 @@ impl DataFrame {
                     }
                     return Ok(out.into_frame());
                 }
-                s.arg_sort(options)
+                s.to_comparison_key()?.arg_sort(options)
             },
             _ => arg_sort(&by_column, sort_options)?,
         };
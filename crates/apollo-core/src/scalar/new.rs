@@ -30,6 +30,11 @@ impl Scalar {
         )
     }
 
+    #[cfg(feature = "dtype-interval")]
+    pub fn new_interval(value: crate::prelude::months_days_ns) -> Self {
+        Scalar::new(DataType::Interval, AnyValue::Interval(value))
+    }
+
     #[cfg(feature = "dtype-time")]
     pub fn new_time(value: i64) -> Self {
         Scalar::new(DataType::Time, AnyValue::Time(value))
@@ -134,6 +134,10 @@ pub enum SerializableScalar {
 
     #[cfg(feature = "dtype-struct")]
     Struct(Vec<(PlSmallStr, SerializableScalar)>),
+
+    /// A calendar interval of months, days and nanoseconds.
+    #[cfg(feature = "dtype-interval")]
+    Interval(i32, i32, i64),
 }
 
 impl TryFrom<Scalar> for SerializableScalar {
@@ -262,6 +266,9 @@ impl TryFrom<Scalar> for SerializableScalar {
 
             #[cfg(feature = "dtype-decimal")]
             AnyValue::Decimal(v, prec, scale) => Self::Decimal(v, prec, scale),
+
+            #[cfg(feature = "dtype-interval")]
+            AnyValue::Interval(v) => Self::Interval(v.months(), v.days(), v.ns()),
         };
         Ok(out)
     }
@@ -303,6 +310,10 @@ impl TryFrom<SerializableScalar> for Scalar {
             S::Array(v, width) => Self::new_array(v, width),
             #[cfg(feature = "dtype-decimal")]
             S::Decimal(v, prec, scale) => Self::new_decimal(v, prec, scale),
+            #[cfg(feature = "dtype-interval")]
+            S::Interval(months, days, ns) => {
+                Self::new_interval(crate::prelude::months_days_ns::new(months, days, ns))
+            },
 
             #[cfg(feature = "dtype-categorical")]
             S::Categorical {
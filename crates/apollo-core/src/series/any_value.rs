@@ -1,8 +1,12 @@
 use std::fmt::Write;
 
+#[cfg(feature = "dtype-union")]
+use arrow::bitmap::Bitmap;
 use arrow::bitmap::MutableBitmap;
 use num_traits::AsPrimitive;
 use apollo_compute::cast::SerPrimitive;
+#[cfg(feature = "dtype-union")]
+use apollo_utils::format_pl_smallstr;
 
 #[cfg(feature = "dtype-categorical")]
 use crate::chunked_array::builder::CategoricalChunkedBuilder;
@@ -138,6 +142,8 @@ impl Series {
             },
             #[cfg(feature = "dtype-duration")]
             DataType::Duration(tu) => any_values_to_duration(values, *tu, strict)?.into_series(),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => any_values_to_interval(values, strict)?.into_series(),
             #[cfg(feature = "dtype-categorical")]
             dt @ (DataType::Categorical(_, _) | DataType::Enum(_, _)) => {
                 any_values_to_categorical(values, dt, strict)?
@@ -151,6 +157,18 @@ impl Series {
                 Series::from_any_values_and_dtype(name.clone(), values, storage, strict)?
                     .into_extension(typ.clone())
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => Series::from_any_values_and_dtype(
+                name.clone(),
+                values,
+                &DataType::map_storage_dtype(key, value),
+                strict,
+            )?
+            .into_map(),
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(fields, _) => {
+                any_values_to_union(values, fields, strict)?.into_union(dtype.clone())
+            },
             DataType::List(inner) => any_values_to_list(values, inner, strict)?.into_series(),
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner, size) => any_values_to_array(values, inner, strict, *size)?
@@ -562,6 +580,28 @@ fn any_values_to_duration(
     Ok(builder.finish().into_duration(time_unit))
 }
 
+#[cfg(feature = "dtype-interval")]
+fn any_values_to_interval(values: &[AnyValue], strict: bool) -> ApolloResult<IntervalChunked> {
+    let mut builder = PrimitiveChunkedBuilder::<Int128Type>::new(PlSmallStr::EMPTY, values.len());
+    let target_dtype = DataType::Interval;
+    for av in values {
+        match av {
+            AnyValue::Interval(v) => builder.append_value(interval_to_i128(*v)),
+            AnyValue::Null => builder.append_null(),
+            av => {
+                if strict {
+                    return Err(invalid_value_error(&target_dtype, av));
+                }
+                match av.cast(&target_dtype) {
+                    AnyValue::Interval(v) => builder.append_value(interval_to_i128(v)),
+                    _ => builder.append_null(),
+                }
+            },
+        }
+    }
+    Ok(builder.finish().into_interval())
+}
+
 #[cfg(feature = "dtype-categorical")]
 fn any_values_to_categorical(
     values: &[AnyValue],
@@ -943,6 +983,69 @@ fn any_values_to_struct(
     Ok(out.into_series())
 }
 
+/// Builds the storage of a union, every value goes to the first member it can be cast to.
+#[cfg(feature = "dtype-union")]
+fn any_values_to_union(
+    values: &[AnyValue],
+    fields: &[Field],
+    strict: bool,
+) -> ApolloResult<Series> {
+    let mut tags = Vec::with_capacity(values.len());
+    for av in values {
+        let tag = match av {
+            AnyValue::Null => None,
+            av => {
+                let av_dtype = av.dtype();
+                let member = fields
+                    .iter()
+                    .position(|fld| fld.dtype() == &av_dtype)
+                    .or_else(|| {
+                        fields
+                            .iter()
+                            .position(|fld| av.strict_cast(fld.dtype()).is_some())
+                    });
+                match member {
+                    Some(i) => Some(i as i8),
+                    None if strict => {
+                        return Err(invalid_value_error(
+                            &DataType::Union(fields.to_vec(), Default::default()),
+                            av,
+                        ));
+                    },
+                    None => None,
+                }
+            },
+        };
+        tags.push(tag);
+    }
+
+    let mut series_fields = Vec::with_capacity(fields.len() + 1);
+    series_fields
+        .push(Int8Chunked::from_iter_options(UNION_TAG_NAME, tags.iter().copied()).into_series());
+    let mut field_avs = Vec::with_capacity(values.len());
+    for (i, field) in fields.iter().enumerate() {
+        field_avs.clear();
+        field_avs.extend(values.iter().zip(&tags).map(|(av, tag)| match tag {
+            Some(tag) if *tag as usize == i => av.clone(),
+            _ => AnyValue::Null,
+        }));
+        series_fields.push(Series::from_any_values_and_dtype(
+            format_pl_smallstr!("{i}"),
+            &field_avs,
+            field.dtype(),
+            strict,
+        )?);
+    }
+
+    let mut out =
+        StructChunked::from_series(PlSmallStr::EMPTY, values.len(), series_fields.iter())?;
+    if tags.iter().any(|tag| tag.is_none()) {
+        let validity: Bitmap = tags.iter().map(|tag| tag.is_some()).collect();
+        out.set_outer_validity(Some(validity))
+    }
+    Ok(out.into_series())
+}
+
 #[cfg(feature = "object")]
 fn any_values_to_object(values: &[AnyValue]) -> ApolloResult<Series> {
     use crate::chunked_array::object::registry;
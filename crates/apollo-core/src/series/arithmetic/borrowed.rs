@@ -562,6 +562,14 @@ impl Mul for &Series {
         match (self.dtype(), rhs.dtype()) {
             #[cfg(feature = "dtype-struct")]
             (Struct(_), Struct(_)) => _struct_arithmetic(self, rhs, |a, b| a.mul(b)),
+            #[cfg(feature = "dtype-interval")]
+            (Interval, _) => self.multiply(rhs),
+            #[cfg(feature = "dtype-interval")]
+            (_, Interval) => {
+                // swap order
+                let out = rhs.multiply(self)?;
+                Ok(out.with_name(self.name().clone()))
+            },
             // temporal lh
             (Duration(_), _) | (Date, _) | (Datetime(_, _), _) | (Time, _) => self.multiply(rhs),
             // temporal rhs
@@ -101,6 +101,17 @@ impl CategoricalToArrowConverter {
             },
             #[cfg(feature = "dtype-extension")]
             Extension(_, inner) => self.initialize(inner),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => {
+                self.initialize(key);
+                self.initialize(value);
+            },
+            #[cfg(feature = "dtype-union")]
+            Union(fields, _) => {
+                for field in fields {
+                    self.initialize(field.dtype())
+                }
+            },
             _ => assert!(!dtype.is_nested()),
         }
     }
@@ -55,7 +55,7 @@ macro_rules! impl_eq_compare {
             (le @ Extension(_, _), re @ Extension(_, _)) if le == re => {
                 let lhs = lhs.ext().unwrap();
                 let rhs = rhs.ext().unwrap();
-                return lhs.storage().$method(rhs.storage());
+                return lhs.comparison_key()?.$method(rhs.comparison_key()?);
             },
 
             #[cfg(feature = "dtype-extension")]
@@ -69,6 +69,20 @@ macro_rules! impl_eq_compare {
                 let rhs = rhs.ext().unwrap();
                 return lhs.$method(rhs.storage());
             },
+
+            #[cfg(feature = "dtype-map")]
+            (lm @ Map(_, _), rm @ Map(_, _)) if lm == rm => {
+                let lhs = lhs.map_().unwrap();
+                let rhs = rhs.map_().unwrap();
+                return lhs.storage().$method(rhs.storage());
+            },
+
+            #[cfg(feature = "dtype-union")]
+            (lu @ Union(_, _), ru @ Union(_, _)) if lu == ru => {
+                let lhs = lhs.union().unwrap();
+                let rhs = rhs.union().unwrap();
+                return lhs.storage().$method(rhs.storage());
+            },
             _ => (),
         };
 
@@ -173,7 +187,7 @@ macro_rules! impl_ineq_compare {
             (le @ Extension(_, _), re @ Extension(_, _)) if le == re => {
                 let lhs = lhs.ext().unwrap();
                 let rhs = rhs.ext().unwrap();
-                return lhs.storage().$method(rhs.storage());
+                return lhs.comparison_key()?.$method(rhs.comparison_key()?);
             },
 
             #[cfg(feature = "dtype-extension")]
@@ -187,6 +201,17 @@ macro_rules! impl_ineq_compare {
                 let rhs = rhs.ext().unwrap();
                 return lhs.$method(rhs.storage());
             },
+
+            #[cfg(feature = "dtype-map")]
+            (lm @ Map(_, _), rm @ Map(_, _)) if lm == rm => {
+                let lhs = lhs.map_().unwrap();
+                let rhs = rhs.map_().unwrap();
+                return lhs.storage().$method(rhs.storage());
+            },
+
+            // Intervals have no total order, eg: "1mo" vs "30d".
+            #[cfg(feature = "dtype-interval")]
+            (Interval, _) | (_, Interval) => bail_invalid_ineq!(lhs, rhs, $op),
             _ => (),
         };
 
@@ -1,3 +1,7 @@
+#[cfg(feature = "dtype-union")]
+use arrow::array::UnionArray;
+#[cfg(feature = "dtype-union")]
+use arrow::datatypes::UnionType;
 use arrow::datatypes::{IntervalUnit, Metadata};
 use arrow::offset::OffsetsBuffer;
 #[cfg(any(
@@ -12,7 +16,10 @@ use apollo_compute::cast::cast_unchecked as cast;
 #[cfg(feature = "dtype-decimal")]
 use apollo_compute::decimal::dec128_fits;
 use apollo_error::feature_gated;
+#[cfg(not(feature = "dtype-interval"))]
 use apollo_utils::check_allow_importing_interval_as_struct;
+#[cfg(feature = "dtype-union")]
+use apollo_utils::format_pl_smallstr;
 use apollo_utils::itertools::Itertools;
 
 use crate::chunked_array::cast::{CastOptions, cast_chunks};
@@ -96,6 +103,10 @@ impl Series {
             Decimal(precision, scale) => Int128Chunked::from_chunks(name, chunks)
                 .into_decimal_unchecked(*precision, *scale)
                 .into_series(),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Int128Chunked::from_chunks(name, chunks)
+                .into_interval()
+                .into_series(),
             #[cfg(feature = "dtype-array")]
             Array(_, _) => {
                 ArrayChunked::from_chunks_and_dtype_unchecked(name, chunks, dtype.clone())
@@ -124,6 +135,20 @@ impl Series {
                 Series::from_chunks_and_dtype_unchecked(name, chunks, storage),
             )
             .into_series(),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => Series::from_chunks_and_dtype_unchecked(
+                name,
+                chunks,
+                &DataType::map_storage_dtype(key, value),
+            )
+            .into_map(),
+            #[cfg(feature = "dtype-union")]
+            Union(fields, _) => Series::from_chunks_and_dtype_unchecked(
+                name,
+                chunks,
+                &DataType::union_storage_dtype(fields),
+            )
+            .into_union(dtype.clone()),
             #[cfg(feature = "dtype-struct")]
             Struct(_) => {
                 let mut ca =
@@ -491,7 +516,9 @@ impl Series {
                         storage.dtype(),
                         ext.metadata.as_deref(),
                     ) {
-                        Some(typ) => ExtensionChunked::from_storage(typ, storage).into_series(),
+                        Some(typ) => {
+                            ExtensionChunked::try_from_storage(typ, storage)?.into_series()
+                        },
                         None => storage,
                     },
                 )
@@ -513,10 +540,18 @@ impl Series {
                 Ok(BinaryChunked::from_chunks(name, chunks).into_series())
             },
             ArrowDataType::Map(field, _is_ordered) => {
+                // Only maps written by Apollo are read as a `Map`, others are read as a list of
+                // their entries.
+                #[cfg(feature = "dtype-map")]
+                let as_map = md.is_some_and(|md| md.maintain_type());
                 let struct_arrays = chunks
                     .iter()
                     .map(|arr| {
                         let arr = arr.as_any().downcast_ref::<MapArray>().unwrap();
+                        #[cfg(feature = "dtype-map")]
+                        if as_map {
+                            return map_entries_with_canonical_names(arr.field().as_ref());
+                        }
                         arr.field().clone()
                     })
                     .collect::<Vec<_>>();
@@ -531,7 +566,7 @@ impl Series {
                         let arr = arr.as_any().downcast_ref::<MapArray>().unwrap();
                         let offsets: &OffsetsBuffer<i32> = arr.offsets();
 
-                        let validity = values.validity().cloned();
+                        let validity = arr.validity().cloned();
 
                         Box::from(ListArray::<i64>::new(
                             ListArray::<i64>::default_datatype(values.dtype().clone()),
@@ -549,9 +584,39 @@ impl Series {
                         DataType::List(Box::new(dtype)),
                     );
 
+                    #[cfg(feature = "dtype-map")]
+                    if as_map {
+                        return Ok(out.into_series().into_map());
+                    }
                     Ok(out.into_series())
                 }
             },
+            #[cfg(feature = "dtype-union")]
+            ArrowDataType::Union(union) => {
+                let mut chunks = chunks.iter().map(|arr| {
+                    let arr = arr.as_any().downcast_ref::<UnionArray>().unwrap();
+                    union_array_to_series(name.clone(), arr, union)
+                });
+                let Some(out) = chunks.next() else {
+                    return Ok(Series::new_empty(name, &DataType::from_arrow(dtype, md)));
+                };
+                let mut out = out?;
+                for s in chunks {
+                    out.append_owned(s?)?;
+                }
+                Ok(out)
+            },
+            #[cfg(feature = "dtype-interval")]
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| convert_month_day_nano_to_i128(&**arr))
+                    .collect();
+                Ok(Int128Chunked::from_chunks(name, chunks)
+                    .into_interval()
+                    .into_series())
+            },
+            #[cfg(not(feature = "dtype-interval"))]
             ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
                 check_allow_importing_interval_as_struct("month_day_nano_interval")?;
 
@@ -745,6 +810,20 @@ unsafe fn to_physical_and_dtype(
             let dtype = s.dtype().clone();
             (std::mem::take(s.chunks_mut()), dtype)
         },
+        #[cfg(feature = "dtype-interval")]
+        dt @ ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+            let dt = dt.clone();
+            let mut s = Series::_try_from_arrow_unchecked(PlSmallStr::EMPTY, arrays, &dt).unwrap();
+            let dtype = s.dtype().clone();
+            (std::mem::take(s.chunks_mut()), dtype)
+        },
+        #[cfg(feature = "dtype-union")]
+        dt @ ArrowDataType::Union(_) => {
+            let dt = dt.clone();
+            let mut s = Series::_try_from_arrow_unchecked(PlSmallStr::EMPTY, arrays, &dt).unwrap();
+            let dtype = s.dtype().clone();
+            (std::mem::take(s.chunks_mut()), dtype)
+        },
         dt => {
             let dtype = DataType::from_arrow(dt, md);
             (arrays, dtype)
@@ -752,6 +831,71 @@ unsafe fn to_physical_and_dtype(
     }
 }
 
+/// Names the fields of the entries of a map `key` and `value`, arrow does not enforce any names.
+#[cfg(feature = "dtype-map")]
+fn map_entries_with_canonical_names(entries: &dyn Array) -> ArrayRef {
+    let entries = entries.as_any().downcast_ref::<StructArray>().unwrap();
+    let fields = entries
+        .fields()
+        .iter()
+        .zip([MAP_KEY_NAME, MAP_VALUE_NAME])
+        .map(|(field, name)| ArrowField {
+            name,
+            ..field.clone()
+        })
+        .collect();
+    StructArray::new(
+        ArrowDataType::Struct(fields),
+        entries.len(),
+        entries.values().to_vec(),
+        entries.validity().cloned(),
+    )
+    .boxed()
+}
+
+/// Converts an arrow union to a [`DataType::Union`], the members are gathered into a sparse
+/// struct in which each member is null in the rows it is not selected in.
+#[cfg(feature = "dtype-union")]
+unsafe fn union_array_to_series(
+    name: PlSmallStr,
+    arr: &UnionArray,
+    union: &UnionType,
+) -> ApolloResult<Series> {
+    let len = arr.len();
+    let (tags, slots): (Vec<i8>, Vec<IdxSize>) = (0..len)
+        .map(|i| {
+            let (tag, slot) = arr.index(i);
+            (tag as i8, slot as IdxSize)
+        })
+        .unzip();
+
+    let mut members = Vec::with_capacity(union.fields.len());
+    let mut series_fields = Vec::with_capacity(union.fields.len() + 1);
+    series_fields.push(Int8Chunked::from_slice(UNION_TAG_NAME, &tags).into_series());
+    for (i, (field, values)) in union.fields.iter().zip(arr.fields()).enumerate() {
+        let values = Series::_try_from_arrow_unchecked_with_md(
+            format_pl_smallstr!("{i}"),
+            vec![values.clone()],
+            field.dtype(),
+            field.metadata.as_deref(),
+        )?;
+        let idx: IdxCa = tags
+            .iter()
+            .zip(&slots)
+            .map(|(tag, slot)| (*tag as usize == i).then_some(*slot))
+            .collect_ca(PlSmallStr::EMPTY);
+        members.push(Field::new(field.name.clone(), values.dtype().clone()));
+        series_fields.push(values.take(&idx)?);
+    }
+
+    let storage = StructChunked::from_series(name, len, series_fields.iter())?.into_series();
+    let layout = UnionLayout {
+        dense: union.mode.is_dense(),
+        type_ids: union.ids.clone(),
+    };
+    Ok(storage.into_union(DataType::Union(members, layout)))
+}
+
 #[cfg(feature = "dtype-categorical")]
 unsafe fn import_arrow_dictionary_array(
     name: PlSmallStr,
@@ -841,7 +985,14 @@ unsafe fn import_arrow_dictionary_array(
     }
 }
 
-#[cfg(feature = "dtype-struct")]
+#[cfg(feature = "dtype-interval")]
+fn convert_month_day_nano_to_i128(arr: &dyn Array) -> ArrayRef {
+    let arr: &PrimitiveArray<months_days_ns> = arr.as_any().downcast_ref().unwrap();
+    let values = arr.values().iter().map(|v| interval_to_i128(*v)).collect();
+    PrimitiveArray::<i128>::new(ArrowDataType::Int128, values, arr.validity().cloned()).boxed()
+}
+
+#[cfg(all(feature = "dtype-struct", not(feature = "dtype-interval")))]
 fn convert_month_day_nano_to_struct(chunk: Box<dyn Array>) -> ApolloResult<Box<dyn Array>> {
     let arr: &PrimitiveArray<months_days_ns> = chunk.as_any().downcast_ref().unwrap();
 
@@ -21,6 +21,15 @@ impl SeriesWrap<ExtensionChunked> {
     {
         Ok(apply(self.0.storage())?.into_extension(self.0.extension_type().clone()))
     }
+
+    /// The comparison key for trait methods that can't return an error.
+    ///
+    /// The fallible entry points (sorting, joins, group-by) compute the key up
+    /// front and propagate its error, so falling back to the storage here only
+    /// happens for keys that already failed there.
+    fn comparison_key_or_storage(&self) -> &Series {
+        self.0.comparison_key().unwrap_or(self.0.storage())
+    }
 }
 
 impl private::PrivateSeries for SeriesWrap<ExtensionChunked> {
@@ -45,11 +54,11 @@ impl private::PrivateSeries for SeriesWrap<ExtensionChunked> {
     }
 
     fn into_total_eq_inner<'a>(&'a self) -> Box<dyn TotalEqInner + 'a> {
-        self.0.storage().into_total_eq_inner()
+        self.comparison_key_or_storage().into_total_eq_inner()
     }
 
     fn into_total_ord_inner<'a>(&'a self) -> Box<dyn TotalOrdInner + 'a> {
-        self.0.storage().into_total_ord_inner()
+        self.comparison_key_or_storage().into_total_ord_inner()
     }
 
     fn vec_hash(
@@ -57,7 +66,7 @@ impl private::PrivateSeries for SeriesWrap<ExtensionChunked> {
         build_hasher: PlSeedableRandomStateQuality,
         buf: &mut Vec<u64>,
     ) -> ApolloResult<()> {
-        self.0.storage().vec_hash(build_hasher, buf)
+        self.0.comparison_key()?.vec_hash(build_hasher, buf)
     }
 
     fn vec_hash_combine(
@@ -65,11 +74,13 @@ impl private::PrivateSeries for SeriesWrap<ExtensionChunked> {
         build_hasher: PlSeedableRandomStateQuality,
         hashes: &mut [u64],
     ) -> ApolloResult<()> {
-        self.0.storage().vec_hash_combine(build_hasher, hashes)
+        self.0
+            .comparison_key()?
+            .vec_hash_combine(build_hasher, hashes)
     }
 
     fn group_tuples(&self, multithreaded: bool, sorted: bool) -> ApolloResult<GroupsType> {
-        self.0.storage().group_tuples(multithreaded, sorted)
+        self.0.comparison_key()?.group_tuples(multithreaded, sorted)
     }
 
     fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> ApolloResult<Series> {
@@ -90,13 +101,13 @@ impl private::PrivateSeries for SeriesWrap<ExtensionChunked> {
         by: &[Column],
         options: &SortMultipleOptions,
     ) -> ApolloResult<IdxCa> {
-        self.0.storage().arg_sort_multiple(by, options)
+        self.0.comparison_key()?.arg_sort_multiple(by, options)
     }
 }
 
 impl private::PrivateSeriesNumeric for SeriesWrap<ExtensionChunked> {
     fn bit_repr(&self) -> Option<BitRepr> {
-        self.0.storage().bit_repr()
+        self.comparison_key_or_storage().bit_repr()
     }
 }
 
@@ -283,27 +294,41 @@ impl SeriesTrait for SeriesWrap<ExtensionChunked> {
     }
 
     fn sort_with(&self, options: SortOptions) -> ApolloResult<Series> {
-        self.try_apply_on_storage(|s| s.sort_with(options))
+        match self.0.custom_comparison_key()? {
+            Some(key) => {
+                let idx = key.arg_sort(options);
+                // SAFETY: the key has the same length as the storage.
+                Ok(unsafe { self.take_unchecked(&idx) })
+            },
+            None => self.try_apply_on_storage(|s| s.sort_with(options)),
+        }
     }
 
     fn arg_sort(&self, options: SortOptions) -> IdxCa {
-        self.0.storage().arg_sort(options)
+        self.comparison_key_or_storage().arg_sort(options)
     }
 
     fn unique(&self) -> ApolloResult<Series> {
-        self.try_apply_on_storage(|s| s.unique())
+        match self.0.custom_comparison_key()? {
+            Some(key) => {
+                let idx = key.arg_unique()?;
+                // SAFETY: the key has the same length as the storage.
+                Ok(unsafe { self.take_unchecked(&idx) })
+            },
+            None => self.try_apply_on_storage(|s| s.unique()),
+        }
     }
 
     fn n_unique(&self) -> ApolloResult<usize> {
-        self.0.storage().n_unique()
+        self.0.comparison_key()?.n_unique()
     }
 
     fn arg_unique(&self) -> ApolloResult<IdxCa> {
-        self.0.storage().arg_unique()
+        self.0.comparison_key()?.arg_unique()
     }
 
     fn unique_id(&self) -> ApolloResult<(IdxSize, Vec<IdxSize>)> {
-        self.0.storage().unique_id()
+        self.0.comparison_key()?.unique_id()
     }
 
     fn as_single_ptr(&mut self) -> ApolloResult<usize> {
@@ -312,6 +337,6 @@ impl SeriesTrait for SeriesWrap<ExtensionChunked> {
 
     #[cfg(feature = "approx_unique")]
     fn approx_n_unique(&self) -> ApolloResult<IdxSize> {
-        self.0.storage().approx_n_unique()
+        self.0.comparison_key()?.approx_n_unique()
     }
 }
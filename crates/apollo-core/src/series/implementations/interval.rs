@@ -0,0 +1,395 @@
+use super::*;
+use crate::prelude::*;
+
+unsafe impl IntoSeries for IntervalChunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl private::PrivateSeriesNumeric for SeriesWrap<IntervalChunked> {
+    fn bit_repr(&self) -> Option<BitRepr> {
+        Some(self.0.physical().to_bit_repr())
+    }
+}
+
+impl SeriesWrap<IntervalChunked> {
+    fn apply_physical_to_s<F: Fn(&Int128Chunked) -> Int128Chunked>(&self, f: F) -> Series {
+        f(self.0.physical()).into_interval().into_series()
+    }
+
+    /// Cast `rhs` to an interval and get the physical values of both sides.
+    fn physical_operands(&self, rhs: &Series) -> ApolloResult<(Series, Series)> {
+        let rhs = rhs.cast(&DataType::Interval)?;
+        let lhs = self.0.physical().clone().into_series();
+        let rhs = rhs.to_physical_repr().into_owned();
+        Ok((lhs, rhs))
+    }
+}
+
+impl private::PrivateSeries for SeriesWrap<IntervalChunked> {
+    fn compute_len(&mut self) {
+        self.0.physical_mut().compute_len()
+    }
+
+    fn _field(&self) -> Cow<'_, Field> {
+        Cow::Owned(self.0.field())
+    }
+
+    fn _dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+
+    fn _get_flags(&self) -> StatisticsFlags {
+        self.0.physical().get_flags()
+    }
+
+    fn _set_flags(&mut self, flags: StatisticsFlags) {
+        self.0.physical_mut().set_flags(flags)
+    }
+
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        self.0.physical().equal_element(idx_self, idx_other, other)
+    }
+
+    #[cfg(feature = "zip_with")]
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> ApolloResult<Series> {
+        let other = other.interval()?;
+        Ok(self
+            .0
+            .physical()
+            .zip_with(mask, other.physical())?
+            .into_interval()
+            .into_series())
+    }
+
+    fn into_total_eq_inner<'a>(&'a self) -> Box<dyn TotalEqInner + 'a> {
+        self.0.physical().into_total_eq_inner()
+    }
+
+    fn into_total_ord_inner<'a>(&'a self) -> Box<dyn TotalOrdInner + 'a> {
+        self.0.physical().into_total_ord_inner()
+    }
+
+    fn vec_hash(
+        &self,
+        random_state: PlSeedableRandomStateQuality,
+        buf: &mut Vec<u64>,
+    ) -> ApolloResult<()> {
+        self.0.physical().vec_hash(random_state, buf)?;
+        Ok(())
+    }
+
+    fn vec_hash_combine(
+        &self,
+        build_hasher: PlSeedableRandomStateQuality,
+        hashes: &mut [u64],
+    ) -> ApolloResult<()> {
+        self.0.physical().vec_hash_combine(build_hasher, hashes)?;
+        Ok(())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_sum(&self, groups: &GroupsType) -> Series {
+        self.0
+            .physical()
+            .agg_sum(groups)
+            .i128()
+            .unwrap()
+            .clone()
+            .into_interval()
+            .into_series()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_list(&self, groups: &GroupsType) -> Series {
+        // we cannot cast and dispatch as the inner type of the list would be incorrect
+        self.0
+            .physical()
+            .agg_list(groups)
+            .cast(&DataType::List(Box::new(self.dtype().clone())))
+            .unwrap()
+    }
+
+    // Intervals are added and subtracted component-wise, which the packed
+    // physical representation does for us.
+    fn subtract(&self, rhs: &Series) -> ApolloResult<Series> {
+        match rhs.dtype() {
+            DataType::Interval | DataType::Duration(_) => {
+                let (lhs, rhs) = self.physical_operands(rhs)?;
+                Ok(lhs
+                    .subtract(&rhs)?
+                    .i128()?
+                    .clone()
+                    .into_interval()
+                    .into_series())
+            },
+            dtr => apollo_bail!(opq = sub, self.dtype(), dtr),
+        }
+    }
+
+    fn add_to(&self, rhs: &Series) -> ApolloResult<Series> {
+        match rhs.dtype() {
+            DataType::Interval | DataType::Duration(_) => {
+                let (lhs, rhs) = self.physical_operands(rhs)?;
+                Ok(lhs
+                    .add_to(&rhs)?
+                    .i128()?
+                    .clone()
+                    .into_interval()
+                    .into_series())
+            },
+            dtr => apollo_bail!(opq = add, self.dtype(), dtr),
+        }
+    }
+
+    fn multiply(&self, rhs: &Series) -> ApolloResult<Series> {
+        match rhs.dtype() {
+            dt if dt.is_integer() => {
+                let rhs = rhs.cast(&DataType::Int128)?;
+                Ok((self.0.physical() * rhs.i128().unwrap())
+                    .into_interval()
+                    .into_series())
+            },
+            dtr => apollo_bail!(opq = mul, self.dtype(), dtr),
+        }
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> ApolloResult<GroupsType> {
+        self.0.physical().group_tuples(multithreaded, sorted)
+    }
+
+    fn arg_sort_multiple(
+        &self,
+        by: &[Column],
+        options: &SortMultipleOptions,
+    ) -> ApolloResult<IdxCa> {
+        self.0.physical().arg_sort_multiple(by, options)
+    }
+}
+
+impl SeriesTrait for SeriesWrap<IntervalChunked> {
+    fn rename(&mut self, name: PlSmallStr) {
+        self.0.rename(name)
+    }
+
+    fn chunk_lengths(&self) -> ChunkLenIter<'_> {
+        self.0.physical().chunk_lengths()
+    }
+
+    fn name(&self) -> &PlSmallStr {
+        self.0.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.physical().chunks()
+    }
+
+    unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.0.physical_mut().chunks_mut()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.physical_mut().shrink_to_fit()
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.apply_physical_to_s(|ca| ca.slice(offset, length))
+    }
+
+    fn split_at(&self, offset: i64) -> (Series, Series) {
+        let (a, b) = self.0.split_at(offset);
+        (a.into_series(), b.into_series())
+    }
+
+    fn append(&mut self, other: &Series) -> ApolloResult<()> {
+        apollo_ensure!(self.0.dtype() == other.dtype(), append);
+        let mut other = other.to_physical_repr().into_owned();
+        self.0
+            .physical_mut()
+            .append_owned(std::mem::take(other._get_inner_mut().as_mut()))
+    }
+
+    fn append_owned(&mut self, mut other: Series) -> ApolloResult<()> {
+        apollo_ensure!(self.0.dtype() == other.dtype(), append);
+        self.0.physical_mut().append_owned(std::mem::take(
+            &mut other
+                ._get_inner_mut()
+                .as_any_mut()
+                .downcast_mut::<IntervalChunked>()
+                .unwrap()
+                .phys,
+        ))
+    }
+
+    fn extend(&mut self, other: &Series) -> ApolloResult<()> {
+        apollo_ensure!(self.0.dtype() == other.dtype(), extend);
+        let other = other.to_physical_repr();
+        self.0
+            .physical_mut()
+            .extend(other.as_ref().as_ref().as_ref())?;
+        Ok(())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> ApolloResult<Series> {
+        Ok(self
+            .0
+            .physical()
+            .filter(filter)?
+            .into_interval()
+            .into_series())
+    }
+
+    fn take(&self, indices: &IdxCa) -> ApolloResult<Series> {
+        Ok(self
+            .0
+            .physical()
+            .take(indices)?
+            .into_interval()
+            .into_series())
+    }
+
+    unsafe fn take_unchecked(&self, indices: &IdxCa) -> Series {
+        self.0
+            .physical()
+            .take_unchecked(indices)
+            .into_interval()
+            .into_series()
+    }
+
+    fn take_slice(&self, indices: &[IdxSize]) -> ApolloResult<Series> {
+        Ok(self
+            .0
+            .physical()
+            .take(indices)?
+            .into_interval()
+            .into_series())
+    }
+
+    unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Series {
+        self.0
+            .physical()
+            .take_unchecked(indices)
+            .into_interval()
+            .into_series()
+    }
+
+    fn deposit(&self, validity: &Bitmap) -> Series {
+        self.apply_physical_to_s(|ca| ca.deposit(validity))
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        self.0
+            .physical()
+            .rechunk()
+            .into_owned()
+            .into_interval()
+            .into_series()
+    }
+
+    fn new_from_index(&self, index: usize, length: usize) -> Series {
+        self.apply_physical_to_s(|ca| ca.new_from_index(index, length))
+    }
+
+    fn cast(&self, dtype: &DataType, cast_options: CastOptions) -> ApolloResult<Series> {
+        self.0.cast_with_options(dtype, cast_options)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue<'_> {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    fn sort_with(&self, options: SortOptions) -> ApolloResult<Series> {
+        Ok(self.apply_physical_to_s(|ca| ca.sort_with(options)))
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        self.0.physical().arg_sort(options)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn has_nulls(&self) -> bool {
+        self.0.has_nulls()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique(&self) -> ApolloResult<Series> {
+        Ok(self.apply_physical_to_s(|ca| ca.unique().unwrap()))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn n_unique(&self) -> ApolloResult<usize> {
+        self.0.physical().n_unique()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique(&self) -> ApolloResult<IdxCa> {
+        self.0.physical().arg_unique()
+    }
+
+    fn unique_id(&self) -> ApolloResult<(IdxSize, Vec<IdxSize>)> {
+        ChunkUnique::unique_id(self.0.physical())
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        self.apply_physical_to_s(|ca| ca.reverse())
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        self.apply_physical_to_s(|ca| ca.shift(periods))
+    }
+
+    #[cfg(feature = "approx_unique")]
+    fn approx_n_unique(&self) -> ApolloResult<IdxSize> {
+        Ok(ChunkApproxNUnique::approx_n_unique(self.0.physical()))
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    fn sum_reduce(&self) -> ApolloResult<Scalar> {
+        let sum = self.0.physical().sum().unwrap_or(0);
+        Ok(Scalar::new(
+            DataType::Interval,
+            AnyValue::Interval(i128_to_interval(sum)),
+        ))
+    }
+
+    fn find_validity_mismatch(&self, other: &Series, idxs: &mut Vec<IdxSize>) {
+        self.0.physical().find_validity_mismatch(other, idxs)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        &mut self.0
+    }
+
+    fn as_phys_any(&self) -> &dyn Any {
+        self.0.physical()
+    }
+
+    fn as_arc_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync> {
+        self as _
+    }
+}
@@ -17,7 +17,11 @@ mod duration;
 #[cfg(feature = "dtype-extension")]
 mod extension;
 mod floats;
+#[cfg(feature = "dtype-interval")]
+mod interval;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 pub(crate) mod null;
 #[cfg(feature = "object")]
 mod object;
@@ -26,6 +30,8 @@ mod string;
 mod struct_;
 #[cfg(feature = "dtype-time")]
 mod time;
+#[cfg(feature = "dtype-union")]
+mod union;
 
 use std::any::Any;
 use std::borrow::Cow;
@@ -277,6 +277,20 @@ impl ToArrowConverter {
                 .clone()
                 .to(dtype.to_arrow(CompatLevel::newest()))
                 .to_boxed(),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => {
+                let arr = array
+                    .as_any()
+                    .downcast_ref::<arrow::array::PrimitiveArray<i128>>()
+                    .unwrap();
+                let values = arr.values().iter().map(|v| i128_to_interval(*v)).collect();
+                arrow::array::PrimitiveArray::<months_days_ns>::new(
+                    dtype.to_arrow(self.compat_level),
+                    values,
+                    arr.validity().cloned(),
+                )
+                .to_boxed()
+            },
             #[cfg(feature = "object")]
             DataType::Object(_) => {
                 use crate::chunked_array::object::builder::object_series_to_arrow_array;
@@ -366,11 +380,14 @@ impl ToArrowConverter {
                     None => None,
                 };
 
-                let mut arr = self.array_to_arrow(
-                    array,
-                    storage_dtype,
-                    expected_inner_output_field.as_ref(),
-                )?;
+                let mut arr = match typ.0.arrow_storage_dtype() {
+                    Some(arrow_storage) => cast_default(array, &arrow_storage)?,
+                    None => self.array_to_arrow(
+                        array,
+                        storage_dtype,
+                        expected_inner_output_field.as_ref(),
+                    )?,
+                };
 
                 *arr.dtype_mut() = ArrowDataType::Extension(Box::new(ExtensionType {
                     name: output_ext_name,
@@ -379,6 +396,151 @@ impl ToArrowConverter {
                 }));
                 arr
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => {
+                use arrow::array::MapArray;
+                use arrow::offset::OffsetsBuffer;
+
+                let arr: &ListArray<i64> = array.as_any().downcast_ref().unwrap();
+
+                let expected_entries_field: Option<&ArrowField> = match output_arrow_field {
+                    Some(ArrowField {
+                        name: _,
+                        dtype: ArrowDataType::Map(entries_field, _),
+                        is_nullable: _,
+                        metadata: _,
+                    }) => Some(entries_field),
+                    Some(ArrowField { dtype, .. }) => apollo_bail!(
+                        SchemaMismatch:
+                        "to_arrow(): map dtype mismatch: {:?} != expected: {:?}",
+                        dtype, arr.dtype(),
+                    ),
+                    None => None,
+                };
+
+                let mut entries = self.array_to_arrow(
+                    arr.values().as_ref(),
+                    &DataType::map_entries_dtype(key, value),
+                    expected_entries_field,
+                )?;
+
+                // Arrow requires the keys of a map to be non-nullable.
+                let ArrowDataType::Struct(mut entry_fields) = entries.dtype().clone() else {
+                    unreachable!()
+                };
+                entry_fields[0].is_nullable = false;
+                *entries.dtype_mut() = ArrowDataType::Struct(entry_fields);
+
+                let arr = MapArray::try_new(
+                    ArrowDataType::Map(
+                        Box::new(ArrowField::new(
+                            MAP_ENTRIES_NAME,
+                            entries.dtype().clone(),
+                            false,
+                        )),
+                        false,
+                    ),
+                    OffsetsBuffer::<i32>::try_from(arr.offsets())?,
+                    entries,
+                    arr.validity().cloned(),
+                )?;
+                Box::new(arr)
+            },
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(fields, layout) => {
+                use apollo_compute::gather::take_unchecked;
+                use arrow::array::{StructArray, UnionArray};
+                use arrow::bitmap::Bitmap;
+                use arrow::compute::utils::combine_validities_and;
+                use arrow::datatypes::{UnionMode, UnionType};
+
+                let arr: &StructArray = array.as_any().downcast_ref().unwrap();
+
+                let expected_member_fields: &[ArrowField] = match output_arrow_field {
+                    Some(ArrowField {
+                        name: _,
+                        dtype: ArrowDataType::Union(union),
+                        is_nullable: _,
+                        metadata: _,
+                    }) if union.fields.len() == fields.len() => union.fields.as_slice(),
+                    Some(ArrowField { dtype, .. }) => apollo_bail!(
+                        SchemaMismatch:
+                        "to_arrow(): union dtype mismatch: {:?} != expected: {:?}",
+                        dtype, arr.dtype(),
+                    ),
+                    None => &[],
+                };
+
+                // Arrow unions have no validity of their own, a null row selects a null value of
+                // the first member instead.
+                let tags: &PrimitiveArray<i8> = arr.values()[0].as_any().downcast_ref().unwrap();
+                let indices: Vec<usize> = (0..arr.len())
+                    .map(|i| match arr.is_valid(i) && tags.is_valid(i) {
+                        true => tags.value(i) as usize,
+                        false => 0,
+                    })
+                    .collect();
+                let is_selected = |member: usize| -> Bitmap {
+                    (0..arr.len())
+                        .map(|i| indices[i] == member && arr.is_valid(i))
+                        .collect()
+                };
+
+                let mut offsets = layout.dense.then(|| vec![0i32; arr.len()]);
+                let mut values = Vec::with_capacity(fields.len());
+                let mut arrow_fields = Vec::with_capacity(fields.len());
+                for (member, field) in fields.iter().enumerate() {
+                    let member_values = self.array_to_arrow(
+                        arr.values()[member + 1].as_ref(),
+                        field.dtype(),
+                        expected_member_fields.get(member),
+                    )?;
+                    let member_values = match &mut offsets {
+                        Some(offsets) => {
+                            let idx: IdxArr = indices
+                                .iter()
+                                .enumerate()
+                                .filter(|(_, m)| **m == member)
+                                .enumerate()
+                                .map(|(slot, (row, _))| {
+                                    offsets[row] = slot as i32;
+                                    arr.is_valid(row).then_some(row as IdxSize)
+                                })
+                                .collect();
+                            unsafe { take_unchecked(member_values.as_ref(), &idx) }
+                        },
+                        None => {
+                            let validity = combine_validities_and(
+                                member_values.validity(),
+                                Some(&is_selected(member)),
+                            );
+                            member_values.with_validity(validity)
+                        },
+                    };
+                    arrow_fields.push(ArrowField::new(
+                        field.name().clone(),
+                        member_values.dtype().clone(),
+                        true,
+                    ));
+                    values.push(member_values);
+                }
+
+                let types: Vec<i8> = indices
+                    .iter()
+                    .map(|m| {
+                        layout
+                            .type_ids
+                            .as_ref()
+                            .map_or(*m as i8, |ids| ids[*m] as i8)
+                    })
+                    .collect();
+                let dtype = ArrowDataType::Union(Box::new(UnionType {
+                    fields: arrow_fields,
+                    ids: layout.type_ids.clone(),
+                    mode: UnionMode::sparse(!layout.dense),
+                }));
+                UnionArray::try_new(dtype, types.into(), values, offsets.map(Into::into))?.boxed()
+            },
             _ => {
                 assert!(!dtype.is_logical());
                 array.to_boxed()
@@ -462,6 +462,24 @@ impl Series {
             return Ok(Series::full_null(slf.name().clone(), len, dtype));
         }
 
+        #[cfg(feature = "dtype-extension")]
+        if let D::Extension(typ, storage) = dtype {
+            if !slf.dtype().is_extension() {
+                let out = match typ.0.cast_from(&slf, options)? {
+                    Some(out) => {
+                        apollo_ensure!(
+                            out.dtype() == storage.as_ref(),
+                            ComputeError: "cast to extension type '{}' returned dtype {}, expected {}",
+                            typ.name(), out.dtype(), storage
+                        );
+                        out
+                    },
+                    None => slf.cast_with_options(storage, options)?,
+                };
+                return Ok(ExtensionChunked::try_from_storage(typ.clone(), out)?.into_series());
+            }
+        }
+
         let new_options = match options {
             // Strictness is handled on this level to improve error messages.
             CastOptions::Strict => CastOptions::NonStrict,
@@ -518,6 +536,10 @@ impl Series {
                     .into_decimal_unchecked(*precision, *scale)
                     .into_series())
             },
+            #[cfg(feature = "dtype-interval")]
+            (D::Int128, D::Interval) => {
+                Ok(self.i128().unwrap().clone().into_interval().into_series())
+            },
 
             #[cfg(feature = "dtype-categorical")]
             (phys, D::Categorical(cats, _)) if &cats.physical().dtype() == phys => {
@@ -581,6 +603,16 @@ impl Series {
                 let ext = ExtensionChunked::from_storage(typ.clone(), storage_series);
                 Ok(ext.into_series())
             },
+            #[cfg(feature = "dtype-map")]
+            (D::List(_), D::Map(key, value)) => {
+                let storage = self.from_physical_unchecked(&D::map_storage_dtype(key, value))?;
+                Ok(storage.into_map())
+            },
+            #[cfg(feature = "dtype-union")]
+            (D::Struct(_), D::Union(fields, _)) => {
+                let storage = self.from_physical_unchecked(&D::union_storage_dtype(fields))?;
+                Ok(storage.into_union(dtype.clone()))
+            },
 
             _ => panic!("invalid from_physical({dtype:?}) for {:?}", self.dtype()),
         }
@@ -593,6 +625,24 @@ impl Series {
         ext.into_series()
     }
 
+    /// Interpret a list of `{key, value}` structs as a map.
+    ///
+    /// # Panics
+    /// Panics if the Series is not a list of `{key, value}` structs.
+    #[cfg(feature = "dtype-map")]
+    pub fn into_map(self) -> Series {
+        MapChunked::from_storage(self).into_series()
+    }
+
+    /// Interpret a struct of a tag and the members as a union of type `dtype`.
+    ///
+    /// # Panics
+    /// Panics if `dtype` is not a union or the Series is not of its storage dtype.
+    #[cfg(feature = "dtype-union")]
+    pub fn into_union(self, dtype: DataType) -> Series {
+        UnionChunked::from_storage(self, dtype).into_series()
+    }
+
     /// Cast numerical types to f64, and keep floats as is.
     pub fn to_float(&self) -> ApolloResult<Series> {
         match self.dtype() {
@@ -644,6 +694,8 @@ impl Series {
             DataType::List(_) => self.list().unwrap().explode(options),
             #[cfg(feature = "dtype-array")]
             DataType::Array(_, _) => self.array().unwrap().explode(options),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => self.map_().unwrap().entries().explode(options),
             _ => Ok(self.clone()),
         }
     }
@@ -731,12 +783,14 @@ impl Series {
     /// * Datetime -> Int64
     /// * Duration -> Int64
     /// * Decimal -> Int128
+    /// * Interval -> Int128
     /// * Time -> Int64
     /// * Categorical -> U8/U16/U32
     /// * List(inner) -> List(physical of inner)
     /// * Array(inner) -> Array(physical of inner)
     /// * Struct -> Struct with physical repr of each struct column
     /// * Extension -> physical of storage type
+    /// * Map -> List(Struct) with physical repr of the keys and values
     pub fn to_physical_repr(&self) -> Cow<'_, Series> {
         use DataType::*;
         match self.dtype() {
@@ -759,6 +813,8 @@ impl Series {
             },
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => Cow::Owned(self.decimal().unwrap().phys.clone().into_series()),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Cow::Owned(self.interval().unwrap().phys.clone().into_series()),
             List(_) => match self.list().unwrap().to_physical_repr() {
                 Cow::Borrowed(_) => Cow::Borrowed(self),
                 Cow::Owned(ca) => Cow::Owned(ca.into_series()),
@@ -775,6 +831,10 @@ impl Series {
             },
             #[cfg(feature = "dtype-extension")]
             Extension(_, _) => self.ext().unwrap().storage().to_physical_repr(),
+            #[cfg(feature = "dtype-map")]
+            Map(_, _) => self.map_().unwrap().storage().to_physical_repr(),
+            #[cfg(feature = "dtype-union")]
+            Union(_, _) => self.union().unwrap().storage().to_physical_repr(),
             _ => Cow::Borrowed(self),
         }
     }
@@ -791,6 +851,18 @@ impl Series {
         self
     }
 
+    /// If the Series is an Extension type, return the Series defining equality,
+    /// hashing and ordering of its values. Otherwise, return itself.
+    pub fn to_comparison_key(&self) -> ApolloResult<&Series> {
+        #[cfg(feature = "dtype-extension")]
+        {
+            if let DataType::Extension(_, _) = self.dtype() {
+                return self.ext().unwrap().comparison_key();
+            }
+        }
+        Ok(self)
+    }
+
     /// Traverse and collect every nth element in a new array.
     pub fn gather_every(&self, n: usize, offset: usize) -> ApolloResult<Series> {
         apollo_ensure!(n > 0, ComputeError: "cannot perform gather every for `n=0`");
@@ -970,6 +1042,10 @@ impl Series {
 
     // used for formatting
     pub fn str_value(&self, index: usize) -> ApolloResult<Cow<'_, str>> {
+        #[cfg(feature = "dtype-extension")]
+        if let Some(ext) = self.try_ext() {
+            return ext.str_value(index);
+        }
         Ok(self.0.get(index)?.str_value())
     }
     /// Get the head of the Series.
@@ -160,6 +160,12 @@ impl Series {
         try_unpack_chunked!(self, DataType::Decimal(_, _) => DecimalChunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Interval`]
+    #[cfg(feature = "dtype-interval")]
+    pub fn try_interval(&self) -> Option<&IntervalChunked> {
+        try_unpack_chunked!(self, DataType::Interval => IntervalChunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype list
     pub fn try_list(&self) -> Option<&ListChunked> {
         try_unpack_chunked!(self, DataType::List(_) => ListChunked)
@@ -198,6 +204,18 @@ impl Series {
         try_unpack_chunked!(self, DataType::Extension(_, _) => ExtensionChunked)
     }
 
+    /// Unpack to [`MapChunked`] of dtype [`DataType::Map`].
+    #[cfg(feature = "dtype-map")]
+    pub fn try_map_(&self) -> Option<&MapChunked> {
+        try_unpack_chunked!(self, DataType::Map(_, _) => MapChunked)
+    }
+
+    /// Unpack to [`UnionChunked`] of dtype [`DataType::Union`].
+    #[cfg(feature = "dtype-union")]
+    pub fn try_union(&self) -> Option<&UnionChunked> {
+        try_unpack_chunked!(self, DataType::Union(_, _) => UnionChunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Struct`]
     #[cfg(feature = "dtype-struct")]
     pub fn try_struct(&self) -> Option<&StructChunked> {
@@ -369,6 +387,13 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "Decimal"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Interval`]
+    #[cfg(feature = "dtype-interval")]
+    pub fn interval(&self) -> ApolloResult<&IntervalChunked> {
+        self.try_interval()
+            .ok_or_else(|| unpack_chunked_err!(self => "Interval"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype list
     pub fn list(&self) -> ApolloResult<&ListChunked> {
         self.try_list()
@@ -432,6 +457,20 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "Extension"))
     }
 
+    /// Unpack to [`MapChunked`] of dtype [`DataType::Map`].
+    #[cfg(feature = "dtype-map")]
+    pub fn map_(&self) -> ApolloResult<&MapChunked> {
+        self.try_map_()
+            .ok_or_else(|| unpack_chunked_err!(self => "Map"))
+    }
+
+    /// Unpack to [`UnionChunked`] of dtype [`DataType::Union`].
+    #[cfg(feature = "dtype-union")]
+    pub fn union(&self) -> ApolloResult<&UnionChunked> {
+        self.try_union()
+            .ok_or_else(|| unpack_chunked_err!(self => "Union"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Null`]
     pub fn null(&self) -> ApolloResult<&NullChunked> {
         self.try_null()
@@ -48,6 +48,10 @@ impl Series {
             DataType::Decimal(precision, scale) => Int128Chunked::full_null(name, size)
                 .into_decimal_unchecked(*precision, *scale)
                 .into_series(),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => Int128Chunked::full_null(name, size)
+                .into_interval()
+                .into_series(),
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => {
                 let fields = fields
@@ -101,6 +105,15 @@ impl Series {
             DataType::Extension(typ, storage_dtype) => {
                 Series::full_null(name, size, storage_dtype).into_extension(typ.clone())
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => {
+                Series::full_null(name, size, &DataType::map_storage_dtype(key, value)).into_map()
+            },
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(fields, _) => {
+                Series::full_null(name, size, &DataType::union_storage_dtype(fields))
+                    .into_union(dtype.clone())
+            },
             _ => {
                 macro_rules! primitive {
                     ($type:ty) => {{ ChunkedArray::<$type>::full_null(name, size).into_series() }};
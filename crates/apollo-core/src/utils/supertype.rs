@@ -399,6 +399,8 @@ pub fn get_supertype_with_options(
             (Duration(_), Date) | (Date, Duration(_)) => Some(Date),
             #[cfg(feature = "dtype-duration")]
             (Duration(lu), Duration(ru)) => Some(Duration(get_time_units(lu, ru))),
+            #[cfg(feature = "dtype-interval")]
+            (Interval, Duration(_)) => Some(Interval),
 
             // both None or both Some("<tz>") timezones
             // we cast from more precision to higher precision as that always fits with occasional loss of precision
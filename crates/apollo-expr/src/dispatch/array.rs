@@ -1,6 +1,8 @@
 use apollo_core::error::{ApolloResult, apollo_bail, apollo_ensure, apollo_err};
 use apollo_core::prelude::{Column, DataType, ExplodeOptions, IntoColumn, SortOptions};
 use apollo_ops::prelude::array::ArrayNameSpace;
+#[cfg(feature = "array_vector")]
+use apollo_ops::prelude::array::VectorMetric;
 #[cfg(feature = "array_to_struct")]
 use apollo_plan::dsl::DslNameGenerator;
 use apollo_plan::dsl::{ColumnsUdf, SpecialEq};
@@ -43,6 +45,10 @@ pub fn function_expr_to_udf(func: IRArrayFunction) -> SpecialEq<Arc<dyn ColumnsU
         Slice(offset, length) => map!(slice, offset, length),
         #[cfg(feature = "array_to_struct")]
         ToStruct(ng) => map!(arr_to_struct, ng.clone()),
+        #[cfg(feature = "array_vector")]
+        Metric(metric) => map_as_slice!(vector_metric, metric),
+        #[cfg(feature = "array_vector")]
+        Normalize => map!(normalize),
     }
 }
 
@@ -259,3 +265,20 @@ fn arr_to_struct(s: &Column, name_generator: Option<DslNameGenerator>) -> Apollo
         .to_struct(name_generator)
         .map(IntoColumn::into_column)
 }
+
+#[cfg(feature = "array_vector")]
+pub(super) fn vector_metric(s: &[Column], metric: VectorMetric) -> ApolloResult<Column> {
+    let ca = s[0].array()?;
+    // Allow vectors given as lists, e.g. a literal query vector.
+    let other = match s[1].dtype() {
+        DataType::List(inner) => s[1].cast(&DataType::Array(inner.clone(), ca.width()))?,
+        _ => s[1].clone(),
+    };
+    ca.array_vector_metric(other.array()?, metric)
+        .map(IntoColumn::into_column)
+}
+
+#[cfg(feature = "array_vector")]
+pub(super) fn normalize(s: &Column) -> ApolloResult<Column> {
+    Ok(s.array()?.array_normalize()?.into_column())
+}
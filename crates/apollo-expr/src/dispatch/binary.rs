@@ -27,6 +27,8 @@ pub fn function_expr_to_udf(func: IRBinaryFunction) -> SpecialEq<Arc<dyn Columns
         #[cfg(feature = "binary_encoding")]
         Base64Encode => map!(base64_encode),
         Size => map!(size_bytes),
+        #[cfg(feature = "hash_digest")]
+        Digest { algorithm, hex } => map!(digest, algorithm, hex),
         #[cfg(feature = "binary_encoding")]
         Reinterpret(dtype, is_little_endian) => map!(reinterpret, &dtype, is_little_endian),
         Slice => {
@@ -75,6 +77,20 @@ pub(super) fn size_bytes(s: &Column) -> ApolloResult<Column> {
     Ok(ca.size_bytes().into_column())
 }
 
+#[cfg(feature = "hash_digest")]
+pub(super) fn digest(
+    s: &Column,
+    algorithm: apollo_ops::prelude::HashAlgorithm,
+    hex: bool,
+) -> ApolloResult<Column> {
+    let ca = s.binary()?;
+    Ok(if hex {
+        ca.bin_digest_hex(algorithm).into_column()
+    } else {
+        ca.bin_digest(algorithm).into_column()
+    })
+}
+
 #[cfg(feature = "binary_encoding")]
 pub(super) fn hex_decode(s: &Column, strict: bool) -> ApolloResult<Column> {
     let ca = s.binary()?;
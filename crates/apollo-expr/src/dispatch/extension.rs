@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use apollo_core::datatypes::extension::{UuidVersion, generate_uuids};
 use apollo_core::error::ApolloResult;
 use apollo_core::prelude::*;
 use apollo_plan::dsl::{ColumnsUdf, SpecialEq};
@@ -10,6 +11,7 @@ pub fn function_expr_to_udf(func: IRExtensionFunction) -> SpecialEq<Arc<dyn Colu
     match func {
         To(dtype) => map!(ext_to, dtype.clone()),
         Storage => map!(ext_storage),
+        GenerateUuid(version) => map!(generate_uuid, version),
     }
 }
 
@@ -18,12 +20,25 @@ fn ext_to(s: &Column, dtype: DataType) -> ApolloResult<Column> {
         apollo_bail!(ComputeError: "ext.to() requires an Extension dtype")
     };
 
-    Ok(s.apply_unary_elementwise(|s| {
+    s.try_apply_unary_elementwise(|s| {
         assert!(*s.dtype() == **storage);
-        s.clone().into_extension(typ.clone())
-    }))
+        Ok(ExtensionChunked::try_from_storage(typ.clone(), s.clone())?.into_series())
+    })
 }
 
 fn ext_storage(s: &Column) -> ApolloResult<Column> {
     Ok(s.apply_unary_elementwise(|s| s.to_storage().clone()))
 }
+
+fn generate_uuid(n: &Column, version: UuidVersion) -> ApolloResult<Column> {
+    apollo_ensure!(
+        n.len() == 1,
+        ComputeError: "`n` must contain exactly one value, got {} values", n.len()
+    );
+    let n = n
+        .strict_cast(&IDX_DTYPE)?
+        .idx()?
+        .get(0)
+        .ok_or_else(|| apollo_err!(ComputeError: "invalid null input for `generate_uuid`"))?;
+    Ok(generate_uuids(PlSmallStr::from_static("uuid"), n as usize, version).into_column())
+}
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use apollo_core::error::ApolloResult;
+use apollo_core::prelude::{ChunkExpandAtIndex, Column, IntoColumn, IntoSeries};
+use apollo_ops::prelude::{MapNameSpaceImpl, map_from_lists};
+use apollo_plan::dsl::{ColumnsUdf, SpecialEq};
+use apollo_plan::plans::IRMapFunction;
+use apollo_utils::pl_str::PlSmallStr;
+
+pub fn function_expr_to_udf(func: IRMapFunction) -> SpecialEq<Arc<dyn ColumnsUdf>> {
+    use IRMapFunction::*;
+    match func {
+        Get => map_as_slice!(get),
+        Keys => map!(keys),
+        Values => map!(values),
+        ContainsKey => map_as_slice!(contains_key),
+        FromLists => map_as_slice!(from_lists),
+        ToStruct(names) => map!(to_struct, &names),
+    }
+}
+
+fn get(args: &mut [Column]) -> ApolloResult<Column> {
+    let ca = args[0].map_()?;
+    ca.map_get(args[1].as_materialized_series())
+        .map(Column::from)
+}
+
+fn keys(c: &Column) -> ApolloResult<Column> {
+    c.map_()?.map_keys().map(IntoColumn::into_column)
+}
+
+fn values(c: &Column) -> ApolloResult<Column> {
+    c.map_()?.map_values().map(IntoColumn::into_column)
+}
+
+fn contains_key(args: &mut [Column]) -> ApolloResult<Column> {
+    let ca = args[0].map_()?;
+    ca.map_contains_key(args[1].as_materialized_series())
+        .map(IntoColumn::into_column)
+}
+
+fn from_lists(args: &mut [Column]) -> ApolloResult<Column> {
+    let keys = args[0].list()?;
+    let values = args[1].list()?;
+    // Broadcast a single list of keys or values over the other side.
+    let out = match (keys.len(), values.len()) {
+        (1, n) if n != 1 => map_from_lists(&keys.new_from_index(0, n), values)?,
+        (n, 1) if n != 1 => map_from_lists(keys, &values.new_from_index(0, n))?,
+        _ => map_from_lists(keys, values)?,
+    };
+    Ok(out.into_series().into_column())
+}
+
+fn to_struct(c: &Column, names: &[PlSmallStr]) -> ApolloResult<Column> {
+    c.map_()?.map_to_struct(names).map(IntoColumn::into_column)
+}
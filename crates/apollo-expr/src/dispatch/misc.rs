@@ -36,6 +36,12 @@ pub(super) fn approx_n_unique(s: &Column) -> ApolloResult<Column> {
         .map(|v| Column::new_scalar(s.name().clone(), Scalar::new(IDX_DTYPE, v.into()), 1))
 }
 
+#[cfg(feature = "approx_quantile")]
+pub(super) fn approx_quantile(s: &Column, quantile: f64, accuracy: u32) -> ApolloResult<Column> {
+    apollo_ops::prelude::approx_quantile(s.as_materialized_series(), quantile, accuracy)
+        .map(Column::from)
+}
+
 #[cfg(feature = "diff")]
 pub(super) fn diff(s: &[Column], null_behavior: NullBehavior) -> ApolloResult<Column> {
     let s1 = s[0].as_materialized_series();
@@ -235,6 +241,7 @@ pub(super) fn arg_max(s: &Column) -> ApolloResult<Column> {
 pub(super) fn arg_sort(s: &Column, descending: bool, nulls_last: bool) -> ApolloResult<Column> {
     // @scalar-opt
     Ok(s.as_materialized_series()
+        .to_comparison_key()?
         .arg_sort(SortOptions {
             descending,
             nulls_last,
@@ -1006,6 +1013,21 @@ pub fn row_decode(
     assert_eq!(c.len(), 1);
     let ca = c[0].binary_offset()?;
 
+    // Extension types with a custom comparison key are encoded through that key, which can't be
+    // decoded back into the original values.
+    #[cfg(feature = "dtype-extension")]
+    for field in &fields {
+        if let DataType::Extension(typ, storage) = field.dtype() {
+            let probe =
+                Series::full_null(PlSmallStr::EMPTY, 0, storage).into_extension(typ.clone());
+            apollo_ensure!(
+                probe.ext()?.custom_comparison_key()?.is_none(),
+                InvalidOperation: "cannot row-decode extension type '{}' with a custom comparison key",
+                typ.name()
+            );
+        }
+    }
+
     let mut opts = Vec::with_capacity(fields.len());
     match variant {
         RowEncodingVariant::Unordered => opts.extend(std::iter::repeat_n(
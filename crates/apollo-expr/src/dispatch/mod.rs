@@ -111,6 +111,8 @@ mod extension;
 mod groups_dispatch;
 mod horizontal;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 mod misc;
 mod pow;
 #[cfg(feature = "random")]
@@ -132,6 +134,8 @@ pub(crate) mod struct_;
 mod temporal;
 #[cfg(feature = "trigonometry")]
 mod trigonometry;
+#[cfg(feature = "dtype-union")]
+mod union;
 
 pub use groups_dispatch::drop_items;
 
@@ -147,10 +151,14 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         #[cfg(feature = "dtype-extension")]
         F::Extension(func) => extension::function_expr_to_udf(func),
         F::ListExpr(func) => list::function_expr_to_udf(func),
+        #[cfg(feature = "dtype-map")]
+        F::MapExpr(func) => map::function_expr_to_udf(func),
         #[cfg(feature = "strings")]
         F::StringExpr(func) => strings::function_expr_to_udf(func),
         #[cfg(feature = "dtype-struct")]
         F::StructExpr(func) => struct_::function_expr_to_udf(func),
+        #[cfg(feature = "dtype-union")]
+        F::UnionExpr(func) => union::function_expr_to_udf(func),
         #[cfg(feature = "temporal")]
         F::TemporalExpr(func) => temporal::temporal_func_to_udf(func),
         #[cfg(feature = "bitwise")]
@@ -337,6 +345,10 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         F::Reverse => map!(misc::reverse),
         #[cfg(feature = "approx_unique")]
         F::ApproxNUnique => map!(misc::approx_n_unique),
+        #[cfg(feature = "approx_quantile")]
+        F::ApproxQuantile { quantile, accuracy } => {
+            map!(misc::approx_quantile, quantile, accuracy)
+        },
         F::Coalesce => map_as_slice!(misc::coalesce),
         #[cfg(feature = "diff")]
         F::Diff(null_behavior) => map_as_slice!(misc::diff, null_behavior),
@@ -84,6 +84,39 @@ pub fn function_expr_to_udf(func: IRStringFunction) -> SpecialEq<Arc<dyn Columns
         Normalize { form } => map!(strings::normalize, form.clone()),
         #[cfg(feature = "string_reverse")]
         Reverse => map!(strings::reverse),
+        #[cfg(feature = "string_similarity")]
+        LevenshteinDistance { max_distance } => {
+            map_as_slice!(strings::levenshtein_distance, max_distance)
+        },
+        #[cfg(feature = "string_similarity")]
+        DamerauLevenshteinDistance { max_distance } => {
+            map_as_slice!(strings::damerau_levenshtein_distance, max_distance)
+        },
+        #[cfg(feature = "string_similarity")]
+        HammingDistance => map_as_slice!(strings::hamming_distance),
+        #[cfg(feature = "string_similarity")]
+        JaroWinklerSimilarity => map_as_slice!(strings::jaro_winkler_similarity),
+        #[cfg(feature = "string_similarity")]
+        JaccardSimilarity { q } => map_as_slice!(strings::jaccard_similarity, q),
+        #[cfg(feature = "hash_digest")]
+        Digest { algorithm, hex } => map!(strings::digest, algorithm, hex),
+        #[cfg(feature = "string_url")]
+        ParseUrl => map!(strings::parse_url),
+        #[cfg(feature = "string_url")]
+        UrlQueryParam { name } => map!(strings::url_query_param, &name),
+        #[cfg(feature = "string_ip")]
+        Ipv4ToInt => map!(strings::ipv4_to_int),
+        #[cfg(feature = "string_ip")]
+        Ipv6ToInt => map!(strings::ipv6_to_int),
+        #[cfg(feature = "string_ip")]
+        IpToBinary => map!(strings::ip_to_binary),
+        #[cfg(feature = "string_ip")]
+        IpInCidr => map_as_slice!(strings::ip_in_cidr),
+        #[cfg(feature = "string_ip")]
+        IpMask {
+            ipv4_prefix,
+            ipv6_prefix,
+        } => map!(strings::ip_mask, ipv4_prefix, ipv6_prefix),
         Uppercase => map!(uppercase),
         Lowercase => map!(lowercase),
         #[cfg(feature = "nightly")]
@@ -110,6 +143,8 @@ pub fn function_expr_to_udf(func: IRStringFunction) -> SpecialEq<Arc<dyn Columns
         Base64Decode(strict) => map!(strings::base64_decode, strict),
         #[cfg(feature = "dtype-decimal")]
         ToDecimal { scale } => map!(strings::to_decimal, scale),
+        #[cfg(feature = "dtype-interval")]
+        ToInterval { strict } => map!(strings::to_interval, strict),
         #[cfg(feature = "extract_jsonpath")]
         JsonDecode(dtype) => map!(strings::json_decode, dtype.clone()),
         #[cfg(feature = "extract_jsonpath")]
@@ -770,6 +805,116 @@ pub(super) fn reverse(s: &Column) -> ApolloResult<Column> {
     Ok(ca.str_reverse().into_column())
 }
 
+#[cfg(feature = "string_similarity")]
+pub(super) fn levenshtein_distance(
+    s: &[Column],
+    max_distance: Option<u32>,
+) -> ApolloResult<Column> {
+    _check_same_length(s, "levenshtein_distance")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca
+        .str_levenshtein_distance(other, max_distance)
+        .into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn damerau_levenshtein_distance(
+    s: &[Column],
+    max_distance: Option<u32>,
+) -> ApolloResult<Column> {
+    _check_same_length(s, "damerau_levenshtein_distance")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca
+        .str_damerau_levenshtein_distance(other, max_distance)
+        .into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn hamming_distance(s: &[Column]) -> ApolloResult<Column> {
+    _check_same_length(s, "hamming_distance")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca.str_hamming_distance(other).into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn jaro_winkler_similarity(s: &[Column]) -> ApolloResult<Column> {
+    _check_same_length(s, "jaro_winkler_similarity")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca.str_jaro_winkler_similarity(other).into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn jaccard_similarity(s: &[Column], q: usize) -> ApolloResult<Column> {
+    _check_same_length(s, "jaccard_similarity")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    ca.str_jaccard_similarity(other, q)
+        .map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "hash_digest")]
+pub(super) fn digest(
+    s: &Column,
+    algorithm: apollo_ops::prelude::HashAlgorithm,
+    hex: bool,
+) -> ApolloResult<Column> {
+    let ca = s.str()?;
+    Ok(if hex {
+        ca.str_digest_hex(algorithm).into_column()
+    } else {
+        ca.str_digest(algorithm).into_column()
+    })
+}
+
+#[cfg(feature = "string_url")]
+pub(super) fn parse_url(s: &Column) -> ApolloResult<Column> {
+    let ca = s.str()?;
+    ca.str_parse_url().map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "string_url")]
+pub(super) fn url_query_param(s: &Column, name: &str) -> ApolloResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.str_url_query_param(name).into_column())
+}
+
+#[cfg(feature = "string_ip")]
+pub(super) fn ipv4_to_int(s: &Column) -> ApolloResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.str_ipv4_to_int().into_column())
+}
+
+#[cfg(feature = "string_ip")]
+pub(super) fn ipv6_to_int(s: &Column) -> ApolloResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.str_ipv6_to_int().into_column())
+}
+
+#[cfg(feature = "string_ip")]
+pub(super) fn ip_to_binary(s: &Column) -> ApolloResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.str_ip_to_binary().into_column())
+}
+
+#[cfg(feature = "string_ip")]
+pub(super) fn ip_in_cidr(s: &[Column]) -> ApolloResult<Column> {
+    _check_same_length(s, "ip_in_cidr")?;
+    let ca = s[0].str()?;
+    let cidr = s[1].str()?;
+    ca.str_ip_in_cidr(cidr).map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "string_ip")]
+pub(super) fn ip_mask(s: &Column, ipv4_prefix: u8, ipv6_prefix: u8) -> ApolloResult<Column> {
+    let ca = s.str()?;
+    ca.str_ip_mask(ipv4_prefix, ipv6_prefix)
+        .map(|ca| ca.into_column())
+}
+
 #[cfg(feature = "string_to_integer")]
 pub(super) fn to_integer(
     s: &[Column],
@@ -852,11 +997,17 @@ pub(super) fn to_decimal(s: &Column, scale: usize) -> ApolloResult<Column> {
         .map(Column::from)
 }
 
+#[cfg(feature = "dtype-interval")]
+pub(super) fn to_interval(s: &Column, strict: bool) -> ApolloResult<Column> {
+    let ca = s.str()?;
+    apollo_time::str_to_interval(ca, strict).map(|ca| ca.into_column())
+}
+
 #[cfg(feature = "extract_jsonpath")]
 pub(super) fn json_decode(s: &Column, dtype: DataType) -> ApolloResult<Column> {
     use apollo_ops::prelude::Utf8JsonPathImpl;
 
-    let ca = s.str()?;
+    let ca = json_str(s)?;
     ca.json_decode(Some(dtype), None).map(Column::from)
 }
 
@@ -865,11 +1016,21 @@ pub(super) fn json_path_match(s: &[Column]) -> ApolloResult<Column> {
     use apollo_ops::prelude::Utf8JsonPathImpl;
 
     _check_same_length(s, "json_path_match")?;
-    let ca = s[0].str()?;
+    let ca = json_str(&s[0])?;
     let pat = s[1].str()?;
     Ok(ca.json_path_match(pat)?.into_column())
 }
 
+/// The strings of a String column, or the documents of a JSON extension column.
+#[cfg(feature = "extract_jsonpath")]
+fn json_str(s: &Column) -> ApolloResult<&StringChunked> {
+    if s.dtype().is_json() {
+        s.as_materialized_series().to_storage().str()
+    } else {
+        s.str()
+    }
+}
+
 #[cfg(feature = "regex")]
 pub(super) fn escape_regex(s: &Column) -> ApolloResult<Column> {
     let ca = s.str()?;
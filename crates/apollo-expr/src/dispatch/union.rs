@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use apollo_core::error::ApolloResult;
+use apollo_core::prelude::{Column, IntoColumn};
+use apollo_plan::dsl::{ColumnsUdf, SpecialEq};
+use apollo_plan::plans::IRUnionFunction;
+use apollo_utils::pl_str::PlSmallStr;
+
+pub fn function_expr_to_udf(func: IRUnionFunction) -> SpecialEq<Arc<dyn ColumnsUdf>> {
+    use IRUnionFunction::*;
+    match func {
+        Tag => map!(tag),
+        Field(name) => map!(field, &name),
+    }
+}
+
+fn tag(c: &Column) -> ApolloResult<Column> {
+    Ok(c.union()?.type_ids().into_column())
+}
+
+fn field(c: &Column, name: &PlSmallStr) -> ApolloResult<Column> {
+    c.union()?.member(name).map(Column::from)
+}
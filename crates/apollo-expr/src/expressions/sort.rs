@@ -67,7 +67,7 @@ impl PhysicalExpr for SortExpr {
                 ac.with_values(out.into_column(), true, Some(&self.expr))?;
             },
             _ => {
-                let series = ac.flat_naive().into_owned();
+                let series = ac.flat_naive().to_comparison_key()?;
 
                 let mut sort_options = self.options;
                 sort_options.multithreaded = false;
@@ -59,7 +59,7 @@ pub(super) fn update_groups_sort_by(
     options: &SortOptions,
 ) -> ApolloResult<GroupsType> {
     // Will trigger a gather for every group, so rechunk before.
-    let sort_by_s = sort_by_s.rechunk();
+    let sort_by_s = sort_by_s.to_comparison_key()?.rechunk();
     let groups = POOL.install(|| {
         groups
             .par_iter()
@@ -119,7 +119,7 @@ fn sort_by_groups_no_match_single<'a>(
             .map(|(opt_s, s_sort_by)| match (opt_s, s_sort_by) {
                 (Some(s), Some(s_sort_by)) => {
                     apollo_ensure!(s.len() == s_sort_by.len(), ComputeError: "series lengths don't match in 'sort_by' expression");
-                    let idx = s_sort_by.arg_sort(SortOptions {
+                    let idx = s_sort_by.to_comparison_key()?.arg_sort(SortOptions {
                         descending,
                         // We are already in par iter.
                         multithreaded: false,
@@ -209,7 +209,9 @@ impl PhysicalExpr for SortByExpr {
         let (series, sorted_idx) = if self.by.len() == 1 {
             let sorted_idx_f = || {
                 let s_sort_by = self.by[0].evaluate(df, state)?;
-                Ok(s_sort_by.arg_sort(SortOptions::from(&self.sort_options)))
+                Ok(s_sort_by
+                    .to_comparison_key()?
+                    .arg_sort(SortOptions::from(&self.sort_options)))
             };
             POOL.install(|| rayon::join(series_f, sorted_idx_f))
         } else {
@@ -225,11 +227,12 @@ impl PhysicalExpr for SortByExpr {
                     .iter()
                     .enumerate()
                     .map(|(i, e)| {
-                        let column = e.evaluate(df, state).map(|c| match c.dtype() {
+                        let column = e.evaluate(df, state)?.to_comparison_key()?;
+                        let column = match column.dtype() {
                             #[cfg(feature = "dtype-categorical")]
-                            DataType::Categorical(_, _) | DataType::Enum(_, _) => c,
-                            _ => c.to_physical_repr(),
-                        })?;
+                            DataType::Categorical(_, _) | DataType::Enum(_, _) => column,
+                            _ => column.to_physical_repr(),
+                        };
 
                         if column.len() == 1 && broadcast_length != 1 {
                             apollo_ensure!(
@@ -0,0 +1,70 @@
+use apollo_compute::tdigest::TDigest;
+use apollo_ops::series::{approx_quantile_dtype, check_approx_quantile_args, tdigest_extend};
+
+use super::*;
+
+pub fn new_approx_quantile_reduction(
+    dtype: DataType,
+    quantile: f64,
+    accuracy: u32,
+) -> ApolloResult<Box<dyn GroupedReduction>> {
+    check_approx_quantile_args(&dtype, quantile, accuracy)?;
+    Ok(match dtype {
+        DataType::Null => Box::new(super::NullGroupedReduction::new(Scalar::null(
+            DataType::Float64,
+        ))),
+        _ => Box::new(VecGroupedReduction::new(
+            dtype,
+            ApproxQuantileReducer { quantile, accuracy },
+        )),
+    })
+}
+
+/// Collects the values of each group in a t-digest, which can be merged across morsels.
+#[derive(Clone)]
+struct ApproxQuantileReducer {
+    quantile: f64,
+    accuracy: u32,
+}
+
+impl Reducer for ApproxQuantileReducer {
+    type Dtype = Float64Type;
+    type Value = TDigest;
+
+    fn init(&self) -> Self::Value {
+        TDigest::new(self.accuracy)
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        Cow::Owned(s.cast(&DataType::Float64).unwrap())
+    }
+
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        a.merge(b);
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<f64>, _seq_id: u64) {
+        if let Some(b) = b {
+            a.add(b);
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &Float64Chunked, _seq_id: u64) {
+        tdigest_extend(v, ca);
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        dtype: &DataType,
+    ) -> ApolloResult<Series> {
+        assert!(m.is_none());
+        let ca: Float64Chunked = v
+            .into_iter()
+            .map(|mut digest| digest.quantile(self.quantile))
+            .collect_ca(PlSmallStr::EMPTY);
+        ca.into_series().cast(&approx_quantile_dtype(dtype))
+    }
+}
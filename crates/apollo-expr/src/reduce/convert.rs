@@ -6,6 +6,8 @@ use super::*;
 use crate::reduce::any_all::{new_all_reduction, new_any_reduction};
 #[cfg(feature = "approx_unique")]
 use crate::reduce::approx_n_unique::new_approx_n_unique_reduction;
+#[cfg(feature = "approx_quantile")]
+use crate::reduce::approx_quantile::new_approx_quantile_reduction;
 #[cfg(feature = "bitwise")]
 use crate::reduce::bitwise::{
     new_bitwise_and_reduction, new_bitwise_or_reduction, new_bitwise_xor_reduction,
@@ -123,6 +125,18 @@ pub fn into_reduction(
             (out, input)
         },
 
+        #[cfg(feature = "approx_quantile")]
+        AExpr::Function {
+            input: inner_exprs,
+            function: IRFunctionExpr::ApproxQuantile { quantile, accuracy },
+            options: _,
+        } => {
+            assert!(inner_exprs.len() == 1);
+            let input = inner_exprs[0].node();
+            let out = new_approx_quantile_reduction(get_dt(input)?, *quantile, *accuracy)?;
+            (out, input)
+        },
+
         #[cfg(feature = "bitwise")]
         AExpr::Function {
             input: inner_exprs,
@@ -0,0 +1,164 @@
+//! Block-level access to Avro object container files, used by the streaming engine.
+use std::io::Cursor;
+use std::sync::Arc;
+
+use apollo_buffer::Buffer;
+use arrow::datatypes::ArrowSchemaRef;
+pub use arrow::io::avro::avro_schema::file::Block as AvroBlock;
+use arrow::io::avro::avro_schema::file::{Block, FileMetadata};
+use arrow::io::avro::avro_schema::read::BlockStreamingIterator;
+use arrow::io::avro::avro_schema::read::fallible_streaming_iterator::FallibleStreamingIterator;
+use arrow::io::avro::{self, read};
+use apollo_core::error::to_compute_err;
+use apollo_core::prelude::*;
+
+/// Size of the sync marker that terminates every data block.
+const SYNC_MARKER_SIZE: usize = 16;
+
+/// Metadata of an Avro object container file.
+#[derive(Debug, Clone)]
+pub struct AvroFileMetadata {
+    pub metadata: FileMetadata,
+    pub arrow_schema: ArrowSchemaRef,
+    /// Byte offset of the first data block.
+    pub data_offset: usize,
+}
+
+impl AvroFileMetadata {
+    /// Reads the header of the Avro file contained in `bytes`.
+    pub fn try_from_bytes(bytes: &[u8]) -> ApolloResult<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let metadata =
+            avro::avro_schema::read::read_metadata(&mut cursor).map_err(to_compute_err)?;
+        let arrow_schema = Arc::new(read::infer_schema(&metadata.record)?);
+
+        Ok(Self {
+            metadata,
+            arrow_schema,
+            data_offset: cursor.position() as usize,
+        })
+    }
+
+    /// Builds a projection mask over the file columns from the given column names.
+    pub fn projection_mask<'a>(&self, columns: impl IntoIterator<Item = &'a str>) -> Vec<bool> {
+        let mut mask = vec![false; self.arrow_schema.len()];
+
+        for name in columns {
+            if let Some(idx) = self.arrow_schema.index_of(name) {
+                mask[idx] = true;
+            }
+        }
+
+        mask
+    }
+
+    /// Decodes a decompressed block into a [`DataFrame`] holding the projected columns.
+    pub fn decode_block(&self, block: &Block, projection: &[bool]) -> ApolloResult<DataFrame> {
+        let batch = read::deserialize(
+            block,
+            &self.arrow_schema,
+            &self.metadata.record.fields,
+            projection,
+        )?;
+
+        Ok(DataFrame::from(batch))
+    }
+}
+
+/// Iterates the decompressed data blocks of an Avro file.
+pub struct AvroBlockReader {
+    inner: BlockStreamingIterator<Cursor<Buffer<u8>>>,
+}
+
+impl AvroBlockReader {
+    /// `full_bytes` must contain the entire file that `metadata` was read from.
+    pub fn new(full_bytes: Buffer<u8>, metadata: &AvroFileMetadata) -> Self {
+        let data = full_bytes.sliced(metadata.data_offset..);
+
+        Self {
+            inner: avro::avro_schema::read::block_iterator(
+                Cursor::new(data),
+                metadata.metadata.compression,
+                metadata.metadata.marker,
+            ),
+        }
+    }
+
+    /// Returns the next decompressed block, or `None` once the file is exhausted.
+    pub fn next_block(&mut self) -> ApolloResult<Option<&AvroBlock>> {
+        self.inner.next().map_err(to_compute_err)
+    }
+}
+
+/// Count the number of rows. The slice passed must represent the entire file.
+///
+/// Only the block headers are read, the block data is neither decompressed nor decoded.
+pub fn count_rows(full_bytes: &[u8], metadata: &AvroFileMetadata) -> ApolloResult<usize> {
+    let mut offset = metadata.data_offset;
+    let mut num_rows: usize = 0;
+
+    while offset < full_bytes.len() {
+        let block_rows = read_zigzag_long(full_bytes, &mut offset)?;
+        let block_bytes = read_zigzag_long(full_bytes, &mut offset)?;
+
+        apollo_ensure!(
+            block_rows >= 0 && block_bytes >= 0,
+            ComputeError: "invalid avro block header at byte offset {}", offset
+        );
+
+        num_rows = num_rows.saturating_add(block_rows as usize);
+        offset = offset
+            .saturating_add(block_bytes as usize)
+            .saturating_add(SYNC_MARKER_SIZE);
+    }
+
+    Ok(num_rows)
+}
+
+/// Reads a zigzag-encoded variable-length `long` as defined by the Avro specification.
+fn read_zigzag_long(bytes: &[u8], offset: &mut usize) -> ApolloResult<i64> {
+    let mut value: u64 = 0;
+    let mut shift: u32 = 0;
+
+    loop {
+        let Some(&byte) = bytes.get(*offset) else {
+            apollo_bail!(ComputeError: "unexpected end of avro file")
+        };
+        *offset += 1;
+
+        value |= u64::from(byte & 0x7F) << shift;
+
+        if byte & 0x80 == 0 {
+            break;
+        }
+
+        shift += 7;
+        apollo_ensure!(shift < 64, ComputeError: "zigzag decoding failed - corrupt avro file");
+    }
+
+    Ok(((value >> 1) as i64) ^ -((value & 1) as i64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::read_zigzag_long;
+
+    #[test]
+    fn test_read_zigzag_long() {
+        // Examples from the Avro specification.
+        for (bytes, expected) in [
+            (&[0x00][..], 0),
+            (&[0x01], -1),
+            (&[0x02], 1),
+            (&[0x7F], -64),
+            (&[0x80, 0x01], 64),
+        ] {
+            let mut offset = 0;
+            assert_eq!(read_zigzag_long(bytes, &mut offset).unwrap(), expected);
+            assert_eq!(offset, bytes.len());
+        }
+
+        let mut offset = 0;
+        assert!(read_zigzag_long(&[0x80], &mut offset).is_err());
+    }
+}
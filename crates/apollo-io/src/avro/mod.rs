@@ -1,5 +1,7 @@
+mod blocks;
 mod read;
 mod write;
 
+pub use blocks::*;
 pub use read::*;
 pub use write::*;
@@ -1,14 +1,46 @@
 use std::io::Write;
 
-pub use Compression as AvroCompression;
 pub use arrow::io::avro::avro_schema::file::Compression;
+use arrow::io::avro::avro_schema::file::{Block, CompressedBlock};
+use arrow::io::avro::avro_schema::schema::Record;
 use arrow::io::avro::avro_schema::{self};
 use arrow::io::avro::write;
 use apollo_core::error::to_compute_err;
 use apollo_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::shared::{SerWriter, schema_to_arrow_checked};
 
+/// Compression codec of the data blocks in an Avro object container file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum AvroCompression {
+    Deflate,
+    Snappy,
+}
+
+impl From<AvroCompression> for Compression {
+    fn from(value: AvroCompression) -> Self {
+        match value {
+            AvroCompression::Deflate => Compression::Deflate,
+            AvroCompression::Snappy => Compression::Snappy,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct AvroWriterOptions {
+    /// Compression codec of the data blocks.
+    pub compression: Option<AvroCompression>,
+    /// Name of the top-level Avro record.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub name: PlSmallStr,
+}
+
 /// Write a [`DataFrame`] to [Apache Avro] format
 ///
 /// [Apache Avro]: https://avro.apache.org
@@ -64,37 +96,91 @@ where
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> ApolloResult<()> {
-        let schema = schema_to_arrow_checked(df.schema(), CompatLevel::oldest(), "avro")?;
-        let record = write::to_record(&schema, self.name.clone())?;
+        let mut encoder = AvroBlockEncoder::try_new(df.schema(), &self.name, self.compression)?;
+
+        let mut header = vec![];
+        encoder.write_header(&mut header)?;
+        self.writer.write_all(&header)?;
 
         let mut data = vec![];
-        let mut compressed_block = avro_schema::file::CompressedBlock::default();
+        encoder.encode(df, &mut data)?;
+        self.writer.write_all(&data)?;
+
+        Ok(())
+    }
+}
+
+/// Encodes [`DataFrame`]s into the data blocks of an Avro object container file.
+///
+/// The header is written separately through [`AvroBlockEncoder::write_header`], so that blocks can
+/// be encoded independently of each other (e.g. in parallel) and concatenated afterwards.
+#[derive(Clone)]
+pub struct AvroBlockEncoder {
+    record: Record,
+    compression: Option<AvroCompression>,
+    block: Block,
+    compressed_block: CompressedBlock,
+}
+
+impl AvroBlockEncoder {
+    pub fn try_new(
+        schema: &Schema,
+        name: &str,
+        compression: Option<AvroCompression>,
+    ) -> ApolloResult<Self> {
+        let arrow_schema = schema_to_arrow_checked(schema, CompatLevel::oldest(), "avro")?;
+        let record = write::to_record(&arrow_schema, name.to_string())?;
+
+        Ok(Self {
+            record,
+            compression,
+            block: Block::default(),
+            compressed_block: CompressedBlock::default(),
+        })
+    }
+
+    /// Writes the file header (magic bytes, schema, codec and sync marker).
+    pub fn write_header<W: Write>(&self, writer: &mut W) -> ApolloResult<()> {
+        avro_schema::write::write_metadata(
+            writer,
+            self.record.clone(),
+            self.compression.map(Into::into),
+        )
+        .map_err(to_compute_err)
+    }
+
+    /// Encodes every chunk of `df` as a separate data block, appending the blocks to `out`.
+    pub fn encode(&mut self, df: &DataFrame, out: &mut Vec<u8>) -> ApolloResult<()> {
+        let compression = self.compression.map(Into::into);
+
         for chunk in df.iter_chunks(CompatLevel::oldest(), true) {
+            if chunk.is_empty() {
+                continue;
+            }
+
             let mut serializers = chunk
                 .iter()
-                .zip(record.fields.iter())
+                .zip(self.record.fields.iter())
                 .map(|(array, field)| write::new_serializer(array.as_ref(), &field.schema))
                 .collect::<Vec<_>>();
 
-            let mut block =
-                avro_schema::file::Block::new(chunk.arrays()[0].len(), std::mem::take(&mut data));
-            write::serialize(&mut serializers, &mut block);
-            let _was_compressed =
-                avro_schema::write::compress(&mut block, &mut compressed_block, self.compression)
-                    .map_err(to_compute_err)?;
+            self.block.number_of_rows = chunk.len();
+            write::serialize(&mut serializers, &mut self.block);
 
-            avro_schema::write::write_metadata(&mut self.writer, record.clone(), self.compression)
-                .map_err(to_compute_err)?;
+            let _was_compressed = avro_schema::write::compress(
+                &mut self.block,
+                &mut self.compressed_block,
+                compression,
+            )
+            .map_err(to_compute_err)?;
 
-            avro_schema::write::write_block(&mut self.writer, &compressed_block)
+            avro_schema::write::write_block(out, &self.compressed_block)
                 .map_err(to_compute_err)?;
-            // reuse block for next iteration.
-            data = block.data;
-            data.clear();
 
-            // reuse block for next iteration
-            compressed_block.data.clear();
-            compressed_block.number_of_rows = 0
+            // Reuse the allocations for the next chunk.
+            self.block.data.clear();
+            self.compressed_block.data.clear();
+            self.compressed_block.number_of_rows = 0;
         }
 
         Ok(())
@@ -57,6 +57,15 @@ impl BlockingCloudWriter {
     ) -> ApolloResult<Self> {
         let (cloud_location, object_store) =
             crate::cloud::build_object_store(uri, cloud_options, false).await?;
+
+        // Buffer the whole file for stores that only accept single-request uploads, such as
+        // HTTP (WebDAV) servers.
+        let cloud_upload_chunk_size = if object_store.supports_multipart_upload() {
+            cloud_upload_chunk_size
+        } else {
+            usize::MAX
+        };
+
         Self::new_with_object_store(
             object_store.to_dyn_object_store().await,
             object_path_from_str(&cloud_location.prefix)?,
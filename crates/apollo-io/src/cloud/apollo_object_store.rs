@@ -9,6 +9,7 @@ use apollo_core::prelude::{InitHashMaps, PlHashMap};
 use apollo_error::{ApolloError, ApolloResult};
 use tokio::io::{AsyncSeekExt, AsyncWriteExt};
 
+use super::block_cache::{BlockCache, get_block_cache};
 use crate::pl_async::{
     self, MAX_BUDGET_PER_REQUEST, get_concurrency_limit, get_download_chunk_size,
     tune_with_concurrency_budget, with_concurrency_budget,
@@ -91,6 +92,19 @@ mod inner {
             Ok((*current_store).clone())
         }
 
+        pub(crate) fn is_local(&self) -> bool {
+            self.inner.builder.is_local()
+        }
+
+        pub(crate) fn supports_multipart_upload(&self) -> bool {
+            self.inner.builder.supports_multipart_upload()
+        }
+
+        /// The part of the URL that object paths of this store are relative to.
+        pub(crate) fn url_base(&self) -> &str {
+            self.inner.builder.url_base()
+        }
+
         pub async fn try_exec_rebuild_on_err<Fn, Fut, O>(&self, mut func: Fn) -> ApolloResult<O>
         where
             Fn: FnMut(&Arc<dyn ObjectStore>) -> Fut,
@@ -144,11 +158,13 @@ pub use inner::ApolloObjectStore;
 pub type ObjectStorePath = object_store::path::Path;
 
 impl ApolloObjectStore {
-    /// Returns a buffered stream that downloads concurrently up to the concurrency limit.
+    /// Returns a buffered stream that downloads concurrently up to the concurrency limit. If
+    /// `e_tag` is given, the requests fail if the object no longer has that ETag.
     fn get_buffered_ranges_stream<'a, T: Iterator<Item = Range<usize>>>(
         store: &'a dyn ObjectStore,
         path: &'a Path,
         ranges: T,
+        e_tag: Option<&'a str>,
     ) -> impl StreamExt<Item = ApolloResult<Buffer<u8>>>
     + TryStreamExt<Ok = Buffer<u8>, Error = ApolloError, Item = ApolloResult<Buffer<u8>>>
     + use<'a, T> {
@@ -157,20 +173,55 @@ impl ApolloObjectStore {
                 return Ok(Buffer::new());
             }
 
-            let out = store
-                .get_range(path, range.start as u64..range.end as u64)
-                .await?;
+            let range = range.start as u64..range.end as u64;
+
+            let out = match e_tag {
+                None => store.get_range(path, range).await?,
+                Some(e_tag) => {
+                    store
+                        .get_opts(
+                            path,
+                            object_store::GetOptions {
+                                if_match: Some(e_tag.to_string()),
+                                range: Some(range.into()),
+                                ..Default::default()
+                            },
+                        )
+                        .await?
+                        .bytes()
+                        .await?
+                },
+            };
             Ok(Buffer::from_owner(out))
         }))
         // Add a limit locally as this gets run inside a single `tune_with_concurrency_budget`.
         .buffered(get_concurrency_limit() as usize)
     }
 
+    /// Returns the block cache if it is enabled and applies to this store.
+    fn block_cache(&self) -> Option<&'static BlockCache> {
+        get_block_cache().filter(|_| !self.is_local())
+    }
+
     pub async fn get_range(&self, path: &Path, range: Range<usize>) -> ApolloResult<Buffer<u8>> {
         if range.is_empty() {
             return Ok(Buffer::new());
         }
 
+        if let Some(cache) = self.block_cache()
+            && let Some(out) = cache.get_range(self, path, range.clone()).await?
+        {
+            return Ok(out);
+        }
+
+        self.get_range_uncached(path, range).await
+    }
+
+    async fn get_range_uncached(
+        &self,
+        path: &Path,
+        range: Range<usize>,
+    ) -> ApolloResult<Buffer<u8>> {
         self.try_exec_rebuild_on_err(move |store| {
             let range = range.clone();
             let st = store.clone();
@@ -193,7 +244,7 @@ impl ApolloObjectStore {
                     let parts = tune_with_concurrency_budget(
                         parts.len().clamp(0, MAX_BUDGET_PER_REQUEST) as u32,
                         || {
-                            Self::get_buffered_ranges_stream(&store, path, parts)
+                            Self::get_buffered_ranges_stream(&store, path, parts, None)
                                 .try_collect::<Vec<Buffer<u8>>>()
                         },
                     )
@@ -230,6 +281,29 @@ impl ApolloObjectStore {
 
         ranges.sort_unstable_by_key(|x| x.start);
 
+        if let Some(cache) = self.block_cache()
+            && let Some(out) = cache.get_ranges(self, path, ranges).await?
+        {
+            return Ok(out);
+        }
+
+        self.get_ranges_sort_uncached(path, ranges, None).await
+    }
+
+    /// [`Self::get_ranges_sort`], bypassing the block cache. If `e_tag` is given, this fails if
+    /// the object no longer has that ETag.
+    pub(super) async fn get_ranges_sort_uncached(
+        &self,
+        path: &Path,
+        ranges: &mut [Range<usize>],
+        e_tag: Option<&str>,
+    ) -> ApolloResult<PlHashMap<usize, Buffer<u8>>> {
+        if ranges.is_empty() {
+            return Ok(Default::default());
+        }
+
+        ranges.sort_unstable_by_key(|x| x.start);
+
         let ranges_len = ranges.len();
         let (merged_ranges, merged_ends): (Vec<_>, Vec<_>) = merge_ranges(ranges).unzip();
 
@@ -240,8 +314,12 @@ impl ApolloObjectStore {
                 let store = st;
                 let mut out = PlHashMap::with_capacity(ranges_len);
 
-                let mut stream =
-                    Self::get_buffered_ranges_stream(&store, path, merged_ranges.iter().cloned());
+                let mut stream = Self::get_buffered_ranges_stream(
+                    &store,
+                    path,
+                    merged_ranges.iter().cloned(),
+                    e_tag,
+                );
 
                 tune_with_concurrency_budget(
                     merged_ranges.len().clamp(0, MAX_BUDGET_PER_REQUEST) as u32,
@@ -338,7 +416,8 @@ impl ApolloObjectStore {
                     tune_with_concurrency_budget(
                         parts.len().clamp(0, MAX_BUDGET_PER_REQUEST) as u32,
                         || async {
-                            let mut stream = Self::get_buffered_ranges_stream(&store, path, parts);
+                            let mut stream =
+                                Self::get_buffered_ranges_stream(&store, path, parts, None);
                             let mut len = 0;
                             while let Some(bytes) = stream.try_next().await? {
                                 len += bytes.len();
@@ -413,6 +492,42 @@ impl ApolloObjectStore {
         })
         .await
     }
+
+    /// Moves the object at `from` to `to`, replacing any existing object at `to`.
+    pub async fn rename(&self, from: &Path, to: &Path) -> ApolloResult<()> {
+        self.try_exec_rebuild_on_err(|store| {
+            let st = store.clone();
+
+            async {
+                with_concurrency_budget(1, || async {
+                    let store = st;
+                    store.rename(from, to).await?;
+                    Ok(())
+                })
+                .await
+            }
+        })
+        .await
+    }
+
+    /// Deletes the object at `path`. Deleting an object that doesn't exist is not an error.
+    pub async fn delete(&self, path: &Path) -> ApolloResult<()> {
+        self.try_exec_rebuild_on_err(|store| {
+            let st = store.clone();
+
+            async {
+                with_concurrency_budget(1, || async {
+                    let store = st;
+                    match store.delete(path).await {
+                        Err(object_store::Error::NotFound { .. }) => Ok(()),
+                        v => Ok(v?),
+                    }
+                })
+                .await
+            }
+        })
+        .await
+    }
 }
 
 /// Splits a single range into multiple smaller ranges, which can be downloaded concurrently for
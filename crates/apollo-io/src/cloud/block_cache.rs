@@ -0,0 +1,642 @@
+//! Local-disk cache for byte ranges of remote objects.
+//!
+//! Objects are split into fixed-size blocks, which are stored as individual files keyed by the
+//! object URL, its ETag and the block index. A changed object gets a new ETag, so stale blocks
+//! are never served and simply age out of the cache. Missing blocks are fetched on the condition
+//! that the object still has the ETag, so blocks of a newer version are never stored under the
+//! key of an older one. Objects without an ETag, such as files on SFTP servers, are never cached.
+//! Blocks are evicted in least-recently-used order once the cache exceeds its size budget.
+//!
+//! The cache is configured through environment variables:
+//! * `APOLLO_BLOCK_CACHE_SIZE`: Size budget in bytes. The cache is disabled if this is unset or 0.
+//! * `APOLLO_BLOCK_CACHE_BLOCK_SIZE`: Block size in bytes, defaults to 4 MiB.
+//! * `APOLLO_BLOCK_CACHE_DIR`: Cache directory, defaults to `{APOLLO_TEMP_DIR}/block-cache/`.
+//! * `APOLLO_BLOCK_CACHE_METADATA_TTL`: Number of seconds for which the ETag of an object is
+//!   reused before it is requested again, defaults to 10.
+//!
+//! The cache directory can be shared between processes, but every process enforces the size
+//! budget only for the blocks it knows about.
+use std::ops::Range;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use apollo_buffer::Buffer;
+use apollo_core::config;
+use apollo_core::prelude::{InitHashMaps, PlHashMap};
+use apollo_error::ApolloResult;
+use apollo_utils::cache::LruCache;
+
+use super::{ApolloObjectStore, ObjectStorePath};
+use crate::path_utils::{APOLLO_TEMP_DIR_BASE_PATH, ensure_directory_init};
+
+static BLOCK_CACHE: LazyLock<Option<BlockCache>> = LazyLock::new(|| {
+    let max_bytes = get_env_u64("APOLLO_BLOCK_CACHE_SIZE").unwrap_or(0);
+
+    if max_bytes == 0 {
+        return None;
+    }
+
+    let block_size = get_env_u64("APOLLO_BLOCK_CACHE_BLOCK_SIZE").unwrap_or(4 * 1024 * 1024);
+    let metadata_ttl = get_env_u64("APOLLO_BLOCK_CACHE_METADATA_TTL").unwrap_or(10);
+    let dir = std::env::var("APOLLO_BLOCK_CACHE_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| APOLLO_TEMP_DIR_BASE_PATH.join("block-cache/"));
+
+    if config::verbose() {
+        eprintln!(
+            "block cache: dir: {}, max_bytes: {max_bytes}, block_size: {block_size}, metadata_ttl: {metadata_ttl}",
+            dir.display()
+        );
+    }
+
+    match BlockCache::try_new(
+        dir,
+        block_size as usize,
+        max_bytes,
+        Duration::from_secs(metadata_ttl),
+    ) {
+        Ok(v) => Some(v),
+        Err(err) => panic!("failed to initialize block cache: err = {err}"),
+    }
+});
+
+fn get_env_u64(name: &str) -> Option<u64> {
+    std::env::var(name).ok().map(|x| {
+        x.parse()
+            .unwrap_or_else(|_| panic!("{name} must be an integer"))
+    })
+}
+
+/// Returns the block cache if it was enabled.
+pub(super) fn get_block_cache() -> Option<&'static BlockCache> {
+    BLOCK_CACHE.as_ref()
+}
+
+pub(super) struct BlockCache {
+    dir: PathBuf,
+    block_size: usize,
+    max_bytes: u64,
+    metadata_ttl: Duration,
+    index: Mutex<BlockIndex>,
+    /// Object versions keyed by URL, along with the time they were fetched.
+    versions: Mutex<PlHashMap<String, (Instant, Option<ObjectVersion>)>>,
+    tmp_counter: AtomicU64,
+}
+
+/// Tracks the blocks on disk in least-recently-used order.
+struct BlockIndex {
+    /// File name -> size in bytes.
+    blocks: LruCache<String, u64>,
+    num_blocks: usize,
+    max_blocks: usize,
+    num_bytes: u64,
+}
+
+#[derive(Clone)]
+struct ObjectVersion {
+    url: String,
+    e_tag: String,
+    size: usize,
+}
+
+impl BlockIndex {
+    /// Marks a block as used, registering it if it isn't known yet. Returns the names of the
+    /// blocks that should be evicted.
+    fn insert(&mut self, name: String, size: u64, max_bytes: u64) -> Vec<String> {
+        if self.blocks.get(&name).is_some() {
+            return vec![];
+        }
+
+        let mut evicted = vec![];
+
+        while self.num_blocks > 0
+            && (self.num_bytes + size > max_bytes || self.num_blocks >= self.max_blocks)
+        {
+            let (name, size) = self.blocks.pop_lru().unwrap();
+            self.num_blocks -= 1;
+            self.num_bytes -= size;
+            evicted.push(name);
+        }
+
+        self.blocks.insert(name, size);
+        self.num_blocks += 1;
+        self.num_bytes += size;
+
+        evicted
+    }
+}
+
+impl BlockCache {
+    /// Initializes a cache in `dir`, registering the blocks that are already in the directory.
+    pub(super) fn try_new(
+        dir: PathBuf,
+        block_size: usize,
+        max_bytes: u64,
+        metadata_ttl: Duration,
+    ) -> ApolloResult<Self> {
+        assert!(block_size > 0);
+
+        ensure_directory_init(&dir)?;
+
+        // Limits the size of the index, as the last block of an object can be arbitrarily small.
+        let max_blocks = 4 * usize::try_from(max_bytes.div_ceil(block_size as u64))
+            .unwrap_or(usize::MAX / 8)
+            .max(1);
+
+        let mut existing = vec![];
+
+        for entry in std::fs::read_dir(&dir)? {
+            let entry = entry?;
+            let Ok(name) = entry.file_name().into_string() else {
+                continue;
+            };
+            let metadata = entry.metadata()?;
+
+            if name.ends_with(".tmp") {
+                // Left behind by an interrupted write.
+                let _ = std::fs::remove_file(entry.path());
+                continue;
+            }
+
+            existing.push((metadata.modified()?, name, metadata.len()));
+        }
+
+        existing.sort_unstable();
+
+        let mut index = BlockIndex {
+            blocks: LruCache::with_capacity(max_blocks),
+            num_blocks: 0,
+            max_blocks,
+            num_bytes: 0,
+        };
+
+        for (_, name, size) in existing {
+            for name in index.insert(name, size, max_bytes) {
+                let _ = std::fs::remove_file(dir.join(name));
+            }
+        }
+
+        Ok(Self {
+            dir,
+            block_size,
+            max_bytes,
+            metadata_ttl,
+            index: Mutex::new(index),
+            versions: Mutex::new(PlHashMap::new()),
+            tmp_counter: AtomicU64::new(0),
+        })
+    }
+
+    /// Returns `None` if the object can't be cached, in which case the range should be fetched
+    /// directly.
+    pub(super) async fn get_range(
+        &self,
+        store: &ApolloObjectStore,
+        path: &ObjectStorePath,
+        range: Range<usize>,
+    ) -> ApolloResult<Option<Buffer<u8>>> {
+        let Some(version) = self.object_version(store, path).await? else {
+            return Ok(None);
+        };
+
+        if range.end > version.size {
+            return Ok(None);
+        }
+
+        let Some(blocks) = self
+            .get_blocks(store, path, &version, self.block_indices(&range).collect())
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(self.slice_blocks(&blocks, range)))
+    }
+
+    /// Returns `None` if the object can't be cached, in which case the ranges should be fetched
+    /// directly. The output is keyed by the range start, as in
+    /// [`ApolloObjectStore::get_ranges_sort`].
+    pub(super) async fn get_ranges(
+        &self,
+        store: &ApolloObjectStore,
+        path: &ObjectStorePath,
+        ranges: &[Range<usize>],
+    ) -> ApolloResult<Option<PlHashMap<usize, Buffer<u8>>>> {
+        let Some(version) = self.object_version(store, path).await? else {
+            return Ok(None);
+        };
+
+        if ranges.iter().any(|r| r.end > version.size) {
+            return Ok(None);
+        }
+
+        let mut block_indices: Vec<usize> =
+            ranges.iter().flat_map(|r| self.block_indices(r)).collect();
+        block_indices.sort_unstable();
+        block_indices.dedup();
+
+        let Some(blocks) = self
+            .get_blocks(store, path, &version, block_indices)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let mut out = PlHashMap::with_capacity(ranges.len());
+
+        for range in ranges {
+            let slice = self.slice_blocks(&blocks, range.clone());
+
+            // Keep the longest range for a given start.
+            if out
+                .get(&range.start)
+                .is_none_or(|v: &Buffer<u8>| v.len() < slice.len())
+            {
+                out.insert(range.start, slice);
+            }
+        }
+
+        Ok(Some(out))
+    }
+
+    fn block_indices(&self, range: &Range<usize>) -> Range<usize> {
+        if range.is_empty() {
+            return 0..0;
+        }
+
+        range.start / self.block_size..(range.end - 1) / self.block_size + 1
+    }
+
+    fn block_range(&self, object_size: usize, block_idx: usize) -> Range<usize> {
+        let start = block_idx * self.block_size;
+        start..(start + self.block_size).min(object_size)
+    }
+
+    fn block_name(&self, version: &ObjectVersion, block_idx: usize) -> String {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(version.url.as_bytes());
+        hasher.update(&[0]);
+        hasher.update(version.e_tag.as_bytes());
+        hasher.update(&[0]);
+        hasher.update(&(block_idx as u64).to_le_bytes());
+
+        hasher.finalize().to_hex()[..32].to_string()
+    }
+
+    /// Assembles `range` from the blocks that contain it.
+    fn slice_blocks(
+        &self,
+        blocks: &PlHashMap<usize, Buffer<u8>>,
+        range: Range<usize>,
+    ) -> Buffer<u8> {
+        let block_indices = self.block_indices(&range);
+
+        if block_indices.len() == 1 {
+            let offset = block_indices.start * self.block_size;
+            return blocks[&block_indices.start]
+                .clone()
+                .sliced(range.start - offset..range.end - offset);
+        }
+
+        let mut out = Vec::with_capacity(range.len());
+
+        for block_idx in block_indices {
+            let block_start = block_idx * self.block_size;
+            let block = &blocks[&block_idx];
+
+            let start = range.start.saturating_sub(block_start);
+            let end = (range.end - block_start).min(block.len());
+            out.extend_from_slice(&block[start..end]);
+        }
+
+        Buffer::from_vec(out)
+    }
+
+    /// Gets the ETag and size of the object, reusing them if they were fetched within the
+    /// metadata TTL. Returns `None` if the object has no ETag.
+    async fn object_version(
+        &self,
+        store: &ApolloObjectStore,
+        path: &ObjectStorePath,
+    ) -> ApolloResult<Option<ObjectVersion>> {
+        let url = format!("{}/{}", store.url_base(), path);
+
+        if let Some((fetched_at, version)) = self.versions.lock().unwrap().get(&url)
+            && fetched_at.elapsed() < self.metadata_ttl
+        {
+            return Ok(version.clone());
+        }
+
+        let meta = store.head(path).await?;
+
+        let version = meta.e_tag.map(|e_tag| ObjectVersion {
+            url: url.clone(),
+            e_tag,
+            size: meta.size as usize,
+        });
+
+        let mut versions = self.versions.lock().unwrap();
+
+        if versions.len() >= 4096 {
+            versions.clear();
+        }
+
+        versions.insert(url, (Instant::now(), version.clone()));
+
+        Ok(version)
+    }
+
+    /// Reads the blocks from disk, fetching and storing the ones that are missing. Returns `None`
+    /// if the object changed since `version` was fetched.
+    async fn get_blocks(
+        &self,
+        store: &ApolloObjectStore,
+        path: &ObjectStorePath,
+        version: &ObjectVersion,
+        block_indices: Vec<usize>,
+    ) -> ApolloResult<Option<PlHashMap<usize, Buffer<u8>>>> {
+        let names: Vec<String> = block_indices
+            .iter()
+            .map(|&i| self.block_name(version, i))
+            .collect();
+
+        let cached = futures::future::join_all(block_indices.iter().zip(&names).map(
+            |(&block_idx, name)| {
+                self.read_block(name, self.block_range(version.size, block_idx).len())
+            },
+        ))
+        .await;
+
+        let mut out = PlHashMap::with_capacity(block_indices.len());
+        let mut missing = vec![];
+
+        for ((block_idx, name), block) in block_indices.into_iter().zip(names).zip(cached) {
+            match block {
+                Some(block) => {
+                    out.insert(block_idx, block);
+                },
+                None => missing.push((block_idx, name)),
+            }
+        }
+
+        if config::verbose() {
+            eprintln!(
+                "block cache: path: {}, hits: {}, misses: {}",
+                path,
+                out.len(),
+                missing.len()
+            );
+        }
+
+        if missing.is_empty() {
+            return Ok(Some(out));
+        }
+
+        let mut ranges: Vec<Range<usize>> = missing
+            .iter()
+            .map(|(block_idx, _)| self.block_range(version.size, *block_idx))
+            .collect();
+
+        let mut fetched = match store
+            .get_ranges_sort_uncached(path, &mut ranges, Some(&version.e_tag))
+            .await
+        {
+            Ok(v) => v,
+            Err(err) => {
+                // A failed ETag precondition doesn't have a distinct error, so check whether the
+                // object changed and forget the outdated version if so.
+                self.versions.lock().unwrap().remove(&version.url);
+
+                if self
+                    .object_version(store, path)
+                    .await?
+                    .is_none_or(|v| v.e_tag != version.e_tag)
+                {
+                    if config::verbose() {
+                        eprintln!("block cache: path: {path}, object changed while reading");
+                    }
+
+                    return Ok(None);
+                }
+
+                return Err(err);
+            },
+        };
+
+        let missing: Vec<(usize, String, Buffer<u8>)> = missing
+            .into_iter()
+            .map(|(block_idx, name)| {
+                let block = fetched.remove(&(block_idx * self.block_size)).unwrap();
+                (block_idx, name, block)
+            })
+            .collect();
+
+        futures::future::join_all(
+            missing
+                .iter()
+                .map(|(_, name, block)| self.write_block(name, block)),
+        )
+        .await;
+
+        out.extend(
+            missing
+                .into_iter()
+                .map(|(block_idx, _, block)| (block_idx, block)),
+        );
+
+        Ok(Some(out))
+    }
+
+    /// Returns `None` if the block is not on disk.
+    async fn read_block(&self, name: &str, expected_len: usize) -> Option<Buffer<u8>> {
+        let bytes = tokio::fs::read(self.dir.join(name)).await.ok()?;
+
+        // Guard against blocks that were truncated.
+        if bytes.len() != expected_len {
+            return None;
+        }
+
+        self.register(name.to_string(), bytes.len() as u64).await;
+
+        Some(Buffer::from_vec(bytes))
+    }
+
+    /// Failing to store a block is not an error, it will be fetched again next time.
+    async fn write_block(&self, name: &str, block: &[u8]) {
+        let tmp_path = self.dir.join(format!(
+            "{name}.{}.{}.tmp",
+            std::process::id(),
+            self.tmp_counter.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        let result = async {
+            tokio::fs::write(&tmp_path, block).await?;
+            tokio::fs::rename(&tmp_path, self.dir.join(name)).await
+        }
+        .await;
+
+        if let Err(err) = result {
+            if config::verbose() {
+                eprintln!("block cache: failed to write block {name}: {err}");
+            }
+
+            let _ = tokio::fs::remove_file(&tmp_path).await;
+            return;
+        }
+
+        self.register(name.to_string(), block.len() as u64).await;
+    }
+
+    async fn register(&self, name: String, size: u64) {
+        let evicted = self
+            .index
+            .lock()
+            .unwrap()
+            .insert(name, size, self.max_bytes);
+
+        for name in evicted {
+            let _ = tokio::fs::remove_file(self.dir.join(name)).await;
+        }
+    }
+
+    #[cfg(test)]
+    fn num_bytes(&self) -> u64 {
+        self.index.lock().unwrap().num_bytes
+    }
+
+    #[cfg(test)]
+    fn dir(&self) -> &std::path::Path {
+        &self.dir
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use apollo_utils::pl_path::PlRefPath;
+
+    use super::BlockCache;
+    use crate::cloud::{CloudLocation, build_object_store, object_path_from_str};
+    use crate::pl_async;
+
+    #[test]
+    fn test_block_cache() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let file_path = tmp_dir.path().join("data.bin");
+        let cache_dir = tmp_dir.path().join("block-cache");
+
+        let data: Vec<u8> = (0..10_000u32).map(|x| (x % 251) as u8).collect();
+        std::fs::write(&file_path, &data).unwrap();
+
+        let cache = BlockCache::try_new(cache_dir, 1024, 4096, Duration::ZERO).unwrap();
+
+        let num_files = |cache: &BlockCache| std::fs::read_dir(cache.dir()).unwrap().count();
+
+        pl_async::get_runtime().block_on(async {
+            let (CloudLocation { prefix, .. }, store) =
+                build_object_store(PlRefPath::try_from_path(&file_path).unwrap(), None, false)
+                    .await
+                    .unwrap();
+            let path = object_path_from_str(&prefix).unwrap();
+
+            for _ in 0..2 {
+                let out = cache.get_range(&store, &path, 100..3000).await.unwrap();
+                assert_eq!(out.unwrap().as_slice(), &data[100..3000]);
+                assert_eq!(num_files(&cache), 3);
+            }
+
+            // Ranges that span the last, partial block. This evicts the least recently used
+            // blocks to stay within the size budget.
+            let ranges = [10..20, 5000..7000, 9990..10_000];
+            let out = cache
+                .get_ranges(&store, &path, &ranges)
+                .await
+                .unwrap()
+                .unwrap();
+
+            for range in ranges {
+                assert_eq!(out[&range.start].as_slice(), &data[range]);
+            }
+
+            assert!(cache.num_bytes() <= 4096);
+            assert!(num_files(&cache) <= 4);
+
+            // Out of bounds ranges are not served from the cache.
+            assert!(
+                cache
+                    .get_range(&store, &path, 9000..11_000)
+                    .await
+                    .unwrap()
+                    .is_none()
+            );
+
+            // Changing the object changes its ETag. The size changes as well so that the ETag
+            // differs even if the modification time has a coarse resolution.
+            let data: Vec<u8> = data.iter().rev().copied().chain([0]).collect();
+            std::fs::write(&file_path, &data).unwrap();
+
+            let out = cache.get_range(&store, &path, 100..3000).await.unwrap();
+            assert_eq!(out.unwrap().as_slice(), &data[100..3000]);
+        });
+
+        // Blocks on disk are picked up by a new cache.
+        let cache = BlockCache::try_new(
+            tmp_dir.path().join("block-cache"),
+            1024,
+            4096,
+            Duration::ZERO,
+        )
+        .unwrap();
+        assert!(cache.num_bytes() > 0 && cache.num_bytes() <= 4096);
+    }
+
+    #[test]
+    fn test_block_cache_object_changed() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let file_path = tmp_dir.path().join("data.bin");
+
+        let data: Vec<u8> = (0..4096u32).map(|x| (x % 251) as u8).collect();
+        std::fs::write(&file_path, &data).unwrap();
+
+        // The ETag is reused for the whole test.
+        let cache = BlockCache::try_new(
+            tmp_dir.path().join("block-cache"),
+            1024,
+            4096,
+            Duration::from_secs(3600),
+        )
+        .unwrap();
+
+        pl_async::get_runtime().block_on(async {
+            let (CloudLocation { prefix, .. }, store) =
+                build_object_store(PlRefPath::try_from_path(&file_path).unwrap(), None, false)
+                    .await
+                    .unwrap();
+            let path = object_path_from_str(&prefix).unwrap();
+
+            let out = cache.get_range(&store, &path, 0..100).await.unwrap();
+            assert_eq!(out.unwrap().as_slice(), &data[0..100]);
+
+            std::thread::sleep(Duration::from_millis(10));
+            let data: Vec<u8> = data.iter().rev().copied().collect();
+            std::fs::write(&file_path, &data).unwrap();
+
+            // The missing block is not fetched from the changed object.
+            assert!(
+                cache
+                    .get_range(&store, &path, 2000..2100)
+                    .await
+                    .unwrap()
+                    .is_none()
+            );
+            assert_eq!(cache.num_bytes(), 1024);
+
+            // The new version is picked up afterwards.
+            let out = cache.get_range(&store, &path, 2000..2100).await.unwrap();
+            assert_eq!(out.unwrap().as_slice(), &data[2000..2100]);
+        });
+    }
+}
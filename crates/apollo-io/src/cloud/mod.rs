@@ -3,10 +3,14 @@
 #[cfg(feature = "cloud")]
 mod adaptors;
 #[cfg(feature = "cloud")]
+mod block_cache;
+#[cfg(feature = "cloud")]
 mod glob;
 #[cfg(feature = "cloud")]
 mod object_store_setup;
 pub mod options;
+#[cfg(feature = "sftp")]
+mod sftp;
 #[cfg(feature = "cloud")]
 mod apollo_object_store;
 
@@ -19,6 +23,8 @@ pub use object_store_setup::*;
 pub use options::*;
 #[cfg(feature = "cloud")]
 pub use apollo_object_store::*;
+#[cfg(feature = "sftp")]
+pub use sftp::SftpStore;
 
 #[cfg(feature = "cloud")]
 pub mod credential_provider;
@@ -174,6 +174,15 @@ impl ApolloObjectStoreBuilder {
                 return err_missing_feature("http", &cloud_location.scheme);
             },
             CloudType::Hf => panic!("impl error: unresolved hf:// path"),
+            CloudType::Sftp => {
+                #[cfg(feature = "sftp")]
+                {
+                    let store = options.build_sftp(&self.path)?;
+                    Ok::<_, ApolloError>(Arc::new(store) as Arc<dyn ObjectStore>)
+                }
+                #[cfg(not(feature = "sftp"))]
+                return err_missing_feature("sftp", &self.cloud_type);
+            },
         }?;
 
         Ok(store)
@@ -182,7 +191,7 @@ impl ApolloObjectStoreBuilder {
     /// Note: Use `build_impl` for a non-caching version.
     pub(super) async fn build(self) -> ApolloResult<ApolloObjectStore> {
         let opt_cache_key = match &self.cloud_type {
-            CloudType::Aws | CloudType::Gcp | CloudType::Azure => {
+            CloudType::Aws | CloudType::Gcp | CloudType::Azure | CloudType::Sftp => {
                 Some(path_and_creds_to_key(&self.path, self.options.as_ref()))
             },
             CloudType::File | CloudType::Http | CloudType::Hf => None,
@@ -232,6 +241,25 @@ impl ApolloObjectStoreBuilder {
     pub(crate) fn is_azure(&self) -> bool {
         matches!(&self.cloud_type, CloudType::Azure)
     }
+
+    pub(crate) fn is_local(&self) -> bool {
+        matches!(&self.cloud_type, CloudType::File)
+    }
+
+    /// Whether the store can upload a file in parts. Other stores receive the file in a single
+    /// request.
+    pub(crate) fn supports_multipart_upload(&self) -> bool {
+        !matches!(&self.cloud_type, CloudType::Http)
+    }
+
+    /// The part of the URL that object paths are relative to.
+    pub(crate) fn url_base(&self) -> &str {
+        match &self.cloud_type {
+            // HTTP stores are built from the full URL.
+            CloudType::Http => self.path.as_str(),
+            _ => &self.path.as_str()[..self.path.authority_end_position()],
+        }
+    }
 }
 
 /// Build an [`ObjectStore`] based on the URL and passed in url. Return the cloud location and an implementation of the object store.
@@ -31,6 +31,8 @@ use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "cloud")]
 use super::credential_provider::PlCredentialProvider;
+#[cfg(feature = "sftp")]
+pub use super::sftp::SftpConfigKey;
 #[cfg(feature = "file_cache")]
 use crate::file_cache::get_env_file_cache_ttl;
 #[cfg(feature = "aws")]
@@ -71,6 +73,8 @@ pub(crate) enum CloudConfig {
     ),
     #[cfg(feature = "http")]
     Http { headers: Vec<(String, String)> },
+    #[cfg(feature = "sftp")]
+    Sftp(Configs<SftpConfigKey>),
 }
 
 #[derive(Clone, Debug, PartialEq, Hash, Eq)]
@@ -230,6 +234,7 @@ pub enum CloudType {
     Http,
     /// HuggingFace
     Hf,
+    Sftp,
 }
 
 impl CloudType {
@@ -250,6 +255,8 @@ impl CloudType {
             CloudScheme::Http | CloudScheme::Https => Self::Http,
 
             CloudScheme::S3 | CloudScheme::S3a => Self::Aws,
+
+            CloudScheme::Sftp => Self::Sftp,
         }
     }
 }
@@ -628,6 +635,33 @@ impl CloudOptions {
         Ok(out)
     }
 
+    /// Set the configuration for SFTP connections. This is the preferred API from rust.
+    #[cfg(feature = "sftp")]
+    pub fn with_sftp<I: IntoIterator<Item = (SftpConfigKey, impl Into<String>)>>(
+        mut self,
+        configs: I,
+    ) -> Self {
+        self.config = Some(CloudConfig::Sftp(
+            configs.into_iter().map(|(k, v)| (k, v.into())).collect(),
+        ));
+        self
+    }
+
+    /// Build the [`object_store::ObjectStore`] implementation for SFTP.
+    #[cfg(feature = "sftp")]
+    pub fn build_sftp(&self, path: &PlRefPath) -> ApolloResult<super::sftp::SftpStore> {
+        let configs = match &self.config {
+            Some(CloudConfig::Sftp(configs)) => configs.as_slice(),
+            _ => &[],
+        };
+
+        let Some((authority, _)) = path.strip_scheme_split_authority() else {
+            apollo_bail!(ComputeError: "could not extract host from SFTP path: '{}'", path)
+        };
+
+        super::sftp::SftpStore::new(authority, configs)
+    }
+
     /// Parse a configuration from a Hashmap. This is the interface from Python.
     #[allow(unused_variables)]
     pub fn from_untyped_config<I: IntoIterator<Item = (impl AsRef<str>, impl Into<String>)>>(
@@ -658,7 +692,64 @@ impl CloudOptions {
                 }
             },
             CloudType::File => Ok(Self::default()),
-            CloudType::Http => Ok(Self::default()),
+            CloudType::Http => {
+                #[cfg(feature = "http")]
+                {
+                    let mut bearer_token = None;
+                    let mut username = None;
+                    let mut password = None;
+
+                    // Other keys are ignored, HTTP paths to S3 are re-written to `s3://` paths
+                    // that use the AWS keys.
+                    for (k, v) in config {
+                        match k.as_ref() {
+                            "bearer_token" => bearer_token = Some(v.into()),
+                            "username" => username = Some(v.into()),
+                            "password" => password = Some(v.into()),
+                            _ => {},
+                        }
+                    }
+
+                    let authorization = match (bearer_token, username) {
+                        (Some(_), Some(_)) => apollo_bail!(
+                            ComputeError:
+                            "'bearer_token' and 'username' cannot both be set for HTTP"
+                        ),
+                        (Some(token), None) => Some(format!("Bearer {token}")),
+                        (None, Some(username)) => {
+                            use base64::Engine;
+
+                            let credentials =
+                                format!("{username}:{}", password.as_deref().unwrap_or(""));
+                            Some(format!(
+                                "Basic {}",
+                                base64::engine::general_purpose::STANDARD.encode(credentials)
+                            ))
+                        },
+                        (None, None) => {
+                            apollo_ensure!(
+                                password.is_none(),
+                                ComputeError: "'password' requires 'username' to be set for HTTP"
+                            );
+                            None
+                        },
+                    };
+
+                    let mut this = Self::default();
+
+                    if let Some(v) = authorization {
+                        this.config = Some(CloudConfig::Http {
+                            headers: vec![("Authorization".into(), v)],
+                        })
+                    }
+
+                    Ok(this)
+                }
+                #[cfg(not(feature = "http"))]
+                {
+                    Ok(Self::default())
+                }
+            },
             CloudType::Gcp => {
                 #[cfg(feature = "gcp")]
                 {
@@ -735,6 +826,20 @@ impl CloudOptions {
                     apollo_bail!(ComputeError: "'http' feature is not enabled");
                 }
             },
+            CloudType::Sftp => {
+                #[cfg(feature = "sftp")]
+                {
+                    config
+                        .into_iter()
+                        .map(|(k, v)| Ok((SftpConfigKey::from_str(k.as_ref())?, v.into())))
+                        .collect::<ApolloResult<Configs<SftpConfigKey>>>()
+                        .map(|sftp| Self::default().with_sftp(sftp))
+                }
+                #[cfg(not(feature = "sftp"))]
+                {
+                    apollo_bail!(ComputeError: "'sftp' feature is not enabled");
+                }
+            },
         }
     }
 
@@ -0,0 +1,879 @@
+//! [`ObjectStore`] implementation for SFTP servers.
+//!
+//! Object paths are absolute paths on the server, `sftp://user@host:2222/data/a.parquet` refers
+//! to `/data/a.parquet` on `host`. Every request takes an SSH session from a small pool of idle
+//! sessions, connecting a new one if none is idle, so that concurrent requests don't wait on each
+//! other. A session is dropped after a failed request. libssh2 is blocking, so requests run on
+//! the blocking thread pool.
+use std::fmt::{Debug, Display, Formatter};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::net::TcpStream;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::stream::BoxStream;
+use futures::{StreamExt, TryStreamExt};
+use object_store::path::Path;
+use object_store::{
+    CopyMode, CopyOptions, GetOptions, GetRange, GetResult, GetResultPayload, ListResult,
+    MultipartUpload, ObjectMeta, ObjectStore, PutMode, PutMultipartOptions, PutOptions, PutPayload,
+    PutResult, UploadPart,
+};
+use apollo_error::{ApolloError, ApolloResult, apollo_bail, apollo_ensure, apollo_err};
+use apollo_utils::unique_id::UniqueId;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use ssh2::{
+    CheckResult, ErrorCode, FileStat, KnownHostFileKind, OpenFlags, OpenType, RenameFlags, Session,
+    Sftp,
+};
+
+use crate::path_utils::resolve_homedir;
+
+const STORE: &str = "SFTP";
+const DEFAULT_PORT: u16 = 22;
+/// Size of the chunks that a streaming `get` reads in a single request.
+const READ_CHUNK_SIZE: u64 = 8 * 1024 * 1024;
+/// Maximum number of idle sessions kept per store. More sessions are connected under load, but
+/// are closed once their request is done.
+const MAX_IDLE_SESSIONS: usize = 8;
+
+// SFTP status codes, see https://datatracker.ietf.org/doc/html/draft-ietf-secsh-filexfer-02#section-7.
+const SSH_FX_NO_SUCH_FILE: i32 = 2;
+
+/// Configuration keys for SFTP connections.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum SftpConfigKey {
+    /// User to log in as. Defaults to the user in the URL, and then to `$USER`.
+    Username,
+    Password,
+    /// Path to a private key file. If neither a password nor a key is given, the keys of the
+    /// SSH agent are tried.
+    PrivateKeyPath,
+    PrivateKeyPassphrase,
+    /// Defaults to `~/.ssh/known_hosts`.
+    KnownHostsPath,
+    /// Accept any host key. Only use this against servers on a trusted network.
+    SkipHostKeyCheck,
+}
+
+impl FromStr for SftpConfigKey {
+    type Err = ApolloError;
+
+    fn from_str(s: &str) -> ApolloResult<Self> {
+        Ok(match s {
+            "username" => Self::Username,
+            "password" => Self::Password,
+            "private_key_path" => Self::PrivateKeyPath,
+            "private_key_passphrase" => Self::PrivateKeyPassphrase,
+            "known_hosts_path" => Self::KnownHostsPath,
+            "skip_host_key_check" => Self::SkipHostKeyCheck,
+            _ => apollo_bail!(ComputeError: "unknown configuration key for SFTP: {}", s),
+        })
+    }
+}
+
+/// Splits the authority of an `sftp://` URL into the user, host and port.
+fn parse_authority(authority: &str) -> ApolloResult<(Option<&str>, &str, u16)> {
+    let (user, host_port) = match authority.rsplit_once('@') {
+        Some((user, host_port)) => {
+            apollo_ensure!(
+                !user.contains(':'),
+                ComputeError: "passwords in SFTP URLs are not supported, use the 'password' \
+                storage option instead"
+            );
+            (Some(user), host_port)
+        },
+        None => (None, authority),
+    };
+
+    let (host, port) = if let Some(rest) = host_port.strip_prefix('[') {
+        // IPv6 address, e.g. `[::1]:22`.
+        let Some((host, port)) = rest.split_once(']') else {
+            apollo_bail!(ComputeError: "invalid host in SFTP URL: '{}'", authority)
+        };
+        (host, port.strip_prefix(':'))
+    } else {
+        match host_port.rsplit_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (host_port, None),
+        }
+    };
+
+    let port = match port {
+        Some(port) => port
+            .parse()
+            .map_err(|_| apollo_err!(ComputeError: "invalid port in SFTP URL: '{}'", authority))?,
+        None => DEFAULT_PORT,
+    };
+
+    apollo_ensure!(
+        !host.is_empty(),
+        ComputeError: "missing host in SFTP URL: '{}'", authority
+    );
+
+    Ok((user, host, port))
+}
+
+fn generic_err(err: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> object_store::Error {
+    object_store::Error::Generic {
+        store: STORE,
+        source: err.into(),
+    }
+}
+
+/// Converts the error of a request for `location`.
+fn ssh_err(location: &Path, err: ssh2::Error) -> object_store::Error {
+    match err.code() {
+        ErrorCode::SFTP(SSH_FX_NO_SUCH_FILE) => object_store::Error::NotFound {
+            path: location.to_string(),
+            source: err.into(),
+        },
+        _ => generic_err(err),
+    }
+}
+
+fn remote_path(location: &Path) -> PathBuf {
+    PathBuf::from(format!("/{location}"))
+}
+
+fn object_meta(location: Path, stat: &FileStat) -> ObjectMeta {
+    let size = stat.size.unwrap_or(0);
+    let mtime = stat.mtime.unwrap_or(0);
+
+    ObjectMeta {
+        location,
+        last_modified: chrono::DateTime::from_timestamp(mtime as i64, 0).unwrap_or_default(),
+        size,
+        // SFTP only reports modification times in whole seconds, which can't tell apart two
+        // writes of the same size within a second. Without an ETag, the block cache and the
+        // parquet metadata cache don't cache the object.
+        e_tag: None,
+        version: None,
+    }
+}
+
+fn resolve_range(range: GetRange, len: u64) -> object_store::Result<Range<u64>> {
+    let out = match &range {
+        GetRange::Bounded(r) => r.start..r.end.min(len),
+        GetRange::Offset(offset) => *offset..len,
+        GetRange::Suffix(n) => len.saturating_sub(*n)..len,
+    };
+
+    if out.start > out.end || (out.start >= len && len > 0) {
+        return Err(generic_err(format!(
+            "invalid range {range:?} for file of size {len}"
+        )));
+    }
+
+    Ok(out)
+}
+
+async fn run_blocking<F, T>(f: F) -> object_store::Result<T>
+where
+    F: FnOnce() -> object_store::Result<T> + Send + 'static,
+    T: Send + 'static,
+{
+    tokio::task::spawn_blocking(f).await.unwrap()
+}
+
+struct SftpClient {
+    host: String,
+    port: u16,
+    username: String,
+    password: Option<String>,
+    private_key_path: Option<PathBuf>,
+    private_key_passphrase: Option<String>,
+    known_hosts_path: Option<PathBuf>,
+    skip_host_key_check: bool,
+    /// SFTP channels of the sessions that are not in use.
+    idle: Mutex<Vec<Sftp>>,
+}
+
+impl Debug for SftpClient {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SftpClient")
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("username", &self.username)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SftpClient {
+    fn connect(&self) -> object_store::Result<Sftp> {
+        let tcp = TcpStream::connect((self.host.as_str(), self.port)).map_err(generic_err)?;
+        let mut session = Session::new().map_err(generic_err)?;
+        session.set_tcp_stream(tcp);
+        session.handshake().map_err(generic_err)?;
+
+        self.check_host_key(&session)?;
+
+        if let Some(password) = &self.password {
+            session.userauth_password(&self.username, password)
+        } else if let Some(private_key_path) = &self.private_key_path {
+            session.userauth_pubkey_file(
+                &self.username,
+                None,
+                private_key_path,
+                self.private_key_passphrase.as_deref(),
+            )
+        } else {
+            session.userauth_agent(&self.username)
+        }
+        .map_err(generic_err)?;
+
+        if !session.authenticated() {
+            return Err(generic_err(format!(
+                "authentication as '{}' failed",
+                self.username
+            )));
+        }
+
+        session.sftp().map_err(generic_err)
+    }
+
+    fn check_host_key(&self, session: &Session) -> object_store::Result<()> {
+        if self.skip_host_key_check {
+            return Ok(());
+        }
+
+        let Some((key, _)) = session.host_key() else {
+            return Err(generic_err("server did not send a host key"));
+        };
+
+        let known_hosts_path = self
+            .known_hosts_path
+            .clone()
+            .unwrap_or_else(|| resolve_homedir("~/.ssh/known_hosts"));
+
+        let mut known_hosts = session.known_hosts().map_err(generic_err)?;
+        known_hosts
+            .read_file(&known_hosts_path, KnownHostFileKind::OpenSSH)
+            .map_err(generic_err)?;
+
+        match known_hosts.check_port(&self.host, self.port, key) {
+            CheckResult::Match => Ok(()),
+            CheckResult::Mismatch => Err(generic_err(format!(
+                "host key of '{}' does not match the key in {}",
+                self.host,
+                known_hosts_path.display()
+            ))),
+            CheckResult::NotFound => Err(generic_err(format!(
+                "host '{}' is not in {}, add it or set 'known_hosts_path'",
+                self.host,
+                known_hosts_path.display()
+            ))),
+            CheckResult::Failure => Err(generic_err(format!(
+                "could not check the host key of '{}'",
+                self.host
+            ))),
+        }
+    }
+
+    /// Runs `f` with an idle SFTP channel, connecting a new session if there is none.
+    fn with_sftp<T>(
+        &self,
+        f: impl FnOnce(&Sftp) -> object_store::Result<T>,
+    ) -> object_store::Result<T> {
+        // Don't hold the lock while connecting or running `f`.
+        let idle = self.idle.lock().unwrap().pop();
+        let sftp = match idle {
+            Some(sftp) => sftp,
+            None => self.connect()?,
+        };
+
+        let out = f(&sftp);
+
+        // The session may be broken after a generic error, so it is not reused.
+        if !matches!(&out, Err(object_store::Error::Generic { .. })) {
+            let mut idle = self.idle.lock().unwrap();
+
+            if idle.len() < MAX_IDLE_SESSIONS {
+                idle.push(sftp);
+            }
+        }
+
+        out
+    }
+}
+
+fn create_dir_all(sftp: &Sftp, path: &std::path::Path) -> object_store::Result<()> {
+    if path.as_os_str().is_empty() || sftp.stat(path).is_ok() {
+        return Ok(());
+    }
+
+    if let Some(parent) = path.parent() {
+        create_dir_all(sftp, parent)?;
+    }
+
+    sftp.mkdir(path, 0o755).or_else(|e| {
+        // Another request may have created it in the meantime.
+        if sftp.stat(path).is_ok_and(|stat| stat.is_dir()) {
+            Ok(())
+        } else {
+            Err(generic_err(e))
+        }
+    })
+}
+
+/// Path next to `path` that a file is written to before it is moved to `path`.
+fn upload_path(path: &std::path::Path) -> PathBuf {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!(".{name}.{}.upload", UniqueId::new()))
+}
+
+/// Moves `from` to `to`, replacing `to` if it exists.
+fn rename_replace(
+    sftp: &Sftp,
+    from: &std::path::Path,
+    to: &std::path::Path,
+) -> object_store::Result<()> {
+    let flags = RenameFlags::OVERWRITE | RenameFlags::ATOMIC | RenameFlags::NATIVE;
+
+    if let Err(e) = sftp.rename(from, to, Some(flags)) {
+        // Version 3 of the protocol, which most servers speak, has no overwriting rename.
+        if sftp.stat(to).is_err() {
+            return Err(generic_err(e));
+        }
+        sftp.unlink(to).map_err(generic_err)?;
+        sftp.rename(from, to, None).map_err(generic_err)?;
+    }
+
+    Ok(())
+}
+
+/// Moves a finished upload to `location`.
+fn commit_upload(
+    sftp: &Sftp,
+    upload_path: &std::path::Path,
+    location: &Path,
+) -> object_store::Result<PutResult> {
+    let path = remote_path(location);
+    rename_replace(sftp, upload_path, &path)?;
+
+    Ok(PutResult {
+        e_tag: None,
+        version: None,
+    })
+}
+
+fn check_put_mode(sftp: &Sftp, location: &Path, mode: &PutMode) -> object_store::Result<()> {
+    match mode {
+        PutMode::Overwrite => Ok(()),
+        PutMode::Create => match sftp.stat(&remote_path(location)) {
+            Ok(_) => Err(object_store::Error::AlreadyExists {
+                path: location.to_string(),
+                source: "file already exists".into(),
+            }),
+            Err(_) => Ok(()),
+        },
+        PutMode::Update(_) => Err(generic_err("conditional updates are not supported")),
+    }
+}
+
+/// Lists the files below `prefix`, recursing into directories if `recursive` is set. Returns the
+/// files and the directories that were not recursed into.
+fn list_dir(
+    sftp: &Sftp,
+    prefix: &Path,
+    recursive: bool,
+) -> object_store::Result<(Vec<ObjectMeta>, Vec<Path>)> {
+    let mut objects = vec![];
+    let mut common_prefixes = vec![];
+    let mut dirs = vec![remote_path(prefix)];
+
+    while let Some(dir) = dirs.pop() {
+        let entries = match sftp.readdir(&dir) {
+            Ok(entries) => entries,
+            // A prefix that does not exist contains no files.
+            Err(e) if matches!(e.code(), ErrorCode::SFTP(SSH_FX_NO_SUCH_FILE)) => continue,
+            Err(e) => return Err(generic_err(e)),
+        };
+
+        for (path, stat) in entries {
+            let Some(location) = path.to_str().map(|x| x.trim_start_matches('/')) else {
+                continue;
+            };
+            let location = Path::parse(location).map_err(generic_err)?;
+
+            if stat.is_dir() {
+                if recursive {
+                    dirs.push(path);
+                } else {
+                    common_prefixes.push(location);
+                }
+            } else if stat.is_file() {
+                objects.push(object_meta(location, &stat));
+            }
+        }
+    }
+
+    Ok((objects, common_prefixes))
+}
+
+/// [`ObjectStore`] for a server that is accessed over SFTP.
+#[derive(Debug, Clone)]
+pub struct SftpStore {
+    client: Arc<SftpClient>,
+}
+
+impl SftpStore {
+    /// Creates a store for the server at `authority` (`[user@]host[:port]`). No connection is made
+    /// until the first request.
+    pub fn new(authority: &str, configs: &[(SftpConfigKey, String)]) -> ApolloResult<Self> {
+        let (url_user, host, port) = parse_authority(authority)?;
+
+        let mut username = url_user.map(|x| x.to_string());
+        let mut password = None;
+        let mut private_key_path = None;
+        let mut private_key_passphrase = None;
+        let mut known_hosts_path = None;
+        let mut skip_host_key_check = false;
+
+        for (key, value) in configs {
+            match key {
+                SftpConfigKey::Username => username = Some(value.clone()),
+                SftpConfigKey::Password => password = Some(value.clone()),
+                SftpConfigKey::PrivateKeyPath => private_key_path = Some(resolve_homedir(value)),
+                SftpConfigKey::PrivateKeyPassphrase => private_key_passphrase = Some(value.clone()),
+                SftpConfigKey::KnownHostsPath => known_hosts_path = Some(resolve_homedir(value)),
+                SftpConfigKey::SkipHostKeyCheck => {
+                    skip_host_key_check = value.parse().map_err(|_| {
+                        apollo_err!(
+                            ComputeError: "invalid value for 'skip_host_key_check': '{}'", value
+                        )
+                    })?
+                },
+            }
+        }
+
+        let Some(username) = username.or_else(|| std::env::var("USER").ok()) else {
+            apollo_bail!(ComputeError: "no user given for SFTP host '{}'", host)
+        };
+
+        Ok(Self {
+            client: Arc::new(SftpClient {
+                host: host.to_string(),
+                port,
+                username,
+                password,
+                private_key_path,
+                private_key_passphrase,
+                known_hosts_path,
+                skip_host_key_check,
+                idle: Mutex::default(),
+            }),
+        })
+    }
+
+    /// Runs `f` with the SFTP channel on the blocking thread pool.
+    async fn run<F, T>(&self, f: F) -> object_store::Result<T>
+    where
+        F: FnOnce(&Sftp) -> object_store::Result<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let client = self.client.clone();
+        run_blocking(move || client.with_sftp(f)).await
+    }
+}
+
+impl Display for SftpStore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SftpStore({}:{})", self.client.host, self.client.port)
+    }
+}
+
+#[async_trait]
+impl ObjectStore for SftpStore {
+    async fn put_opts(
+        &self,
+        location: &Path,
+        payload: PutPayload,
+        opts: PutOptions,
+    ) -> object_store::Result<PutResult> {
+        let location = location.clone();
+
+        self.run(move |sftp| {
+            check_put_mode(sftp, &location, &opts.mode)?;
+
+            let path = remote_path(&location);
+            if let Some(parent) = path.parent() {
+                create_dir_all(sftp, parent)?;
+            }
+
+            // Write next to the destination first, so that readers never see a partial file.
+            let upload_path = upload_path(&path);
+            let result = (|| {
+                let mut file = sftp.create(&upload_path).map_err(generic_err)?;
+                for chunk in payload.iter() {
+                    file.write_all(chunk).map_err(generic_err)?;
+                }
+                drop(file);
+
+                commit_upload(sftp, &upload_path, &location)
+            })();
+
+            if result.is_err() {
+                _ = sftp.unlink(&upload_path);
+            }
+
+            result
+        })
+        .await
+    }
+
+    async fn put_multipart_opts(
+        &self,
+        location: &Path,
+        _opts: PutMultipartOptions,
+    ) -> object_store::Result<Box<dyn MultipartUpload>> {
+        let path = remote_path(location);
+        let upload_path = upload_path(&path);
+
+        self.run({
+            let upload_path = upload_path.clone();
+            move |sftp| {
+                if let Some(parent) = path.parent() {
+                    create_dir_all(sftp, parent)?;
+                }
+                sftp.create(&upload_path).map_err(generic_err)?;
+                Ok(())
+            }
+        })
+        .await?;
+
+        Ok(Box::new(SftpUpload {
+            client: self.client.clone(),
+            location: location.clone(),
+            upload_path,
+            offset: 0,
+        }))
+    }
+
+    async fn get_opts(
+        &self,
+        location: &Path,
+        options: GetOptions,
+    ) -> object_store::Result<GetResult> {
+        let meta = self
+            .run({
+                let location = location.clone();
+                move |sftp| {
+                    let stat = sftp
+                        .stat(&remote_path(&location))
+                        .map_err(|e| ssh_err(&location, e))?;
+
+                    if stat.is_dir() {
+                        return Err(object_store::Error::NotFound {
+                            path: location.to_string(),
+                            source: "path is a directory".into(),
+                        });
+                    }
+
+                    Ok(object_meta(location, &stat))
+                }
+            })
+            .await?;
+
+        options.check_preconditions(&meta)?;
+
+        let range = match options.range {
+            Some(range) => resolve_range(range, meta.size)?,
+            None => 0..meta.size,
+        };
+
+        let stream = if options.head {
+            futures::stream::empty().boxed()
+        } else {
+            let client = self.client.clone();
+            let location = location.clone();
+
+            // Read in chunks, so that large files are not held in memory at once.
+            futures::stream::try_unfold(range.clone(), move |range| {
+                let client = client.clone();
+                let location = location.clone();
+
+                async move {
+                    if range.is_empty() {
+                        return Ok(None);
+                    }
+
+                    let len = (range.end - range.start).min(READ_CHUNK_SIZE);
+                    let start = range.start;
+
+                    let bytes = run_blocking(move || {
+                        client.with_sftp(|sftp| {
+                            let mut file = sftp
+                                .open(&remote_path(&location))
+                                .map_err(|e| ssh_err(&location, e))?;
+                            file.seek(SeekFrom::Start(start)).map_err(generic_err)?;
+
+                            let mut buf = vec![0; len as usize];
+                            file.read_exact(&mut buf).map_err(generic_err)?;
+                            Ok(Bytes::from(buf))
+                        })
+                    })
+                    .await?;
+
+                    Ok(Some((bytes, start + len..range.end)))
+                }
+            })
+            .boxed()
+        };
+
+        Ok(GetResult {
+            payload: GetResultPayload::Stream(stream),
+            meta,
+            range,
+            attributes: Default::default(),
+        })
+    }
+
+    async fn get_ranges(
+        &self,
+        location: &Path,
+        ranges: &[Range<u64>],
+    ) -> object_store::Result<Vec<Bytes>> {
+        let location = location.clone();
+        let ranges = ranges.to_vec();
+
+        // Serve all ranges from a single file handle.
+        self.run(move |sftp| {
+            let mut file = sftp
+                .open(&remote_path(&location))
+                .map_err(|e| ssh_err(&location, e))?;
+
+            ranges
+                .into_iter()
+                .map(|range| {
+                    file.seek(SeekFrom::Start(range.start))
+                        .map_err(generic_err)?;
+                    let mut buf = vec![0; (range.end - range.start) as usize];
+                    file.read_exact(&mut buf).map_err(generic_err)?;
+                    Ok(Bytes::from(buf))
+                })
+                .collect()
+        })
+        .await
+    }
+
+    fn delete_stream(
+        &self,
+        locations: BoxStream<'static, object_store::Result<Path>>,
+    ) -> BoxStream<'static, object_store::Result<Path>> {
+        let store = self.clone();
+
+        locations
+            .and_then(move |location| {
+                let store = store.clone();
+
+                async move {
+                    store
+                        .run(move |sftp| {
+                            sftp.unlink(&remote_path(&location))
+                                .map_err(|e| ssh_err(&location, e))?;
+                            Ok(location)
+                        })
+                        .await
+                }
+            })
+            .boxed()
+    }
+
+    fn list(&self, prefix: Option<&Path>) -> BoxStream<'static, object_store::Result<ObjectMeta>> {
+        let store = self.clone();
+        let prefix = prefix.cloned().unwrap_or_default();
+
+        futures::stream::once(async move {
+            store
+                .run(move |sftp| list_dir(sftp, &prefix, true))
+                .await
+                .map(|(objects, _)| futures::stream::iter(objects.into_iter().map(Ok)))
+        })
+        .try_flatten()
+        .boxed()
+    }
+
+    async fn list_with_delimiter(&self, prefix: Option<&Path>) -> object_store::Result<ListResult> {
+        let prefix = prefix.cloned().unwrap_or_default();
+
+        let (objects, common_prefixes) =
+            self.run(move |sftp| list_dir(sftp, &prefix, false)).await?;
+
+        Ok(ListResult {
+            common_prefixes,
+            objects,
+        })
+    }
+
+    async fn copy_opts(
+        &self,
+        from: &Path,
+        to: &Path,
+        options: CopyOptions,
+    ) -> object_store::Result<()> {
+        let (from, to) = (from.clone(), to.clone());
+
+        // SFTP has no server-side copy, the data goes through the client.
+        self.run(move |sftp| {
+            if let CopyMode::Create = options.mode {
+                check_put_mode(sftp, &to, &PutMode::Create)?;
+            }
+
+            let to_path = remote_path(&to);
+            if let Some(parent) = to_path.parent() {
+                create_dir_all(sftp, parent)?;
+            }
+
+            let upload_path = upload_path(&to_path);
+            let result = (|| {
+                let mut src = sftp
+                    .open(&remote_path(&from))
+                    .map_err(|e| ssh_err(&from, e))?;
+                let mut dst = sftp.create(&upload_path).map_err(generic_err)?;
+                std::io::copy(&mut src, &mut dst).map_err(generic_err)?;
+                drop(dst);
+
+                commit_upload(sftp, &upload_path, &to).map(|_| ())
+            })();
+
+            if result.is_err() {
+                _ = sftp.unlink(&upload_path);
+            }
+
+            result
+        })
+        .await
+    }
+}
+
+/// Writes each part at its offset in a hidden file next to the destination, which is moved into
+/// place on completion.
+#[derive(Debug)]
+struct SftpUpload {
+    client: Arc<SftpClient>,
+    location: Path,
+    upload_path: PathBuf,
+    /// Offset of the next part.
+    offset: u64,
+}
+
+#[async_trait]
+impl MultipartUpload for SftpUpload {
+    fn put_part(&mut self, data: PutPayload) -> UploadPart {
+        let client = self.client.clone();
+        let upload_path = self.upload_path.clone();
+        let offset = self.offset;
+        self.offset += data.content_length() as u64;
+
+        Box::pin(run_blocking(move || {
+            client.with_sftp(|sftp| {
+                let mut file = sftp
+                    .open_mode(&upload_path, OpenFlags::WRITE, 0o644, OpenType::File)
+                    .map_err(generic_err)?;
+                file.seek(SeekFrom::Start(offset)).map_err(generic_err)?;
+                for chunk in data.iter() {
+                    file.write_all(chunk).map_err(generic_err)?;
+                }
+                Ok(())
+            })
+        }))
+    }
+
+    async fn complete(&mut self) -> object_store::Result<PutResult> {
+        let client = self.client.clone();
+        let upload_path = self.upload_path.clone();
+        let location = self.location.clone();
+
+        run_blocking(move || client.with_sftp(|sftp| commit_upload(sftp, &upload_path, &location)))
+            .await
+    }
+
+    async fn abort(&mut self) -> object_store::Result<()> {
+        let client = self.client.clone();
+        let upload_path = self.upload_path.clone();
+
+        run_blocking(move || {
+            client.with_sftp(|sftp| {
+                _ = sftp.unlink(&upload_path);
+                Ok(())
+            })
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_authority() {
+        assert_eq!(
+            parse_authority("host").unwrap(),
+            (None, "host", DEFAULT_PORT)
+        );
+        assert_eq!(
+            parse_authority("user@host:2222").unwrap(),
+            (Some("user"), "host", 2222)
+        );
+        assert_eq!(
+            parse_authority("user@[::1]:2222").unwrap(),
+            (Some("user"), "::1", 2222)
+        );
+        assert_eq!(
+            parse_authority("[::1]").unwrap(),
+            (None, "::1", DEFAULT_PORT)
+        );
+        assert!(parse_authority("user:secret@host").is_err());
+        assert!(parse_authority("host:port").is_err());
+        assert!(parse_authority("user@").is_err());
+    }
+
+    #[test]
+    fn test_resolve_range() {
+        assert_eq!(resolve_range(GetRange::Bounded(2..5), 10).unwrap(), 2..5);
+        assert_eq!(resolve_range(GetRange::Bounded(2..50), 10).unwrap(), 2..10);
+        assert_eq!(resolve_range(GetRange::Offset(4), 10).unwrap(), 4..10);
+        assert_eq!(resolve_range(GetRange::Suffix(3), 10).unwrap(), 7..10);
+        assert_eq!(resolve_range(GetRange::Suffix(30), 10).unwrap(), 0..10);
+        assert!(resolve_range(GetRange::Bounded(10..12), 10).is_err());
+        assert!(resolve_range(GetRange::Offset(11), 10).is_err());
+    }
+
+    #[test]
+    fn test_config_keys() {
+        let store = SftpStore::new(
+            "alice@example.com",
+            &[
+                (SftpConfigKey::from_str("password").unwrap(), "pw".into()),
+                (
+                    SftpConfigKey::from_str("skip_host_key_check").unwrap(),
+                    "true".into(),
+                ),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(store.client.username, "alice");
+        assert_eq!(store.client.password.as_deref(), Some("pw"));
+        assert!(store.client.skip_host_key_check);
+        assert_eq!(store.to_string(), "SftpStore(example.com:22)");
+
+        assert!(SftpConfigKey::from_str("aws_region").is_err());
+        assert!(
+            SftpStore::new(
+                "example.com",
+                &[(SftpConfigKey::SkipHostKeyCheck, "maybe".into())]
+            )
+            .is_err()
+        );
+    }
+}
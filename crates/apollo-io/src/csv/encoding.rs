@@ -0,0 +1,323 @@
+//! Transcoding of CSV data between UTF-8 and the other supported text encodings.
+//!
+//! The CSV parser and serializer only operate on UTF-8. Other encodings are decoded to UTF-8
+//! while reading, and UTF-8 output is encoded to the target encoding while writing.
+
+use std::io::Read;
+
+use apollo_buffer::Buffer;
+use apollo_error::{ApolloResult, apollo_bail, apollo_err};
+use encoding_rs::{DecoderResult, EncoderResult, Encoding};
+
+use super::read::CsvEncoding;
+use super::write::UTF8_BOM;
+use crate::utils::compression::CompressedReader;
+
+const UTF16_LE_BOM: [u8; 2] = [0xFF, 0xFE];
+const UTF16_BE_BOM: [u8; 2] = [0xFE, 0xFF];
+
+/// Number of encoded bytes that are decoded at once.
+const DECODE_CHUNK_SIZE: usize = 64 * 1024;
+
+impl CsvEncoding {
+    /// Whether data in this encoding is parsed as-is, without transcoding.
+    pub fn is_utf8(self) -> bool {
+        matches!(self, Self::Utf8 | Self::LossyUtf8)
+    }
+
+    /// The byte order mark that is written when a BOM is requested. Empty for encodings without
+    /// a byte order mark.
+    pub fn bom(self) -> &'static [u8] {
+        match self {
+            Self::Utf8 | Self::LossyUtf8 => &UTF8_BOM,
+            Self::Utf16Le => &UTF16_LE_BOM,
+            Self::Utf16Be => &UTF16_BE_BOM,
+            Self::Latin1 | Self::Windows1252 | Self::ShiftJis => &[],
+        }
+    }
+
+    /// Detects UTF-16 from the byte order mark at the start of `bytes`.
+    pub fn detect_utf16_bom(bytes: &[u8]) -> Option<Self> {
+        match bytes.get(..2)? {
+            b if b == UTF16_LE_BOM => Some(Self::Utf16Le),
+            b if b == UTF16_BE_BOM => Some(Self::Utf16Be),
+            _ => None,
+        }
+    }
+
+    fn encoding_rs(self) -> Option<&'static Encoding> {
+        match self {
+            Self::Utf8 | Self::LossyUtf8 | Self::Latin1 => None,
+            Self::Windows1252 => Some(encoding_rs::WINDOWS_1252),
+            Self::ShiftJis => Some(encoding_rs::SHIFT_JIS),
+            Self::Utf16Le => Some(encoding_rs::UTF_16LE),
+            Self::Utf16Be => Some(encoding_rs::UTF_16BE),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Utf8 | Self::LossyUtf8 => "utf-8",
+            Self::Latin1 => "latin-1",
+            Self::Windows1252 => "windows-1252",
+            Self::ShiftJis => "shift_jis",
+            Self::Utf16Le => "utf-16le",
+            Self::Utf16Be => "utf-16be",
+        }
+    }
+}
+
+impl CompressedReader {
+    /// Creates a reader that decompresses `bytes` and transcodes the result to UTF-8.
+    ///
+    /// A UTF-16 byte order mark at the start of the decompressed data takes precedence over
+    /// `encoding`.
+    pub fn try_new_with_encoding(bytes: Buffer<u8>, encoding: CsvEncoding) -> ApolloResult<Self> {
+        let mut bom = [0u8; 2];
+        let mut probe = CompressedReader::try_new(bytes.clone())?;
+        let mut n_read = 0;
+
+        while n_read < bom.len() {
+            match probe.read(&mut bom[n_read..])? {
+                0 => break,
+                n => n_read += n,
+            }
+        }
+
+        let encoding = CsvEncoding::detect_utf16_bom(&bom[..n_read]).unwrap_or(encoding);
+        let reader = CompressedReader::try_new(bytes)?;
+
+        Ok(if encoding.is_utf8() {
+            reader
+        } else {
+            CompressedReader::Transcoding(Box::new(TranscodingReader::new(reader, encoding)))
+        })
+    }
+}
+
+enum TextDecoder {
+    Latin1,
+    EncodingRs(encoding_rs::Decoder),
+}
+
+/// Reader that decodes the output of a [`CompressedReader`] to UTF-8.
+///
+/// Malformed input raises an error instead of being replaced.
+pub struct TranscodingReader {
+    inner: CompressedReader,
+    encoding: CsvEncoding,
+    decoder: TextDecoder,
+    input: Vec<u8>,
+    output: Vec<u8>,
+    output_offset: usize,
+    finished: bool,
+}
+
+impl TranscodingReader {
+    fn new(inner: CompressedReader, encoding: CsvEncoding) -> Self {
+        let decoder = match encoding.encoding_rs() {
+            // Also strips the byte order mark for UTF-16.
+            Some(encoding) => TextDecoder::EncodingRs(encoding.new_decoder_with_bom_removal()),
+            None => TextDecoder::Latin1,
+        };
+
+        Self {
+            inner,
+            encoding,
+            decoder,
+            input: Vec::new(),
+            output: Vec::new(),
+            output_offset: 0,
+            finished: false,
+        }
+    }
+
+    pub(crate) fn encoded_len_estimate(&self) -> usize {
+        self.inner.total_len_estimate()
+    }
+
+    fn decode_next_chunk(&mut self) -> std::io::Result<()> {
+        self.input.resize(DECODE_CHUNK_SIZE, 0);
+        let n_read = self.inner.read(&mut self.input)?;
+        self.input.truncate(n_read);
+
+        let last = n_read == 0;
+
+        self.output.clear();
+        self.output_offset = 0;
+
+        match &mut self.decoder {
+            TextDecoder::Latin1 => {
+                self.output.resize(2 * n_read, 0);
+                let n_written = encoding_rs::mem::convert_latin1_to_utf8(&self.input, &mut self.output);
+                self.output.truncate(n_written);
+            },
+            TextDecoder::EncodingRs(decoder) => {
+                let max_len = decoder
+                    .max_utf8_buffer_length_without_replacement(n_read)
+                    .ok_or_else(|| std::io::Error::other("CSV chunk too large to transcode"))?;
+                self.output.resize(max_len, 0);
+
+                let (result, _, n_written) =
+                    decoder.decode_to_utf8_without_replacement(&self.input, &mut self.output, last);
+
+                match result {
+                    DecoderResult::InputEmpty => {},
+                    DecoderResult::OutputFull => unreachable!(),
+                    DecoderResult::Malformed(..) => {
+                        return Err(std::io::Error::new(
+                            std::io::ErrorKind::InvalidData,
+                            format!("invalid {} byte sequence", self.encoding.name()),
+                        ));
+                    },
+                }
+
+                self.output.truncate(n_written);
+            },
+        }
+
+        self.finished = last;
+
+        Ok(())
+    }
+}
+
+impl Read for TranscodingReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.output_offset == self.output.len() {
+            if self.finished {
+                return Ok(0);
+            }
+            self.decode_next_chunk()?;
+        }
+
+        let n = usize::min(buf.len(), self.output.len() - self.output_offset);
+        buf[..n].copy_from_slice(&self.output[self.output_offset..self.output_offset + n]);
+        self.output_offset += n;
+
+        Ok(n)
+    }
+}
+
+/// Encodes the UTF-8 CSV data in `data` to `encoding` and appends the result to `out`.
+///
+/// `data` must consist of complete characters. Characters that cannot be represented in the
+/// target encoding raise an error.
+pub fn encode_utf8_csv(encoding: CsvEncoding, data: &[u8], out: &mut Vec<u8>) -> ApolloResult<()> {
+    if encoding.is_utf8() {
+        out.extend_from_slice(data);
+        return Ok(());
+    }
+
+    let data = simdutf8::basic::from_utf8(data).map_err(
+        |_| apollo_err!(ComputeError: "cannot encode non-UTF-8 CSV data as {}", encoding.name()),
+    )?;
+
+    match encoding {
+        CsvEncoding::Latin1 => {
+            if let Some(c) = data.chars().find(|c| u32::from(*c) > 0xFF) {
+                apollo_bail!(ComputeError: "character {:?} cannot be encoded as latin-1", c)
+            }
+
+            let start = out.len();
+            out.resize(start + data.len(), 0);
+            let n_written = encoding_rs::mem::convert_utf8_to_latin1_lossy(
+                data.as_bytes(),
+                &mut out[start..],
+            );
+            out.truncate(start + n_written);
+        },
+        // `encoding_rs` only decodes UTF-16, it maps it to UTF-8 for output.
+        CsvEncoding::Utf16Le => {
+            out.reserve(2 * data.len());
+            out.extend(data.encode_utf16().flat_map(u16::to_le_bytes));
+        },
+        CsvEncoding::Utf16Be => {
+            out.reserve(2 * data.len());
+            out.extend(data.encode_utf16().flat_map(u16::to_be_bytes));
+        },
+        CsvEncoding::Windows1252 | CsvEncoding::ShiftJis => {
+            let mut encoder = encoding.encoding_rs().unwrap().new_encoder();
+            let max_len = encoder
+                .max_buffer_length_from_utf8_without_replacement(data.len())
+                .ok_or_else(|| apollo_err!(ComputeError: "CSV chunk too large to transcode"))?;
+
+            let start = out.len();
+            out.resize(start + max_len, 0);
+
+            let (result, _, n_written) =
+                encoder.encode_from_utf8_without_replacement(data, &mut out[start..], true);
+
+            match result {
+                EncoderResult::InputEmpty => {},
+                EncoderResult::OutputFull => unreachable!(),
+                EncoderResult::Unmappable(c) => {
+                    apollo_bail!(
+                        ComputeError: "character {:?} cannot be encoded as {}", c, encoding.name()
+                    )
+                },
+            }
+
+            out.truncate(start + n_written);
+        },
+        CsvEncoding::Utf8 | CsvEncoding::LossyUtf8 => unreachable!(),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use apollo_buffer::Buffer;
+
+    use super::*;
+
+    fn decode(bytes: &[u8], encoding: CsvEncoding) -> String {
+        let mut reader =
+            CompressedReader::try_new_with_encoding(Buffer::from_vec(bytes.to_vec()), encoding)
+                .unwrap();
+        let mut out = String::new();
+        reader.read_to_string(&mut out).unwrap();
+        out
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let text = "a,b\nÄpfel,Größe\n";
+
+        for encoding in [
+            CsvEncoding::Latin1,
+            CsvEncoding::Windows1252,
+            CsvEncoding::Utf16Le,
+            CsvEncoding::Utf16Be,
+        ] {
+            let mut encoded = vec![];
+            encode_utf8_csv(encoding, text.as_bytes(), &mut encoded).unwrap();
+            assert_eq!(decode(&encoded, encoding), text);
+        }
+
+        let text = "名前,値\n東京,1\n";
+        let mut encoded = vec![];
+        encode_utf8_csv(CsvEncoding::ShiftJis, text.as_bytes(), &mut encoded).unwrap();
+        assert_eq!(decode(&encoded, CsvEncoding::ShiftJis), text);
+    }
+
+    #[test]
+    fn test_utf16_bom_detection() {
+        let mut bytes = UTF16_BE_BOM.to_vec();
+        encode_utf8_csv(CsvEncoding::Utf16Be, b"x\n1\n", &mut bytes).unwrap();
+
+        assert_eq!(decode(&bytes, CsvEncoding::Utf8), "x\n1\n");
+        assert_eq!(decode(&bytes, CsvEncoding::Utf16Le), "x\n1\n");
+    }
+
+    #[test]
+    fn test_unmappable() {
+        let mut out = vec![];
+        assert!(encode_utf8_csv(CsvEncoding::Latin1, "€".as_bytes(), &mut out).is_err());
+        assert!(encode_utf8_csv(CsvEncoding::Windows1252, "€".as_bytes(), &mut out).is_ok());
+        assert_eq!(out, [0x80]);
+    }
+}
@@ -1,4 +1,5 @@
 //! Functionality for reading and writing CSV files.
 
+pub mod encoding;
 pub mod read;
 pub mod write;
@@ -22,6 +22,7 @@ mod parser;
 mod read_impl;
 mod reader;
 pub mod schema_inference;
+mod sniff;
 mod splitfields;
 pub mod streaming;
 mod utils;
@@ -29,12 +30,13 @@ mod utils;
 pub use options::{CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues};
 pub use parser::{SplitLines, count_rows, count_rows_from_slice_par};
 pub use reader::CsvReader;
+pub use sniff::{CsvDialect, sniff_dialect, sniff_dialect_from_slice};
 pub use streaming::read_until_start_and_infer_schema;
 
 pub mod _csv_read_internal {
     pub use super::builder::validate_utf8;
     pub use super::options::{CommentPrefix, NullValuesCompiled};
     pub use super::parser::{CountLines, SplitLines, is_comment_line};
-    pub use super::read_impl::{cast_columns, read_chunk};
+    pub use super::read_impl::{cast_columns, read_chunk, read_chunk_rejecting};
     pub use super::reader::prepare_csv_schema;
 }
@@ -5,6 +5,7 @@ use std::sync::Arc;
 use apollo_core::datatypes::{DataType, Field};
 use apollo_core::schema::{Schema, SchemaRef};
 use apollo_error::ApolloResult;
+use apollo_utils::pl_path::PlRefPath;
 use apollo_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -42,6 +43,14 @@ pub struct CsvReadOptions {
     pub raise_if_empty: bool,
     pub ignore_errors: bool,
     pub fields_to_cast: Vec<Field>,
+    /// Detect the separator, quote char, header and preamble lines from this many lines at the
+    /// start of the (first) file, overriding the configured values.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sniff_dialect: Option<usize>,
+    /// Write rows that fail to parse to a CSV file at this path instead of raising an error. See
+    /// [`RejectedRows`](crate::utils::RejectedRows).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub reject_rows: Option<PlRefPath>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -88,6 +97,8 @@ impl Default for CsvReadOptions {
             raise_if_empty: true,
             ignore_errors: false,
             fields_to_cast: vec![],
+            sniff_dialect: None,
+            reject_rows: None,
         }
     }
 }
@@ -247,6 +258,22 @@ impl CsvReadOptions {
         self
     }
 
+    /// Write rows that fail to parse to a CSV file at `reject_rows` and leave them out of the
+    /// result, instead of raising an error. Cannot be combined with `ignore_errors`.
+    pub fn with_reject_rows(mut self, reject_rows: Option<PlRefPath>) -> Self {
+        self.reject_rows = reject_rows;
+        self
+    }
+
+    /// Detect the dialect (separator, quote char, header and preamble lines) from the first
+    /// `sample_lines` lines instead of using the configured values. See [`CsvDialect`].
+    ///
+    /// [`CsvDialect`]: super::CsvDialect
+    pub fn with_sniff_dialect(mut self, sample_lines: Option<usize>) -> Self {
+        self.sniff_dialect = sample_lines;
+        self
+    }
+
     /// Apply a function to the parse options.
     pub fn map_parse_options<F: Fn(CsvParseOptions) -> CsvParseOptions>(
         mut self,
@@ -339,6 +366,16 @@ pub enum CsvEncoding {
     Utf8,
     /// Utf8 encoding and unknown bytes are replaced with �.
     LossyUtf8,
+    /// ISO-8859-1, every byte maps to the code point of the same value.
+    Latin1,
+    /// Windows-1252, the superset of Latin-1 used by most Windows exports.
+    Windows1252,
+    /// Shift_JIS (Windows-31J).
+    ShiftJis,
+    /// Little-endian UTF-16.
+    Utf16Le,
+    /// Big-endian UTF-16.
+    Utf16Be,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
@@ -12,7 +12,7 @@ use rayon::prelude::*;
 
 use super::CsvParseOptions;
 use super::builder::Builder;
-use super::options::{CommentPrefix, NullValuesCompiled};
+use super::options::{CommentPrefix, CsvEncoding, NullValuesCompiled};
 use super::splitfields::SplitFields;
 use crate::csv::read::read_until_start_and_infer_schema;
 use crate::prelude::CsvReadOptions;
@@ -26,6 +26,7 @@ pub fn count_rows(
     quote_char: Option<u8>,
     comment_prefix: Option<&CommentPrefix>,
     eol_char: u8,
+    encoding: CsvEncoding,
     has_header: bool,
     skip_lines: usize,
     skip_rows_before_header: usize,
@@ -50,6 +51,7 @@ pub fn count_rows(
         quote_char,
         comment_prefix,
         eol_char,
+        encoding,
         has_header,
         skip_lines,
         skip_rows_before_header,
@@ -65,18 +67,20 @@ pub fn count_rows_from_slice_par(
     quote_char: Option<u8>,
     comment_prefix: Option<&CommentPrefix>,
     eol_char: u8,
+    encoding: CsvEncoding,
     has_header: bool,
     skip_lines: usize,
     skip_rows_before_header: usize,
     skip_rows_after_header: usize,
 ) -> ApolloResult<usize> {
-    let mut reader = CompressedReader::try_new(buffer)?;
+    let mut reader = CompressedReader::try_new_with_encoding(buffer, encoding)?;
 
     let reader_options = CsvReadOptions {
         parse_options: Arc::new(CsvParseOptions {
             quote_char,
             comment_prefix: comment_prefix.cloned(),
             eol_char,
+            encoding,
             ..Default::default()
         }),
         has_header,
@@ -1,28 +1,32 @@
-use std::fmt;
 use std::sync::Mutex;
+use std::{cmp, fmt};
 
 use apollo_buffer::{Buffer, SharedStorage};
 use apollo_core::POOL;
 use apollo_core::prelude::*;
-use apollo_core::utils::{accumulate_dataframes_vertical, handle_casting_failures};
+use apollo_core::utils::{
+    accumulate_dataframes_vertical, accumulate_dataframes_vertical_unchecked,
+    handle_casting_failures,
+};
 #[cfg(feature = "apollo-time")]
 use apollo_time::prelude::*;
 use apollo_utils::relaxed_cell::RelaxedCell;
 use rayon::prelude::*;
 
 use super::CsvParseOptions;
-use super::builder::init_builders;
+use super::builder::{init_builders, validate_utf8};
 use super::options::{CsvEncoding, NullValuesCompiled};
-use super::parser::{CountLines, is_comment_line, parse_lines};
+use super::parser::{CountLines, SplitLines, is_comment_line, parse_lines};
 use super::reader::prepare_csv_schema;
+use super::streaming::read_until_start_and_infer_schema_with_line_offset;
 #[cfg(feature = "decompress")]
 use super::utils::decompress;
 use crate::RowIndex;
-use crate::csv::read::{CsvReadOptions, read_until_start_and_infer_schema};
+use crate::csv::read::CsvReadOptions;
 use crate::mmap::ReaderBytes;
 use crate::predicates::PhysicalIoExpr;
 use crate::utils::compression::{CompressedReader, SupportedCompression};
-use crate::utils::update_row_counts2;
+use crate::utils::{RejectLocation, RejectedRow, RejectedRows, update_row_counts2};
 
 pub fn cast_columns(
     df: &mut DataFrame,
@@ -119,6 +123,10 @@ pub(crate) struct CoreReader<'a> {
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     to_cast: Vec<Field>,
     row_index: Option<RowIndex>,
+    reject_rows: Option<RejectedRows>,
+    path: Option<PlSmallStr>,
+    /// Number of lines before the first content row.
+    line_offset: usize,
 }
 
 impl fmt::Debug for CoreReader<'_> {
@@ -153,6 +161,8 @@ impl<'a> CoreReader<'a> {
         skip_rows_after_header: usize,
         row_index: Option<RowIndex>,
         raise_if_empty: bool,
+        reject_rows: Option<RejectedRows>,
+        path: Option<PlSmallStr>,
     ) -> ApolloResult<CoreReader<'a>> {
         let separator = parse_options.separator;
 
@@ -170,8 +180,10 @@ impl<'a> CoreReader<'a> {
         // again after decompression.
         #[cfg(feature = "decompress")]
         {
-            let total_n_rows =
-                n_rows.map(|n| skip_rows + (has_header as usize) + skip_rows_after_header + n);
+            // Line ends can only be located in the encoded bytes for UTF-8.
+            let total_n_rows = n_rows
+                .filter(|_| parse_options.encoding.is_utf8())
+                .map(|n| skip_rows + (has_header as usize) + skip_rows_after_header + n);
             if let Some(b) = decompress(
                 &reader_bytes,
                 total_n_rows,
@@ -192,7 +204,8 @@ impl<'a> CoreReader<'a> {
             },
             ReaderBytes::Owned(slice) => slice.clone(),
         };
-        let mut compressed_reader = CompressedReader::try_new(reader_slice)?;
+        let mut compressed_reader =
+            CompressedReader::try_new_with_encoding(reader_slice, parse_options.encoding)?;
 
         let read_options = CsvReadOptions {
             parse_options: parse_options.clone(),
@@ -216,8 +229,13 @@ impl<'a> CoreReader<'a> {
         };
 
         // Since this is also used to skip to the start, always call it.
-        let (inferred_schema, leftover) =
-            read_until_start_and_infer_schema(&read_options, None, None, &mut compressed_reader)?;
+        let (inferred_schema, leftover, line_offset) =
+            read_until_start_and_infer_schema_with_line_offset(
+                &read_options,
+                None,
+                None,
+                &mut compressed_reader,
+            )?;
 
         let mut schema = match schema {
             Some(schema) => schema,
@@ -269,6 +287,9 @@ impl<'a> CoreReader<'a> {
             predicate,
             to_cast,
             row_index,
+            reject_rows,
+            path,
+            line_offset,
         })
     }
 
@@ -314,6 +335,38 @@ impl<'a> CoreReader<'a> {
         Ok(df)
     }
 
+    /// Re-parses a chunk that failed to parse row by row, collecting the rejected rows.
+    fn read_chunk_rejecting(
+        &self,
+        chunk: &[u8],
+        body_bytes: &[u8],
+        projection: &[usize],
+        check_utf8: bool,
+        reject_rows: &RejectedRows,
+    ) -> ApolloResult<DataFrame> {
+        let chunk_start = chunk.as_ptr() as usize - body_bytes.as_ptr() as usize;
+        let location = RejectLocation {
+            path: self.path.clone(),
+            line_offset: self.line_offset
+                + memchr::memchr_iter(self.parse_options.eol_char, &body_bytes[..chunk_start])
+                    .count(),
+        };
+
+        let (df, rejected) = read_chunk_rejecting(
+            chunk,
+            &self.parse_options,
+            &self.schema,
+            projection,
+            self.null_values.as_ref(),
+            &self.to_cast,
+            check_utf8,
+            &location,
+        )?;
+        reject_rows.extend(rejected)?;
+
+        Ok(df)
+    }
+
     // The code adheres to RFC 4180 in a strict sense, unless explicitly documented otherwise.
     // Malformed CSV is common, see e.g. the use of lazy_quotes, whitespace and comments.
     // In case malformed CSV is detected, a warning or an error will be issued.
@@ -434,63 +487,72 @@ impl<'a> CoreReader<'a> {
                     let projection = projection.as_ref();
                     let slf = &(*self);
                     s.spawn(move |_| {
-                        if check_utf8 && !super::builder::validate_utf8(b) {
-                            let mut results = results.lock().unwrap();
-                            results.push((
-                                b.as_ptr() as usize,
-                                Err(apollo_err!(ComputeError: "invalid utf-8 sequence")),
-                            ));
-                            return;
+                        let mut result = if check_utf8 && !validate_utf8(b) {
+                            Err(apollo_err!(ComputeError: "invalid utf-8 sequence"))
+                        } else {
+                            slf.read_chunk(b, projection, 0, count, Some(0), b.len())
+                        };
+
+                        // Rows are only rejected when the chunk as a whole failed to parse.
+                        let mut has_rejects = false;
+                        if let (Err(_), Some(reject_rows)) = (&result, &slf.reject_rows) {
+                            has_rejects = true;
+                            result = slf.read_chunk_rejecting(
+                                b,
+                                bytes,
+                                projection,
+                                check_utf8,
+                                reject_rows,
+                            );
                         }
 
-                        let result = slf
-                            .read_chunk(b, projection, 0, count, Some(0), b.len())
-                            .and_then(|mut df| {
-                                // Check malformed
-                                if df.height() > count
+                        let result = result.and_then(|mut df| {
+                            // Check malformed
+                            if !has_rejects
+                                && (df.height() > count
                                     || (df.height() < count
-                                        && slf.parse_options.comment_prefix.is_none())
-                                {
-                                    // Note: in case data is malformed, df.height() is more likely to be correct than count.
-                                    let msg = format!(
-                                        "CSV malformed: expected {} rows, \
+                                        && slf.parse_options.comment_prefix.is_none()))
+                            {
+                                // Note: in case data is malformed, df.height() is more likely to be correct than count.
+                                let msg = format!(
+                                    "CSV malformed: expected {} rows, \
                                         actual {} rows, in chunk starting at \
                                         byte offset {}, length {}",
-                                        count,
-                                        df.height(),
-                                        previous_total_offset,
-                                        b.len()
-                                    );
-                                    if slf.ignore_errors {
-                                        apollo_warn!("{}", msg);
-                                    } else {
-                                        apollo_bail!(ComputeError: msg);
-                                    }
-                                }
-
-                                if slf.n_rows.is_some() {
-                                    total_line_count.fetch_add(df.height());
+                                    count,
+                                    df.height(),
+                                    previous_total_offset,
+                                    b.len()
+                                );
+                                if slf.ignore_errors {
+                                    apollo_warn!("{}", msg);
+                                } else {
+                                    apollo_bail!(ComputeError: msg);
                                 }
-
-                                // We cannot use the line count as there can be comments in the lines so we must correct line counts later.
-                                if let Some(rc) = &slf.row_index {
-                                    // is first chunk
-                                    let offset = if std::ptr::eq(b.as_ptr(), bytes.as_ptr()) {
-                                        Some(rc.offset)
-                                    } else {
-                                        None
-                                    };
-
-                                    unsafe { df.with_row_index_mut(rc.name.clone(), offset) };
+                            }
+
+                            if slf.n_rows.is_some() {
+                                total_line_count.fetch_add(df.height());
+                            }
+
+                            // We cannot use the line count as there can be comments in the lines so we must correct line counts later.
+                            if let Some(rc) = &slf.row_index {
+                                // is first chunk
+                                let offset = if std::ptr::eq(b.as_ptr(), bytes.as_ptr()) {
+                                    Some(rc.offset)
+                                } else {
+                                    None
                                 };
 
-                                if let Some(predicate) = slf.predicate.as_ref() {
-                                    let s = predicate.evaluate_io(&df)?;
-                                    let mask = s.bool()?;
-                                    df = df.filter(mask)?;
-                                }
-                                Ok(df)
-                            });
+                                unsafe { df.with_row_index_mut(rc.name.clone(), offset) };
+                            };
+
+                            if let Some(predicate) = slf.predicate.as_ref() {
+                                let s = predicate.evaluate_io(&df)?;
+                                let mask = s.bool()?;
+                                df = df.filter(mask)?;
+                            }
+                            Ok(df)
+                        });
 
                         results.lock().unwrap().push((b.as_ptr() as usize, result));
                     });
@@ -598,3 +660,104 @@ pub fn read_chunk(
         .collect::<ApolloResult<Vec<_>>>()?;
     Ok(unsafe { DataFrame::new_unchecked_infer_height(columns) })
 }
+
+/// Parses `bytes`, leaving out rows that fail to parse or cast and returning them as rejected
+/// rows. Ranges of rows that fail are split in halves until the failing rows are found, so the
+/// valid rows are still parsed in bulk. This is slower than [`read_chunk`], so it should only be
+/// used after parsing the chunk as a whole has failed.
+#[allow(clippy::too_many_arguments)]
+pub fn read_chunk_rejecting(
+    bytes: &[u8],
+    parse_options: &CsvParseOptions,
+    schema: &Schema,
+    projection: &[usize],
+    null_values: Option<&NullValuesCompiled>,
+    to_cast: &[Field],
+    check_utf8: bool,
+    location: &RejectLocation,
+) -> ApolloResult<(DataFrame, Vec<RejectedRow>)> {
+    let eol_char = parse_options.eol_char;
+    let read_rows = |rows: &[u8], n_rows: usize| {
+        let mut df = read_chunk(
+            rows,
+            parse_options,
+            schema,
+            false,
+            projection,
+            0,
+            n_rows,
+            null_values,
+            usize::MAX,
+            rows.len(),
+            Some(0),
+        )?;
+        cast_columns(&mut df, to_cast, false, false)?;
+        ApolloResult::Ok(df)
+    };
+
+    // The non-comment lines as (line, end of the row including the line terminator, line index).
+    let mut lines = vec![];
+    let mut lines_into_chunk = 0;
+    let mut prev_start = 0;
+    let split_lines = SplitLines::new(
+        bytes,
+        parse_options.quote_char,
+        eol_char,
+        parse_options.comment_prefix.as_ref(),
+    );
+    for line in split_lines {
+        let start = line.as_ptr() as usize - bytes.as_ptr() as usize;
+        lines_into_chunk += memchr::memchr_iter(eol_char, &bytes[prev_start..start]).count();
+        prev_start = start;
+
+        if is_comment_line(line, parse_options.comment_prefix.as_ref()) {
+            continue;
+        }
+
+        // Include the line terminator, so that empty lines are parsed the same as in `read_chunk`.
+        let row_end = cmp::min(start + line.len() + 1, bytes.len());
+        lines.push((line, row_end, lines_into_chunk));
+    }
+
+    let mut dfs = vec![];
+    let mut rejected = vec![];
+    // Ranges are popped in order, as the second half of a range is pushed before the first.
+    let mut ranges = vec![0..lines.len()];
+    while let Some(range) = ranges.pop() {
+        if range.is_empty() {
+            continue;
+        }
+        let start = lines[range.start].0.as_ptr() as usize - bytes.as_ptr() as usize;
+        let rows = &bytes[start..lines[range.end - 1].1];
+
+        let result = if check_utf8 && !validate_utf8(rows) {
+            Err(apollo_err!(ComputeError: "invalid utf-8 sequence"))
+        } else {
+            read_rows(rows, range.len())
+        };
+
+        match result {
+            Ok(df) => dfs.push(df),
+            Err(err) if range.len() == 1 => {
+                let (line, _, line_idx) = lines[range.start];
+                let raw = line.strip_suffix(b"\r").unwrap_or(line);
+                rejected.push(location.reject(line_idx, raw, &err));
+            },
+            Err(_) => {
+                let mid = range.start + range.len() / 2;
+                ranges.push(mid..range.end);
+                ranges.push(range.start..mid);
+            },
+        }
+    }
+
+    let df = if dfs.is_empty() {
+        read_rows(&[], 0)?
+    } else {
+        let mut df = accumulate_dataframes_vertical_unchecked(dfs);
+        df.rechunk_mut_par();
+        df
+    };
+
+    Ok((df, rejected))
+}
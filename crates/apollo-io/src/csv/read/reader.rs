@@ -1,15 +1,17 @@
 use std::fs::File;
 use std::path::PathBuf;
 
+use apollo_core::config;
 use apollo_core::prelude::*;
 
 use super::options::CsvReadOptions;
 use super::read_impl::CoreReader;
+use super::sniff::sniff_dialect_from_slice;
 use crate::mmap::MmapBytesReader;
 use crate::path_utils::resolve_homedir;
 use crate::predicates::PhysicalIoExpr;
 use crate::shared::SerReader;
-use crate::utils::get_reader_bytes;
+use crate::utils::{RejectedRows, get_reader_bytes};
 
 /// Create a new DataFrame by reading a csv file.
 ///
@@ -100,6 +102,27 @@ impl<R: MmapBytesReader> CsvReader<R> {
     fn core_reader(&mut self) -> ApolloResult<CoreReader<'_>> {
         let reader_bytes = get_reader_bytes(&mut self.reader)?;
 
+        if let Some(sample_lines) = self.options.sniff_dialect {
+            let dialect = sniff_dialect_from_slice(&reader_bytes, &self.options, sample_lines)?;
+
+            if config::verbose() {
+                eprintln!("[CsvReader]: detected dialect: {dialect}");
+            }
+
+            dialect.apply(&mut self.options)?;
+        }
+
+        apollo_ensure!(
+            !(self.options.ignore_errors && self.options.reject_rows.is_some()),
+            InvalidOperation: "'ignore_errors' cannot be combined with 'reject_rows'"
+        );
+        let reject_rows = self
+            .options
+            .reject_rows
+            .as_ref()
+            .map(RejectedRows::try_new)
+            .transpose()?;
+
         let parse_options = self.options.get_parse_options();
 
         CoreReader::new(
@@ -122,6 +145,11 @@ impl<R: MmapBytesReader> CsvReader<R> {
             self.options.skip_rows_after_header,
             self.options.row_index.clone(),
             self.options.raise_if_empty,
+            reject_rows,
+            self.options
+                .path
+                .as_ref()
+                .map(|p| p.to_string_lossy().as_ref().into()),
         )
     }
 }
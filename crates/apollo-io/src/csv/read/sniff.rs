@@ -0,0 +1,543 @@
+//! Detection of the CSV dialect from a sample of the data.
+
+use std::fmt;
+use std::io::Read;
+
+use apollo_buffer::{Buffer, SharedStorage};
+use apollo_core::prelude::*;
+
+use super::options::CsvReadOptions;
+use super::parser::is_comment_line;
+use super::schema_inference::infer_field_schema;
+use super::splitfields::SplitFields;
+use crate::csv::write::UTF8_BOM;
+use crate::utils::compression::CompressedReader;
+
+/// Single-byte separators that are tried, in order of preference. A space is only considered
+/// when none of the others is consistent.
+const CANDIDATE_SEPARATORS: [u8; 6] = [b',', b'\t', b';', b'|', b':', b'^'];
+/// Maximum number of distinct non-ASCII characters that are tried as separator.
+const MAX_MULTI_BYTE_CANDIDATES: usize = 16;
+/// Upper bound on the number of bytes that are sampled.
+const MAX_SAMPLE_BYTES: usize = 4 * 1024 * 1024;
+const SAMPLE_READ_SIZE: u64 = 64 * 1024;
+/// Fraction of the sampled rows that must agree on the number of fields for a separator to be
+/// accepted.
+const MIN_CONSISTENCY: f64 = 0.9;
+
+/// The dialect of a CSV file, as detected by [`sniff_dialect`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CsvDialect {
+    /// The detected separator. This can be longer than a single byte, in which case the dialect
+    /// cannot be applied to [`CsvReadOptions`].
+    pub separator: PlSmallStr,
+    pub quote_char: Option<u8>,
+    pub has_header: bool,
+    /// Number of preamble lines before the header (or the first row if there is no header).
+    pub skip_lines: usize,
+}
+
+impl CsvDialect {
+    /// Pins the dialect into `options`, replacing the configured separator, quote character,
+    /// header and skip settings.
+    ///
+    /// Raises an error if the separator is longer than a single byte, as the parser does not
+    /// support those.
+    pub fn apply(&self, options: &mut CsvReadOptions) -> ApolloResult<()> {
+        let &[separator] = self.separator.as_bytes() else {
+            apollo_bail!(
+                ComputeError:
+                "detected multi-byte CSV separator {:?}; only single-byte separators are supported",
+                self.separator.as_str()
+            )
+        };
+
+        let parse_options = Arc::make_mut(&mut options.parse_options);
+        parse_options.separator = separator;
+        parse_options.quote_char = self.quote_char;
+
+        options.has_header = self.has_header;
+        options.skip_lines = self.skip_lines;
+        options.skip_rows = 0;
+        options.sniff_dialect = None;
+        Ok(())
+    }
+}
+
+impl fmt::Display for CsvDialect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "separator: {:?}, quote_char: {:?}, has_header: {}, skip_lines: {}",
+            self.separator.as_str(),
+            self.quote_char.map(char::from),
+            self.has_header,
+            self.skip_lines
+        )
+    }
+}
+
+/// Detects the separator, quote character, header row and preamble lines from the first
+/// `sample_lines` lines of `reader`.
+///
+/// Properties that cannot be detected from the sample are taken from `options`. A separator that
+/// is longer than a single byte is returned as is; the header of such data is not detected.
+pub fn sniff_dialect(
+    reader: &mut CompressedReader,
+    options: &CsvReadOptions,
+    sample_lines: usize,
+) -> ApolloResult<CsvDialect> {
+    let parse_options = &options.parse_options;
+    let eol_char = parse_options.eol_char;
+    let (sample, is_eof) = read_sample(reader, eol_char, sample_lines)?;
+
+    let mut lines = split_sample_lines(&sample, eol_char, is_eof, sample_lines);
+    if let Some(first) = lines.first_mut() {
+        let line = *first;
+        *first = line.strip_prefix(UTF8_BOM.as_slice()).unwrap_or(line);
+    }
+    let is_data: Vec<bool> = lines
+        .iter()
+        .map(|line| {
+            !line.is_empty() && !is_comment_line(line, parse_options.comment_prefix.as_ref())
+        })
+        .collect();
+
+    let quote_char = detect_quote_char(&lines, parse_options.quote_char);
+
+    let single_byte = CANDIDATE_SEPARATORS
+        .iter()
+        .filter_map(|&sep| score_separator(&lines, &is_data, sep, quote_char, eol_char))
+        .fold(None::<SeparatorScore>, |best, score| match best {
+            Some(best) if best.consistency >= score.consistency => Some(best),
+            _ => Some(score),
+        })
+        .or_else(|| score_separator(&lines, &is_data, b' ', quote_char, eol_char));
+
+    if let Some((separator, skip_lines)) =
+        detect_multi_byte_separator(&lines, &is_data, single_byte.as_ref())
+    {
+        return Ok(CsvDialect {
+            separator,
+            quote_char,
+            has_header: options.has_header,
+            skip_lines,
+        });
+    }
+
+    let Some(SeparatorScore {
+        separator,
+        n_fields,
+        skip_lines,
+        ..
+    }) = single_byte
+    else {
+        // A single column, only the header can be detected.
+        let skip_lines = is_data.iter().position(|x| *x).unwrap_or(0);
+        let rows = split_rows(&lines, &is_data, skip_lines, options, None, quote_char);
+
+        return Ok(CsvDialect {
+            separator: char::from(parse_options.separator).to_string().into(),
+            quote_char,
+            has_header: detect_header(&rows, options).unwrap_or(options.has_header),
+            skip_lines,
+        });
+    };
+
+    let rows = split_rows(
+        &lines,
+        &is_data,
+        skip_lines,
+        options,
+        Some((separator, n_fields)),
+        quote_char,
+    );
+
+    Ok(CsvDialect {
+        separator: char::from(separator).to_string().into(),
+        quote_char,
+        has_header: detect_header(&rows, options).unwrap_or(options.has_header),
+        skip_lines,
+    })
+}
+
+/// Same as [`sniff_dialect`], for (possibly compressed) CSV data in memory.
+pub fn sniff_dialect_from_slice(
+    bytes: &[u8],
+    options: &CsvReadOptions,
+    sample_lines: usize,
+) -> ApolloResult<CsvDialect> {
+    // SAFETY: The reader is dropped before returning, so it does not outlive `bytes`.
+    let bytes = Buffer::from_storage(unsafe { SharedStorage::from_slice_unchecked(bytes) });
+    let mut reader =
+        CompressedReader::try_new_with_encoding(bytes, options.parse_options.encoding)?;
+    sniff_dialect(&mut reader, options, sample_lines)
+}
+
+/// Reads until the sample holds more than `sample_lines` lines. Also returns whether the end of
+/// the data was reached.
+fn read_sample(
+    reader: &mut CompressedReader,
+    eol_char: u8,
+    sample_lines: usize,
+) -> ApolloResult<(Vec<u8>, bool)> {
+    let mut sample = Vec::new();
+    let mut n_lines = 0;
+
+    while n_lines <= sample_lines && sample.len() < MAX_SAMPLE_BYTES {
+        let offset = sample.len();
+        if reader
+            .by_ref()
+            .take(SAMPLE_READ_SIZE)
+            .read_to_end(&mut sample)?
+            == 0
+        {
+            return Ok((sample, true));
+        }
+        n_lines += memchr::memchr_iter(eol_char, &sample[offset..]).count();
+    }
+
+    Ok((sample, false))
+}
+
+/// Splits the sample into at most `sample_lines` complete lines, without line terminators.
+fn split_sample_lines(
+    sample: &[u8],
+    eol_char: u8,
+    is_eof: bool,
+    sample_lines: usize,
+) -> Vec<&[u8]> {
+    // Unless the end of the data was reached, the last line may be cut off.
+    let complete = if is_eof {
+        sample.strip_suffix(&[eol_char]).unwrap_or(sample)
+    } else {
+        &sample[..memchr::memrchr(eol_char, sample).unwrap_or(0)]
+    };
+
+    complete
+        .split(|&c| c == eol_char)
+        .take(sample_lines)
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+        .collect()
+}
+
+/// Detects the quote character from the fields that start and end with it.
+fn detect_quote_char(lines: &[&[u8]], default: Option<u8>) -> Option<u8> {
+    let is_boundary = |c: Option<&u8>| {
+        c.is_none_or(|c| CANDIDATE_SEPARATORS.contains(c) || c.is_ascii_whitespace())
+    };
+
+    let score = |quote: u8| {
+        let (mut opening, mut closing) = (0usize, 0usize);
+        for line in lines {
+            for (i, &c) in line.iter().enumerate() {
+                if c == quote {
+                    opening += is_boundary(i.checked_sub(1).map(|i| &line[i])) as usize;
+                    closing += is_boundary(line.get(i + 1)) as usize;
+                }
+            }
+        }
+        usize::min(opening, closing)
+    };
+
+    match (score(b'"'), score(b'\'')) {
+        (double, single) if single > double => Some(b'\''),
+        (0, _) => default,
+        _ => Some(b'"'),
+    }
+}
+
+struct SeparatorScore {
+    separator: u8,
+    n_fields: usize,
+    skip_lines: usize,
+    consistency: f64,
+}
+
+/// Scores a separator by how consistent the number of fields per line is. The lines before the
+/// first line with the most common number of fields are taken as preamble.
+fn score_separator(
+    lines: &[&[u8]],
+    is_data: &[bool],
+    separator: u8,
+    quote_char: Option<u8>,
+    eol_char: u8,
+) -> Option<SeparatorScore> {
+    let n_fields: Vec<usize> = lines
+        .iter()
+        .map(|line| SplitFields::new(line, separator, quote_char, eol_char).count())
+        .collect();
+
+    consistency_of(&n_fields, is_data).map(|(n_fields, skip_lines, consistency)| SeparatorScore {
+        separator,
+        n_fields,
+        skip_lines,
+        consistency,
+    })
+}
+
+/// Returns the most common number of fields, the number of preamble lines and the fraction of
+/// the data lines after the preamble with the most common number of fields.
+fn consistency_of(n_fields: &[usize], is_data: &[bool]) -> Option<(usize, usize, f64)> {
+    let mut counts = PlHashMap::<usize, usize>::new();
+    for (n, _) in n_fields.iter().zip(is_data).filter(|(_, d)| **d) {
+        *counts.entry(*n).or_default() += 1;
+    }
+
+    let (mode, _) = counts
+        .into_iter()
+        .max_by_key(|&(n_fields, count)| (count, n_fields))?;
+    if mode < 2 {
+        return None;
+    }
+
+    let skip_lines = n_fields
+        .iter()
+        .zip(is_data)
+        .position(|(n, d)| *d && *n == mode)
+        .unwrap();
+
+    let (total, consistent) = n_fields[skip_lines..]
+        .iter()
+        .zip(&is_data[skip_lines..])
+        .filter(|(_, d)| **d)
+        .fold((0usize, 0usize), |(total, consistent), (n, _)| {
+            (total + 1, consistent + (*n == mode) as usize)
+        });
+    let consistency = consistent as f64 / total as f64;
+
+    (consistency >= MIN_CONSISTENCY).then_some((mode, skip_lines, consistency))
+}
+
+/// Returns the separator and the number of preamble lines if the data is separated by a
+/// multi-byte separator that splits the lines more consistently than `single_byte`.
+fn detect_multi_byte_separator(
+    lines: &[&[u8]],
+    is_data: &[bool],
+    single_byte: Option<&SeparatorScore>,
+) -> Option<(PlSmallStr, usize)> {
+    // Data that is consistently split by a single byte is never taken as multi-byte separated,
+    // so that empty fields (e.g. `1,,3`) are not mistaken for a doubled separator.
+    if single_byte.is_some_and(|score| score.consistency >= 1.0) {
+        return None;
+    }
+
+    let texts: Vec<&str> = lines
+        .iter()
+        .map(|line| std::str::from_utf8(line).unwrap_or_default())
+        .collect();
+
+    // A doubled single-byte separator (e.g. `||`) differs from the single byte on values that
+    // contain the single byte.
+    let mut candidates: Vec<String> = CANDIDATE_SEPARATORS
+        .iter()
+        .filter(|&&sep| {
+            lines
+                .iter()
+                .any(|line| line.windows(2).any(|w| w == [sep, sep]))
+        })
+        .map(|&sep| char::from(sep).to_string().repeat(2))
+        .collect();
+
+    let mut symbols = Vec::new();
+    for c in texts.iter().flat_map(|text| text.chars()) {
+        if !c.is_ascii() && !c.is_alphanumeric() && !symbols.contains(&c) {
+            symbols.push(c);
+            if symbols.len() == MAX_MULTI_BYTE_CANDIDATES {
+                break;
+            }
+        }
+    }
+    candidates.extend(symbols.into_iter().map(String::from));
+
+    let n_data_lines = is_data.iter().filter(|d| **d).count();
+
+    candidates
+        .into_iter()
+        // Symbols such as currency signs lead or trail a value instead of separating two.
+        .filter(|sep| {
+            let n_at_edge = texts
+                .iter()
+                .zip(is_data)
+                .filter(|(text, d)| **d && (text.starts_with(sep) || text.ends_with(sep)))
+                .count();
+            2 * n_at_edge <= n_data_lines
+        })
+        .filter_map(|sep| {
+            let n_fields: Vec<usize> = texts
+                .iter()
+                .map(|text| text.split(sep.as_str()).count())
+                .collect();
+            let (_, skip_lines, consistency) = consistency_of(&n_fields, is_data)?;
+            single_byte
+                .is_none_or(|score| consistency > score.consistency)
+                .then_some((sep, skip_lines, consistency))
+        })
+        .max_by(|a, b| a.2.total_cmp(&b.2))
+        .map(|(sep, skip_lines, _)| (sep.into(), skip_lines))
+}
+
+/// Splits the data lines after the preamble into fields. Lines that do not have the expected
+/// number of fields are left out.
+fn split_rows<'a>(
+    lines: &[&'a [u8]],
+    is_data: &[bool],
+    skip_lines: usize,
+    options: &CsvReadOptions,
+    separator: Option<(u8, usize)>,
+    quote_char: Option<u8>,
+) -> Vec<Vec<&'a [u8]>> {
+    let eol_char = options.parse_options.eol_char;
+
+    lines[skip_lines..]
+        .iter()
+        .zip(&is_data[skip_lines..])
+        .filter(|(_, d)| **d)
+        .filter_map(|(line, _)| match separator {
+            None => Some(vec![unquote(line, quote_char)]),
+            Some((separator, n_fields)) => {
+                let row: Vec<&[u8]> = SplitFields::new(line, separator, quote_char, eol_char)
+                    .map(|(field, _)| unquote(field, quote_char))
+                    .collect();
+                (row.len() == n_fields).then_some(row)
+            },
+        })
+        .collect()
+}
+
+fn unquote(field: &[u8], quote_char: Option<u8>) -> &[u8] {
+    match (quote_char, field) {
+        (Some(q), [first, inner @ .., last]) if *first == q && *last == q => inner,
+        _ => field,
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ValueKind {
+    Boolean,
+    Numeric,
+    Temporal,
+    String,
+}
+
+fn value_kind(value: &[u8], options: &CsvReadOptions) -> ValueKind {
+    let Ok(value) = std::str::from_utf8(value) else {
+        return ValueKind::String;
+    };
+
+    match infer_field_schema(
+        value,
+        options.parse_options.try_parse_dates,
+        options.parse_options.decimal_comma,
+    ) {
+        DataType::Boolean => ValueKind::Boolean,
+        dt if dt.is_primitive_numeric() => ValueKind::Numeric,
+        dt if dt.is_temporal() => ValueKind::Temporal,
+        _ => ValueKind::String,
+    }
+}
+
+/// Detects whether the first row is a header by comparing its values to the values of the
+/// other rows in the same column. Returns `None` if the sample is inconclusive.
+fn detect_header(rows: &[Vec<&[u8]>], options: &CsvReadOptions) -> Option<bool> {
+    let (first, body) = rows.split_first()?;
+    let mut votes = 0i64;
+
+    for (i, header_value) in first.iter().enumerate() {
+        let values: Vec<&[u8]> = body
+            .iter()
+            .map(|row| row[i])
+            .filter(|value| !value.is_empty())
+            .collect();
+        let Some(kind) = values.first().map(|value| value_kind(value, options)) else {
+            continue;
+        };
+
+        if kind != ValueKind::String {
+            if values
+                .iter()
+                .any(|value| value_kind(value, options) != kind)
+            {
+                continue;
+            }
+            if !header_value.is_empty() {
+                votes += if value_kind(header_value, options) == kind {
+                    -1
+                } else {
+                    1
+                };
+            }
+        } else {
+            // Columns with values of a fixed length, such as codes.
+            let len = values[0].len();
+            if values.iter().all(|value| value.len() == len) {
+                votes += if header_value.len() == len { -1 } else { 1 };
+            }
+        }
+    }
+
+    match votes {
+        0 => None,
+        votes => Some(votes > 0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sniff(data: &str) -> ApolloResult<CsvDialect> {
+        sniff_dialect_from_slice(data.as_bytes(), &CsvReadOptions::default(), 100)
+    }
+
+    #[test]
+    fn test_sniff_separator_and_header() {
+        let dialect = sniff("name;age;city\nAnnabel;31;Oslo\nBert;45;\"Den Haag\"\n").unwrap();
+        assert_eq!(
+            dialect,
+            CsvDialect {
+                separator: ";".into(),
+                quote_char: Some(b'"'),
+                has_header: true,
+                skip_lines: 0,
+            }
+        );
+
+        let dialect = sniff("1\t2.5\ta\n3\t4.5\tb\n5\t6.0\tc\n").unwrap();
+        assert_eq!(dialect.separator, "\t");
+        assert!(!dialect.has_header);
+    }
+
+    #[test]
+    fn test_sniff_preamble_and_quotes() {
+        let dialect = sniff(
+            "Export of vendor data\ngenerated 2024-01-01\n\nid|label\n1|'a|b'\n2|'c'\n3|'d'\n",
+        )
+        .unwrap();
+        assert_eq!(dialect.separator, "|");
+        assert_eq!(dialect.quote_char, Some(b'\''));
+        assert_eq!(dialect.skip_lines, 3);
+        assert!(dialect.has_header);
+    }
+
+    #[test]
+    fn test_sniff_multi_byte_separator() {
+        let dialect = sniff("a¦b¦c\n1¦2¦3\n4¦5¦6\n").unwrap();
+        assert_eq!(dialect.separator, "¦");
+        let mut options = CsvReadOptions::default();
+        assert!(dialect.apply(&mut options).is_err());
+
+        let dialect = sniff("preamble\na||b||c\n1||x|y||3\n4||5||6\n").unwrap();
+        assert_eq!(dialect.separator, "||");
+        assert_eq!(dialect.skip_lines, 1);
+    }
+
+    #[test]
+    fn test_sniff_empty_fields() {
+        let dialect = sniff("1,,3\n4,,6\n").unwrap();
+        assert_eq!(dialect.separator, ",");
+
+        let dialect = sniff("a||b||c\n1||2||3\n").unwrap();
+        assert_eq!(dialect.separator, "|");
+    }
+}
@@ -30,9 +30,27 @@ pub type InspectContentFn<'a> = Box<dyn FnMut(&[u8]) + 'a>;
 pub fn read_until_start_and_infer_schema(
     options: &CsvReadOptions,
     projected_schema: Option<SchemaRef>,
-    mut inspect_first_content_row_fn: Option<InspectContentFn<'_>>,
+    inspect_first_content_row_fn: Option<InspectContentFn<'_>>,
     reader: &mut CompressedReader,
 ) -> ApolloResult<(Schema, Buffer<u8>)> {
+    let (schema, leftover, _) = read_until_start_and_infer_schema_with_line_offset(
+        options,
+        projected_schema,
+        inspect_first_content_row_fn,
+        reader,
+    )?;
+    Ok((schema, leftover))
+}
+
+/// Same as [`read_until_start_and_infer_schema`], but additionally returns the number of lines
+/// (according to the `eol_char`) that were consumed before the first content row.
+#[inline(never)]
+pub fn read_until_start_and_infer_schema_with_line_offset(
+    options: &CsvReadOptions,
+    projected_schema: Option<SchemaRef>,
+    mut inspect_first_content_row_fn: Option<InspectContentFn<'_>>,
+    reader: &mut CompressedReader,
+) -> ApolloResult<(Schema, Buffer<u8>, usize)> {
     #[derive(Copy, Clone)]
     enum State {
         // Ordered so that all states only happen after the ones before it.
@@ -75,11 +93,14 @@ pub fn read_until_start_and_infer_schema(
     let mut header_line = None;
     let mut content_lines = Vec::with_capacity(options.infer_schema_length.unwrap_or(256));
 
+    let mut line_offset = options.skip_lines;
+
     let leftover = for_each_line_from_reader(
         &options.parse_options,
         true,
         prev_leftover,
         reader,
+        &mut line_offset,
         |mem_slice_line| {
             let line = &*mem_slice_line;
 
@@ -162,7 +183,7 @@ pub fn read_until_start_and_infer_schema(
         projected_schema,
     )?;
 
-    Ok((inferred_schema, leftover))
+    Ok((inferred_schema, leftover, line_offset))
 }
 
 enum LineUse {
@@ -175,11 +196,14 @@ enum LineUse {
 ///
 /// Returning `ConsumeDiscard` after `ConsumeKeep` is a logic error, since a segmented `Buffer`
 /// can't be constructed.
+///
+/// `consumed_lines` is incremented by the number of lines before the returned leftover.
 fn for_each_line_from_reader(
     parse_options: &CsvParseOptions,
     is_file_start: bool,
     mut prev_leftover: Buffer<u8>,
     reader: &mut CompressedReader,
+    consumed_lines: &mut usize,
     mut line_fn: impl FnMut(Buffer<u8>) -> ApolloResult<LineUse>,
 ) -> ApolloResult<Buffer<u8>> {
     let mut is_first_line = is_file_start;
@@ -266,11 +290,14 @@ fn for_each_line_from_reader(
         } else {
             // Since `read_next_slice` has to copy the leftover bytes in the decompression case,
             // it's more efficient to hand in as little as possible.
+            *consumed_lines += count_eol(&slice[..unconsumed_offset], parse_options.eol_char);
             prev_leftover = slice.sliced(unconsumed_offset..);
         }
 
         if should_ret {
-            let leftover = prev_leftover.sliced(retain_offset.unwrap_or(0)..);
+            let retain_offset = retain_offset.unwrap_or(0);
+            *consumed_lines += count_eol(&prev_leftover[..retain_offset], parse_options.eol_char);
+            let leftover = prev_leftover.sliced(retain_offset..);
             return Ok(leftover);
         }
 
@@ -280,6 +307,10 @@ fn for_each_line_from_reader(
     }
 }
 
+fn count_eol(bytes: &[u8], eol_char: u8) -> usize {
+    memchr::memchr_iter(eol_char, bytes).count()
+}
+
 fn skip_lines_naive(
     eol_char: u8,
     skip_lines: usize,
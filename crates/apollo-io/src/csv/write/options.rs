@@ -6,6 +6,7 @@ use apollo_utils::pl_str::PlSmallStr;
 use serde::{Deserialize, Serialize};
 
 use crate::ExternalCompression;
+use crate::csv::read::CsvEncoding;
 
 /// Options for writing CSV files.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -13,6 +14,9 @@ use crate::ExternalCompression;
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
 pub struct CsvWriterOptions {
     pub include_bom: bool,
+    /// Text encoding of the output. The byte order mark, if requested, matches the encoding.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub encoding: CsvEncoding,
     #[cfg_attr(feature = "serde", serde(default))]
     pub compression: ExternalCompression,
     #[cfg_attr(feature = "serde", serde(default))]
@@ -26,6 +30,7 @@ impl Default for CsvWriterOptions {
     fn default() -> Self {
         Self {
             include_bom: false,
+            encoding: CsvEncoding::default(),
             compression: ExternalCompression::default(),
             check_extension: true,
             include_header: true,
@@ -9,6 +9,8 @@ use apollo_utils::reuse_vec::reuse_vec;
 use rayon::prelude::*;
 use serializer::{serializer_for, string_serializer};
 
+use crate::csv::encoding::encode_utf8_csv;
+use crate::csv::read::CsvEncoding;
 use crate::csv::write::SerializeOptions;
 
 type ColumnSerializer<'a> =
@@ -203,6 +205,7 @@ pub(crate) fn write(
     chunk_size: usize,
     options: Arc<SerializeOptions>,
     n_threads: usize,
+    encoding: CsvEncoding,
 ) -> ApolloResult<()> {
     let len = df.height();
     let total_rows_per_pool_iter = n_threads * chunk_size;
@@ -227,7 +230,13 @@ pub(crate) fn write(
                 // would go OOM
                 df.rechunk_mut();
 
-                csv_serializer.serialize_to_csv(&df, write_buffer)?;
+                if encoding.is_utf8() {
+                    csv_serializer.serialize_to_csv(&df, write_buffer)?;
+                } else {
+                    let mut utf8_buffer = Vec::new();
+                    csv_serializer.serialize_to_csv(&df, &mut utf8_buffer)?;
+                    encode_utf8_csv(encoding, &utf8_buffer, write_buffer)?;
+                }
 
                 Ok(())
             };
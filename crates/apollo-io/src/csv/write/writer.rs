@@ -8,8 +8,10 @@ use apollo_core::schema::Schema;
 use apollo_error::ApolloResult;
 use apollo_utils::pl_str::PlSmallStr;
 
-use super::write_impl::{UTF8_BOM, csv_header, write};
+use super::write_impl::{csv_header, write};
 use super::{QuoteStyle, SerializeOptions};
+use crate::csv::encoding::encode_utf8_csv;
+use crate::csv::read::CsvEncoding;
 use crate::shared::SerWriter;
 
 /// Write a DataFrame to csv.
@@ -22,6 +24,7 @@ pub struct CsvWriter<W: Write> {
     options: Arc<SerializeOptions>,
     header: bool,
     bom: bool,
+    encoding: CsvEncoding,
     batch_size: NonZeroUsize,
     n_threads: usize,
 }
@@ -38,6 +41,7 @@ where
             options: options.into(),
             header: true,
             bom: false,
+            encoding: CsvEncoding::Utf8,
             batch_size: NonZeroUsize::new(1024).unwrap(),
             n_threads: POOL.current_num_threads(),
         }
@@ -45,7 +49,7 @@ where
 
     fn finish(&mut self, df: &mut DataFrame) -> ApolloResult<()> {
         if self.bom {
-            self.buffer.write_all(&UTF8_BOM)?;
+            self.buffer.write_all(self.encoding.bom())?;
         }
         let names = df
             .get_column_names()
@@ -53,8 +57,7 @@ where
             .map(|x| x.as_str())
             .collect::<Vec<_>>();
         if self.header {
-            self.buffer
-                .write_all(&csv_header(names.as_slice(), &self.options)?)?;
+            self.write_header(names.as_slice())?;
         }
         write(
             &mut self.buffer,
@@ -62,6 +65,7 @@ where
             self.batch_size.into(),
             self.options.clone(),
             self.n_threads,
+            self.encoding,
         )
     }
 }
@@ -74,12 +78,26 @@ where
         Arc::make_mut(&mut self.options)
     }
 
-    /// Set whether to write UTF-8 UTF8_BOM.
+    fn write_header(&mut self, names: &[&str]) -> ApolloResult<()> {
+        let header = csv_header(names, &self.options)?;
+        let mut encoded = Vec::with_capacity(header.len());
+        encode_utf8_csv(self.encoding, &header, &mut encoded)?;
+        self.buffer.write_all(&encoded)?;
+        Ok(())
+    }
+
+    /// Set whether to write the byte order mark of the output encoding.
     pub fn include_bom(mut self, include_bom: bool) -> Self {
         self.bom = include_bom;
         self
     }
 
+    /// Set the text encoding of the output.
+    pub fn with_encoding(mut self, encoding: CsvEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
     /// Set whether to write headers.
     pub fn include_header(mut self, include_header: bool) -> Self {
         self.header = include_header;
@@ -201,7 +219,8 @@ impl<W: Write> BatchedWriter<W> {
     pub fn write_batch(&mut self, df: &DataFrame) -> ApolloResult<()> {
         if !self.has_written_bom {
             self.has_written_bom = true;
-            self.writer.buffer.write_all(&UTF8_BOM)?;
+            let bom = self.writer.encoding.bom();
+            self.writer.buffer.write_all(bom)?;
         }
 
         if !self.has_written_header {
@@ -212,9 +231,7 @@ impl<W: Write> BatchedWriter<W> {
                 .map(|x| x.as_str())
                 .collect::<Vec<_>>();
 
-            self.writer
-                .buffer
-                .write_all(&csv_header(names.as_slice(), &self.writer.options)?)?;
+            self.writer.write_header(names.as_slice())?;
         }
 
         write(
@@ -223,6 +240,7 @@ impl<W: Write> BatchedWriter<W> {
             self.writer.batch_size.into(),
             self.writer.options.clone(),
             self.writer.n_threads,
+            self.writer.encoding,
         )?;
         Ok(())
     }
@@ -231,7 +249,8 @@ impl<W: Write> BatchedWriter<W> {
     pub fn finish(&mut self) -> ApolloResult<()> {
         if !self.has_written_bom {
             self.has_written_bom = true;
-            self.writer.buffer.write_all(&UTF8_BOM)?;
+            let bom = self.writer.encoding.bom();
+            self.writer.buffer.write_all(bom)?;
         }
 
         if !self.has_written_header {
@@ -242,9 +261,7 @@ impl<W: Write> BatchedWriter<W> {
                 .map(|x| x.as_str())
                 .collect::<Vec<_>>();
 
-            self.writer
-                .buffer
-                .write_all(&csv_header(&names, &self.writer.options)?)?;
+            self.writer.write_header(&names)?;
         };
 
         Ok(())
@@ -75,6 +75,7 @@ use apollo_core::error::to_compute_err;
 use apollo_core::prelude::*;
 use apollo_error::{ApolloResult, apollo_bail};
 use apollo_json::json::write::FallibleStreamingIterator;
+use apollo_utils::pl_path::PlRefPath;
 use simd_json::BorrowedValue;
 
 use crate::mmap::{MmapBytesReader, ReaderBytes};
@@ -216,6 +217,8 @@ where
     schema: Option<SchemaRef>,
     schema_overwrite: Option<&'a Schema>,
     json_format: JsonFormat,
+    reject_rows: Option<PlRefPath>,
+    path: Option<PlSmallStr>,
 }
 
 pub fn remove_bom(bytes: &[u8]) -> ApolloResult<&[u8]> {
@@ -244,6 +247,8 @@ where
             schema: None,
             schema_overwrite: None,
             json_format: JsonFormat::Json,
+            reject_rows: None,
+            path: None,
         }
     }
 
@@ -264,6 +269,7 @@ where
         let out = match self.json_format {
             JsonFormat::Json => {
                 apollo_ensure!(!self.ignore_errors, InvalidOperation: "'ignore_errors' only supported in ndjson");
+                apollo_ensure!(self.reject_rows.is_none(), InvalidOperation: "'reject_rows' only supported in ndjson");
                 let mut bytes = rb.deref().to_vec();
                 let owned = &mut vec![];
                 #[expect(deprecated)] // JSON is not a row-format
@@ -378,6 +384,16 @@ where
                 df
             },
             JsonFormat::JsonLines => {
+                apollo_ensure!(
+                    !(self.ignore_errors && self.reject_rows.is_some()),
+                    InvalidOperation: "'ignore_errors' cannot be combined with 'reject_rows'"
+                );
+                let reject_rows = self
+                    .reject_rows
+                    .as_ref()
+                    .map(RejectedRows::try_new)
+                    .transpose()?;
+
                 let mut json_reader = CoreJsonReader::new(
                     rb,
                     None,
@@ -392,6 +408,8 @@ where
                     None,
                     None,
                     None,
+                    reject_rows,
+                    self.path,
                 )?;
                 let mut df: DataFrame = json_reader.as_df()?;
                 if self.rechunk {
@@ -468,4 +486,17 @@ where
         self.ignore_errors = ignore;
         self
     }
+
+    /// Write lines that fail to parse to a CSV file at `reject_rows` and leave them out of the
+    /// result, instead of raising an error. Only supported for [`JsonFormat::JsonLines`].
+    pub fn with_reject_rows(mut self, reject_rows: Option<PlRefPath>) -> Self {
+        self.reject_rows = reject_rows;
+        self
+    }
+
+    /// The path of the file that is read, written along with the rejected rows.
+    pub fn with_path(mut self, path: Option<PlSmallStr>) -> Self {
+        self.path = path;
+        self
+    }
 }
@@ -24,6 +24,8 @@ pub mod mmap;
 #[cfg(feature = "json")]
 pub mod ndjson;
 mod options;
+#[cfg(feature = "orc")]
+pub mod orc;
 #[cfg(feature = "parquet")]
 pub mod parquet;
 pub mod path_utils;
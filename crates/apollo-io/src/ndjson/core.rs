@@ -5,7 +5,9 @@ pub use arrow::array::StructArray;
 use num_traits::pow::Pow;
 use apollo_core::POOL;
 use apollo_core::prelude::*;
-use apollo_core::utils::accumulate_dataframes_vertical;
+use apollo_core::utils::{
+    accumulate_dataframes_vertical, accumulate_dataframes_vertical_unchecked,
+};
 use rayon::prelude::*;
 
 use crate::RowIndex;
@@ -13,6 +15,7 @@ use crate::mmap::ReaderBytes;
 use crate::ndjson::buffer::*;
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::*;
+use crate::utils::{RejectLocation, RejectedRow, RejectedRows};
 const NEWLINE: u8 = b'\n';
 const CLOSING_BRACKET: u8 = b'}';
 
@@ -28,6 +31,8 @@ pub(crate) struct CoreJsonReader<'a> {
     row_index: Option<&'a mut RowIndex>,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     projection: Option<Arc<[PlSmallStr]>>,
+    reject_rows: Option<RejectedRows>,
+    path: Option<PlSmallStr>,
 }
 impl<'a> CoreJsonReader<'a> {
     #[allow(clippy::too_many_arguments)]
@@ -45,6 +50,8 @@ impl<'a> CoreJsonReader<'a> {
         row_index: Option<&'a mut RowIndex>,
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
         projection: Option<Arc<[PlSmallStr]>>,
+        reject_rows: Option<RejectedRows>,
+        path: Option<PlSmallStr>,
     ) -> ApolloResult<CoreJsonReader<'a>> {
         let reader_bytes = reader_bytes;
 
@@ -73,6 +80,8 @@ impl<'a> CoreJsonReader<'a> {
             row_index,
             predicate,
             projection,
+            reject_rows,
+            path,
         })
     }
 
@@ -116,12 +125,25 @@ impl<'a> CoreJsonReader<'a> {
             file_chunks
                 .into_par_iter()
                 .map(|(start_pos, stop_at_nbytes)| {
-                    let mut local_df = parse_ndjson(
-                        &bytes[start_pos..stop_at_nbytes],
-                        Some(capacity),
-                        &self.schema,
-                        self.ignore_errors,
-                    )?;
+                    let chunk = &bytes[start_pos..stop_at_nbytes];
+                    let result =
+                        parse_ndjson(chunk, Some(capacity), &self.schema, self.ignore_errors);
+
+                    let mut local_df = match (result, &self.reject_rows) {
+                        // Rows are only rejected when the chunk as a whole failed to parse.
+                        (Err(_), Some(reject_rows)) => {
+                            let location = RejectLocation {
+                                path: self.path.clone(),
+                                line_offset: memchr::memchr_iter(NEWLINE, &bytes[..start_pos])
+                                    .count(),
+                            };
+                            let (df, rejected) =
+                                parse_ndjson_rejecting(chunk, &self.schema, &location)?;
+                            reject_rows.extend(rejected)?;
+                            df
+                        },
+                        (result, _) => result?,
+                    };
 
                     let prepredicate_height = local_df.height() as IdxSize;
                     if let Some(projection) = self.projection.as_deref() {
@@ -266,6 +288,59 @@ pub fn parse_ndjson(
     )
 }
 
+/// Parses `bytes`, leaving out lines that fail to parse and returning them as rejected rows.
+/// Ranges of lines that fail are split in halves until the failing lines are found, so the valid
+/// lines are still parsed in bulk. This is slower than [`parse_ndjson`], so it should only be
+/// used after parsing the chunk as a whole has failed.
+pub fn parse_ndjson_rejecting(
+    bytes: &[u8],
+    schema: &Schema,
+    location: &RejectLocation,
+) -> ApolloResult<(DataFrame, Vec<RejectedRow>)> {
+    let lines = bytes
+        .split(|&b| b == NEWLINE)
+        .enumerate()
+        .filter(|(_, line)| is_json_line(line))
+        .collect::<Vec<_>>();
+
+    let mut dfs = vec![];
+    let mut rejected = vec![];
+    // Ranges are popped in order, as the second half of a range is pushed before the first.
+    let mut ranges = vec![0..lines.len()];
+    while let Some(range) = ranges.pop() {
+        if range.is_empty() {
+            continue;
+        }
+        let start = lines[range.start].1.as_ptr() as usize - bytes.as_ptr() as usize;
+        let last = lines[range.end - 1].1;
+        let end = last.as_ptr() as usize - bytes.as_ptr() as usize + last.len();
+
+        match parse_ndjson(&bytes[start..end], Some(range.len()), schema, false) {
+            Ok(df) => dfs.push(df),
+            Err(err) if range.len() == 1 => {
+                let (lines_into_chunk, line) = lines[range.start];
+                let raw = line.strip_suffix(b"\r").unwrap_or(line);
+                rejected.push(location.reject(lines_into_chunk, raw, &err));
+            },
+            Err(_) => {
+                let mid = range.start + range.len() / 2;
+                ranges.push(mid..range.end);
+                ranges.push(range.start..mid);
+            },
+        }
+    }
+
+    let df = if dfs.is_empty() {
+        parse_ndjson(&[], Some(0), schema, false)?
+    } else {
+        let mut df = accumulate_dataframes_vertical_unchecked(dfs);
+        df.rechunk_mut_par();
+        df
+    };
+
+    Ok((df, rejected))
+}
+
 pub fn estimate_n_lines_in_file(file_bytes: &[u8], sample_size: usize) -> usize {
     if let Some((mean, std)) = get_line_stats_json(file_bytes, sample_size) {
         (file_bytes.len() as f32 / (mean - 0.01 * std)) as usize
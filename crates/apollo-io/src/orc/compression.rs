@@ -0,0 +1,153 @@
+use std::borrow::Cow;
+use std::io::{Read, Write};
+
+use apollo_error::{ApolloResult, apollo_bail, apollo_ensure, to_compute_err};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::proto::CompressionKind;
+
+/// Size of the uncompressed chunks that streams are split into when compressing.
+pub(super) const COMPRESSION_BLOCK_SIZE: usize = 256 * 1024;
+
+/// Compression codec of the streams in an ORC file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum OrcCompression {
+    Zlib,
+    Snappy,
+    Zstd,
+}
+
+impl From<Option<OrcCompression>> for CompressionKind {
+    fn from(value: Option<OrcCompression>) -> Self {
+        match value {
+            None => CompressionKind::None,
+            Some(OrcCompression::Zlib) => CompressionKind::Zlib,
+            Some(OrcCompression::Snappy) => CompressionKind::Snappy,
+            Some(OrcCompression::Zstd) => CompressionKind::Zstd,
+        }
+    }
+}
+
+/// Decompresses an ORC stream.
+///
+/// Compressed streams consist of chunks that are each prefixed by a 3 byte little-endian header
+/// holding `chunk_length << 1 | is_original`.
+pub(super) fn decompress_stream(kind: CompressionKind, data: &[u8]) -> ApolloResult<Cow<'_, [u8]>> {
+    if kind == CompressionKind::None {
+        return Ok(Cow::Borrowed(data));
+    }
+
+    let mut out = Vec::with_capacity(data.len() * 2);
+    let mut rest = data;
+
+    while !rest.is_empty() {
+        apollo_ensure!(
+            rest.len() >= 3,
+            ComputeError: "invalid ORC compression chunk header"
+        );
+
+        let header = u32::from_le_bytes([rest[0], rest[1], rest[2], 0]);
+        let is_original = header & 1 == 1;
+        let chunk_len = (header >> 1) as usize;
+
+        let Some(chunk) = rest.get(3..3 + chunk_len) else {
+            apollo_bail!(ComputeError: "ORC compression chunk exceeds stream length")
+        };
+        rest = &rest[3 + chunk_len..];
+
+        if is_original {
+            out.extend_from_slice(chunk);
+            continue;
+        }
+
+        match kind {
+            CompressionKind::None => unreachable!(),
+            CompressionKind::Zlib => {
+                flate2::read::DeflateDecoder::new(chunk)
+                    .read_to_end(&mut out)
+                    .map_err(to_compute_err)?;
+            },
+            CompressionKind::Snappy => {
+                let offset = out.len();
+                let len = snap::raw::decompress_len(chunk).map_err(to_compute_err)?;
+                out.resize(offset + len, 0);
+                snap::raw::Decoder::new()
+                    .decompress(chunk, &mut out[offset..])
+                    .map_err(to_compute_err)?;
+            },
+            CompressionKind::Zstd => {
+                zstd::stream::copy_decode(chunk, &mut out).map_err(to_compute_err)?;
+            },
+            CompressionKind::Lzo | CompressionKind::Lz4 => {
+                apollo_bail!(ComputeError: "ORC compression {:?} is not supported", kind)
+            },
+        }
+    }
+
+    Ok(Cow::Owned(out))
+}
+
+/// Compresses `data` as an ORC stream, appending the result to `out`.
+pub(super) fn compress_stream(
+    kind: CompressionKind,
+    data: &[u8],
+    out: &mut Vec<u8>,
+) -> ApolloResult<()> {
+    if kind == CompressionKind::None {
+        out.extend_from_slice(data);
+        return Ok(());
+    }
+
+    for chunk in data.chunks(COMPRESSION_BLOCK_SIZE) {
+        let compressed = match kind {
+            CompressionKind::Zlib => {
+                let mut encoder =
+                    flate2::write::DeflateEncoder::new(vec![], flate2::Compression::default());
+                encoder.write_all(chunk).map_err(to_compute_err)?;
+                encoder.finish().map_err(to_compute_err)?
+            },
+            CompressionKind::Snappy => snap::raw::Encoder::new()
+                .compress_vec(chunk)
+                .map_err(to_compute_err)?,
+            CompressionKind::Zstd => zstd::bulk::compress(chunk, 0).map_err(to_compute_err)?,
+            CompressionKind::None | CompressionKind::Lzo | CompressionKind::Lz4 => unreachable!(),
+        };
+
+        let (header, payload) = if compressed.len() < chunk.len() {
+            ((compressed.len() as u32) << 1, compressed.as_slice())
+        } else {
+            (((chunk.len() as u32) << 1) | 1, chunk)
+        };
+
+        out.extend_from_slice(&header.to_le_bytes()[..3]);
+        out.extend_from_slice(payload);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compression_roundtrip() {
+        let data = (0..1_000_000u32)
+            .flat_map(|x| (x % 1000).to_le_bytes())
+            .collect::<Vec<_>>();
+
+        for kind in [
+            CompressionKind::None,
+            CompressionKind::Zlib,
+            CompressionKind::Snappy,
+            CompressionKind::Zstd,
+        ] {
+            let mut compressed = vec![];
+            compress_stream(kind, &data, &mut compressed).unwrap();
+            assert_eq!(decompress_stream(kind, &compressed).unwrap().as_ref(), &data);
+        }
+    }
+}
@@ -0,0 +1,350 @@
+use std::sync::Arc;
+
+use apollo_core::prelude::*;
+use apollo_error::{ApolloResult, apollo_bail, apollo_ensure, apollo_err};
+use apollo_utils::format_pl_smallstr;
+
+use super::compression::decompress_stream;
+use super::proto::{
+    DateStatistics, DoubleStatistics, Footer, IntegerStatistics, Metadata, PostScript,
+    ProtoMessage, StringStatistics, StripeInformation, StripeStatistics, Type, TypeKind,
+};
+
+/// Magic bytes at the start of every ORC file.
+pub const ORC_MAGIC: &[u8; 3] = b"ORC";
+
+/// Metadata of an ORC file, parsed from the file tail.
+#[derive(Debug, Clone)]
+pub struct OrcFileMetadata {
+    pub(super) postscript: PostScript,
+    pub(super) footer: Footer,
+    pub(super) stripe_statistics: Vec<StripeStatistics>,
+    pub schema: SchemaRef,
+    /// ORC column id of every top-level column in `schema`.
+    pub(super) column_ids: Vec<usize>,
+    pub(super) column_kinds: Vec<TypeKind>,
+}
+
+impl OrcFileMetadata {
+    /// Reads the tail (postscript, footer and metadata) of the ORC file contained in `bytes`.
+    pub fn try_from_bytes(bytes: &[u8]) -> ApolloResult<Self> {
+        apollo_ensure!(
+            bytes.len() > ORC_MAGIC.len() + 1 && bytes.starts_with(ORC_MAGIC),
+            ComputeError: "not an ORC file"
+        );
+
+        let ps_len = *bytes.last().unwrap() as usize;
+        let ps_end = bytes.len() - 1;
+        let ps_start = ps_end
+            .checked_sub(ps_len)
+            .ok_or_else(|| apollo_err!(ComputeError: "invalid ORC postscript length"))?;
+        let postscript = PostScript::decode(&bytes[ps_start..ps_end])?;
+
+        if let Some(magic) = &postscript.magic {
+            apollo_ensure!(
+                magic.as_bytes() == ORC_MAGIC,
+                ComputeError: "invalid ORC postscript magic: {}", magic
+            );
+        }
+
+        let footer_start = ps_start
+            .checked_sub(postscript.footer_length as usize)
+            .ok_or_else(|| apollo_err!(ComputeError: "invalid ORC footer length"))?;
+        let footer = Footer::decode(&decompress_stream(
+            postscript.compression,
+            &bytes[footer_start..ps_start],
+        )?)?;
+
+        let metadata_start = footer_start
+            .checked_sub(postscript.metadata_length as usize)
+            .ok_or_else(|| apollo_err!(ComputeError: "invalid ORC metadata length"))?;
+        let stripe_statistics = if metadata_start < footer_start {
+            Metadata::decode(&decompress_stream(
+                postscript.compression,
+                &bytes[metadata_start..footer_start],
+            )?)?
+            .stripe_stats
+        } else {
+            vec![]
+        };
+
+        let Some(root) = footer.types.first() else {
+            apollo_bail!(ComputeError: "ORC file has no type information")
+        };
+        apollo_ensure!(
+            root.kind == TypeKind::Struct && root.subtypes.len() == root.field_names.len(),
+            ComputeError: "the root type of an ORC file must be a struct"
+        );
+
+        let mut schema = Schema::with_capacity(root.subtypes.len());
+        let mut column_ids = Vec::with_capacity(root.subtypes.len());
+        let mut column_kinds = Vec::with_capacity(root.subtypes.len());
+
+        for (name, &column_id) in root.field_names.iter().zip(root.subtypes.iter()) {
+            let column_id = column_id as usize;
+            let Some(ty) = footer.types.get(column_id) else {
+                apollo_bail!(ComputeError: "invalid ORC column id: {}", column_id)
+            };
+
+            schema.with_column(name.into(), orc_type_to_dtype(&footer.types, column_id)?);
+            column_ids.push(column_id);
+            column_kinds.push(ty.kind);
+        }
+
+        Ok(Self {
+            postscript,
+            footer,
+            stripe_statistics,
+            schema: Arc::new(schema),
+            column_ids,
+            column_kinds,
+        })
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.footer.number_of_rows as usize
+    }
+
+    pub fn num_stripes(&self) -> usize {
+        self.footer.stripes.len()
+    }
+
+    pub fn stripe_num_rows(&self, stripe_idx: usize) -> usize {
+        self.footer.stripes[stripe_idx].number_of_rows as usize
+    }
+
+    pub(super) fn stripe(&self, stripe_idx: usize) -> &StripeInformation {
+        &self.footer.stripes[stripe_idx]
+    }
+
+    /// Whether the file contains statistics for every stripe.
+    pub fn has_stripe_statistics(&self) -> bool {
+        !self.footer.stripes.is_empty()
+            && self.stripe_statistics.len() == self.footer.stripes.len()
+    }
+
+    /// Loads the per-stripe statistics of a top-level column for the stripes in `stripes`.
+    ///
+    /// Statistics that are missing or cannot be represented are returned as nulls.
+    pub fn stripe_column_statistics(
+        &self,
+        name: &str,
+        stripes: std::ops::Range<usize>,
+    ) -> ApolloResult<OrcStatisticsColumns> {
+        let height = stripes.len();
+
+        let (Some(idx), true) = (self.schema.index_of(name), self.has_stripe_statistics()) else {
+            let dtype = self.schema.get(name).cloned().unwrap_or(DataType::Null);
+            return Ok(OrcStatisticsColumns::new_null(&dtype, height));
+        };
+
+        let dtype = self.schema.get_at_index(idx).unwrap().1;
+        let column_id = self.column_ids[idx];
+
+        let stats = stripes
+            .clone()
+            .map(|i| self.stripe_statistics[i].col_stats.get(column_id))
+            .collect::<Vec<_>>();
+
+        let null_count = stripes
+            .clone()
+            .zip(stats.iter())
+            .map(|(i, stats)| {
+                let stats = (*stats)?;
+                if stats.has_null == Some(false) {
+                    return Some(0);
+                }
+                let n_values = stats.number_of_values?;
+                Some(self.stripe_num_rows(i).saturating_sub(n_values as usize) as IdxSize)
+            })
+            .collect::<Vec<_>>();
+
+        let (min, max) = match dtype {
+            DataType::Boolean => {
+                let min_max = stats
+                    .iter()
+                    .map(|stats| {
+                        let stats = (*stats)?;
+                        let n_values = stats.number_of_values?;
+                        let n_true = *stats.bucket_statistics.as_ref()?.count.first()?;
+                        (n_values > 0).then_some((n_true == n_values, n_true > 0))
+                    })
+                    .collect::<Vec<_>>();
+
+                (
+                    Series::new(
+                        PlSmallStr::EMPTY,
+                        min_max.iter().map(|x| x.map(|x| x.0)).collect::<Vec<_>>(),
+                    ),
+                    Series::new(
+                        PlSmallStr::EMPTY,
+                        min_max.iter().map(|x| x.map(|x| x.1)).collect::<Vec<_>>(),
+                    ),
+                )
+            },
+            dt if dt.is_integer() => {
+                let get = |f: fn(&IntegerStatistics) -> Option<i64>| {
+                    Series::new(
+                        PlSmallStr::EMPTY,
+                        stats
+                            .iter()
+                            .map(|s| s.and_then(|s| f(s.int_statistics.as_ref()?)))
+                            .collect::<Vec<_>>(),
+                    )
+                    .cast(dt)
+                };
+
+                (get(|s| s.minimum)?, get(|s| s.maximum)?)
+            },
+            dt if dt.is_float() => {
+                let get = |f: fn(&DoubleStatistics) -> Option<f64>| {
+                    Series::new(
+                        PlSmallStr::EMPTY,
+                        stats
+                            .iter()
+                            .map(|s| s.and_then(|s| f(s.double_statistics.as_ref()?)))
+                            // NaN bounds cannot be used for skipping.
+                            .map(|v| v.filter(|v| !v.is_nan()))
+                            .collect::<Vec<_>>(),
+                    )
+                    .cast(dt)
+                };
+
+                (get(|s| s.minimum)?, get(|s| s.maximum)?)
+            },
+            DataType::String => {
+                let get = |f: fn(&StringStatistics) -> Option<&String>| {
+                    Series::new(
+                        PlSmallStr::EMPTY,
+                        stats
+                            .iter()
+                            .map(|s| s.and_then(|s| f(s.string_statistics.as_ref()?)))
+                            .map(|v| v.map(|v| v.as_str()))
+                            .collect::<Vec<_>>(),
+                    )
+                };
+
+                (
+                    get(|s| s.minimum.as_ref()),
+                    get(|s| s.maximum.as_ref()),
+                )
+            },
+            DataType::Date => {
+                let get = |f: fn(&DateStatistics) -> Option<i32>| {
+                    Series::new(
+                        PlSmallStr::EMPTY,
+                        stats
+                            .iter()
+                            .map(|s| s.and_then(|s| f(s.date_statistics.as_ref()?)))
+                            .collect::<Vec<_>>(),
+                    )
+                    .cast(dt)
+                };
+
+                (get(|s| s.minimum)?, get(|s| s.maximum)?)
+            },
+            dt => {
+                let mut out = OrcStatisticsColumns::new_null(dt, height);
+                out.null_count = Column::new(PlSmallStr::EMPTY, null_count);
+                return Ok(out);
+            },
+        };
+
+        Ok(OrcStatisticsColumns {
+            min: min.into_column(),
+            max: max.into_column(),
+            null_count: Column::new(PlSmallStr::EMPTY, null_count),
+        })
+    }
+}
+
+/// Per-stripe statistics of a single column.
+pub struct OrcStatisticsColumns {
+    pub min: Column,
+    pub max: Column,
+    pub null_count: Column,
+}
+
+impl OrcStatisticsColumns {
+    fn new_null(dtype: &DataType, height: usize) -> Self {
+        Self {
+            min: Column::full_null(PlSmallStr::EMPTY, height, dtype),
+            max: Column::full_null(PlSmallStr::EMPTY, height, dtype),
+            null_count: Column::full_null(PlSmallStr::EMPTY, height, &IDX_DTYPE),
+        }
+    }
+}
+
+/// Converts the ORC type with the given id to a [`DataType`].
+///
+/// Nested types are converted so that they show up in the schema, but they cannot be decoded
+/// yet. This is only raised once such a column is read.
+fn orc_type_to_dtype(types: &[Type], type_id: usize) -> ApolloResult<DataType> {
+    use TypeKind as K;
+
+    let ty = &types[type_id];
+    let child = |i: usize| -> ApolloResult<DataType> {
+        let Some(&child_id) = ty.subtypes.get(i) else {
+            apollo_bail!(ComputeError: "missing subtype of ORC type {}", type_id)
+        };
+        let child_id = child_id as usize;
+        // Types are stored in pre-order, which also rules out cycles.
+        apollo_ensure!(
+            child_id > type_id && child_id < types.len(),
+            ComputeError: "invalid ORC type id: {}", child_id
+        );
+        orc_type_to_dtype(types, child_id)
+    };
+    let fields = || -> ApolloResult<Vec<Field>> {
+        (0..ty.subtypes.len())
+            .map(|i| {
+                let name = match ty.field_names.get(i) {
+                    Some(name) => PlSmallStr::from_str(name),
+                    None => format_pl_smallstr!("{}", i),
+                };
+                Ok(Field::new(name, child(i)?))
+            })
+            .collect()
+    };
+
+    Ok(match ty.kind {
+        K::Boolean => DataType::Boolean,
+        K::Byte => DataType::Int8,
+        K::Short => DataType::Int16,
+        K::Int => DataType::Int32,
+        K::Long => DataType::Int64,
+        K::Float => DataType::Float32,
+        K::Double => DataType::Float64,
+        K::String | K::Varchar | K::Char => DataType::String,
+        K::Binary => DataType::Binary,
+        K::Date => DataType::Date,
+        // Timestamps are read as-is, without applying the writer timezone.
+        K::Timestamp => DataType::Datetime(TimeUnit::Nanoseconds, None),
+        // Instants are anchored to UTC.
+        K::TimestampInstant => DataType::Datetime(TimeUnit::Nanoseconds, Some(TimeZone::UTC)),
+        // Writers that omit the precision and scale use these defaults.
+        K::Decimal => DataType::Decimal(
+            ty.precision.unwrap_or(38) as usize,
+            ty.scale.unwrap_or(10) as usize,
+        ),
+        K::List => DataType::List(Box::new(child(0)?)),
+        #[cfg(feature = "dtype-map")]
+        K::Map => DataType::Map(Box::new(child(0)?), Box::new(child(1)?)),
+        #[cfg(not(feature = "dtype-map"))]
+        K::Map => DataType::List(Box::new(DataType::Struct(vec![
+            Field::new(PlSmallStr::from_static("key"), child(0)?),
+            Field::new(PlSmallStr::from_static("value"), child(1)?),
+        ]))),
+        K::Struct => DataType::Struct(fields()?),
+        #[cfg(feature = "dtype-union")]
+        K::Union => DataType::Union(
+            fields()?,
+            UnionLayout {
+                dense: true,
+                type_ids: None,
+            },
+        ),
+        #[cfg(not(feature = "dtype-union"))]
+        K::Union => DataType::Struct(fields()?),
+    })
+}
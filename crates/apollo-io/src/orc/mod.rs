@@ -0,0 +1,17 @@
+//! Reading and writing of [Apache ORC](https://orc.apache.org) files.
+//!
+//! The reader supports flat schemas with run length encoding versions 1 and 2, direct and
+//! dictionary encoded strings, decimals, and zlib, snappy and zstd compression. Nested columns
+//! are part of the schema, but reading them raises an error.
+mod compression;
+mod metadata;
+mod proto;
+mod read;
+mod rle;
+mod stripe;
+mod write;
+
+pub use compression::OrcCompression;
+pub use metadata::*;
+pub use read::*;
+pub use write::*;
@@ -0,0 +1,914 @@
+//! Minimal protobuf encoding and decoding of the ORC file tail and stripe footer messages.
+//!
+//! Only the fields that are needed for reading and writing flat ORC files are modelled, all other
+//! fields are skipped when decoding.
+use apollo_error::{ApolloResult, apollo_bail, apollo_err};
+
+const WIRE_VARINT: u8 = 0;
+const WIRE_FIXED64: u8 = 1;
+const WIRE_LEN: u8 = 2;
+const WIRE_FIXED32: u8 = 5;
+
+pub(super) struct ProtoReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ProtoReader<'a> {
+    pub(super) fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    fn read_byte(&mut self) -> ApolloResult<u8> {
+        let b = *self
+            .buf
+            .get(self.pos)
+            .ok_or_else(|| apollo_err!(ComputeError: "unexpected end of ORC protobuf message"))?;
+        self.pos += 1;
+        Ok(b)
+    }
+
+    fn read_varint(&mut self) -> ApolloResult<u64> {
+        let mut out = 0u64;
+
+        for shift in (0..64).step_by(7) {
+            let b = self.read_byte()?;
+            out |= u64::from(b & 0x7f) << shift;
+
+            if b & 0x80 == 0 {
+                return Ok(out);
+            }
+        }
+
+        apollo_bail!(ComputeError: "invalid varint in ORC protobuf message")
+    }
+
+    fn read_sint(&mut self) -> ApolloResult<i64> {
+        let v = self.read_varint()?;
+        Ok(((v >> 1) as i64) ^ -((v & 1) as i64))
+    }
+
+    fn read_fixed<const N: usize>(&mut self) -> ApolloResult<[u8; N]> {
+        let out = self
+            .buf
+            .get(self.pos..self.pos + N)
+            .ok_or_else(|| apollo_err!(ComputeError: "unexpected end of ORC protobuf message"))?;
+        self.pos += N;
+        Ok(out.try_into().unwrap())
+    }
+
+    fn read_len_delimited(&mut self) -> ApolloResult<&'a [u8]> {
+        let len = self.read_varint()? as usize;
+        let out = self
+            .buf
+            .get(self.pos..self.pos + len)
+            .ok_or_else(|| apollo_err!(ComputeError: "unexpected end of ORC protobuf message"))?;
+        self.pos += len;
+        Ok(out)
+    }
+
+    fn read_string(&mut self) -> ApolloResult<String> {
+        let bytes = self.read_len_delimited()?;
+        String::from_utf8(bytes.to_vec())
+            .map_err(|_| apollo_err!(ComputeError: "invalid utf-8 in ORC protobuf message"))
+    }
+
+    /// Returns the next `(field_number, wire_type)`, or `None` at the end of the message.
+    fn next_field(&mut self) -> ApolloResult<Option<(u32, u8)>> {
+        if self.pos >= self.buf.len() {
+            return Ok(None);
+        }
+
+        let key = self.read_varint()?;
+        Ok(Some(((key >> 3) as u32, (key & 0x7) as u8)))
+    }
+
+    fn skip(&mut self, wire_type: u8) -> ApolloResult<()> {
+        match wire_type {
+            WIRE_VARINT => {
+                self.read_varint()?;
+            },
+            WIRE_FIXED64 => {
+                self.read_fixed::<8>()?;
+            },
+            WIRE_LEN => {
+                self.read_len_delimited()?;
+            },
+            WIRE_FIXED32 => {
+                self.read_fixed::<4>()?;
+            },
+            _ => apollo_bail!(ComputeError: "unsupported protobuf wire type {} in ORC file", wire_type),
+        }
+
+        Ok(())
+    }
+
+    /// Reads a repeated integer field that may or may not be packed.
+    fn read_repeated_varint(&mut self, wire_type: u8, out: &mut Vec<u64>) -> ApolloResult<()> {
+        if wire_type == WIRE_LEN {
+            let mut packed = ProtoReader::new(self.read_len_delimited()?);
+            while packed.pos < packed.buf.len() {
+                out.push(packed.read_varint()?);
+            }
+        } else {
+            out.push(self.read_varint()?);
+        }
+
+        Ok(())
+    }
+
+    fn read_message<T: ProtoMessage>(&mut self) -> ApolloResult<T> {
+        T::decode(self.read_len_delimited()?)
+    }
+}
+
+#[derive(Default)]
+pub(super) struct ProtoWriter {
+    buf: Vec<u8>,
+}
+
+impl ProtoWriter {
+    pub(super) fn into_inner(self) -> Vec<u8> {
+        self.buf
+    }
+
+    fn write_varint(&mut self, mut v: u64) {
+        while v >= 0x80 {
+            self.buf.push((v as u8) | 0x80);
+            v >>= 7;
+        }
+        self.buf.push(v as u8);
+    }
+
+    fn write_key(&mut self, field: u32, wire_type: u8) {
+        self.write_varint(((field as u64) << 3) | wire_type as u64);
+    }
+
+    fn uint(&mut self, field: u32, v: u64) {
+        self.write_key(field, WIRE_VARINT);
+        self.write_varint(v);
+    }
+
+    fn sint(&mut self, field: u32, v: i64) {
+        self.write_key(field, WIRE_VARINT);
+        self.write_varint(((v << 1) ^ (v >> 63)) as u64);
+    }
+
+    fn double(&mut self, field: u32, v: f64) {
+        self.write_key(field, WIRE_FIXED64);
+        self.buf.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn bytes(&mut self, field: u32, v: &[u8]) {
+        self.write_key(field, WIRE_LEN);
+        self.write_varint(v.len() as u64);
+        self.buf.extend_from_slice(v);
+    }
+
+    fn packed(&mut self, field: u32, values: impl IntoIterator<Item = u64>) {
+        let mut packed = ProtoWriter::default();
+        for v in values {
+            packed.write_varint(v);
+        }
+        self.bytes(field, &packed.buf);
+    }
+
+    fn message<T: ProtoMessage>(&mut self, field: u32, msg: &T) {
+        let mut inner = ProtoWriter::default();
+        msg.encode(&mut inner);
+        self.bytes(field, &inner.buf);
+    }
+}
+
+pub(super) trait ProtoMessage: Sized + Default {
+    fn decode(buf: &[u8]) -> ApolloResult<Self> {
+        let mut reader = ProtoReader::new(buf);
+        let mut out = Self::default();
+
+        while let Some((field, wire_type)) = reader.next_field()? {
+            out.decode_field(&mut reader, field, wire_type)?;
+        }
+
+        Ok(out)
+    }
+
+    fn decode_field(&mut self, r: &mut ProtoReader, field: u32, wire_type: u8)
+    -> ApolloResult<()>;
+
+    fn encode(&self, w: &mut ProtoWriter);
+
+    fn encode_to_vec(&self) -> Vec<u8> {
+        let mut w = ProtoWriter::default();
+        self.encode(&mut w);
+        w.into_inner()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(super) enum CompressionKind {
+    #[default]
+    None,
+    Zlib,
+    Snappy,
+    Lzo,
+    Lz4,
+    Zstd,
+}
+
+impl CompressionKind {
+    fn try_from_u64(v: u64) -> ApolloResult<Self> {
+        Ok(match v {
+            0 => Self::None,
+            1 => Self::Zlib,
+            2 => Self::Snappy,
+            3 => Self::Lzo,
+            4 => Self::Lz4,
+            5 => Self::Zstd,
+            v => apollo_bail!(ComputeError: "unknown ORC compression kind: {}", v),
+        })
+    }
+
+    fn to_u64(self) -> u64 {
+        match self {
+            Self::None => 0,
+            Self::Zlib => 1,
+            Self::Snappy => 2,
+            Self::Lzo => 3,
+            Self::Lz4 => 4,
+            Self::Zstd => 5,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct PostScript {
+    pub footer_length: u64,
+    pub compression: CompressionKind,
+    pub compression_block_size: Option<u64>,
+    pub version: Vec<u64>,
+    pub metadata_length: u64,
+    pub writer_version: Option<u64>,
+    pub magic: Option<String>,
+}
+
+impl ProtoMessage for PostScript {
+    fn decode_field(&mut self, r: &mut ProtoReader, field: u32, wire_type: u8) -> ApolloResult<()> {
+        match field {
+            1 => self.footer_length = r.read_varint()?,
+            2 => self.compression = CompressionKind::try_from_u64(r.read_varint()?)?,
+            3 => self.compression_block_size = Some(r.read_varint()?),
+            4 => r.read_repeated_varint(wire_type, &mut self.version)?,
+            5 => self.metadata_length = r.read_varint()?,
+            6 => self.writer_version = Some(r.read_varint()?),
+            8000 => self.magic = Some(r.read_string()?),
+            _ => r.skip(wire_type)?,
+        }
+        Ok(())
+    }
+
+    fn encode(&self, w: &mut ProtoWriter) {
+        w.uint(1, self.footer_length);
+        w.uint(2, self.compression.to_u64());
+        if let Some(v) = self.compression_block_size {
+            w.uint(3, v);
+        }
+        w.packed(4, self.version.iter().copied());
+        w.uint(5, self.metadata_length);
+        if let Some(v) = self.writer_version {
+            w.uint(6, v);
+        }
+        if let Some(v) = &self.magic {
+            w.bytes(8000, v.as_bytes());
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct Footer {
+    pub header_length: u64,
+    pub content_length: u64,
+    pub stripes: Vec<StripeInformation>,
+    pub types: Vec<Type>,
+    pub number_of_rows: u64,
+    pub statistics: Vec<ColumnStatistics>,
+    pub row_index_stride: u64,
+}
+
+impl ProtoMessage for Footer {
+    fn decode_field(&mut self, r: &mut ProtoReader, field: u32, wire_type: u8) -> ApolloResult<()> {
+        match field {
+            1 => self.header_length = r.read_varint()?,
+            2 => self.content_length = r.read_varint()?,
+            3 => self.stripes.push(r.read_message()?),
+            4 => self.types.push(r.read_message()?),
+            6 => self.number_of_rows = r.read_varint()?,
+            7 => self.statistics.push(r.read_message()?),
+            8 => self.row_index_stride = r.read_varint()?,
+            _ => r.skip(wire_type)?,
+        }
+        Ok(())
+    }
+
+    fn encode(&self, w: &mut ProtoWriter) {
+        w.uint(1, self.header_length);
+        w.uint(2, self.content_length);
+        for v in &self.stripes {
+            w.message(3, v);
+        }
+        for v in &self.types {
+            w.message(4, v);
+        }
+        w.uint(6, self.number_of_rows);
+        for v in &self.statistics {
+            w.message(7, v);
+        }
+        w.uint(8, self.row_index_stride);
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct StripeInformation {
+    pub offset: u64,
+    pub index_length: u64,
+    pub data_length: u64,
+    pub footer_length: u64,
+    pub number_of_rows: u64,
+}
+
+impl ProtoMessage for StripeInformation {
+    fn decode_field(&mut self, r: &mut ProtoReader, field: u32, wire_type: u8) -> ApolloResult<()> {
+        match field {
+            1 => self.offset = r.read_varint()?,
+            2 => self.index_length = r.read_varint()?,
+            3 => self.data_length = r.read_varint()?,
+            4 => self.footer_length = r.read_varint()?,
+            5 => self.number_of_rows = r.read_varint()?,
+            _ => r.skip(wire_type)?,
+        }
+        Ok(())
+    }
+
+    fn encode(&self, w: &mut ProtoWriter) {
+        w.uint(1, self.offset);
+        w.uint(2, self.index_length);
+        w.uint(3, self.data_length);
+        w.uint(4, self.footer_length);
+        w.uint(5, self.number_of_rows);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(super) enum TypeKind {
+    #[default]
+    Boolean,
+    Byte,
+    Short,
+    Int,
+    Long,
+    Float,
+    Double,
+    String,
+    Binary,
+    Timestamp,
+    List,
+    Map,
+    Struct,
+    Union,
+    Decimal,
+    Date,
+    Varchar,
+    Char,
+    TimestampInstant,
+}
+
+impl TypeKind {
+    fn try_from_u64(v: u64) -> ApolloResult<Self> {
+        use TypeKind as K;
+        Ok(match v {
+            0 => K::Boolean,
+            1 => K::Byte,
+            2 => K::Short,
+            3 => K::Int,
+            4 => K::Long,
+            5 => K::Float,
+            6 => K::Double,
+            7 => K::String,
+            8 => K::Binary,
+            9 => K::Timestamp,
+            10 => K::List,
+            11 => K::Map,
+            12 => K::Struct,
+            13 => K::Union,
+            14 => K::Decimal,
+            15 => K::Date,
+            16 => K::Varchar,
+            17 => K::Char,
+            18 => K::TimestampInstant,
+            v => apollo_bail!(ComputeError: "unknown ORC type kind: {}", v),
+        })
+    }
+
+    fn to_u64(self) -> u64 {
+        use TypeKind as K;
+        match self {
+            K::Boolean => 0,
+            K::Byte => 1,
+            K::Short => 2,
+            K::Int => 3,
+            K::Long => 4,
+            K::Float => 5,
+            K::Double => 6,
+            K::String => 7,
+            K::Binary => 8,
+            K::Timestamp => 9,
+            K::List => 10,
+            K::Map => 11,
+            K::Struct => 12,
+            K::Union => 13,
+            K::Decimal => 14,
+            K::Date => 15,
+            K::Varchar => 16,
+            K::Char => 17,
+            K::TimestampInstant => 18,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct Type {
+    pub kind: TypeKind,
+    pub subtypes: Vec<u64>,
+    pub field_names: Vec<String>,
+    /// Precision and scale of a decimal type.
+    pub precision: Option<u64>,
+    pub scale: Option<u64>,
+}
+
+impl ProtoMessage for Type {
+    fn decode_field(&mut self, r: &mut ProtoReader, field: u32, wire_type: u8) -> ApolloResult<()> {
+        match field {
+            1 => self.kind = TypeKind::try_from_u64(r.read_varint()?)?,
+            2 => r.read_repeated_varint(wire_type, &mut self.subtypes)?,
+            3 => self.field_names.push(r.read_string()?),
+            5 => self.precision = Some(r.read_varint()?),
+            6 => self.scale = Some(r.read_varint()?),
+            _ => r.skip(wire_type)?,
+        }
+        Ok(())
+    }
+
+    fn encode(&self, w: &mut ProtoWriter) {
+        w.uint(1, self.kind.to_u64());
+        if !self.subtypes.is_empty() {
+            w.packed(2, self.subtypes.iter().copied());
+        }
+        for v in &self.field_names {
+            w.bytes(3, v.as_bytes());
+        }
+        if let Some(v) = self.precision {
+            w.uint(5, v);
+        }
+        if let Some(v) = self.scale {
+            w.uint(6, v);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(super) struct IntegerStatistics {
+    pub minimum: Option<i64>,
+    pub maximum: Option<i64>,
+    pub sum: Option<i64>,
+}
+
+impl ProtoMessage for IntegerStatistics {
+    fn decode_field(&mut self, r: &mut ProtoReader, field: u32, wire_type: u8) -> ApolloResult<()> {
+        match field {
+            1 => self.minimum = Some(r.read_sint()?),
+            2 => self.maximum = Some(r.read_sint()?),
+            3 => self.sum = Some(r.read_sint()?),
+            _ => r.skip(wire_type)?,
+        }
+        Ok(())
+    }
+
+    fn encode(&self, w: &mut ProtoWriter) {
+        if let Some(v) = self.minimum {
+            w.sint(1, v);
+        }
+        if let Some(v) = self.maximum {
+            w.sint(2, v);
+        }
+        if let Some(v) = self.sum {
+            w.sint(3, v);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(super) struct DoubleStatistics {
+    pub minimum: Option<f64>,
+    pub maximum: Option<f64>,
+    pub sum: Option<f64>,
+}
+
+impl ProtoMessage for DoubleStatistics {
+    fn decode_field(&mut self, r: &mut ProtoReader, field: u32, wire_type: u8) -> ApolloResult<()> {
+        match (field, wire_type) {
+            (1, WIRE_FIXED64) => self.minimum = Some(f64::from_le_bytes(r.read_fixed()?)),
+            (2, WIRE_FIXED64) => self.maximum = Some(f64::from_le_bytes(r.read_fixed()?)),
+            (3, WIRE_FIXED64) => self.sum = Some(f64::from_le_bytes(r.read_fixed()?)),
+            _ => r.skip(wire_type)?,
+        }
+        Ok(())
+    }
+
+    fn encode(&self, w: &mut ProtoWriter) {
+        if let Some(v) = self.minimum {
+            w.double(1, v);
+        }
+        if let Some(v) = self.maximum {
+            w.double(2, v);
+        }
+        if let Some(v) = self.sum {
+            w.double(3, v);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(super) struct StringStatistics {
+    pub minimum: Option<String>,
+    pub maximum: Option<String>,
+    pub sum: Option<i64>,
+}
+
+impl ProtoMessage for StringStatistics {
+    fn decode_field(&mut self, r: &mut ProtoReader, field: u32, wire_type: u8) -> ApolloResult<()> {
+        match field {
+            1 => self.minimum = Some(r.read_string()?),
+            2 => self.maximum = Some(r.read_string()?),
+            3 => self.sum = Some(r.read_sint()?),
+            _ => r.skip(wire_type)?,
+        }
+        Ok(())
+    }
+
+    fn encode(&self, w: &mut ProtoWriter) {
+        if let Some(v) = &self.minimum {
+            w.bytes(1, v.as_bytes());
+        }
+        if let Some(v) = &self.maximum {
+            w.bytes(2, v.as_bytes());
+        }
+        if let Some(v) = self.sum {
+            w.sint(3, v);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(super) struct BucketStatistics {
+    pub count: Vec<u64>,
+}
+
+impl ProtoMessage for BucketStatistics {
+    fn decode_field(&mut self, r: &mut ProtoReader, field: u32, wire_type: u8) -> ApolloResult<()> {
+        match field {
+            1 => r.read_repeated_varint(wire_type, &mut self.count)?,
+            _ => r.skip(wire_type)?,
+        }
+        Ok(())
+    }
+
+    fn encode(&self, w: &mut ProtoWriter) {
+        w.packed(1, self.count.iter().copied());
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(super) struct DateStatistics {
+    pub minimum: Option<i32>,
+    pub maximum: Option<i32>,
+}
+
+impl ProtoMessage for DateStatistics {
+    fn decode_field(&mut self, r: &mut ProtoReader, field: u32, wire_type: u8) -> ApolloResult<()> {
+        match field {
+            1 => self.minimum = Some(r.read_sint()? as i32),
+            2 => self.maximum = Some(r.read_sint()? as i32),
+            _ => r.skip(wire_type)?,
+        }
+        Ok(())
+    }
+
+    fn encode(&self, w: &mut ProtoWriter) {
+        if let Some(v) = self.minimum {
+            w.sint(1, v as i64);
+        }
+        if let Some(v) = self.maximum {
+            w.sint(2, v as i64);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(super) struct BinaryStatistics {
+    pub sum: Option<i64>,
+}
+
+impl ProtoMessage for BinaryStatistics {
+    fn decode_field(&mut self, r: &mut ProtoReader, field: u32, wire_type: u8) -> ApolloResult<()> {
+        match field {
+            1 => self.sum = Some(r.read_sint()?),
+            _ => r.skip(wire_type)?,
+        }
+        Ok(())
+    }
+
+    fn encode(&self, w: &mut ProtoWriter) {
+        if let Some(v) = self.sum {
+            w.sint(1, v);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(super) struct ColumnStatistics {
+    pub number_of_values: Option<u64>,
+    pub int_statistics: Option<IntegerStatistics>,
+    pub double_statistics: Option<DoubleStatistics>,
+    pub string_statistics: Option<StringStatistics>,
+    pub bucket_statistics: Option<BucketStatistics>,
+    pub date_statistics: Option<DateStatistics>,
+    pub binary_statistics: Option<BinaryStatistics>,
+    pub has_null: Option<bool>,
+}
+
+impl ProtoMessage for ColumnStatistics {
+    fn decode_field(&mut self, r: &mut ProtoReader, field: u32, wire_type: u8) -> ApolloResult<()> {
+        match field {
+            1 => self.number_of_values = Some(r.read_varint()?),
+            2 => self.int_statistics = Some(r.read_message()?),
+            3 => self.double_statistics = Some(r.read_message()?),
+            4 => self.string_statistics = Some(r.read_message()?),
+            5 => self.bucket_statistics = Some(r.read_message()?),
+            7 => self.date_statistics = Some(r.read_message()?),
+            8 => self.binary_statistics = Some(r.read_message()?),
+            10 => self.has_null = Some(r.read_varint()? != 0),
+            _ => r.skip(wire_type)?,
+        }
+        Ok(())
+    }
+
+    fn encode(&self, w: &mut ProtoWriter) {
+        if let Some(v) = self.number_of_values {
+            w.uint(1, v);
+        }
+        if let Some(v) = &self.int_statistics {
+            w.message(2, v);
+        }
+        if let Some(v) = &self.double_statistics {
+            w.message(3, v);
+        }
+        if let Some(v) = &self.string_statistics {
+            w.message(4, v);
+        }
+        if let Some(v) = &self.bucket_statistics {
+            w.message(5, v);
+        }
+        if let Some(v) = &self.date_statistics {
+            w.message(7, v);
+        }
+        if let Some(v) = &self.binary_statistics {
+            w.message(8, v);
+        }
+        if let Some(v) = self.has_null {
+            w.uint(10, v as u64);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct StripeStatistics {
+    pub col_stats: Vec<ColumnStatistics>,
+}
+
+impl ProtoMessage for StripeStatistics {
+    fn decode_field(&mut self, r: &mut ProtoReader, field: u32, wire_type: u8) -> ApolloResult<()> {
+        match field {
+            1 => self.col_stats.push(r.read_message()?),
+            _ => r.skip(wire_type)?,
+        }
+        Ok(())
+    }
+
+    fn encode(&self, w: &mut ProtoWriter) {
+        for v in &self.col_stats {
+            w.message(1, v);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct Metadata {
+    pub stripe_stats: Vec<StripeStatistics>,
+}
+
+impl ProtoMessage for Metadata {
+    fn decode_field(&mut self, r: &mut ProtoReader, field: u32, wire_type: u8) -> ApolloResult<()> {
+        match field {
+            1 => self.stripe_stats.push(r.read_message()?),
+            _ => r.skip(wire_type)?,
+        }
+        Ok(())
+    }
+
+    fn encode(&self, w: &mut ProtoWriter) {
+        for v in &self.stripe_stats {
+            w.message(1, v);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub(super) enum StreamKind {
+    #[default]
+    Present,
+    Data,
+    Length,
+    DictionaryData,
+    Secondary,
+    /// Index and bloom filter streams, which are not used by the reader.
+    Other(u64),
+}
+
+impl StreamKind {
+    fn from_u64(v: u64) -> Self {
+        match v {
+            0 => Self::Present,
+            1 => Self::Data,
+            2 => Self::Length,
+            3 => Self::DictionaryData,
+            5 => Self::Secondary,
+            v => Self::Other(v),
+        }
+    }
+
+    fn to_u64(self) -> u64 {
+        match self {
+            Self::Present => 0,
+            Self::Data => 1,
+            Self::Length => 2,
+            Self::DictionaryData => 3,
+            Self::Secondary => 5,
+            Self::Other(v) => v,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct Stream {
+    pub kind: StreamKind,
+    pub column: u64,
+    pub length: u64,
+}
+
+impl ProtoMessage for Stream {
+    fn decode_field(&mut self, r: &mut ProtoReader, field: u32, wire_type: u8) -> ApolloResult<()> {
+        match field {
+            1 => self.kind = StreamKind::from_u64(r.read_varint()?),
+            2 => self.column = r.read_varint()?,
+            3 => self.length = r.read_varint()?,
+            _ => r.skip(wire_type)?,
+        }
+        Ok(())
+    }
+
+    fn encode(&self, w: &mut ProtoWriter) {
+        w.uint(1, self.kind.to_u64());
+        w.uint(2, self.column);
+        w.uint(3, self.length);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(super) enum ColumnEncodingKind {
+    #[default]
+    Direct,
+    Dictionary,
+    DirectV2,
+    DictionaryV2,
+}
+
+impl ColumnEncodingKind {
+    fn try_from_u64(v: u64) -> ApolloResult<Self> {
+        Ok(match v {
+            0 => Self::Direct,
+            1 => Self::Dictionary,
+            2 => Self::DirectV2,
+            3 => Self::DictionaryV2,
+            v => apollo_bail!(ComputeError: "unknown ORC column encoding: {}", v),
+        })
+    }
+
+    fn to_u64(self) -> u64 {
+        match self {
+            Self::Direct => 0,
+            Self::Dictionary => 1,
+            Self::DirectV2 => 2,
+            Self::DictionaryV2 => 3,
+        }
+    }
+
+    /// Whether integer streams use run length encoding version 2.
+    pub(super) fn is_rle_v2(self) -> bool {
+        matches!(self, Self::DirectV2 | Self::DictionaryV2)
+    }
+
+    pub(super) fn is_dictionary(self) -> bool {
+        matches!(self, Self::Dictionary | Self::DictionaryV2)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct ColumnEncoding {
+    pub kind: ColumnEncodingKind,
+    pub dictionary_size: Option<u64>,
+}
+
+impl ProtoMessage for ColumnEncoding {
+    fn decode_field(&mut self, r: &mut ProtoReader, field: u32, wire_type: u8) -> ApolloResult<()> {
+        match field {
+            1 => self.kind = ColumnEncodingKind::try_from_u64(r.read_varint()?)?,
+            2 => self.dictionary_size = Some(r.read_varint()?),
+            _ => r.skip(wire_type)?,
+        }
+        Ok(())
+    }
+
+    fn encode(&self, w: &mut ProtoWriter) {
+        w.uint(1, self.kind.to_u64());
+        if let Some(v) = self.dictionary_size {
+            w.uint(2, v);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct StripeFooter {
+    pub streams: Vec<Stream>,
+    pub columns: Vec<ColumnEncoding>,
+    pub writer_timezone: Option<String>,
+}
+
+impl ProtoMessage for StripeFooter {
+    fn decode_field(&mut self, r: &mut ProtoReader, field: u32, wire_type: u8) -> ApolloResult<()> {
+        match field {
+            1 => self.streams.push(r.read_message()?),
+            2 => self.columns.push(r.read_message()?),
+            3 => self.writer_timezone = Some(r.read_string()?),
+            _ => r.skip(wire_type)?,
+        }
+        Ok(())
+    }
+
+    fn encode(&self, w: &mut ProtoWriter) {
+        for v in &self.streams {
+            w.message(1, v);
+        }
+        for v in &self.columns {
+            w.message(2, v);
+        }
+        if let Some(v) = &self.writer_timezone {
+            w.bytes(3, v.as_bytes());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip_column_statistics() {
+        let stats = ColumnStatistics {
+            number_of_values: Some(3),
+            int_statistics: Some(IntegerStatistics {
+                minimum: Some(-5),
+                maximum: Some(i64::MAX),
+                sum: None,
+            }),
+            string_statistics: Some(StringStatistics {
+                minimum: Some("a".into()),
+                maximum: Some("z".into()),
+                sum: Some(2),
+            }),
+            has_null: Some(true),
+            ..Default::default()
+        };
+
+        let decoded = ColumnStatistics::decode(&stats.encode_to_vec()).unwrap();
+        assert_eq!(decoded, stats);
+    }
+}
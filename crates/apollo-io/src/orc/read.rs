@@ -0,0 +1,143 @@
+use apollo_core::prelude::*;
+use apollo_core::utils::accumulate_dataframes_vertical;
+
+use super::metadata::OrcFileMetadata;
+use crate::RowIndex;
+use crate::mmap::MmapBytesReader;
+use crate::prelude::*;
+use crate::utils::get_reader_bytes;
+
+/// Read [Apache ORC] format into a [`DataFrame`]
+///
+/// [Apache ORC]: https://orc.apache.org
+///
+/// # Example
+/// ```
+/// use std::fs::File;
+/// use apollo_core::prelude::*;
+/// use apollo_io::orc::OrcReader;
+/// use apollo_io::SerReader;
+///
+/// fn example() -> ApolloResult<DataFrame> {
+///     let file = File::open("file.orc").expect("file not found");
+///
+///     OrcReader::new(file)
+///             .finish()
+/// }
+/// ```
+#[must_use]
+pub struct OrcReader<R> {
+    reader: R,
+    rechunk: bool,
+    n_rows: Option<usize>,
+    columns: Option<Vec<String>>,
+    projection: Option<Vec<usize>>,
+    row_index: Option<RowIndex>,
+}
+
+impl<R: MmapBytesReader> OrcReader<R> {
+    /// Get schema of the ORC file.
+    pub fn schema(&mut self) -> ApolloResult<SchemaRef> {
+        let bytes = get_reader_bytes(&mut self.reader)?;
+        Ok(OrcFileMetadata::try_from_bytes(&bytes)?.schema)
+    }
+
+    /// Stop reading when `n` rows are read.
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Set the reader's column projection. This counts from 0, meaning that
+    /// `vec![0, 4]` would select the 1st and 5th column.
+    pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Columns to select/ project
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Add a row index column.
+    pub fn with_row_index(mut self, row_index: Option<RowIndex>) -> Self {
+        self.row_index = row_index;
+        self
+    }
+}
+
+impl<R: MmapBytesReader> SerReader<R> for OrcReader<R> {
+    fn new(reader: R) -> Self {
+        OrcReader {
+            reader,
+            rechunk: true,
+            n_rows: None,
+            columns: None,
+            projection: None,
+            row_index: None,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(mut self) -> ApolloResult<DataFrame> {
+        let bytes = get_reader_bytes(&mut self.reader)?;
+        let metadata = OrcFileMetadata::try_from_bytes(&bytes)?;
+
+        let columns: Vec<PlSmallStr> = if let Some(columns) = &self.columns {
+            columns
+                .iter()
+                .map(|name| {
+                    metadata.schema.try_get(name)?;
+                    Ok(PlSmallStr::from_str(name))
+                })
+                .collect::<ApolloResult<_>>()?
+        } else if let Some(projection) = &self.projection {
+            projection
+                .iter()
+                .map(|i| Ok(metadata.schema.try_get_at_index(*i)?.0.clone()))
+                .collect::<ApolloResult<_>>()?
+        } else {
+            metadata.schema.iter_names_cloned().collect()
+        };
+
+        let mut remaining = self.n_rows.unwrap_or(usize::MAX);
+        let mut dfs = vec![];
+
+        for stripe_idx in 0..metadata.num_stripes() {
+            if remaining == 0 {
+                break;
+            }
+
+            let mut df = metadata.decode_stripe(&bytes, stripe_idx, &columns)?;
+
+            if df.height() > remaining {
+                df = df.head(Some(remaining));
+            }
+
+            remaining -= df.height();
+            dfs.push(df);
+        }
+
+        let mut df = if dfs.is_empty() {
+            DataFrame::empty_with_schema(&metadata.schema.try_project(columns.iter())?)
+        } else {
+            accumulate_dataframes_vertical(dfs)?
+        };
+
+        if self.rechunk {
+            df.rechunk_mut_par();
+        }
+
+        if let Some(RowIndex { name, offset }) = self.row_index {
+            df = df.with_row_index(name, Some(offset))?;
+        }
+
+        Ok(df)
+    }
+}
@@ -0,0 +1,571 @@
+//! Run length encodings used by the ORC format.
+//!
+//! The decoders always decode a full (decompressed) stream at once.
+use apollo_error::{ApolloResult, apollo_bail, apollo_ensure, apollo_err};
+
+struct ByteCursor<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteCursor<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    fn next_byte(&mut self) -> ApolloResult<u8> {
+        let b = *self
+            .data
+            .get(self.pos)
+            .ok_or_else(|| apollo_err!(ComputeError: "unexpected end of ORC stream"))?;
+        self.pos += 1;
+        Ok(b)
+    }
+
+    fn take(&mut self, n: usize) -> ApolloResult<&'a [u8]> {
+        let out = self
+            .data
+            .get(self.pos..self.pos + n)
+            .ok_or_else(|| apollo_err!(ComputeError: "unexpected end of ORC stream"))?;
+        self.pos += n;
+        Ok(out)
+    }
+
+    fn read_uvarint(&mut self) -> ApolloResult<u64> {
+        let mut out = 0u64;
+
+        for shift in (0..64).step_by(7) {
+            let b = self.next_byte()?;
+            out |= u64::from(b & 0x7f) << shift;
+
+            if b & 0x80 == 0 {
+                return Ok(out);
+            }
+        }
+
+        apollo_bail!(ComputeError: "invalid varint in ORC stream")
+    }
+
+    fn read_svarint(&mut self) -> ApolloResult<i64> {
+        Ok(zigzag_decode(self.read_uvarint()?))
+    }
+
+    fn read_varint(&mut self, signed: bool) -> ApolloResult<i64> {
+        if signed {
+            self.read_svarint()
+        } else {
+            Ok(self.read_uvarint()? as i64)
+        }
+    }
+
+    /// Reads a `n_bytes` wide big-endian unsigned integer.
+    fn read_be(&mut self, n_bytes: usize) -> ApolloResult<u64> {
+        Ok(self
+            .take(n_bytes)?
+            .iter()
+            .fold(0u64, |acc, b| (acc << 8) | u64::from(*b)))
+    }
+
+    /// Unpacks `n` big-endian bit-packed values of `width` bits. The values start at a byte
+    /// boundary and the trailing bits of the last byte are discarded.
+    fn unpack(&mut self, width: u32, n: usize, out: &mut Vec<u64>) -> ApolloResult<()> {
+        let mut current = 0u64;
+        let mut bits_left = 0u32;
+
+        for _ in 0..n {
+            let mut value = 0u64;
+            let mut needed = width;
+
+            while needed > 0 {
+                if bits_left == 0 {
+                    current = u64::from(self.next_byte()?);
+                    bits_left = 8;
+                }
+
+                let take = needed.min(bits_left);
+                let bits = (current >> (bits_left - take)) & ((1 << take) - 1);
+                value = (value << take) | bits;
+                bits_left -= take;
+                needed -= take;
+            }
+
+            out.push(value);
+        }
+
+        Ok(())
+    }
+}
+
+fn zigzag_decode(v: u64) -> i64 {
+    ((v >> 1) as i64) ^ -((v & 1) as i64)
+}
+
+fn zigzag_encode(v: i64) -> u64 {
+    ((v << 1) ^ (v >> 63)) as u64
+}
+
+fn mask(bits: u32) -> u64 {
+    if bits >= 64 {
+        u64::MAX
+    } else {
+        (1u64 << bits) - 1
+    }
+}
+
+/// Decodes a byte run length encoded stream.
+pub(super) fn decode_byte_rle(data: &[u8], out: &mut Vec<u8>) -> ApolloResult<()> {
+    let mut c = ByteCursor::new(data);
+
+    while !c.is_empty() {
+        let header = c.next_byte()? as i8;
+
+        if header >= 0 {
+            let run = header as usize + 3;
+            let value = c.next_byte()?;
+            out.extend(std::iter::repeat_n(value, run));
+        } else {
+            let n = -(header as isize) as usize;
+            out.extend_from_slice(c.take(n)?);
+        }
+    }
+
+    Ok(())
+}
+
+/// Decodes a boolean run length encoded stream into bits, most significant bit first. The output
+/// may have trailing padding bits.
+pub(super) fn decode_bool_rle(data: &[u8]) -> ApolloResult<Vec<u8>> {
+    let mut bytes = vec![];
+    decode_byte_rle(data, &mut bytes)?;
+    Ok(bytes)
+}
+
+/// Returns bit `i` of a decoded boolean stream.
+#[inline]
+pub(super) fn get_bit_msb(bytes: &[u8], i: usize) -> bool {
+    (bytes[i / 8] >> (7 - (i % 8))) & 1 == 1
+}
+
+/// Decodes an integer stream using either run length encoding version 1 or 2.
+pub(super) fn decode_int_rle(data: &[u8], signed: bool, v2: bool) -> ApolloResult<Vec<i64>> {
+    let mut out = vec![];
+
+    if v2 {
+        decode_int_rle_v2(data, signed, &mut out)?;
+    } else {
+        decode_int_rle_v1(data, signed, &mut out)?;
+    }
+
+    Ok(out)
+}
+
+/// Decodes a stream of zigzag encoded, unbounded base 128 varints, as used by decimal columns.
+pub(super) fn decode_varint128(data: &[u8]) -> ApolloResult<Vec<i128>> {
+    let mut c = ByteCursor::new(data);
+    let mut out = vec![];
+
+    while !c.is_empty() {
+        let mut v = 0u128;
+
+        for shift in (0..128).step_by(7) {
+            let b = c.next_byte()?;
+            v |= u128::from(b & 0x7f) << shift;
+
+            if b & 0x80 == 0 {
+                break;
+            }
+            apollo_ensure!(shift + 7 < 128, ComputeError: "ORC decimal value is too large");
+        }
+
+        out.push(((v >> 1) as i128) ^ -((v & 1) as i128));
+    }
+
+    Ok(out)
+}
+
+fn decode_int_rle_v1(data: &[u8], signed: bool, out: &mut Vec<i64>) -> ApolloResult<()> {
+    let mut c = ByteCursor::new(data);
+
+    while !c.is_empty() {
+        let header = c.next_byte()? as i8;
+
+        if header >= 0 {
+            let run = header as i64 + 3;
+            let delta = c.next_byte()? as i8 as i64;
+            let base = c.read_varint(signed)?;
+            out.extend((0..run).map(|i| base.wrapping_add(i * delta)));
+        } else {
+            let n = -(header as i64);
+            for _ in 0..n {
+                out.push(c.read_varint(signed)?);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Maps the 5 bit encoded width of run length encoding version 2 to a bit width.
+fn decode_bit_width(code: u8) -> u32 {
+    match code {
+        0..=23 => code as u32 + 1,
+        24 => 26,
+        25 => 28,
+        26 => 30,
+        27 => 32,
+        28 => 40,
+        29 => 48,
+        30 => 56,
+        _ => 64,
+    }
+}
+
+fn encode_bit_width(width: u32) -> u8 {
+    match width {
+        0..=24 => width.max(1) as u8 - 1,
+        26 => 24,
+        28 => 25,
+        30 => 26,
+        32 => 27,
+        40 => 28,
+        48 => 29,
+        56 => 30,
+        _ => 31,
+    }
+}
+
+/// Rounds a bit width up to the next width that can be encoded.
+fn closest_fixed_bits(width: u32) -> u32 {
+    match width {
+        0 => 1,
+        1..=24 => width,
+        25..=26 => 26,
+        27..=28 => 28,
+        29..=30 => 30,
+        31..=32 => 32,
+        33..=40 => 40,
+        41..=48 => 48,
+        49..=56 => 56,
+        _ => 64,
+    }
+}
+
+fn decode_int_rle_v2(data: &[u8], signed: bool, out: &mut Vec<i64>) -> ApolloResult<()> {
+    let mut c = ByteCursor::new(data);
+    let mut buf = vec![];
+
+    let map = |v: u64| if signed { zigzag_decode(v) } else { v as i64 };
+
+    while !c.is_empty() {
+        let first = c.next_byte()?;
+
+        match first >> 6 {
+            // SHORT_REPEAT
+            0 => {
+                let width = ((first >> 3) & 0x7) as usize + 1;
+                let run = (first & 0x7) as usize + 3;
+                let value = map(c.read_be(width)?);
+                out.extend(std::iter::repeat_n(value, run));
+            },
+            // DIRECT
+            1 => {
+                let width = decode_bit_width((first >> 1) & 0x1f);
+                let len = ((((first & 1) as usize) << 8) | c.next_byte()? as usize) + 1;
+
+                buf.clear();
+                c.unpack(width, len, &mut buf)?;
+                out.extend(buf.iter().map(|v| map(*v)));
+            },
+            // PATCHED_BASE
+            2 => {
+                let width = decode_bit_width((first >> 1) & 0x1f);
+                let len = ((((first & 1) as usize) << 8) | c.next_byte()? as usize) + 1;
+
+                let third = c.next_byte()?;
+                let base_width = ((third >> 5) & 0x7) as usize + 1;
+                let patch_width = decode_bit_width(third & 0x1f);
+
+                let fourth = c.next_byte()?;
+                let patch_gap_width = ((fourth >> 5) & 0x7) as u32 + 1;
+                let patch_list_len = (fourth & 0x1f) as usize;
+
+                // The base is stored as sign-magnitude with the sign in the most significant bit.
+                let base = c.read_be(base_width)?;
+                let sign_bit = 1u64 << (base_width * 8 - 1);
+                let base = if base & sign_bit != 0 {
+                    -((base & !sign_bit) as i64)
+                } else {
+                    base as i64
+                };
+
+                buf.clear();
+                c.unpack(width, len, &mut buf)?;
+
+                let mut patches = vec![];
+                c.unpack(
+                    closest_fixed_bits(patch_width + patch_gap_width),
+                    patch_list_len,
+                    &mut patches,
+                )?;
+
+                let mut idx = 0usize;
+                for patch in patches {
+                    idx += (patch >> patch_width) as usize;
+                    let value = buf.get_mut(idx).ok_or_else(
+                        || apollo_err!(ComputeError: "invalid patch position in ORC stream"),
+                    )?;
+                    *value |= (patch & mask(patch_width))
+                        .checked_shl(width)
+                        .unwrap_or(0);
+                }
+
+                out.extend(buf.iter().map(|v| base.wrapping_add(*v as i64)));
+            },
+            // DELTA
+            _ => {
+                let width_code = (first >> 1) & 0x1f;
+                let width = if width_code == 0 {
+                    0
+                } else {
+                    decode_bit_width(width_code)
+                };
+                let len = ((((first & 1) as usize) << 8) | c.next_byte()? as usize) + 1;
+
+                let base = c.read_varint(signed)?;
+                let delta_base = c.read_svarint()?;
+
+                out.push(base);
+
+                if width == 0 {
+                    let mut prev = base;
+                    for _ in 1..len {
+                        prev = prev.wrapping_add(delta_base);
+                        out.push(prev);
+                    }
+                } else if len > 1 {
+                    let mut prev = base.wrapping_add(delta_base);
+                    out.push(prev);
+
+                    buf.clear();
+                    c.unpack(width, len - 2, &mut buf)?;
+
+                    for delta in buf.iter() {
+                        prev = if delta_base < 0 {
+                            prev.wrapping_sub(*delta as i64)
+                        } else {
+                            prev.wrapping_add(*delta as i64)
+                        };
+                        out.push(prev);
+                    }
+                }
+            },
+        }
+    }
+
+    Ok(())
+}
+
+/// Encodes bytes using byte run length encoding.
+pub(super) fn encode_byte_rle(values: &[u8], out: &mut Vec<u8>) {
+    let mut i = 0;
+
+    while i < values.len() {
+        let run = values[i..]
+            .iter()
+            .take(130)
+            .take_while(|v| **v == values[i])
+            .count();
+
+        if run >= 3 {
+            out.push((run - 3) as u8);
+            out.push(values[i]);
+            i += run;
+            continue;
+        }
+
+        // Collect literals until the next run of at least 3 values.
+        let start = i;
+        while i < values.len() && i - start < 128 {
+            if i + 2 < values.len() && values[i] == values[i + 1] && values[i] == values[i + 2] {
+                break;
+            }
+            i += 1;
+        }
+
+        out.push((-((i - start) as i16)) as u8);
+        out.extend_from_slice(&values[start..i]);
+    }
+}
+
+/// Encodes booleans as bits (most significant bit first) using byte run length encoding.
+pub(super) fn encode_bool_rle(values: impl IntoIterator<Item = bool>, out: &mut Vec<u8>) {
+    let mut bytes = vec![];
+    let mut current = 0u8;
+    let mut n_bits = 0;
+
+    for v in values {
+        current = (current << 1) | v as u8;
+        n_bits += 1;
+
+        if n_bits == 8 {
+            bytes.push(current);
+            current = 0;
+            n_bits = 0;
+        }
+    }
+
+    if n_bits > 0 {
+        bytes.push(current << (8 - n_bits));
+    }
+
+    encode_byte_rle(&bytes, out)
+}
+
+/// Encodes integers using run length encoding version 2.
+///
+/// Repeated values are written as fixed DELTA runs, everything else is bit-packed as DIRECT runs.
+pub(super) fn encode_int_rle_v2(values: &[i64], signed: bool, out: &mut Vec<u8>) {
+    const MAX_RUN: usize = 512;
+
+    let to_unsigned = |v: i64| if signed { zigzag_encode(v) } else { v as u64 };
+
+    for chunk in values.chunks(MAX_RUN) {
+        let header_len = chunk.len() - 1;
+
+        if chunk.len() >= 3 && chunk.iter().all(|v| *v == chunk[0]) {
+            out.push(0b11 << 6 | ((header_len >> 8) as u8 & 1));
+            out.push(header_len as u8);
+            write_uvarint(to_unsigned(chunk[0]), out);
+            // Fixed delta of 0.
+            write_uvarint(0, out);
+            continue;
+        }
+
+        let max = chunk.iter().map(|v| to_unsigned(*v)).max().unwrap_or(0);
+        let width = closest_fixed_bits(64 - max.leading_zeros());
+
+        out.push(0b01 << 6 | encode_bit_width(width) << 1 | ((header_len >> 8) as u8 & 1));
+        out.push(header_len as u8);
+
+        let mut current = 0u8;
+        let mut n_bits = 0;
+
+        for v in chunk.iter().map(|v| to_unsigned(*v)) {
+            for bit in (0..width).rev() {
+                current = (current << 1) | ((v >> bit) & 1) as u8;
+                n_bits += 1;
+
+                if n_bits == 8 {
+                    out.push(current);
+                    current = 0;
+                    n_bits = 0;
+                }
+            }
+        }
+
+        if n_bits > 0 {
+            out.push(current << (8 - n_bits));
+        }
+    }
+}
+
+/// Encodes `values` as zigzag encoded, unbounded base 128 varints.
+pub(super) fn encode_varint128(values: &[i128], out: &mut Vec<u8>) {
+    for v in values {
+        let mut v = ((v << 1) ^ (v >> 127)) as u128;
+        while v >= 0x80 {
+            out.push((v as u8) | 0x80);
+            v >>= 7;
+        }
+        out.push(v as u8);
+    }
+}
+
+fn write_uvarint(mut v: u64, out: &mut Vec<u8>) {
+    while v >= 0x80 {
+        out.push((v as u8) | 0x80);
+        v >>= 7;
+    }
+    out.push(v as u8);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_int_rle_v2_spec_examples() {
+        // Examples from the ORC specification.
+        let short_repeat = [0x0a, 0x27, 0x10];
+        assert_eq!(
+            decode_int_rle(&short_repeat, false, true).unwrap(),
+            vec![10000; 5]
+        );
+
+        let direct = [0x5e, 0x03, 0x5c, 0xa1, 0xab, 0x1e, 0xde, 0xad, 0xbe, 0xef];
+        assert_eq!(
+            decode_int_rle(&direct, false, true).unwrap(),
+            vec![23713, 43806, 57005, 48879]
+        );
+
+        let patched_base = [
+            0x8e, 0x13, 0x2b, 0x21, 0x07, 0xd0, 0x1e, 0x00, 0x14, 0x70, 0x28, 0x32, 0x3c, 0x46,
+            0x50, 0x5a, 0x64, 0x6e, 0x78, 0x82, 0x8c, 0x96, 0xa0, 0xaa, 0xb4, 0xbe, 0xfc, 0xe8,
+        ];
+        assert_eq!(
+            decode_int_rle(&patched_base, false, true).unwrap(),
+            vec![
+                2030, 2000, 2020, 1000000, 2040, 2050, 2060, 2070, 2080, 2090, 2100, 2110, 2120,
+                2130, 2140, 2150, 2160, 2170, 2180, 2190
+            ]
+        );
+
+        let delta = [0xc6, 0x09, 0x02, 0x02, 0x22, 0x42, 0x42, 0x46];
+        assert_eq!(
+            decode_int_rle(&delta, false, true).unwrap(),
+            vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]
+        );
+    }
+
+    #[test]
+    fn test_int_rle_v2_roundtrip() {
+        let values = (0..2000i64)
+            .map(|i| if i % 7 == 0 { -i * 1_000_003 } else { 42 })
+            .chain(std::iter::repeat_n(-3, 600))
+            .collect::<Vec<_>>();
+
+        let mut encoded = vec![];
+        encode_int_rle_v2(&values, true, &mut encoded);
+        assert_eq!(decode_int_rle(&encoded, true, true).unwrap(), values);
+    }
+
+    #[test]
+    fn test_varint128_roundtrip() {
+        let values = [0, 1, -1, 63, -64, 64, i128::MAX, i128::MIN];
+
+        let mut encoded = vec![];
+        encode_varint128(&values, &mut encoded);
+        assert_eq!(&encoded[..4], &[0x00, 0x02, 0x01, 0x7e]);
+        assert_eq!(decode_varint128(&encoded).unwrap(), values);
+    }
+
+    #[test]
+    fn test_byte_rle_roundtrip() {
+        let values = [1u8, 2, 3, 3, 3, 3, 4, 5]
+            .into_iter()
+            .chain(std::iter::repeat_n(9, 300))
+            .chain(0..=255)
+            .collect::<Vec<_>>();
+
+        let mut encoded = vec![];
+        encode_byte_rle(&values, &mut encoded);
+
+        let mut decoded = vec![];
+        decode_byte_rle(&encoded, &mut decoded).unwrap();
+        assert_eq!(decoded, values);
+    }
+}
@@ -0,0 +1,434 @@
+//! Decoding of ORC stripes into [`DataFrame`]s.
+use std::borrow::Cow;
+
+use apollo_core::prelude::*;
+use apollo_error::{ApolloResult, apollo_bail, apollo_ensure, apollo_err};
+use apollo_utils::aliases::PlHashMap;
+use arrow::array::{BooleanArray, MutableBinaryViewArray};
+use arrow::bitmap::Bitmap;
+use arrow::datatypes::ArrowDataType;
+
+use super::compression::decompress_stream;
+use super::metadata::OrcFileMetadata;
+use super::proto::{ColumnEncoding, ProtoMessage, StreamKind, StripeFooter, TypeKind};
+use super::rle::{decode_bool_rle, decode_byte_rle, decode_int_rle, decode_varint128, get_bit_msb};
+
+/// Seconds between the UNIX epoch and the ORC timestamp epoch (2015-01-01 00:00:00).
+pub(super) const ORC_TIMESTAMP_EPOCH_SECONDS: i64 = 1_420_070_400;
+
+impl OrcFileMetadata {
+    /// Decodes the given top-level columns of a stripe from the full file `bytes`.
+    ///
+    /// The columns are output in the order of `columns`, which must all exist in the file schema.
+    pub fn decode_stripe(
+        &self,
+        bytes: &[u8],
+        stripe_idx: usize,
+        columns: &[PlSmallStr],
+    ) -> ApolloResult<DataFrame> {
+        let stripe = self.stripe(stripe_idx);
+        let height = stripe.number_of_rows as usize;
+
+        if columns.is_empty() {
+            return Ok(DataFrame::empty_with_height(height));
+        }
+
+        let stripe_start = stripe.offset as usize;
+        let footer_start = stripe_start + (stripe.index_length + stripe.data_length) as usize;
+        let footer_end = footer_start + stripe.footer_length as usize;
+
+        apollo_ensure!(
+            footer_end <= bytes.len(),
+            ComputeError: "ORC stripe {} exceeds the file length", stripe_idx
+        );
+
+        let footer = StripeFooter::decode(&decompress_stream(
+            self.postscript.compression,
+            &bytes[footer_start..footer_end],
+        )?)?;
+
+        // Streams are stored back-to-back in the order they are listed in the stripe footer.
+        let mut streams = PlHashMap::with_capacity(footer.streams.len());
+        let mut offset = stripe_start;
+
+        for stream in footer.streams.iter() {
+            let end = offset + stream.length as usize;
+            apollo_ensure!(
+                end <= footer_start,
+                ComputeError: "ORC stream exceeds the stripe length"
+            );
+            streams.insert((stream.column as usize, stream.kind), &bytes[offset..end]);
+            offset = end;
+        }
+
+        let stripe = StripeStreams {
+            metadata: self,
+            footer: &footer,
+            streams,
+        };
+
+        let columns = columns
+            .iter()
+            .map(|name| {
+                let idx = self
+                    .schema
+                    .index_of(name)
+                    .ok_or_else(|| apollo_err!(ColumnNotFound: "{}", name))?;
+                let (name, dtype) = self.schema.get_at_index(idx).unwrap();
+
+                stripe
+                    .decode_column(
+                        self.column_ids[idx],
+                        self.column_kinds[idx],
+                        name.clone(),
+                        dtype,
+                        height,
+                    )
+                    .map(Column::from)
+            })
+            .collect::<ApolloResult<Vec<_>>>()?;
+
+        DataFrame::new(height, columns)
+    }
+}
+
+struct StripeStreams<'a> {
+    metadata: &'a OrcFileMetadata,
+    footer: &'a StripeFooter,
+    streams: PlHashMap<(usize, StreamKind), &'a [u8]>,
+}
+
+impl StripeStreams<'_> {
+    /// Returns the decompressed stream, missing streams are returned as empty.
+    fn get(&self, column_id: usize, kind: StreamKind) -> ApolloResult<Cow<'_, [u8]>> {
+        match self.streams.get(&(column_id, kind)) {
+            Some(data) => decompress_stream(self.metadata.postscript.compression, data),
+            None => Ok(Cow::Borrowed(&[])),
+        }
+    }
+
+    fn encoding(&self, column_id: usize) -> ApolloResult<&ColumnEncoding> {
+        self.footer
+            .columns
+            .get(column_id)
+            .ok_or_else(
+                || apollo_err!(ComputeError: "missing ORC encoding for column {}", column_id),
+            )
+    }
+
+    fn decode_ints(
+        &self,
+        column_id: usize,
+        kind: StreamKind,
+        signed: bool,
+    ) -> ApolloResult<Vec<i64>> {
+        let v2 = self.encoding(column_id)?.kind.is_rle_v2();
+        decode_int_rle(&self.get(column_id, kind)?, signed, v2)
+    }
+
+    fn decode_column(
+        &self,
+        column_id: usize,
+        kind: TypeKind,
+        name: PlSmallStr,
+        dtype: &DataType,
+        height: usize,
+    ) -> ApolloResult<Series> {
+        let validity = if self.streams.contains_key(&(column_id, StreamKind::Present)) {
+            let bits = decode_bool_rle(&self.get(column_id, StreamKind::Present)?)?;
+            apollo_ensure!(
+                bits.len() * 8 >= height,
+                ComputeError: "ORC present stream is too short"
+            );
+            Some((0..height).map(|i| get_bit_msb(&bits, i)).collect::<Bitmap>())
+        } else {
+            None
+        };
+
+        let n_values = validity
+            .as_ref()
+            .map_or(height, |validity| height - validity.unset_bits());
+
+        use TypeKind as K;
+
+        let s = match kind {
+            K::Boolean => {
+                let bits = decode_bool_rle(&self.get(column_id, StreamKind::Data)?)?;
+                apollo_ensure!(
+                    bits.len() * 8 >= n_values,
+                    ComputeError: "ORC boolean stream is too short"
+                );
+                let values = (0..n_values)
+                    .map(|i| get_bit_msb(&bits, i))
+                    .collect::<Vec<_>>();
+                let values = spread(values, validity.as_ref(), height)?
+                    .into_iter()
+                    .collect::<Bitmap>();
+
+                BooleanChunked::with_chunk(
+                    name,
+                    BooleanArray::new(ArrowDataType::Boolean, values, validity),
+                )
+                .into_series()
+            },
+            K::Byte => {
+                let mut values = vec![];
+                decode_byte_rle(&self.get(column_id, StreamKind::Data)?, &mut values)?;
+                let values = values.into_iter().map(|v| v as i8).collect::<Vec<_>>();
+
+                let values = spread(values, validity.as_ref(), height)?;
+                Int8Chunked::from_vec_validity(name, values, validity).into_series()
+            },
+            K::Short | K::Int | K::Long | K::Date => {
+                let values = self.decode_ints(column_id, StreamKind::Data, true)?;
+                let values = spread(values, validity.as_ref(), height)?;
+
+                match kind {
+                    K::Short => Int16Chunked::from_vec_validity(
+                        name,
+                        values.into_iter().map(|v| v as i16).collect(),
+                        validity,
+                    )
+                    .into_series(),
+                    K::Int => Int32Chunked::from_vec_validity(
+                        name,
+                        values.into_iter().map(|v| v as i32).collect(),
+                        validity,
+                    )
+                    .into_series(),
+                    K::Date => Int32Chunked::from_vec_validity(
+                        name,
+                        values.into_iter().map(|v| v as i32).collect(),
+                        validity,
+                    )
+                    .into_date()
+                    .into_series(),
+                    _ => Int64Chunked::from_vec_validity(name, values, validity).into_series(),
+                }
+            },
+            K::Float => {
+                let data = self.get(column_id, StreamKind::Data)?;
+                let values = data
+                    .chunks_exact(4)
+                    .map(|b| f32::from_le_bytes(b.try_into().unwrap()))
+                    .collect::<Vec<_>>();
+
+                let values = spread(values, validity.as_ref(), height)?;
+                Float32Chunked::from_vec_validity(name, values, validity).into_series()
+            },
+            K::Double => {
+                let data = self.get(column_id, StreamKind::Data)?;
+                let values = data
+                    .chunks_exact(8)
+                    .map(|b| f64::from_le_bytes(b.try_into().unwrap()))
+                    .collect::<Vec<_>>();
+
+                let values = spread(values, validity.as_ref(), height)?;
+                Float64Chunked::from_vec_validity(name, values, validity).into_series()
+            },
+            K::String | K::Varchar | K::Char | K::Binary => {
+                self.decode_binary(column_id, name, dtype, validity.as_ref(), height, n_values)?
+            },
+            K::Timestamp | K::TimestampInstant => {
+                let DataType::Datetime(_, time_zone) = dtype else {
+                    unreachable!()
+                };
+                let seconds = self.decode_ints(column_id, StreamKind::Data, true)?;
+                let nanos = self.decode_ints(column_id, StreamKind::Secondary, false)?;
+
+                apollo_ensure!(
+                    seconds.len() >= n_values && nanos.len() >= n_values,
+                    ComputeError: "ORC timestamp streams are too short"
+                );
+
+                let values = seconds
+                    .into_iter()
+                    .zip(nanos)
+                    .take(n_values)
+                    .map(|(seconds, nanos)| {
+                        let nanos = decode_nanos(nanos as u64);
+                        let mut seconds = seconds + ORC_TIMESTAMP_EPOCH_SECONDS;
+                        // Writers truncate the seconds towards zero for timestamps before the
+                        // UNIX epoch.
+                        if seconds < 0 && nanos > 999_999 {
+                            seconds -= 1;
+                        }
+                        seconds * 1_000_000_000 + nanos
+                    })
+                    .collect::<Vec<_>>();
+
+                let values = spread(values, validity.as_ref(), height)?;
+                Int64Chunked::from_vec_validity(name, values, validity)
+                    .into_datetime(TimeUnit::Nanoseconds, time_zone.clone())
+                    .into_series()
+            },
+            K::Decimal => {
+                let DataType::Decimal(precision, scale) = *dtype else {
+                    unreachable!()
+                };
+                let values = decode_varint128(&self.get(column_id, StreamKind::Data)?)?;
+                let scales = self.decode_ints(column_id, StreamKind::Secondary, true)?;
+
+                apollo_ensure!(
+                    values.len() >= n_values && scales.len() >= n_values,
+                    ComputeError: "ORC decimal streams are too short"
+                );
+
+                // Every value carries its own scale, which is rescaled to the column scale.
+                let values = values
+                    .into_iter()
+                    .zip(scales)
+                    .take(n_values)
+                    .map(|(v, value_scale)| {
+                        let diff = scale as i64 - value_scale;
+                        let factor = 10i128.checked_pow(diff.unsigned_abs() as u32).ok_or_else(
+                            || apollo_err!(ComputeError: "invalid ORC decimal scale"),
+                        )?;
+                        if diff >= 0 {
+                            v.checked_mul(factor).ok_or_else(
+                                || apollo_err!(ComputeError: "ORC decimal value is too large"),
+                            )
+                        } else {
+                            Ok(v / factor)
+                        }
+                    })
+                    .collect::<ApolloResult<Vec<_>>>()?;
+
+                let values = spread(values, validity.as_ref(), height)?;
+                Int128Chunked::from_vec_validity(name, values, validity)
+                    .into_decimal(precision, scale)?
+                    .into_series()
+            },
+            K::List | K::Map | K::Struct | K::Union => {
+                apollo_bail!(
+                    ComputeError: "ORC type {:?} of column '{}' is not supported", kind, name
+                )
+            },
+        };
+
+        Ok(s)
+    }
+
+    fn decode_binary(
+        &self,
+        column_id: usize,
+        name: PlSmallStr,
+        dtype: &DataType,
+        validity: Option<&Bitmap>,
+        height: usize,
+        n_values: usize,
+    ) -> ApolloResult<Series> {
+        let encoding = self.encoding(column_id)?;
+
+        // Offsets into `data` for every (dictionary) value.
+        let (data, offsets, indices) = if encoding.kind.is_dictionary() {
+            let dictionary = self.get(column_id, StreamKind::DictionaryData)?;
+            let lengths = self.decode_ints(column_id, StreamKind::Length, false)?;
+            let indices = self.decode_ints(column_id, StreamKind::Data, false)?;
+            (dictionary, lengths_to_offsets(&lengths, usize::MAX)?, Some(indices))
+        } else {
+            let data = self.get(column_id, StreamKind::Data)?;
+            let lengths = self.decode_ints(column_id, StreamKind::Length, false)?;
+            (data, lengths_to_offsets(&lengths, n_values)?, None)
+        };
+
+        let mut array = MutableBinaryViewArray::<[u8]>::with_capacity(height);
+        let mut value_idx = 0;
+
+        for row in 0..height {
+            if validity.is_some_and(|validity| !validity.get_bit(row)) {
+                array.push_null();
+                continue;
+            }
+
+            let i = match &indices {
+                Some(indices) => *indices.get(value_idx).ok_or_else(
+                    || apollo_err!(ComputeError: "ORC dictionary index stream is too short"),
+                )? as usize,
+                None => value_idx,
+            };
+            value_idx += 1;
+
+            let (start, end) = offsets
+                .get(i)
+                .zip(offsets.get(i + 1))
+                .ok_or_else(|| apollo_err!(ComputeError: "invalid ORC string index: {}", i))?;
+            let value = data
+                .get(*start..*end)
+                .ok_or_else(|| apollo_err!(ComputeError: "ORC string data stream is too short"))?;
+
+            array.push_value(value);
+        }
+
+        let array = array.freeze();
+
+        Ok(match dtype {
+            DataType::String => {
+                StringChunked::with_chunk(name, array.to_utf8view()?).into_series()
+            },
+            _ => BinaryChunked::with_chunk(name, array).into_series(),
+        })
+    }
+}
+
+/// Converts the lengths of (at most `limit`) values into offsets.
+fn lengths_to_offsets(lengths: &[i64], limit: usize) -> ApolloResult<Vec<usize>> {
+    let mut offsets = Vec::with_capacity(lengths.len().min(limit) + 1);
+    let mut offset = 0usize;
+    offsets.push(0);
+
+    for length in lengths.iter().take(limit) {
+        apollo_ensure!(*length >= 0, ComputeError: "negative length in ORC stream");
+        offset += *length as usize;
+        offsets.push(offset);
+    }
+
+    Ok(offsets)
+}
+
+/// Decodes the nanoseconds of a timestamp. The lower 3 bits hold the number of trailing decimal
+/// zeros that were removed (minus one).
+fn decode_nanos(v: u64) -> i64 {
+    let zeros = v & 0x7;
+    let nanos = (v >> 3) as i64;
+
+    if zeros == 0 {
+        nanos
+    } else {
+        nanos * 10i64.pow(zeros as u32 + 1)
+    }
+}
+
+/// Spreads the non-null `values` over a column of `height` rows, filling null slots with the
+/// default value.
+fn spread<T: Copy + Default>(
+    mut values: Vec<T>,
+    validity: Option<&Bitmap>,
+    height: usize,
+) -> ApolloResult<Vec<T>> {
+    let n_values = validity.map_or(height, |validity| height - validity.unset_bits());
+
+    apollo_ensure!(
+        values.len() >= n_values,
+        ComputeError: "ORC data stream is too short: expected {} values, got {}",
+        n_values, values.len()
+    );
+
+    let Some(validity) = validity else {
+        values.truncate(height);
+        return Ok(values);
+    };
+
+    let mut values = values.into_iter();
+
+    Ok(validity
+        .iter()
+        .map(|valid| {
+            if valid {
+                values.next().unwrap()
+            } else {
+                T::default()
+            }
+        })
+        .collect())
+}
@@ -0,0 +1,654 @@
+use std::io::Write;
+
+use apollo_core::prelude::*;
+use apollo_error::{ApolloResult, apollo_bail};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::compression::{COMPRESSION_BLOCK_SIZE, OrcCompression, compress_stream};
+use super::metadata::ORC_MAGIC;
+use super::proto::{
+    BinaryStatistics, BucketStatistics, ColumnEncoding, ColumnEncodingKind, ColumnStatistics,
+    CompressionKind, DateStatistics, DoubleStatistics, Footer, IntegerStatistics, Metadata,
+    PostScript, ProtoMessage, Stream, StreamKind, StringStatistics, StripeFooter,
+    StripeInformation, StripeStatistics, Type, TypeKind,
+};
+use super::rle::{encode_bool_rle, encode_byte_rle, encode_int_rle_v2, encode_varint128};
+use super::stripe::ORC_TIMESTAMP_EPOCH_SECONDS;
+use crate::shared::SerWriter;
+
+/// Default number of rows per stripe.
+const DEFAULT_STRIPE_SIZE: usize = 1024 * 1024;
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct OrcWriterOptions {
+    /// Compression codec of the streams.
+    pub compression: Option<OrcCompression>,
+    /// Maximum number of rows per stripe.
+    pub stripe_size: Option<usize>,
+}
+
+/// Write a [`DataFrame`] to [Apache ORC] format
+///
+/// [Apache ORC]: https://orc.apache.org
+///
+/// Only flat schemas are supported. Integers are written using run length encoding version 2 and
+/// strings are always written with direct encoding.
+///
+/// # Example
+///
+/// ```
+/// use apollo_core::prelude::*;
+/// use apollo_io::orc::OrcWriter;
+/// use std::fs::File;
+/// use apollo_io::SerWriter;
+///
+/// fn example(df: &mut DataFrame) -> ApolloResult<()> {
+///     let mut file = File::create("file.orc").expect("could not create file");
+///
+///     OrcWriter::new(&mut file)
+///         .finish(df)
+/// }
+/// ```
+#[must_use]
+pub struct OrcWriter<W> {
+    writer: W,
+    compression: Option<OrcCompression>,
+    stripe_size: usize,
+}
+
+impl<W> OrcWriter<W>
+where
+    W: Write,
+{
+    /// Set the compression used. Defaults to None.
+    pub fn with_compression(mut self, compression: Option<OrcCompression>) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Set the maximum number of rows per stripe.
+    pub fn with_stripe_size(mut self, stripe_size: Option<usize>) -> Self {
+        self.stripe_size = stripe_size.unwrap_or(DEFAULT_STRIPE_SIZE).max(1);
+        self
+    }
+}
+
+impl<W> SerWriter<W> for OrcWriter<W>
+where
+    W: Write,
+{
+    fn new(writer: W) -> Self {
+        Self {
+            writer,
+            compression: None,
+            stripe_size: DEFAULT_STRIPE_SIZE,
+        }
+    }
+
+    fn finish(&mut self, df: &mut DataFrame) -> ApolloResult<()> {
+        let encoder = OrcStripeEncoder::try_new(df.schema(), self.compression)?;
+        let mut tail = OrcFileTail::new(&encoder);
+
+        self.writer.write_all(ORC_MAGIC)?;
+
+        for offset in (0..df.height()).step_by(self.stripe_size) {
+            let stripe = encoder.encode(&df.slice(offset as i64, self.stripe_size))?;
+            self.writer.write_all(&stripe.data)?;
+            tail.push_stripe(stripe);
+        }
+
+        self.writer.write_all(&tail.finish()?)?;
+
+        Ok(())
+    }
+}
+
+/// An encoded stripe, ready to be appended to the file.
+pub struct EncodedStripe {
+    pub data: Vec<u8>,
+    info: StripeInformation,
+    statistics: StripeStatistics,
+}
+
+impl EncodedStripe {
+    pub fn num_rows(&self) -> usize {
+        self.info.number_of_rows as usize
+    }
+}
+
+/// Encodes [`DataFrame`]s into ORC stripes.
+///
+/// Stripes do not depend on their position in the file, so they can be encoded independently of
+/// each other (e.g. in parallel) and appended in order, after the [`ORC_MAGIC`] header. The file
+/// is completed by the [`OrcFileTail`].
+#[derive(Clone)]
+pub struct OrcStripeEncoder {
+    schema: SchemaRef,
+    types: Vec<Type>,
+    compression: CompressionKind,
+}
+
+impl OrcStripeEncoder {
+    pub fn try_new(schema: &Schema, compression: Option<OrcCompression>) -> ApolloResult<Self> {
+        let mut types = Vec::with_capacity(schema.len() + 1);
+
+        types.push(Type {
+            kind: TypeKind::Struct,
+            subtypes: (1..=schema.len() as u64).collect(),
+            field_names: schema.iter_names().map(|x| x.to_string()).collect(),
+        });
+
+        for (name, dtype) in schema.iter() {
+            let (precision, scale) = match dtype {
+                DataType::Decimal(precision, scale) => {
+                    (Some(*precision as u64), Some(*scale as u64))
+                },
+                _ => (None, None),
+            };
+            types.push(Type {
+                kind: dtype_to_orc_kind(dtype, name)?,
+                precision,
+                scale,
+                ..Default::default()
+            });
+        }
+
+        Ok(Self {
+            schema: Arc::new(schema.clone()),
+            types,
+            compression: compression.into(),
+        })
+    }
+
+    pub fn encode(&self, df: &DataFrame) -> ApolloResult<EncodedStripe> {
+        let height = df.height();
+
+        let mut streams = vec![];
+        let mut data = vec![];
+        let mut encodings = vec![ColumnEncoding::default()];
+        let mut col_stats = vec![ColumnStatistics {
+            number_of_values: Some(height as u64),
+            has_null: Some(false),
+            ..Default::default()
+        }];
+
+        let mut push_stream = |column: usize, kind: StreamKind, bytes: &[u8]| {
+            let offset = data.len();
+            compress_stream(self.compression, bytes, &mut data)?;
+            streams.push(Stream {
+                kind,
+                column: column as u64,
+                length: (data.len() - offset) as u64,
+            });
+            ApolloResult::Ok(())
+        };
+
+        for (i, name) in self.schema.iter_names().enumerate() {
+            let column_id = i + 1;
+            let column = df.column(name)?.as_materialized_series();
+            let encoded = encode_column(column, self.types[column_id].kind)?;
+
+            if let Some(present) = &encoded.present {
+                push_stream(column_id, StreamKind::Present, present)?;
+            }
+            for (kind, bytes) in encoded.streams.iter() {
+                push_stream(column_id, *kind, bytes)?;
+            }
+
+            encodings.push(ColumnEncoding {
+                kind: encoded.encoding,
+                dictionary_size: None,
+            });
+            col_stats.push(encoded.statistics);
+        }
+
+        let data_length = data.len() as u64;
+
+        let footer = StripeFooter {
+            streams,
+            columns: encodings,
+            writer_timezone: Some("UTC".to_string()),
+        };
+        compress_stream(self.compression, &footer.encode_to_vec(), &mut data)?;
+
+        Ok(EncodedStripe {
+            info: StripeInformation {
+                offset: 0,
+                index_length: 0,
+                data_length,
+                footer_length: data.len() as u64 - data_length,
+                number_of_rows: height as u64,
+            },
+            data,
+            statistics: StripeStatistics { col_stats },
+        })
+    }
+}
+
+/// Collects the stripe information of the written stripes and serializes the file tail (metadata,
+/// footer and postscript).
+pub struct OrcFileTail {
+    types: Vec<Type>,
+    compression: CompressionKind,
+    stripes: Vec<StripeInformation>,
+    stripe_statistics: Vec<StripeStatistics>,
+    file_statistics: Vec<ColumnStatistics>,
+    offset: u64,
+    num_rows: u64,
+}
+
+impl OrcFileTail {
+    pub fn new(encoder: &OrcStripeEncoder) -> Self {
+        Self {
+            types: encoder.types.clone(),
+            compression: encoder.compression,
+            stripes: vec![],
+            stripe_statistics: vec![],
+            file_statistics: vec![],
+            offset: ORC_MAGIC.len() as u64,
+            num_rows: 0,
+        }
+    }
+
+    /// Registers a stripe that was written directly after the previously pushed stripe.
+    pub fn push_stripe(&mut self, stripe: EncodedStripe) {
+        let EncodedStripe {
+            data,
+            mut info,
+            statistics,
+        } = stripe;
+
+        info.offset = self.offset;
+        self.offset += data.len() as u64;
+        self.num_rows += info.number_of_rows;
+
+        if self.file_statistics.is_empty() {
+            self.file_statistics = statistics.col_stats.clone();
+        } else {
+            for (acc, stats) in self.file_statistics.iter_mut().zip(&statistics.col_stats) {
+                merge_statistics(acc, stats);
+            }
+        }
+
+        self.stripes.push(info);
+        self.stripe_statistics.push(statistics);
+    }
+
+    pub fn finish(self) -> ApolloResult<Vec<u8>> {
+        let mut out = vec![];
+
+        let metadata = Metadata {
+            stripe_stats: self.stripe_statistics,
+        };
+        compress_stream(self.compression, &metadata.encode_to_vec(), &mut out)?;
+        let metadata_length = out.len() as u64;
+
+        let footer = Footer {
+            header_length: ORC_MAGIC.len() as u64,
+            content_length: self.offset - ORC_MAGIC.len() as u64,
+            stripes: self.stripes,
+            types: self.types,
+            number_of_rows: self.num_rows,
+            statistics: self.file_statistics,
+            row_index_stride: 0,
+        };
+        compress_stream(self.compression, &footer.encode_to_vec(), &mut out)?;
+        let footer_length = out.len() as u64 - metadata_length;
+
+        let postscript = PostScript {
+            footer_length,
+            compression: self.compression,
+            compression_block_size: Some(COMPRESSION_BLOCK_SIZE as u64),
+            version: vec![0, 12],
+            metadata_length,
+            // ORC-135, timestamps are written relative to UTC.
+            writer_version: Some(6),
+            magic: Some("ORC".to_string()),
+        }
+        .encode_to_vec();
+
+        out.extend_from_slice(&postscript);
+        out.push(postscript.len() as u8);
+
+        Ok(out)
+    }
+}
+
+fn dtype_to_orc_kind(dtype: &DataType, name: &str) -> ApolloResult<TypeKind> {
+    use TypeKind as K;
+
+    Ok(match dtype {
+        DataType::Boolean => K::Boolean,
+        DataType::Int8 => K::Byte,
+        DataType::Int16 | DataType::UInt8 => K::Short,
+        DataType::Int32 | DataType::UInt16 => K::Int,
+        DataType::Int64 | DataType::UInt32 => K::Long,
+        DataType::Float32 => K::Float,
+        DataType::Float64 => K::Double,
+        DataType::String => K::String,
+        dt if dt.is_categorical() || dt.is_enum() => K::String,
+        DataType::Binary => K::Binary,
+        DataType::Date => K::Date,
+        DataType::Datetime(_, _) => K::Timestamp,
+        DataType::Decimal(_, _) => K::Decimal,
+        dt => apollo_bail!(
+            InvalidOperation: "cannot write column '{}' of dtype {} to ORC", name, dt
+        ),
+    })
+}
+
+struct EncodedColumn {
+    present: Option<Vec<u8>>,
+    streams: Vec<(StreamKind, Vec<u8>)>,
+    encoding: ColumnEncodingKind,
+    statistics: ColumnStatistics,
+}
+
+fn encode_column(s: &Series, kind: TypeKind) -> ApolloResult<EncodedColumn> {
+    use TypeKind as K;
+
+    let null_count = s.null_count();
+    let present = (null_count > 0).then(|| {
+        let mut out = vec![];
+        encode_bool_rle(s.is_not_null().into_no_null_iter(), &mut out);
+        out
+    });
+
+    let mut statistics = ColumnStatistics {
+        number_of_values: Some((s.len() - null_count) as u64),
+        has_null: Some(null_count > 0),
+        ..Default::default()
+    };
+
+    let (encoding, streams) = match kind {
+        K::Boolean => {
+            let ca = s.bool()?;
+            let mut data = vec![];
+            encode_bool_rle(ca.into_iter().flatten(), &mut data);
+
+            statistics.bucket_statistics = Some(BucketStatistics {
+                count: vec![ca.num_trues() as u64],
+            });
+
+            (ColumnEncodingKind::Direct, vec![(StreamKind::Data, data)])
+        },
+        K::Byte => {
+            let values = s.i8()?.into_iter().flatten().collect::<Vec<_>>();
+            let mut data = vec![];
+            encode_byte_rle(
+                &values.iter().map(|v| *v as u8).collect::<Vec<_>>(),
+                &mut data,
+            );
+
+            statistics.int_statistics =
+                Some(integer_statistics(values.iter().map(|v| *v as i64)));
+
+            (ColumnEncodingKind::Direct, vec![(StreamKind::Data, data)])
+        },
+        K::Short | K::Int | K::Long => {
+            let s = s.cast(&DataType::Int64)?;
+            let values = s.i64()?.into_iter().flatten().collect::<Vec<_>>();
+            let mut data = vec![];
+            encode_int_rle_v2(&values, true, &mut data);
+
+            statistics.int_statistics = Some(integer_statistics(values.iter().copied()));
+
+            (ColumnEncodingKind::DirectV2, vec![(StreamKind::Data, data)])
+        },
+        K::Float | K::Double => {
+            let s = s.cast(&DataType::Float64)?;
+            let values = s.f64()?.into_iter().flatten();
+            let mut data = vec![];
+            let mut stats = DoubleStatistics {
+                sum: Some(0.0),
+                ..Default::default()
+            };
+
+            for v in values {
+                if kind == K::Float {
+                    data.extend_from_slice(&(v as f32).to_le_bytes());
+                } else {
+                    data.extend_from_slice(&v.to_le_bytes());
+                }
+
+                if !v.is_nan() {
+                    stats.minimum = Some(stats.minimum.map_or(v, |m| m.min(v)));
+                    stats.maximum = Some(stats.maximum.map_or(v, |m| m.max(v)));
+                }
+                stats.sum = stats.sum.map(|sum| sum + v);
+            }
+
+            statistics.double_statistics = Some(stats);
+
+            (ColumnEncodingKind::Direct, vec![(StreamKind::Data, data)])
+        },
+        K::String | K::Binary => {
+            let s = if kind == K::String {
+                s.cast(&DataType::String)?.cast(&DataType::Binary)?
+            } else {
+                s.clone()
+            };
+            let ca = s.binary()?;
+
+            let mut data = vec![];
+            let mut lengths = vec![];
+            let mut min: Option<&[u8]> = None;
+            let mut max: Option<&[u8]> = None;
+
+            for v in ca.into_iter().flatten() {
+                data.extend_from_slice(v);
+                lengths.push(v.len() as i64);
+                min = Some(min.map_or(v, |m| m.min(v)));
+                max = Some(max.map_or(v, |m| m.max(v)));
+            }
+
+            let total_length = data.len() as i64;
+
+            if kind == K::String {
+                // The values originate from a string column, so they are valid utf-8.
+                let to_string = |v: &[u8]| String::from_utf8_lossy(v).into_owned();
+
+                statistics.string_statistics = Some(StringStatistics {
+                    minimum: min.map(to_string),
+                    maximum: max.map(to_string),
+                    sum: Some(total_length),
+                });
+            } else {
+                statistics.binary_statistics = Some(BinaryStatistics {
+                    sum: Some(total_length),
+                });
+            }
+
+            let mut length_data = vec![];
+            encode_int_rle_v2(&lengths, false, &mut length_data);
+
+            (
+                ColumnEncodingKind::DirectV2,
+                vec![
+                    (StreamKind::Data, data),
+                    (StreamKind::Length, length_data),
+                ],
+            )
+        },
+        K::Date => {
+            let values = s
+                .to_physical_repr()
+                .i32()?
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>();
+            let mut data = vec![];
+            encode_int_rle_v2(
+                &values.iter().map(|v| *v as i64).collect::<Vec<_>>(),
+                true,
+                &mut data,
+            );
+
+            statistics.date_statistics = Some(DateStatistics {
+                minimum: values.iter().min().copied(),
+                maximum: values.iter().max().copied(),
+            });
+
+            (ColumnEncodingKind::DirectV2, vec![(StreamKind::Data, data)])
+        },
+        K::Timestamp => {
+            let DataType::Datetime(_, tz) = s.dtype() else {
+                unreachable!()
+            };
+            let s = s.cast(&DataType::Datetime(TimeUnit::Nanoseconds, tz.clone()))?;
+
+            let mut seconds = vec![];
+            let mut nanos = vec![];
+
+            for v in s.to_physical_repr().i64()?.into_iter().flatten() {
+                let mut secs = v.div_euclid(1_000_000_000);
+                let ns = v.rem_euclid(1_000_000_000);
+
+                // Mirrors the reader, which subtracts a second for negative timestamps with
+                // sub-second parts of at least a millisecond.
+                if secs < 0 && ns > 999_999 {
+                    secs += 1;
+                }
+
+                seconds.push(secs - ORC_TIMESTAMP_EPOCH_SECONDS);
+                nanos.push(encode_nanos(ns as u64) as i64);
+            }
+
+            let mut data = vec![];
+            encode_int_rle_v2(&seconds, true, &mut data);
+            let mut secondary = vec![];
+            encode_int_rle_v2(&nanos, false, &mut secondary);
+
+            (
+                ColumnEncodingKind::DirectV2,
+                vec![
+                    (StreamKind::Data, data),
+                    (StreamKind::Secondary, secondary),
+                ],
+            )
+        },
+        K::Decimal => {
+            let DataType::Decimal(_, scale) = s.dtype() else {
+                unreachable!()
+            };
+            let values = s
+                .to_physical_repr()
+                .i128()?
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>();
+            let mut data = vec![];
+            encode_varint128(&values, &mut data);
+            // All values share the scale of the column.
+            let mut secondary = vec![];
+            encode_int_rle_v2(&vec![*scale as i64; values.len()], true, &mut secondary);
+
+            (
+                ColumnEncodingKind::DirectV2,
+                vec![
+                    (StreamKind::Data, data),
+                    (StreamKind::Secondary, secondary),
+                ],
+            )
+        },
+        K::Varchar | K::Char | K::TimestampInstant | K::List | K::Map | K::Struct | K::Union => {
+            unreachable!()
+        },
+    };
+
+    Ok(EncodedColumn {
+        present,
+        streams,
+        encoding,
+        statistics,
+    })
+}
+
+fn integer_statistics(values: impl Iterator<Item = i64>) -> IntegerStatistics {
+    let mut stats = IntegerStatistics {
+        sum: Some(0),
+        ..Default::default()
+    };
+
+    for v in values {
+        stats.minimum = Some(stats.minimum.map_or(v, |m| m.min(v)));
+        stats.maximum = Some(stats.maximum.map_or(v, |m| m.max(v)));
+        // The sum is omitted on overflow.
+        stats.sum = stats.sum.and_then(|sum| sum.checked_add(v));
+    }
+
+    stats
+}
+
+/// Encodes the nanoseconds of a timestamp, stripping trailing decimal zeros.
+fn encode_nanos(nanos: u64) -> u64 {
+    if nanos == 0 || nanos % 100 != 0 {
+        return nanos << 3;
+    }
+
+    let mut nanos = nanos / 100;
+    let mut zeros = 1;
+
+    while nanos % 10 == 0 && zeros < 7 {
+        nanos /= 10;
+        zeros += 1;
+    }
+
+    (nanos << 3) | zeros
+}
+
+fn merge_statistics(acc: &mut ColumnStatistics, other: &ColumnStatistics) {
+    fn merge<T: Copy>(a: Option<T>, b: Option<T>, f: impl Fn(T, T) -> T) -> Option<T> {
+        match (a, b) {
+            (Some(a), Some(b)) => Some(f(a, b)),
+            (a, b) => a.or(b),
+        }
+    }
+
+    acc.number_of_values = merge(acc.number_of_values, other.number_of_values, |a, b| a + b);
+    acc.has_null = merge(acc.has_null, other.has_null, |a, b| a || b);
+
+    if let (Some(a), Some(b)) = (&mut acc.int_statistics, &other.int_statistics) {
+        a.minimum = merge(a.minimum, b.minimum, i64::min);
+        a.maximum = merge(a.maximum, b.maximum, i64::max);
+        a.sum = a.sum.zip(b.sum).and_then(|(a, b)| a.checked_add(b));
+    }
+
+    if let (Some(a), Some(b)) = (&mut acc.double_statistics, &other.double_statistics) {
+        a.minimum = merge(a.minimum, b.minimum, f64::min);
+        a.maximum = merge(a.maximum, b.maximum, f64::max);
+        a.sum = a.sum.zip(b.sum).map(|(a, b)| a + b);
+    }
+
+    if let (Some(a), Some(b)) = (&mut acc.string_statistics, &other.string_statistics) {
+        if let Some(min) = &b.minimum {
+            if a.minimum.as_ref().is_none_or(|a| min < a) {
+                a.minimum = Some(min.clone());
+            }
+        }
+        if let Some(max) = &b.maximum {
+            if a.maximum.as_ref().is_none_or(|a| max > a) {
+                a.maximum = Some(max.clone());
+            }
+        }
+        a.sum = a.sum.zip(b.sum).map(|(a, b)| a + b);
+    }
+
+    if let (Some(a), Some(b)) = (&mut acc.date_statistics, &other.date_statistics) {
+        a.minimum = merge(a.minimum, b.minimum, i32::min);
+        a.maximum = merge(a.maximum, b.maximum, i32::max);
+    }
+
+    if let (Some(a), Some(b)) = (&mut acc.bucket_statistics, &other.bucket_statistics) {
+        for (a, b) in a.count.iter_mut().zip(&b.count) {
+            *a += b;
+        }
+    }
+
+    if let (Some(a), Some(b)) = (&mut acc.binary_statistics, &other.binary_statistics) {
+        a.sum = a.sum.zip(b.sum).map(|(a, b)| a + b);
+    }
+}
@@ -0,0 +1,344 @@
+//! Process-wide cache of decoded parquet file metadata.
+//!
+//! Fetching and decoding the footer of a parquet file dominates the latency of interactive queries
+//! over many small files. This cache keeps the decoded metadata of files around between scans. An
+//! entry is keyed by the file path and is only used if the version of the file (its ETag, or its
+//! modification time and size) still matches. Remote objects without an ETag, such as files on
+//! SFTP servers, are never cached: their modification time only has a resolution of seconds, so a
+//! rewrite within the same second would go unnoticed.
+//!
+//! The cache is opt-in and disabled unless `APOLLO_PARQUET_METADATA_CACHE_SIZE` is set to a size
+//! in bytes. The size of an entry is the size of the encoded footer. Once the budget is exceeded,
+//! the least recently used entries are evicted.
+//!
+//! The cache is used by scans, which know the path of the file they read. The eager
+//! [`ParquetReader`](super::read::ParquetReader) reads from an arbitrary reader and never uses it.
+use std::sync::{LazyLock, Mutex};
+
+use apollo_error::{ApolloResult, to_compute_err};
+use apollo_utils::cache::LruCache;
+use apollo_utils::pl_path::{CloudScheme, PlRefPath};
+use apollo_utils::pl_str::PlSmallStr;
+
+use super::metadata::FileMetadataRef;
+#[cfg(feature = "async")]
+use crate::cloud::CloudOptions;
+
+/// Upper bound on the number of entries, the byte budget is what is actually enforced.
+const MAX_ENTRIES: usize = 1 << 16;
+
+static METADATA_CACHE: LazyLock<Mutex<MetadataCache>> =
+    LazyLock::new(|| Mutex::new(MetadataCache::new(MAX_ENTRIES)));
+
+/// Size budget of the metadata cache in bytes, 0 disables the cache.
+fn metadata_cache_budget() -> usize {
+    std::env::var("APOLLO_PARQUET_METADATA_CACHE_SIZE")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0)
+}
+
+pub fn metadata_cache_enabled() -> bool {
+    metadata_cache_budget() > 0
+}
+
+/// Identifies the contents of a file. The cached metadata of a file is only used if its version
+/// is unchanged.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileVersion {
+    /// Size of the file in bytes.
+    pub size: usize,
+    /// `None` if the version can't be determined reliably, such files are never cached.
+    tag: Option<PlSmallStr>,
+}
+
+impl FileVersion {
+    pub fn from_std_metadata(metadata: &std::fs::Metadata) -> ApolloResult<Self> {
+        let modified = metadata
+            .modified()?
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(to_compute_err)?;
+
+        Ok(Self {
+            size: metadata.len() as usize,
+            tag: Some(apollo_utils::format_pl_smallstr!("{}", modified.as_nanos())),
+        })
+    }
+
+    #[cfg(feature = "cloud")]
+    pub fn from_object_meta(metadata: &object_store::ObjectMeta) -> Self {
+        Self {
+            size: metadata.size as usize,
+            tag: metadata.e_tag.as_deref().map(PlSmallStr::from_str),
+        }
+    }
+}
+
+/// Gets the current version of the file at `path`.
+#[cfg(feature = "async")]
+pub async fn file_version(
+    path: &PlRefPath,
+    #[cfg_attr(not(feature = "cloud"), allow(unused))] cloud_options: Option<&CloudOptions>,
+) -> ApolloResult<FileVersion> {
+    if is_local(path) {
+        let metadata = tokio::fs::metadata(crate::resolve_homedir(path.strip_scheme())).await?;
+        return FileVersion::from_std_metadata(&metadata);
+    }
+
+    apollo_error::feature_gated!("cloud", {
+        use crate::cloud::{CloudLocation, build_object_store, object_path_from_str};
+
+        let (CloudLocation { prefix, .. }, store) =
+            build_object_store(path.clone(), cloud_options, false).await?;
+        let metadata = store.head(&object_path_from_str(&prefix)?).await?;
+
+        Ok(FileVersion::from_object_meta(&metadata))
+    })
+}
+
+/// Relative local paths are made absolute, so that they share an entry with the absolute path.
+fn cache_key(path: &PlRefPath) -> PlRefPath {
+    path.to_absolute_path()
+        .map_or_else(|_| path.clone(), |path| path.into_owned())
+}
+
+fn is_local(path: &PlRefPath) -> bool {
+    matches!(
+        path.scheme(),
+        None | Some(CloudScheme::File | CloudScheme::FileNoHostname)
+    )
+}
+
+struct Entry {
+    version: FileVersion,
+    metadata: FileMetadataRef,
+    size: usize,
+}
+
+struct MetadataCache {
+    entries: LruCache<PlRefPath, Entry>,
+    max_entries: usize,
+    size: usize,
+}
+
+impl MetadataCache {
+    fn new(max_entries: usize) -> Self {
+        Self {
+            entries: LruCache::with_capacity(max_entries),
+            max_entries,
+            size: 0,
+        }
+    }
+
+    fn insert(&mut self, path: PlRefPath, entry: Entry, budget: usize) {
+        self.remove(&path);
+        // The LRU cache silently drops its least recently used entry when it is full, so make
+        // room explicitly to keep the size in sync.
+        if self.entries.len() == self.max_entries {
+            self.pop_lru();
+        }
+        self.size += entry.size;
+        self.entries.insert(path, entry);
+        self.evict(budget);
+    }
+
+    fn remove(&mut self, path: &PlRefPath) {
+        if let Some(entry) = self.entries.remove(path) {
+            self.size -= entry.size;
+        }
+    }
+
+    fn evict(&mut self, budget: usize) {
+        while self.size > budget {
+            if !self.pop_lru() {
+                break;
+            }
+        }
+    }
+
+    fn pop_lru(&mut self) -> bool {
+        let Some((_, entry)) = self.entries.pop_lru() else {
+            return false;
+        };
+        self.size -= entry.size;
+        true
+    }
+}
+
+/// Gets the cached metadata of the file at `path`, if it was cached for the same `version`.
+pub fn get(path: &PlRefPath, version: &FileVersion) -> Option<FileMetadataRef> {
+    if !metadata_cache_enabled() || version.tag.is_none() {
+        return None;
+    }
+
+    let path = cache_key(path);
+    let mut cache = METADATA_CACHE.lock().unwrap();
+
+    match cache.entries.get(&path) {
+        Some(entry) if &entry.version == version => Some(entry.metadata.clone()),
+        Some(_) => {
+            // The file has changed.
+            cache.remove(&path);
+            None
+        },
+        None => None,
+    }
+}
+
+/// Caches the metadata of the file at `path`. `encoded_size` is the size of the footer in bytes.
+pub fn insert(
+    path: PlRefPath,
+    version: FileVersion,
+    metadata: FileMetadataRef,
+    encoded_size: usize,
+) {
+    let budget = metadata_cache_budget();
+
+    if budget == 0 || encoded_size > budget || version.tag.is_none() {
+        return;
+    }
+
+    METADATA_CACHE.lock().unwrap().insert(
+        cache_key(&path),
+        Entry {
+            version,
+            metadata,
+            size: encoded_size,
+        },
+        budget,
+    );
+}
+
+/// Removes the cached metadata of the file at `path`, or of all files if `path` is `None`.
+pub fn invalidate(path: Option<&PlRefPath>) {
+    let mut cache = METADATA_CACHE.lock().unwrap();
+
+    match path {
+        Some(path) => cache.remove(&cache_key(path)),
+        None => *cache = MetadataCache::new(MAX_ENTRIES),
+    }
+}
+
+/// Fetches the metadata of the given files into the cache, skipping files that are already cached.
+#[cfg(feature = "async")]
+pub async fn prewarm(
+    paths: Vec<PlRefPath>,
+    cloud_options: Option<&CloudOptions>,
+) -> ApolloResult<()> {
+    use futures::{StreamExt, TryStreamExt};
+
+    apollo_error::apollo_ensure!(
+        metadata_cache_enabled(),
+        InvalidOperation: "the parquet metadata cache is disabled, set \
+        APOLLO_PARQUET_METADATA_CACHE_SIZE to enable it"
+    );
+
+    futures::stream::iter(paths)
+        .map(|path| async move {
+            if is_local(&path) {
+                return crate::pl_async::get_runtime()
+                    .spawn_blocking(move || prewarm_local(&path))
+                    .await
+                    .unwrap();
+            }
+
+            apollo_error::feature_gated!("cloud", {
+                // Consults and populates the cache.
+                super::read::ParquetObjectStore::from_uri(path, cloud_options, None)
+                    .await?
+                    .get_metadata()
+                    .await?;
+                Ok(())
+            })
+        })
+        .buffer_unordered(crate::pl_async::get_concurrency_limit() as usize)
+        .try_collect::<Vec<()>>()
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+fn prewarm_local(path: &PlRefPath) -> ApolloResult<()> {
+    let (version, file) = open_local_file(path)?;
+
+    if get(path, &version).is_none() {
+        read_local_metadata(path.clone(), version, file)?;
+    }
+
+    Ok(())
+}
+
+/// Opens the local file at `path`, along with its version.
+pub fn open_local_file(path: &PlRefPath) -> ApolloResult<(FileVersion, std::fs::File)> {
+    let file = apollo_utils::open_file(&crate::resolve_homedir(path.strip_scheme()))?;
+    let version = FileVersion::from_std_metadata(&file.metadata()?)?;
+    Ok((version, file))
+}
+
+/// Reads the metadata of a local file and caches it.
+pub fn read_local_metadata(
+    path: PlRefPath,
+    version: FileVersion,
+    mut file: std::fs::File,
+) -> ApolloResult<FileMetadataRef> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    let metadata = std::sync::Arc::new(apollo_parquet::read::read_metadata(&mut file)?);
+
+    // The footer ends with its length and the magic bytes.
+    let mut footer_len = [0u8; 4];
+    file.seek(SeekFrom::End(
+        -(apollo_parquet::parquet::FOOTER_SIZE as i64),
+    ))?;
+    file.read_exact(&mut footer_len)?;
+    let encoded_size = u32::from_le_bytes(footer_len) as usize;
+
+    insert(path, version, metadata.clone(), encoded_size);
+
+    Ok(metadata)
+}
+
+#[cfg(test)]
+mod tests {
+    use apollo_core::df;
+
+    use super::*;
+    use crate::prelude::ParquetWriter;
+
+    #[test]
+    fn test_metadata_cache_size_on_eviction() {
+        let mut df = df!["a" => [1i64, 2, 3]].unwrap();
+        let mut buf = vec![];
+        ParquetWriter::new(&mut buf).finish(&mut df).unwrap();
+        let metadata = std::sync::Arc::new(
+            apollo_parquet::read::read_metadata(&mut std::io::Cursor::new(buf)).unwrap(),
+        );
+
+        let entry = |size| Entry {
+            version: FileVersion {
+                size: 0,
+                tag: Some(PlSmallStr::EMPTY),
+            },
+            metadata: metadata.clone(),
+            size,
+        };
+        let path = |i: usize| PlRefPath::new(format!("/{i}.parquet"));
+
+        let mut cache = MetadataCache::new(2);
+        for i in 0..3 {
+            cache.insert(path(i), entry(10), 100);
+        }
+        assert_eq!(cache.entries.len(), 2);
+        assert_eq!(cache.size, 20);
+        assert!(cache.entries.get(&path(0)).is_none());
+
+        // Replacing an entry doesn't count it twice.
+        cache.insert(path(1), entry(30), 100);
+        assert_eq!(cache.size, 40);
+
+        cache.insert(path(3), entry(80), 100);
+        assert_eq!(cache.entries.len(), 1);
+        assert_eq!(cache.size, 80);
+    }
+}
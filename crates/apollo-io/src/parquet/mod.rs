@@ -1,5 +1,6 @@
 //! Functionality for reading and writing Apache Parquet files.
 
 pub mod metadata;
+pub mod metadata_cache;
 pub mod read;
 pub mod write;
@@ -10,11 +10,13 @@ use crate::cloud::{
     CloudLocation, CloudOptions, ApolloObjectStore, build_object_store, object_path_from_str,
 };
 use crate::parquet::metadata::FileMetadataRef;
+use crate::parquet::metadata_cache::{self, FileVersion};
 
 pub struct ParquetObjectStore {
+    uri: PlRefPath,
     store: ApolloObjectStore,
     path: ObjectPath,
-    length: Option<usize>,
+    version: Option<FileVersion>,
     metadata: Option<FileMetadataRef>,
     schema: Option<ArrowSchemaRef>,
 }
@@ -25,24 +27,27 @@ impl ParquetObjectStore {
         options: Option<&CloudOptions>,
         metadata: Option<FileMetadataRef>,
     ) -> ApolloResult<Self> {
-        let (CloudLocation { prefix, .. }, store) = build_object_store(uri, options, false).await?;
+        let (CloudLocation { prefix, .. }, store) =
+            build_object_store(uri.clone(), options, false).await?;
         let path = object_path_from_str(&prefix)?;
 
         Ok(ParquetObjectStore {
+            uri,
             store,
             path,
-            length: None,
+            version: None,
             metadata,
             schema: None,
         })
     }
 
-    /// Initialize the length property of the object, unless it has already been fetched.
-    async fn length(&mut self) -> ApolloResult<usize> {
-        if self.length.is_none() {
-            self.length = Some(self.store.head(&self.path).await?.size as usize);
+    /// Initialize the version (and length) of the object, unless it has already been fetched.
+    async fn version(&mut self) -> ApolloResult<&FileVersion> {
+        if self.version.is_none() {
+            let object_meta = self.store.head(&self.path).await?;
+            self.version = Some(FileVersion::from_object_meta(&object_meta));
         }
-        Ok(self.length.unwrap())
+        Ok(self.version.as_ref().unwrap())
     }
 
     /// Number of rows in the parquet file.
@@ -51,16 +56,28 @@ impl ParquetObjectStore {
         Ok(metadata.num_rows)
     }
 
-    /// Fetch the metadata of the parquet file, do not memoize it.
-    async fn fetch_metadata(&mut self) -> ApolloResult<FileMetadata> {
-        let length = self.length().await?;
-        fetch_metadata(&self.store, &self.path, length).await
+    /// Fetch the metadata of the parquet file from the metadata cache or the object store, do
+    /// not memoize it.
+    async fn fetch_metadata(&mut self) -> ApolloResult<FileMetadataRef> {
+        let version = self.version().await?.clone();
+
+        if let Some(metadata) = metadata_cache::get(&self.uri, &version) {
+            return Ok(metadata);
+        }
+
+        let (metadata, encoded_size) =
+            fetch_metadata(&self.store, &self.path, version.size).await?;
+        let metadata = Arc::new(metadata);
+
+        metadata_cache::insert(self.uri.clone(), version, metadata.clone(), encoded_size);
+
+        Ok(metadata)
     }
 
     /// Fetch and memoize the metadata of the parquet file.
     pub async fn get_metadata(&mut self) -> ApolloResult<&FileMetadataRef> {
         if self.metadata.is_none() {
-            self.metadata = Some(Arc::new(self.fetch_metadata().await?));
+            self.metadata = Some(self.fetch_metadata().await?);
         }
         Ok(self.metadata.as_ref().unwrap())
     }
@@ -93,12 +110,12 @@ fn read_i32le(reader: &mut &[u8]) -> Option<i32> {
     read_n(reader).map(i32::from_le_bytes)
 }
 
-/// Asynchronously reads the files' metadata
+/// Asynchronously reads the files' metadata. Also returns the size of the footer in bytes.
 pub async fn fetch_metadata(
     store: &ApolloObjectStore,
     path: &ObjectPath,
     file_byte_length: usize,
-) -> ApolloResult<FileMetadata> {
+) -> ApolloResult<(FileMetadata, usize)> {
     let footer_header_bytes = store
         .get_range(
             path,
@@ -143,11 +160,13 @@ pub async fn fetch_metadata(
         )
         .await?;
 
-    Ok(apollo_parquet::parquet::read::deserialize_metadata(
+    let metadata = apollo_parquet::parquet::read::deserialize_metadata(
         std::io::Cursor::new(footer_bytes.as_ref()),
         // TODO: Describe why this makes sense. Taken from the previous
         // implementation which said "a highly nested but sparse struct could
         // result in many allocations".
         footer_bytes.as_ref().len() * 2 + 1024,
-    )?)
+    )?;
+
+    Ok((metadata, footer_bytes.len()))
 }
@@ -37,14 +37,12 @@ fn assert_dtypes(dtype: &ArrowDataType) {
         // This should have been converted to a LargeList
         D::List(_) => unreachable!(),
 
-        // This should have been converted to a LargeList(Struct(_))
-        D::Map(_, _) => unreachable!(),
-
         // Recursive checks
         D::Dictionary(_, dtype, _) => assert_dtypes(dtype),
         D::Extension(ext) => assert_dtypes(&ext.inner),
         D::LargeList(inner) => assert_dtypes(&inner.dtype),
         D::FixedSizeList(inner, _) => assert_dtypes(&inner.dtype),
+        D::Map(inner, _) => assert_dtypes(&inner.dtype),
         D::Struct(fields) => fields.iter().for_each(|f| assert_dtypes(f.dtype())),
 
         _ => {},
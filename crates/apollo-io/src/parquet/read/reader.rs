@@ -14,6 +14,9 @@ use crate::parquet::metadata::FileMetadataRef;
 use crate::prelude::*;
 
 /// Read Apache parquet format into a DataFrame.
+///
+/// This never uses the [metadata cache](crate::parquet::metadata_cache), as the reader has no
+/// path to key the metadata by.
 #[must_use]
 pub struct ParquetReader<R: Read + Seek> {
     reader: R,
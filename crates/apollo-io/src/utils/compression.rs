@@ -85,6 +85,9 @@ pub enum CompressedReader {
     Zlib(flate2::bufread::ZlibDecoder<Cursor<Buffer<u8>>>),
     #[cfg(feature = "decompress")]
     Zstd(zstd::Decoder<'static, Cursor<Buffer<u8>>>),
+    /// Text in a non UTF-8 encoding, decoded to UTF-8 while reading.
+    #[cfg(feature = "csv")]
+    Transcoding(Box<crate::csv::encoding::TranscodingReader>),
 }
 
 impl CompressedReader {
@@ -151,6 +154,10 @@ impl CompressedReader {
             CompressedReader::Zstd(reader) => {
                 reader.get_ref().get_ref().len() * ESTIMATED_ZSTD_RATIO
             },
+            #[cfg(feature = "csv")]
+            // UTF-16 shrinks and the legacy multi-byte encodings grow when decoded to UTF-8, so
+            // this is only a rough estimate.
+            CompressedReader::Transcoding(reader) => reader.encoded_len_estimate(),
         }
     }
 
@@ -215,6 +222,11 @@ impl CompressedReader {
             CompressedReader::Zstd(decoder) => {
                 new_slice_from_read(decoder.take(read_size as u64).read_to_end(&mut buf)?, buf)
             },
+            #[cfg(feature = "csv")]
+            CompressedReader::Transcoding(decoder) => new_slice_from_read(
+                decoder.as_mut().take(read_size as u64).read_to_end(&mut buf)?,
+                buf,
+            ),
         }
     }
 }
@@ -236,6 +248,8 @@ impl Read for CompressedReader {
             CompressedReader::Zlib(decoder) => decoder.read(buf),
             #[cfg(feature = "decompress")]
             CompressedReader::Zstd(decoder) => decoder.read(buf),
+            #[cfg(feature = "csv")]
+            CompressedReader::Transcoding(decoder) => decoder.read(buf),
         }
     }
 }
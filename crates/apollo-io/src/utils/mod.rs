@@ -2,11 +2,17 @@ pub mod compression;
 mod other;
 
 pub use other::*;
+#[cfg(feature = "csv")]
+pub use rejected_rows::{RejectLocation, RejectedRow, RejectedRows};
 #[cfg(any(feature = "async", feature = "cloud"))]
 pub mod byte_source;
 pub mod file;
 pub mod mkdir;
+#[cfg(feature = "csv")]
+mod rejected_rows;
 pub mod slice;
+#[cfg(feature = "async")]
+pub mod staging;
 pub mod sync_on_close;
 
 /// Excludes only the unreserved URI characters in RFC-3986:
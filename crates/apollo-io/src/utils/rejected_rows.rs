@@ -0,0 +1,140 @@
+use std::fmt;
+use std::fs::File;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+use apollo_core::prelude::*;
+use apollo_utils::pl_path::PlRefPath;
+
+/// A row that was routed to the reject sink because it could not be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RejectedRow {
+    /// Path of the file the row was read from, if the source is a file.
+    pub path: Option<PlSmallStr>,
+    /// 1-based line number of the (first line of the) row in the file.
+    pub line_number: usize,
+    /// The raw bytes of the row, without the line terminator.
+    pub raw: Vec<u8>,
+    /// The error that was raised when parsing the row.
+    pub error: String,
+}
+
+/// Sink for rows that could not be parsed by the CSV or NDJSON readers.
+///
+/// Readers configured with a reject path do not fail on malformed rows. Instead, the row is left
+/// out of the result and appended to a CSV file with the columns `file_path`, `line_number`,
+/// `raw_line` and `error`, so that it can be audited after the read. The file is created (or
+/// truncated) once per execution of the read, and the rows are written in the order in which they
+/// are rejected. Cloning shares the underlying file.
+#[derive(Clone)]
+pub struct RejectedRows(Arc<Mutex<File>>);
+
+impl RejectedRows {
+    /// Creates the file at `path` and writes the header.
+    pub fn try_new(path: &PlRefPath) -> ApolloResult<Self> {
+        apollo_ensure!(
+            !path.has_scheme(),
+            InvalidOperation: "'reject_rows' must be a local path, got '{}'", path.as_str()
+        );
+
+        let mut file = File::create(path.as_std_path()).map_err(
+            |err| apollo_err!(ComputeError: "failed to create '{}': {}", path.as_str(), err),
+        )?;
+        file.write_all(b"file_path,line_number,raw_line,error\n")?;
+
+        Ok(Self(Arc::new(Mutex::new(file))))
+    }
+
+    pub fn extend(&self, rows: Vec<RejectedRow>) -> ApolloResult<()> {
+        if rows.is_empty() {
+            return Ok(());
+        }
+
+        let mut buf = Vec::new();
+        write_rows(&rows, &mut buf);
+        self.0.lock().unwrap().write_all(&buf)?;
+        Ok(())
+    }
+}
+
+impl fmt::Debug for RejectedRows {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RejectedRows")
+    }
+}
+
+/// Formats the rows as CSV lines. All fields but the line number are quoted, a missing path is
+/// written as an empty field.
+fn write_rows(rows: &[RejectedRow], buf: &mut Vec<u8>) {
+    let write_quoted = |buf: &mut Vec<u8>, value: &[u8]| {
+        buf.push(b'"');
+        for &c in value {
+            if c == b'"' {
+                buf.push(b'"');
+            }
+            buf.push(c);
+        }
+        buf.push(b'"');
+    };
+
+    for row in rows {
+        if let Some(path) = &row.path {
+            write_quoted(buf, path.as_bytes());
+        }
+        write!(buf, ",{},", row.line_number).unwrap();
+        write_quoted(buf, String::from_utf8_lossy(&row.raw).as_bytes());
+        buf.push(b',');
+        write_quoted(buf, row.error.as_bytes());
+        buf.push(b'\n');
+    }
+}
+
+/// Where a chunk of rows starts, used to report rejected rows.
+#[derive(Clone, Debug, Default)]
+pub struct RejectLocation {
+    pub path: Option<PlSmallStr>,
+    /// Number of lines in the file before the chunk.
+    pub line_offset: usize,
+}
+
+impl RejectLocation {
+    pub fn reject(&self, lines_into_chunk: usize, raw: &[u8], error: &ApolloError) -> RejectedRow {
+        RejectedRow {
+            path: self.path.clone(),
+            line_number: self.line_offset + lines_into_chunk + 1,
+            raw: raw.to_vec(),
+            error: error.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_rejected_rows() {
+        let location = RejectLocation {
+            path: Some("b.csv".into()),
+            line_offset: 10,
+        };
+        let err = apollo_err!(ComputeError: "bad \"row\"");
+
+        let mut buf = Vec::new();
+        write_rows(
+            &[
+                location.reject(2, b"x,y", &err),
+                RejectLocation::default().reject(0, b"z", &err),
+            ],
+            &mut buf,
+        );
+
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            format!(
+                "\"b.csv\",13,\"x,y\",\"{0}\"\n,1,\"z\",\"{0}\"\n",
+                err.to_string().replace('"', "\"\"")
+            )
+        );
+    }
+}
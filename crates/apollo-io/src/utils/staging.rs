@@ -0,0 +1,108 @@
+//! Staging locations for atomic writes.
+//!
+//! An atomic write first writes to a hidden staging location, and only moves the written files to
+//! their final paths once everything was written successfully. Readers skip hidden files, so they
+//! never observe a partially written result.
+use apollo_error::{ApolloResult, feature_gated};
+use apollo_utils::pl_path::{CloudScheme, PlRefPath};
+use apollo_utils::unique_id::UniqueId;
+
+use crate::cloud::CloudOptions;
+use crate::resolve_homedir;
+
+/// Hidden sibling of `path` to write to before committing to `path`.
+///
+/// Staging next to the final path keeps the commit a rename within the same directory / bucket.
+pub fn staging_file_path(path: &PlRefPath) -> PlRefPath {
+    let s = path.as_str();
+    let name_start = s.rfind('/').map_or(0, |i| i + 1);
+    PlRefPath::new(format!(
+        "{}.{}.{}.staging",
+        &s[..name_start],
+        &s[name_start..],
+        UniqueId::new()
+    ))
+}
+
+/// Hidden directory within `base_path` to write to before committing to `base_path`.
+pub fn staging_dir_path(base_path: &PlRefPath) -> PlRefPath {
+    base_path.join(format!(".apollo-staging-{}", UniqueId::new()))
+}
+
+fn is_local(path: &PlRefPath) -> bool {
+    matches!(
+        path.scheme(),
+        None | Some(CloudScheme::File | CloudScheme::FileNoHostname)
+    )
+}
+
+/// Moves the staged file at `staged` to `path`, replacing any existing file.
+pub async fn commit_staged_file(
+    staged: &PlRefPath,
+    path: &PlRefPath,
+    #[cfg_attr(not(feature = "cloud"), expect(unused))] cloud_options: Option<&CloudOptions>,
+) -> ApolloResult<()> {
+    if is_local(path) {
+        let path = resolve_homedir(path.strip_scheme());
+        if let Some(parent) = path.parent() {
+            tokio::fs::DirBuilder::new()
+                .recursive(true)
+                .create(parent)
+                .await?;
+        }
+        tokio::fs::rename(resolve_homedir(staged.strip_scheme()), path).await?;
+        return Ok(());
+    }
+
+    feature_gated!("cloud", {
+        use crate::cloud::{CloudLocation, build_object_store, object_path_from_str};
+
+        let (CloudLocation { prefix, .. }, object_store) =
+            build_object_store(path.clone(), cloud_options, false).await?;
+        let CloudLocation {
+            prefix: staged_prefix,
+            ..
+        } = CloudLocation::new(staged.clone(), false)?;
+
+        object_store
+            .rename(
+                &object_path_from_str(&staged_prefix)?,
+                &object_path_from_str(&prefix)?,
+            )
+            .await
+    })
+}
+
+/// Removes the staged file at `staged`. A file that doesn't exist is not an error.
+pub async fn remove_staged_file(
+    staged: &PlRefPath,
+    #[cfg_attr(not(feature = "cloud"), expect(unused))] cloud_options: Option<&CloudOptions>,
+) -> ApolloResult<()> {
+    if is_local(staged) {
+        return match tokio::fs::remove_file(resolve_homedir(staged.strip_scheme())).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        };
+    }
+
+    feature_gated!("cloud", {
+        use crate::cloud::{CloudLocation, build_object_store, object_path_from_str};
+
+        let (CloudLocation { prefix, .. }, object_store) =
+            build_object_store(staged.clone(), cloud_options, false).await?;
+
+        object_store.delete(&object_path_from_str(&prefix)?).await
+    })
+}
+
+/// Removes a local staging directory and everything in it. Object stores have no directories, so
+/// this is a no-op for cloud paths.
+pub fn remove_staging_dir(dir: &PlRefPath) -> ApolloResult<()> {
+    if is_local(dir) {
+        match std::fs::remove_dir_all(resolve_homedir(dir.strip_scheme())) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e.into()),
+            _ => {},
+        }
+    }
+    Ok(())
+}
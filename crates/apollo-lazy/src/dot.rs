@@ -6,7 +6,7 @@ impl LazyFrame {
     /// Get a dot language representation of the LogicalPlan.
     pub fn to_dot(&self, optimized: bool) -> ApolloResult<String> {
         let lp = if optimized {
-            self.clone().to_alp_optimized()
+            self.clone().with_result_cache(false).to_alp_optimized()
         } else {
             self.clone().to_alp()
         }?;
@@ -17,7 +17,10 @@ impl LazyFrame {
     /// Get a dot language representation of the streaming physical plan.
     #[cfg(feature = "new_streaming")]
     pub fn to_dot_streaming_phys(&self, optimized: bool) -> ApolloResult<String> {
-        let lf = self.clone().with_new_streaming(true);
+        let lf = self
+            .clone()
+            .with_new_streaming(true)
+            .with_result_cache(false);
         let mut lp = if optimized {
             lf.to_alp_optimized()
         } else {
@@ -202,6 +202,18 @@ impl LazyFrame {
         self
     }
 
+    /// Reorder trees of inner joins based on the estimated cardinalities of their inputs.
+    pub fn with_join_reorder(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::JOIN_REORDER, toggle);
+        self
+    }
+
+    /// Look up and store subplan results in the cross-query result cache.
+    pub fn with_result_cache(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::RESULT_CACHE, toggle);
+        self
+    }
+
     /// Run every node eagerly. This turns off multi-node optimizations.
     pub fn _with_eager(mut self, toggle: bool) -> Self {
         self.opt_state.set(OptFlags::EAGER, toggle);
@@ -222,14 +234,16 @@ impl LazyFrame {
     ///
     /// Returns `Err` if optimizing the logical plan fails.
     pub fn describe_optimized_plan(&self) -> ApolloResult<String> {
-        Ok(self.clone().to_alp_optimized()?.describe())
+        let lf = self.clone().with_result_cache(false);
+        Ok(lf.to_alp_optimized()?.describe())
     }
 
     /// Return a String describing the optimized logical plan in tree format.
     ///
     /// Returns `Err` if optimizing the logical plan fails.
     pub fn describe_optimized_plan_tree(&self) -> ApolloResult<String> {
-        Ok(self.clone().to_alp_optimized()?.describe_tree_format())
+        let lf = self.clone().with_result_cache(false);
+        Ok(lf.to_alp_optimized()?.describe_tree_format())
     }
 
     /// Return a String describing the logical plan.
@@ -901,6 +915,28 @@ impl LazyFrame {
         self._profile_post_opt(|_, _, _, _| Ok(()))
     }
 
+    /// Run the query with the streaming engine and return its physical plan annotated with the
+    /// metrics of every node, such as the time spent and the number of rows processed, together
+    /// with the peak memory usage. The result of the query itself is discarded.
+    #[cfg(feature = "new_streaming")]
+    pub fn explain_analyze(mut self) -> ApolloResult<apollo_stream::QueryProfile> {
+        if !matches!(
+            self.logical_plan,
+            DslPlan::Sink { .. } | DslPlan::SinkMultiple { .. }
+        ) {
+            self.logical_plan = DslPlan::Sink {
+                input: Arc::new(self.logical_plan),
+                payload: SinkType::Memory,
+            };
+        }
+        let mut alp_plan = self.with_new_streaming(true).to_alp_optimized()?;
+        apollo_stream::explain_analyze(
+            alp_plan.lp_top,
+            &mut alp_plan.lp_arena,
+            &mut alp_plan.expr_arena,
+        )
+    }
+
     pub fn sink_batches(
         mut self,
         function: PlanCallback<DataFrame, bool>,
@@ -981,28 +1017,34 @@ impl LazyFrame {
         mut self,
         sink_type: SinkDestination,
         file_format: FileWriteFormat,
-        unified_sink_args: UnifiedSinkArgs,
+        mut unified_sink_args: UnifiedSinkArgs,
     ) -> ApolloResult<Self> {
         apollo_ensure!(
             !matches!(self.logical_plan, DslPlan::Sink { .. }),
             InvalidOperation: "cannot create a sink on top of another sink"
         );
 
-        self.logical_plan = DslPlan::Sink {
-            input: Arc::new(self.logical_plan),
-            payload: match sink_type {
-                SinkDestination::File { target } => SinkType::File(FileSinkOptions {
-                    target,
-                    file_format,
-                    unified_sink_args,
-                }),
-                SinkDestination::Partitioned {
-                    base_path,
-                    file_path_provider,
-                    partition_strategy,
-                    max_rows_per_file,
-                    approximate_bytes_per_file,
-                } => SinkType::Partitioned(PartitionedSinkOptions {
+        let payload = match sink_type {
+            SinkDestination::File { target } => SinkType::File(FileSinkOptions {
+                target,
+                file_format,
+                unified_sink_args,
+            }),
+            SinkDestination::Partitioned {
+                base_path,
+                file_path_provider,
+                partition_strategy,
+                per_partition_sort_by,
+                max_rows_per_file,
+                approximate_bytes_per_file,
+                manifest_path,
+            } => {
+                if let Some((by, sort_options)) = per_partition_sort_by {
+                    self = self.sort_for_partitioned_sink(&partition_strategy, by, sort_options)?;
+                    unified_sink_args.maintain_order = true;
+                }
+
+                SinkType::Partitioned(PartitionedSinkOptions {
                     base_path,
                     file_path_provider,
                     partition_strategy,
@@ -1010,12 +1052,54 @@ impl LazyFrame {
                     unified_sink_args,
                     max_rows_per_file,
                     approximate_bytes_per_file,
-                }),
+                    manifest_path,
+                })
             },
         };
+
+        self.logical_plan = DslPlan::Sink {
+            input: Arc::new(self.logical_plan),
+            payload,
+        };
         Ok(self)
     }
 
+    /// Sorts the input of a partitioned sink such that the rows of every partition arrive sorted
+    /// by `by`.
+    ///
+    /// Keyed partitions are additionally sorted by their keys first, so that every partition is
+    /// written in one go rather than having many files open at once.
+    fn sort_for_partitioned_sink(
+        self,
+        partition_strategy: &PartitionStrategy,
+        by: Vec<Expr>,
+        mut sort_options: SortMultipleOptions,
+    ) -> ApolloResult<Self> {
+        apollo_ensure!(
+            !by.is_empty(),
+            InvalidOperation: "per-partition sort requires at least one column to sort by"
+        );
+
+        let PartitionStrategy::Keyed { keys, .. } = partition_strategy else {
+            return Ok(self.sort_by_exprs(by, sort_options.with_maintain_order(true)));
+        };
+
+        for flags in [&mut sort_options.descending, &mut sort_options.nulls_last] {
+            if flags.len() == 1 {
+                *flags = vec![flags[0]; by.len()];
+            }
+            apollo_ensure!(
+                flags.len() == by.len(),
+                InvalidOperation: "the length of `descending` and `nulls_last` must match the length of `by`"
+            );
+            flags.splice(0..0, std::iter::repeat_n(false, keys.len()));
+        }
+
+        let by = keys.iter().cloned().chain(by).collect::<Vec<_>>();
+
+        Ok(self.sort_by_exprs(by, sort_options.with_maintain_order(true)))
+    }
+
     /// Filter frame rows that match a predicate expression.
     ///
     /// The expression must yield boolean values (note that rows where the
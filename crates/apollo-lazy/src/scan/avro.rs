@@ -0,0 +1,26 @@
+use apollo_buffer::Buffer;
+use apollo_core::prelude::*;
+use apollo_utils::pl_path::PlRefPath;
+
+use crate::prelude::*;
+
+impl LazyFrame {
+    /// Create a LazyFrame directly from an avro scan.
+    pub fn scan_avro(path: PlRefPath, unified_scan_args: UnifiedScanArgs) -> ApolloResult<Self> {
+        Self::scan_avro_sources(
+            ScanSources::Paths(Buffer::from_iter([path])),
+            unified_scan_args,
+        )
+    }
+
+    pub fn scan_avro_sources(
+        sources: ScanSources,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> ApolloResult<Self> {
+        let lf = DslBuilder::scan_avro(sources, unified_scan_args)?
+            .build()
+            .into();
+
+        Ok(lf)
+    }
+}
@@ -1,10 +1,12 @@
+use std::borrow::Cow;
+
 #[cfg(feature = "csv")]
 use apollo_buffer::Buffer;
 use apollo_core::prelude::*;
 use apollo_io::cloud::CloudOptions;
 use apollo_io::csv::read::{
     CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues,
-    read_until_start_and_infer_schema,
+    read_until_start_and_infer_schema, sniff_dialect,
 };
 use apollo_io::path_utils::expand_paths;
 use apollo_io::utils::compression::CompressedReader;
@@ -101,6 +103,14 @@ impl LazyCsvReader {
         self
     }
 
+    /// Write rows that fail to parse to a CSV file at `reject_rows` and leave them out of the
+    /// result, instead of raising an error.
+    #[must_use]
+    pub fn with_reject_rows(mut self, reject_rows: Option<PlRefPath>) -> Self {
+        self.read_options.reject_rows = reject_rows;
+        self
+    }
+
     /// Set the CSV file's schema
     #[must_use]
     pub fn with_schema(mut self, schema: Option<SchemaRef>) -> Self {
@@ -116,6 +126,14 @@ impl LazyCsvReader {
         self
     }
 
+    /// Detect the separator, quote char, header and preamble lines from the first `sample_lines`
+    /// lines of the first file, overriding the configured values.
+    #[must_use]
+    pub fn with_sniff_dialect(mut self, sample_lines: Option<usize>) -> Self {
+        self.read_options.sniff_dialect = sample_lines;
+        self
+    }
+
     /// Skip the first `n` lines during parsing. The header will be parsed at line `n`.
     /// We don't respect CSV escaping when skipping lines.
     #[must_use]
@@ -256,10 +274,19 @@ impl LazyCsvReader {
         let n_threads = self.read_options.n_threads;
 
         let infer_schema = |bytes: Buffer<u8>| {
-            let mut reader = CompressedReader::try_new(bytes)?;
+            let encoding = self.read_options.parse_options.encoding;
+            let mut read_options = Cow::Borrowed(&self.read_options);
+
+            if let Some(sample_lines) = self.read_options.sniff_dialect {
+                let mut reader = CompressedReader::try_new_with_encoding(bytes.clone(), encoding)?;
+                sniff_dialect(&mut reader, &self.read_options, sample_lines)?
+                    .apply(read_options.to_mut())?;
+            }
+
+            let mut reader = CompressedReader::try_new_with_encoding(bytes, encoding)?;
 
             let (inferred_schema, _) =
-                read_until_start_and_infer_schema(&self.read_options, None, None, &mut reader)?;
+                read_until_start_and_infer_schema(&read_options, None, None, &mut reader)?;
 
             ApolloResult::Ok(inferred_schema)
         };
@@ -1,4 +1,6 @@
 pub(super) mod anonymous_scan;
+#[cfg(feature = "avro")]
+pub(super) mod avro;
 #[cfg(feature = "csv")]
 pub(super) mod csv;
 pub(super) mod file_list_reader;
@@ -6,6 +8,8 @@ pub(super) mod file_list_reader;
 pub(super) mod ipc;
 #[cfg(feature = "json")]
 pub(super) mod ndjson;
+#[cfg(feature = "orc")]
+pub(super) mod orc;
 #[cfg(feature = "parquet")]
 pub(super) mod parquet;
 
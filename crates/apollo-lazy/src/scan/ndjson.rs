@@ -26,6 +26,7 @@ pub struct LazyJsonLineReader {
     pub(crate) infer_schema_length: Option<NonZeroUsize>,
     pub(crate) n_rows: Option<usize>,
     pub(crate) ignore_errors: bool,
+    pub(crate) reject_rows: Option<PlRefPath>,
     pub(crate) include_file_paths: Option<PlSmallStr>,
     pub(crate) cloud_options: Option<CloudOptions>,
 }
@@ -46,6 +47,7 @@ impl LazyJsonLineReader {
             row_index: None,
             infer_schema_length: NonZeroUsize::new(100),
             ignore_errors: false,
+            reject_rows: None,
             n_rows: None,
             include_file_paths: None,
             cloud_options: None,
@@ -69,6 +71,15 @@ impl LazyJsonLineReader {
         self.ignore_errors = ignore_errors;
         self
     }
+
+    /// Write lines that fail to parse to a CSV file at `reject_rows` and leave them out of the
+    /// result, instead of raising an error.
+    #[must_use]
+    pub fn with_reject_rows(mut self, reject_rows: Option<PlRefPath>) -> Self {
+        self.reject_rows = reject_rows;
+        self
+    }
+
     /// Try to stop parsing when `n` rows are parsed. During multithreaded parsing the upper bound `n` cannot
     /// be guaranteed.
     #[must_use]
@@ -155,6 +166,7 @@ impl LazyFileListReader for LazyJsonLineReader {
             ignore_errors: self.ignore_errors,
             schema: self.schema,
             schema_overwrite: self.schema_overwrite,
+            reject_rows: self.reject_rows,
         };
 
         let scan_type = Box::new(FileScanDsl::NDJson { options });
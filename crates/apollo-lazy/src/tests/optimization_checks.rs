@@ -650,3 +650,85 @@ fn test_cluster_with_columns_chain() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_join_reorder() -> ApolloResult<()> {
+    let dir = tempfile::tempdir()?;
+
+    let ids: Vec<i64> = (0..1000).collect();
+    let mut fact = df![
+        "big_id" => &ids,
+        "small_id" => ids.iter().map(|i| i % 100).collect::<Vec<_>>(),
+    ]?;
+    let mut big = df![
+        "big_id" => &ids,
+        "big_val" => ids.iter().map(|i| i * 2).collect::<Vec<_>>(),
+    ]?;
+    let mut small = df![
+        "small_id" => [0i64, 1],
+        "small_val" => ["a", "b"],
+    ]?;
+
+    let scan = |name: &str, df: &mut DataFrame| -> ApolloResult<LazyFrame> {
+        let path = dir.path().join(format!("{name}.parquet"));
+        ParquetWriter::new(std::fs::File::create(&path)?)
+            .with_statistics(StatisticsOptions::full())
+            .finish(df)?;
+        LazyFrame::scan_parquet(
+            PlRefPath::new(path.to_str().unwrap()),
+            ScanArgsParquet::default(),
+        )
+    };
+    let q = scan("fact", &mut fact)?
+        .join(
+            scan("big", &mut big)?,
+            [col("big_id")],
+            [col("big_id")],
+            JoinType::Inner.into(),
+        )
+        .join(
+            scan("small", &mut small)?,
+            [col("small_id")],
+            [col("small_id")],
+            JoinType::Inner.into(),
+        );
+
+    // The selective join with `small` should be done first.
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q
+        .clone()
+        .with_join_reorder(true)
+        .optimize(&mut lp_arena, &mut expr_arena)?;
+    let innermost = lp_arena
+        .iter(lp)
+        .filter_map(|(_, ir)| match ir {
+            IR::Join {
+                input_left,
+                input_right,
+                ..
+            } => Some([*input_left, *input_right]),
+            _ => None,
+        })
+        .find(|inputs| {
+            inputs
+                .iter()
+                .all(|n| !matches!(lp_arena.get(*n), IR::Join { .. }))
+        })
+        .unwrap();
+    assert!(
+        innermost
+            .iter()
+            .any(|n| lp_arena.get(*n).schema(&lp_arena).contains("small_val"))
+    );
+
+    let expected = q.clone().collect()?.sort(["big_id"], Default::default())?;
+    let out = q
+        .with_join_reorder(true)
+        .collect()?
+        .sort(["big_id"], Default::default())?;
+    assert_eq!(out.height(), 20);
+    assert!(out.equals(&expected));
+
+    Ok(())
+}
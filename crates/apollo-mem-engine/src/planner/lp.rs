@@ -387,6 +387,8 @@ fn create_physical_plan_impl(
                     not(any(
                         feature = "parquet",
                         feature = "ipc",
+                        feature = "avro",
+                        feature = "orc",
                         feature = "csv",
                         feature = "json",
                         feature = "scan_lines"
@@ -474,6 +474,12 @@ where
                 metadata,
             } => *metadata = None,
 
+            #[cfg(feature = "avro")]
+            FileScanIR::Avro => {},
+
+            #[cfg(feature = "orc")]
+            FileScanIR::Orc => {},
+
             #[cfg(feature = "csv")]
             FileScanIR::Csv { options: _ } => {},
 
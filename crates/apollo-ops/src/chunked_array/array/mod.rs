@@ -9,11 +9,15 @@ mod namespace;
 mod sum_mean;
 #[cfg(feature = "array_to_struct")]
 mod to_struct;
+#[cfg(feature = "array_vector")]
+mod vector;
 
 pub use namespace::ArrayNameSpace;
 use apollo_core::prelude::*;
 #[cfg(feature = "array_to_struct")]
 pub use to_struct::*;
+#[cfg(feature = "array_vector")]
+pub use vector::{VectorMetric, vector_float_dtype};
 
 pub trait AsArray {
     fn as_array(&self) -> &ArrayChunked;
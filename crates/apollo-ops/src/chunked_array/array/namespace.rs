@@ -10,6 +10,8 @@ use super::*;
 use crate::chunked_array::array::count::array_count_matches;
 use crate::chunked_array::array::count::count_boolean_bits;
 use crate::chunked_array::array::sum_mean::sum_with_nulls;
+#[cfg(feature = "array_vector")]
+use crate::chunked_array::array::vector::{VectorMetric, array_normalize, array_vector_metric};
 #[cfg(feature = "array_any_all")]
 use crate::prelude::array::any_all::{array_all, array_any};
 use crate::prelude::array::get::array_get;
@@ -149,6 +151,24 @@ pub trait ArrayNameSpace: AsArray {
         array_count_matches(ca, element)
     }
 
+    /// Compute `metric` between the vectors and those of `other`.
+    #[cfg(feature = "array_vector")]
+    fn array_vector_metric(
+        &self,
+        other: &ArrayChunked,
+        metric: VectorMetric,
+    ) -> ApolloResult<Series> {
+        let ca = self.as_array();
+        array_vector_metric(ca, other, metric)
+    }
+
+    /// Scale the vectors to unit length.
+    #[cfg(feature = "array_vector")]
+    fn array_normalize(&self) -> ApolloResult<ArrayChunked> {
+        let ca = self.as_array();
+        array_normalize(ca)
+    }
+
     fn array_shift(&self, n: &Series) -> ApolloResult<Series> {
         let ca = self.as_array();
         let n_s = n.cast(&DataType::Int64)?;
@@ -0,0 +1,284 @@
+//! Vector operations between the rows of numeric arrays, e.g. to compare embeddings.
+//!
+//! The arrays are treated as vectors of floats: `Float32` arrays are computed in `f32` and all
+//! other numeric arrays in `f64`. Rows that are null or contain a null value give null.
+use arrow::array::{Array, FixedSizeListArray, PrimitiveArray};
+use arrow::bitmap::{Bitmap, BitmapBuilder};
+use arrow::compute::utils::combine_validities_and;
+use arrow::types::NativeType;
+use num_traits::Float;
+
+use super::*;
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum VectorMetric {
+    /// The dot product.
+    Dot,
+    /// The cosine of the angle between the vectors.
+    Cosine,
+    /// The L2 distance.
+    Euclidean,
+    /// The L1 distance.
+    Manhattan,
+}
+
+impl VectorMetric {
+    /// Whether a larger value means that the vectors are closer.
+    pub fn is_similarity(self) -> bool {
+        matches!(self, Self::Dot | Self::Cosine)
+    }
+}
+
+/// The float type the vector operations on arrays of `inner_dtype` are computed in.
+pub fn vector_float_dtype(inner_dtype: &DataType) -> DataType {
+    match inner_dtype {
+        DataType::Float32 => DataType::Float32,
+        _ => DataType::Float64,
+    }
+}
+
+/// Sum `f` over the pairs of `a` and `b`, with independent accumulators so that the loop can be
+/// vectorized.
+#[inline(always)]
+fn sum_pairs<T: Float>(a: &[T], b: &[T], f: impl Fn(T, T) -> T) -> T {
+    const LANES: usize = 8;
+
+    let a_chunks = a.chunks_exact(LANES);
+    let b_chunks = b.chunks_exact(LANES);
+    let tail = a_chunks
+        .remainder()
+        .iter()
+        .zip(b_chunks.remainder())
+        .fold(T::zero(), |acc, (x, y)| acc + f(*x, *y));
+
+    let mut acc = [T::zero(); LANES];
+    for (x, y) in a_chunks.zip(b_chunks) {
+        for i in 0..LANES {
+            acc[i] = acc[i] + f(x[i], y[i]);
+        }
+    }
+    acc.into_iter().fold(tail, |acc, x| acc + x)
+}
+
+fn dot<T: Float>(a: &[T], b: &[T]) -> T {
+    sum_pairs(a, b, |x, y| x * y)
+}
+
+fn metric_kernel<T: Float>(metric: VectorMetric) -> fn(&[T], &[T]) -> T {
+    match metric {
+        VectorMetric::Dot => dot,
+        VectorMetric::Cosine => |a, b| dot(a, b) / (dot(a, a) * dot(b, b)).sqrt(),
+        VectorMetric::Euclidean => |a, b| sum_pairs(a, b, |x, y| (x - y) * (x - y)).sqrt(),
+        VectorMetric::Manhattan => |a, b| sum_pairs(a, b, |x, y| (x - y).abs()),
+    }
+}
+
+/// Cast `ca` to a single chunk of vectors of `dtype`.
+fn float_vectors(ca: &ArrayChunked, dtype: &DataType) -> ApolloResult<ArrayChunked> {
+    let inner = ca.inner_dtype();
+    apollo_ensure!(
+        inner.is_primitive_numeric(),
+        InvalidOperation: "vector operations require a numeric array, got {}", ca.dtype()
+    );
+    let out = ca.cast(&DataType::Array(Box::new(dtype.clone()), ca.width()))?;
+    Ok(out.array()?.rechunk().into_owned())
+}
+
+/// The validity of the rows of `arr`, in which rows containing a null value are null.
+fn row_validity(arr: &FixedSizeListArray) -> Option<Bitmap> {
+    let values = arr.values();
+    if values.null_count() == 0 {
+        return arr.validity().cloned();
+    }
+    let inner = values.validity().unwrap();
+    let width = arr.size();
+    let mut complete = BitmapBuilder::with_capacity(arr.len());
+    for i in 0..arr.len() {
+        complete.push(inner.null_count_range(i * width, width) == 0);
+    }
+    combine_validities_and(arr.validity(), complete.into_opt_validity().as_ref())
+}
+
+/// The validity of a vector operand broadcast to `len` rows.
+fn broadcast_validity(arr: &FixedSizeListArray, len: usize) -> Option<Bitmap> {
+    let validity = row_validity(arr);
+    if arr.len() != 1 || arr.len() == len {
+        return validity;
+    }
+    match validity {
+        Some(validity) if !validity.get_bit(0) => Some(Bitmap::new_zeroed(len)),
+        _ => None,
+    }
+}
+
+fn float_values<T: NativeType>(arr: &FixedSizeListArray) -> &[T] {
+    let values = arr.values().as_any();
+    values.downcast_ref::<PrimitiveArray<T>>().unwrap().values()
+}
+
+fn metric_impl<T>(lhs: &ArrayChunked, rhs: &ArrayChunked, metric: VectorMetric) -> ChunkedArray<T>
+where
+    T: ApolloFloatType,
+    T::Native: Float,
+{
+    let width = lhs.width();
+    let len = if lhs.len() == 1 { rhs.len() } else { lhs.len() };
+    let (lhs_arr, rhs_arr) = (lhs.downcast_as_array(), rhs.downcast_as_array());
+    let (lhs_values, rhs_values) = (float_values(lhs_arr), float_values(rhs_arr));
+    // The `i`-th vector of an operand with `n` rows, a single row is broadcast.
+    fn row<T>(values: &[T], width: usize, n: usize, i: usize) -> &[T] {
+        let i = if n == 1 { 0 } else { i };
+        &values[i * width..(i + 1) * width]
+    }
+
+    let kernel = metric_kernel::<T::Native>(metric);
+    let values = (0..len)
+        .map(|i| {
+            kernel(
+                row(lhs_values, width, lhs.len(), i),
+                row(rhs_values, width, rhs.len(), i),
+            )
+        })
+        .collect::<Vec<_>>();
+    let validity = combine_validities_and(
+        broadcast_validity(lhs_arr, len).as_ref(),
+        broadcast_validity(rhs_arr, len).as_ref(),
+    );
+    let arr = PrimitiveArray::from_vec(values).with_validity(validity);
+    ChunkedArray::with_chunk(lhs.name().clone(), arr)
+}
+
+/// Compute `metric` between the vectors in `lhs` and `rhs`, either of which may have a single
+/// row that is compared with all rows of the other.
+///
+/// `rhs` is cast to the float type of `lhs`, see [`vector_float_dtype`].
+pub fn array_vector_metric(
+    lhs: &ArrayChunked,
+    rhs: &ArrayChunked,
+    metric: VectorMetric,
+) -> ApolloResult<Series> {
+    apollo_ensure!(
+        lhs.width() == rhs.width(),
+        ShapeMismatch: "vectors must have the same width, got {} and {}", lhs.width(), rhs.width()
+    );
+    apollo_ensure!(
+        lhs.len() == rhs.len() || lhs.len() == 1 || rhs.len() == 1,
+        ShapeMismatch: "vector operands must have equal or unit length, got {} and {}",
+        lhs.len(), rhs.len()
+    );
+
+    let dtype = vector_float_dtype(lhs.inner_dtype());
+    let lhs = float_vectors(lhs, &dtype)?;
+    let rhs = float_vectors(rhs, &dtype)?;
+    Ok(match dtype {
+        DataType::Float32 => metric_impl::<Float32Type>(&lhs, &rhs, metric).into_series(),
+        _ => metric_impl::<Float64Type>(&lhs, &rhs, metric).into_series(),
+    })
+}
+
+fn normalize_impl<T>(ca: &ArrayChunked) -> ArrayChunked
+where
+    T: ApolloFloatType,
+    T::Native: Float,
+{
+    let arr = ca.downcast_as_array();
+    let width = arr.size();
+    let values = float_values::<T::Native>(arr);
+
+    let mut out = Vec::with_capacity(values.len());
+    for v in values.chunks_exact(width.max(1)) {
+        let norm = dot(v, v).sqrt();
+        out.extend(v.iter().map(|x| *x / norm));
+    }
+    let values = PrimitiveArray::from_vec(out).boxed();
+    let dtype = FixedSizeListArray::default_datatype(values.dtype().clone(), width);
+    let arr = FixedSizeListArray::new(dtype, arr.len(), values, row_validity(arr));
+    ArrayChunked::with_chunk(ca.name().clone(), arr)
+}
+
+/// Scale the vectors in `ca` to unit length, zero vectors give NaN.
+pub fn array_normalize(ca: &ArrayChunked) -> ApolloResult<ArrayChunked> {
+    let dtype = vector_float_dtype(ca.inner_dtype());
+    let ca = float_vectors(ca, &dtype)?;
+    Ok(match dtype {
+        DataType::Float32 => normalize_impl::<Float32Type>(&ca),
+        _ => normalize_impl::<Float64Type>(&ca),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn vectors(values: &[f64], width: usize, validity: Option<&[bool]>) -> ArrayChunked {
+        let values = PrimitiveArray::from_slice(values).boxed();
+        let dtype = FixedSizeListArray::default_datatype(values.dtype().clone(), width);
+        let len = values.len() / width;
+        let validity = validity.map(|v| v.iter().copied().collect());
+        let arr = FixedSizeListArray::new(dtype, len, values, validity);
+        ArrayChunked::with_chunk("a".into(), arr)
+    }
+
+    fn metric(lhs: &ArrayChunked, rhs: &ArrayChunked, metric: VectorMetric) -> Vec<Option<f64>> {
+        let out = array_vector_metric(lhs, rhs, metric).unwrap();
+        Vec::from(out.f64().unwrap())
+    }
+
+    #[test]
+    fn test_kernels_match_naive() {
+        let a = (0..19).map(|i| i as f64 * 0.5 - 3.0).collect::<Vec<_>>();
+        let b = (0..19).map(|i| (i * i) as f64 / 7.0).collect::<Vec<_>>();
+        let naive_dot = a.iter().zip(&b).map(|(x, y)| x * y).sum::<f64>();
+        let naive_l1 = a.iter().zip(&b).map(|(x, y)| (x - y).abs()).sum::<f64>();
+
+        let dot_kernel = metric_kernel::<f64>(VectorMetric::Dot);
+        let l1_kernel = metric_kernel::<f64>(VectorMetric::Manhattan);
+        assert!((dot_kernel(&a, &b) - naive_dot).abs() < 1e-9);
+        assert!((l1_kernel(&a, &b) - naive_l1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_vector_metric() {
+        let lhs = vectors(
+            &[3.0, 4.0, 1.0, 0.0, 0.0, 0.0],
+            2,
+            Some(&[true, true, false]),
+        );
+        let rhs = vectors(&[0.0, 0.0], 2, None);
+        assert_eq!(
+            metric(&lhs, &rhs, VectorMetric::Euclidean),
+            &[Some(5.0), Some(1.0), None]
+        );
+        assert_eq!(
+            metric(&lhs, &rhs, VectorMetric::Manhattan),
+            &[Some(7.0), Some(1.0), None]
+        );
+
+        let rhs = vectors(&[1.0, 0.0], 2, None);
+        assert_eq!(
+            metric(&lhs, &rhs, VectorMetric::Cosine),
+            &[Some(0.6), Some(1.0), None]
+        );
+        assert_eq!(
+            metric(&rhs, &lhs, VectorMetric::Dot),
+            &[Some(3.0), Some(1.0), None]
+        );
+
+        let rhs = vectors(&[1.0, 2.0, 3.0], 3, None);
+        assert!(array_vector_metric(&lhs, &rhs, VectorMetric::Dot).is_err());
+    }
+
+    #[test]
+    fn test_normalize() {
+        let ca = vectors(&[3.0, 4.0, 0.0, 2.0], 2, Some(&[true, false]));
+        let out = array_normalize(&ca).unwrap();
+        assert_eq!(out.null_count(), 1);
+        let values = out.get_inner();
+        assert_eq!(
+            Vec::from(values.f64().unwrap())[..2],
+            [Some(0.6), Some(0.8)]
+        );
+    }
+}
@@ -0,0 +1,130 @@
+//! Stable digests of binary values.
+//!
+//! Unlike the hashes used internally, which may change between versions, these are fixed by their
+//! specification and give the same output on every platform and version.
+use apollo_core::prelude::arity::unary_elementwise_values;
+use apollo_core::prelude::*;
+use sha2::Digest;
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum HashAlgorithm {
+    Md5,
+    Sha1,
+    Sha256,
+    /// XXH64 with seed 0, in big-endian byte order.
+    XxHash64,
+    /// MurmurHash3 x86_32 with seed 0, in big-endian byte order.
+    Murmur3_32,
+}
+
+impl HashAlgorithm {
+    /// The number of bytes in a digest.
+    pub fn digest_size(self) -> usize {
+        match self {
+            Self::Md5 => 16,
+            Self::Sha1 => 20,
+            Self::Sha256 => 32,
+            Self::XxHash64 => 8,
+            Self::Murmur3_32 => 4,
+        }
+    }
+
+    fn digest(self, value: &[u8]) -> Vec<u8> {
+        match self {
+            Self::Md5 => md5::Md5::digest(value).to_vec(),
+            Self::Sha1 => sha1::Sha1::digest(value).to_vec(),
+            Self::Sha256 => sha2::Sha256::digest(value).to_vec(),
+            Self::XxHash64 => xxhash_rust::xxh64::xxh64(value, 0).to_be_bytes().to_vec(),
+            Self::Murmur3_32 => murmur3_32(value, 0).to_be_bytes().to_vec(),
+        }
+    }
+}
+
+fn murmur3_32(data: &[u8], seed: u32) -> u32 {
+    const C1: u32 = 0xcc9e2d51;
+    const C2: u32 = 0x1b873593;
+
+    let scramble = |k: u32| k.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+
+    let mut h = seed;
+    let mut blocks = data.chunks_exact(4);
+    for block in &mut blocks {
+        h ^= scramble(u32::from_le_bytes(block.try_into().unwrap()));
+        h = h.rotate_left(13).wrapping_mul(5).wrapping_add(0xe6546b64);
+    }
+    let tail = blocks.remainder();
+    if !tail.is_empty() {
+        let k = tail.iter().rev().fold(0u32, |k, b| (k << 8) | *b as u32);
+        h ^= scramble(k);
+    }
+
+    // The length is mixed in modulo 2^32, as in the reference implementation.
+    h ^= data.len() as u32;
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85ebca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2ae35);
+    h ^ (h >> 16)
+}
+
+/// The digests of the values in `ca`.
+pub fn digest(ca: &BinaryChunked, algorithm: HashAlgorithm) -> BinaryChunked {
+    unary_elementwise_values(ca, |v: &[u8]| algorithm.digest(v))
+}
+
+/// The digests of the values in `ca`, as lowercase hexadecimal strings.
+pub fn digest_hex(ca: &BinaryChunked, algorithm: HashAlgorithm) -> StringChunked {
+    unary_elementwise_values(ca, |v: &[u8]| hex::encode(algorithm.digest(v)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn hex_digest(algorithm: HashAlgorithm, value: &str) -> String {
+        hex::encode(algorithm.digest(value.as_bytes()))
+    }
+
+    #[test]
+    fn test_known_digests() {
+        use HashAlgorithm::*;
+        assert_eq!(hex_digest(Md5, ""), "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(hex_digest(Md5, "abc"), "900150983cd24fb0d6963f7d28e17f72");
+        assert_eq!(
+            hex_digest(Sha1, "abc"),
+            "a9993e364706816aba3e25717850c26c9cd0d89d"
+        );
+        assert_eq!(
+            hex_digest(Sha256, "abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(hex_digest(XxHash64, ""), "ef46db3751d8e999");
+        assert_eq!(hex_digest(XxHash64, "abc"), "44bc2cf5ad770999");
+        assert_eq!(hex_digest(Murmur3_32, ""), "00000000");
+        assert_eq!(hex_digest(Murmur3_32, "test"), "ba6bd213");
+        assert_eq!(hex_digest(Murmur3_32, "Hello, world!"), "c0363e43");
+
+        for algorithm in [Md5, Sha1, Sha256, XxHash64, Murmur3_32] {
+            assert_eq!(algorithm.digest(b"abc").len(), algorithm.digest_size());
+        }
+    }
+
+    #[test]
+    fn test_murmur3_seed() {
+        assert_eq!(murmur3_32(b"", 1), 0x514e28b7);
+        assert_eq!(murmur3_32(b"", 0xffffffff), 0x81f16f39);
+    }
+
+    #[test]
+    fn test_digest_nulls() {
+        let ca = BinaryChunked::from_slice_options("a".into(), &[Some(b"abc".as_slice()), None]);
+        let out = digest_hex(&ca, HashAlgorithm::Md5);
+        assert_eq!(
+            Vec::from(&out),
+            &[Some("900150983cd24fb0d6963f7d28e17f72"), None]
+        );
+        assert_eq!(digest(&ca, HashAlgorithm::Murmur3_32).null_count(), 1);
+    }
+}
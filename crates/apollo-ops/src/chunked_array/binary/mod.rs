@@ -1,6 +1,10 @@
+#[cfg(feature = "hash_digest")]
+mod digest;
 mod namespace;
 mod slice;
 
+#[cfg(feature = "hash_digest")]
+pub use digest::HashAlgorithm;
 pub use namespace::*;
 use apollo_core::prelude::*;
 
@@ -188,6 +188,18 @@ pub trait BinaryNameSpaceImpl: AsBinary {
         }
     }
 
+    /// Stable digest of the binary values.
+    #[cfg(feature = "hash_digest")]
+    fn bin_digest(&self, algorithm: HashAlgorithm) -> BinaryChunked {
+        super::digest::digest(self.as_binary(), algorithm)
+    }
+
+    /// Stable digest of the binary values, as lowercase hexadecimal strings.
+    #[cfg(feature = "hash_digest")]
+    fn bin_digest_hex(&self, algorithm: HashAlgorithm) -> StringChunked {
+        super::digest::digest_hex(self.as_binary(), algorithm)
+    }
+
     #[cfg(feature = "binary_encoding")]
     fn reinterpret(&self, dtype: &DataType, is_little_endian: bool) -> ApolloResult<Series> {
         unsafe {
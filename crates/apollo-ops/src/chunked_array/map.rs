@@ -0,0 +1,239 @@
+use std::ops::Range;
+
+use arrow::array::{Array, LargeListArray};
+use arrow::bitmap::Bitmap;
+use arrow::offset::Offsets;
+use apollo_core::prelude::*;
+use apollo_utils::pl_str::PlSmallStr;
+
+pub trait AsMap {
+    fn as_map(&self) -> &MapChunked;
+}
+
+impl AsMap for MapChunked {
+    fn as_map(&self) -> &MapChunked {
+        self
+    }
+}
+
+/// The flat keys and values of the entries that are referred to by the rows of a map column.
+struct FlatEntries {
+    storage: ListChunked,
+    keys: Series,
+    values: Series,
+}
+
+impl FlatEntries {
+    fn new(ca: &MapChunked) -> Self {
+        let storage = ca.entries().rechunk().into_owned();
+        let offsets = storage.downcast_as_array().offsets();
+        let start = *offsets.first();
+        let length = offsets.range() as usize;
+
+        let entries = storage.get_inner().slice(start, length);
+        let mut fields = entries.struct_().unwrap().fields_as_series().into_iter();
+        let keys = fields.next().unwrap();
+        let values = fields.next().unwrap();
+        Self {
+            storage,
+            keys,
+            values,
+        }
+    }
+
+    /// The range of the entries of every row, relative to the flat keys and values, and whether
+    /// the row is valid.
+    fn row_ranges(&self) -> impl Iterator<Item = (Range<usize>, bool)> + '_ {
+        let arr = self.storage.downcast_as_array();
+        let offsets = arr.offsets();
+        let start = *offsets.first() as usize;
+        offsets
+            .offset_and_length_iter()
+            .enumerate()
+            .map(move |(i, (offset, length))| {
+                (offset - start..offset - start + length, arr.is_valid(i))
+            })
+    }
+}
+
+/// For each map, find the index of the last entry whose key equals the key of that row in `key`.
+/// The outer `None` marks a `null` map or a `null` key.
+fn find_entries(
+    ca: &MapChunked,
+    key: &Series,
+) -> ApolloResult<(FlatEntries, Vec<Option<Option<IdxSize>>>)> {
+    apollo_ensure!(
+        key.len() == 1 || key.len() == ca.len(),
+        length_mismatch = "map.get",
+        ca.len(),
+        key.len()
+    );
+    let key = key.strict_cast(ca.key_dtype())?;
+    let flat = FlatEntries::new(ca);
+
+    // Broadcast the lookup key to all the entries of its row.
+    let key_per_entry = if key.len() == 1 {
+        key.new_from_index(0, flat.keys.len())
+    } else {
+        let row_idx: Vec<IdxSize> = flat
+            .row_ranges()
+            .enumerate()
+            .flat_map(|(i, (range, _))| std::iter::repeat_n(i as IdxSize, range.len()))
+            .collect();
+        key.take_slice(&row_idx)?
+    };
+
+    let is_match = flat.keys.equal(&key_per_entry)?.rechunk().into_owned();
+    let is_match = is_match.downcast_as_array();
+    let key_is_valid = key.is_not_null().rechunk().into_owned();
+    let key_is_valid = key_is_valid.downcast_as_array();
+
+    let found = flat
+        .row_ranges()
+        .enumerate()
+        .map(|(i, (mut range, is_valid))| {
+            let key_idx = if key.len() == 1 { 0 } else { i };
+            (is_valid && key_is_valid.value(key_idx)).then(|| {
+                range
+                    .rfind(|&j| is_match.is_valid(j) && is_match.value(j))
+                    .map(|j| j as IdxSize)
+            })
+        })
+        .collect();
+    Ok((flat, found))
+}
+
+pub trait MapNameSpaceImpl: AsMap {
+    /// The keys of every map as a list.
+    fn map_keys(&self) -> ApolloResult<ListChunked> {
+        let ca = self.as_map();
+        ca.entries()
+            .apply_to_inner(&|s| Ok(s.struct_()?.fields_as_series()[0].clone()))
+    }
+
+    /// The values of every map as a list.
+    fn map_values(&self) -> ApolloResult<ListChunked> {
+        let ca = self.as_map();
+        ca.entries()
+            .apply_to_inner(&|s| Ok(s.struct_()?.fields_as_series()[1].clone()))
+    }
+
+    /// Look up `key` in every map, `key` is either a single key or one key per map.
+    ///
+    /// Missing keys give `null`. If a map holds the same key multiple times, the last entry wins.
+    fn map_get(&self, key: &Series) -> ApolloResult<Series> {
+        let ca = self.as_map();
+        let (flat, found) = find_entries(ca, key)?;
+        let idx =
+            IdxCa::from_iter_options(PlSmallStr::EMPTY, found.into_iter().map(Option::flatten));
+        let mut out = flat.values.take(&idx)?;
+        out.rename(ca.name().clone());
+        Ok(out)
+    }
+
+    /// Whether every map holds `key`, `key` is either a single key or one key per map.
+    fn map_contains_key(&self, key: &Series) -> ApolloResult<BooleanChunked> {
+        let ca = self.as_map();
+        let (_, found) = find_entries(ca, key)?;
+        Ok(BooleanChunked::from_iter_options(
+            ca.name().clone(),
+            found.into_iter().map(|f| f.map(|idx| idx.is_some())),
+        ))
+    }
+
+    /// Look up each of the `names` as a string key and collect the values into a struct with a
+    /// field per name.
+    fn map_to_struct(&self, names: &[PlSmallStr]) -> ApolloResult<StructChunked> {
+        let ca = self.as_map();
+        let fields = names
+            .iter()
+            .map(|name| {
+                let key = Series::new(PlSmallStr::EMPTY, [name.as_str()]);
+                let mut field = self.map_get(&key)?;
+                field.rename(name.clone());
+                Ok(field)
+            })
+            .collect::<ApolloResult<Vec<_>>>()?;
+        let out = StructChunked::from_series(ca.name().clone(), ca.len(), fields.iter())?;
+        let storage = ca.entries().rechunk();
+        Ok(out.with_outer_validity(storage.downcast_as_array().validity().cloned()))
+    }
+}
+
+impl MapNameSpaceImpl for MapChunked {}
+
+/// Zip a list of keys and a list of values into a map.
+///
+/// The lists have to be of equal length in every row and the keys cannot be `null`. A row is
+/// `null` if either of the lists is `null`.
+pub fn map_from_lists(keys: &ListChunked, values: &ListChunked) -> ApolloResult<MapChunked> {
+    apollo_ensure!(
+        keys.len() == values.len(),
+        length_mismatch = "map.from_lists",
+        keys.len(),
+        values.len()
+    );
+    let keys = keys.rechunk();
+    let values = values.rechunk();
+    let key_arr = keys.downcast_as_array();
+    let value_arr = values.downcast_as_array();
+
+    let mut key_idx = Vec::new();
+    let mut value_idx = Vec::new();
+    let mut lengths = Vec::with_capacity(keys.len());
+    let mut validity = Vec::with_capacity(keys.len());
+    let rows = key_arr
+        .offsets()
+        .offset_and_length_iter()
+        .zip(value_arr.offsets().offset_and_length_iter())
+        .enumerate();
+    for (i, ((key_offset, key_length), (value_offset, value_length))) in rows {
+        let is_valid = key_arr.is_valid(i) && value_arr.is_valid(i);
+        validity.push(is_valid);
+        if !is_valid {
+            lengths.push(0);
+            continue;
+        }
+        apollo_ensure!(
+            key_length == value_length,
+            ShapeMismatch: "map.from_lists: row {i} has {key_length} keys but {value_length} values"
+        );
+        key_idx.extend((key_offset..key_offset + key_length).map(|j| j as IdxSize));
+        value_idx.extend((value_offset..value_offset + value_length).map(|j| j as IdxSize));
+        lengths.push(key_length);
+    }
+
+    let mut key = keys
+        .get_inner()
+        .take(&IdxCa::from_vec(PlSmallStr::EMPTY, key_idx))?;
+    apollo_ensure!(
+        !key.has_nulls(),
+        ComputeError: "map.from_lists: map keys cannot be null"
+    );
+    let mut value = values
+        .get_inner()
+        .take(&IdxCa::from_vec(PlSmallStr::EMPTY, value_idx))?;
+    key.rename(MAP_KEY_NAME);
+    value.rename(MAP_VALUE_NAME);
+    let entries = StructChunked::from_series(MAP_ENTRIES_NAME, key.len(), [key, value].iter())?
+        .into_series()
+        .rechunk();
+
+    let validity = Bitmap::from_iter(validity);
+    let validity = (validity.unset_bits() > 0).then_some(validity);
+    let arr = LargeListArray::new(
+        LargeListArray::default_datatype(entries.chunks()[0].dtype().clone()),
+        Offsets::try_from_lengths(lengths.into_iter())?.into(),
+        entries.chunks()[0].clone(),
+        validity,
+    );
+    // SAFETY: the inner dtype is the dtype of the entries array.
+    let storage = unsafe {
+        ListChunked::from_chunks_and_dtype_unchecked(
+            keys.name().clone(),
+            vec![arr.boxed()],
+            DataType::List(Box::new(entries.dtype().clone())),
+        )
+    };
+    Ok(MapChunked::from_storage(storage.into_series()))
+}
@@ -4,6 +4,8 @@ mod binary;
 #[cfg(feature = "timezones")]
 pub mod datetime;
 pub mod list;
+#[cfg(feature = "dtype-map")]
+pub mod map;
 #[cfg(feature = "propagate_nans")]
 pub mod nan_propagating_aggregate;
 #[cfg(feature = "peaks")]
@@ -35,6 +37,8 @@ pub use gather::*;
 #[cfg(feature = "hist")]
 pub use hist::*;
 pub use list::*;
+#[cfg(feature = "dtype-map")]
+pub use map::*;
 #[allow(unused_imports)]
 use apollo_core::prelude::*;
 #[cfg(feature = "repeat_by")]
@@ -0,0 +1,198 @@
+//! Parsing and masking of IPv4 and IPv6 addresses.
+//!
+//! Addresses use the textual representations accepted by [`std::net`]. Values that are not valid
+//! addresses give null rather than an error, so that columns of mixed quality can be processed.
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use apollo_core::prelude::arity::{broadcast_try_binary_elementwise, unary_elementwise};
+use apollo_core::prelude::*;
+
+/// An IP network in CIDR notation, e.g. `192.168.0.0/16`.
+#[derive(Clone, Copy)]
+struct Network {
+    addr: IpAddr,
+    prefix: u32,
+}
+
+impl Network {
+    fn parse(s: &str) -> ApolloResult<Self> {
+        let invalid = || apollo_err!(InvalidOperation: "invalid CIDR network: '{}'", s);
+        let (addr, prefix) = match s.split_once('/') {
+            Some((addr, prefix)) => (addr, Some(prefix)),
+            None => (s, None),
+        };
+        let addr = addr.parse::<IpAddr>().map_err(|_| invalid())?;
+        let max_prefix = max_prefix(addr);
+        let prefix = match prefix {
+            Some(prefix) => prefix
+                .parse::<u32>()
+                .ok()
+                .filter(|p| *p <= max_prefix)
+                .ok_or_else(invalid)?,
+            None => max_prefix,
+        };
+        Ok(Self { addr, prefix })
+    }
+
+    /// Whether `addr` is in this network, addresses of the other family never are.
+    fn contains(&self, addr: IpAddr) -> bool {
+        match (self.addr, addr) {
+            (IpAddr::V4(net), IpAddr::V4(addr)) => {
+                mask_v4(net, self.prefix) == mask_v4(addr, self.prefix)
+            },
+            (IpAddr::V6(net), IpAddr::V6(addr)) => {
+                mask_v6(net, self.prefix) == mask_v6(addr, self.prefix)
+            },
+            _ => false,
+        }
+    }
+}
+
+fn max_prefix(addr: IpAddr) -> u32 {
+    match addr {
+        IpAddr::V4(_) => Ipv4Addr::BITS,
+        IpAddr::V6(_) => Ipv6Addr::BITS,
+    }
+}
+
+/// Keep the first `prefix` bits of `addr`.
+fn mask_v4(addr: Ipv4Addr, prefix: u32) -> Ipv4Addr {
+    let mask = u32::MAX.checked_shl(Ipv4Addr::BITS - prefix).unwrap_or(0);
+    Ipv4Addr::from_bits(addr.to_bits() & mask)
+}
+
+/// Keep the first `prefix` bits of `addr`.
+fn mask_v6(addr: Ipv6Addr, prefix: u32) -> Ipv6Addr {
+    let mask = u128::MAX.checked_shl(Ipv6Addr::BITS - prefix).unwrap_or(0);
+    Ipv6Addr::from_bits(addr.to_bits() & mask)
+}
+
+/// Parse IPv4 addresses into their 32-bit integer value.
+pub fn ipv4_to_int(ca: &StringChunked) -> UInt32Chunked {
+    unary_elementwise(ca, |opt_s: Option<&str>| {
+        opt_s?.parse::<Ipv4Addr>().ok().map(Ipv4Addr::to_bits)
+    })
+}
+
+/// Parse IP addresses into their 128-bit integer value, IPv4 addresses are mapped into the
+/// IPv6 space as `::ffff:a.b.c.d`.
+pub fn ipv6_to_int(ca: &StringChunked) -> UInt128Chunked {
+    unary_elementwise(ca, |opt_s: Option<&str>| {
+        let addr = match opt_s?.parse::<IpAddr>().ok()? {
+            IpAddr::V4(addr) => addr.to_ipv6_mapped(),
+            IpAddr::V6(addr) => addr,
+        };
+        Some(addr.to_bits())
+    })
+}
+
+/// Parse IP addresses into their bytes in network order, 4 for IPv4 and 16 for IPv6.
+pub fn ip_to_binary(ca: &StringChunked) -> BinaryChunked {
+    unary_elementwise(ca, |opt_s: Option<&str>| {
+        Some(match opt_s?.parse::<IpAddr>().ok()? {
+            IpAddr::V4(addr) => addr.octets().to_vec(),
+            IpAddr::V6(addr) => addr.octets().to_vec(),
+        })
+    })
+}
+
+/// Whether the IP addresses in `ca` are in the CIDR networks in `cidr`.
+pub fn ip_in_cidr(ca: &StringChunked, cidr: &StringChunked) -> ApolloResult<BooleanChunked> {
+    if cidr.len() == 1 && ca.len() != 1 {
+        let Some(network) = cidr.get(0).map(Network::parse).transpose()? else {
+            return Ok(BooleanChunked::full_null(ca.name().clone(), ca.len()));
+        };
+        return Ok(unary_elementwise(ca, |opt_s: Option<&str>| {
+            Some(network.contains(opt_s?.parse().ok()?))
+        }));
+    }
+
+    broadcast_try_binary_elementwise(ca, cidr, |opt_s, opt_cidr| {
+        let (Some(s), Some(cidr)) = (opt_s, opt_cidr) else {
+            return Ok(None);
+        };
+        let network = Network::parse(cidr)?;
+        Ok(s.parse().ok().map(|addr| network.contains(addr)))
+    })
+}
+
+/// Mask the IP addresses in `ca` to their network prefix, keeping the first `ipv4_prefix` bits
+/// of IPv4 addresses and the first `ipv6_prefix` bits of IPv6 addresses.
+pub fn ip_mask(
+    ca: &StringChunked,
+    ipv4_prefix: u8,
+    ipv6_prefix: u8,
+) -> ApolloResult<StringChunked> {
+    let (ipv4_prefix, ipv6_prefix) = (ipv4_prefix as u32, ipv6_prefix as u32);
+    apollo_ensure!(
+        ipv4_prefix <= Ipv4Addr::BITS,
+        InvalidOperation: "IPv4 prefix length must be at most 32, got {}", ipv4_prefix
+    );
+    apollo_ensure!(
+        ipv6_prefix <= Ipv6Addr::BITS,
+        InvalidOperation: "IPv6 prefix length must be at most 128, got {}", ipv6_prefix
+    );
+    Ok(unary_elementwise(ca, |opt_s: Option<&str>| {
+        Some(match opt_s?.parse::<IpAddr>().ok()? {
+            IpAddr::V4(addr) => mask_v4(addr, ipv4_prefix).to_string(),
+            IpAddr::V6(addr) => mask_v6(addr, ipv6_prefix).to_string(),
+        })
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_network() {
+        let net = Network::parse("10.1.0.0/16").unwrap();
+        assert!(net.contains("10.1.255.3".parse().unwrap()));
+        assert!(!net.contains("10.2.0.1".parse().unwrap()));
+        assert!(!net.contains("::ffff:10.1.0.1".parse().unwrap()));
+
+        let net = Network::parse("2001:db8::/32").unwrap();
+        assert!(net.contains("2001:db8:1::1".parse().unwrap()));
+        assert!(!net.contains("2001:db9::1".parse().unwrap()));
+
+        assert!(
+            Network::parse("0.0.0.0/0")
+                .unwrap()
+                .contains("1.2.3.4".parse().unwrap())
+        );
+        let host = Network::parse("1.2.3.4").unwrap();
+        assert_eq!(host.prefix, 32);
+
+        for invalid in ["1.2.3.4/33", "1.2.3/8", "::/129", "1.2.3.4/", "1.2.3.4/-1"] {
+            assert!(Network::parse(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_mask() {
+        let addr = "192.168.123.45".parse().unwrap();
+        assert_eq!(mask_v4(addr, 24).to_string(), "192.168.123.0");
+        assert_eq!(mask_v4(addr, 0).to_string(), "0.0.0.0");
+        assert_eq!(mask_v4(addr, 32), addr);
+
+        let addr = "2001:db8:abcd:12::1".parse().unwrap();
+        assert_eq!(mask_v6(addr, 48).to_string(), "2001:db8:abcd::");
+        assert_eq!(mask_v6(addr, 128), addr);
+    }
+
+    #[test]
+    fn test_ip_to_int() {
+        let ca = StringChunked::from_slice_options(
+            "a".into(),
+            &[Some("1.2.3.4"), Some("::1"), Some("nope"), None],
+        );
+        assert_eq!(
+            Vec::from(&ipv4_to_int(&ca)),
+            &[Some(0x01020304), None, None, None]
+        );
+        assert_eq!(
+            Vec::from(&ipv6_to_int(&ca)),
+            &[Some(0xffff_0102_0304), Some(1), None, None]
+        );
+    }
+}
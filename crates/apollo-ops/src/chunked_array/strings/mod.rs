@@ -8,6 +8,8 @@ mod escape_regex;
 mod extract;
 #[cfg(feature = "find_many")]
 mod find_many;
+#[cfg(feature = "string_ip")]
+mod ip;
 #[cfg(feature = "extract_jsonpath")]
 mod json_path;
 #[cfg(feature = "strings")]
@@ -18,6 +20,8 @@ mod normalize;
 mod pad;
 #[cfg(feature = "string_reverse")]
 mod reverse;
+#[cfg(feature = "string_similarity")]
+mod similarity;
 #[cfg(feature = "strings")]
 mod split;
 #[cfg(feature = "strings")]
@@ -26,6 +30,8 @@ mod strip;
 mod substring;
 #[cfg(all(not(feature = "nightly"), feature = "strings"))]
 mod unicode_internals;
+#[cfg(feature = "string_url")]
+mod url;
 
 #[cfg(feature = "strings")]
 pub use concat::*;
@@ -40,12 +46,16 @@ pub use namespace::*;
 #[cfg(feature = "string_normalize")]
 pub use normalize::*;
 use apollo_core::prelude::*;
+#[cfg(feature = "string_similarity")]
+pub use similarity::*;
 #[cfg(feature = "strings")]
 pub use split::*;
 #[cfg(feature = "strings")]
 pub use strip::*;
 #[cfg(feature = "strings")]
 pub use substring::{substring_ternary_offsets_value, update_view};
+#[cfg(feature = "string_url")]
+pub use url::url_fields;
 
 pub trait AsString {
     fn as_string(&self) -> &StringChunked;
@@ -10,8 +10,10 @@ use apollo_core::prelude::arity::*;
 use apollo_utils::regex_cache::{compile_regex, with_regex_cache};
 
 use super::*;
-#[cfg(feature = "binary_encoding")]
+#[cfg(any(feature = "binary_encoding", feature = "hash_digest"))]
 use crate::chunked_array::binary::BinaryNameSpaceImpl;
+#[cfg(feature = "hash_digest")]
+use crate::chunked_array::binary::HashAlgorithm;
 #[cfg(feature = "string_normalize")]
 use crate::prelude::strings::normalize::UnicodeForm;
 
@@ -666,6 +668,116 @@ pub trait StringNameSpaceImpl: AsString {
         reverse::reverse(ca)
     }
 
+    /// Stable digest of the UTF-8 bytes of the string values.
+    #[cfg(feature = "hash_digest")]
+    fn str_digest(&self, algorithm: HashAlgorithm) -> BinaryChunked {
+        let ca = self.as_string();
+        ca.as_binary().bin_digest(algorithm)
+    }
+
+    /// Stable digest of the UTF-8 bytes of the string values, as lowercase hexadecimal strings.
+    #[cfg(feature = "hash_digest")]
+    fn str_digest_hex(&self, algorithm: HashAlgorithm) -> StringChunked {
+        let ca = self.as_string();
+        ca.as_binary().bin_digest_hex(algorithm)
+    }
+
+    /// Levenshtein distance to the values of `other`, capped at `max_distance + 1`.
+    #[cfg(feature = "string_similarity")]
+    fn str_levenshtein_distance(
+        &self,
+        other: &StringChunked,
+        max_distance: Option<u32>,
+    ) -> UInt32Chunked {
+        let ca = self.as_string();
+        similarity::levenshtein_distance(ca, other, max_distance)
+    }
+
+    /// Damerau-Levenshtein distance to the values of `other`, capped at `max_distance + 1`.
+    #[cfg(feature = "string_similarity")]
+    fn str_damerau_levenshtein_distance(
+        &self,
+        other: &StringChunked,
+        max_distance: Option<u32>,
+    ) -> UInt32Chunked {
+        let ca = self.as_string();
+        similarity::damerau_levenshtein_distance(ca, other, max_distance)
+    }
+
+    /// Hamming distance to the values of `other`, null where the lengths differ.
+    #[cfg(feature = "string_similarity")]
+    fn str_hamming_distance(&self, other: &StringChunked) -> UInt32Chunked {
+        let ca = self.as_string();
+        similarity::hamming_distance(ca, other)
+    }
+
+    /// Jaro-Winkler similarity to the values of `other`.
+    #[cfg(feature = "string_similarity")]
+    fn str_jaro_winkler_similarity(&self, other: &StringChunked) -> Float64Chunked {
+        let ca = self.as_string();
+        similarity::jaro_winkler_similarity(ca, other)
+    }
+
+    /// Jaccard similarity of the `q`-grams of the values of `other`.
+    #[cfg(feature = "string_similarity")]
+    fn str_jaccard_similarity(
+        &self,
+        other: &StringChunked,
+        q: usize,
+    ) -> ApolloResult<Float64Chunked> {
+        let ca = self.as_string();
+        similarity::jaccard_similarity(ca, other, q)
+    }
+
+    /// Parse the URLs into a struct of their components, see [`url_fields`].
+    #[cfg(feature = "string_url")]
+    fn str_parse_url(&self) -> ApolloResult<StructChunked> {
+        let ca = self.as_string();
+        url::parse_url(ca)
+    }
+
+    /// The decoded value of the query parameter `name` of the URLs.
+    #[cfg(feature = "string_url")]
+    fn str_url_query_param(&self, name: &str) -> StringChunked {
+        let ca = self.as_string();
+        url::url_query_param(ca, name)
+    }
+
+    /// Parse IPv4 addresses into integers.
+    #[cfg(feature = "string_ip")]
+    fn str_ipv4_to_int(&self) -> UInt32Chunked {
+        let ca = self.as_string();
+        ip::ipv4_to_int(ca)
+    }
+
+    /// Parse IP addresses into IPv6 integers, mapping IPv4 addresses into the IPv6 space.
+    #[cfg(feature = "string_ip")]
+    fn str_ipv6_to_int(&self) -> UInt128Chunked {
+        let ca = self.as_string();
+        ip::ipv6_to_int(ca)
+    }
+
+    /// Parse IP addresses into their bytes in network order.
+    #[cfg(feature = "string_ip")]
+    fn str_ip_to_binary(&self) -> BinaryChunked {
+        let ca = self.as_string();
+        ip::ip_to_binary(ca)
+    }
+
+    /// Whether the IP addresses are in the CIDR networks of `cidr`.
+    #[cfg(feature = "string_ip")]
+    fn str_ip_in_cidr(&self, cidr: &StringChunked) -> ApolloResult<BooleanChunked> {
+        let ca = self.as_string();
+        ip::ip_in_cidr(ca, cidr)
+    }
+
+    /// Mask the IP addresses to the network prefixes of the given lengths.
+    #[cfg(feature = "string_ip")]
+    fn str_ip_mask(&self, ipv4_prefix: u8, ipv6_prefix: u8) -> ApolloResult<StringChunked> {
+        let ca = self.as_string();
+        ip::ip_mask(ca, ipv4_prefix, ipv6_prefix)
+    }
+
     /// Slice the string values.
     ///
     /// Determines a substring starting from `offset` and with length `length` of each of the elements in `array`.
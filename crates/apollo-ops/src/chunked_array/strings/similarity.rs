@@ -0,0 +1,321 @@
+//! Edit distances and similarity scores between strings.
+//!
+//! All kernels compare unicode code points, with a fast path that compares bytes when both
+//! strings are ASCII.
+use std::hash::Hash;
+
+use apollo_core::prelude::arity::broadcast_binary_elementwise;
+use apollo_core::prelude::*;
+
+/// Run `op` on the code points of `a` and `b`, or on their bytes if both are ASCII.
+macro_rules! with_units {
+    ($a:expr, $b:expr, |$ua:ident, $ub:ident| $op:expr) => {{
+        let (a, b): (&str, &str) = ($a, $b);
+        if a.is_ascii() && b.is_ascii() {
+            let ($ua, $ub) = (a.as_bytes(), b.as_bytes());
+            $op
+        } else {
+            let a = a.chars().collect::<Vec<_>>();
+            let b = b.chars().collect::<Vec<_>>();
+            let ($ua, $ub) = (a.as_slice(), b.as_slice());
+            $op
+        }
+    }};
+}
+
+/// Strip the common prefix and suffix, they don't change the edit distance.
+fn strip_common_affixes<'a, T: PartialEq>(a: &'a [T], b: &'a [T]) -> (&'a [T], &'a [T]) {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let (a, b) = (&a[prefix..], &b[prefix..]);
+    let suffix = a
+        .iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    (&a[..a.len() - suffix], &b[..b.len() - suffix])
+}
+
+/// The Levenshtein distance, or `bound + 1` if it is larger than `bound`.
+fn levenshtein_units<T: PartialEq>(a: &[T], b: &[T], bound: usize) -> usize {
+    let (a, b) = strip_common_affixes(a, b);
+    // Keep the shorter string in the row.
+    let (a, b) = if a.len() > b.len() { (b, a) } else { (a, b) };
+    if b.len() - a.len() > bound {
+        return bound + 1;
+    }
+    if a.is_empty() {
+        return b.len();
+    }
+
+    let mut row = (0..=a.len()).collect::<Vec<_>>();
+    for (i, y) in b.iter().enumerate() {
+        let mut diag = row[0];
+        row[0] = i + 1;
+        let mut row_min = row[0];
+        for (j, x) in a.iter().enumerate() {
+            let value = (diag + (x != y) as usize)
+                .min(row[j] + 1)
+                .min(row[j + 1] + 1);
+            diag = row[j + 1];
+            row[j + 1] = value;
+            row_min = row_min.min(value);
+        }
+        // The distance never drops below the minimum of a row.
+        if row_min > bound {
+            return bound + 1;
+        }
+    }
+    row[a.len()].min(bound + 1)
+}
+
+/// The unrestricted Damerau-Levenshtein distance, or `bound + 1` if it is larger than `bound`.
+fn damerau_levenshtein_units<T: Eq + Hash + Copy>(a: &[T], b: &[T], bound: usize) -> usize {
+    if a.len().abs_diff(b.len()) > bound {
+        return bound + 1;
+    }
+    let (n, m) = (a.len(), b.len());
+    if n == 0 || m == 0 {
+        return (n + m).min(bound + 1);
+    }
+
+    // The distance matrix has an extra leading row and column holding `n + m`, which is larger
+    // than any distance, so transpositions with characters not seen before are never picked.
+    let inf = n + m;
+    let width = m + 2;
+    let mut d = vec![0; (n + 2) * width];
+    d[0] = inf;
+    for i in 0..=n {
+        d[(i + 1) * width] = inf;
+        d[(i + 1) * width + 1] = i;
+    }
+    for j in 0..=m {
+        d[j + 1] = inf;
+        d[width + j + 1] = j;
+    }
+
+    // The last row in which every unit of `a` was seen.
+    let mut last_row = PlHashMap::new();
+    for i in 1..=n {
+        let mut last_match_col = 0;
+        for j in 1..=m {
+            let k = last_row.get(&b[j - 1]).copied().unwrap_or(0);
+            let l = last_match_col;
+            let cost = if a[i - 1] == b[j - 1] {
+                last_match_col = j;
+                0
+            } else {
+                1
+            };
+            let substitution = d[i * width + j] + cost;
+            let insertion = d[(i + 1) * width + j] + 1;
+            let deletion = d[i * width + j + 1] + 1;
+            let transposition = d[k * width + l] + (i - k - 1) + 1 + (j - l - 1);
+            d[(i + 1) * width + j + 1] =
+                substitution.min(insertion).min(deletion).min(transposition);
+        }
+        last_row.insert(a[i - 1], i);
+    }
+    d[(n + 1) * width + m + 1].min(bound + 1)
+}
+
+fn hamming_units<T: PartialEq>(a: &[T], b: &[T]) -> Option<usize> {
+    (a.len() == b.len()).then(|| a.iter().zip(b).filter(|(x, y)| x != y).count())
+}
+
+fn jaro_units<T: PartialEq>(a: &[T], b: &[T]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut a_matched = vec![false; a.len()];
+    let mut b_matched = vec![false; b.len()];
+    let mut matches = 0;
+    for (i, x) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        for j in start..end {
+            if !b_matched[j] && b[j] == *x {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let mut half_transpositions = 0;
+    let mut b_matches = b.iter().zip(&b_matched).filter(|(_, m)| **m);
+    for (x, _) in a.iter().zip(&a_matched).filter(|(_, m)| **m) {
+        let (y, _) = b_matches.next().unwrap();
+        half_transpositions += (x != y) as usize;
+    }
+
+    let m = matches as f64;
+    let t = half_transpositions as f64 / 2.0;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - t) / m) / 3.0
+}
+
+fn jaro_winkler_units<T: PartialEq>(a: &[T], b: &[T]) -> f64 {
+    const PREFIX_SCALE: f64 = 0.1;
+    const MAX_PREFIX: usize = 4;
+
+    let sim = jaro_units(a, b);
+    let prefix = a
+        .iter()
+        .zip(b)
+        .take(MAX_PREFIX)
+        .take_while(|(x, y)| x == y)
+        .count();
+    sim + prefix as f64 * PREFIX_SCALE * (1.0 - sim)
+}
+
+/// The Jaccard index of the sets of `q`-grams, strings shorter than `q` are a single gram.
+fn jaccard_units<T: Eq + Hash>(a: &[T], b: &[T], q: usize) -> f64 {
+    fn grams<T: Eq + Hash>(s: &[T], q: usize) -> PlHashSet<&[T]> {
+        if s.len() < q {
+            PlHashSet::from_iter([s])
+        } else {
+            s.windows(q).collect()
+        }
+    }
+
+    let (a, b) = (grams(a, q), grams(b, q));
+    let intersection = a.intersection(&b).count();
+    let union = a.len() + b.len() - intersection;
+    if union == 0 {
+        1.0
+    } else {
+        intersection as f64 / union as f64
+    }
+}
+
+fn bound(max_distance: Option<u32>) -> usize {
+    max_distance.map_or(usize::MAX - 1, |max| max as usize)
+}
+
+/// The Levenshtein distance between the strings in `ca` and `other`.
+///
+/// If `max_distance` is given, distances larger than it are reported as `max_distance + 1`, which
+/// allows stopping early.
+pub fn levenshtein_distance(
+    ca: &StringChunked,
+    other: &StringChunked,
+    max_distance: Option<u32>,
+) -> UInt32Chunked {
+    let bound = bound(max_distance);
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        let d = with_units!(a?, b?, |a, b| levenshtein_units(a, b, bound));
+        Some(d as u32)
+    })
+}
+
+/// The Damerau-Levenshtein distance between the strings in `ca` and `other`, counting the
+/// transposition of two adjacent characters as a single edit.
+///
+/// If `max_distance` is given, distances larger than it are reported as `max_distance + 1`.
+pub fn damerau_levenshtein_distance(
+    ca: &StringChunked,
+    other: &StringChunked,
+    max_distance: Option<u32>,
+) -> UInt32Chunked {
+    let bound = bound(max_distance);
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        let d = with_units!(a?, b?, |a, b| damerau_levenshtein_units(a, b, bound));
+        Some(d as u32)
+    })
+}
+
+/// The number of positions at which the strings in `ca` and `other` differ, null if their lengths
+/// differ.
+pub fn hamming_distance(ca: &StringChunked, other: &StringChunked) -> UInt32Chunked {
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        with_units!(a?, b?, |a, b| hamming_units(a, b)).map(|d| d as u32)
+    })
+}
+
+/// The Jaro-Winkler similarity between the strings in `ca` and `other`, between 0 and 1.
+pub fn jaro_winkler_similarity(ca: &StringChunked, other: &StringChunked) -> Float64Chunked {
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        Some(with_units!(a?, b?, |a, b| jaro_winkler_units(a, b)))
+    })
+}
+
+/// The Jaccard similarity between the sets of `q`-grams of the strings in `ca` and `other`.
+pub fn jaccard_similarity(
+    ca: &StringChunked,
+    other: &StringChunked,
+    q: usize,
+) -> ApolloResult<Float64Chunked> {
+    apollo_ensure!(q > 0, InvalidOperation: "q-gram size must be positive");
+    Ok(broadcast_binary_elementwise(
+        ca,
+        other,
+        |a: Option<&str>, b: Option<&str>| Some(with_units!(a?, b?, |a, b| jaccard_units(a, b, q))),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn lev(a: &str, b: &str, max: Option<u32>) -> usize {
+        with_units!(a, b, |a, b| levenshtein_units(a, b, bound(max)))
+    }
+
+    fn dl(a: &str, b: &str) -> usize {
+        with_units!(a, b, |a, b| damerau_levenshtein_units(a, b, bound(None)))
+    }
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(lev("kitten", "sitting", None), 3);
+        assert_eq!(lev("", "abc", None), 3);
+        assert_eq!(lev("flaw", "lawn", None), 2);
+        assert_eq!(lev("ǆemal", "džemal", None), 2);
+        assert_eq!(lev("kitten", "sitting", Some(1)), 2);
+        assert_eq!(lev("a", "abcdef", Some(2)), 3);
+    }
+
+    #[test]
+    fn test_damerau_levenshtein() {
+        assert_eq!(dl("ca", "abc"), 2);
+        assert_eq!(dl("abcd", "acbd"), 1);
+        assert_eq!(dl("kitten", "sitting"), 3);
+        assert_eq!(dl("", "ab"), 2);
+    }
+
+    #[test]
+    fn test_jaro_winkler() {
+        let jw = |a: &str, b: &str| with_units!(a, b, |a, b| jaro_winkler_units(a, b));
+        assert!((jw("martha", "marhta") - 0.9611).abs() < 1e-4);
+        assert!((jw("dixon", "dicksonx") - 0.8133).abs() < 1e-4);
+        assert_eq!(jw("abc", "abc"), 1.0);
+        assert_eq!(jw("abc", "xyz"), 0.0);
+    }
+
+    #[test]
+    fn test_jaccard() {
+        let jac = |a: &str, b: &str, q| with_units!(a, b, |a, b| jaccard_units(a, b, q));
+        assert_eq!(jac("night", "nacht", 2), 1.0 / 7.0);
+        assert_eq!(jac("a", "a", 2), 1.0);
+        assert_eq!(jac("a", "b", 2), 0.0);
+    }
+
+    #[test]
+    fn test_broadcast() {
+        let ca = StringChunked::new("a".into(), &[Some("abc"), None, Some("abd")]);
+        let lit = StringChunked::new("b".into(), &[Some("abc")]);
+        let out = levenshtein_distance(&ca, &lit, None);
+        assert_eq!(Vec::from(&out), &[Some(0), None, Some(1)]);
+        let out = hamming_distance(&ca, &StringChunked::new("b".into(), &[Some("ab")]));
+        assert_eq!(Vec::from(&out), &[None, None, None]);
+    }
+}
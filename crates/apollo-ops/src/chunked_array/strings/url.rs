@@ -0,0 +1,231 @@
+//! Parsing of URLs into their components.
+//!
+//! URLs are split following the generic syntax of RFC 3986. This is deliberately lenient: the
+//! components are not validated beyond what is needed to find them, and nothing is normalized.
+use std::borrow::Cow;
+
+use apollo_core::prelude::arity::unary_elementwise;
+use apollo_core::prelude::*;
+use percent_encoding::percent_decode_str;
+
+/// The components of a URL, as in `scheme://userinfo@host:port/path?query#fragment`.
+#[derive(Debug, Default, PartialEq)]
+struct UrlParts<'a> {
+    scheme: Option<&'a str>,
+    host: Option<&'a str>,
+    port: Option<u16>,
+    path: &'a str,
+    query: Option<&'a str>,
+    fragment: Option<&'a str>,
+}
+
+/// The fields of the struct returned by [`parse_url`].
+pub fn url_fields() -> Vec<Field> {
+    vec![
+        Field::new(PlSmallStr::from_static("scheme"), DataType::String),
+        Field::new(PlSmallStr::from_static("host"), DataType::String),
+        Field::new(PlSmallStr::from_static("port"), DataType::UInt16),
+        Field::new(PlSmallStr::from_static("path"), DataType::String),
+        Field::new(PlSmallStr::from_static("query"), DataType::String),
+        Field::new(PlSmallStr::from_static("fragment"), DataType::String),
+    ]
+}
+
+/// `ALPHA *( ALPHA / DIGIT / "+" / "-" / "." )`
+fn is_scheme(s: &str) -> bool {
+    let mut bytes = s.bytes();
+    bytes.next().is_some_and(|b| b.is_ascii_alphabetic())
+        && bytes.all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.'))
+}
+
+/// Split an authority into its host and port, dropping the userinfo.
+fn split_authority(authority: &str) -> (&str, Option<u16>) {
+    let host_port = authority.rsplit_once('@').map_or(authority, |(_, hp)| hp);
+    let (host, port) = match host_port.strip_prefix('[') {
+        // IP literals are enclosed in brackets, as they contain colons themselves.
+        Some(literal) => match literal.split_once(']') {
+            Some((host, rest)) => (host, rest.strip_prefix(':')),
+            None => (host_port, None),
+        },
+        None => match host_port.rsplit_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (host_port, None),
+        },
+    };
+    let port = port
+        .filter(|p| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit()))
+        .and_then(|p| p.parse().ok());
+    (host, port)
+}
+
+fn split_url(url: &str) -> UrlParts<'_> {
+    let (rest, fragment) = match url.split_once('#') {
+        Some((rest, fragment)) => (rest, Some(fragment)),
+        None => (url, None),
+    };
+    let (rest, query) = match rest.split_once('?') {
+        Some((rest, query)) => (rest, Some(query)),
+        None => (rest, None),
+    };
+    let (scheme, rest) = match rest.split_once(':') {
+        Some((scheme, rest)) if is_scheme(scheme) => (Some(scheme), rest),
+        _ => (None, rest),
+    };
+    let (host, port, path) = match rest.strip_prefix("//") {
+        Some(rest) => {
+            let end = rest.find('/').unwrap_or(rest.len());
+            let (host, port) = split_authority(&rest[..end]);
+            (Some(host), port, &rest[end..])
+        },
+        None => (None, None, rest),
+    };
+    UrlParts {
+        scheme,
+        host,
+        port,
+        path,
+        query,
+        fragment,
+    }
+}
+
+/// Parse the values of `ca` into a struct with the fields of [`url_fields`].
+///
+/// Components that are absent from a URL are null, except the path, which is always present
+/// but may be empty.
+pub fn parse_url(ca: &StringChunked) -> ApolloResult<StructChunked> {
+    let len = ca.len();
+    let mut scheme = StringChunkedBuilder::new(PlSmallStr::from_static("scheme"), len);
+    let mut host = StringChunkedBuilder::new(PlSmallStr::from_static("host"), len);
+    let mut port = PrimitiveChunkedBuilder::<UInt16Type>::new(PlSmallStr::from_static("port"), len);
+    let mut path = StringChunkedBuilder::new(PlSmallStr::from_static("path"), len);
+    let mut query = StringChunkedBuilder::new(PlSmallStr::from_static("query"), len);
+    let mut fragment = StringChunkedBuilder::new(PlSmallStr::from_static("fragment"), len);
+
+    for opt_url in ca.iter() {
+        match opt_url.map(split_url) {
+            Some(parts) => {
+                scheme.append_option(parts.scheme);
+                host.append_option(parts.host);
+                port.append_option(parts.port);
+                path.append_value(parts.path);
+                query.append_option(parts.query);
+                fragment.append_option(parts.fragment);
+            },
+            // The outer validity marks these rows as null.
+            None => {
+                scheme.append_null();
+                host.append_null();
+                port.append_null();
+                path.append_null();
+                query.append_null();
+                fragment.append_null();
+            },
+        }
+    }
+
+    let fields = [
+        scheme.finish().into_series(),
+        host.finish().into_series(),
+        port.finish().into_series(),
+        path.finish().into_series(),
+        query.finish().into_series(),
+        fragment.finish().into_series(),
+    ];
+    let out = StructChunked::from_series(ca.name().clone(), len, fields.iter())?;
+    Ok(out.with_outer_validity(ca.rechunk_validity()))
+}
+
+/// Decode a component of an `application/x-www-form-urlencoded` query.
+fn decode_query_component(s: &str) -> Cow<'_, str> {
+    if s.contains('+') {
+        Cow::Owned(
+            percent_decode_str(&s.replace('+', " "))
+                .decode_utf8_lossy()
+                .into_owned(),
+        )
+    } else {
+        percent_decode_str(s).decode_utf8_lossy()
+    }
+}
+
+fn query_param<'a>(url: &'a str, name: &str) -> Option<Cow<'a, str>> {
+    split_url(url)
+        .query?
+        .split('&')
+        .map(|pair| pair.split_once('=').unwrap_or((pair, "")))
+        .find(|(key, _)| decode_query_component(key) == name)
+        .map(|(_, value)| decode_query_component(value))
+}
+
+/// The decoded value of the first query parameter called `name` in the URLs in `ca`.
+///
+/// URLs without the parameter give null, a parameter without a value gives an empty string.
+pub fn url_query_param(ca: &StringChunked, name: &str) -> StringChunked {
+    unary_elementwise(ca, |opt_url: Option<&str>| query_param(opt_url?, name))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_split_url() {
+        assert_eq!(
+            split_url("https://user:pw@example.com:8080/a/b?x=1&y=2#top"),
+            UrlParts {
+                scheme: Some("https"),
+                host: Some("example.com"),
+                port: Some(8080),
+                path: "/a/b",
+                query: Some("x=1&y=2"),
+                fragment: Some("top"),
+            }
+        );
+        assert_eq!(
+            split_url("http://[::1]:80"),
+            UrlParts {
+                scheme: Some("http"),
+                host: Some("::1"),
+                port: Some(80),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            split_url("mailto:someone@example.com"),
+            UrlParts {
+                scheme: Some("mailto"),
+                path: "someone@example.com",
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            split_url("../a:b?q"),
+            UrlParts {
+                path: "../a:b",
+                query: Some("q"),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_split_url_invalid_port() {
+        for url in ["http://host:", "http://host:+80", "http://host:65536"] {
+            let parts = split_url(url);
+            assert_eq!(parts.host, Some("host"));
+            assert_eq!(parts.port, None);
+        }
+    }
+
+    #[test]
+    fn test_query_param() {
+        let url = "https://example.com/?a=1&b=hello+world&c&a=2&d%20e=%C3%B1";
+        assert_eq!(query_param(url, "a").as_deref(), Some("1"));
+        assert_eq!(query_param(url, "b").as_deref(), Some("hello world"));
+        assert_eq!(query_param(url, "c").as_deref(), Some(""));
+        assert_eq!(query_param(url, "d e").as_deref(), Some("ñ"));
+        assert_eq!(query_param(url, "e"), None);
+        assert_eq!(query_param("https://example.com/", "a"), None);
+    }
+}
@@ -79,6 +79,19 @@ fn merge_series(lhs: &Series, rhs: &Series, merge_indicator: &[bool]) -> ApolloR
             let rhs = rhs.ext().unwrap();
             merge_series(lhs.storage(), rhs.storage(), merge_indicator)?.into_extension(typ.clone())
         },
+        #[cfg(feature = "dtype-map")]
+        Map(_, _) => {
+            let lhs = lhs.map_().unwrap();
+            let rhs = rhs.map_().unwrap();
+            merge_series(lhs.storage(), rhs.storage(), merge_indicator)?.into_map()
+        },
+        #[cfg(feature = "dtype-union")]
+        Union(_, _) => {
+            let lhs = lhs.union().unwrap();
+            let rhs = rhs.union().unwrap();
+            merge_series(lhs.storage(), rhs.storage(), merge_indicator)?
+                .into_union(lhs.dtype().clone())
+        },
         #[cfg(feature = "dtype-struct")]
         Struct(_) => {
             let lhs = lhs.struct_().unwrap();
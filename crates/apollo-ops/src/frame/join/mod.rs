@@ -227,6 +227,14 @@ pub trait DataFrameJoinOps: IntoDf {
             );
         };
 
+        // Extension types may define their own equality and ordering.
+        #[cfg(feature = "dtype-extension")]
+        for s in selected_left.iter_mut().chain(selected_right.iter_mut()) {
+            if s.dtype().is_extension() {
+                *s = s.to_comparison_key()?.clone();
+            }
+        }
+
         #[cfg(feature = "iejoin")]
         if let JoinType::IEJoin = args.how {
             let Some(JoinTypeOptions::IEJoin(options)) = options else {
@@ -0,0 +1,82 @@
+use apollo_compute::tdigest::TDigest;
+use apollo_core::prelude::*;
+
+/// Check the arguments of an approximate quantile of values of `dtype`.
+pub fn check_approx_quantile_args(
+    dtype: &DataType,
+    quantile: f64,
+    accuracy: u32,
+) -> ApolloResult<()> {
+    apollo_ensure!(
+        dtype.is_primitive_numeric() || dtype.is_null(),
+        InvalidOperation: "`approx_quantile` operation not supported for dtype `{}`", dtype
+    );
+    apollo_ensure!(
+        (0.0..=1.0).contains(&quantile),
+        ComputeError: "`quantile` should be between 0.0 and 1.0"
+    );
+    apollo_ensure!(
+        accuracy > 0,
+        ComputeError: "`accuracy` should be positive"
+    );
+    Ok(())
+}
+
+/// The data type of the approximate quantile of values of `dtype`.
+pub fn approx_quantile_dtype(dtype: &DataType) -> DataType {
+    match dtype {
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => DataType::Float16,
+        DataType::Float32 => DataType::Float32,
+        _ => DataType::Float64,
+    }
+}
+
+/// Add the non-null values of `ca` to `digest`.
+pub fn tdigest_extend(digest: &mut TDigest, ca: &Float64Chunked) {
+    for arr in ca.downcast_iter() {
+        if arr.has_nulls() {
+            arr.iter().flatten().for_each(|v| digest.add(*v));
+        } else {
+            arr.values().iter().for_each(|v| digest.add(*v));
+        }
+    }
+}
+
+/// Estimate the `quantile` of the values in `s` with a t-digest of compression `accuracy`.
+///
+/// Null and NaN values are ignored, the result is null if there are no other values.
+pub fn approx_quantile(s: &Series, quantile: f64, accuracy: u32) -> ApolloResult<Series> {
+    check_approx_quantile_args(s.dtype(), quantile, accuracy)?;
+    let mut digest = TDigest::new(accuracy);
+    tdigest_extend(&mut digest, s.cast(&DataType::Float64)?.f64()?);
+    let out = Float64Chunked::from_slice_options(s.name().clone(), &[digest.quantile(quantile)]);
+    out.into_series().cast(&approx_quantile_dtype(s.dtype()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_approx_quantile() {
+        let s = Series::new("a".into(), &[Some(3i32), None, Some(1), Some(2), Some(4)]);
+        let out = approx_quantile(&s, 0.5, 100).unwrap();
+        assert_eq!(out.dtype(), &DataType::Float64);
+        assert_eq!(out.f64().unwrap().get(0), Some(2.5));
+
+        let s = Series::new("a".into(), &[f32::NAN, 1.0, 2.0]);
+        let out = approx_quantile(&s, 1.0, 100).unwrap();
+        assert_eq!(out.f32().unwrap().get(0), Some(2.0));
+
+        let s = Series::new_null("a".into(), 3);
+        let out = approx_quantile(&s, 0.5, 100).unwrap();
+        assert_eq!(out.null_count(), 1);
+
+        let s = Series::new("a".into(), &["x"]);
+        assert!(approx_quantile(&s, 0.5, 100).is_err());
+        let s = Series::new("a".into(), &[1.0]);
+        assert!(approx_quantile(&s, 1.5, 100).is_err());
+        assert!(approx_quantile(&s, 0.5, 0).is_err());
+    }
+}
@@ -29,6 +29,11 @@ pub fn negate(s: &Series) -> ApolloResult<Series> {
             let out = ca.wrapping_neg().into_series();
             out.cast(s.dtype())?
         },
+        #[cfg(feature = "dtype-interval")]
+        Interval => {
+            let out = s.interval().unwrap().physical().wrapping_neg();
+            out.into_interval().into_series()
+        },
         dt => apollo_bail!(opq = neg, dt),
     };
     Ok(out)
@@ -115,6 +115,10 @@ pub fn unique_counts(s: &Series) -> ApolloResult<Series> {
 
         #[cfg(feature = "dtype-extension")]
         DataType::Extension(_, _) => unique_counts(s.ext().unwrap().storage()),
+        #[cfg(feature = "dtype-map")]
+        DataType::Map(_, _) => unique_counts(s.map_().unwrap().storage()),
+        #[cfg(feature = "dtype-union")]
+        DataType::Union(_, _) => unique_counts(s.union().unwrap().storage()),
 
         DataType::UInt8
         | DataType::UInt16
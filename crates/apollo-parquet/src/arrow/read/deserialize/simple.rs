@@ -4,7 +4,7 @@ use arrow::datatypes::{
     ArrowDataType, DTYPE_CATEGORICAL_LEGACY, DTYPE_CATEGORICAL_NEW, DTYPE_ENUM_VALUES_LEGACY,
     DTYPE_ENUM_VALUES_NEW, Field, IntegerType, IntervalUnit, TimeUnit,
 };
-use arrow::types::{days_ms, i256};
+use arrow::types::{days_ms, i256, months_days_ns};
 use ethnum::I256;
 use apollo_compute::cast::CastOptionsImpl;
 use apollo_utils::float16::pf16;
@@ -241,6 +241,43 @@ pub fn page_iter_to_array(
 
             (nested, out, ptm)
         },
+        (PhysicalType::FixedLenByteArray(16), Interval(IntervalUnit::MonthDayNano)) => {
+            let n = 16;
+            let (nested, array, ptm) = PageDecoder::new(
+                &field.name,
+                pages,
+                ArrowDataType::FixedSizeBinary(n),
+                fixed_size_binary::BinaryDecoder { size: n },
+                init_nested,
+            )?
+            .collect(filter)?;
+
+            let array = array
+                .into_iter()
+                .map(|array| {
+                    let values = array
+                        .values()
+                        .chunks_exact(n)
+                        .map(|value: &[u8]| {
+                            months_days_ns::new(
+                                i32::from_le_bytes(value[..4].try_into().unwrap()),
+                                i32::from_le_bytes(value[4..8].try_into().unwrap()),
+                                i64::from_le_bytes(value[8..].try_into().unwrap()),
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                    let validity = array.validity().cloned();
+                    Ok(PrimitiveArray::<months_days_ns>::try_new(
+                        dtype.clone(),
+                        values.into(),
+                        validity,
+                    )?
+                    .to_boxed())
+                })
+                .collect::<ParquetResult<Vec<Box<dyn Array>>>>()?;
+
+            (nested, array, ptm)
+        },
         (PhysicalType::FixedLenByteArray(16), UInt128) => {
             let n = 16;
             let (nested, array, ptm) = PageDecoder::new(
@@ -88,12 +88,10 @@ fn convert_dtype(mut dtype: ArrowDataType) -> ArrowDataType {
         Extension(ref mut ext) => {
             ext.inner = convert_dtype(std::mem::take(&mut ext.inner));
         },
-        Map(mut field, _ordered) => {
-            // Apollo doesn't support Map.
-            // A map is physically a `List<Struct<K, V>>`
-            // So we read as list.
+        Map(ref mut field, _ordered) => {
+            // Keep the map so that Apollo can restore its `Map` dtype, maps
+            // that were not written by Apollo are read as a `List<Struct<K, V>>`.
             convert_field(field.as_mut());
-            dtype = LargeList(field);
         },
         _ => {},
     }
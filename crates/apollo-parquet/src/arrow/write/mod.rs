@@ -28,7 +28,7 @@ mod utils;
 use arrow::array::*;
 use arrow::bitmap::Bitmap;
 use arrow::datatypes::*;
-use arrow::types::{NativeType, days_ms, i256};
+use arrow::types::{NativeType, days_ms, i256, months_days_ns};
 pub use nested::{num_values, write_rep_and_def};
 pub use pages::{to_leaves, to_nested, to_parquet_leaves};
 use apollo_utils::float16::pf16;
@@ -598,6 +598,10 @@ pub fn array_to_page_simple(
             };
             fixed_size_binary::array_to_page(&array, options, type_, statistics)
         },
+        ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+            let array = month_day_nano_to_fixed_size_binary(array);
+            fixed_size_binary::array_to_page(&array, options, type_, None)
+        },
         ArrowDataType::FixedSizeBinary(_) => {
             let array = array.as_any().downcast_ref().unwrap();
             let statistics = if options.has_statistics() {
@@ -1128,11 +1132,34 @@ fn array_to_page_nested(
             );
             fixed_size_binary::nested_array_to_page(&array, options, type_, nested, statistics)
         },
+        Interval(IntervalUnit::MonthDayNano) => {
+            let array = month_day_nano_to_fixed_size_binary(array);
+            fixed_size_binary::nested_array_to_page(&array, options, type_, nested, None)
+        },
         other => apollo_bail!(nyi = "Writing nested parquet pages for data type {other:?}"),
     }
     .map(Page::Data)
 }
 
+/// Write the months, days and nanoseconds of every interval as little-endian bytes.
+fn month_day_nano_to_fixed_size_binary(array: &dyn Array) -> FixedSizeBinaryArray {
+    let array = array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<months_days_ns>>()
+        .unwrap();
+    let mut values = Vec::<u8>::with_capacity(16 * array.len());
+    array.values().iter().for_each(|x| {
+        values.extend_from_slice(&x.months().to_le_bytes());
+        values.extend_from_slice(&x.days().to_le_bytes());
+        values.extend_from_slice(&x.ns().to_le_bytes());
+    });
+    FixedSizeBinaryArray::new(
+        ArrowDataType::FixedSizeBinary(16),
+        values.into(),
+        array.validity().cloned(),
+    )
+}
+
 fn get_encodings_recursive(dtype: &ArrowDataType, encodings: &mut Vec<Encoding>) {
     use arrow::datatypes::PhysicalType::*;
     match dtype.to_physical_type() {
@@ -1195,6 +1222,8 @@ pub fn get_dtype_encoding(dtype: &ArrowDataType) -> Vec<Encoding> {
 
 fn get_primitive_dtype_encoding(dtype: &ArrowDataType) -> Encoding {
     match dtype.to_physical_type() {
+        // written as raw fixed-size binary
+        PhysicalType::Primitive(arrow::types::PrimitiveType::MonthDayNano) => Encoding::Plain,
         PhysicalType::Dictionary(_)
         | PhysicalType::LargeBinary
         | PhysicalType::LargeUtf8
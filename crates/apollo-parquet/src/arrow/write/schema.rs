@@ -1,7 +1,7 @@
 use std::sync::{Arc, LazyLock};
 
 use arrow::datatypes::{
-    ArrowDataType, ArrowSchema, ExtensionType, Field, PARQUET_EMPTY_STRUCT, TimeUnit,
+    ArrowDataType, ArrowSchema, ExtensionType, Field, IntervalUnit, PARQUET_EMPTY_STRUCT, TimeUnit,
 };
 use arrow::io::ipc::write::{default_ipc_fields, schema_to_bytes};
 use base64::Engine as _;
@@ -298,6 +298,11 @@ pub fn to_parquet_type(field: &Field) -> ApolloResult<ParquetType> {
                 (PhysicalType::FixedLenByteArray(32), None, None)
             }
         },
+        // Parquet's interval type only has millisecond precision, so
+        // month-day-nano intervals are written as raw bytes.
+        ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+            (PhysicalType::FixedLenByteArray(16), None, None)
+        },
         ArrowDataType::Interval(_) => (
             PhysicalType::FixedLenByteArray(12),
             Some(PrimitiveConvertedType::Interval),
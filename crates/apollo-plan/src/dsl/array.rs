@@ -1,6 +1,10 @@
 use apollo_core::prelude::*;
+#[cfg(feature = "array_vector")]
+use apollo_ops::chunked_array::array::VectorMetric;
 
 use crate::dsl::function_expr::ArrayFunction;
+#[cfg(feature = "array_vector")]
+use crate::dsl::functions::int_range;
 use crate::prelude::*;
 
 /// Specialized expressions for [`Series`] of [`DataType::Array`].
@@ -153,6 +157,40 @@ impl ArrayNameSpace {
         self.0.map_unary(ArrayFunction::ToStruct(name_generator))
     }
 
+    #[cfg(feature = "array_vector")]
+    /// Compute `metric` between every subarray and the subarray of `other`.
+    pub fn vector_metric<E: Into<Expr>>(self, other: E, metric: VectorMetric) -> Expr {
+        self.0.map_binary(
+            FunctionExpr::ArrayExpr(ArrayFunction::Metric(metric)),
+            other.into(),
+        )
+    }
+
+    #[cfg(feature = "array_vector")]
+    /// Scale every subarray to unit length.
+    pub fn normalize(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ArrayExpr(ArrayFunction::Normalize))
+    }
+
+    #[cfg(feature = "array_vector")]
+    /// Get the row indices of the `k` subarrays closest to `query` by `metric`, closest first.
+    ///
+    /// Subarrays with a null or NaN score are never returned, so the output can have fewer
+    /// than `k` rows.
+    pub fn nearest<E: Into<Expr>>(self, query: E, k: Expr, metric: VectorMetric) -> Expr {
+        let index = int_range(lit(0), self.0.clone().len(), 1, IDX_DTYPE);
+        let score = self.vector_metric(query, metric);
+        let is_valid = score.clone().is_not_null().and(score.clone().is_not_nan());
+        let index = index.filter(is_valid.clone());
+        let score = score.filter(is_valid);
+        if metric.is_similarity() {
+            index.top_k_by(k, [score], vec![false])
+        } else {
+            index.bottom_k_by(k, [score], vec![false])
+        }
+    }
+
     /// Slice every subarray.
     pub fn slice(self, offset: Expr, length: Expr, as_array: bool) -> ApolloResult<Expr> {
         if as_array {
@@ -53,6 +53,16 @@ impl BinaryNameSpace {
             .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Base64Encode))
     }
 
+    /// Compute a stable digest of each value, as hexadecimal strings if `hex` is set.
+    #[cfg(feature = "hash_digest")]
+    pub fn digest(self, algorithm: HashAlgorithm, hex: bool) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Digest {
+                algorithm,
+                hex,
+            }))
+    }
+
     #[cfg(feature = "binary_encoding")]
     pub fn reinterpret(self, to_type: impl Into<DataTypeExpr>, is_little_endian: bool) -> Expr {
         self.0
@@ -81,6 +81,34 @@ impl DslBuilder {
         .into())
     }
 
+    #[cfg(feature = "avro")]
+    pub fn scan_avro(
+        sources: ScanSources,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> ApolloResult<Self> {
+        Ok(DslPlan::Scan {
+            sources,
+            unified_scan_args: Box::new(unified_scan_args),
+            scan_type: Box::new(FileScanDsl::Avro),
+            cached_ir: Default::default(),
+        }
+        .into())
+    }
+
+    #[cfg(feature = "orc")]
+    pub fn scan_orc(
+        sources: ScanSources,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> ApolloResult<Self> {
+        Ok(DslPlan::Scan {
+            sources,
+            unified_scan_args: Box::new(unified_scan_args),
+            scan_type: Box::new(FileScanDsl::Orc),
+            cached_ir: Default::default(),
+        }
+        .into())
+    }
+
     #[cfg(feature = "scan_lines")]
     pub fn scan_lines(
         sources: ScanSources,
@@ -1,3 +1,5 @@
+use apollo_core::datatypes::extension::UuidVersion;
+
 use super::*;
 
 /// Specialized expressions for Categorical dtypes.
@@ -12,3 +14,8 @@ impl ExtensionNameSpace {
         self.0.map_unary(ExtensionFunction::Storage)
     }
 }
+
+/// Generate `n` new UUIDs of the given `version`, with the `arrow.uuid` extension dtype.
+pub fn generate_uuid(n: Expr, version: UuidVersion) -> Expr {
+    n.map_unary(ExtensionFunction::GenerateUuid(version))
+}
@@ -57,6 +57,12 @@ pub enum FileScanDsl {
     #[cfg(feature = "ipc")]
     Ipc { options: IpcScanOptions },
 
+    #[cfg(feature = "avro")]
+    Avro,
+
+    #[cfg(feature = "orc")]
+    Orc,
+
     #[cfg(feature = "python")]
     PythonDataset {
         dataset_object: Arc<python_dataset::PythonDatasetProvider>,
@@ -102,6 +108,12 @@ pub enum FileScanIR {
         metadata: Option<Arc<arrow::io::ipc::read::FileMetadata>>,
     },
 
+    #[cfg(feature = "avro")]
+    Avro,
+
+    #[cfg(feature = "orc")]
+    Orc,
+
     #[cfg(feature = "python")]
     PythonDataset {
         dataset_object: Arc<python_dataset::PythonDatasetProvider>,
@@ -405,6 +417,12 @@ mod _file_scan_eq_hash {
             metadata: Option<usize>,
         },
 
+        #[cfg(feature = "avro")]
+        Avro,
+
+        #[cfg(feature = "orc")]
+        Orc,
+
         #[cfg(feature = "python")]
         PythonDataset {
             dataset_object: usize,
@@ -445,6 +463,12 @@ mod _file_scan_eq_hash {
                     metadata: metadata.as_ref().map(arc_as_ptr),
                 },
 
+                #[cfg(feature = "avro")]
+                FileScanIR::Avro => FileScanEqHashWrap::Avro,
+
+                #[cfg(feature = "orc")]
+                FileScanIR::Orc => FileScanEqHashWrap::Orc,
+
                 #[cfg(feature = "python")]
                 FileScanIR::PythonDataset {
                     dataset_object,
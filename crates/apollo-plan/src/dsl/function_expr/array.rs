@@ -1,6 +1,8 @@
 use std::fmt;
 
 use apollo_core::prelude::{ExplodeOptions, SortOptions};
+#[cfg(feature = "array_vector")]
+use apollo_ops::chunked_array::array::VectorMetric;
 
 use super::FunctionExpr;
 
@@ -41,6 +43,10 @@ pub enum ArrayFunction {
     Concat,
     #[cfg(feature = "array_to_struct")]
     ToStruct(Option<super::DslNameGenerator>),
+    #[cfg(feature = "array_vector")]
+    Metric(VectorMetric),
+    #[cfg(feature = "array_vector")]
+    Normalize,
 }
 
 impl fmt::Display for ArrayFunction {
@@ -78,6 +84,15 @@ impl fmt::Display for ArrayFunction {
             Explode { .. } => "explode",
             #[cfg(feature = "array_to_struct")]
             ToStruct(_) => "to_struct",
+            #[cfg(feature = "array_vector")]
+            Metric(metric) => match metric {
+                VectorMetric::Dot => "dot",
+                VectorMetric::Cosine => "cosine_similarity",
+                VectorMetric::Euclidean => "euclidean_distance",
+                VectorMetric::Manhattan => "manhattan_distance",
+            },
+            #[cfg(feature = "array_vector")]
+            Normalize => "normalize",
         };
         write!(f, "arr.{name}")
     }
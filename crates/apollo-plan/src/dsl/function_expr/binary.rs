@@ -19,6 +19,11 @@ pub enum BinaryFunction {
     #[cfg(feature = "binary_encoding")]
     Base64Encode,
     Size,
+    #[cfg(feature = "hash_digest")]
+    Digest {
+        algorithm: HashAlgorithm,
+        hex: bool,
+    },
     #[cfg(feature = "binary_encoding")]
     /// The parameters are destination type, and whether to use little endian
     /// encoding.
@@ -44,6 +49,8 @@ impl Display for BinaryFunction {
             #[cfg(feature = "binary_encoding")]
             Base64Encode => "base64_encode",
             Size => "size_bytes",
+            #[cfg(feature = "hash_digest")]
+            Digest { .. } => "digest",
             #[cfg(feature = "binary_encoding")]
             Reinterpret(_, _) => "reinterpret",
             Slice => "slice",
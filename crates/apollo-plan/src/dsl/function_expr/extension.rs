@@ -1,3 +1,5 @@
+use apollo_core::datatypes::extension::UuidVersion;
+
 use super::*;
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -6,6 +8,7 @@ use super::*;
 pub enum ExtensionFunction {
     To(DataTypeExpr),
     Storage,
+    GenerateUuid(UuidVersion),
 }
 
 impl Display for ExtensionFunction {
@@ -14,6 +17,7 @@ impl Display for ExtensionFunction {
         match self {
             To(dtype) => write!(f, "ext.to({dtype:?})"),
             Storage => write!(f, "ext.storage()"),
+            GenerateUuid(version) => write!(f, "generate_uuid({version:?})"),
         }
     }
 }
@@ -0,0 +1,33 @@
+use super::*;
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum MapFunction {
+    Get,
+    Keys,
+    Values,
+    ContainsKey,
+    FromLists,
+    ToStruct(Arc<[PlSmallStr]>),
+}
+
+impl Display for MapFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use MapFunction::*;
+        match self {
+            Get => write!(f, "map.get"),
+            Keys => write!(f, "map.keys"),
+            Values => write!(f, "map.values"),
+            ContainsKey => write!(f, "map.contains_key"),
+            FromLists => write!(f, "map.from_lists"),
+            ToStruct(names) => write!(f, "map.to_struct({names:?})"),
+        }
+    }
+}
+
+impl From<MapFunction> for FunctionExpr {
+    fn from(func: MapFunction) -> Self {
+        FunctionExpr::MapExpr(func)
+    }
+}
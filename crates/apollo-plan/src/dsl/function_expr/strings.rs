@@ -65,6 +65,46 @@ pub enum StringFunction {
     },
     #[cfg(feature = "string_reverse")]
     Reverse,
+    #[cfg(feature = "string_similarity")]
+    LevenshteinDistance {
+        max_distance: Option<u32>,
+    },
+    #[cfg(feature = "string_similarity")]
+    DamerauLevenshteinDistance {
+        max_distance: Option<u32>,
+    },
+    #[cfg(feature = "string_similarity")]
+    HammingDistance,
+    #[cfg(feature = "string_similarity")]
+    JaroWinklerSimilarity,
+    #[cfg(feature = "string_similarity")]
+    JaccardSimilarity {
+        q: usize,
+    },
+    #[cfg(feature = "hash_digest")]
+    Digest {
+        algorithm: HashAlgorithm,
+        hex: bool,
+    },
+    #[cfg(feature = "string_url")]
+    ParseUrl,
+    #[cfg(feature = "string_url")]
+    UrlQueryParam {
+        name: PlSmallStr,
+    },
+    #[cfg(feature = "string_ip")]
+    Ipv4ToInt,
+    #[cfg(feature = "string_ip")]
+    Ipv6ToInt,
+    #[cfg(feature = "string_ip")]
+    IpToBinary,
+    #[cfg(feature = "string_ip")]
+    IpInCidr,
+    #[cfg(feature = "string_ip")]
+    IpMask {
+        ipv4_prefix: u8,
+        ipv6_prefix: u8,
+    },
     #[cfg(feature = "string_pad")]
     PadStart {
         fill_char: char,
@@ -109,6 +149,10 @@ pub enum StringFunction {
     ToDecimal {
         scale: usize,
     },
+    #[cfg(feature = "dtype-interval")]
+    ToInterval {
+        strict: bool,
+    },
     #[cfg(feature = "nightly")]
     Titlecase,
     Uppercase,
@@ -179,6 +223,32 @@ impl Display for StringFunction {
             Normalize { .. } => "normalize",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
+            #[cfg(feature = "string_similarity")]
+            LevenshteinDistance { .. } => "levenshtein_distance",
+            #[cfg(feature = "string_similarity")]
+            DamerauLevenshteinDistance { .. } => "damerau_levenshtein_distance",
+            #[cfg(feature = "string_similarity")]
+            HammingDistance => "hamming_distance",
+            #[cfg(feature = "string_similarity")]
+            JaroWinklerSimilarity => "jaro_winkler_similarity",
+            #[cfg(feature = "string_similarity")]
+            JaccardSimilarity { .. } => "jaccard_similarity",
+            #[cfg(feature = "hash_digest")]
+            Digest { .. } => "digest",
+            #[cfg(feature = "string_url")]
+            ParseUrl => "parse_url",
+            #[cfg(feature = "string_url")]
+            UrlQueryParam { .. } => "url_query_param",
+            #[cfg(feature = "string_ip")]
+            Ipv4ToInt => "ipv4_to_int",
+            #[cfg(feature = "string_ip")]
+            Ipv6ToInt => "ipv6_to_int",
+            #[cfg(feature = "string_ip")]
+            IpToBinary => "ip_to_binary",
+            #[cfg(feature = "string_ip")]
+            IpInCidr => "ip_in_cidr",
+            #[cfg(feature = "string_ip")]
+            IpMask { .. } => "ip_mask",
             #[cfg(feature = "string_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
@@ -225,6 +295,8 @@ impl Display for StringFunction {
             Titlecase => "to_titlecase",
             #[cfg(feature = "dtype-decimal")]
             ToDecimal { .. } => "to_decimal",
+            #[cfg(feature = "dtype-interval")]
+            ToInterval { .. } => "to_interval",
             Uppercase => "to_uppercase",
             #[cfg(feature = "string_pad")]
             ZFill => "zfill",
@@ -0,0 +1,25 @@
+use super::*;
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum UnionFunction {
+    Tag,
+    Field(PlSmallStr),
+}
+
+impl Display for UnionFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use UnionFunction::*;
+        match self {
+            Tag => write!(f, "union.tag"),
+            Field(name) => write!(f, "union.field({name})"),
+        }
+    }
+}
+
+impl From<UnionFunction> for FunctionExpr {
+    fn from(func: UnionFunction) -> Self {
+        FunctionExpr::UnionExpr(func)
+    }
+}
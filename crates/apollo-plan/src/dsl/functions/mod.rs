@@ -50,7 +50,7 @@ pub use syntactic_sugar::*;
 #[cfg(feature = "temporal")]
 pub use temporal::*;
 
-#[cfg(feature = "arg_where")]
+#[cfg(any(feature = "arg_where", feature = "hash_digest"))]
 use crate::dsl::function_expr::FunctionExpr;
 use crate::dsl::function_expr::ListFunction;
 #[cfg(all(feature = "concat_str", feature = "strings"))]
@@ -90,3 +90,20 @@ pub fn nth(n: i64) -> Selector {
 pub fn lit<L: Literal>(t: L) -> Expr {
     t.lit()
 }
+
+/// Compute a deterministic digest of each row of `exprs`.
+///
+/// The digest is taken over the row encoding of the values, so it depends on both the values and
+/// their data types. The row encoding is internal and may change between versions, so digests
+/// should not be compared across versions. Categoricals are rejected as their encoding depends on
+/// the order the categories were seen in. Set `hex` to get hexadecimal strings instead of binary
+/// values.
+#[cfg(feature = "hash_digest")]
+pub fn digest_rows(exprs: Vec<Expr>, algorithm: HashAlgorithm, hex: bool) -> Expr {
+    Expr::n_ary(
+        FunctionExpr::RowEncode(crate::plans::RowEncodingVariant::Unordered),
+        exprs,
+    )
+    .binary()
+    .digest(algorithm, hex)
+}
@@ -0,0 +1,42 @@
+use super::*;
+
+/// Specialized expressions for Map dtypes.
+pub struct MapNameSpace(pub(crate) Expr);
+
+impl MapNameSpace {
+    /// Get the value for `key` in every map, or `null` if the map does not hold the key.
+    pub fn get<E: Into<Expr>>(self, key: E) -> Expr {
+        self.0.map_binary(MapFunction::Get, key.into())
+    }
+
+    /// Get the keys of every map as a list.
+    pub fn keys(self) -> Expr {
+        self.0.map_unary(MapFunction::Keys)
+    }
+
+    /// Get the values of every map as a list.
+    pub fn values(self) -> Expr {
+        self.0.map_unary(MapFunction::Values)
+    }
+
+    /// Check whether every map holds `key`.
+    pub fn contains_key<E: Into<Expr>>(self, key: E) -> Expr {
+        self.0.map_binary(MapFunction::ContainsKey, key.into())
+    }
+
+    /// Build maps from this list of keys and a list of `values` of the same length.
+    pub fn from_lists<E: Into<Expr>>(self, values: E) -> Expr {
+        self.0.map_binary(MapFunction::FromLists, values.into())
+    }
+
+    /// Convert maps with String keys into a struct with a field for each of the known `keys`.
+    pub fn to_struct<I, S>(self, keys: I) -> Expr
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+    {
+        self.0.map_unary(MapFunction::ToStruct(
+            keys.into_iter().map(|x| x.into()).collect(),
+        ))
+    }
+}
@@ -27,6 +27,8 @@ mod from;
 pub mod function_expr;
 pub mod functions;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 mod match_to_schema;
 #[cfg(feature = "meta")]
 mod meta;
@@ -46,6 +48,8 @@ pub mod string;
 #[cfg(feature = "dtype-struct")]
 mod struct_;
 pub mod udf;
+#[cfg(feature = "dtype-union")]
+mod union;
 
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -61,6 +65,8 @@ pub use expr::*;
 pub use extension::*;
 pub use function_expr::*;
 pub use list::*;
+#[cfg(feature = "dtype-map")]
+pub use map::*;
 pub use match_to_schema::*;
 #[cfg(feature = "meta")]
 pub use meta::*;
@@ -80,6 +86,8 @@ pub use selector::{DataTypeSelector, Selector, TimeUnitSet, TimeZoneSet};
 #[cfg(feature = "dtype-struct")]
 pub use struct_::*;
 pub use udf::UserDefinedFunction;
+#[cfg(feature = "dtype-union")]
+pub use union::*;
 mod file_scan;
 pub use file_scan::*;
 use functions::lit;
@@ -974,6 +982,14 @@ impl Expr {
         self.map_unary(FunctionExpr::ApproxNUnique)
     }
 
+    /// Get the approximate `quantile` of the values, estimated with a t-digest.
+    ///
+    /// A larger `accuracy` gives a more accurate estimate at the cost of memory.
+    #[cfg(feature = "approx_quantile")]
+    pub fn approx_quantile(self, quantile: f64, accuracy: u32) -> Self {
+        self.map_unary(FunctionExpr::ApproxQuantile { quantile, accuracy })
+    }
+
     /// Bitwise "and" operation.
     pub fn and<E: Into<Expr>>(self, expr: E) -> Self {
         binary_expr(self, Operator::And, expr.into())
@@ -1654,12 +1670,24 @@ impl Expr {
         extension::ExtensionNameSpace(self)
     }
 
+    /// Get the [`map::MapNameSpace`].
+    #[cfg(feature = "dtype-map")]
+    pub fn map_(self) -> map::MapNameSpace {
+        map::MapNameSpace(self)
+    }
+
     /// Get the [`struct_::StructNameSpace`].
     #[cfg(feature = "dtype-struct")]
     pub fn struct_(self) -> struct_::StructNameSpace {
         struct_::StructNameSpace(self)
     }
 
+    /// Get the [`union::UnionNameSpace`].
+    #[cfg(feature = "dtype-union")]
+    pub fn union(self) -> union::UnionNameSpace {
+        union::UnionNameSpace(self)
+    }
+
     /// Get the [`meta::MetaNameSpace`]
     #[cfg(feature = "meta")]
     pub fn meta(self) -> meta::MetaNameSpace {
@@ -8,6 +8,7 @@ use apollo_io::hive::HivePathFormatter;
 use apollo_io::utils::file::Writeable;
 use apollo_utils::pl_str::PlSmallStr;
 
+use crate::dsl::BucketHash;
 use crate::prelude::PlanCallback;
 
 #[derive(Debug)]
@@ -29,6 +30,7 @@ pub type FileProviderFunction = PlanCallback<FileProviderArgs, FileProviderRetur
 #[derive(Clone, Debug, Hash, PartialEq)]
 pub enum FileProviderType {
     Hive(HivePathProvider),
+    Bucket(BucketPathProvider),
     Function(FileProviderFunction),
 }
 
@@ -39,10 +41,21 @@ pub struct HivePathProvider {
     pub extension: PlSmallStr,
 }
 
+/// Flat file names that identify the bucket. Spark takes the bucket from the end of the file
+/// name, Hive from the start.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Hash, PartialEq)]
+pub struct BucketPathProvider {
+    pub extension: PlSmallStr,
+    pub hash: BucketHash,
+}
+
 impl FileProviderType {
     pub fn get_path_or_file(&self, args: FileProviderArgs) -> ApolloResult<FileProviderReturn> {
         match self {
             Self::Hive(v) => v.get_path(args).map(FileProviderReturn::Path),
+            Self::Bucket(v) => v.get_path(args).map(FileProviderReturn::Path),
             Self::Function(v) => v.get_path_or_file(args),
         }
     }
@@ -78,6 +91,28 @@ impl HivePathProvider {
     }
 }
 
+impl BucketPathProvider {
+    pub fn get_path(&self, args: FileProviderArgs) -> ApolloResult<String> {
+        let BucketPathProvider { extension, hash } = self;
+
+        let FileProviderArgs {
+            index_in_partition,
+            partition_keys,
+        } = args;
+
+        let bucket = partition_keys.columns()[0].u32()?.get(0).unwrap();
+
+        assert!(index_in_partition <= 0xffff_ffff);
+
+        Ok(match hash {
+            BucketHash::Spark => format!("{index_in_partition:08x}_{bucket:05}.{extension}"),
+            // Hive names additional files of the same bucket as copies.
+            BucketHash::Hive if index_in_partition == 0 => format!("{bucket:06}_0.{extension}"),
+            BucketHash::Hive => format!("{bucket:06}_0_copy_{index_in_partition}.{extension}"),
+        })
+    }
+}
+
 impl FileProviderFunction {
     pub fn get_path_or_file(&self, args: FileProviderArgs) -> ApolloResult<FileProviderReturn> {
         match self {
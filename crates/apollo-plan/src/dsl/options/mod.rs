@@ -8,12 +8,16 @@ pub mod file_provider;
 pub mod sink;
 use apollo_core::error::ApolloResult;
 use apollo_core::prelude::*;
+#[cfg(feature = "avro")]
+use apollo_io::avro::AvroWriterOptions;
 #[cfg(feature = "csv")]
 use apollo_io::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
 use apollo_io::ipc::IpcWriterOptions;
 #[cfg(feature = "json")]
 use apollo_io::ndjson::NDJsonWriterOptions;
+#[cfg(feature = "orc")]
+use apollo_io::orc::OrcWriterOptions;
 #[cfg(feature = "parquet")]
 use apollo_io::parquet::write::ParquetWriteOptions;
 #[cfg(feature = "iejoin")]
@@ -29,7 +33,7 @@ use apollo_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 pub use sink::{
-    CallbackSinkType, FileSinkOptions, PartitionStrategy, PartitionStrategyIR,
+    BucketHash, CallbackSinkType, FileSinkOptions, PartitionStrategy, PartitionStrategyIR,
     PartitionedSinkOptions, PartitionedSinkOptionsIR, SinkDestination, SinkTarget, SinkType,
     SinkTypeIR, UnifiedSinkArgs,
 };
@@ -363,6 +367,10 @@ pub enum FileWriteFormat {
     Csv(CsvWriterOptions),
     #[cfg(feature = "json")]
     NDJson(NDJsonWriterOptions),
+    #[cfg(feature = "avro")]
+    Avro(AvroWriterOptions),
+    #[cfg(feature = "orc")]
+    Orc(OrcWriterOptions),
 }
 
 impl FileWriteFormat {
@@ -376,6 +384,10 @@ impl FileWriteFormat {
             Self::Csv(_) => "csv",
             #[cfg(feature = "json")]
             Self::NDJson(_) => "jsonl",
+            #[cfg(feature = "avro")]
+            Self::Avro(_) => "avro",
+            #[cfg(feature = "orc")]
+            Self::Orc(_) => "orc",
 
             #[allow(unreachable_patterns)]
             _ => unreachable!("enable file type features"),
@@ -440,4 +452,7 @@ pub struct NDJsonReadOptions {
     pub ignore_errors: bool,
     pub schema: Option<SchemaRef>,
     pub schema_overwrite: Option<SchemaRef>,
+    /// Write lines that fail to parse to a CSV file at this path instead of raising an error.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub reject_rows: Option<apollo_utils::pl_path::PlRefPath>,
 }
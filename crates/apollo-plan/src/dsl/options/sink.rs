@@ -6,7 +6,7 @@ use std::sync::Arc;
 
 use apollo_core::error::ApolloResult;
 use apollo_core::frame::DataFrame;
-use apollo_core::prelude::PlHashSet;
+use apollo_core::prelude::{PlHashSet, SortMultipleOptions};
 use apollo_core::schema::Schema;
 use apollo_io::cloud::CloudOptions;
 use apollo_io::utils::file::Writeable;
@@ -32,6 +32,9 @@ pub struct UnifiedSinkArgs {
     pub maintain_order: bool,
     pub sync_on_close: SyncOnCloseType,
     pub cloud_options: Option<Arc<CloudOptions>>,
+    /// Write to a hidden staging location and only move the output to its final location once
+    /// the sink has finished successfully.
+    pub atomic: bool,
 }
 
 impl Default for UnifiedSinkArgs {
@@ -41,6 +44,7 @@ impl Default for UnifiedSinkArgs {
             maintain_order: true,
             sync_on_close: SyncOnCloseType::None,
             cloud_options: None,
+            atomic: false,
         }
     }
 }
@@ -54,8 +58,11 @@ pub enum SinkDestination {
         base_path: PlRefPath,
         file_path_provider: Option<FileProviderType>,
         partition_strategy: PartitionStrategy,
+        /// Sort the rows within every partition, so that each file is sorted.
+        per_partition_sort_by: Option<(Vec<Expr>, SortMultipleOptions)>,
         max_rows_per_file: IdxSize,
         approximate_bytes_per_file: u64,
+        manifest_path: Option<PlRefPath>,
     },
 }
 
@@ -255,6 +262,8 @@ pub struct PartitionedSinkOptions {
     pub unified_sink_args: UnifiedSinkArgs,
     pub max_rows_per_file: IdxSize,
     pub approximate_bytes_per_file: u64,
+    /// Write a JSON manifest of the written files to this path.
+    pub manifest_path: Option<PlRefPath>,
 }
 
 impl PartitionedSinkOptions {
@@ -276,6 +285,26 @@ pub enum PartitionStrategy {
     ///
     /// Semantically equivalent to a 0-key partition by.
     FileSize,
+    /// Split into `num_buckets` partitions by the hash of the keys. The hash matches that of
+    /// Spark or Hive, so that the output can be read as a bucketed table by either.
+    Bucketed {
+        keys: Vec<Expr>,
+        num_buckets: u32,
+        hash: BucketHash,
+    },
+}
+
+/// Hash function that assigns rows to buckets.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub enum BucketHash {
+    /// Spark's Murmur3 hash.
+    #[default]
+    Spark,
+    /// Hive's original hash, i.e. tables with `bucketing_version=1`. The Murmur3 based hash of
+    /// `bucketing_version=2` (the default since Hive 3) is not supported.
+    Hive,
 }
 
 #[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
@@ -290,6 +319,12 @@ pub enum PartitionStrategyIR {
     ///
     /// Semantically equivalent to a 0-key partition by.
     FileSize,
+    /// Split into `num_buckets` partitions by the hash of the keys.
+    Bucketed {
+        keys: Vec<ExprIR>,
+        num_buckets: u32,
+        hash: BucketHash,
+    },
 }
 
 #[cfg(feature = "cse")]
@@ -310,6 +345,18 @@ impl PartitionStrategyIR {
                 keys_pre_grouped.hash(state);
             },
             Self::FileSize => {},
+            Self::Bucketed {
+                keys,
+                num_buckets,
+                hash,
+            } => {
+                for k in keys {
+                    k.traverse_and_hash(expr_arena, state);
+                }
+
+                num_buckets.hash(state);
+                hash.hash(state);
+            },
         }
     }
 }
@@ -352,6 +399,7 @@ pub struct PartitionedSinkOptionsIR {
     pub unified_sink_args: UnifiedSinkArgs,
     pub max_rows_per_file: IdxSize,
     pub approximate_bytes_per_file: u64,
+    pub manifest_path: Option<PlRefPath>,
 }
 
 impl PartitionedSinkOptionsIR {
@@ -365,6 +413,11 @@ impl PartitionedSinkOptionsIR {
                 keys,
                 include_keys: _,
                 keys_pre_grouped: _,
+            }
+            | PartitionStrategyIR::Bucketed {
+                keys,
+                num_buckets: _,
+                hash: _,
             } => keys.iter(),
             PartitionStrategyIR::FileSize => [][..].iter(),
         }
@@ -409,7 +462,10 @@ impl PartitionedSinkOptionsIR {
                     Cow::Owned(out)
                 }
             },
-            PartitionStrategyIR::FileSize => Cow::Borrowed(input_schema),
+            // The keys only determine the bucket, the files contain the unmodified input.
+            PartitionStrategyIR::FileSize | PartitionStrategyIR::Bucketed { .. } => {
+                Cow::Borrowed(input_schema)
+            },
         })
     }
 
@@ -423,6 +479,7 @@ impl PartitionedSinkOptionsIR {
             unified_sink_args,
             max_rows_per_file,
             approximate_bytes_per_file,
+            manifest_path,
         } = self;
 
         base_path.hash(state);
@@ -432,6 +489,7 @@ impl PartitionedSinkOptionsIR {
         unified_sink_args.hash(state);
         max_rows_per_file.hash(state);
         approximate_bytes_per_file.hash(state);
+        manifest_path.hash(state);
     }
 }
 
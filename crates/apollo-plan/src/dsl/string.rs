@@ -150,6 +150,13 @@ impl StringNameSpace {
         self.0.map_unary(StringFunction::Base64Decode(strict))
     }
 
+    /// Compute a stable digest of the UTF-8 bytes of each string, as hexadecimal strings if `hex`
+    /// is set.
+    #[cfg(feature = "hash_digest")]
+    pub fn digest(self, algorithm: HashAlgorithm, hex: bool) -> Expr {
+        self.0.map_unary(StringFunction::Digest { algorithm, hex })
+    }
+
     /// Extract a regex pattern from the a string value. If `group_index` is out of bounds, null is returned.
     pub fn extract(self, pat: Expr, group_index: usize) -> Expr {
         self.0.map_binary(StringFunction::Extract(group_index), pat)
@@ -313,6 +320,15 @@ impl StringNameSpace {
         self.0.map_unary(StringFunction::ToDecimal { scale })
     }
 
+    /// Convert a String column into an Interval column.
+    ///
+    /// Strings look like `"1mo 2d 3h"` or `"1 year 2 months"`. If `strict`,
+    /// strings that can't be parsed raise an error, otherwise they become null.
+    #[cfg(feature = "dtype-interval")]
+    pub fn to_interval(self, strict: bool) -> Expr {
+        self.0.map_unary(StringFunction::ToInterval { strict })
+    }
+
     /// Concat the values into a string array.
     /// # Arguments
     ///
@@ -420,6 +436,89 @@ impl StringNameSpace {
         self.0.map_unary(StringFunction::Reverse)
     }
 
+    #[cfg(feature = "string_similarity")]
+    /// Levenshtein distance to `other`, distances above `max_distance` are reported as
+    /// `max_distance + 1`.
+    pub fn levenshtein_distance(self, other: Expr, max_distance: Option<u32>) -> Expr {
+        self.0
+            .map_binary(StringFunction::LevenshteinDistance { max_distance }, other)
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Damerau-Levenshtein distance to `other`, distances above `max_distance` are reported as
+    /// `max_distance + 1`.
+    pub fn damerau_levenshtein_distance(self, other: Expr, max_distance: Option<u32>) -> Expr {
+        self.0.map_binary(
+            StringFunction::DamerauLevenshteinDistance { max_distance },
+            other,
+        )
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Number of positions at which the string differs from `other`, null if the lengths differ.
+    pub fn hamming_distance(self, other: Expr) -> Expr {
+        self.0.map_binary(StringFunction::HammingDistance, other)
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Jaro-Winkler similarity to `other`.
+    pub fn jaro_winkler_similarity(self, other: Expr) -> Expr {
+        self.0
+            .map_binary(StringFunction::JaroWinklerSimilarity, other)
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Jaccard similarity of the sets of `q`-grams of the string and `other`.
+    pub fn jaccard_similarity(self, other: Expr, q: usize) -> Expr {
+        self.0
+            .map_binary(StringFunction::JaccardSimilarity { q }, other)
+    }
+
+    #[cfg(feature = "string_url")]
+    /// Parse URLs into a struct of their scheme, host, port, path, query and fragment.
+    pub fn parse_url(self) -> Expr {
+        self.0.map_unary(StringFunction::ParseUrl)
+    }
+
+    #[cfg(feature = "string_url")]
+    /// The decoded value of the first query parameter called `name`.
+    pub fn url_query_param(self, name: PlSmallStr) -> Expr {
+        self.0.map_unary(StringFunction::UrlQueryParam { name })
+    }
+
+    #[cfg(feature = "string_ip")]
+    /// Parse IPv4 addresses into 32-bit integers.
+    pub fn ipv4_to_int(self) -> Expr {
+        self.0.map_unary(StringFunction::Ipv4ToInt)
+    }
+
+    #[cfg(feature = "string_ip")]
+    /// Parse IP addresses into 128-bit integers, IPv4 addresses are mapped to `::ffff:a.b.c.d`.
+    pub fn ipv6_to_int(self) -> Expr {
+        self.0.map_unary(StringFunction::Ipv6ToInt)
+    }
+
+    #[cfg(feature = "string_ip")]
+    /// Parse IP addresses into their bytes in network order.
+    pub fn ip_to_binary(self) -> Expr {
+        self.0.map_unary(StringFunction::IpToBinary)
+    }
+
+    #[cfg(feature = "string_ip")]
+    /// Whether the IP address is in the network given in CIDR notation by `cidr`.
+    pub fn ip_in_cidr(self, cidr: Expr) -> Expr {
+        self.0.map_binary(StringFunction::IpInCidr, cidr)
+    }
+
+    #[cfg(feature = "string_ip")]
+    /// Mask IP addresses to their network, keeping the first `ipv4_prefix` or `ipv6_prefix` bits.
+    pub fn ip_mask(self, ipv4_prefix: u8, ipv6_prefix: u8) -> Expr {
+        self.0.map_unary(StringFunction::IpMask {
+            ipv4_prefix,
+            ipv6_prefix,
+        })
+    }
+
     /// Remove leading and trailing characters, or whitespace if matches is None.
     pub fn strip_chars(self, matches: Expr) -> Expr {
         self.0.map_binary(StringFunction::StripChars, matches)
@@ -0,0 +1,16 @@
+use super::*;
+
+/// Specialized expressions for Union dtypes.
+pub struct UnionNameSpace(pub(crate) Expr);
+
+impl UnionNameSpace {
+    /// Get the type id of the member selected in every row.
+    pub fn tag(self) -> Expr {
+        self.0.map_unary(UnionFunction::Tag)
+    }
+
+    /// Get the values of the member `name`, or `null` where another member is selected.
+    pub fn field(self, name: impl Into<PlSmallStr>) -> Expr {
+        self.0.map_unary(UnionFunction::Field(name.into()))
+    }
+}
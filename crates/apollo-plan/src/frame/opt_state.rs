@@ -37,6 +37,11 @@ bitflags! {
         /// Check if operations are order dependent and unset maintaining_order if
         /// the order would not be observed.
         const CHECK_ORDER_OBSERVE = 1 << 15;
+        /// Reorder trees of inner joins based on the estimated cardinalities of their inputs.
+        const JOIN_REORDER = 1 << 16;
+        /// Look up and store subplan results in the cross-query result cache. This has no effect
+        /// unless the cache is enabled through `APOLLO_RESULT_CACHE_SIZE`.
+        const RESULT_CACHE = 1 << 17;
     }
 }
 
@@ -72,11 +77,20 @@ impl OptFlags {
     pub fn fast_projection(&self) -> bool {
         self.contains(OptFlags::FAST_PROJECTION)
     }
+    pub fn join_reorder(&self) -> bool {
+        self.contains(OptFlags::JOIN_REORDER)
+    }
+    pub fn result_cache(&self) -> bool {
+        self.contains(OptFlags::RESULT_CACHE)
+    }
 }
 
 impl Default for OptFlags {
     fn default() -> Self {
-        Self::from_bits_truncate(u32::MAX) & !Self::NEW_STREAMING & !Self::EAGER
+        Self::from_bits_truncate(u32::MAX)
+            & !Self::NEW_STREAMING
+            & !Self::EAGER
+            & !Self::JOIN_REORDER
     }
 }
 
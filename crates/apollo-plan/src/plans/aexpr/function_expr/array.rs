@@ -39,6 +39,10 @@ pub enum IRArrayFunction {
     Slice(i64, i64),
     #[cfg(feature = "array_to_struct")]
     ToStruct(Option<DslNameGenerator>),
+    #[cfg(feature = "array_vector")]
+    Metric(VectorMetric),
+    #[cfg(feature = "array_vector")]
+    Normalize,
 }
 
 impl<'a> FieldsMapper<'a> {
@@ -118,6 +122,17 @@ impl IRArrayFunction {
                     .collect::<ApolloResult<Vec<Field>>>()
                     .map(DataType::Struct)
             }),
+            #[cfg(feature = "array_vector")]
+            Metric(_) => mapper
+                .ensure_is_array()?
+                .try_map_dtype(|dtype| Ok(vector_float_dtype(array_inner_dtype(dtype)?))),
+            #[cfg(feature = "array_vector")]
+            Normalize => mapper.ensure_is_array()?.try_map_dtype(|dtype| {
+                let DataType::Array(inner, width) = dtype else {
+                    apollo_bail!(InvalidOperation: "expected Array type, got: {dtype}")
+                };
+                Ok(DataType::Array(Box::new(vector_float_dtype(inner)), *width))
+            }),
         }
     }
 
@@ -154,10 +169,20 @@ impl IRArrayFunction {
             A::Explode { .. } => FunctionOptions::row_separable(),
             #[cfg(feature = "array_to_struct")]
             A::ToStruct(_) => FunctionOptions::elementwise(),
+            #[cfg(feature = "array_vector")]
+            A::Metric(_) | A::Normalize => FunctionOptions::elementwise(),
         }
     }
 }
 
+#[cfg(feature = "array_vector")]
+fn array_inner_dtype(datatype: &DataType) -> ApolloResult<&DataType> {
+    match datatype {
+        DataType::Array(inner, _) => Ok(inner),
+        _ => apollo_bail!(InvalidOperation: "expected Array type, got: {datatype}"),
+    }
+}
+
 fn map_array_dtype_to_list_dtype(datatype: &DataType) -> ApolloResult<DataType> {
     if let DataType::Array(inner, _) = datatype {
         Ok(DataType::List(inner.clone()))
@@ -222,6 +247,15 @@ impl Display for IRArrayFunction {
             Explode { .. } => "explode",
             #[cfg(feature = "array_to_struct")]
             ToStruct(_) => "to_struct",
+            #[cfg(feature = "array_vector")]
+            Metric(metric) => match metric {
+                VectorMetric::Dot => "dot",
+                VectorMetric::Cosine => "cosine_similarity",
+                VectorMetric::Euclidean => "euclidean_distance",
+                VectorMetric::Manhattan => "manhattan_distance",
+            },
+            #[cfg(feature = "array_vector")]
+            Normalize => "normalize",
         };
         write!(f, "arr.{name}")
     }
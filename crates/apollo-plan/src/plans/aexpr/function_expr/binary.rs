@@ -15,6 +15,11 @@ pub enum IRBinaryFunction {
     #[cfg(feature = "binary_encoding")]
     Base64Encode,
     Size,
+    #[cfg(feature = "hash_digest")]
+    Digest {
+        algorithm: HashAlgorithm,
+        hex: bool,
+    },
     #[cfg(feature = "binary_encoding")]
     Reinterpret(DataType, bool),
     Slice,
@@ -33,6 +38,10 @@ impl IRBinaryFunction {
             #[cfg(feature = "binary_encoding")]
             HexEncode | Base64Encode => mapper.with_dtype(DataType::String),
             Size => mapper.with_dtype(DataType::UInt32),
+            #[cfg(feature = "hash_digest")]
+            Digest { hex: true, .. } => mapper.with_dtype(DataType::String),
+            #[cfg(feature = "hash_digest")]
+            Digest { hex: false, .. } => mapper.with_same_dtype(),
             #[cfg(feature = "binary_encoding")]
             Reinterpret(dtype, _) => mapper.with_dtype(dtype.clone()),
             Slice | Head | Tail => mapper.with_same_dtype(),
@@ -46,6 +55,8 @@ impl IRBinaryFunction {
                 FunctionOptions::elementwise().with_supertyping(Default::default())
             },
             B::Size => FunctionOptions::elementwise(),
+            #[cfg(feature = "hash_digest")]
+            B::Digest { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "binary_encoding")]
             B::HexDecode(_)
             | B::HexEncode
@@ -73,6 +84,8 @@ impl Display for IRBinaryFunction {
             #[cfg(feature = "binary_encoding")]
             Base64Encode => "base64_encode",
             Size => "size_bytes",
+            #[cfg(feature = "hash_digest")]
+            Digest { .. } => "digest",
             #[cfg(feature = "binary_encoding")]
             Reinterpret(_, _) => "reinterpret",
             Slice => "slice",
@@ -1,3 +1,5 @@
+use apollo_core::datatypes::extension::{UuidExtensionType, UuidVersion};
+
 use super::*;
 
 #[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
@@ -5,6 +7,7 @@ use super::*;
 pub enum IRExtensionFunction {
     To(DataType),
     Storage,
+    GenerateUuid(UuidVersion),
 }
 
 impl IRExtensionFunction {
@@ -16,6 +19,7 @@ impl IRExtensionFunction {
                 DataType::Extension(_, storage) => (**storage).clone(),
                 dt => dt.clone(),
             }),
+            GenerateUuid(_) => mapper.with_dtype(UuidExtensionType::dtype()),
         }
     }
 
@@ -24,6 +28,9 @@ impl IRExtensionFunction {
         match self {
             To(_dtype) => FunctionOptions::elementwise(),
             Storage => FunctionOptions::elementwise(),
+            GenerateUuid(_) => {
+                FunctionOptions::row_separable().with_flags(|f| f | FunctionFlags::ALLOW_RENAME)
+            },
         }
     }
 }
@@ -34,6 +41,7 @@ impl Display for IRExtensionFunction {
         match self {
             To(dtype) => write!(f, "ext.to({dtype:?})"),
             Storage => write!(f, "ext.storage()"),
+            GenerateUuid(version) => write!(f, "generate_uuid({version:?})"),
         }
     }
 }
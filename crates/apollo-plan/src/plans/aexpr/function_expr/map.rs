@@ -0,0 +1,95 @@
+use super::*;
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IRMapFunction {
+    Get,
+    Keys,
+    Values,
+    ContainsKey,
+    FromLists,
+    ToStruct(Arc<[PlSmallStr]>),
+}
+
+impl<'a> FieldsMapper<'a> {
+    fn map_key_value_dtypes(&self) -> ApolloResult<(&'a DataType, &'a DataType)> {
+        match self.args()[0].dtype() {
+            DataType::Map(key, value) => Ok((key, value)),
+            dt => apollo_bail!(op = "map", got = dt, expected = "Map"),
+        }
+    }
+}
+
+impl IRMapFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> ApolloResult<Field> {
+        use IRMapFunction::*;
+        match self {
+            Get => {
+                let (_, value) = mapper.map_key_value_dtypes()?;
+                mapper.with_dtype(value.clone())
+            },
+            Keys => {
+                let (key, _) = mapper.map_key_value_dtypes()?;
+                mapper.with_dtype(DataType::List(Box::new(key.clone())))
+            },
+            Values => {
+                let (_, value) = mapper.map_key_value_dtypes()?;
+                mapper.with_dtype(DataType::List(Box::new(value.clone())))
+            },
+            ContainsKey => {
+                mapper.map_key_value_dtypes()?;
+                mapper.with_dtype(DataType::Boolean)
+            },
+            FromLists => {
+                let [keys, values] = mapper.args() else {
+                    apollo_bail!(InvalidOperation: "map.from_lists expects a list of keys and a list of values")
+                };
+                match (keys.dtype(), values.dtype()) {
+                    (DataType::List(key), DataType::List(value)) => {
+                        mapper.with_dtype(DataType::Map(key.clone(), value.clone()))
+                    },
+                    (DataType::List(_), dt) | (dt, _) => {
+                        apollo_bail!(op = "map.from_lists", got = dt, expected = "List")
+                    },
+                }
+            },
+            ToStruct(names) => {
+                let (key, value) = mapper.map_key_value_dtypes()?;
+                apollo_ensure!(
+                    key.is_string(),
+                    InvalidOperation: "map.to_struct requires String keys, got {key}"
+                );
+                mapper.with_dtype(DataType::Struct(
+                    names
+                        .iter()
+                        .map(|name| Field::new(name.clone(), value.clone()))
+                        .collect(),
+                ))
+            },
+        }
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        FunctionOptions::elementwise()
+    }
+}
+
+impl Display for IRMapFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IRMapFunction::*;
+        match self {
+            Get => write!(f, "map.get"),
+            Keys => write!(f, "map.keys"),
+            Values => write!(f, "map.values"),
+            ContainsKey => write!(f, "map.contains_key"),
+            FromLists => write!(f, "map.from_lists"),
+            ToStruct(names) => write!(f, "map.to_struct({names:?})"),
+        }
+    }
+}
+
+impl From<IRMapFunction> for IRFunctionExpr {
+    fn from(func: IRMapFunction) -> Self {
+        IRFunctionExpr::MapExpr(func)
+    }
+}
@@ -19,6 +19,8 @@ mod extension;
 #[cfg(feature = "fused")]
 mod fused;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 #[cfg(feature = "ffi_plugin")]
 pub mod plugin;
 mod pow;
@@ -38,6 +40,8 @@ mod strings;
 mod struct_;
 #[cfg(feature = "trigonometry")]
 mod trigonometry;
+#[cfg(feature = "dtype-union")]
+mod union;
 
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
@@ -70,6 +74,8 @@ pub use self::cat::IRCategoricalFunction;
 pub use self::datetime::IRTemporalFunction;
 #[cfg(feature = "dtype-extension")]
 pub use self::extension::IRExtensionFunction;
+#[cfg(feature = "dtype-map")]
+pub use self::map::IRMapFunction;
 pub use self::pow::IRPowFunction;
 #[cfg(feature = "range")]
 pub use self::range::IRRangeFunction;
@@ -86,6 +92,8 @@ pub use self::strings::TZ_AWARE_RE;
 pub use self::struct_::IRStructFunction;
 #[cfg(feature = "trigonometry")]
 pub use self::trigonometry::IRTrigonometricFunction;
+#[cfg(feature = "dtype-union")]
+pub use self::union::IRUnionFunction;
 use super::*;
 
 #[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
@@ -100,10 +108,14 @@ pub enum IRFunctionExpr {
     #[cfg(feature = "dtype-extension")]
     Extension(IRExtensionFunction),
     ListExpr(IRListFunction),
+    #[cfg(feature = "dtype-map")]
+    MapExpr(IRMapFunction),
     #[cfg(feature = "strings")]
     StringExpr(IRStringFunction),
     #[cfg(feature = "dtype-struct")]
     StructExpr(IRStructFunction),
+    #[cfg(feature = "dtype-union")]
+    UnionExpr(IRUnionFunction),
     #[cfg(feature = "temporal")]
     TemporalExpr(IRTemporalFunction),
     #[cfg(feature = "bitwise")]
@@ -238,6 +250,11 @@ pub enum IRFunctionExpr {
     UniqueCounts,
     #[cfg(feature = "approx_unique")]
     ApproxNUnique,
+    #[cfg(feature = "approx_quantile")]
+    ApproxQuantile {
+        quantile: f64,
+        accuracy: u32,
+    },
     Coalesce,
     #[cfg(feature = "diff")]
     Diff(NullBehavior),
@@ -403,10 +420,14 @@ impl Hash for IRFunctionExpr {
             #[cfg(feature = "dtype-extension")]
             Extension(f) => f.hash(state),
             ListExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(f) => f.hash(state),
             #[cfg(feature = "strings")]
             StringExpr(f) => f.hash(state),
             #[cfg(feature = "dtype-struct")]
             StructExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-union")]
+            UnionExpr(f) => f.hash(state),
             #[cfg(feature = "temporal")]
             TemporalExpr(f) => f.hash(state),
             #[cfg(feature = "bitwise")]
@@ -582,6 +603,11 @@ impl Hash for IRFunctionExpr {
             UniqueCounts => {},
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => {},
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile { quantile, accuracy } => {
+                quantile.to_bits().hash(state);
+                accuracy.hash(state);
+            },
             Coalesce => {},
             #[cfg(feature = "pct_change")]
             PctChange => {},
@@ -707,10 +733,14 @@ impl Display for IRFunctionExpr {
             #[cfg(feature = "dtype-extension")]
             Extension(func) => return write!(f, "{func}"),
             ListExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "strings")]
             StringExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "dtype-struct")]
             StructExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-union")]
+            UnionExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "temporal")]
             TemporalExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "bitwise")]
@@ -810,6 +840,8 @@ impl Display for IRFunctionExpr {
             Reverse => "reverse",
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => "approx_n_unique",
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile { .. } => "approx_quantile",
             Coalesce => "coalesce",
             #[cfg(feature = "diff")]
             Diff(_) => "diff",
@@ -1003,10 +1035,14 @@ impl IRFunctionExpr {
             #[cfg(feature = "dtype-extension")]
             F::Extension(e) => e.function_options(),
             F::ListExpr(e) => e.function_options(),
+            #[cfg(feature = "dtype-map")]
+            F::MapExpr(e) => e.function_options(),
             #[cfg(feature = "strings")]
             F::StringExpr(e) => e.function_options(),
             #[cfg(feature = "dtype-struct")]
             F::StructExpr(e) => e.function_options(),
+            #[cfg(feature = "dtype-union")]
+            F::UnionExpr(e) => e.function_options(),
             #[cfg(feature = "temporal")]
             F::TemporalExpr(e) => e.function_options(),
             #[cfg(feature = "bitwise")]
@@ -1126,6 +1162,10 @@ impl IRFunctionExpr {
             F::ApproxNUnique => {
                 FunctionOptions::aggregation().flag(FunctionFlags::NON_ORDER_OBSERVING)
             },
+            #[cfg(feature = "approx_quantile")]
+            F::ApproxQuantile { .. } => {
+                FunctionOptions::aggregation().flag(FunctionFlags::NON_ORDER_OBSERVING)
+            },
             F::Coalesce => FunctionOptions::elementwise()
                 .with_flags(|f| f | FunctionFlags::INPUT_WILDCARD_EXPANSION)
                 .with_supertyping(Default::default()),
@@ -21,10 +21,14 @@ impl IRFunctionExpr {
             #[cfg(feature = "dtype-extension")]
             Extension(func) => func.get_field(mapper),
             ListExpr(func) => func.get_field(mapper),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => func.get_field(mapper),
             #[cfg(feature = "strings")]
             StringExpr(s) => s.get_field(mapper),
             #[cfg(feature = "dtype-struct")]
             StructExpr(s) => s.get_field(mapper),
+            #[cfg(feature = "dtype-union")]
+            UnionExpr(func) => func.get_field(mapper),
             #[cfg(feature = "temporal")]
             TemporalExpr(fun) => fun.get_field(mapper),
             #[cfg(feature = "bitwise")]
@@ -216,6 +220,8 @@ impl IRFunctionExpr {
             CumMax { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "hist")]
             Hist {
                 include_category,
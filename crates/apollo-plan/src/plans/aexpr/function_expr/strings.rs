@@ -71,6 +71,46 @@ pub enum IRStringFunction {
     },
     #[cfg(feature = "string_reverse")]
     Reverse,
+    #[cfg(feature = "string_similarity")]
+    LevenshteinDistance {
+        max_distance: Option<u32>,
+    },
+    #[cfg(feature = "string_similarity")]
+    DamerauLevenshteinDistance {
+        max_distance: Option<u32>,
+    },
+    #[cfg(feature = "string_similarity")]
+    HammingDistance,
+    #[cfg(feature = "string_similarity")]
+    JaroWinklerSimilarity,
+    #[cfg(feature = "string_similarity")]
+    JaccardSimilarity {
+        q: usize,
+    },
+    #[cfg(feature = "hash_digest")]
+    Digest {
+        algorithm: HashAlgorithm,
+        hex: bool,
+    },
+    #[cfg(feature = "string_url")]
+    ParseUrl,
+    #[cfg(feature = "string_url")]
+    UrlQueryParam {
+        name: PlSmallStr,
+    },
+    #[cfg(feature = "string_ip")]
+    Ipv4ToInt,
+    #[cfg(feature = "string_ip")]
+    Ipv6ToInt,
+    #[cfg(feature = "string_ip")]
+    IpToBinary,
+    #[cfg(feature = "string_ip")]
+    IpInCidr,
+    #[cfg(feature = "string_ip")]
+    IpMask {
+        ipv4_prefix: u8,
+        ipv6_prefix: u8,
+    },
     #[cfg(feature = "string_pad")]
     PadStart {
         fill_char: char,
@@ -116,6 +156,10 @@ pub enum IRStringFunction {
     ToDecimal {
         scale: usize,
     },
+    #[cfg(feature = "dtype-interval")]
+    ToInterval {
+        strict: bool,
+    },
     #[cfg(feature = "nightly")]
     Titlecase,
     Uppercase,
@@ -177,6 +221,32 @@ impl IRStringFunction {
             Normalize { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "string_reverse")]
             Reverse => mapper.with_same_dtype(),
+            #[cfg(feature = "string_similarity")]
+            LevenshteinDistance { .. } | DamerauLevenshteinDistance { .. } | HammingDistance => {
+                mapper.with_dtype(DataType::UInt32)
+            },
+            #[cfg(feature = "string_similarity")]
+            JaroWinklerSimilarity | JaccardSimilarity { .. } => {
+                mapper.with_dtype(DataType::Float64)
+            },
+            #[cfg(feature = "hash_digest")]
+            Digest { hex: true, .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "hash_digest")]
+            Digest { hex: false, .. } => mapper.with_dtype(DataType::Binary),
+            #[cfg(feature = "string_url")]
+            ParseUrl => mapper.with_dtype(DataType::Struct(url_fields())),
+            #[cfg(feature = "string_url")]
+            UrlQueryParam { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "string_ip")]
+            Ipv4ToInt => mapper.with_dtype(DataType::UInt32),
+            #[cfg(feature = "string_ip")]
+            Ipv6ToInt => mapper.with_dtype(DataType::UInt128),
+            #[cfg(feature = "string_ip")]
+            IpToBinary => mapper.with_dtype(DataType::Binary),
+            #[cfg(feature = "string_ip")]
+            IpInCidr => mapper.with_dtype(DataType::Boolean),
+            #[cfg(feature = "string_ip")]
+            IpMask { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "temporal")]
             Strptime(dtype, options) => match dtype {
                 #[cfg(feature = "dtype-datetime")]
@@ -202,6 +272,8 @@ impl IRStringFunction {
             Titlecase => mapper.with_same_dtype(),
             #[cfg(feature = "dtype-decimal")]
             ToDecimal { scale } => mapper.with_dtype(DataType::Decimal(DEC128_MAX_PREC, *scale)),
+            #[cfg(feature = "dtype-interval")]
+            ToInterval { .. } => mapper.with_dtype(DataType::Interval),
             #[cfg(feature = "string_encoding")]
             HexEncode => mapper.with_same_dtype(),
             #[cfg(feature = "binary_encoding")]
@@ -276,6 +348,22 @@ impl IRStringFunction {
             S::Normalize { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "string_reverse")]
             S::Reverse => FunctionOptions::elementwise(),
+            #[cfg(feature = "string_similarity")]
+            S::LevenshteinDistance { .. }
+            | S::DamerauLevenshteinDistance { .. }
+            | S::HammingDistance
+            | S::JaroWinklerSimilarity
+            | S::JaccardSimilarity { .. } => {
+                FunctionOptions::elementwise().with_supertyping(Default::default())
+            },
+            #[cfg(feature = "hash_digest")]
+            S::Digest { .. } => FunctionOptions::elementwise(),
+            #[cfg(feature = "string_url")]
+            S::ParseUrl | S::UrlQueryParam { .. } => FunctionOptions::elementwise(),
+            #[cfg(feature = "string_ip")]
+            S::Ipv4ToInt | S::Ipv6ToInt | S::IpToBinary | S::IpInCidr | S::IpMask { .. } => {
+                FunctionOptions::elementwise()
+            },
             #[cfg(feature = "temporal")]
             S::Strptime(_, options) if options.format.is_some() => FunctionOptions::elementwise(),
             #[cfg(feature = "temporal")]
@@ -285,6 +373,8 @@ impl IRStringFunction {
             S::Titlecase => FunctionOptions::elementwise(),
             #[cfg(feature = "dtype-decimal")]
             S::ToDecimal { .. } => FunctionOptions::elementwise(),
+            #[cfg(feature = "dtype-interval")]
+            S::ToInterval { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "string_encoding")]
             S::HexEncode | S::Base64Encode => FunctionOptions::elementwise(),
             #[cfg(feature = "binary_encoding")]
@@ -360,6 +450,32 @@ impl Display for IRStringFunction {
             Normalize { .. } => "normalize",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
+            #[cfg(feature = "string_similarity")]
+            LevenshteinDistance { .. } => "levenshtein_distance",
+            #[cfg(feature = "string_similarity")]
+            DamerauLevenshteinDistance { .. } => "damerau_levenshtein_distance",
+            #[cfg(feature = "string_similarity")]
+            HammingDistance => "hamming_distance",
+            #[cfg(feature = "string_similarity")]
+            JaroWinklerSimilarity => "jaro_winkler_similarity",
+            #[cfg(feature = "string_similarity")]
+            JaccardSimilarity { .. } => "jaccard_similarity",
+            #[cfg(feature = "hash_digest")]
+            Digest { .. } => "digest",
+            #[cfg(feature = "string_url")]
+            ParseUrl => "parse_url",
+            #[cfg(feature = "string_url")]
+            UrlQueryParam { .. } => "url_query_param",
+            #[cfg(feature = "string_ip")]
+            Ipv4ToInt => "ipv4_to_int",
+            #[cfg(feature = "string_ip")]
+            Ipv6ToInt => "ipv6_to_int",
+            #[cfg(feature = "string_ip")]
+            IpToBinary => "ip_to_binary",
+            #[cfg(feature = "string_ip")]
+            IpInCidr => "ip_in_cidr",
+            #[cfg(feature = "string_ip")]
+            IpMask { .. } => "ip_mask",
             #[cfg(feature = "string_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
@@ -406,6 +522,8 @@ impl Display for IRStringFunction {
             Titlecase => "to_titlecase",
             #[cfg(feature = "dtype-decimal")]
             ToDecimal { .. } => "to_decimal",
+            #[cfg(feature = "dtype-interval")]
+            ToInterval { .. } => "to_interval",
             Uppercase => "to_uppercase",
             #[cfg(feature = "string_pad")]
             ZFill => "zfill",
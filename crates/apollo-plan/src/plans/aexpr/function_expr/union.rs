@@ -0,0 +1,56 @@
+use super::*;
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IRUnionFunction {
+    Tag,
+    Field(PlSmallStr),
+}
+
+impl<'a> FieldsMapper<'a> {
+    fn union_members(&self) -> ApolloResult<&'a [Field]> {
+        match self.args()[0].dtype() {
+            DataType::Union(fields, _) => Ok(fields),
+            dt => apollo_bail!(op = "union", got = dt, expected = "Union"),
+        }
+    }
+}
+
+impl IRUnionFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> ApolloResult<Field> {
+        use IRUnionFunction::*;
+        match self {
+            Tag => {
+                mapper.union_members()?;
+                mapper.with_dtype(DataType::Int8)
+            },
+            Field(name) => {
+                let members = mapper.union_members()?;
+                let Some(fld) = members.iter().find(|fld| fld.name() == name) else {
+                    apollo_bail!(StructFieldNotFound: "{name}")
+                };
+                Ok(fld.clone())
+            },
+        }
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        FunctionOptions::elementwise()
+    }
+}
+
+impl Display for IRUnionFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IRUnionFunction::*;
+        match self {
+            Tag => write!(f, "union.tag"),
+            Field(name) => write!(f, "union.field({name})"),
+        }
+    }
+}
+
+impl From<IRUnionFunction> for IRFunctionExpr {
+    fn from(func: IRUnionFunction) -> Self {
+        IRFunctionExpr::UnionExpr(func)
+    }
+}
@@ -90,6 +90,11 @@ impl AExpr {
                     List(inner) => Field::new(field.name().clone(), *inner.clone()),
                     #[cfg(feature = "dtype-array")]
                     Array(inner, ..) => Field::new(field.name().clone(), *inner.clone()),
+                    #[cfg(feature = "dtype-map")]
+                    Map(key, value) => Field::new(
+                        field.name().clone(),
+                        DataType::map_entries_dtype(key, value),
+                    ),
                     _ => field,
                 };
 
@@ -512,6 +517,10 @@ fn get_arithmetic_field(
                 | (Date, Duration(_))
                 | (Duration(_), Time)
                 | (Time, Duration(_)) => try_get_supertype(left_field.dtype(), &right_type)?,
+                #[cfg(feature = "dtype-interval")]
+                (Datetime(_, _) | Date, Interval) => left_field.dtype.clone(),
+                #[cfg(feature = "dtype-interval")]
+                (Interval, Interval | Duration(_)) => Interval,
                 (Datetime(tu, _), Date) | (Date, Datetime(tu, _)) => Duration(*tu),
                 // T - T != T if T is a datetime / date
                 (Datetime(tul, _), Datetime(tur, _)) => Duration(get_time_units(tul, tur)),
@@ -584,6 +593,12 @@ fn get_arithmetic_field(
                 | (Date, Duration(_))
                 | (Duration(_), Time)
                 | (Time, Duration(_)) => try_get_supertype(left_field.dtype(), &right_type)?,
+                #[cfg(feature = "dtype-interval")]
+                (Datetime(_, _) | Date, Interval) => left_field.dtype.clone(),
+                #[cfg(feature = "dtype-interval")]
+                (Interval, dt @ (Datetime(_, _) | Date)) => dt.clone(),
+                #[cfg(feature = "dtype-interval")]
+                (Interval, Interval | Duration(_)) | (Duration(_), Interval) => Interval,
                 (_, Datetime(_, _))
                 | (Datetime(_, _), _)
                 | (_, Date)
@@ -649,6 +664,11 @@ fn get_arithmetic_field(
                 | (_, Date) => {
                     apollo_bail!(InvalidOperation: "{} not allowed on {} and {}", op, left_field.dtype, right_type)
                 },
+                #[cfg(feature = "dtype-interval")]
+                (Interval, r) | (r, Interval) if r.is_integer() && op == Operator::Multiply => {
+                    left_field.coerce(Interval);
+                    return Ok(left_field);
+                },
                 (Duration(_), Duration(_)) => {
                     // True divide handled somewhere else
                     apollo_bail!(InvalidOperation: "{} not allowed on {} and {}", op, left_field.dtype, right_type)
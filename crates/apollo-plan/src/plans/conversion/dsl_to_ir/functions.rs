@@ -59,6 +59,10 @@ pub(super) fn convert_functions(
                 A::Slice(offset, length) => IA::Slice(offset, length),
                 #[cfg(feature = "array_to_struct")]
                 A::ToStruct(ng) => IA::ToStruct(ng),
+                #[cfg(feature = "array_vector")]
+                A::Metric(metric) => IA::Metric(metric),
+                #[cfg(feature = "array_vector")]
+                A::Normalize => IA::Normalize,
             })
         },
         F::BinaryExpr(binary_function) => {
@@ -76,6 +80,26 @@ pub(super) fn convert_functions(
                 #[cfg(feature = "binary_encoding")]
                 B::Base64Encode => IB::Base64Encode,
                 B::Size => IB::Size,
+                #[cfg(feature = "hash_digest")]
+                B::Digest { algorithm, hex } => {
+                    // Row digests must be stable across sessions, but categoricals are encoded
+                    // by their physical ids which depend on the order categories were seen in.
+                    let unstable_dtype = match ctx.arena.get(e[0].node()) {
+                        AExpr::Function {
+                            function: I::RowEncode(dts, _),
+                            ..
+                        } => dts
+                            .iter()
+                            .find(|dt| dt.contains_categoricals() || dt.contains_objects()),
+                        _ => None,
+                    };
+                    if let Some(dt) = unstable_dtype {
+                        apollo_bail!(
+                            InvalidOperation: "cannot compute a row digest of dtype {dt}, cast it to String first"
+                        );
+                    }
+                    IB::Digest { algorithm, hex }
+                },
                 #[cfg(feature = "binary_encoding")]
                 B::Reinterpret(dtype_expr, v) => {
                     let dtype = dtype_expr.into_datatype(ctx.schema)?;
@@ -125,6 +149,19 @@ pub(super) fn convert_functions(
                     IE::To(concrete_dtype)
                 },
                 E::Storage => IE::Storage,
+                E::GenerateUuid(version) => IE::GenerateUuid(version),
+            })
+        },
+        #[cfg(feature = "dtype-map")]
+        F::MapExpr(map_function) => {
+            use {IRMapFunction as IM, MapFunction as M};
+            I::MapExpr(match map_function {
+                M::Get => IM::Get,
+                M::Keys => IM::Keys,
+                M::Values => IM::Values,
+                M::ContainsKey => IM::ContainsKey,
+                M::FromLists => IM::FromLists,
+                M::ToStruct(names) => IM::ToStruct(names),
             })
         },
         F::ListExpr(list_function) => {
@@ -247,6 +284,40 @@ pub(super) fn convert_functions(
                 S::Normalize { form } => IS::Normalize { form },
                 #[cfg(feature = "string_reverse")]
                 S::Reverse => IS::Reverse,
+                #[cfg(feature = "string_similarity")]
+                S::LevenshteinDistance { max_distance } => IS::LevenshteinDistance { max_distance },
+                #[cfg(feature = "string_similarity")]
+                S::DamerauLevenshteinDistance { max_distance } => {
+                    IS::DamerauLevenshteinDistance { max_distance }
+                },
+                #[cfg(feature = "string_similarity")]
+                S::HammingDistance => IS::HammingDistance,
+                #[cfg(feature = "string_similarity")]
+                S::JaroWinklerSimilarity => IS::JaroWinklerSimilarity,
+                #[cfg(feature = "string_similarity")]
+                S::JaccardSimilarity { q } => IS::JaccardSimilarity { q },
+                #[cfg(feature = "hash_digest")]
+                S::Digest { algorithm, hex } => IS::Digest { algorithm, hex },
+                #[cfg(feature = "string_url")]
+                S::ParseUrl => IS::ParseUrl,
+                #[cfg(feature = "string_url")]
+                S::UrlQueryParam { name } => IS::UrlQueryParam { name },
+                #[cfg(feature = "string_ip")]
+                S::Ipv4ToInt => IS::Ipv4ToInt,
+                #[cfg(feature = "string_ip")]
+                S::Ipv6ToInt => IS::Ipv6ToInt,
+                #[cfg(feature = "string_ip")]
+                S::IpToBinary => IS::IpToBinary,
+                #[cfg(feature = "string_ip")]
+                S::IpInCidr => IS::IpInCidr,
+                #[cfg(feature = "string_ip")]
+                S::IpMask {
+                    ipv4_prefix,
+                    ipv6_prefix,
+                } => IS::IpMask {
+                    ipv4_prefix,
+                    ipv6_prefix,
+                },
                 #[cfg(feature = "string_pad")]
                 S::PadStart { fill_char } => IS::PadStart { fill_char },
                 #[cfg(feature = "string_pad")]
@@ -292,6 +363,8 @@ pub(super) fn convert_functions(
                 S::Split(v) => IS::Split(v),
                 #[cfg(feature = "dtype-decimal")]
                 S::ToDecimal { scale } => IS::ToDecimal { scale },
+                #[cfg(feature = "dtype-interval")]
+                S::ToInterval { strict } => IS::ToInterval { strict },
                 #[cfg(feature = "nightly")]
                 S::Titlecase => IS::Titlecase,
                 S::Uppercase => IS::Uppercase,
@@ -349,6 +422,14 @@ pub(super) fn convert_functions(
                 S::MapFieldNames(f) => IS::MapFieldNames(f),
             })
         },
+        #[cfg(feature = "dtype-union")]
+        F::UnionExpr(union_function) => {
+            use {IRUnionFunction as IU, UnionFunction as U};
+            I::UnionExpr(match union_function {
+                U::Tag => IU::Tag,
+                U::Field(name) => IU::Field(name),
+            })
+        },
         #[cfg(feature = "temporal")]
         F::TemporalExpr(temporal_function) => {
             use {IRTemporalFunction as IT, TemporalFunction as T};
@@ -852,6 +933,8 @@ pub(super) fn convert_functions(
         F::UniqueCounts => I::UniqueCounts,
         #[cfg(feature = "approx_unique")]
         F::ApproxNUnique => I::ApproxNUnique,
+        #[cfg(feature = "approx_quantile")]
+        F::ApproxQuantile { quantile, accuracy } => I::ApproxQuantile { quantile, accuracy },
         F::Coalesce => I::Coalesce,
         #[cfg(feature = "diff")]
         F::Diff(n) => {
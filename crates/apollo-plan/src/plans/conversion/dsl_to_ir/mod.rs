@@ -17,7 +17,7 @@ use super::stack_opt::ConversionOptimizer;
 use super::*;
 use crate::constants::get_pl_element_name;
 use crate::dsl::PartitionedSinkOptions;
-use crate::dsl::file_provider::{FileProviderType, HivePathProvider};
+use crate::dsl::file_provider::{BucketPathProvider, FileProviderType, HivePathProvider};
 use crate::dsl::functions::{all_horizontal, col};
 use crate::plans::conversion::dsl_to_ir::scans::SourcesToFileInfo;
 
@@ -1359,6 +1359,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> ApolloResult
                     unified_sink_args,
                     max_rows_per_file,
                     approximate_bytes_per_file,
+                    manifest_path,
                 }) => {
                     let expr_to_ir_cx = &mut ExprToIRContext::new_with_opt_eager(
                         ctxt.expr_arena,
@@ -1387,20 +1388,57 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> ApolloResult
                             }
                         },
                         PartitionStrategy::FileSize => PartitionStrategyIR::FileSize,
+                        PartitionStrategy::Bucketed {
+                            keys,
+                            num_buckets,
+                            hash,
+                        } => {
+                            apollo_ensure!(
+                                !keys.is_empty(),
+                                InvalidOperation: "bucketed PartitionBy requires at least one key"
+                            );
+                            apollo_ensure!(
+                                num_buckets > 0,
+                                InvalidOperation: "number of buckets must be greater than 0"
+                            );
+
+                            let keys = to_expr_irs(keys, expr_to_ir_cx)?;
+
+                            apollo_ensure!(
+                                keys.iter().all(|e| is_elementwise_rec(e.node(), ctxt.expr_arena)),
+                                InvalidOperation:
+                                "cannot use non-elementwise expressions for PartitionBy keys"
+                            );
+
+                            PartitionStrategyIR::Bucketed {
+                                keys,
+                                num_buckets,
+                                hash,
+                            }
+                        },
                     };
 
                     let options = PartitionedSinkOptionsIR {
                         base_path,
                         file_path_provider: file_path_provider.unwrap_or_else(|| {
-                            FileProviderType::Hive(HivePathProvider {
-                                extension: PlSmallStr::from_static(file_format.extension()),
-                            })
+                            let extension = PlSmallStr::from_static(file_format.extension());
+
+                            match &partition_strategy {
+                                PartitionStrategyIR::Bucketed { hash, .. } => {
+                                    FileProviderType::Bucket(BucketPathProvider {
+                                        extension,
+                                        hash: *hash,
+                                    })
+                                },
+                                _ => FileProviderType::Hive(HivePathProvider { extension }),
+                            }
                         }),
                         partition_strategy,
                         file_format,
                         unified_sink_args,
                         max_rows_per_file,
                         approximate_bytes_per_file,
+                        manifest_path,
                     };
 
                     #[cfg(feature = "parquet")]
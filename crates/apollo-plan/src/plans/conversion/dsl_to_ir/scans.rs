@@ -59,6 +59,18 @@ pub(super) async fn dsl_to_ir(
                     .expand_paths_with_hive_update(unified_scan_args)
                     .await?
             },
+            #[cfg(feature = "avro")]
+            FileScanDsl::Avro => {
+                sources
+                    .expand_paths_with_hive_update(unified_scan_args)
+                    .await?
+            },
+            #[cfg(feature = "orc")]
+            FileScanDsl::Orc => {
+                sources
+                    .expand_paths_with_hive_update(unified_scan_args)
+                    .await?
+            },
             #[cfg(feature = "csv")]
             FileScanDsl::Csv { .. } => sources.expand_paths(unified_scan_args).await?,
             #[cfg(feature = "json")]
@@ -206,7 +218,12 @@ pub(super) fn insert_row_index_to_schema(
     Ok(())
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "avro",
+    feature = "orc"
+))]
 fn prepare_output_schema(
     mut schema: Schema,
     row_index: Option<&RowIndex>,
@@ -254,6 +271,22 @@ pub(super) async fn parquet_file_info(
                     reader.get_metadata().await?.clone(),
                 )
             })
+        } else if let ScanSourceRef::Path(path) = first_scan_source
+            && apollo_io::parquet::metadata_cache::metadata_cache_enabled()
+        {
+            use apollo_io::parquet::metadata_cache;
+
+            let (version, file) = metadata_cache::open_local_file(path)?;
+            let metadata = match metadata_cache::get(path, &version) {
+                Some(metadata) => metadata,
+                None => metadata_cache::read_local_metadata(path.clone(), version, file)?,
+            };
+
+            (
+                Arc::new(apollo_io::parquet::read::infer_schema(&metadata)?),
+                metadata.num_rows,
+                metadata,
+            )
         } else {
             let memslice = first_scan_source.to_memslice()?;
             let mut reader = ParquetReader::new(std::io::Cursor::new(memslice));
@@ -337,10 +370,107 @@ pub(super) async fn ipc_file_info(
     Ok((file_info, metadata))
 }
 
+#[cfg(feature = "avro")]
+pub(super) async fn avro_file_info(
+    sources: &ScanSources,
+    first_scan_source: ScanSourceRef<'_>,
+    row_index: Option<&RowIndex>,
+    cloud_options: Option<&apollo_io::cloud::CloudOptions>,
+) -> ApolloResult<FileInfo> {
+    use apollo_core::config;
+    use apollo_core::error::feature_gated;
+    use apollo_io::avro::AvroFileMetadata;
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            let sources = sources.clone();
+            assert!(sources.as_paths().is_some());
+
+            feature_gated!("cloud", {
+                Some(
+                    apollo_io::file_cache::init_entries_from_uri_list(
+                        (0..sources.len())
+                            .map(move |i| sources.as_paths().unwrap().get(i).unwrap().clone()),
+                        cloud_options,
+                    )
+                    .await?,
+                )
+            })
+        } else {
+            None
+        }
+    };
+
+    let mem_slice =
+        first_scan_source.to_buffer_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+    let reader_schema = AvroFileMetadata::try_from_bytes(&mem_slice)?.arrow_schema;
+
+    let file_info = FileInfo::new(
+        prepare_output_schema(
+            Schema::from_arrow_schema(reader_schema.as_ref()),
+            row_index,
+        )?,
+        Some(Either::Left(reader_schema)),
+        (None, usize::MAX),
+    );
+
+    Ok(file_info)
+}
+
+#[cfg(feature = "orc")]
+pub(super) async fn orc_file_info(
+    sources: &ScanSources,
+    first_scan_source: ScanSourceRef<'_>,
+    row_index: Option<&RowIndex>,
+    cloud_options: Option<&apollo_io::cloud::CloudOptions>,
+) -> ApolloResult<FileInfo> {
+    use apollo_core::config;
+    use apollo_core::error::feature_gated;
+    use apollo_io::orc::OrcFileMetadata;
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            let sources = sources.clone();
+            assert!(sources.as_paths().is_some());
+
+            feature_gated!("cloud", {
+                Some(
+                    apollo_io::file_cache::init_entries_from_uri_list(
+                        (0..sources.len())
+                            .map(move |i| sources.as_paths().unwrap().get(i).unwrap().clone()),
+                        cloud_options,
+                    )
+                    .await?,
+                )
+            })
+        } else {
+            None
+        }
+    };
+
+    let mem_slice =
+        first_scan_source.to_buffer_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+    let reader_schema = OrcFileMetadata::try_from_bytes(&mem_slice)?.schema;
+
+    let file_info = FileInfo::new(
+        prepare_output_schema(reader_schema.as_ref().clone(), row_index)?,
+        Some(Either::Right(reader_schema)),
+        (None, usize::MAX),
+    );
+
+    Ok(file_info)
+}
+
+/// Detects the dialect of the first source if requested, and infers the schema unless it is
+/// provided. The first source is only fetched once for both.
 #[cfg(feature = "csv")]
 pub async fn csv_file_info(
     sources: &ScanSources,
-    _first_scan_source: ScanSourceRef<'_>,
+    first_scan_source: ScanSourceRef<'_>,
     row_index: Option<&RowIndex>,
     csv_options: &mut CsvReadOptions,
     cloud_options: Option<&apollo_io::cloud::CloudOptions>,
@@ -349,7 +479,7 @@ pub async fn csv_file_info(
     use apollo_core::{POOL, config};
     use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
-    // Holding _first_scan_source should guarantee sources is not empty.
+    // Holding first_scan_source should guarantee sources is not empty.
     debug_assert!(!sources.is_empty());
 
     // TODO:
@@ -359,6 +489,13 @@ pub async fn csv_file_info(
     // prints the error message if paths is empty.
     let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
 
+    // Only the first source is needed if the schema is provided.
+    let n_sources = if csv_options.schema.is_some() {
+        1
+    } else {
+        sources.len()
+    };
+
     let cache_entries = {
         if run_async {
             let sources = sources.clone();
@@ -367,7 +504,7 @@ pub async fn csv_file_info(
             feature_gated!("cloud", {
                 Some(
                     apollo_io::file_cache::init_entries_from_uri_list(
-                        (0..sources.len())
+                        (0..n_sources)
                             .map(move |i| sources.as_paths().unwrap().get(i).unwrap().clone()),
                         cloud_options,
                     )
@@ -379,10 +516,40 @@ pub async fn csv_file_info(
         }
     };
 
-    let infer_schema_func = |i| {
-        let source = sources.at(i);
-        let mem_slice = source.to_buffer_possibly_async(run_async, cache_entries.as_ref(), i)?;
-        let mut reader = CompressedReader::try_new(mem_slice)?;
+    let first_mem_slice =
+        first_scan_source.to_buffer_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+
+    // The dialect is sniffed from the first source only and pinned into the options, so
+    // all sources of the scan are read with the same dialect.
+    if let Some(sample_lines) = csv_options.sniff_dialect {
+        let mut reader = CompressedReader::try_new_with_encoding(
+            first_mem_slice.clone(),
+            csv_options.parse_options.encoding,
+        )?;
+        let dialect = sniff_dialect(&mut reader, csv_options, sample_lines)?;
+
+        if config::verbose() {
+            eprintln!(
+                "detected csv dialect of '{}': {dialect}",
+                first_scan_source.to_include_path_name()
+            )
+        }
+
+        dialect.apply(csv_options)?;
+    }
+
+    if let Some(schema) = csv_options.schema.clone() {
+        return Ok(FileInfo::new(
+            schema.clone(),
+            Some(Either::Right(schema)),
+            (None, usize::MAX),
+        ));
+    }
+
+    let csv_options = &*csv_options;
+    let infer_schema_func = |mem_slice| {
+        let mut reader =
+            CompressedReader::try_new_with_encoding(mem_slice, csv_options.parse_options.encoding)?;
 
         let mut first_row_len = 0;
         let (schema, _) = read_until_start_and_infer_schema(
@@ -415,18 +582,21 @@ pub async fn csv_file_info(
             },
         };
 
-    assert!(
-        csv_options.schema.is_none(),
-        "DSL to IR schema inference should not run if user provides a schema."
-    );
     // Run inference in parallel with a specific merge order.
     // TODO: flatten to single level once Schema::to_supertype is commutative.
     let si_results = POOL.join(
-        || infer_schema_func(0),
+        || infer_schema_func(first_mem_slice),
         || {
             (1..sources.len())
                 .into_par_iter()
-                .map(infer_schema_func)
+                .map(|i| {
+                    let source = sources.at(i);
+                    infer_schema_func(source.to_buffer_possibly_async(
+                        run_async,
+                        cache_entries.as_ref(),
+                        i,
+                    )?)
+                })
                 .reduce(|| Ok(Default::default()), merge_func)
         },
     );
@@ -638,9 +808,89 @@ this scan to succeed with an empty DataFrame.",
                 ))
             }
             .map_err(|e| e.context(failed_here!(ipc scan)))?,
+            #[cfg(feature = "avro")]
+            FileScanDsl::Avro => {
+                let first_scan_source =
+                    require_first_source("failed to retrieve first file schema (avro)", "")?;
+
+                if verbose() {
+                    eprintln!(
+                        "sourcing avro scan file schema from: '{}'",
+                        first_scan_source.to_include_path_name()
+                    )
+                }
+
+                let file_info = scans::avro_file_info(
+                    sources,
+                    first_scan_source,
+                    unified_scan_args.row_index.as_ref(),
+                    cloud_options,
+                )
+                .await?;
+
+                ApolloResult::Ok((file_info, FileScanIR::Avro))
+            }
+            .map_err(|e| e.context(failed_here!(avro scan)))?,
+            #[cfg(feature = "orc")]
+            FileScanDsl::Orc => {
+                let first_scan_source =
+                    require_first_source("failed to retrieve first file schema (orc)", "")?;
+
+                if verbose() {
+                    eprintln!(
+                        "sourcing orc scan file schema from: '{}'",
+                        first_scan_source.to_include_path_name()
+                    )
+                }
+
+                let file_info = scans::orc_file_info(
+                    sources,
+                    first_scan_source,
+                    unified_scan_args.row_index.as_ref(),
+                    cloud_options,
+                )
+                .await?;
+
+                ApolloResult::Ok((file_info, FileScanIR::Orc))
+            }
+            .map_err(|e| e.context(failed_here!(orc scan)))?,
             #[cfg(feature = "csv")]
             FileScanDsl::Csv { mut options } => {
+                apollo_ensure!(
+                    !(options.ignore_errors && options.reject_rows.is_some()),
+                    InvalidOperation: "'ignore_errors' cannot be combined with 'reject_rows'"
+                );
+
                 {
+                    let file_info = match options.schema.clone() {
+                        Some(schema) if options.sniff_dialect.is_none() => FileInfo {
+                            schema: schema.clone(),
+                            reader_schema: Some(either::Either::Right(schema)),
+                            row_estimation: (None, usize::MAX),
+                        },
+                        // The dialect is sniffed while sourcing the schema.
+                        _ => {
+                            let first_scan_source =
+                                require_first_source("failed to retrieve file schemas (csv)", "")?;
+
+                            if verbose() {
+                                eprintln!(
+                                    "sourcing csv scan file schema from: '{}'",
+                                    first_scan_source.to_include_path_name()
+                                )
+                            }
+
+                            scans::csv_file_info(
+                                sources,
+                                first_scan_source,
+                                unified_scan_args.row_index.as_ref(),
+                                Arc::make_mut(&mut options),
+                                cloud_options,
+                            )
+                            .await?
+                        },
+                    };
+
                     // TODO: This is a hack. We conditionally set `allow_missing_columns` to
                     // mimic existing behavior, but this should be taken from a user provided
                     // parameter instead.
@@ -648,39 +898,17 @@ this scan to succeed with an empty DataFrame.",
                         unified_scan_args.missing_columns_policy = MissingColumnsPolicy::Insert;
                     }
 
-                    let file_info = if let Some(schema) = options.schema.clone() {
-                        FileInfo {
-                            schema: schema.clone(),
-                            reader_schema: Some(either::Either::Right(schema)),
-                            row_estimation: (None, usize::MAX),
-                        }
-                    } else {
-                        let first_scan_source =
-                            require_first_source("failed to retrieve file schemas (csv)", "")?;
-
-                        if verbose() {
-                            eprintln!(
-                                "sourcing csv scan file schema from: '{}'",
-                                first_scan_source.to_include_path_name()
-                            )
-                        }
-
-                        scans::csv_file_info(
-                            sources,
-                            first_scan_source,
-                            unified_scan_args.row_index.as_ref(),
-                            Arc::make_mut(&mut options),
-                            cloud_options,
-                        )
-                        .await?
-                    };
-
                     ApolloResult::Ok((file_info, FileScanIR::Csv { options }))
                 }
                 .map_err(|e| e.context(failed_here!(csv scan)))?
             },
             #[cfg(feature = "json")]
             FileScanDsl::NDJson { options } => {
+                apollo_ensure!(
+                    !(options.ignore_errors && options.reject_rows.is_some()),
+                    InvalidOperation: "'ignore_errors' cannot be combined with 'reject_rows'"
+                );
+
                 let file_info = if let Some(schema) = options.schema.clone() {
                     FileInfo {
                         schema: schema.clone(),
@@ -808,7 +1036,8 @@ this scan to succeed with an empty DataFrame.",
                 (key, v.cloned())
             },
             #[cfg(feature = "csv")]
-            FileScanDsl::Csv { options } => {
+            // The key does not include the path that rejected rows are written to.
+            FileScanDsl::Csv { options } if options.reject_rows.is_none() => {
                 let key = CachedSourceKey::CsvJson {
                     paths: paths.clone(),
                     schema: options.schema.clone(),
@@ -819,7 +1048,7 @@ this scan to succeed with an empty DataFrame.",
                 (key, v.cloned())
             },
             #[cfg(feature = "json")]
-            FileScanDsl::NDJson { options } => {
+            FileScanDsl::NDJson { options } if options.reject_rows.is_none() => {
                 let key = CachedSourceKey::CsvJson {
                     paths: paths.clone(),
                     schema: options.schema.clone(),
@@ -336,6 +336,10 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IA::Explode(options) => A::Explode(options),
                 #[cfg(feature = "array_to_struct")]
                 IA::ToStruct(ng) => A::ToStruct(ng),
+                #[cfg(feature = "array_vector")]
+                IA::Metric(metric) => A::Metric(metric),
+                #[cfg(feature = "array_vector")]
+                IA::Normalize => A::Normalize,
             })
         },
         IF::BinaryExpr(f) => {
@@ -353,6 +357,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 #[cfg(feature = "binary_encoding")]
                 IB::Base64Encode => B::Base64Encode,
                 IB::Size => B::Size,
+                #[cfg(feature = "hash_digest")]
+                IB::Digest { algorithm, hex } => B::Digest { algorithm, hex },
                 #[cfg(feature = "binary_encoding")]
                 IB::Reinterpret(data_type, v) => B::Reinterpret(data_type.into(), v),
                 IB::Slice => B::Slice,
@@ -383,6 +389,19 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
             F::Extension(match f {
                 IE::To(dtype) => E::To(dtype.into()),
                 IE::Storage => E::Storage,
+                IE::GenerateUuid(version) => E::GenerateUuid(version),
+            })
+        },
+        #[cfg(feature = "dtype-map")]
+        IF::MapExpr(f) => {
+            use {IRMapFunction as IM, MapFunction as M};
+            F::MapExpr(match f {
+                IM::Get => M::Get,
+                IM::Keys => M::Keys,
+                IM::Values => M::Values,
+                IM::ContainsKey => M::ContainsKey,
+                IM::FromLists => M::FromLists,
+                IM::ToStruct(names) => M::ToStruct(names),
             })
         },
         IF::ListExpr(f) => {
@@ -489,6 +508,40 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::Normalize { form } => B::Normalize { form },
                 #[cfg(feature = "string_reverse")]
                 IB::Reverse => B::Reverse,
+                #[cfg(feature = "string_similarity")]
+                IB::LevenshteinDistance { max_distance } => B::LevenshteinDistance { max_distance },
+                #[cfg(feature = "string_similarity")]
+                IB::DamerauLevenshteinDistance { max_distance } => {
+                    B::DamerauLevenshteinDistance { max_distance }
+                },
+                #[cfg(feature = "string_similarity")]
+                IB::HammingDistance => B::HammingDistance,
+                #[cfg(feature = "string_similarity")]
+                IB::JaroWinklerSimilarity => B::JaroWinklerSimilarity,
+                #[cfg(feature = "string_similarity")]
+                IB::JaccardSimilarity { q } => B::JaccardSimilarity { q },
+                #[cfg(feature = "hash_digest")]
+                IB::Digest { algorithm, hex } => B::Digest { algorithm, hex },
+                #[cfg(feature = "string_url")]
+                IB::ParseUrl => B::ParseUrl,
+                #[cfg(feature = "string_url")]
+                IB::UrlQueryParam { name } => B::UrlQueryParam { name },
+                #[cfg(feature = "string_ip")]
+                IB::Ipv4ToInt => B::Ipv4ToInt,
+                #[cfg(feature = "string_ip")]
+                IB::Ipv6ToInt => B::Ipv6ToInt,
+                #[cfg(feature = "string_ip")]
+                IB::IpToBinary => B::IpToBinary,
+                #[cfg(feature = "string_ip")]
+                IB::IpInCidr => B::IpInCidr,
+                #[cfg(feature = "string_ip")]
+                IB::IpMask {
+                    ipv4_prefix,
+                    ipv6_prefix,
+                } => B::IpMask {
+                    ipv4_prefix,
+                    ipv6_prefix,
+                },
                 #[cfg(feature = "string_pad")]
                 IB::PadStart { fill_char } => B::PadStart { fill_char },
                 #[cfg(feature = "string_pad")]
@@ -523,6 +576,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::SplitRegex { inclusive, strict } => B::SplitRegex { inclusive, strict },
                 #[cfg(feature = "dtype-decimal")]
                 IB::ToDecimal { scale } => B::ToDecimal { scale },
+                #[cfg(feature = "dtype-interval")]
+                IB::ToInterval { strict } => B::ToInterval { strict },
                 #[cfg(feature = "nightly")]
                 IB::Titlecase => B::Titlecase,
                 IB::Uppercase => B::Uppercase,
@@ -579,6 +634,14 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::MapFieldNames(f) => B::MapFieldNames(f),
             })
         },
+        #[cfg(feature = "dtype-union")]
+        IF::UnionExpr(f) => {
+            use {IRUnionFunction as IU, UnionFunction as U};
+            F::UnionExpr(match f {
+                IU::Tag => U::Tag,
+                IU::Field(name) => U::Field(name),
+            })
+        },
         #[cfg(feature = "temporal")]
         IF::TemporalExpr(f) => {
             use {IRTemporalFunction as IB, TemporalFunction as B};
@@ -982,6 +1045,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::UniqueCounts => F::UniqueCounts,
         #[cfg(feature = "approx_unique")]
         IF::ApproxNUnique => F::ApproxNUnique,
+        #[cfg(feature = "approx_quantile")]
+        IF::ApproxQuantile { quantile, accuracy } => F::ApproxQuantile { quantile, accuracy },
         IF::Coalesce => F::Coalesce,
         #[cfg(feature = "diff")]
         IF::Diff(nb) => F::Diff(nb),
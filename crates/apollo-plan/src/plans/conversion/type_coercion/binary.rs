@@ -115,6 +115,37 @@ fn err_date_str_compare() -> ApolloResult<()> {
     }
 }
 
+/// Rewrite `temporal ± interval` to an `offset_by`, as only a calendar-aware
+/// offset gives months and days their proper length.
+#[cfg(all(feature = "dtype-interval", feature = "offset_by"))]
+fn offset_by_interval(
+    expr_arena: &mut Arena<AExpr>,
+    node_temporal: Node,
+    op: Operator,
+    node_interval: Node,
+) -> AExpr {
+    let interval = ExprIR::from_node(node_interval, expr_arena);
+    let interval = if op == Operator::Minus {
+        let function = IRFunctionExpr::Negate;
+        let options = function.function_options();
+        let node = expr_arena.add(AExpr::Function {
+            input: vec![interval],
+            function,
+            options,
+        });
+        ExprIR::from_node(node, expr_arena)
+    } else {
+        interval
+    };
+    let function = IRFunctionExpr::TemporalExpr(IRTemporalFunction::OffsetBy);
+    let options = function.function_options();
+    AExpr::Function {
+        input: vec![ExprIR::from_node(node_temporal, expr_arena), interval],
+        function,
+        options,
+    }
+}
+
 pub(super) fn process_binary(
     expr_arena: &mut Arena<AExpr>,
     input_schema: &Schema,
@@ -209,6 +240,18 @@ pub(super) fn process_binary(
 
     if op.is_arithmetic() {
         match (&type_left, &type_right) {
+            #[cfg(all(feature = "dtype-interval", feature = "offset_by"))]
+            (Date | Datetime(_, _), Interval) if matches!(op, Operator::Plus | Operator::Minus) => {
+                return Ok(Some(offset_by_interval(
+                    expr_arena, node_left, op, node_right,
+                )));
+            },
+            #[cfg(all(feature = "dtype-interval", feature = "offset_by"))]
+            (Interval, Date | Datetime(_, _)) if op == Operator::Plus => {
+                return Ok(Some(offset_by_interval(
+                    expr_arena, node_right, op, node_left,
+                )));
+            },
             (Duration(_), Duration(_)) => return Ok(None),
             (Duration(_), r) if r.is_primitive_numeric() => return Ok(None),
             (String, a) | (a, String) if a.is_primitive_numeric() => {
@@ -58,10 +58,18 @@ fn check_namespace(function: &IRFunctionExpr, first_dtype: &DataType) -> ApolloR
         IRFunctionExpr::ArrayExpr(_) => {
             apollo_ensure!(matches!(first_dtype, DataType::Array(_, _)), InvalidOperation: "expected Array type, got: {}", first_dtype)
         },
+        #[cfg(feature = "dtype-map")]
+        IRFunctionExpr::MapExpr(f) if !matches!(f, IRMapFunction::FromLists) => {
+            apollo_ensure!(matches!(first_dtype, DataType::Map(_, _)), InvalidOperation: "expected Map type, got: {}", first_dtype)
+        },
         #[cfg(feature = "dtype-struct")]
         IRFunctionExpr::StructExpr(_) => {
             apollo_ensure!(matches!(first_dtype, DataType::Struct(_)), InvalidOperation: "expected Struct type, got: {}", first_dtype)
         },
+        #[cfg(feature = "dtype-union")]
+        IRFunctionExpr::UnionExpr(_) => {
+            apollo_ensure!(matches!(first_dtype, DataType::Union(_, _)), InvalidOperation: "expected Union type, got: {}", first_dtype)
+        },
         #[cfg(feature = "dtype-categorical")]
         IRFunctionExpr::Categorical(_) => {
             apollo_ensure!(matches!(first_dtype, DataType::Categorical(_, _)), InvalidOperation: "expected Categorical type, got: {}", first_dtype)
@@ -3,6 +3,7 @@ mod dsl;
 mod hint;
 #[cfg(feature = "python")]
 mod python_udf;
+pub(crate) mod result_cache;
 mod schema;
 
 use std::borrow::Cow;
@@ -12,6 +13,7 @@ use std::sync::Arc;
 
 pub use dsl::*;
 pub use hint::*;
+pub use result_cache::ResultCacheStore;
 use apollo_core::error::feature_gated;
 use apollo_core::prelude::*;
 use apollo_core::series::IsSorted;
@@ -75,6 +77,8 @@ pub enum FunctionIR {
         fmt_str: PlSmallStr,
     },
     Hint(HintIR),
+    #[cfg_attr(feature = "ir_serde", serde(skip))]
+    ResultCacheStore(ResultCacheStore),
 }
 
 impl Hash for FunctionIR {
@@ -117,6 +121,7 @@ impl Hash for FunctionIR {
                 offset.hash(state);
             },
             FunctionIR::Hint(hint) => hint.hash(state),
+            FunctionIR::ResultCacheStore(store) => store.hash(state),
         }
     }
 }
@@ -134,7 +139,7 @@ impl FunctionIR {
             #[cfg(feature = "python")]
             OpaquePython(OpaquePythonUdf { streamable, .. }) => *streamable,
             RowIndex { .. } => false,
-            Hint(_) => true,
+            Hint(_) | ResultCacheStore(_) => true,
         }
     }
 
@@ -158,7 +163,7 @@ impl FunctionIR {
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
             Rechunk | Unnest { .. } | Explode { .. } | Hint(_) => true,
-            RowIndex { .. } | FastCount { .. } => false,
+            RowIndex { .. } | FastCount { .. } | ResultCacheStore(_) => false,
         }
     }
 
@@ -172,6 +177,7 @@ impl FunctionIR {
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
             RowIndex { .. } => true,
+            ResultCacheStore(_) => false,
         }
     }
 
@@ -239,6 +245,10 @@ impl FunctionIR {
 
                 Ok(df)
             },
+            ResultCacheStore(store) => {
+                store.store(df.clone());
+                Ok(df)
+            },
         }
     }
 
@@ -254,7 +264,7 @@ impl FunctionIR {
             #[cfg(feature = "pivot")]
             FunctionIR::Unpivot { .. } => true,
             FunctionIR::Opaque { .. } => true,
-            FunctionIR::Hint(_) => is_input_ordered,
+            FunctionIR::Hint(_) | FunctionIR::ResultCacheStore(_) => is_input_ordered,
         }
     }
 
@@ -269,7 +279,8 @@ impl FunctionIR {
             | Self::FastCount { .. }
             | Self::Rechunk
             | Self::Explode { .. }
-            | Self::Opaque { .. } => false,
+            | Self::Opaque { .. }
+            | Self::ResultCacheStore(_) => false,
         }
     }
 
@@ -280,7 +291,7 @@ impl FunctionIR {
     /// Is the input ordering always the same as the output ordering.
     pub fn has_equal_order(&self) -> bool {
         match self {
-            Self::Unnest { .. } | Self::Rechunk | Self::Hint(_) => true,
+            Self::Unnest { .. } | Self::Rechunk | Self::Hint(_) | Self::ResultCacheStore(_) => true,
             #[cfg(feature = "python")]
             Self::OpaquePython(..) => false,
             #[cfg(feature = "pivot")]
@@ -307,6 +318,7 @@ impl Display for FunctionIR {
                 write!(f, "hint.{hint}")
             },
             Opaque { fmt_str, .. } => write!(f, "{fmt_str}"),
+            ResultCacheStore(_) => f.write_str("RESULT CACHE STORE"),
             Unnest { columns, separator } => {
                 write!(f, "UNNEST by:")?;
                 let columns = columns.as_ref();
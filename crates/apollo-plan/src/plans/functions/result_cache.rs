@@ -0,0 +1,163 @@
+//! Storage of the cross-query result cache.
+//!
+//! The cache is opt-in and disabled unless `APOLLO_RESULT_CACHE_SIZE` is set to a size in bytes.
+//! Once the budget is exceeded, the least recently used results are evicted.
+use std::sync::{Mutex, OnceLock};
+
+use apollo_core::prelude::*;
+use apollo_utils::cache::LruCache;
+
+pub(crate) type Fingerprint = [u8; 32];
+
+/// Upper bound on the number of entries, the byte budget is what is actually enforced.
+const MAX_ENTRIES: usize = 1 << 16;
+
+static RESULT_CACHE: OnceLock<Mutex<ResultCache>> = OnceLock::new();
+
+/// Size budget of the result cache in bytes, 0 disables the cache.
+pub(crate) fn result_cache_budget() -> usize {
+    std::env::var("APOLLO_RESULT_CACHE_SIZE")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0)
+}
+
+/// Sets the budget of the result cache, evicting results that no longer fit.
+///
+/// A budget of 0 only clears a cache that was enabled before, a disabled cache is never
+/// allocated.
+pub(crate) fn set_result_cache_budget(budget: usize) {
+    if budget == 0 {
+        if let Some(cache) = RESULT_CACHE.get() {
+            cache.lock().unwrap().set_budget(0);
+        }
+        return;
+    }
+    result_cache().lock().unwrap().set_budget(budget);
+}
+
+pub(crate) fn get_cached_result(key: &Fingerprint) -> Option<DataFrame> {
+    result_cache().lock().unwrap().get(key)
+}
+
+fn result_cache() -> &'static Mutex<ResultCache> {
+    RESULT_CACHE.get_or_init(|| Mutex::new(ResultCache::new(MAX_ENTRIES)))
+}
+
+struct ResultCache {
+    entries: LruCache<Fingerprint, (DataFrame, usize)>,
+    max_entries: usize,
+    size: usize,
+    budget: usize,
+}
+
+impl ResultCache {
+    fn new(max_entries: usize) -> Self {
+        Self {
+            entries: LruCache::with_capacity(max_entries),
+            max_entries,
+            size: 0,
+            budget: 0,
+        }
+    }
+
+    fn set_budget(&mut self, budget: usize) {
+        self.budget = budget;
+        self.evict();
+    }
+
+    fn get(&mut self, key: &Fingerprint) -> Option<DataFrame> {
+        self.entries.get(key).map(|(df, _)| df.clone())
+    }
+
+    fn insert(&mut self, key: Fingerprint, df: DataFrame) {
+        let size = df.estimated_size();
+        if size > self.budget {
+            return;
+        }
+        if let Some((_, old_size)) = self.entries.remove(&key) {
+            self.size -= old_size;
+        }
+        // The LRU cache silently drops its least recently used entry when it is full, so make
+        // room explicitly to keep the size in sync.
+        if self.entries.len() == self.max_entries {
+            self.pop_lru();
+        }
+        self.size += size;
+        self.entries.insert(key, (df, size));
+        self.evict();
+    }
+
+    fn evict(&mut self) {
+        while self.size > self.budget {
+            if !self.pop_lru() {
+                break;
+            }
+        }
+    }
+
+    fn pop_lru(&mut self) -> bool {
+        let Some((_, (_, size))) = self.entries.pop_lru() else {
+            return false;
+        };
+        self.size -= size;
+        true
+    }
+}
+
+/// Stores the result of a subplan in the result cache as it passes through.
+///
+/// The streaming engine buffers the morsels and only stores the result once the input is
+/// exhausted, so a partially consumed subplan is never stored.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ResultCacheStore {
+    key: Fingerprint,
+}
+
+impl ResultCacheStore {
+    pub(crate) fn new(key: Fingerprint) -> Self {
+        Self { key }
+    }
+
+    /// The size in bytes of the largest result that fits in the cache.
+    pub fn max_size(&self) -> usize {
+        RESULT_CACHE
+            .get()
+            .map_or(0, |cache| cache.lock().unwrap().budget)
+    }
+
+    pub fn store(&self, df: DataFrame) {
+        result_cache().lock().unwrap().insert(self.key, df);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use apollo_core::df;
+
+    use super::*;
+
+    #[test]
+    fn test_result_cache_size_on_eviction() {
+        let df = df!["a" => [1i64, 2, 3]].unwrap();
+        let size = df.estimated_size();
+
+        let mut cache = ResultCache::new(2);
+        cache.set_budget(10 * size);
+        for i in 0..3 {
+            cache.insert([i; 32], df.clone());
+        }
+        assert_eq!(cache.entries.len(), 2);
+        assert_eq!(cache.size, 2 * size);
+        assert!(cache.get(&[0; 32]).is_none());
+
+        // Replacing an entry doesn't count it twice.
+        cache.insert([1; 32], df.clone());
+        assert_eq!(cache.size, 2 * size);
+
+        cache.set_budget(size);
+        assert_eq!(cache.entries.len(), 1);
+        assert_eq!(cache.size, size);
+        assert!(cache.get(&[1; 32]).is_some());
+    }
+}
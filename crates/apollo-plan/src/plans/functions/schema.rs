@@ -44,7 +44,7 @@ impl FunctionIR {
                 schema.insert_at_index(0, name, IDX_DTYPE)?;
                 Ok(Cow::Owned(Arc::new(schema)))
             },
-            Rechunk => Ok(Cow::Borrowed(input_schema)),
+            Rechunk | ResultCacheStore(_) => Ok(Cow::Borrowed(input_schema)),
             Unnest { columns, separator } => {
                 #[cfg(feature = "dtype-struct")]
                 {
@@ -141,6 +141,10 @@ fn explode_schema<'a>(
             DataType::Array(inner, _) => {
                 schema.with_column(name.clone(), inner.as_ref().clone());
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => {
+                schema.with_column(name.clone(), DataType::map_entries_dtype(key, value));
+            },
             _ => {},
         }
 
@@ -112,6 +112,11 @@ impl IR {
                         keys,
                         include_keys: _,
                         keys_pre_grouped: _,
+                    }
+                    | PartitionStrategyIR::Bucketed {
+                        keys,
+                        num_buckets: _,
+                        hash: _,
                     } => Exprs::Slice(keys.iter()),
                     PartitionStrategyIR::FileSize => Exprs::Empty,
                 },
@@ -184,6 +189,11 @@ impl IR {
                         keys,
                         include_keys: _,
                         keys_pre_grouped: _,
+                    }
+                    | PartitionStrategyIR::Bucketed {
+                        keys,
+                        num_buckets: _,
+                        hash: _,
                     } => ExprsMut::Slice(keys.iter_mut()),
                     PartitionStrategyIR::FileSize => ExprsMut::Empty,
                 },
@@ -6,18 +6,18 @@ use apollo_utils::unique_id::UniqueId;
 use super::*;
 use crate::prelude::visitor::IRNode;
 
-struct Blake3Hasher {
+pub(super) struct Blake3Hasher {
     hasher: blake3::Hasher,
 }
 
 impl Blake3Hasher {
-    fn new() -> Self {
+    pub(super) fn new() -> Self {
         Self {
             hasher: blake3::Hasher::new(),
         }
     }
 
-    fn finalize(self) -> [u8; 32] {
+    pub(super) fn finalize(self) -> [u8; 32] {
         self.hasher.finalize().into()
     }
 }
@@ -1,11 +1,13 @@
 mod cache_states;
 mod csee;
 mod cspe;
+mod result_cache;
 
 use cache_states::set_cache_states;
 pub(super) use csee::CommonSubExprOptimizer;
 pub use csee::NaiveExprMerger;
 use cspe::elim_cmn_subplans;
+pub(super) use result_cache::apply_result_cache;
 
 use super::*;
 
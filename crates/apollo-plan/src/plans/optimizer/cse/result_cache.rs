@@ -0,0 +1,303 @@
+//! Cross-query result cache.
+//!
+//! Common subplan elimination only deduplicates work within a single query. This cache keeps the
+//! materialized results of subplans around between queries, so that running the same (sub)query
+//! again, e.g. from a dashboard that refreshes, doesn't have to scan and aggregate again.
+//!
+//! Every cacheable subplan is identified by a fingerprint over its nodes, its expressions and the
+//! version (modification time or ETag) of the files it scans. A changed file thus never serves a
+//! stale result. Subplans that depend on in-memory data, user-defined functions or randomness are
+//! never cached.
+//!
+//! The storage itself lives in [`ResultCacheStore`], this module decides what to look up and
+//! what to store.
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use apollo_utils::pl_path::{CloudScheme, PlRefPath};
+
+use super::cspe::Blake3Hasher;
+use super::*;
+use crate::plans::functions::result_cache::{
+    Fingerprint, get_cached_result, result_cache_budget, set_result_cache_budget,
+};
+use crate::prelude::visitor::IRNode;
+
+/// Replaces subplans whose result is cached by a scan over that result, and makes the executor
+/// store the results of the other cacheable subplans.
+///
+/// This must run after all other optimizations, as the fingerprints are taken over the final plan.
+pub(crate) fn apply_result_cache(
+    root: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    verbose: bool,
+) {
+    let budget = result_cache_budget();
+    set_result_cache_budget(budget);
+    if budget == 0 {
+        return;
+    }
+
+    let mut fingerprints = PlHashMap::default();
+    fingerprint(root, ir_arena, expr_arena, &mut fingerprints);
+    rewrite(root, false, false, ir_arena, &fingerprints, verbose);
+}
+
+/// `covered` is set if the result of an ancestor is stored, in which case only results that are
+/// expensive to recompute are stored as well.
+#[recursive::recursive]
+fn rewrite(
+    node: Node,
+    covered: bool,
+    is_sink_input: bool,
+    ir_arena: &mut Arena<IR>,
+    fingerprints: &PlHashMap<Node, Option<Fingerprint>>,
+    verbose: bool,
+) {
+    let fp = fingerprints.get(&node).copied().flatten();
+
+    if let Some(fp) = fp
+        && let Some(df) = get_cached_result(&fp)
+    {
+        if verbose {
+            eprintln!(
+                "result cache hit: replacing {} node",
+                ir_arena.get(node).name()
+            );
+        }
+        let schema = ir_arena.get(node).schema(ir_arena).into_owned();
+        ir_arena.replace(
+            node,
+            IR::DataFrameScan {
+                df: Arc::new(df),
+                schema,
+                output_schema: None,
+            },
+        );
+        return;
+    }
+
+    // Store the largest cacheable subplans and everything that is expensive to recompute. Sinks
+    // are lowered based on their direct input, so that is never wrapped.
+    let store = fp.filter(|_| {
+        !is_sink_input
+            && (!covered
+                || matches!(
+                    ir_arena.get(node),
+                    IR::GroupBy { .. } | IR::Join { .. } | IR::Distinct { .. } | IR::Sort { .. }
+                ))
+    });
+    let covered = fp.is_some() && (covered || store.is_some());
+    let is_sink = matches!(
+        ir_arena.get(node),
+        IR::Sink { .. } | IR::SinkMultiple { .. }
+    );
+    for input in ir_arena.get(node).get_inputs() {
+        rewrite(input, covered, is_sink, ir_arena, fingerprints, verbose);
+    }
+
+    if let Some(fp) = store {
+        let ir = ir_arena.take(node);
+        let input = ir_arena.add(ir);
+        ir_arena.replace(
+            node,
+            IR::MapFunction {
+                input,
+                function: FunctionIR::ResultCacheStore(ResultCacheStore::new(fp)),
+            },
+        );
+    }
+}
+
+/// Computes the fingerprint of the subplan at `node`, `None` if its result can't be cached.
+#[recursive::recursive]
+fn fingerprint(
+    node: Node,
+    ir_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    fingerprints: &mut PlHashMap<Node, Option<Fingerprint>>,
+) -> Option<Fingerprint> {
+    if let Some(fp) = fingerprints.get(&node) {
+        return *fp;
+    }
+
+    let ir = ir_arena.get(node);
+    let mut cacheable = is_cacheable_node(ir);
+
+    let mut h = Blake3Hasher::new();
+    for input in ir.inputs() {
+        // Visit all inputs, so that cacheable subplans below an uncacheable node are fingerprinted.
+        match fingerprint(input, ir_arena, expr_arena, fingerprints) {
+            Some(fp) => fp.hash(&mut h),
+            None => cacheable = false,
+        }
+    }
+
+    let fp = cacheable
+        .then(|| {
+            for e in ir.exprs() {
+                hash_expr_exact(e.node(), expr_arena, &mut h)?;
+            }
+            if let IR::Scan {
+                sources,
+                unified_scan_args,
+                ..
+            } = ir
+            {
+                hash_source_versions(sources, unified_scan_args, &mut h)?;
+            }
+
+            IRNode::new(node)
+                .hashable_and_cmp(ir_arena, expr_arena)
+                .hash_as_equality()
+                .hash(&mut h);
+            ir.schema(ir_arena).hash(&mut h);
+            Some(h.finalize())
+        })
+        .flatten();
+
+    fingerprints.insert(node, fp);
+    fp
+}
+
+fn is_cacheable_node(ir: &IR) -> bool {
+    match ir {
+        IR::DataFrameScan { .. }
+        | IR::Cache { .. }
+        | IR::ExtContext { .. }
+        | IR::Sink { .. }
+        | IR::SinkMultiple { .. }
+        | IR::Invalid => false,
+        #[cfg(feature = "python")]
+        IR::PythonScan { .. } => false,
+        IR::Scan { scan_type, .. } => match &**scan_type {
+            FileScanIR::Anonymous { .. } => false,
+            #[cfg(feature = "python")]
+            FileScanIR::PythonDataset { .. } => false,
+            _ => true,
+        },
+        IR::GroupBy { apply, .. } => apply.is_none(),
+        IR::MapFunction { function, .. } => match function {
+            FunctionIR::Opaque { .. } | FunctionIR::FastCount { .. } => false,
+            #[cfg(feature = "python")]
+            FunctionIR::OpaquePython(_) => false,
+            _ => true,
+        },
+        _ => true,
+    }
+}
+
+/// Hashes the parts of an expression that aren't exactly covered by the plan hash. Returns `None`
+/// if the expression can't be cached.
+fn hash_expr_exact(node: Node, expr_arena: &Arena<AExpr>, h: &mut Blake3Hasher) -> Option<()> {
+    for (_, ae) in expr_arena.iter(node) {
+        match ae {
+            AExpr::AnonymousFunction { .. } | AExpr::AnonymousAgg { .. } => return None,
+            #[cfg(feature = "random")]
+            AExpr::Function {
+                function: IRFunctionExpr::Random { .. },
+                ..
+            } => return None,
+            // The literal hash only samples a few values.
+            AExpr::Literal(LiteralValue::Series(s)) => {
+                if s.dtype().is_object() {
+                    return None;
+                }
+                for av in s.rechunk().iter() {
+                    av.hash(h);
+                }
+            },
+            _ => {},
+        }
+    }
+    Some(())
+}
+
+/// Hashes the current version of every scanned file. Returns `None` if a version can't be
+/// determined.
+fn hash_source_versions(
+    sources: &ScanSources,
+    unified_scan_args: &UnifiedScanArgs,
+    h: &mut Blake3Hasher,
+) -> Option<()> {
+    let ScanSources::Paths(paths) = sources else {
+        return None;
+    };
+
+    let is_local = |path: &PlRefPath| {
+        matches!(
+            path.scheme(),
+            None | Some(CloudScheme::File | CloudScheme::FileNoHostname)
+        )
+    };
+    let cloud_paths: Vec<PlRefPath> = paths.iter().filter(|&p| !is_local(p)).cloned().collect();
+    let mut cloud_versions = if cloud_paths.is_empty() {
+        Vec::new()
+    } else {
+        cloud_versions(cloud_paths, unified_scan_args)?
+    }
+    .into_iter();
+
+    for path in paths.iter() {
+        if is_local(path) {
+            let metadata = std::fs::metadata(path.strip_scheme()).ok()?;
+            metadata.len().hash(h);
+            metadata.modified().ok()?.hash(h);
+        } else {
+            cloud_versions.next()?.hash(h);
+        }
+    }
+    Some(())
+}
+
+/// Fetches the versions of the cloud objects at `paths` in order. The requests are sent
+/// concurrently, so that planning a scan over many objects doesn't wait for them one by one.
+#[cfg(feature = "cloud")]
+fn cloud_versions(
+    paths: Vec<PlRefPath>,
+    unified_scan_args: &UnifiedScanArgs,
+) -> Option<Vec<String>> {
+    use apollo_io::cloud::{CloudLocation, build_object_store, object_path_from_str};
+    use apollo_io::pl_async::{get_concurrency_limit, get_runtime};
+    use futures::{StreamExt, TryStreamExt};
+
+    let cloud_options = unified_scan_args.cloud_options.as_ref();
+    let metadata = get_runtime()
+        .block_in_place_on(async {
+            futures::stream::iter(paths)
+                .map(|path| async move {
+                    let (_, object_store) =
+                        build_object_store(path.clone(), cloud_options, false).await?;
+                    let CloudLocation { prefix, .. } = CloudLocation::new(path, false)?;
+                    object_store.head(&object_path_from_str(&prefix)?).await
+                })
+                .buffered(get_concurrency_limit() as usize)
+                .try_collect::<Vec<_>>()
+                .await
+        })
+        .ok()?;
+
+    Some(
+        metadata
+            .into_iter()
+            .map(|metadata| {
+                metadata.e_tag.unwrap_or_else(|| {
+                    format!(
+                        "{}-{}",
+                        metadata.last_modified.timestamp_millis(),
+                        metadata.size
+                    )
+                })
+            })
+            .collect(),
+    )
+}
+
+#[cfg(not(feature = "cloud"))]
+fn cloud_versions(
+    _paths: Vec<PlRefPath>,
+    _unified_scan_args: &UnifiedScanArgs,
+) -> Option<Vec<String>> {
+    None
+}
@@ -279,6 +279,12 @@ pub(super) fn expand_datasets(
                                 metadata: None,
                             },
 
+                            #[cfg(feature = "avro")]
+                            FileScanDsl::Avro => FileScanIR::Avro,
+
+                            #[cfg(feature = "orc")]
+                            FileScanDsl::Orc => FileScanIR::Orc,
+
                             #[cfg(feature = "parquet")]
                             FileScanDsl::Parquet { options } => FileScanIR::Parquet {
                                 options,
@@ -0,0 +1,764 @@
+//! Cost-based reordering of inner joins.
+//!
+//! Trees of inner equi-joins on plain columns are flattened into a join graph over their inputs.
+//! The join order with the smallest sum of estimated intermediate result sizes is then searched,
+//! exhaustively with dynamic programming over connected subsets for small graphs and greedily
+//! for large ones. Cardinalities come from the row counts of the scans and, when available, from
+//! the distinct counts and min/max statistics of parquet columns.
+//!
+//! Trees for which not every input has a row estimate are left untouched, as are trees where
+//! reordering would change the output columns in a way that can't be undone by a projection.
+use std::sync::Arc;
+
+use apollo_core::config::verbose;
+use apollo_core::prelude::*;
+use apollo_utils::arena::{Arena, Node};
+
+use crate::prelude::*;
+
+/// Join trees with more inputs than this are ordered greedily.
+const MAX_DP_RELATIONS: usize = 12;
+/// Fraction of the rows that is assumed to pass a filter.
+const FILTER_SELECTIVITY: f64 = 0.25;
+
+pub(super) fn optimize(
+    root: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> ApolloResult<()> {
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        if let Some(graph) = JoinGraph::try_new(node, ir_arena, expr_arena) {
+            stack.extend(graph.relations.iter().map(|r| r.node));
+            if let Some(ir) = graph.reorder(node, ir_arena, expr_arena)? {
+                ir_arena.replace(node, ir);
+            }
+        } else {
+            ir_arena.get(node).copy_inputs(&mut stack);
+        }
+    }
+
+    Ok(())
+}
+
+fn is_reorderable(options: &JoinOptionsIR) -> bool {
+    let args = &options.args;
+
+    matches!(args.how, JoinType::Inner)
+        && args.should_coalesce()
+        && args.slice.is_none()
+        && args.build_side.is_none()
+        && matches!(args.validation, JoinValidation::ManyToMany)
+        && matches!(args.maintain_order, MaintainOrderJoin::None)
+        && options.options.is_none()
+}
+
+fn key_column<'a>(e: &'a ExprIR, expr_arena: &'a Arena<AExpr>) -> Option<&'a PlSmallStr> {
+    match expr_arena.get(e.node()) {
+        AExpr::Column(name) if e.output_name() == name => Some(name),
+        _ => None,
+    }
+}
+
+fn column_expr(name: &PlSmallStr, expr_arena: &mut Arena<AExpr>) -> ExprIR {
+    ExprIR::new(
+        expr_arena.add(AExpr::Column(name.clone())),
+        OutputName::ColumnLhs(name.clone()),
+    )
+}
+
+/// Output columns of a join (sub)tree, mapped to the relation they originate from.
+type Owners = PlIndexMap<PlSmallStr, usize>;
+
+enum JoinTree {
+    Relation(usize),
+    Join(Box<JoinTree>, Box<JoinTree>),
+}
+
+struct Relation {
+    node: Node,
+    rows: f64,
+}
+
+/// A set of relations that are all known to share the same key value in the join output.
+struct KeyClass {
+    /// The key columns as `(relation, column)`.
+    members: Vec<(usize, PlSmallStr)>,
+    /// The estimated number of distinct values of each member in its relation.
+    distinct: Vec<Option<f64>>,
+    /// Bitmask of the relations that have a member in this class.
+    relations: u64,
+}
+
+struct JoinGraph {
+    relations: Vec<Relation>,
+    classes: Vec<KeyClass>,
+    original: JoinTree,
+    output: Owners,
+    options: Arc<JoinOptionsIR>,
+}
+
+/// Estimate of a (sub)plan.
+#[derive(Clone, Copy)]
+struct Estimate {
+    rows: f64,
+    cost: f64,
+}
+
+impl JoinGraph {
+    fn try_new(node: Node, ir_arena: &Arena<IR>, expr_arena: &Arena<AExpr>) -> Option<Self> {
+        let IR::Join { options, .. } = ir_arena.get(node) else {
+            return None;
+        };
+        if !is_reorderable(options) {
+            return None;
+        }
+        let options = options.clone();
+
+        let mut nodes = vec![];
+        let mut predicates = vec![];
+        let (original, output) = Self::flatten(
+            node,
+            ir_arena,
+            expr_arena,
+            &options,
+            &mut nodes,
+            &mut predicates,
+        )?;
+
+        // Two-way joins have nothing to reorder.
+        if nodes.len() < 3 || nodes.len() > u64::BITS as usize {
+            return None;
+        }
+
+        let relations = nodes
+            .into_iter()
+            .map(|node| {
+                Some(Relation {
+                    node,
+                    rows: estimate_rows(node, ir_arena)?.max(1.0),
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let classes = Self::key_classes(predicates, &relations, ir_arena);
+
+        Some(Self {
+            relations,
+            classes,
+            original,
+            output,
+            options,
+        })
+    }
+
+    /// Collects the inputs of the join tree rooted at `node` into `relations`, and the equality
+    /// predicates between their columns into `predicates`.
+    fn flatten(
+        node: Node,
+        ir_arena: &Arena<IR>,
+        expr_arena: &Arena<AExpr>,
+        root_options: &JoinOptionsIR,
+        relations: &mut Vec<Node>,
+        predicates: &mut Vec<[(usize, PlSmallStr); 2]>,
+    ) -> Option<(JoinTree, Owners)> {
+        let ir = ir_arena.get(node);
+
+        let IR::Join {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            options,
+            ..
+        } = ir
+        else {
+            let idx = relations.len();
+            relations.push(node);
+            let owners = ir
+                .schema(ir_arena)
+                .iter_names()
+                .map(|name| (name.clone(), idx));
+            return Some((JoinTree::Relation(idx), owners.collect()));
+        };
+
+        if !is_reorderable(options)
+            || options.args.nulls_equal != root_options.args.nulls_equal
+            || left_on.is_empty()
+        {
+            // Treat the join as an opaque input.
+            let idx = relations.len();
+            relations.push(node);
+            let owners = ir
+                .schema(ir_arena)
+                .iter_names()
+                .map(|name| (name.clone(), idx));
+            return Some((JoinTree::Relation(idx), owners.collect()));
+        }
+
+        let (left, mut owners) = Self::flatten(
+            *input_left,
+            ir_arena,
+            expr_arena,
+            root_options,
+            relations,
+            predicates,
+        )?;
+        let (right, right_owners) = Self::flatten(
+            *input_right,
+            ir_arena,
+            expr_arena,
+            root_options,
+            relations,
+            predicates,
+        )?;
+
+        let schema_left = ir_arena.get(*input_left).schema(ir_arena);
+        let schema_right = ir_arena.get(*input_right).schema(ir_arena);
+
+        let mut right_keys = PlHashSet::with_capacity(right_on.len());
+        for (l, r) in left_on.iter().zip(right_on) {
+            let l = key_column(l, expr_arena)?;
+            let r = key_column(r, expr_arena)?;
+            // Keys with different dtypes can't be joined on directly after reordering.
+            if schema_left.get(l)? != schema_right.get(r)? {
+                return None;
+            }
+            predicates.push([
+                (*owners.get(l)?, l.clone()),
+                (*right_owners.get(r)?, r.clone()),
+            ]);
+            right_keys.insert(r.clone());
+        }
+
+        for (name, idx) in right_owners {
+            if right_keys.contains(&name) {
+                continue;
+            }
+            // The column would be suffixed. Positions of suffixed columns depend on the join
+            // order, so don't touch these trees.
+            if owners.insert(name, idx).is_some() {
+                return None;
+            }
+        }
+
+        Some((JoinTree::Join(Box::new(left), Box::new(right)), owners))
+    }
+
+    /// Groups the key columns into equivalence classes.
+    fn key_classes(
+        predicates: Vec<[(usize, PlSmallStr); 2]>,
+        relations: &[Relation],
+        ir_arena: &Arena<IR>,
+    ) -> Vec<KeyClass> {
+        let mut keys: PlIndexMap<(usize, PlSmallStr), usize> = PlIndexMap::new();
+        let mut parents: Vec<usize> = vec![];
+
+        fn find(parents: &mut [usize], mut i: usize) -> usize {
+            while parents[i] != i {
+                parents[i] = parents[parents[i]];
+                i = parents[i];
+            }
+            i
+        }
+
+        for [l, r] in predicates {
+            let mut id = |key| {
+                let next = keys.len();
+                *keys.entry(key).or_insert_with(|| {
+                    parents.push(next);
+                    next
+                })
+            };
+            let (l, r) = (id(l), id(r));
+            let (l, r) = (find(&mut parents, l), find(&mut parents, r));
+            parents[r] = l;
+        }
+
+        let mut classes: PlIndexMap<usize, KeyClass> = PlIndexMap::new();
+        for (i, (rel, name)) in keys.into_iter().map(|(k, _)| k).enumerate() {
+            let distinct = distinct_count(relations[rel].node, &name, ir_arena)
+                .map(|d| d.clamp(1.0, relations[rel].rows));
+            let class = classes
+                .entry(find(&mut parents, i))
+                .or_insert_with(|| KeyClass {
+                    members: vec![],
+                    distinct: vec![],
+                    relations: 0,
+                });
+            class.members.push((rel, name));
+            class.distinct.push(distinct);
+            class.relations |= 1 << rel;
+        }
+
+        classes.into_values().collect()
+    }
+
+    fn class_of(&self, key: (usize, &str)) -> Option<usize> {
+        self.classes.iter().position(|c| {
+            c.members
+                .iter()
+                .any(|(rel, name)| (*rel, name.as_str()) == key)
+        })
+    }
+
+    /// Estimates the result of joining the relations in `left` and `right`. Returns `None` if the
+    /// two sets are not connected by a join predicate.
+    fn estimate_join(&self, left: u64, l: Estimate, right: u64, r: Estimate) -> Option<Estimate> {
+        let mut distinct: Option<f64> = None;
+
+        for class in &self.classes {
+            if class.relations & left == 0 || class.relations & right == 0 {
+                continue;
+            }
+
+            // The number of distinct values of a key in a set of relations is bounded by its
+            // most selective member.
+            let set_distinct = |set: u64, rows: f64| {
+                let mut out: Option<f64> = None;
+                for ((rel, _), d) in class.members.iter().zip(&class.distinct) {
+                    if let Some(d) = *d
+                        && set & (1 << *rel) != 0
+                    {
+                        out = Some(out.map_or(d, |v| v.min(d)));
+                    }
+                }
+                out.map(|d| d.min(rows))
+            };
+
+            // Without statistics, assume a key / foreign-key relationship where every row of the
+            // larger side finds exactly one match.
+            let d = match (set_distinct(left, l.rows), set_distinct(right, r.rows)) {
+                (Some(a), Some(b)) => a.max(b),
+                (Some(d), None) | (None, Some(d)) => d.max(l.rows.min(r.rows)),
+                (None, None) => l.rows.min(r.rows),
+            };
+            distinct = Some(distinct.map_or(d, |v| v.max(d)));
+        }
+
+        let rows = (l.rows * r.rows / distinct?.max(1.0)).max(1.0);
+        Some(Estimate {
+            rows,
+            cost: l.cost + r.cost + rows,
+        })
+    }
+
+    fn estimate_tree(&self, tree: &JoinTree) -> Option<(u64, Estimate)> {
+        match tree {
+            JoinTree::Relation(idx) => Some((
+                1 << idx,
+                Estimate {
+                    rows: self.relations[*idx].rows,
+                    cost: 0.0,
+                },
+            )),
+            JoinTree::Join(left, right) => {
+                let (lm, l) = self.estimate_tree(left)?;
+                let (rm, r) = self.estimate_tree(right)?;
+                Some((lm | rm, self.estimate_join(lm, l, rm, r)?))
+            },
+        }
+    }
+
+    /// Exhaustive search over all bushy join trees without cross products.
+    fn best_tree_dp(&self) -> Option<(JoinTree, Estimate)> {
+        let n = self.relations.len();
+        let full = (1u64 << n) - 1;
+        let mut best: Vec<Option<(Estimate, u64)>> = vec![None; 1 << n];
+
+        for (i, rel) in self.relations.iter().enumerate() {
+            best[1 << i] = Some((
+                Estimate {
+                    rows: rel.rows,
+                    cost: 0.0,
+                },
+                0,
+            ));
+        }
+
+        for set in 1..=full {
+            if set.count_ones() < 2 {
+                continue;
+            }
+            let lowest = set & set.wrapping_neg();
+            let mut left = (set - 1) & set;
+
+            while left != 0 {
+                // Only visit each split once.
+                if left & lowest != 0 {
+                    let right = set ^ left;
+                    if let (Some((l, _)), Some((r, _))) =
+                        (best[left as usize], best[right as usize])
+                        && let Some(est) = self.estimate_join(left, l, right, r)
+                        && best[set as usize].is_none_or(|(b, _)| est.cost < b.cost)
+                    {
+                        best[set as usize] = Some((est, left));
+                    }
+                }
+                left = (left - 1) & set;
+            }
+        }
+
+        fn build(best: &[Option<(Estimate, u64)>], set: u64) -> JoinTree {
+            let (_, left) = best[set as usize].unwrap();
+            if left == 0 {
+                return JoinTree::Relation(set.trailing_zeros() as usize);
+            }
+            let right = set ^ left;
+            let (l, _) = best[left as usize].unwrap();
+            let (r, _) = best[right as usize].unwrap();
+            // Keep the larger input on the left.
+            let (left, right) = if l.rows >= r.rows {
+                (left, right)
+            } else {
+                (right, left)
+            };
+            JoinTree::Join(Box::new(build(best, left)), Box::new(build(best, right)))
+        }
+
+        let (est, _) = best[full as usize]?;
+        Some((build(&best, full), est))
+    }
+
+    /// Repeatedly joins the pair of connected subtrees with the smallest result.
+    fn best_tree_greedy(&self) -> Option<(JoinTree, Estimate)> {
+        let mut trees: Vec<(u64, Estimate, JoinTree)> = self
+            .relations
+            .iter()
+            .enumerate()
+            .map(|(i, rel)| {
+                let est = Estimate {
+                    rows: rel.rows,
+                    cost: 0.0,
+                };
+                (1 << i, est, JoinTree::Relation(i))
+            })
+            .collect();
+
+        while trees.len() > 1 {
+            let mut best: Option<(usize, usize, Estimate)> = None;
+            for i in 0..trees.len() {
+                for j in i + 1..trees.len() {
+                    let (lm, l, _) = &trees[i];
+                    let (rm, r, _) = &trees[j];
+                    if let Some(est) = self.estimate_join(*lm, *l, *rm, *r)
+                        && best.is_none_or(|(_, _, b)| est.rows < b.rows)
+                    {
+                        best = Some((i, j, est));
+                    }
+                }
+            }
+
+            let (i, j, est) = best?;
+            let (rm, r, right) = trees.swap_remove(j);
+            let (lm, l, left) = trees.swap_remove(i);
+            let tree = if l.rows >= r.rows {
+                JoinTree::Join(Box::new(left), Box::new(right))
+            } else {
+                JoinTree::Join(Box::new(right), Box::new(left))
+            };
+            trees.push((lm | rm, est, tree));
+        }
+
+        trees.pop().map(|(_, est, tree)| (tree, est))
+    }
+
+    /// Returns the IR to replace the root of the join tree with, or `None` if the original order
+    /// should be kept.
+    fn reorder(
+        &self,
+        root: Node,
+        ir_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> ApolloResult<Option<IR>> {
+        let Some((_, original)) = self.estimate_tree(&self.original) else {
+            return Ok(None);
+        };
+        let best = if self.relations.len() <= MAX_DP_RELATIONS {
+            self.best_tree_dp()
+        } else {
+            self.best_tree_greedy()
+        };
+        let Some((tree, est)) = best else {
+            return Ok(None);
+        };
+
+        // Not worth the churn.
+        if est.cost >= original.cost * 0.9 {
+            return Ok(None);
+        }
+
+        let Some((node, owners)) = self.build(&tree, ir_arena, expr_arena)? else {
+            return Ok(None);
+        };
+        let Some(ir) = self.restore_output(node, &owners, root, ir_arena, expr_arena) else {
+            return Ok(None);
+        };
+
+        if verbose() {
+            eprintln!(
+                "reordered join of {} inputs; estimated cost {:.0} -> {:.0}",
+                self.relations.len(),
+                original.cost,
+                est.cost
+            );
+        }
+
+        Ok(Some(ir))
+    }
+
+    /// Builds the IR for `tree`, returning the root node and the owners of its output columns.
+    fn build(
+        &self,
+        tree: &JoinTree,
+        ir_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> ApolloResult<Option<(Node, Owners)>> {
+        let (left, right) = match tree {
+            JoinTree::Relation(idx) => {
+                let node = self.relations[*idx].node;
+                let schema = ir_arena.get(node).schema(ir_arena);
+                let owners = schema.iter_names().map(|name| (name.clone(), *idx));
+                return Ok(Some((node, owners.collect())));
+            },
+            JoinTree::Join(left, right) => (left, right),
+        };
+
+        let Some((input_left, mut owners)) = self.build(left, ir_arena, expr_arena)? else {
+            return Ok(None);
+        };
+        let Some((input_right, right_owners)) = self.build(right, ir_arena, expr_arena)? else {
+            return Ok(None);
+        };
+
+        // Join on every key class that spans both sides, using any member that is still part of
+        // the output of each side.
+        let present = |owners: &Owners, class: &KeyClass| {
+            class
+                .members
+                .iter()
+                .find(|(rel, name)| owners.get(name) == Some(rel))
+                .map(|(_, name)| name.clone())
+        };
+        let mut left_on = vec![];
+        let mut right_on = vec![];
+        for class in &self.classes {
+            match (present(&owners, class), present(&right_owners, class)) {
+                (Some(l), Some(r)) => {
+                    left_on.push(column_expr(&l, expr_arena));
+                    right_on.push(column_expr(&r, expr_arena));
+                },
+                _ => continue,
+            }
+        }
+        if left_on.is_empty() {
+            return Ok(None);
+        }
+
+        let right_keys: PlHashSet<_> = right_on.iter().map(|e| e.output_name().clone()).collect();
+        for (name, idx) in right_owners {
+            if !right_keys.contains(&name) && owners.insert(name, idx).is_some() {
+                return Ok(None);
+            }
+        }
+
+        let schema = det_join_schema(
+            &ir_arena.get(input_left).schema(ir_arena),
+            &ir_arena.get(input_right).schema(ir_arena),
+            &left_on,
+            &right_on,
+            &self.options,
+            expr_arena,
+        )?;
+        let node = ir_arena.add(IR::Join {
+            input_left,
+            input_right,
+            schema,
+            left_on,
+            right_on,
+            options: self.options.clone(),
+        });
+
+        Ok(Some((node, owners)))
+    }
+
+    /// Projects the output of the reordered tree back to the schema of the original root. Key
+    /// columns that were coalesced differently are taken from an equivalent key column.
+    fn restore_output(
+        &self,
+        input: Node,
+        owners: &Owners,
+        root: Node,
+        ir_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Option<IR> {
+        let schema = ir_arena.get(root).schema(ir_arena).into_owned();
+        let input_schema = ir_arena.get(input).schema(ir_arena).into_owned();
+
+        let mut exprs = Vec::with_capacity(schema.len());
+        let mut needs_alias = false;
+
+        for (name, dtype) in schema.iter() {
+            let rel = *self.output.get(name)?;
+
+            if owners.get(name) == Some(&rel) {
+                exprs.push(column_expr(name, expr_arena));
+                continue;
+            }
+
+            let class = &self.classes[self.class_of((rel, name.as_str()))?];
+            let (_, source) = class
+                .members
+                .iter()
+                .find(|(rel, name)| owners.get(name) == Some(rel))?;
+            if input_schema.get(source)? != dtype {
+                return None;
+            }
+
+            let e = column_expr(source, expr_arena);
+            exprs.push(if source == name {
+                e
+            } else {
+                needs_alias = true;
+                e.with_alias(name.clone())
+            });
+        }
+
+        Some(if needs_alias {
+            IR::Select {
+                input,
+                expr: exprs,
+                schema,
+                options: ProjectionOptions::default(),
+            }
+        } else {
+            IR::SimpleProjection {
+                input,
+                columns: schema,
+            }
+        })
+    }
+}
+
+fn estimate_rows(node: Node, ir_arena: &Arena<IR>) -> Option<f64> {
+    use IR::*;
+
+    Some(match ir_arena.get(node) {
+        Scan {
+            file_info,
+            predicate,
+            ..
+        } => {
+            let (known, estimated) = file_info.row_estimation;
+            let rows = known.unwrap_or(estimated);
+            if rows == usize::MAX {
+                return None;
+            }
+            let rows = rows as f64;
+            if predicate.is_some() {
+                rows * FILTER_SELECTIVITY
+            } else {
+                rows
+            }
+        },
+        DataFrameScan { df, .. } => df.height() as f64,
+        Filter { input, .. } => estimate_rows(*input, ir_arena)? * FILTER_SELECTIVITY,
+        Slice { input, len, .. } => {
+            let len = *len as f64;
+            estimate_rows(*input, ir_arena).map_or(len, |rows| rows.min(len))
+        },
+        // Upper bounds.
+        Select { input, .. }
+        | SimpleProjection { input, .. }
+        | HStack { input, .. }
+        | Sort { input, .. }
+        | Cache { input, .. }
+        | GroupBy { input, .. }
+        | Distinct { input, .. } => estimate_rows(*input, ir_arena)?,
+        _ => return None,
+    })
+}
+
+fn distinct_count(node: Node, name: &str, ir_arena: &Arena<IR>) -> Option<f64> {
+    use IR::*;
+
+    match ir_arena.get(node) {
+        #[cfg(feature = "parquet")]
+        Scan {
+            sources, scan_type, ..
+        } if sources.len() == 1 => match scan_type.as_ref() {
+            FileScanIR::Parquet {
+                metadata: Some(metadata),
+                ..
+            } => parquet_distinct_count(metadata, name),
+            _ => None,
+        },
+        Filter { input, .. }
+        | SimpleProjection { input, .. }
+        | Sort { input, .. }
+        | Cache { input, .. } => distinct_count(*input, name, ir_arena),
+        _ => None,
+    }
+}
+
+/// Estimates the number of distinct values of a column from its row group statistics.
+#[cfg(feature = "parquet")]
+fn parquet_distinct_count(
+    metadata: &apollo_io::parquet::metadata::FileMetadata,
+    name: &str,
+) -> Option<f64> {
+    use apollo_parquet::parquet::statistics::Statistics;
+
+    let mut distinct = None;
+    let mut range: Option<(i64, i64)> = None;
+    let mut has_range = true;
+
+    for rg in &metadata.row_groups {
+        let mut columns = rg.columns_under_root_iter(name)?;
+        if columns.len() != 1 {
+            return None;
+        }
+        let stats = columns.next()?.statistics()?.ok()?;
+
+        let (d, min_max) = match &stats {
+            Statistics::Int32(s) => (
+                s.distinct_count,
+                s.min_value
+                    .zip(s.max_value)
+                    .map(|(l, h)| (l as i64, h as i64)),
+            ),
+            Statistics::Int64(s) => (s.distinct_count, s.min_value.zip(s.max_value)),
+            Statistics::Binary(s) => (s.distinct_count, None),
+            Statistics::FixedLen(s) => (s.distinct_count, None),
+            _ => (None, None),
+        };
+
+        // Distinct counts of row groups can't be merged, the largest one is a lower bound.
+        if let Some(d) = d {
+            distinct = Some(distinct.map_or(d, |v: i64| v.max(d)));
+        }
+        match min_max {
+            Some((l, h)) => {
+                range = Some(range.map_or((l, h), |(rl, rh)| (rl.min(l), rh.max(h))));
+            },
+            None => has_range = false,
+        }
+    }
+
+    let rows = metadata.num_rows as f64;
+    match (
+        metadata.row_groups.len(),
+        distinct,
+        range.filter(|_| has_range),
+    ) {
+        (1, Some(d), _) => Some(d as f64),
+        // For integers, the range bounds the number of distinct values.
+        (_, d, Some((l, h))) => {
+            let upper = ((h as f64) - (l as f64) + 1.0).min(rows);
+            Some(d.map_or(upper, |d| upper.max(d as f64)))
+        },
+        (_, Some(d), None) => Some(d as f64),
+        _ => None,
+    }
+}
@@ -14,6 +14,7 @@ mod cse;
 mod flatten_union;
 #[cfg(feature = "fused")]
 mod fused;
+mod join_reorder;
 mod join_utils;
 pub(crate) use join_utils::ExprOrigin;
 mod expand_datasets;
@@ -206,6 +207,12 @@ pub fn optimize(
         )?;
     }
 
+    // Run after predicate pushdown, so that filters are estimated at the join inputs.
+    if opt_flags.join_reorder() && !opt_flags.eager() && get_or_init_members!().has_joins_or_unions
+    {
+        join_reorder::optimize(root, ir_arena, expr_arena)?;
+    }
+
     // Make sure its before slice pushdown.
     if opt_flags.fast_projection() {
         rules.push(Box::new(SimpleProjectionAndCollapse::new(
@@ -300,6 +307,12 @@ pub fn optimize(
 
     expand_datasets::expand_datasets(root, ir_arena, expr_arena, apply_scan_predicate_to_scan_ir)?;
 
+    // This must run last, as it fingerprints the final plan.
+    #[cfg(feature = "cse")]
+    if opt_flags.result_cache() {
+        cse::apply_result_cache(root, ir_arena, expr_arena, verbose);
+    }
+
     // During debug we check if the optimizations have not modified the final schema.
     #[cfg(debug_assertions)]
     {
@@ -228,6 +228,12 @@ impl SlicePushDown {
                 #[cfg(feature = "ipc")]
                 FileScanIR::Ipc { .. } => true,
 
+                #[cfg(feature = "avro")]
+                FileScanIR::Avro => true,
+
+                #[cfg(feature = "orc")]
+                FileScanIR::Orc => true,
+
                 #[cfg(feature = "csv")]
                 FileScanIR::Csv { .. } => true,
 
@@ -273,6 +273,17 @@ pub fn _apollo_runtime(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     #[cfg(feature = "parquet")]
     m.add_wrapped(wrap_pyfunction!(functions::read_parquet_metadata))
         .unwrap();
+    #[cfg(feature = "parquet")]
+    m.add_wrapped(wrap_pyfunction!(functions::prewarm_parquet_metadata_cache))
+        .unwrap();
+    #[cfg(feature = "parquet")]
+    m.add_wrapped(wrap_pyfunction!(
+        functions::invalidate_parquet_metadata_cache
+    ))
+    .unwrap();
+    #[cfg(feature = "csv")]
+    m.add_wrapped(wrap_pyfunction!(functions::sniff_csv_dialect))
+        .unwrap();
     #[cfg(feature = "clipboard")]
     m.add_wrapped(wrap_pyfunction!(functions::read_clipboard_string))
         .unwrap();
@@ -344,6 +355,8 @@ pub fn _apollo_runtime(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(extension::_unregister_extension_type))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(extension::generate_uuid))
+        .unwrap();
 
     // Testing
     m.add_wrapped(wrap_pyfunction!(testing::assert_series_equal_py))
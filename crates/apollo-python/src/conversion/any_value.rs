@@ -131,6 +131,7 @@ pub(crate) fn any_value_into_py_object<'py>(
             let s = buf.format_dec128(v, scale, false, false);
             convert.call1((prec, s))
         },
+        AnyValue::Interval(v) => (v.months(), v.days(), v.ns()).into_bound_py_any(py),
     }
 }
 
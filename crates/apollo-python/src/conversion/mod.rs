@@ -14,6 +14,8 @@ use apollo::chunked_array::object::ApolloObjectSafe;
 use apollo::frame::row::Row;
 #[cfg(feature = "avro")]
 use apollo::io::avro::AvroCompression;
+#[cfg(feature = "orc")]
+use apollo::io::orc::OrcCompression;
 use apollo::prelude::ColumnMapping;
 use apollo::prelude::default_values::{
     DefaultFieldValues, IcebergIdentityTransformedPartitionFields,
@@ -22,11 +24,13 @@ use apollo::prelude::deletion::DeletionFilesList;
 use apollo::series::ops::NullBehavior;
 use apollo_buffer::Buffer;
 use apollo_compute::decimal::dec128_verify_prec_scale;
-use apollo_core::datatypes::extension::get_extension_type_or_generic;
+use apollo_core::datatypes::extension::{UuidVersion, get_extension_type_or_generic};
 use apollo_core::schema::iceberg::IcebergSchema;
 use apollo_core::utils::arrow::array::Array;
 use apollo_core::utils::materialize_dyn_int;
 use apollo_lazy::prelude::*;
+#[cfg(feature = "array_vector")]
+use apollo_ops::chunked_array::array::VectorMetric;
 #[cfg(feature = "parquet")]
 use apollo_parquet::write::StatisticsOptions;
 use apollo_plan::dsl::ScanSources;
@@ -270,6 +274,12 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
                 let inner = Wrap(*inner.clone());
                 class.call1((&inner,))
             },
+            DataType::Map(key, value) => {
+                let class = pl.getattr(intern!(py, "Map"))?;
+                let key = Wrap(*key.clone());
+                let value = Wrap(*value.clone());
+                class.call1((&key, &value))
+            },
             DataType::Date => {
                 let class = pl.getattr(intern!(py, "Date"))?;
                 class.call0()
@@ -282,6 +292,10 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
                 let duration_class = pl.getattr(intern!(py, "Duration"))?;
                 duration_class.call1((tu.to_ascii(),))
             },
+            DataType::Interval => {
+                let class = pl.getattr(intern!(py, "Interval"))?;
+                class.call0()
+            },
             #[cfg(feature = "object")]
             DataType::Object(_) => {
                 let class = pl.getattr(intern!(py, "Object"))?;
@@ -318,6 +332,17 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
                 let struct_class = pl.getattr(intern!(py, "Struct"))?;
                 struct_class.call1((fields,))
             },
+            DataType::Union(fields, _) => {
+                let field_class = pl.getattr(intern!(py, "Field"))?;
+                let iter = fields.iter().map(|fld| {
+                    let name = fld.name().as_str();
+                    let dtype = Wrap(fld.dtype().clone());
+                    field_class.call1((name, &dtype)).unwrap()
+                });
+                let fields = PyList::new(py, iter)?;
+                let union_class = pl.getattr(intern!(py, "Union"))?;
+                union_class.call1((fields,))
+            },
             DataType::Null => {
                 let class = pl.getattr(intern!(py, "Null"))?;
                 class.call0()
@@ -404,9 +429,12 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<DataType> {
                     "Time" => DataType::Time,
                     "Datetime" => DataType::Datetime(TimeUnit::Microseconds, None),
                     "Duration" => DataType::Duration(TimeUnit::Microseconds),
+                    "Interval" => DataType::Interval,
                     "List" => DataType::List(Box::new(DataType::Null)),
                     "Array" => DataType::Array(Box::new(DataType::Null), 0),
                     "Struct" => DataType::Struct(vec![]),
+                    "Union" => DataType::Union(vec![], Default::default()),
+                    "Map" => DataType::Map(Box::new(DataType::Null), Box::new(DataType::Null)),
                     "Null" => DataType::Null,
                     #[cfg(feature = "object")]
                     "Object" => DataType::Object(OBJECT_NAME),
@@ -490,6 +518,13 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<DataType> {
                 let size = size.extract::<usize>()?;
                 DataType::Array(Box::new(inner.0), size)
             },
+            "Map" => {
+                let key = ob.getattr(intern!(py, "key")).unwrap();
+                let value = ob.getattr(intern!(py, "value")).unwrap();
+                let key = key.extract::<Wrap<DataType>>()?;
+                let value = value.extract::<Wrap<DataType>>()?;
+                DataType::Map(Box::new(key.0), Box::new(value.0))
+            },
             "Struct" => {
                 let fields = ob.getattr(intern!(py, "fields"))?;
                 let fields = fields
@@ -499,6 +534,15 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<DataType> {
                     .collect::<Vec<Field>>();
                 DataType::Struct(fields)
             },
+            "Union" => {
+                let fields = ob.getattr(intern!(py, "fields"))?;
+                let fields = fields
+                    .extract::<Vec<Wrap<Field>>>()?
+                    .into_iter()
+                    .map(|f| f.0)
+                    .collect::<Vec<Field>>();
+                DataType::Union(fields, Default::default())
+            },
             "Null" => DataType::Null,
             #[cfg(feature = "object")]
             "Object" => DataType::Object(OBJECT_NAME),
@@ -885,6 +929,26 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<Option<AvroCompression>> {
     }
 }
 
+#[cfg(feature = "orc")]
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<Option<OrcCompression>> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "uncompressed" => None,
+            "zlib" => Some(OrcCompression::Zlib),
+            "snappy" => Some(OrcCompression::Snappy),
+            "zstd" => Some(OrcCompression::Zstd),
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "orc `compression` must be one of {{'uncompressed', 'zlib', 'snappy', 'zstd'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<StartBy> {
     type Error = PyErr;
 
@@ -953,9 +1017,15 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<CsvEncoding> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
             "utf8" => CsvEncoding::Utf8,
             "utf8-lossy" => CsvEncoding::LossyUtf8,
+            "latin1" => CsvEncoding::Latin1,
+            "windows-1252" => CsvEncoding::Windows1252,
+            "shift_jis" => CsvEncoding::ShiftJis,
+            "utf-16-le" => CsvEncoding::Utf16Le,
+            "utf-16-be" => CsvEncoding::Utf16Be,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "csv `encoding` must be one of {{'utf8', 'utf8-lossy'}}, got {v}",
+                    "csv `encoding` must be one of {{'utf8', 'utf8-lossy', 'latin1', \
+                    'windows-1252', 'shift_jis', 'utf-16-le', 'utf-16-be'}}, got {v}",
                 )));
             },
         };
@@ -1265,6 +1335,23 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<SearchSortedSide> {
     }
 }
 
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<UuidVersion> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match ob.extract::<u8>()? {
+            4 => UuidVersion::V4,
+            7 => UuidVersion::V7,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`version` must be one of {{4, 7}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<ClosedInterval> {
     type Error = PyErr;
 
@@ -1361,6 +1448,23 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<QuoteStyle> {
     }
 }
 
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<BucketHash> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "spark" => BucketHash::Spark,
+            "hive" => BucketHash::Hive,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`bucket_hash` must be one of {{'spark', 'hive'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "list_sets")]
 impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<SetOperation> {
     type Error = PyErr;
@@ -1658,6 +1762,47 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<UnicodeForm> {
     }
 }
 
+#[cfg(feature = "hash_digest")]
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<HashAlgorithm> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "md5" => HashAlgorithm::Md5,
+            "sha1" => HashAlgorithm::Sha1,
+            "sha256" => HashAlgorithm::Sha256,
+            "xxhash64" => HashAlgorithm::XxHash64,
+            "murmur3_32" => HashAlgorithm::Murmur3_32,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`algorithm` must be one of {{'md5', 'sha1', 'sha256', 'xxhash64', 'murmur3_32'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+#[cfg(feature = "array_vector")]
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<VectorMetric> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "dot" => VectorMetric::Dot,
+            "cosine" => VectorMetric::Cosine,
+            "euclidean" => VectorMetric::Euclidean,
+            "manhattan" => VectorMetric::Manhattan,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`metric` must be one of {{'dot', 'cosine', 'euclidean', 'manhattan'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "parquet")]
 impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<Option<KeyValueMetadata>> {
     type Error = PyErr;
@@ -5,6 +5,8 @@ use std::sync::Arc;
 use apollo::io::RowIndex;
 #[cfg(feature = "avro")]
 use apollo::io::avro::AvroCompression;
+#[cfg(feature = "orc")]
+use apollo::io::orc::OrcCompression;
 use apollo::prelude::*;
 use pyo3::prelude::*;
 use pyo3::pybacked::PyBackedStr;
@@ -24,7 +26,8 @@ impl PyDataFrame {
     skip_rows, skip_lines, projection, separator, rechunk, columns, encoding, n_threads, path,
     overwrite_dtype, overwrite_dtype_slice, low_memory, comment_prefix, quote_char,
     null_values, missing_utf8_is_empty_string, try_parse_dates, skip_rows_after_header,
-    row_index, eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma, schema)
+    row_index, eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma, schema,
+    sniff_dialect, reject_rows)
 )]
     pub fn read_csv(
         py: Python<'_>,
@@ -58,6 +61,8 @@ impl PyDataFrame {
         truncate_ragged_lines: bool,
         decimal_comma: bool,
         schema: Option<Wrap<Schema>>,
+        sniff_dialect: Option<usize>,
+        reject_rows: Option<Wrap<PlRefPath>>,
     ) -> PyResult<Self> {
         let null_values = null_values.map(|w| w.0);
         let eol_char = eol_char.as_bytes()[0];
@@ -106,6 +111,8 @@ impl PyDataFrame {
                 .with_skip_rows_after_header(skip_rows_after_header)
                 .with_row_index(row_index)
                 .with_raise_if_empty(raise_if_empty)
+                .with_sniff_dialect(sniff_dialect)
+                .with_reject_rows(reject_rows.map(|r| r.0))
                 .with_parse_options(
                     CsvParseOptions::default()
                         .with_separator(separator.as_bytes()[0])
@@ -234,6 +241,34 @@ impl PyDataFrame {
         })
     }
 
+    #[staticmethod]
+    #[cfg(feature = "orc")]
+    #[pyo3(signature = (py_f, columns, projection, n_rows, row_index))]
+    pub fn read_orc(
+        py: Python<'_>,
+        py_f: Bound<PyAny>,
+        columns: Option<Vec<String>>,
+        projection: Option<Vec<usize>>,
+        n_rows: Option<usize>,
+        row_index: Option<(String, IdxSize)>,
+    ) -> PyResult<Self> {
+        use apollo::io::orc::OrcReader;
+
+        let row_index = row_index.map(|(name, offset)| RowIndex {
+            name: name.into(),
+            offset,
+        });
+        let mmap_bytes_r = get_mmap_bytes_reader(&py_f)?;
+        py.enter_apollo_df(move || {
+            OrcReader::new(mmap_bytes_r)
+                .with_projection(projection)
+                .with_columns(columns)
+                .with_n_rows(n_rows)
+                .with_row_index(row_index)
+                .finish()
+        })
+    }
+
     #[cfg(feature = "json")]
     pub fn write_json(&self, py: Python<'_>, py_f: Py<PyAny>) -> PyResult<()> {
         let file = BufWriter::new(get_file_like(py_f, true)?);
@@ -281,4 +316,23 @@ impl PyDataFrame {
                 .finish(&mut self.df.write())
         })
     }
+
+    #[cfg(feature = "orc")]
+    #[pyo3(signature = (py_f, compression, stripe_size))]
+    pub fn write_orc(
+        &self,
+        py: Python<'_>,
+        py_f: Py<PyAny>,
+        compression: Wrap<Option<OrcCompression>>,
+        stripe_size: Option<usize>,
+    ) -> PyResult<()> {
+        use apollo::io::orc::OrcWriter;
+        let mut buf = get_file_like(py_f, true)?;
+        py.enter_apollo(|| {
+            OrcWriter::new(&mut buf)
+                .with_compression(compression.0)
+                .with_stripe_size(stripe_size)
+                .finish(&mut self.df.write())
+        })
+    }
 }
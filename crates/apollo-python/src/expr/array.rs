@@ -1,8 +1,12 @@
 use apollo::prelude::*;
+#[cfg(feature = "array_vector")]
+use apollo_ops::chunked_array::array::VectorMetric;
 use apollo_utils::python_function::PythonObject;
 use pyo3::prelude::*;
 use pyo3::pymethods;
 
+#[cfg(feature = "array_vector")]
+use crate::conversion::Wrap;
 use crate::error::PyApolloErr;
 use crate::expr::PyExpr;
 
@@ -118,6 +122,29 @@ impl PyExpr {
         self.inner.clone().arr().count_matches(expr.inner).into()
     }
 
+    #[cfg(feature = "array_vector")]
+    fn arr_vector_metric(&self, other: PyExpr, metric: Wrap<VectorMetric>) -> Self {
+        self.inner
+            .clone()
+            .arr()
+            .vector_metric(other.inner, metric.0)
+            .into()
+    }
+
+    #[cfg(feature = "array_vector")]
+    fn arr_normalize(&self) -> Self {
+        self.inner.clone().arr().normalize().into()
+    }
+
+    #[cfg(feature = "array_vector")]
+    fn arr_nearest(&self, query: PyExpr, k: PyExpr, metric: Wrap<VectorMetric>) -> Self {
+        self.inner
+            .clone()
+            .arr()
+            .nearest(query.inner, k.inner, metric.0)
+            .into()
+    }
+
     #[pyo3(signature = (name_gen))]
     fn arr_to_struct(&self, name_gen: Option<Py<PyAny>>) -> Self {
         let name_gen = name_gen.map(|o| PlanCallback::new_python(PythonObject(o)));
@@ -1,7 +1,11 @@
+#[cfg(feature = "hash_digest")]
+use apollo::prelude::HashAlgorithm;
 use pyo3::prelude::*;
 
 use super::datatype::PyDataTypeExpr;
 use crate::PyExpr;
+#[cfg(feature = "hash_digest")]
+use crate::conversion::Wrap;
 
 #[pymethods]
 impl PyExpr {
@@ -62,6 +66,11 @@ impl PyExpr {
             .into())
     }
 
+    #[cfg(feature = "hash_digest")]
+    fn bin_digest(&self, algorithm: Wrap<HashAlgorithm>, hex: bool) -> Self {
+        self.inner.clone().binary().digest(algorithm.0, hex).into()
+    }
+
     fn bin_size_bytes(&self) -> Self {
         self.inner.clone().binary().size_bytes().into()
     }
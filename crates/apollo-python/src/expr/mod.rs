@@ -15,6 +15,8 @@ mod extension;
 mod general;
 #[cfg(feature = "pymethods")]
 mod list;
+#[cfg(feature = "pymethods")]
+mod map;
 #[cfg(all(feature = "meta", feature = "pymethods"))]
 mod meta;
 #[cfg(feature = "pymethods")]
@@ -28,6 +30,8 @@ mod serde;
 mod string;
 #[cfg(feature = "pymethods")]
 mod r#struct;
+#[cfg(feature = "pymethods")]
+mod union;
 
 use std::mem::ManuallyDrop;
 
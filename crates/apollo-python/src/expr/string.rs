@@ -165,6 +165,85 @@ impl PyExpr {
         self.inner.clone().str().reverse().into()
     }
 
+    #[cfg(feature = "hash_digest")]
+    fn str_digest(&self, algorithm: Wrap<HashAlgorithm>, hex: bool) -> Self {
+        self.inner.clone().str().digest(algorithm.0, hex).into()
+    }
+
+    #[pyo3(signature = (other, max_distance))]
+    fn str_levenshtein_distance(&self, other: Self, max_distance: Option<u32>) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .levenshtein_distance(other.inner, max_distance)
+            .into()
+    }
+
+    #[pyo3(signature = (other, max_distance))]
+    fn str_damerau_levenshtein_distance(&self, other: Self, max_distance: Option<u32>) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .damerau_levenshtein_distance(other.inner, max_distance)
+            .into()
+    }
+
+    fn str_hamming_distance(&self, other: Self) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .hamming_distance(other.inner)
+            .into()
+    }
+
+    fn str_jaro_winkler_similarity(&self, other: Self) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .jaro_winkler_similarity(other.inner)
+            .into()
+    }
+
+    fn str_jaccard_similarity(&self, other: Self, q: usize) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .jaccard_similarity(other.inner, q)
+            .into()
+    }
+
+    fn str_parse_url(&self) -> Self {
+        self.inner.clone().str().parse_url().into()
+    }
+
+    fn str_url_query_param(&self, name: &str) -> Self {
+        self.inner.clone().str().url_query_param(name.into()).into()
+    }
+
+    fn str_ipv4_to_int(&self) -> Self {
+        self.inner.clone().str().ipv4_to_int().into()
+    }
+
+    fn str_ipv6_to_int(&self) -> Self {
+        self.inner.clone().str().ipv6_to_int().into()
+    }
+
+    fn str_ip_to_binary(&self) -> Self {
+        self.inner.clone().str().ip_to_binary().into()
+    }
+
+    fn str_ip_in_cidr(&self, cidr: Self) -> Self {
+        self.inner.clone().str().ip_in_cidr(cidr.inner).into()
+    }
+
+    fn str_ip_mask(&self, ipv4_prefix: u8, ipv6_prefix: u8) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .ip_mask(ipv4_prefix, ipv6_prefix)
+            .into()
+    }
+
     fn str_pad_start(&self, length: PyExpr, fill_char: char) -> Self {
         self.inner
             .clone()
@@ -335,6 +414,10 @@ impl PyExpr {
         self.inner.clone().str().to_decimal(scale).into()
     }
 
+    fn str_to_interval(&self, strict: bool) -> Self {
+        self.inner.clone().str().to_interval(strict).into()
+    }
+
     #[cfg(feature = "find_many")]
     fn str_contains_any(&self, patterns: PyExpr, ascii_case_insensitive: bool) -> Self {
         self.inner
@@ -0,0 +1,14 @@
+use pyo3::prelude::*;
+
+use crate::PyExpr;
+
+#[pymethods]
+impl PyExpr {
+    fn union_tag(&self) -> Self {
+        self.inner.clone().union().tag().into()
+    }
+
+    fn union_field(&self, name: &str) -> Self {
+        self.inner.clone().union().field(name).into()
+    }
+}
@@ -5,11 +5,18 @@ use std::sync::Arc;
 
 use apollo::prelude::PlFixedStateQuality;
 use apollo::prelude::extension::{register_extension_type, unregister_extension_type};
-use apollo_core::datatypes::DataType;
-use apollo_core::datatypes::extension::{ExtensionTypeFactory, ExtensionTypeImpl};
+use apollo_core::chunked_array::cast::CastOptions;
+use apollo_core::datatypes::extension::{ExtensionTypeFactory, ExtensionTypeImpl, UuidVersion};
+use apollo_core::datatypes::{AnyValue, DataType};
+use apollo_core::prelude::{ApolloResult, Series};
+use pyo3::intern;
 use pyo3::prelude::*;
 
+use crate::PyExpr;
+use crate::conversion::{get_series, to_series};
 use crate::prelude::Wrap;
+use crate::py_modules::apollo;
+use crate::series::PySeries;
 use crate::utils::to_py_err;
 
 struct PyExtensionTypeFactory {
@@ -21,6 +28,47 @@ struct PyExtensionTypeImpl {
     name: String,
     display: String,
     metadata: Option<String>,
+    typ_obj: Arc<Py<PyAny>>,
+    hooks: PyExtensionHooks,
+}
+
+/// The optional hooks a Python extension type overrides. Hooks that aren't
+/// overridden are skipped without calling into Python.
+#[derive(Clone, Copy)]
+struct PyExtensionHooks {
+    format_value: bool,
+    cast_to: bool,
+    cast_from: bool,
+    comparison_key: bool,
+    validate: bool,
+}
+
+impl PyExtensionHooks {
+    fn new(cls: &Bound<PyAny>) -> PyResult<Self> {
+        let py = cls.py();
+        let base = apollo(py)
+            .bind(py)
+            .getattr(intern!(py, "datatypes"))?
+            .getattr(intern!(py, "BaseExtension"))?;
+        let overrides = |method: &str| -> PyResult<bool> {
+            Ok(!cls.getattr(method)?.is(&base.getattr(method)?))
+        };
+        Ok(Self {
+            format_value: overrides("ext_format_value")?,
+            cast_to: overrides("ext_cast_to")?,
+            cast_from: overrides("ext_cast_from")?,
+            comparison_key: overrides("ext_comparison_key")?,
+            validate: overrides("ext_validate")?,
+        })
+    }
+}
+
+fn extract_opt_series(obj: &Bound<PyAny>) -> PyResult<Option<Series>> {
+    if obj.is_none() {
+        Ok(None)
+    } else {
+        get_series(obj).map(Some)
+    }
 }
 
 impl ExtensionTypeFactory for PyExtensionTypeFactory {
@@ -31,9 +79,8 @@ impl ExtensionTypeFactory for PyExtensionTypeFactory {
         metadata: Option<&str>,
     ) -> Box<dyn ExtensionTypeImpl> {
         Python::attach(|py| {
-            let typ_obj = self
-                .cls
-                .bind(py)
+            let cls = self.cls.bind(py);
+            let typ_obj = cls
                 .call_method1("ext_from_params", (name, &Wrap(storage.clone()), metadata))
                 .unwrap();
 
@@ -52,6 +99,8 @@ impl ExtensionTypeFactory for PyExtensionTypeFactory {
                 name: name.to_string(),
                 display,
                 metadata,
+                hooks: PyExtensionHooks::new(cls).unwrap(),
+                typ_obj: Arc::new(typ_obj.unbind()),
             })
         })
     }
@@ -99,6 +148,81 @@ impl ExtensionTypeImpl for PyExtensionTypeImpl {
             Cow::Owned(format!("PyExtensionType(name='{}')", self.name))
         }
     }
+
+    fn fmt_value(&self, value: &AnyValue<'_>) -> Option<String> {
+        if !self.hooks.format_value {
+            return None;
+        }
+        // Formatting is for display only, so fall back to the storage on errors.
+        Python::attach(|py| {
+            self.typ_obj
+                .bind(py)
+                .call_method1(intern!(py, "ext_format_value"), (Wrap(value.clone()),))
+                .and_then(|out| out.extract::<Option<String>>())
+                .ok()
+                .flatten()
+        })
+    }
+
+    fn cast_to(
+        &self,
+        storage: &Series,
+        dtype: &DataType,
+        options: CastOptions,
+    ) -> ApolloResult<Option<Series>> {
+        if !self.hooks.cast_to {
+            return Ok(None);
+        }
+        Python::attach(|py| {
+            let s = to_series(py, PySeries::new(storage.clone()))?;
+            let out = self.typ_obj.bind(py).call_method1(
+                intern!(py, "ext_cast_to"),
+                (s, Wrap(dtype.clone()), options.is_strict()),
+            )?;
+            Ok(extract_opt_series(&out)?)
+        })
+    }
+
+    fn cast_from(&self, s: &Series, options: CastOptions) -> ApolloResult<Option<Series>> {
+        if !self.hooks.cast_from {
+            return Ok(None);
+        }
+        Python::attach(|py| {
+            let s = to_series(py, PySeries::new(s.clone()))?;
+            let out = self
+                .typ_obj
+                .bind(py)
+                .call_method1(intern!(py, "ext_cast_from"), (s, options.is_strict()))?;
+            Ok(extract_opt_series(&out)?)
+        })
+    }
+
+    fn comparison_key(&self, storage: &Series) -> ApolloResult<Option<Series>> {
+        if !self.hooks.comparison_key {
+            return Ok(None);
+        }
+        Python::attach(|py| {
+            let s = to_series(py, PySeries::new(storage.clone()))?;
+            let out = self
+                .typ_obj
+                .bind(py)
+                .call_method1(intern!(py, "ext_comparison_key"), (s,))?;
+            Ok(extract_opt_series(&out)?)
+        })
+    }
+
+    fn validate(&self, storage: &Series) -> ApolloResult<()> {
+        if !self.hooks.validate {
+            return Ok(());
+        }
+        Python::attach(|py| {
+            let s = to_series(py, PySeries::new(storage.clone()))?;
+            self.typ_obj
+                .bind(py)
+                .call_method1(intern!(py, "ext_validate"), (s,))?;
+            Ok(())
+        })
+    }
 }
 
 #[pyfunction]
@@ -118,3 +242,8 @@ pub fn _register_extension_type(name: &str, cls: Option<&Bound<PyAny>>) -> PyRes
 pub fn _unregister_extension_type(name: &str) -> PyResult<()> {
     unregister_extension_type(name).map(drop).map_err(to_py_err)
 }
+
+#[pyfunction]
+pub fn generate_uuid(n: PyExpr, version: Wrap<UuidVersion>) -> PyExpr {
+    apollo::lazy::dsl::generate_uuid(n.inner, version.0).into()
+}
@@ -88,6 +88,79 @@ pub fn read_parquet_metadata(
     Ok(dict.unbind())
 }
 
+#[cfg(feature = "parquet")]
+#[pyfunction]
+pub fn prewarm_parquet_metadata_cache(
+    py: Python,
+    paths: Vec<Wrap<apollo_utils::pl_path::PlRefPath>>,
+    storage_options: OptPyCloudOptions,
+    credential_provider: Option<Py<PyAny>>,
+) -> PyResult<()> {
+    use apollo_io::parquet::metadata_cache;
+    use apollo_io::pl_async::get_runtime;
+
+    let paths: Vec<_> = paths.into_iter().map(|p| p.0).collect();
+
+    let Some(first_path) = paths.first() else {
+        return Ok(());
+    };
+
+    let cloud_options = storage_options.extract_opt_cloud_options(
+        CloudScheme::from_path(first_path.as_str()),
+        credential_provider,
+    )?;
+
+    py.detach(|| get_runtime().block_on(metadata_cache::prewarm(paths, cloud_options.as_ref())))
+        .map_err(PyApolloErr::from)?;
+
+    Ok(())
+}
+
+#[cfg(feature = "parquet")]
+#[pyfunction]
+#[pyo3(signature = (path=None))]
+pub fn invalidate_parquet_metadata_cache(path: Option<Wrap<apollo_utils::pl_path::PlRefPath>>) {
+    apollo_io::parquet::metadata_cache::invalidate(path.as_ref().map(|p| &p.0));
+}
+
+#[cfg(feature = "csv")]
+#[pyfunction]
+#[pyo3(signature = (py_f, sample_lines, encoding, eol_char, comment_prefix))]
+pub fn sniff_csv_dialect<'py>(
+    py: Python<'py>,
+    py_f: Bound<'py, PyAny>,
+    sample_lines: usize,
+    encoding: Wrap<apollo_io::csv::read::CsvEncoding>,
+    eol_char: &str,
+    comment_prefix: Option<&str>,
+) -> PyResult<Bound<'py, PyDict>> {
+    use apollo_io::csv::read::{CsvParseOptions, CsvReadOptions, sniff_dialect_from_slice};
+    use apollo_io::utils::get_reader_bytes;
+
+    use crate::file::get_mmap_bytes_reader;
+    use crate::utils::EnterApolloExt;
+
+    let options = CsvReadOptions::default().with_parse_options(
+        CsvParseOptions::default()
+            .with_encoding(encoding.0)
+            .with_eol_char(eol_char.as_bytes()[0])
+            .with_comment_prefix(comment_prefix),
+    );
+
+    let mut mmap_bytes_r = get_mmap_bytes_reader(&py_f)?;
+    let dialect = py.enter_apollo(|| {
+        let bytes = get_reader_bytes(&mut mmap_bytes_r)?;
+        sniff_dialect_from_slice(&bytes, &options, sample_lines)
+    })?;
+
+    let dict = PyDict::new(py);
+    dict.set_item("separator", dialect.separator.as_str())?;
+    dict.set_item("quote_char", dialect.quote_char.map(char::from))?;
+    dict.set_item("has_header", dialect.has_header)?;
+    dict.set_item("skip_lines", dialect.skip_lines)?;
+    Ok(dict)
+}
+
 #[cfg(any(feature = "ipc", feature = "parquet"))]
 fn fields_to_pydict(schema: &ArrowSchema, dict: &Bound<'_, PyDict>) -> PyResult<()> {
     for field in schema.iter_values() {
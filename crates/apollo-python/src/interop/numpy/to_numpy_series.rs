@@ -12,7 +12,7 @@ use super::utils::{
     create_borrowed_np_array, dtype_supports_view, apollo_dtype_to_np_temporal_dtype,
     reshape_numpy_array, series_contains_null,
 };
-use crate::conversion::ObjectValue;
+use crate::conversion::{ObjectValue, Wrap};
 use crate::conversion::chunked_array::{decimal_to_pyobject_iter, time_to_pyobject_iter};
 use crate::series::PySeries;
 
@@ -265,6 +265,19 @@ fn series_to_numpy_with_copy(py: Python<'_>, s: &Series, writable: bool) -> Py<P
                 .map(|v| v.into_py_any(py).unwrap());
             PyArray1::from_iter(py, values).into_py_any(py).unwrap()
         },
+        Interval => {
+            let ca = s.interval().unwrap();
+            let values = ca.physical().iter().map(|opt_v| {
+                opt_v
+                    .map(|v| {
+                        let v = i128_to_interval(v);
+                        (v.months(), v.days(), v.ns())
+                    })
+                    .into_py_any(py)
+                    .unwrap()
+            });
+            PyArray1::from_iter(py, values).into_py_any(py).unwrap()
+        },
         List(_) => list_series_to_numpy(py, s, writable),
         Array(_, _) => array_series_to_numpy(py, s, writable),
         Struct(_) => {
@@ -287,6 +300,13 @@ fn series_to_numpy_with_copy(py: Python<'_>, s: &Series, writable: bool) -> Py<P
             PyArray1::from_iter(py, values).into_py_any(py).unwrap()
         },
         Extension(_, _) => series_to_numpy_with_copy(py, s.ext().unwrap().storage(), writable),
+        Map(_, _) => series_to_numpy_with_copy(py, s.map_().unwrap().storage(), writable),
+        Union(_, _) => {
+            let ca = s.union().unwrap();
+            let values =
+                (0..ca.len()).map(|i| Wrap(ca.get_any_value(i).unwrap()).into_py_any(py).unwrap());
+            PyArray1::from_iter(py, values).into_py_any(py).unwrap()
+        },
         Unknown(_) | BinaryOffset => unreachable!(),
     }
 }
@@ -30,6 +30,7 @@ impl PySinkOptions<'_> {
             sync_on_close: Option<Wrap<SyncOnCloseType>>,
             storage_options: OptPyCloudOptions<'a>,
             credential_provider: Option<Py<PyAny>>,
+            atomic: bool,
         }
 
         let Extract {
@@ -38,6 +39,7 @@ impl PySinkOptions<'_> {
             sync_on_close,
             storage_options,
             credential_provider,
+            atomic,
         } = self.0.extract()?;
 
         let cloud_options =
@@ -50,6 +52,7 @@ impl PySinkOptions<'_> {
             maintain_order,
             sync_on_close,
             cloud_options: cloud_options.map(Arc::new),
+            atomic,
         };
 
         Ok(unified_sink_args)
@@ -1,5 +1,7 @@
 use apollo::prelude::file_provider::{FileProviderFunction, FileProviderType};
-use apollo::prelude::{PartitionStrategy, PlRefPath, SinkDestination, SpecialEq};
+use apollo::prelude::{
+    BucketHash, PartitionStrategy, PlRefPath, SinkDestination, SortMultipleOptions, SpecialEq,
+};
 use apollo_utils::IdxSize;
 use apollo_utils::python_function::PythonObject;
 use pyo3::intern;
@@ -42,8 +44,14 @@ impl PyFileSinkDestination<'_> {
             file_path_provider: Option<Py<PyAny>>,
             key: Option<Vec<PyExpr>>,
             include_key: Option<bool>,
+            num_buckets: Option<u32>,
+            bucket_hash: Wrap<BucketHash>,
+            sort_by: Option<Vec<PyExpr>>,
+            sort_descending: Vec<bool>,
+            sort_nulls_last: Vec<bool>,
             max_rows_per_file: Option<IdxSize>,
             approximate_bytes_per_file: u64,
+            manifest_path: Option<Wrap<PlRefPath>>,
         }
 
         let Extract {
@@ -51,11 +59,26 @@ impl PyFileSinkDestination<'_> {
             file_path_provider,
             key,
             include_key,
+            num_buckets,
+            bucket_hash,
+            sort_by,
+            sort_descending,
+            sort_nulls_last,
             max_rows_per_file,
             approximate_bytes_per_file,
+            manifest_path,
         } = partition_by_dataclass.extract()?;
 
-        let partition_strategy: PartitionStrategy = if let Some(partition_by) = key {
+        let partition_strategy: PartitionStrategy = if let Some(num_buckets) = num_buckets {
+            // Should be validated on Python side
+            assert!(include_key.is_none());
+
+            PartitionStrategy::Bucketed {
+                keys: key.unwrap().into_iter().map(|x| x.inner).collect(),
+                num_buckets,
+                hash: bucket_hash.0,
+            }
+        } else if let Some(partition_by) = key {
             PartitionStrategy::Keyed {
                 keys: partition_by.into_iter().map(|x| x.inner).collect(),
                 include_keys: include_key.unwrap_or(true),
@@ -76,8 +99,17 @@ impl PyFileSinkDestination<'_> {
                 )))
             }),
             partition_strategy,
+            per_partition_sort_by: sort_by.map(|by| {
+                (
+                    by.into_iter().map(|x| x.inner).collect(),
+                    SortMultipleOptions::default()
+                        .with_order_descending_multi(sort_descending)
+                        .with_nulls_last_multi(sort_nulls_last),
+                )
+            }),
             max_rows_per_file: max_rows_per_file.unwrap_or(IdxSize::MAX),
             approximate_bytes_per_file,
+            manifest_path: manifest_path.map(|x| x.0),
         })
     }
 }
@@ -86,7 +86,7 @@ impl PyLazyFrame {
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (
         source, sources, infer_schema_length, schema, schema_overrides, batch_size, n_rows, low_memory, rechunk,
-        row_index, ignore_errors, include_file_paths, cloud_options, credential_provider
+        row_index, ignore_errors, include_file_paths, cloud_options, credential_provider, reject_rows
     ))]
     fn new_from_ndjson(
         source: Option<Py<PyAny>>,
@@ -103,6 +103,7 @@ impl PyLazyFrame {
         include_file_paths: Option<String>,
         cloud_options: OptPyCloudOptions,
         credential_provider: Option<Py<PyAny>>,
+        reject_rows: Option<Wrap<PlRefPath>>,
     ) -> PyResult<Self> {
         let row_index = row_index.map(|(name, offset)| RowIndex {
             name: name.into(),
@@ -138,6 +139,7 @@ impl PyLazyFrame {
             .with_schema_overwrite(schema_overrides.map(|x| Arc::new(x.0)))
             .with_row_index(row_index)
             .with_ignore_errors(ignore_errors)
+            .with_reject_rows(reject_rows.map(|r| r.0))
             .with_include_file_paths(include_file_paths.map(|x| x.into()))
             .finish()
             .map_err(PyApolloErr::from)?;
@@ -151,7 +153,7 @@ impl PyLazyFrame {
         low_memory, comment_prefix, quote_char, null_values, missing_utf8_is_empty_string,
         infer_schema_length, with_schema_modify, rechunk, skip_rows_after_header,
         encoding, row_index, try_parse_dates, eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma, glob, schema,
-        cloud_options, credential_provider, include_file_paths
+        cloud_options, credential_provider, include_file_paths, sniff_dialect, reject_rows
     )
     )]
     fn new_from_csv(
@@ -186,6 +188,8 @@ impl PyLazyFrame {
         cloud_options: OptPyCloudOptions,
         credential_provider: Option<Py<PyAny>>,
         include_file_paths: Option<String>,
+        sniff_dialect: Option<usize>,
+        reject_rows: Option<Wrap<PlRefPath>>,
     ) -> PyResult<Self> {
         let null_values = null_values.map(|w| w.0);
         let quote_char = quote_char.and_then(|s| s.as_bytes().first()).copied();
@@ -237,6 +241,8 @@ impl PyLazyFrame {
             .with_ignore_errors(ignore_errors)
             .with_skip_rows(skip_rows)
             .with_skip_lines(skip_lines)
+            .with_sniff_dialect(sniff_dialect)
+            .with_reject_rows(reject_rows.map(|r| r.0))
             .with_n_rows(n_rows)
             .with_cache(cache)
             .with_dtype_overwrite(overwrite_dtype.map(Arc::new))
@@ -346,6 +352,36 @@ impl PyLazyFrame {
         Ok(lf.into())
     }
 
+    #[cfg(feature = "avro")]
+    #[staticmethod]
+    #[pyo3(signature = (sources, scan_options))]
+    fn new_from_avro(sources: Wrap<ScanSources>, scan_options: PyScanOptions) -> PyResult<Self> {
+        let sources = sources.0;
+        let first_path = sources.first_path().cloned();
+
+        let unified_scan_args =
+            scan_options.extract_unified_scan_args(first_path.as_ref().and_then(|x| x.scheme()))?;
+
+        let lf =
+            LazyFrame::scan_avro_sources(sources, unified_scan_args).map_err(PyApolloErr::from)?;
+        Ok(lf.into())
+    }
+
+    #[cfg(feature = "orc")]
+    #[staticmethod]
+    #[pyo3(signature = (sources, scan_options))]
+    fn new_from_orc(sources: Wrap<ScanSources>, scan_options: PyScanOptions) -> PyResult<Self> {
+        let sources = sources.0;
+        let first_path = sources.first_path().cloned();
+
+        let unified_scan_args =
+            scan_options.extract_unified_scan_args(first_path.as_ref().and_then(|x| x.scheme()))?;
+
+        let lf =
+            LazyFrame::scan_orc_sources(sources, unified_scan_args).map_err(PyApolloErr::from)?;
+        Ok(lf.into())
+    }
+
     #[cfg(feature = "scan_lines")]
     #[staticmethod]
     #[pyo3(signature = (sources, scan_options, name))]
@@ -562,6 +598,12 @@ impl PyLazyFrame {
         Ok((df.into(), time_df.into()))
     }
 
+    #[cfg(feature = "new_streaming")]
+    fn explain_analyze(&self, py: Python<'_>) -> PyResult<(String, PyDataFrame)> {
+        let profile = py.enter_apollo(|| self.ldf.read().clone().explain_analyze())?;
+        Ok((profile.plan, profile.metrics.into()))
+    }
+
     #[pyo3(signature = (engine, lambda_post_opt))]
     fn collect(
         &self,
@@ -724,8 +766,8 @@ impl PyLazyFrame {
 
     #[cfg(feature = "csv")]
     #[pyo3(signature = (
-        target, sink_options, include_bom, compression, compression_level, check_extension,
-        include_header, separator, line_terminator, quote_char, batch_size, datetime_format,
+        target, sink_options, include_bom, encoding, compression, compression_level,
+        check_extension, include_header, separator, line_terminator, quote_char, batch_size, datetime_format,
         date_format, time_format, float_scientific, float_precision, decimal_comma, null_value,
         quote_style
     ))]
@@ -735,6 +777,7 @@ impl PyLazyFrame {
         target: PyFileSinkDestination,
         sink_options: PySinkOptions,
         include_bom: bool,
+        encoding: Wrap<CsvEncoding>,
         compression: &str,
         compression_level: Option<u32>,
         check_extension: bool,
@@ -773,6 +816,7 @@ impl PyLazyFrame {
 
         let options = CsvWriterOptions {
             include_bom,
+            encoding: encoding.0,
             compression: ExternalCompression::try_from(compression, compression_level)
                 .map_err(PyApolloErr::from)?,
             check_extension,
@@ -827,6 +871,64 @@ impl PyLazyFrame {
         .map_err(Into::into)
     }
 
+    #[cfg(feature = "avro")]
+    #[pyo3(signature = (target, compression, name, sink_options))]
+    fn sink_avro(
+        &self,
+        py: Python<'_>,
+        target: PyFileSinkDestination,
+        compression: Wrap<Option<apollo::io::avro::AvroCompression>>,
+        name: String,
+        sink_options: PySinkOptions,
+    ) -> PyResult<PyLazyFrame> {
+        let options = apollo::io::avro::AvroWriterOptions {
+            compression: compression.0,
+            name: name.into(),
+        };
+
+        let target = target.extract_file_sink_destination()?;
+        let unified_sink_args = sink_options.extract_unified_sink_args(target.cloud_scheme())?;
+
+        py.enter_apollo(|| {
+            self.ldf
+                .read()
+                .clone()
+                .sink(target, FileWriteFormat::Avro(options), unified_sink_args)
+                .into()
+        })
+        .map(Into::into)
+        .map_err(Into::into)
+    }
+
+    #[cfg(feature = "orc")]
+    #[pyo3(signature = (target, compression, stripe_size, sink_options))]
+    fn sink_orc(
+        &self,
+        py: Python<'_>,
+        target: PyFileSinkDestination,
+        compression: Wrap<Option<apollo::io::orc::OrcCompression>>,
+        stripe_size: Option<usize>,
+        sink_options: PySinkOptions,
+    ) -> PyResult<PyLazyFrame> {
+        let options = apollo::io::orc::OrcWriterOptions {
+            compression: compression.0,
+            stripe_size,
+        };
+
+        let target = target.extract_file_sink_destination()?;
+        let unified_sink_args = sink_options.extract_unified_sink_args(target.cloud_scheme())?;
+
+        py.enter_apollo(|| {
+            self.ldf
+                .read()
+                .clone()
+                .sink(target, FileWriteFormat::Orc(options), unified_sink_args)
+                .into()
+        })
+        .map(Into::into)
+        .map_err(Into::into)
+    }
+
     #[pyo3(signature = (function, maintain_order, chunk_size))]
     pub fn sink_batches(
         &self,
@@ -58,6 +58,8 @@ flag_getter_setters! {
     (COMM_SUBEXPR_ELIM, get_comm_subexpr_elim, set_comm_subexpr_elim, clear=true)
     (CHECK_ORDER_OBSERVE, get_check_order_observe, set_check_order_observe, clear=true)
     (FAST_PROJECTION, get_fast_projection, set_fast_projection, clear=true)
+    (JOIN_REORDER, get_join_reorder, set_join_reorder, clear=true)
+    (RESULT_CACHE, get_result_cache, set_result_cache, clear=true)
 
     (EAGER, get_eager, set_eager, clear=true)
     (NEW_STREAMING, get_streaming, set_streaming, clear=true)
@@ -170,6 +170,7 @@ pub enum PyStringFunction {
     Split,
     SplitRegex,
     ToDecimal,
+    ToInterval,
     Titlecase,
     Uppercase,
     ZFill,
@@ -791,6 +792,14 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 IRFunctionExpr::ListExpr(_) => {
                     return Err(PyNotImplementedError::new_err("list expr"));
                 },
+                #[cfg(feature = "dtype-map")]
+                IRFunctionExpr::MapExpr(_) => {
+                    return Err(PyNotImplementedError::new_err("map expr"));
+                },
+                #[cfg(feature = "dtype-union")]
+                IRFunctionExpr::UnionExpr(_) => {
+                    return Err(PyNotImplementedError::new_err("union expr"));
+                },
                 IRFunctionExpr::Bitwise(_) => {
                     return Err(PyNotImplementedError::new_err("bitwise expr"));
                 },
@@ -869,6 +878,30 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                     )
                         .into_py_any(py),
                     IRStringFunction::Reverse => (PyStringFunction::Reverse,).into_py_any(py),
+                    #[cfg(feature = "string_similarity")]
+                    IRStringFunction::LevenshteinDistance { .. }
+                    | IRStringFunction::DamerauLevenshteinDistance { .. }
+                    | IRStringFunction::HammingDistance
+                    | IRStringFunction::JaroWinklerSimilarity
+                    | IRStringFunction::JaccardSimilarity { .. } => {
+                        return Err(PyNotImplementedError::new_err("string similarity"));
+                    },
+                    #[cfg(feature = "hash_digest")]
+                    IRStringFunction::Digest { .. } => {
+                        return Err(PyNotImplementedError::new_err("digest"));
+                    },
+                    #[cfg(feature = "string_url")]
+                    IRStringFunction::ParseUrl | IRStringFunction::UrlQueryParam { .. } => {
+                        return Err(PyNotImplementedError::new_err("url parsing"));
+                    },
+                    #[cfg(feature = "string_ip")]
+                    IRStringFunction::Ipv4ToInt
+                    | IRStringFunction::Ipv6ToInt
+                    | IRStringFunction::IpToBinary
+                    | IRStringFunction::IpInCidr
+                    | IRStringFunction::IpMask { .. } => {
+                        return Err(PyNotImplementedError::new_err("ip address functions"));
+                    },
                     IRStringFunction::PadStart { fill_char } => {
                         (PyStringFunction::PadStart, fill_char).into_py_any(py)
                     },
@@ -925,6 +958,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                     IRStringFunction::ToDecimal { scale } => {
                         (PyStringFunction::ToDecimal, scale).into_py_any(py)
                     },
+                    IRStringFunction::ToInterval { strict } => {
+                        (PyStringFunction::ToInterval, strict).into_py_any(py)
+                    },
                     #[cfg(feature = "nightly")]
                     IRStringFunction::Titlecase => (PyStringFunction::Titlecase,).into_py_any(py),
                     IRStringFunction::Uppercase => (PyStringFunction::Uppercase,).into_py_any(py),
@@ -1281,6 +1317,10 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 } => ("value_counts", sort, parallel, name.as_str(), normalize).into_py_any(py),
                 IRFunctionExpr::UniqueCounts => ("unique_counts",).into_py_any(py),
                 IRFunctionExpr::ApproxNUnique => ("approx_n_unique",).into_py_any(py),
+                #[cfg(feature = "approx_quantile")]
+                IRFunctionExpr::ApproxQuantile { quantile, accuracy } => {
+                    ("approx_quantile", quantile, accuracy).into_py_any(py)
+                },
                 IRFunctionExpr::Coalesce => ("coalesce",).into_py_any(py),
                 IRFunctionExpr::Diff(null_behaviour) => (
                     "diff",
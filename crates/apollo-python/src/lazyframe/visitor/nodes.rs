@@ -41,6 +41,10 @@ fn scan_type_to_pyobject(
         },
         #[cfg(feature = "ipc")]
         FileScanIR::Ipc { .. } => Err(PyNotImplementedError::new_err("ipc scan")),
+        #[cfg(feature = "avro")]
+        FileScanIR::Avro => Err(PyNotImplementedError::new_err("avro scan")),
+        #[cfg(feature = "orc")]
+        FileScanIR::Orc => Err(PyNotImplementedError::new_err("orc scan")),
         #[cfg(feature = "json")]
         FileScanIR::NDJson { options, .. } => {
             let options = serde_json::to_string(options)
@@ -619,6 +623,9 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<Py<PyAny>> {
                     streamable: _,
                     fmt_str: _,
                 } => return Err(PyNotImplementedError::new_err("opaque rust mapfunction")),
+                FunctionIR::ResultCacheStore(_) => {
+                    return Err(PyNotImplementedError::new_err("result cache store"));
+                },
                 FunctionIR::Unnest { columns, separator } => (
                     "unnest",
                     columns.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
@@ -107,6 +107,16 @@ impl PySeries {
                     let ca = series.duration().map_err(PyApolloErr::from)?;
                     return Wrap(ca).into_bound_py_any(py);
                 },
+                DataType::Interval => {
+                    let ca = series.interval().map_err(PyApolloErr::from)?;
+                    let iter = ca.physical().iter().map(|opt_v| {
+                        opt_v.map(|v| {
+                            let v = i128_to_interval(v);
+                            (v.months(), v.days(), v.ns())
+                        })
+                    });
+                    PyList::new(py, iter)?
+                },
                 DataType::Binary => {
                     let ca = series.binary().map_err(PyApolloErr::from)?;
                     return Wrap(ca).into_bound_py_any(py);
@@ -143,6 +153,14 @@ impl PySeries {
                 DataType::Extension(_, _) => {
                     return to_list_recursive(py, series.ext().unwrap().storage());
                 },
+                DataType::Map(_, _) => {
+                    return to_list_recursive(py, series.map_().unwrap().storage());
+                },
+                DataType::Union(_, _) => {
+                    let ca = series.union().map_err(PyApolloErr::from)?;
+                    let iter = (0..ca.len()).map(|i| Wrap(ca.get_any_value(i).unwrap()));
+                    PyList::new(py, iter)?
+                },
             };
             Ok(pylist.into_any())
         }
@@ -549,8 +549,12 @@ impl PySeries {
     ) -> PyResult<Self> {
         py.enter_apollo(|| {
             let lock = self.series.read();
-            lock.str()?
-                .json_decode(None, infer_schema_length)
+            let ca = if lock.dtype().is_json() {
+                lock.to_storage().str()?
+            } else {
+                lock.str()?
+            };
+            ca.json_decode(None, infer_schema_length)
                 .map(|s| s.with_name(lock.name().clone()))
         })
         .map(Into::into)
@@ -575,7 +579,9 @@ impl PySeries {
             ))));
         }
 
-        Ok(s.clone().into_extension(typ.clone()).into())
+        let ext = ExtensionChunked::try_from_storage(typ.clone(), s.clone())
+            .map_err(PyApolloErr::from)?;
+        Ok(ext.into_series().into())
     }
 
     fn ext_storage(&self) -> Self {
@@ -35,6 +35,7 @@ macro_rules! apply_all_apollo_dtypes {
             DataType::String => $self.str().unwrap().$method($($args),*),
             DataType::Binary => $self.binary().unwrap().$method($($args),*),
             DataType::Decimal(_, _) => $self.decimal().unwrap().$method($($args),*),
+            DataType::Interval => $self.interval().unwrap().$method($($args),*),
 
             DataType::Date => $self.date().unwrap().$method($($args),*),
             DataType::Datetime(_, _) => $self.datetime().unwrap().$method($($args),*),
@@ -60,10 +61,11 @@ macro_rules! apply_all_apollo_dtypes {
                 .$method($($args),*)
             },
             DataType::Extension(_, _) => $self.ext().unwrap().$method($($args),*),
+            DataType::Map(_, _) => $self.map_().unwrap().entries().$method($($args),*),
 
             DataType::Null => $self.null().unwrap().$method($($args),*),
 
-            dt @ (DataType::BinaryOffset | DataType::Unknown(_)) => panic!("dtype {:?} not supported", dt)
+            dt @ (DataType::BinaryOffset | DataType::Union(_, _) | DataType::Unknown(_)) => panic!("dtype {:?} not supported", dt)
         }
     }
 }
@@ -722,9 +722,25 @@ impl SQLContext {
         Ok(DataFrame::from_rows(frame_rows.as_ref())?.lazy())
     }
 
-    // EXPLAIN SELECT * FROM DF
+    // EXPLAIN [ANALYZE] SELECT * FROM DF
     fn execute_explain(&mut self, stmt: &Statement) -> ApolloResult<LazyFrame> {
         match stmt {
+            Statement::Explain {
+                statement,
+                analyze: true,
+                ..
+            } => {
+                let lf = self.execute_statement(statement)?;
+                #[cfg(feature = "new_streaming")]
+                {
+                    Ok(lf.explain_analyze()?.metrics.lazy())
+                }
+                #[cfg(not(feature = "new_streaming"))]
+                {
+                    _ = lf;
+                    apollo_bail!(SQLInterface: "EXPLAIN ANALYZE requires the 'new_streaming' feature")
+                }
+            },
             Statement::Explain { statement, .. } => {
                 let lf = self.execute_statement(statement)?;
                 let plan = lf.describe_optimized_plan()?;
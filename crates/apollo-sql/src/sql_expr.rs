@@ -148,6 +148,19 @@ impl SQLExprVisitor<'_> {
                 subquery,
                 negated,
             } => self.visit_in_subquery(expr, subquery, *negated),
+            #[cfg(feature = "dtype-interval")]
+            SQLExpr::Interval(interval) => {
+                // years, quarters, and months have no fixed length, so they
+                // can only be represented by a calendar interval
+                let duration = interval_to_duration(interval, false)?;
+                if duration.months() != 0 {
+                    let v = duration.to_months_days_ns()?;
+                    Ok(lit(Scalar::new_interval(v)))
+                } else {
+                    Ok(lit(duration))
+                }
+            },
+            #[cfg(not(feature = "dtype-interval"))]
             SQLExpr::Interval(interval) => Ok(lit(interval_to_duration(interval, true)?)),
             SQLExpr::IsDistinctFrom(e1, e2) => {
                 Ok(self.visit_expr(e1)?.neq_missing(self.visit_expr(e2)?))
@@ -35,6 +35,10 @@ pub fn track_task_metrics(should_track: bool) {
     TRACK_METRICS.store(should_track);
 }
 
+pub fn is_tracking_task_metrics() -> bool {
+    TRACK_METRICS.load()
+}
+
 static GLOBAL_SCHEDULER: OnceLock<Executor> = OnceLock::new();
 
 thread_local!(
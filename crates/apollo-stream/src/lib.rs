@@ -5,14 +5,14 @@ mod skeleton;
 
 use std::sync::LazyLock;
 
-pub use skeleton::{run_query, visualize_physical_plan};
+pub use skeleton::{explain_analyze, run_query, visualize_physical_plan};
 
 mod execute;
 pub use dispatch::build_streaming_query_executor;
 pub(crate) mod expression;
 mod graph;
 pub use graph::{GraphNodeKey, LogicalPipe, LogicalPipeKey};
-pub use skeleton::{QueryResult, StreamingQuery};
+pub use skeleton::{QueryProfile, QueryResult, StreamingQuery};
 mod metrics;
 pub use metrics::{GraphMetrics, NodeMetrics};
 mod morsel;
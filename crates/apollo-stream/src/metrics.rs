@@ -2,6 +2,8 @@ use std::sync::Arc;
 use std::time::Duration;
 
 pub use apollo_io::metrics::{IOMetrics, OptIOMetrics};
+use apollo_utils::relaxed_cell::RelaxedCell;
+use apollo_utils::sys::MEMINFO;
 use slotmap::{SecondaryMap, SlotMap};
 
 use crate::LogicalPipe;
@@ -183,3 +185,37 @@ impl MetricsBuilder {
         io_metrics
     }
 }
+
+/// Samples the resident memory of the process on a background thread to find its peak while a
+/// query runs.
+pub struct PeakMemorySampler {
+    stop: Arc<RelaxedCell<bool>>,
+    handle: std::thread::JoinHandle<Option<u64>>,
+}
+
+impl PeakMemorySampler {
+    const SAMPLE_INTERVAL: Duration = Duration::from_millis(10);
+
+    pub fn start() -> Self {
+        let stop = Arc::new(RelaxedCell::new_bool(false));
+        let handle = std::thread::spawn({
+            let stop = stop.clone();
+            move || {
+                let mut peak = MEMINFO.process_memory();
+                while !stop.load() {
+                    std::thread::sleep(Self::SAMPLE_INTERVAL);
+                    peak = peak.max(MEMINFO.process_memory());
+                }
+                peak
+            }
+        });
+        Self { stop, handle }
+    }
+
+    /// Stops sampling and returns the peak in bytes, `None` if the memory of the process can't be
+    /// determined on this platform.
+    pub fn finish(self) -> Option<u64> {
+        self.stop.store(true);
+        self.handle.join().unwrap()
+    }
+}
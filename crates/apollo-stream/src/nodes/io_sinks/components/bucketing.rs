@@ -0,0 +1,346 @@
+//! Bucket assignment compatible with Spark's and Hive's bucketing.
+//!
+//! Spark assigns a row to bucket `pmod(murmur3_hash(keys, seed = 42), num_buckets)`, where the
+//! hash of every key column is seeded with the hash of the previous key column and null values
+//! leave the hash unchanged. The hashes here match Spark's `Murmur3_x86_32` bit for bit, so that
+//! bucketed output can be joined with tables bucketed by Spark without a shuffle.
+//!
+//! Hive (`bucketing_version=1`) assigns a row to bucket `(h & i32::MAX) % num_buckets`, where `h`
+//! folds the Java `hashCode` of every key into `31 * h + hash(key)` and nulls hash to 0. Hive's
+//! Murmur3 based `bucketing_version=2` is not supported.
+use apollo_core::prelude::{Column, DataType, TimeUnit};
+use apollo_error::{ApolloResult, apollo_bail};
+use apollo_plan::dsl::BucketHash;
+
+const SEED: u32 = 42;
+
+const C1: u32 = 0xcc9e_2d51;
+const C2: u32 = 0x1b87_3593;
+
+#[inline]
+fn mix_k1(k1: u32) -> u32 {
+    k1.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2)
+}
+
+#[inline]
+fn mix_h1(h1: u32, k1: u32) -> u32 {
+    (h1 ^ k1)
+        .rotate_left(13)
+        .wrapping_mul(5)
+        .wrapping_add(0xe654_6b64)
+}
+
+#[inline]
+fn fmix(mut h1: u32, len: u32) -> u32 {
+    h1 ^= len;
+    h1 ^= h1 >> 16;
+    h1 = h1.wrapping_mul(0x85eb_ca6b);
+    h1 ^= h1 >> 13;
+    h1 = h1.wrapping_mul(0xc2b2_ae35);
+    h1 ^ (h1 >> 16)
+}
+
+#[inline]
+fn hash_int(v: i32, seed: u32) -> u32 {
+    fmix(mix_h1(seed, mix_k1(v as u32)), 4)
+}
+
+#[inline]
+fn hash_long(v: i64, seed: u32) -> u32 {
+    let h1 = mix_h1(seed, mix_k1(v as u32));
+    let h1 = mix_h1(h1, mix_k1((v as u64 >> 32) as u32));
+    fmix(h1, 8)
+}
+
+/// Spark's `hashUnsafeBytes`, which (unlike the reference implementation) mixes every trailing
+/// byte as a separate sign-extended block.
+#[inline]
+fn hash_bytes(bytes: &[u8], seed: u32) -> u32 {
+    let mut chunks = bytes.chunks_exact(4);
+    let mut h1 = seed;
+    for chunk in &mut chunks {
+        h1 = mix_h1(h1, mix_k1(u32::from_le_bytes(chunk.try_into().unwrap())));
+    }
+    for &b in chunks.remainder() {
+        h1 = mix_h1(h1, mix_k1(b as i8 as i32 as u32));
+    }
+    fmix(h1, bytes.len() as u32)
+}
+
+#[inline]
+fn hash_f32(v: f32, seed: u32) -> u32 {
+    // Spark hashes -0.0 as 0.0 and uses the canonical NaN of `floatToIntBits`.
+    let bits = if v == 0.0 {
+        0
+    } else if v.is_nan() {
+        0x7fc0_0000
+    } else {
+        v.to_bits()
+    };
+    hash_int(bits as i32, seed)
+}
+
+#[inline]
+fn hash_f64(v: f64, seed: u32) -> u32 {
+    let bits = if v == 0.0 {
+        0
+    } else if v.is_nan() {
+        0x7ff8_0000_0000_0000
+    } else {
+        v.to_bits()
+    };
+    hash_long(bits as i64, seed)
+}
+
+fn hash_values<T>(
+    values: impl Iterator<Item = Option<T>>,
+    hashes: &mut [u32],
+    f: fn(T, u32) -> u32,
+) {
+    for (h, v) in hashes.iter_mut().zip(values) {
+        if let Some(v) = v {
+            *h = f(v, *h);
+        }
+    }
+}
+
+/// Combines the Spark hash of every value in `column` into `hashes`.
+fn spark_hash_column(column: &Column, hashes: &mut [u32]) -> ApolloResult<()> {
+    let s = column.as_materialized_series();
+
+    match s.dtype() {
+        DataType::Null => {},
+        DataType::Boolean => {
+            hash_values(s.bool()?.iter(), hashes, |v, seed| hash_int(v as i32, seed))
+        },
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::Date => {
+            let s = s.to_physical_repr().strict_cast(&DataType::Int32)?;
+            hash_values(s.i32()?.iter(), hashes, hash_int)
+        },
+        DataType::Int64 | DataType::UInt32 | DataType::UInt64 => {
+            let s = s.to_physical_repr().strict_cast(&DataType::Int64)?;
+            hash_values(s.i64()?.iter(), hashes, hash_long)
+        },
+        // Spark day-time intervals are in microseconds.
+        DataType::Duration(_) => {
+            let s = s
+                .cast(&DataType::Duration(TimeUnit::Microseconds))?
+                .to_physical_repr()
+                .into_owned();
+            hash_values(s.i64()?.iter(), hashes, hash_long)
+        },
+        // Spark timestamps are in microseconds.
+        DataType::Datetime(_, tz) => {
+            let s = s
+                .cast(&DataType::Datetime(TimeUnit::Microseconds, tz.clone()))?
+                .to_physical_repr()
+                .into_owned();
+            hash_values(s.i64()?.iter(), hashes, hash_long)
+        },
+        // Spark hashes decimals that fit in a long by their unscaled value.
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(precision, _) if *precision <= 18 => {
+            let s = s.to_physical_repr().strict_cast(&DataType::Int64)?;
+            hash_values(s.i64()?.iter(), hashes, hash_long)
+        },
+        DataType::Float32 => hash_values(s.f32()?.iter(), hashes, hash_f32),
+        DataType::Float64 => hash_values(s.f64()?.iter(), hashes, hash_f64),
+        DataType::String => hash_values(s.str()?.iter(), hashes, |v, seed| {
+            hash_bytes(v.as_bytes(), seed)
+        }),
+        dt if dt.is_categorical() || dt.is_enum() => {
+            let s = s.cast(&DataType::String)?;
+            hash_values(s.str()?.iter(), hashes, |v, seed| {
+                hash_bytes(v.as_bytes(), seed)
+            })
+        },
+        DataType::Binary => hash_values(s.binary()?.iter(), hashes, hash_bytes),
+        dt => apollo_bail!(InvalidOperation: "cannot bucket by column of dtype {}", dt),
+    }
+
+    Ok(())
+}
+
+#[inline]
+fn hive_hash_long(v: i64) -> i32 {
+    (v ^ ((v as u64) >> 32) as i64) as i32
+}
+
+/// Hive hashes strings like Java's `String.hashCode`, but over the signed UTF-8 bytes.
+#[inline]
+fn hive_hash_string(bytes: &[u8]) -> i32 {
+    bytes.iter().fold(0i32, |h, &b| {
+        h.wrapping_mul(31).wrapping_add(b as i8 as i32)
+    })
+}
+
+/// Hadoop's `WritableComparator.hashBytes`, which starts at 1 instead of 0.
+#[inline]
+fn hive_hash_binary(bytes: &[u8]) -> i32 {
+    bytes.iter().fold(1i32, |h, &b| {
+        h.wrapping_mul(31).wrapping_add(b as i8 as i32)
+    })
+}
+
+#[inline]
+fn hive_hash_f32(v: f32) -> i32 {
+    // Java's `floatToIntBits` canonicalizes NaN, but keeps the sign of zero.
+    if v.is_nan() {
+        0x7fc0_0000
+    } else {
+        v.to_bits() as i32
+    }
+}
+
+#[inline]
+fn hive_hash_f64(v: f64) -> i32 {
+    if v.is_nan() {
+        hive_hash_long(0x7ff8_0000_0000_0000)
+    } else {
+        hive_hash_long(v.to_bits() as i64)
+    }
+}
+
+fn hive_hash_values<T>(
+    values: impl Iterator<Item = Option<T>>,
+    hashes: &mut [i32],
+    f: fn(T) -> i32,
+) {
+    for (h, v) in hashes.iter_mut().zip(values) {
+        *h = h.wrapping_mul(31).wrapping_add(v.map_or(0, f));
+    }
+}
+
+/// Combines the Hive hash of every value in `column` into `hashes`.
+fn hive_hash_column(column: &Column, hashes: &mut [i32]) -> ApolloResult<()> {
+    let s = column.as_materialized_series();
+
+    match s.dtype() {
+        DataType::Null => hashes.iter_mut().for_each(|h| *h = h.wrapping_mul(31)),
+        DataType::Boolean => hive_hash_values(s.bool()?.iter(), hashes, |v| v as i32),
+        // Hive hashes dates by their days since the epoch.
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::Date => {
+            let s = s.to_physical_repr().strict_cast(&DataType::Int32)?;
+            hive_hash_values(s.i32()?.iter(), hashes, |v| v)
+        },
+        DataType::Int64 | DataType::UInt32 | DataType::UInt64 => {
+            let s = s.strict_cast(&DataType::Int64)?;
+            hive_hash_values(s.i64()?.iter(), hashes, hive_hash_long)
+        },
+        DataType::Float32 => hive_hash_values(s.f32()?.iter(), hashes, hive_hash_f32),
+        DataType::Float64 => hive_hash_values(s.f64()?.iter(), hashes, hive_hash_f64),
+        DataType::String => {
+            hive_hash_values(s.str()?.iter(), hashes, |v| hive_hash_string(v.as_bytes()))
+        },
+        dt if dt.is_categorical() || dt.is_enum() => {
+            let s = s.cast(&DataType::String)?;
+            hive_hash_values(s.str()?.iter(), hashes, |v| hive_hash_string(v.as_bytes()))
+        },
+        DataType::Binary => hive_hash_values(s.binary()?.iter(), hashes, hive_hash_binary),
+        dt => apollo_bail!(
+            InvalidOperation: "cannot bucket by column of dtype {} with the Hive hash", dt
+        ),
+    }
+
+    Ok(())
+}
+
+/// Computes the bucket of every row of `key_columns`.
+pub fn bucket_ids(
+    key_columns: &[Column],
+    num_buckets: u32,
+    hash: BucketHash,
+) -> ApolloResult<Vec<u32>> {
+    let height = key_columns.first().map_or(0, |c| c.len());
+
+    match hash {
+        BucketHash::Spark => {
+            let mut hashes = vec![SEED; height];
+            for c in key_columns {
+                spark_hash_column(c, &mut hashes)?;
+            }
+
+            // Spark hashes are signed, the bucket is the non-negative modulus.
+            let num_buckets = num_buckets as i64;
+            Ok(hashes
+                .into_iter()
+                .map(|h| (h as i32 as i64).rem_euclid(num_buckets) as u32)
+                .collect())
+        },
+        BucketHash::Hive => {
+            let mut hashes = vec![0; height];
+            for c in key_columns {
+                hive_hash_column(c, &mut hashes)?;
+            }
+
+            Ok(hashes
+                .into_iter()
+                .map(|h| (h & i32::MAX) as u32 % num_buckets)
+                .collect())
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use apollo_core::prelude::*;
+
+    use super::*;
+
+    // Expected values from Spark's `hash()` SQL function.
+    #[test]
+    fn test_spark_murmur3() {
+        assert_eq!(hash_int(1, SEED) as i32, -559580957);
+        assert_eq!(hash_long(1, SEED) as i32, -1712319331);
+        assert_eq!(hash_bytes(b"ABC", SEED) as i32, -757602832);
+        assert_eq!(
+            hash_bytes(b"DEF", hash_bytes(b"ABC", SEED)) as i32,
+            599895104
+        );
+
+        // Durations are hashed in microseconds, whatever their time unit.
+        for (values, unit) in [
+            (vec![1_000i64], TimeUnit::Nanoseconds),
+            (vec![1], TimeUnit::Microseconds),
+        ] {
+            let column = Int64Chunked::from_vec("d".into(), values)
+                .into_duration(unit)
+                .into_column();
+            let mut hashes = vec![SEED];
+            spark_hash_column(&column, &mut hashes).unwrap();
+            assert_eq!(hashes, [hash_long(1, SEED)]);
+        }
+    }
+
+    // Expected values from Java's `hashCode`.
+    #[test]
+    fn test_hive_hash() {
+        assert_eq!(hive_hash_string(b"ABC"), 64578);
+        assert_eq!(hive_hash_long(1), 1);
+        assert_eq!(hive_hash_long(-1), 0);
+        assert_eq!(hive_hash_f64(1.0), 1072693248);
+
+        let keys = [
+            Column::new("a".into(), [Some(1i64), None]),
+            Column::new("b".into(), ["ABC", "ABC"]),
+        ];
+        let mut hashes = vec![0; 2];
+        for c in &keys {
+            hive_hash_column(c, &mut hashes).unwrap();
+        }
+        assert_eq!(hashes, [31 + 64578, 64578]);
+
+        let keys = [Column::new("a".into(), [-5i32])];
+        assert_eq!(bucket_ids(&keys, 4, BucketHash::Hive).unwrap(), [3]);
+    }
+}
@@ -8,18 +8,28 @@ use apollo_plan::dsl::file_provider::{FileProviderReturn, FileProviderType};
 use apollo_plan::prelude::file_provider::FileProviderArgs;
 use apollo_utils::pl_path::PlRefPath;
 
+use crate::nodes::io_sinks::components::staging::StagedFiles;
+
 pub struct FileProvider {
     pub base_path: PlRefPath,
     pub cloud_options: Option<Arc<CloudOptions>>,
+    /// Set for atomic sinks, files are then opened at their staging paths.
+    pub staged_files: Option<Arc<StagedFiles>>,
     pub provider_type: FileProviderType,
     pub upload_chunk_size: usize,
     pub upload_max_concurrency: usize,
 }
 
 impl FileProvider {
-    pub async fn open_file(&self, args: FileProviderArgs) -> ApolloResult<Writeable> {
+    /// Opens the file for `args`. Also returns the path of the file, which is `None` if the file
+    /// path provider returned an opened file.
+    pub async fn open_file(
+        &self,
+        args: FileProviderArgs,
+    ) -> ApolloResult<(Writeable, Option<PlRefPath>)> {
         let provided_path: String = match &self.provider_type {
             FileProviderType::Hive(v) => v.get_path(args)?,
+            FileProviderType::Bucket(v) => v.get_path(args)?,
             FileProviderType::Function(f) => {
                 let f = f.clone();
 
@@ -30,12 +40,17 @@ impl FileProvider {
 
                 match out {
                     FileProviderReturn::Path(p) => p,
-                    FileProviderReturn::Writeable(v) => return Ok(v),
+                    FileProviderReturn::Writeable(v) => return Ok((v, None)),
                 }
             },
         };
 
-        let path = self.base_path.join(&provided_path);
+        let final_path = self.base_path.join(&provided_path);
+
+        let path = match &self.staged_files {
+            Some(staged_files) => staged_files.stage_in_dir(&self.base_path, &provided_path),
+            None => final_path.clone(),
+        };
 
         if !path.has_scheme()
             && let Some(path) = path.parent()
@@ -48,11 +63,13 @@ impl FileProvider {
                 .await;
         }
 
-        Writeable::try_new(
+        let writeable = Writeable::try_new(
             path,
             self.cloud_options.as_deref(),
             self.upload_chunk_size,
             self.upload_max_concurrency,
-        )
+        )?;
+
+        Ok((writeable, Some(final_path)))
     }
 }
@@ -2,6 +2,7 @@ use apollo_error::ApolloResult;
 
 use crate::async_executor;
 use crate::async_primitives::connector;
+use crate::nodes::io_sinks::components::manifest::ManifestFileBuilder;
 use crate::nodes::io_sinks::components::sink_morsel::SinkMorsel;
 use crate::nodes::io_sinks::components::size::RowCountAndSize;
 
@@ -11,11 +12,17 @@ pub struct FileSinkTaskData {
     pub morsel_tx: connector::Sender<SinkMorsel>,
     pub start_position: RowCountAndSize,
     pub task_handle: async_executor::JoinHandle<ApolloResult<FileSinkPermit>>,
+    /// Set if the sink writes a manifest.
+    pub manifest_file: Option<ManifestFileBuilder>,
 }
 
 impl FileSinkTaskData {
     /// Signals to the writer to close, and returns its task handle.
     pub fn close(self) -> async_executor::JoinHandle<ApolloResult<FileSinkPermit>> {
+        if let Some(manifest_file) = self.manifest_file {
+            manifest_file.finish();
+        }
+
         self.task_handle
     }
 }
@@ -0,0 +1,337 @@
+use std::sync::{Arc, Mutex, OnceLock};
+
+use apollo_core::prelude::*;
+use apollo_core::scalar::Scalar;
+use apollo_error::{ApolloResult, feature_gated};
+use apollo_io::cloud::CloudOptions;
+use apollo_utils::IdxSize;
+use apollo_utils::pl_path::{CloudScheme, PlRefPath};
+use apollo_utils::pl_str::PlSmallStr;
+
+/// Records every file written by a partitioned sink, and writes the list to a JSON manifest once
+/// the sink has finished.
+///
+/// The manifest has one row per file, with the columns:
+/// * `path`: Output path, null if the file path provider returned an opened file.
+/// * `partition_keys`: Struct of the partition key values (omitted if there are no keys).
+/// * `num_rows`: Number of rows.
+/// * `num_bytes`: Size of the file in bytes, null if it can't be determined.
+/// * `statistics`: Struct with a `{min, max, null_count}` struct for every column of the file.
+///   `min` and `max` are only present for columns with an ordering.
+pub struct SinkManifest {
+    path: PlRefPath,
+    cloud_options: Option<Arc<CloudOptions>>,
+    file_schema: SchemaRef,
+    files: Mutex<Vec<ManifestFile>>,
+}
+
+struct ManifestFile {
+    path: Arc<OnceLock<PlRefPath>>,
+    partition_keys: Arc<DataFrame>,
+    statistics: FileStatistics,
+}
+
+/// Tracks a file that is being written, see [`SinkManifest::start_file`].
+pub struct ManifestFileBuilder {
+    manifest: Arc<SinkManifest>,
+    /// Set once the file was opened.
+    pub path: Arc<OnceLock<PlRefPath>>,
+    partition_keys: Arc<DataFrame>,
+    statistics: FileStatistics,
+}
+
+struct FileStatistics {
+    num_rows: IdxSize,
+    columns: Vec<ColumnStatistics>,
+}
+
+#[derive(Default)]
+struct ColumnStatistics {
+    min: Option<Scalar>,
+    max: Option<Scalar>,
+    null_count: IdxSize,
+}
+
+fn has_min_max(dtype: &DataType) -> bool {
+    !dtype.is_categorical()
+        && !dtype.is_enum()
+        && (dtype.to_physical().is_primitive_numeric()
+            || matches!(
+                dtype,
+                DataType::Boolean | DataType::String | DataType::Binary
+            ))
+}
+
+/// Combines `new` into the running minimum or maximum `acc`.
+fn merge_extremum(
+    acc: &mut Option<Scalar>,
+    new: Scalar,
+    reduce: fn(&Column) -> ApolloResult<Scalar>,
+) -> ApolloResult<()> {
+    if new.is_null() {
+        return Ok(());
+    }
+
+    *acc = Some(match acc.take() {
+        None => new,
+        Some(acc) => {
+            let mut c = acc.into_column(PlSmallStr::EMPTY);
+            c.append_owned(new.into_column(PlSmallStr::EMPTY))?;
+            reduce(&c)?
+        },
+    });
+
+    Ok(())
+}
+
+impl SinkManifest {
+    pub fn new(
+        path: PlRefPath,
+        cloud_options: Option<Arc<CloudOptions>>,
+        file_schema: SchemaRef,
+    ) -> Self {
+        Self {
+            path,
+            cloud_options,
+            file_schema,
+            files: Mutex::default(),
+        }
+    }
+
+    pub fn start_file(self: &Arc<Self>, partition_keys: Arc<DataFrame>) -> ManifestFileBuilder {
+        ManifestFileBuilder {
+            manifest: Arc::clone(self),
+            path: Arc::default(),
+            partition_keys,
+            statistics: FileStatistics {
+                num_rows: 0,
+                columns: (0..self.file_schema.len())
+                    .map(|_| ColumnStatistics::default())
+                    .collect(),
+            },
+        }
+    }
+
+    /// Writes the manifest. Must be called after all files were closed and committed.
+    pub async fn write(&self) -> ApolloResult<()> {
+        let mut files = std::mem::take(&mut *self.files.lock().unwrap());
+        files.sort_by(|l, r| l.path.get().cmp(&r.path.get()));
+
+        let mut num_bytes = Vec::with_capacity(files.len());
+        for file in &files {
+            num_bytes.push(match file.path.get() {
+                Some(path) => file_size(path, self.cloud_options.as_deref()).await.ok(),
+                None => None,
+            });
+        }
+
+        let df = self.to_df(&files, num_bytes)?;
+
+        self.write_json(df).await
+    }
+
+    #[cfg(feature = "json")]
+    async fn write_json(&self, mut df: DataFrame) -> ApolloResult<()> {
+        use apollo_io::SerWriter;
+        use apollo_io::json::{JsonFormat, JsonWriter};
+
+        let mut buf = vec![];
+        JsonWriter::new(&mut buf)
+            .with_json_format(JsonFormat::Json)
+            .finish(&mut df)?;
+
+        let path = self.path.clone();
+        let cloud_options = self.cloud_options.clone();
+
+        apollo_io::pl_async::get_runtime()
+            .spawn_blocking(move || {
+                use std::io::Write;
+
+                let mut writeable = apollo_io::utils::file::Writeable::try_new(
+                    path,
+                    cloud_options.as_deref(),
+                    apollo_io::get_upload_chunk_size(),
+                    apollo_io::get_upload_concurrency(),
+                )?;
+                writeable.write_all(&buf)?;
+                writeable.close(Default::default())?;
+                ApolloResult::Ok(())
+            })
+            .await
+            .unwrap()
+    }
+
+    #[cfg(not(feature = "json"))]
+    async fn write_json(&self, _df: DataFrame) -> ApolloResult<()> {
+        apollo_error::apollo_bail!(InvalidOperation: "writing a sink manifest requires the 'json' feature")
+    }
+
+    fn to_df(
+        &self,
+        files: &[ManifestFile],
+        num_bytes: Vec<Option<u64>>,
+    ) -> ApolloResult<DataFrame> {
+        let height = files.len();
+
+        let mut columns = Vec::with_capacity(5);
+
+        columns.push(
+            StringChunked::from_iter_options(
+                PlSmallStr::from_static("path"),
+                files.iter().map(|f| f.path.get().map(|p| p.as_str())),
+            )
+            .into_column(),
+        );
+
+        if let Some(first) = files.first()
+            && first.partition_keys.width() > 0
+        {
+            let mut keys = first.partition_keys.as_ref().clone();
+            for f in &files[1..] {
+                keys.vstack_mut(&f.partition_keys)?;
+            }
+
+            columns.push(
+                StructChunked::from_columns(
+                    PlSmallStr::from_static("partition_keys"),
+                    height,
+                    keys.columns(),
+                )?
+                .into_column(),
+            );
+        }
+
+        columns.push(
+            IdxCa::from_vec(
+                PlSmallStr::from_static("num_rows"),
+                files.iter().map(|f| f.statistics.num_rows).collect(),
+            )
+            .into_column(),
+        );
+
+        columns.push(
+            UInt64Chunked::from_iter_options(
+                PlSmallStr::from_static("num_bytes"),
+                num_bytes.into_iter(),
+            )
+            .into_column(),
+        );
+
+        let mut statistics = Vec::with_capacity(self.file_schema.len());
+
+        for (i, (name, dtype)) in self.file_schema.iter().enumerate() {
+            let mut fields = Vec::with_capacity(3);
+
+            if has_min_max(dtype) {
+                for field in ["min", "max"] {
+                    let values: Vec<AnyValue> = files
+                        .iter()
+                        .map(|f| {
+                            let stats = &f.statistics.columns[i];
+                            let v = if field == "min" {
+                                &stats.min
+                            } else {
+                                &stats.max
+                            };
+                            v.as_ref().map_or(AnyValue::Null, |s| s.value().clone())
+                        })
+                        .collect();
+
+                    fields.push(
+                        Series::from_any_values_and_dtype(
+                            PlSmallStr::from_static(field),
+                            &values,
+                            dtype,
+                            true,
+                        )?
+                        .into_column(),
+                    );
+                }
+            }
+
+            fields.push(
+                IdxCa::from_vec(
+                    PlSmallStr::from_static("null_count"),
+                    files
+                        .iter()
+                        .map(|f| f.statistics.columns[i].null_count)
+                        .collect(),
+                )
+                .into_column(),
+            );
+
+            statistics
+                .push(StructChunked::from_columns(name.clone(), height, &fields)?.into_column());
+        }
+
+        columns.push(
+            StructChunked::from_columns(
+                PlSmallStr::from_static("statistics"),
+                height,
+                &statistics,
+            )?
+            .into_column(),
+        );
+
+        DataFrame::new(height, columns)
+    }
+}
+
+impl ManifestFileBuilder {
+    /// Updates the statistics with a morsel that is written to the file.
+    pub fn update(&mut self, df: &DataFrame) -> ApolloResult<()> {
+        self.statistics.num_rows += IdxSize::try_from(df.height()).unwrap();
+
+        for (c, stats) in df.columns().iter().zip(&mut self.statistics.columns) {
+            stats.null_count += IdxSize::try_from(c.null_count()).unwrap();
+
+            if has_min_max(c.dtype()) {
+                merge_extremum(&mut stats.min, c.min_reduce()?, Column::min_reduce)?;
+                merge_extremum(&mut stats.max, c.max_reduce()?, Column::max_reduce)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds the file to the manifest.
+    pub fn finish(self) {
+        let ManifestFileBuilder {
+            manifest,
+            path,
+            partition_keys,
+            statistics,
+        } = self;
+
+        manifest.files.lock().unwrap().push(ManifestFile {
+            path,
+            partition_keys,
+            statistics,
+        });
+    }
+}
+
+async fn file_size(
+    path: &PlRefPath,
+    #[cfg_attr(not(feature = "cloud"), expect(unused))] cloud_options: Option<&CloudOptions>,
+) -> ApolloResult<u64> {
+    if matches!(
+        path.scheme(),
+        None | Some(CloudScheme::File | CloudScheme::FileNoHostname)
+    ) {
+        let metadata = tokio::fs::metadata(apollo_io::resolve_homedir(path.strip_scheme())).await?;
+        return Ok(metadata.len());
+    }
+
+    feature_gated!("cloud", {
+        use apollo_io::cloud::{CloudLocation, build_object_store, object_path_from_str};
+
+        let (CloudLocation { prefix, .. }, object_store) =
+            build_object_store(path.clone(), cloud_options, false).await?;
+
+        Ok(object_store
+            .head(&object_path_from_str(&prefix)?)
+            .await?
+            .size)
+    })
+}
@@ -1,8 +1,10 @@
+pub mod bucketing;
 pub mod error_capture;
 pub mod exclude_keys_projection;
 pub mod file_provider;
 pub mod file_sink;
 pub mod hstack_columns;
+pub mod manifest;
 pub mod morsel_resize_pipeline;
 pub mod par_utils;
 pub mod partition_distributor;
@@ -14,3 +16,4 @@ pub mod partitioner;
 pub mod partitioner_pipeline;
 pub mod sink_morsel;
 pub mod size;
+pub mod staging;
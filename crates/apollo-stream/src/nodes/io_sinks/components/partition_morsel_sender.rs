@@ -175,6 +175,10 @@ impl PartitionMorselSender {
                 *morsel.df_mut() = unsafe { DataFrame::new_unchecked(height, new_columns) };
             };
 
+            if let Some(manifest_file) = file_sink_task_data.manifest_file.as_mut() {
+                manifest_file.update(morsel.df())?;
+            }
+
             if file_sink_task_data.morsel_tx.send(morsel).await.is_err() {
                 let handle = partition.file_sink_task_data.take().unwrap().close();
                 return Err(handle.await.unwrap_err());
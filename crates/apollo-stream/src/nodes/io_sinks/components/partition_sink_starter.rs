@@ -11,6 +11,7 @@ use crate::async_primitives::connector;
 use crate::nodes::TaskPriority;
 use crate::nodes::io_sinks::components::file_provider::FileProvider;
 use crate::nodes::io_sinks::components::file_sink::{FileSinkPermit, FileSinkTaskData};
+use crate::nodes::io_sinks::components::manifest::SinkManifest;
 use crate::nodes::io_sinks::components::size::RowCountAndSize;
 use crate::nodes::io_sinks::writers::interface::{FileOpenTaskHandle, FileWriterStarter};
 use crate::utils::tokio_handle_ext;
@@ -21,6 +22,7 @@ pub struct PartitionSinkStarter {
     pub writer_starter: Arc<dyn FileWriterStarter>,
     pub sync_on_close: SyncOnCloseType,
     pub num_pipelines_per_sink: NonZeroUsize,
+    pub manifest: Option<Arc<SinkManifest>>,
 }
 
 impl PartitionSinkStarter {
@@ -30,11 +32,25 @@ impl PartitionSinkStarter {
         start_position: RowCountAndSize,
         file_permit: FileSinkPermit,
     ) -> ApolloResult<FileSinkTaskData> {
+        let manifest_file = self
+            .manifest
+            .as_ref()
+            .map(|m| m.start_file(file_provider_args.partition_keys.clone()));
+        let path_slot = manifest_file.as_ref().map(|m| Arc::clone(&m.path));
+
         let file_provider = Arc::clone(&self.file_provider);
-        let file_open_task = tokio_handle_ext::AbortOnDropHandle(
-            pl_async::get_runtime()
-                .spawn(async move { file_provider.open_file(file_provider_args).await }),
-        );
+        let file_open_task =
+            tokio_handle_ext::AbortOnDropHandle(pl_async::get_runtime().spawn(async move {
+                let (file, path) = file_provider.open_file(file_provider_args).await?;
+
+                if let Some(path_slot) = path_slot
+                    && let Some(path) = path
+                {
+                    path_slot.set(path).unwrap();
+                }
+
+                Ok(file)
+            }));
 
         let (morsel_tx, morsel_rx) = connector::connector();
 
@@ -53,6 +69,7 @@ impl PartitionSinkStarter {
             morsel_tx,
             start_position,
             task_handle,
+            manifest_file,
         })
     }
 }
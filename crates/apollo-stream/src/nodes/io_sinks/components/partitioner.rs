@@ -2,15 +2,20 @@ use std::borrow::Cow;
 
 use apollo_core::frame::DataFrame;
 use apollo_core::prelude::row_encode::_get_rows_encoded_ca_unordered;
-use apollo_core::prelude::{BinaryOffsetChunked, Column, IntoGroupsType};
+use apollo_core::prelude::{
+    BinaryOffsetChunked, Column, IntoColumn, IntoGroupsType, NewChunkedArray, UInt32Chunked,
+};
 use apollo_error::ApolloResult;
 use apollo_expr::hash_keys::{HashKeysVariant, hash_keys_variant_for_dtype};
 use apollo_expr::state::ExecutionState;
+use apollo_plan::dsl::BucketHash;
+use apollo_utils::IdxSize;
 use apollo_utils::pl_str::PlSmallStr;
 
 use crate::async_primitives::wait_group::WaitToken;
 use crate::expression::StreamExpr;
 use crate::morsel::Morsel;
+use crate::nodes::io_sinks::components::bucketing::bucket_ids;
 use crate::nodes::io_sinks::components::exclude_keys_projection::ExcludeKeysProjection;
 use crate::nodes::io_sinks::components::partition_key::{PartitionKey, PreComputedKeys};
 use crate::nodes::io_sinks::components::size::RowCountAndSize;
@@ -33,6 +38,7 @@ pub enum Partitioner {
     /// All rows to a single partition
     FileSize,
     Keyed(KeyedPartitioner),
+    Bucketed(BucketedPartitioner),
 }
 
 impl Partitioner {
@@ -50,6 +56,7 @@ impl Partitioner {
                 df,
             }],
             Self::Keyed(v) => v.partition_df(df, in_memory_exec_state).await?,
+            Self::Bucketed(v) => v.partition_df(df, in_memory_exec_state).await?,
         };
 
         let out = PartitionedDataFrames {
@@ -74,6 +81,14 @@ impl Partitioner {
                         kp.key_exprs.len(),
                         if kp.key_exprs.len() == 1 { "" } else { "s" }
                     ),
+                    Partitioner::Bucketed(bp) => write!(
+                        f,
+                        "Bucketed({} key{}, {} buckets, {:?})",
+                        bp.key_exprs.len(),
+                        if bp.key_exprs.len() == 1 { "" } else { "s" },
+                        bp.num_buckets,
+                        bp.hash
+                    ),
                 }
             }
         }
@@ -193,3 +208,71 @@ impl KeyedPartitioner {
         Ok(partitions_vec)
     }
 }
+
+pub struct BucketedPartitioner {
+    /// Must be non-empty
+    pub key_exprs: Vec<StreamExpr>,
+    pub num_buckets: u32,
+    pub hash: BucketHash,
+}
+
+impl BucketedPartitioner {
+    /// Name of the column holding the bucket in the partition keys.
+    pub const BUCKET_COLUMN_NAME: &'static str = "bucket";
+
+    async fn partition_df(
+        &self,
+        df: DataFrame,
+        in_memory_exec_state: &ExecutionState,
+    ) -> ApolloResult<Vec<Partition>> {
+        assert!(!self.key_exprs.is_empty());
+
+        let mut key_columns = Vec::with_capacity(self.key_exprs.len());
+
+        for e in self.key_exprs.as_slice() {
+            key_columns.push(
+                e.evaluate_preserve_len_broadcast(&df, in_memory_exec_state)
+                    .await?,
+            );
+        }
+
+        let bucket_ids = bucket_ids(&key_columns, self.num_buckets, self.hash)?;
+
+        let mut bucket_idxs: Vec<Vec<IdxSize>> = vec![vec![]; self.num_buckets as usize];
+
+        for (i, bucket) in bucket_ids.into_iter().enumerate() {
+            bucket_idxs[bucket as usize].push(i as IdxSize);
+        }
+
+        let partitions_vec: Vec<Partition> = bucket_idxs
+            .into_iter()
+            .enumerate()
+            .filter(|(_, idxs)| !idxs.is_empty())
+            .map(|(bucket, idxs)| {
+                let bucket = bucket as u32;
+                let df = unsafe { df.take_slice_unchecked(&idxs) };
+
+                let keys_df = unsafe {
+                    DataFrame::new_unchecked(
+                        1,
+                        vec![
+                            UInt32Chunked::from_slice(
+                                PlSmallStr::from_static(Self::BUCKET_COLUMN_NAME),
+                                &[bucket],
+                            )
+                            .into_column(),
+                        ],
+                    )
+                };
+
+                Partition {
+                    key: PartitionKey::from_slice(&bucket.to_le_bytes()),
+                    keys_df,
+                    df,
+                }
+            })
+            .collect();
+
+        Ok(partitions_vec)
+    }
+}
@@ -0,0 +1,110 @@
+use std::sync::{Arc, Mutex};
+
+use apollo_error::ApolloResult;
+use apollo_io::cloud::CloudOptions;
+use apollo_io::pl_async;
+use apollo_io::utils::staging::{
+    commit_staged_file, remove_staged_file, remove_staging_dir, staging_file_path,
+};
+use apollo_utils::pl_path::{CloudScheme, PlRefPath};
+use apollo_utils::relaxed_cell::RelaxedCell;
+
+/// Tracks the files written by an atomic sink.
+///
+/// Files are written to hidden staging paths and only moved to their final paths by
+/// [`StagedFiles::commit`]. If the sink fails or is cancelled before that, the staged files are
+/// removed when this is dropped.
+pub struct StagedFiles {
+    cloud_options: Option<Arc<CloudOptions>>,
+    /// Hidden directory that partitioned sinks stage their files in.
+    staging_dir: Option<PlRefPath>,
+    /// `(staging path, final path)` of every file.
+    files: Mutex<Vec<(PlRefPath, PlRefPath)>>,
+    committed: RelaxedCell<bool>,
+}
+
+impl StagedFiles {
+    pub fn new(cloud_options: Option<Arc<CloudOptions>>, staging_dir: Option<PlRefPath>) -> Self {
+        Self {
+            cloud_options,
+            staging_dir,
+            files: Mutex::default(),
+            committed: RelaxedCell::from(false),
+        }
+    }
+
+    /// Returns the path to write the file for `path` to.
+    pub fn stage(&self, path: PlRefPath) -> PlRefPath {
+        let staged = staging_file_path(&path);
+        self.files.lock().unwrap().push((staged.clone(), path));
+        staged
+    }
+
+    /// Returns the path to write the file for `relative_path` within `base_path` to. Relative
+    /// paths are staged within the staging directory, so that no partial directory structure
+    /// becomes visible.
+    pub fn stage_in_dir(&self, base_path: &PlRefPath, relative_path: &str) -> PlRefPath {
+        let path = base_path.join(relative_path);
+
+        let staged = match &self.staging_dir {
+            Some(staging_dir)
+                if CloudScheme::from_path(relative_path).is_none()
+                    && std::path::Path::new(relative_path).is_relative() =>
+            {
+                staging_dir.join(relative_path)
+            },
+            _ => staging_file_path(&path),
+        };
+
+        self.files.lock().unwrap().push((staged.clone(), path));
+        staged
+    }
+
+    /// Moves all staged files to their final paths.
+    pub async fn commit(self: Arc<Self>) -> ApolloResult<()> {
+        pl_async::get_runtime()
+            .spawn(async move {
+                let files = self.files.lock().unwrap().clone();
+                let cloud_options = self.cloud_options.as_deref();
+
+                futures::future::try_join_all(
+                    files
+                        .iter()
+                        .map(|(staged, path)| commit_staged_file(staged, path, cloud_options)),
+                )
+                .await?;
+
+                self.committed.store(true);
+
+                if let Some(staging_dir) = &self.staging_dir {
+                    remove_staging_dir(staging_dir)?;
+                }
+
+                Ok(())
+            })
+            .await
+            .unwrap()
+    }
+}
+
+impl Drop for StagedFiles {
+    fn drop(&mut self) {
+        if self.committed.load() {
+            return;
+        }
+
+        let files = std::mem::take(self.files.get_mut().unwrap());
+        let cloud_options = self.cloud_options.as_deref();
+
+        // Errors are ignored, the sink has already failed.
+        pl_async::get_runtime().block_in_place_on(async {
+            for (staged, _) in &files {
+                let _ = remove_staged_file(staged, cloud_options).await;
+            }
+        });
+
+        if let Some(staging_dir) = &self.staging_dir {
+            let _ = remove_staging_dir(staging_dir);
+        }
+    }
+}
@@ -117,4 +117,6 @@ pub struct PartitionedTarget {
     pub include_keys_in_file: bool,
     pub file_schema: SchemaRef,
     pub file_size_limit: Option<NonZeroRowCountAndSize>,
+    /// Write a manifest of the written files to this path.
+    pub manifest_path: Option<PlRefPath>,
 }
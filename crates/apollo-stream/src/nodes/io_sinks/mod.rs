@@ -34,6 +34,7 @@ impl IOSinkNode {
             IOSinkTarget::File(_) => "single-file",
             IOSinkTarget::Partitioned(p) => match &p.partitioner {
                 Partitioner::Keyed(_) => "partition-keyed",
+                Partitioner::Bucketed(_) => "partition-bucketed",
                 Partitioner::FileSize => "partition-file-size",
             },
         };
@@ -2,6 +2,7 @@ use std::num::NonZeroUsize;
 use std::sync::Arc;
 
 use apollo_error::ApolloResult;
+use apollo_io::utils::staging::staging_dir_path;
 use apollo_plan::dsl::UnifiedSinkArgs;
 use apollo_utils::pl_str::PlSmallStr;
 
@@ -11,12 +12,14 @@ use crate::execute::StreamingExecutionState;
 use crate::morsel::Morsel;
 use crate::nodes::io_sinks::components::error_capture::ErrorCapture;
 use crate::nodes::io_sinks::components::file_provider::FileProvider;
+use crate::nodes::io_sinks::components::manifest::SinkManifest;
 use crate::nodes::io_sinks::components::partition_distributor::PartitionDistributor;
 use crate::nodes::io_sinks::components::partition_morsel_sender::PartitionMorselSender;
 use crate::nodes::io_sinks::components::partition_sink_starter::PartitionSinkStarter;
 use crate::nodes::io_sinks::components::partitioner::Partitioner;
 use crate::nodes::io_sinks::components::partitioner_pipeline::PartitionerPipeline;
 use crate::nodes::io_sinks::components::size::NonZeroRowCountAndSize;
+use crate::nodes::io_sinks::components::staging::StagedFiles;
 use crate::nodes::io_sinks::config::{IOSinkNodeConfig, IOSinkTarget, PartitionedTarget};
 use crate::nodes::io_sinks::writers::create_file_writer_starter;
 use crate::nodes::io_sinks::writers::interface::FileWriterStarter;
@@ -44,6 +47,7 @@ pub fn start_partition_sink_pipeline(
                 maintain_order: _,
                 sync_on_close,
                 cloud_options,
+                atomic,
             },
         input_schema: _,
     } = config
@@ -59,15 +63,32 @@ pub fn start_partition_sink_pipeline(
         include_keys_in_file,
         file_schema,
         file_size_limit,
+        manifest_path,
     } = *target;
 
     let node_name = node_name.clone();
     let verbose = apollo_core::config::verbose();
     let in_memory_exec_state = Arc::new(execution_state.in_memory_exec_state.clone());
 
+    let staged_files = atomic.then(|| {
+        Arc::new(StagedFiles::new(
+            cloud_options.clone(),
+            Some(staging_dir_path(&base_path)),
+        ))
+    });
+
+    let manifest = manifest_path.map(|path| {
+        Arc::new(SinkManifest::new(
+            path,
+            cloud_options.clone(),
+            file_schema.clone(),
+        ))
+    });
+
     let file_provider = Arc::new(FileProvider {
         base_path,
         cloud_options,
+        staged_files: staged_files.clone(),
         provider_type: file_path_provider,
         upload_chunk_size,
         upload_max_concurrency,
@@ -105,7 +126,7 @@ pub fn start_partition_sink_pipeline(
     }
 
     let (partitioned_dfs_tx, partitioned_dfs_rx) = tokio::sync::mpsc::channel(match &partitioner {
-        Partitioner::Keyed(_) => inflight_morsel_limit.get(),
+        Partitioner::Keyed(_) | Partitioner::Bucketed(_) => inflight_morsel_limit.get(),
         Partitioner::FileSize => 1,
     });
     let inflight_morsel_semaphore =
@@ -133,6 +154,7 @@ pub fn start_partition_sink_pipeline(
         writer_starter: Arc::clone(&file_writer_starter),
         sync_on_close,
         num_pipelines_per_sink,
+        manifest: manifest.clone(),
     };
 
     let partition_morsel_sender = PartitionMorselSender {
@@ -168,6 +190,15 @@ pub fn start_partition_sink_pipeline(
         async move {
             partitioner_handle.await;
             partition_distributor_handle.await?;
+
+            if let Some(staged_files) = staged_files {
+                staged_files.commit().await?;
+            }
+
+            if let Some(manifest) = manifest {
+                manifest.write().await?;
+            }
+
             Ok(())
         },
     ));
@@ -4,7 +4,7 @@ use std::sync::Arc;
 use apollo_core::frame::DataFrame;
 use apollo_error::ApolloResult;
 use apollo_io::pl_async;
-use apollo_plan::dsl::UnifiedSinkArgs;
+use apollo_plan::dsl::{SinkTarget, UnifiedSinkArgs};
 use apollo_utils::pl_str::PlSmallStr;
 
 use crate::async_executor::{self, TaskPriority};
@@ -12,6 +12,7 @@ use crate::async_primitives::connector;
 use crate::execute::StreamingExecutionState;
 use crate::morsel::Morsel;
 use crate::nodes::io_sinks::components::morsel_resize_pipeline::MorselResizePipeline;
+use crate::nodes::io_sinks::components::staging::StagedFiles;
 use crate::nodes::io_sinks::config::{IOSinkNodeConfig, IOSinkTarget};
 use crate::nodes::io_sinks::writers::create_file_writer_starter;
 use crate::nodes::io_sinks::writers::interface::{FileOpenTaskHandle, FileWriterStarter};
@@ -39,6 +40,7 @@ pub fn start_single_file_sink_pipeline(
                 maintain_order: _,
                 sync_on_close,
                 cloud_options,
+                atomic,
             },
         input_schema,
     } = config
@@ -49,6 +51,16 @@ pub fn start_single_file_sink_pipeline(
     let file_schema = input_schema;
     let verbose = apollo_core::config::verbose();
 
+    // Dynamic targets are opened by the caller, so they can't be staged.
+    let (target, staged_files) = match target {
+        SinkTarget::Path(path) if atomic => {
+            let staged_files = Arc::new(StagedFiles::new(cloud_options.clone(), None));
+            let staged_path = staged_files.stage(path);
+            (SinkTarget::Path(staged_path), Some(staged_files))
+        },
+        target => (target, None),
+    };
+
     let file_open_task =
         tokio_handle_ext::AbortOnDropHandle(pl_async::get_runtime().spawn(async move {
             target
@@ -105,6 +117,10 @@ pub fn start_single_file_sink_pipeline(
             writer_handle.await?;
             let sent_size = resize_pipeline_handle.await?;
 
+            if let Some(staged_files) = staged_files {
+                staged_files.commit().await?;
+            }
+
             if verbose {
                 eprintln!("{node_name}: Statistics: total_size: {sent_size:?}");
             }
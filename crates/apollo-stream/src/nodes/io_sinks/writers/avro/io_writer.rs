@@ -0,0 +1,49 @@
+use apollo_error::ApolloResult;
+use tokio::io::AsyncWriteExt as _;
+
+use crate::async_executor;
+use crate::nodes::io_sinks::components::sink_morsel::SinkMorselPermit;
+use crate::nodes::io_sinks::writers::avro::morsel_serializer::MorselSerializer;
+use crate::nodes::io_sinks::writers::interface::FileOpenTaskHandle;
+
+pub struct IOWriter {
+    pub file: FileOpenTaskHandle,
+    pub header: Vec<u8>,
+    pub filled_serializer_rx: tokio::sync::mpsc::Receiver<(
+        async_executor::AbortOnDropHandle<ApolloResult<MorselSerializer>>,
+        SinkMorselPermit,
+    )>,
+    pub reuse_serializer_tx: tokio::sync::mpsc::Sender<MorselSerializer>,
+}
+
+impl IOWriter {
+    pub async fn run(self) -> ApolloResult<()> {
+        let IOWriter {
+            file,
+            header,
+            mut filled_serializer_rx,
+            reuse_serializer_tx,
+        } = self;
+
+        let (writable, sync_on_close) = file.await?;
+        // Block compression is part of the Avro format, so there is no external compression here.
+        let mut writer = writable.try_into_async_writeable()?;
+
+        // The header is always written, so that sinking an empty frame still produces a valid file.
+        writer.write_all(&header).await?;
+
+        while let Some((handle, permit)) = filled_serializer_rx.recv().await {
+            let serializer = handle.await?;
+
+            writer.write_all(&serializer.serialized_data).await?;
+
+            drop(permit);
+
+            let _ = reuse_serializer_tx.send(serializer).await;
+        }
+
+        writer.close(sync_on_close).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use apollo_error::ApolloResult;
+use apollo_io::avro::AvroBlockEncoder;
+use apollo_io::pl_async;
+use apollo_utils::IdxSize;
+use apollo_utils::index::NonZeroIdxSize;
+
+use crate::async_executor::{self, TaskPriority};
+use crate::async_primitives::connector;
+use crate::morsel::get_ideal_morsel_size;
+use crate::nodes::io_sinks::components::sink_morsel::{SinkMorsel, SinkMorselPermit};
+use crate::nodes::io_sinks::components::size::{
+    NonZeroRowCountAndSize, RowCountAndSize, TakeableRowsProvider,
+};
+use crate::nodes::io_sinks::writers::interface::{
+    FileOpenTaskHandle, FileWriterStarter, ideal_sink_morsel_size_env,
+};
+use crate::utils::tokio_handle_ext;
+
+mod io_writer;
+mod morsel_serializer;
+
+pub struct AvroWriterStarter {
+    /// Encoder with the record schema and codec, cloned for every serializer.
+    pub base_encoder: AvroBlockEncoder,
+    /// Serialized file header.
+    pub header: Vec<u8>,
+}
+
+impl FileWriterStarter for AvroWriterStarter {
+    fn writer_name(&self) -> &str {
+        "avro"
+    }
+
+    fn takeable_rows_provider(&self) -> TakeableRowsProvider {
+        let (env_num_rows, env_num_bytes) = ideal_sink_morsel_size_env();
+
+        let max_size = NonZeroRowCountAndSize::new(RowCountAndSize {
+            num_rows: env_num_rows
+                .unwrap_or(get_ideal_morsel_size().try_into().unwrap_or(IdxSize::MAX)),
+            num_bytes: env_num_bytes.unwrap_or(8 * 1024 * 1024),
+        })
+        .unwrap();
+
+        TakeableRowsProvider {
+            max_size,
+            byte_size_min_rows: NonZeroIdxSize::new(256).unwrap(),
+            allow_non_max_size: true,
+        }
+    }
+
+    fn start_file_writer(
+        &self,
+        morsel_rx: connector::Receiver<SinkMorsel>,
+        file: FileOpenTaskHandle,
+        num_pipelines: std::num::NonZeroUsize,
+    ) -> ApolloResult<async_executor::JoinHandle<ApolloResult<()>>> {
+        let (filled_serializer_tx, filled_serializer_rx) = tokio::sync::mpsc::channel::<(
+            async_executor::AbortOnDropHandle<ApolloResult<morsel_serializer::MorselSerializer>>,
+            SinkMorselPermit,
+        )>(num_pipelines.get());
+
+        let max_serializers = num_pipelines.get();
+        let (reuse_serializer_tx, reuse_serializer_rx) =
+            tokio::sync::mpsc::channel::<morsel_serializer::MorselSerializer>(max_serializers);
+
+        let io_handle = tokio_handle_ext::AbortOnDropHandle(
+            pl_async::get_runtime().spawn(
+                io_writer::IOWriter {
+                    file,
+                    header: self.header.clone(),
+                    filled_serializer_rx,
+                    reuse_serializer_tx,
+                }
+                .run(),
+            ),
+        );
+
+        let serializer_handle = async_executor::spawn(
+            TaskPriority::High,
+            morsel_serializer::MorselSerializerPipeline {
+                morsel_rx,
+                filled_serializer_tx,
+                reuse_serializer_rx,
+                max_serializers,
+                base_encoder: self.base_encoder.clone(),
+            }
+            .run(),
+        );
+
+        Ok(async_executor::spawn(TaskPriority::Low, async move {
+            io_handle.await.unwrap()?;
+            serializer_handle.await;
+            Ok(())
+        }))
+    }
+}
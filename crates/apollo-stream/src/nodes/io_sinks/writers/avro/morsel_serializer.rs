@@ -0,0 +1,87 @@
+use apollo_core::frame::DataFrame;
+use apollo_error::ApolloResult;
+use apollo_io::avro::AvroBlockEncoder;
+
+use crate::async_executor::{self, TaskPriority};
+use crate::async_primitives::connector;
+use crate::nodes::io_sinks::components::par_utils::rechunk_par;
+use crate::nodes::io_sinks::components::sink_morsel::{SinkMorsel, SinkMorselPermit};
+
+pub struct MorselSerializerPipeline {
+    pub morsel_rx: connector::Receiver<SinkMorsel>,
+    pub filled_serializer_tx: tokio::sync::mpsc::Sender<(
+        async_executor::AbortOnDropHandle<ApolloResult<MorselSerializer>>,
+        SinkMorselPermit,
+    )>,
+    pub reuse_serializer_rx: tokio::sync::mpsc::Receiver<MorselSerializer>,
+    pub max_serializers: usize,
+    pub base_encoder: AvroBlockEncoder,
+}
+
+impl MorselSerializerPipeline {
+    pub async fn run(self) {
+        let MorselSerializerPipeline {
+            mut morsel_rx,
+            filled_serializer_tx,
+            mut reuse_serializer_rx,
+            max_serializers,
+            base_encoder,
+        } = self;
+
+        let mut num_created_serializers: usize = 0;
+
+        while let Ok(morsel) = morsel_rx.recv().await {
+            let morsel_serializer: MorselSerializer =
+                if let Ok(serializer) = reuse_serializer_rx.try_recv() {
+                    serializer
+                } else if num_created_serializers < max_serializers {
+                    num_created_serializers += 1;
+                    MorselSerializer {
+                        encoder: base_encoder.clone(),
+                        serialized_data: vec![],
+                    }
+                } else if let Some(serializer) = reuse_serializer_rx.recv().await {
+                    serializer
+                } else {
+                    break;
+                };
+
+            let (df, morsel_permit) = morsel.into_inner();
+
+            let handle = async_executor::AbortOnDropHandle::new(async_executor::spawn(
+                TaskPriority::High,
+                morsel_serializer.serialize_morsel(df),
+            ));
+
+            if filled_serializer_tx
+                .send((handle, morsel_permit))
+                .await
+                .is_err()
+            {
+                break;
+            }
+        }
+    }
+}
+
+pub struct MorselSerializer {
+    pub encoder: AvroBlockEncoder,
+    pub serialized_data: Vec<u8>,
+}
+
+impl MorselSerializer {
+    /// Encodes the morsel into a single data block.
+    pub async fn serialize_morsel(mut self, mut df: DataFrame) -> ApolloResult<Self> {
+        let MorselSerializer {
+            encoder,
+            serialized_data,
+        } = &mut self;
+
+        rechunk_par(unsafe { df.columns_mut_retain_schema() }).await;
+
+        serialized_data.clear();
+        encoder.encode(&df, serialized_data)?;
+
+        Ok(self)
+    }
+}
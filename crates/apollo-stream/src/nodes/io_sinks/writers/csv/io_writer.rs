@@ -2,7 +2,8 @@ use std::sync::Arc;
 
 use apollo_core::schema::SchemaRef;
 use apollo_error::ApolloResult;
-use apollo_io::prelude::{CsvWriterOptions, ExternalCompression, UTF8_BOM, csv_header};
+use apollo_io::csv::encoding::encode_utf8_csv;
+use apollo_io::prelude::{CsvWriterOptions, ExternalCompression, csv_header};
 use apollo_io::utils::compression::CompressedWriter;
 use apollo_io::utils::file::{AsyncDynWriteable, AsyncWriteable};
 use tokio::io::AsyncWriteExt as _;
@@ -51,14 +52,15 @@ impl IOWriter {
         };
 
         if options.include_bom {
-            writer.write_all(&UTF8_BOM).await?;
+            writer.write_all(options.encoding.bom()).await?;
         }
 
         if options.include_header {
             let names: Vec<&str> = schema.iter_names().map(|s| s.as_str()).collect();
-            writer
-                .write_all(&csv_header(names.as_slice(), &options.serialize_options)?)
-                .await?;
+            let header = csv_header(names.as_slice(), &options.serialize_options)?;
+            let mut encoded_header = Vec::with_capacity(header.len());
+            encode_utf8_csv(options.encoding, &header, &mut encoded_header)?;
+            writer.write_all(&encoded_header).await?;
         }
 
         while let Some((handle, permit)) = filled_serializer_rx.recv().await {
@@ -129,6 +129,7 @@ impl FileWriterStarter for CsvWriterStarter {
                 base_csv_serializer,
                 base_allocation_size,
                 max_serializers,
+                encoding: self.options.encoding,
             }
             .run(),
         );
@@ -1,6 +1,7 @@
 use apollo_core::frame::DataFrame;
 use apollo_error::{ApolloResult, apollo_bail};
-use apollo_io::prelude::CsvSerializer;
+use apollo_io::csv::encoding::encode_utf8_csv;
+use apollo_io::prelude::{CsvEncoding, CsvSerializer};
 
 use crate::async_executor::{self, TaskPriority};
 use crate::async_primitives::connector;
@@ -17,6 +18,7 @@ pub struct MorselSerializerPipeline {
     pub base_csv_serializer: CsvSerializer,
     pub base_allocation_size: usize,
     pub max_serializers: usize,
+    pub encoding: CsvEncoding,
 }
 
 impl MorselSerializerPipeline {
@@ -28,6 +30,7 @@ impl MorselSerializerPipeline {
             base_csv_serializer,
             base_allocation_size,
             max_serializers,
+            encoding,
         } = self;
 
         let mut num_created_serializers: usize = 0;
@@ -41,6 +44,8 @@ impl MorselSerializerPipeline {
                     MorselSerializer {
                         csv_serializer: base_csv_serializer.clone(),
                         serialized_data: Vec::with_capacity(base_allocation_size),
+                        utf8_scratch: Vec::new(),
+                        encoding,
                     }
                 } else if let Some(serializer) = reuse_serializer_rx.recv().await {
                     serializer
@@ -69,6 +74,9 @@ impl MorselSerializerPipeline {
 pub struct MorselSerializer {
     pub csv_serializer: CsvSerializer,
     pub serialized_data: Vec<u8>,
+    /// Serialized UTF-8 data before it is encoded, unused for UTF-8 output.
+    pub utf8_scratch: Vec<u8>,
+    pub encoding: CsvEncoding,
 }
 
 impl MorselSerializer {
@@ -76,6 +84,8 @@ impl MorselSerializer {
         let MorselSerializer {
             csv_serializer,
             serialized_data,
+            utf8_scratch,
+            encoding,
         } = &mut self;
 
         if df.width() == 0 && df.height() > 0 {
@@ -89,7 +99,14 @@ impl MorselSerializer {
         rechunk_par(unsafe { df.columns_mut_retain_schema() }).await;
 
         serialized_data.clear();
-        csv_serializer.serialize_to_csv(&df, serialized_data)?;
+
+        if encoding.is_utf8() {
+            csv_serializer.serialize_to_csv(&df, serialized_data)?;
+        } else {
+            utf8_scratch.clear();
+            csv_serializer.serialize_to_csv(&df, utf8_scratch)?;
+            encode_utf8_csv(*encoding, utf8_scratch, serialized_data)?;
+        }
 
         Ok(self)
     }
@@ -107,6 +107,19 @@ where
             },
             #[cfg(feature = "dtype-extension")]
             Extension(_, storage) => self.dtype_to_ipc_field(storage.as_ref()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => IpcField {
+                fields: vec![self.dtype_to_ipc_field(&DataType::map_entries_dtype(key, value))],
+                dictionary_id: None,
+            },
+            #[cfg(feature = "dtype-union")]
+            Union(fields, _) => IpcField {
+                fields: fields
+                    .iter()
+                    .map(|x| self.dtype_to_ipc_field(x.dtype()))
+                    .collect(),
+                dictionary_id: None,
+            },
             _ => {
                 assert!(!dtype.is_nested());
                 IpcField {
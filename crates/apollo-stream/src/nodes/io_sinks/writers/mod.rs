@@ -7,6 +7,8 @@ use apollo_utils::IdxSize;
 
 use crate::nodes::io_sinks::writers::interface::FileWriterStarter;
 
+#[cfg(feature = "avro")]
+mod avro;
 #[cfg(feature = "csv")]
 mod csv;
 pub mod interface;
@@ -14,6 +16,8 @@ pub mod interface;
 mod ipc;
 #[cfg(feature = "json")]
 mod ndjson;
+#[cfg(feature = "orc")]
+mod orc;
 #[cfg(feature = "parquet")]
 mod parquet;
 
@@ -82,11 +86,43 @@ pub fn create_file_writer_starter(
                 initialized_state: Default::default(),
             },
         ) as _,
+        #[cfg(feature = "avro")]
+        FileWriteFormat::Avro(options) => {
+            use apollo_io::avro::AvroBlockEncoder;
+
+            use crate::nodes::io_sinks::writers::avro::AvroWriterStarter;
+
+            let base_encoder =
+                AvroBlockEncoder::try_new(file_schema.as_ref(), &options.name, options.compression)?;
+
+            let mut header = vec![];
+            base_encoder.write_header(&mut header)?;
+
+            Arc::new(AvroWriterStarter {
+                base_encoder,
+                header,
+            }) as _
+        },
+        #[cfg(feature = "orc")]
+        FileWriteFormat::Orc(options) => {
+            use apollo_io::orc::OrcStripeEncoder;
+
+            use crate::nodes::io_sinks::writers::orc::OrcWriterStarter;
+
+            let base_encoder = OrcStripeEncoder::try_new(file_schema.as_ref(), options.compression)?;
+
+            Arc::new(OrcWriterStarter {
+                options: options.clone(),
+                base_encoder,
+            }) as _
+        },
         #[cfg(not(any(
             feature = "parquet",
             feature = "ipc",
             feature = "csv",
-            feature = "json"
+            feature = "json",
+            feature = "avro",
+            feature = "orc"
         )))]
         _ => panic!("no enum variants on FileType (hint: missing feature flags?)"),
     })
@@ -0,0 +1,51 @@
+use apollo_error::ApolloResult;
+use apollo_io::orc::{EncodedStripe, ORC_MAGIC, OrcFileTail, OrcStripeEncoder};
+use tokio::io::AsyncWriteExt as _;
+
+use crate::async_executor;
+use crate::nodes::io_sinks::components::sink_morsel::SinkMorselPermit;
+use crate::nodes::io_sinks::writers::interface::FileOpenTaskHandle;
+
+pub struct IOWriter {
+    pub file: FileOpenTaskHandle,
+    pub encoder: OrcStripeEncoder,
+    pub encoded_stripe_rx: tokio::sync::mpsc::Receiver<(
+        async_executor::AbortOnDropHandle<ApolloResult<EncodedStripe>>,
+        SinkMorselPermit,
+    )>,
+}
+
+impl IOWriter {
+    pub async fn run(self) -> ApolloResult<()> {
+        let IOWriter {
+            file,
+            encoder,
+            mut encoded_stripe_rx,
+        } = self;
+
+        let (writable, sync_on_close) = file.await?;
+        // Stream compression is part of the ORC format, so there is no external compression here.
+        let mut writer = writable.try_into_async_writeable()?;
+
+        let mut tail = OrcFileTail::new(&encoder);
+
+        writer.write_all(ORC_MAGIC).await?;
+
+        while let Some((handle, permit)) = encoded_stripe_rx.recv().await {
+            let stripe = handle.await?;
+
+            if stripe.num_rows() > 0 {
+                writer.write_all(&stripe.data).await?;
+                tail.push_stripe(stripe);
+            }
+
+            drop(permit);
+        }
+
+        // The tail holds the stripe offsets and statistics, so it can only be written at the end.
+        writer.write_all(&tail.finish()?).await?;
+        writer.close(sync_on_close).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use apollo_error::ApolloResult;
+use apollo_io::orc::{OrcStripeEncoder, OrcWriterOptions};
+use apollo_io::pl_async;
+use apollo_utils::IdxSize;
+use apollo_utils::index::NonZeroIdxSize;
+
+use crate::async_executor::{self, TaskPriority};
+use crate::async_primitives::connector;
+use crate::nodes::io_sinks::components::sink_morsel::SinkMorsel;
+use crate::nodes::io_sinks::components::size::{
+    NonZeroRowCountAndSize, RowCountAndSize, TakeableRowsProvider,
+};
+use crate::nodes::io_sinks::writers::interface::{
+    FileOpenTaskHandle, FileWriterStarter, ideal_sink_morsel_size_env,
+};
+use crate::utils::tokio_handle_ext;
+
+mod io_writer;
+mod morsel_serializer;
+
+pub struct OrcWriterStarter {
+    pub options: OrcWriterOptions,
+    /// Encoder with the file types and codec, cloned for every stripe.
+    pub base_encoder: OrcStripeEncoder,
+}
+
+impl FileWriterStarter for OrcWriterStarter {
+    fn writer_name(&self) -> &str {
+        "orc"
+    }
+
+    fn takeable_rows_provider(&self) -> TakeableRowsProvider {
+        let (env_num_rows, env_num_bytes) = ideal_sink_morsel_size_env();
+
+        // Every morsel becomes a stripe, so these are larger than for the row-based formats.
+        let max_size = NonZeroRowCountAndSize::new(RowCountAndSize {
+            num_rows: env_num_rows.unwrap_or(
+                IdxSize::try_from(self.options.stripe_size.unwrap_or(1024 * 1024))
+                    .unwrap_or(IdxSize::MAX)
+                    .max(1),
+            ),
+            num_bytes: env_num_bytes.unwrap_or(64 * 1024 * 1024),
+        })
+        .unwrap();
+
+        TakeableRowsProvider {
+            max_size,
+            byte_size_min_rows: NonZeroIdxSize::new(1024).unwrap(),
+            allow_non_max_size: false,
+        }
+    }
+
+    fn start_file_writer(
+        &self,
+        morsel_rx: connector::Receiver<SinkMorsel>,
+        file: FileOpenTaskHandle,
+        num_pipelines: std::num::NonZeroUsize,
+    ) -> ApolloResult<async_executor::JoinHandle<ApolloResult<()>>> {
+        let (encoded_stripe_tx, encoded_stripe_rx) = tokio::sync::mpsc::channel(num_pipelines.get());
+
+        let io_handle = tokio_handle_ext::AbortOnDropHandle(
+            pl_async::get_runtime().spawn(
+                io_writer::IOWriter {
+                    file,
+                    encoder: self.base_encoder.clone(),
+                    encoded_stripe_rx,
+                }
+                .run(),
+            ),
+        );
+
+        let serializer_handle = async_executor::spawn(
+            TaskPriority::High,
+            morsel_serializer::MorselSerializerPipeline {
+                morsel_rx,
+                encoded_stripe_tx,
+                base_encoder: self.base_encoder.clone(),
+            }
+            .run(),
+        );
+
+        Ok(async_executor::spawn(TaskPriority::Low, async move {
+            io_handle.await.unwrap()?;
+            serializer_handle.await;
+            Ok(())
+        }))
+    }
+}
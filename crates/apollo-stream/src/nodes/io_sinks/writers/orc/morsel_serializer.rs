@@ -0,0 +1,48 @@
+use apollo_error::ApolloResult;
+use apollo_io::orc::{EncodedStripe, OrcStripeEncoder};
+
+use crate::async_executor::{self, TaskPriority};
+use crate::async_primitives::connector;
+use crate::nodes::io_sinks::components::par_utils::rechunk_par;
+use crate::nodes::io_sinks::components::sink_morsel::{SinkMorsel, SinkMorselPermit};
+
+pub struct MorselSerializerPipeline {
+    pub morsel_rx: connector::Receiver<SinkMorsel>,
+    /// The channel capacity limits the number of stripes that are encoded concurrently.
+    pub encoded_stripe_tx: tokio::sync::mpsc::Sender<(
+        async_executor::AbortOnDropHandle<ApolloResult<EncodedStripe>>,
+        SinkMorselPermit,
+    )>,
+    pub base_encoder: OrcStripeEncoder,
+}
+
+impl MorselSerializerPipeline {
+    pub async fn run(self) {
+        let MorselSerializerPipeline {
+            mut morsel_rx,
+            encoded_stripe_tx,
+            base_encoder,
+        } = self;
+
+        while let Ok(morsel) = morsel_rx.recv().await {
+            let (mut df, morsel_permit) = morsel.into_inner();
+            let encoder = base_encoder.clone();
+
+            let handle = async_executor::AbortOnDropHandle::new(async_executor::spawn(
+                TaskPriority::High,
+                async move {
+                    rechunk_par(unsafe { df.columns_mut_retain_schema() }).await;
+                    encoder.encode(&df)
+                },
+            ));
+
+            if encoded_stripe_tx
+                .send((handle, morsel_permit))
+                .await
+                .is_err()
+            {
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use apollo_core::config;
+use apollo_io::cloud::CloudOptions;
+use apollo_plan::dsl::ScanSource;
+
+use super::AvroFileReader;
+use crate::nodes::io_sources::multi_scan::reader_interface::FileReader;
+use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
+use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
+
+#[derive(Debug)]
+pub struct AvroReaderBuilder {}
+
+impl FileReaderBuilder for AvroReaderBuilder {
+    fn reader_name(&self) -> &str {
+        "avro"
+    }
+
+    fn reader_capabilities(&self) -> ReaderCapabilities {
+        use ReaderCapabilities as RC;
+
+        RC::NEEDS_FILE_CACHE_INIT | RC::ROW_INDEX | RC::PRE_SLICE | RC::NEGATIVE_PRE_SLICE
+    }
+
+    fn build_file_reader(
+        &self,
+        source: ScanSource,
+        cloud_options: Option<Arc<CloudOptions>>,
+        _scan_source_idx: usize,
+    ) -> Box<dyn FileReader> {
+        let reader = AvroFileReader {
+            scan_source: source,
+            cloud_options,
+            init_data: None,
+            verbose: config::verbose(),
+        };
+
+        Box::new(reader) as _
+    }
+}
@@ -0,0 +1,335 @@
+pub mod builder;
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow::datatypes::ArrowSchemaRef;
+use async_trait::async_trait;
+use apollo_buffer::Buffer;
+use apollo_core::prelude::DataFrame;
+use apollo_core::schema::{Schema, SchemaRef};
+use apollo_error::{ApolloResult, apollo_err};
+use apollo_io::RowIndex;
+use apollo_io::avro::{AvroBlock, AvroBlockReader, AvroFileMetadata, count_rows};
+use apollo_io::cloud::CloudOptions;
+use apollo_io::utils::slice::SplitSlicePosition;
+use apollo_plan::dsl::ScanSource;
+use apollo_utils::IdxSize;
+use apollo_utils::pl_str::PlSmallStr;
+use apollo_utils::slice_enum::Slice;
+
+use super::multi_scan::reader_interface::output::{FileReaderOutputRecv, FileReaderOutputSend};
+use super::multi_scan::reader_interface::{
+    BeginReadArgs, FileReader, FileReaderCallbacks, Projection, calc_row_position_after_slice,
+};
+use crate::async_executor::{AbortOnDropHandle, JoinHandle, TaskPriority, spawn};
+use crate::async_primitives::distributor_channel::{self, distributor_channel};
+use crate::morsel::{Morsel, MorselSeq, SourceToken};
+
+pub struct AvroFileReader {
+    pub scan_source: ScanSource,
+    #[expect(unused)] // Will be used when implementing cloud streaming.
+    pub cloud_options: Option<Arc<CloudOptions>>,
+    pub init_data: Option<InitializedState>,
+    pub verbose: bool,
+}
+
+#[derive(Clone)]
+pub struct InitializedState {
+    bytes: Buffer<u8>,
+    metadata: Arc<AvroFileMetadata>,
+    n_rows_in_file: IdxSize,
+}
+
+#[async_trait]
+impl FileReader for AvroFileReader {
+    async fn initialize(&mut self) -> ApolloResult<()> {
+        if self.init_data.is_some() {
+            return Ok(());
+        }
+
+        let bytes = self
+            .scan_source
+            .as_scan_source_ref()
+            .to_buffer_async_assume_latest(self.scan_source.run_async())?;
+
+        let metadata = AvroFileMetadata::try_from_bytes(&bytes)?;
+        // Only reads the block headers.
+        let n_rows_in_file = count_rows(&bytes, &metadata)?;
+        let n_rows_in_file = IdxSize::try_from(n_rows_in_file)
+            .map_err(|_| apollo_err!(bigidx, ctx = "avro file", size = n_rows_in_file))?;
+
+        self.init_data = Some(InitializedState {
+            bytes,
+            metadata: Arc::new(metadata),
+            n_rows_in_file,
+        });
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> ApolloResult<(FileReaderOutputRecv, JoinHandle<ApolloResult<()>>)> {
+        let verbose = self.verbose;
+
+        let InitializedState {
+            bytes,
+            metadata,
+            n_rows_in_file,
+        } = self.init_data.clone().unwrap();
+
+        let BeginReadArgs {
+            projection: Projection::Plain(projected_schema),
+            row_index,
+            pre_slice,
+            predicate: None,
+            runtime_filter: _,
+            cast_columns_policy: _,
+            num_pipelines,
+            disable_morsel_split: _,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args
+        else {
+            panic!("unsupported args: {:?}", &args)
+        };
+
+        // The row count is known upfront, so all callbacks can be handled immediately.
+        if let Some(tx) = file_schema_tx {
+            _ = tx.send(Arc::new(Schema::from_arrow_schema(
+                metadata.arrow_schema.as_ref(),
+            )));
+        }
+
+        if let Some(tx) = n_rows_in_file_tx {
+            _ = tx.send(n_rows_in_file);
+        }
+
+        if let Some(tx) = row_position_on_end_tx {
+            _ = tx.send(calc_row_position_after_slice(
+                n_rows_in_file,
+                pre_slice.clone(),
+            ));
+        }
+
+        // Negative slices are resolved against the known row count.
+        let slice_range: Range<usize> = pre_slice.clone().map_or(0..usize::MAX, |slice| {
+            Range::<usize>::from(slice.restrict_to_bounds(n_rows_in_file as usize))
+        });
+
+        // Keep the order of the projected schema, columns missing from the file are handled by
+        // the multi-scan.
+        let projected_names: Arc<[PlSmallStr]> = projected_schema
+            .iter_names()
+            .filter(|name| metadata.arrow_schema.contains(name.as_str()))
+            .cloned()
+            .collect();
+        let projection_mask = Arc::<[bool]>::from(
+            metadata.projection_mask(projected_names.iter().map(|x| x.as_str())),
+        );
+
+        if verbose {
+            eprintln!(
+                "[AvroFileReader]: \
+                project: {} / {}, \
+                slice: {:?}, \
+                row_index: {:?}, \
+                n_rows_in_file: {}",
+                projected_names.len(),
+                metadata.arrow_schema.len(),
+                &slice_range,
+                &row_index,
+                n_rows_in_file,
+            )
+        }
+
+        if slice_range.is_empty() {
+            let (_, rx) = FileReaderOutputSend::new_serial();
+            return Ok((rx, spawn(TaskPriority::Low, async { Ok(()) })));
+        }
+
+        let (mut block_tx, block_receivers) = distributor_channel(num_pipelines, 1);
+        let (morsel_senders, morsel_rx) = FileReaderOutputSend::new_parallel(num_pipelines);
+
+        let decode_handles = block_receivers
+            .into_iter()
+            .zip(morsel_senders)
+            .map(|(block_rx, morsel_tx)| {
+                AbortOnDropHandle::new(spawn(
+                    TaskPriority::Low,
+                    BlockDecoder {
+                        metadata: metadata.clone(),
+                        projected_names: projected_names.clone(),
+                        projection_mask: projection_mask.clone(),
+                        row_index: row_index.clone(),
+                        block_rx,
+                        morsel_tx,
+                    }
+                    .run(),
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        let block_distributor_handle = AbortOnDropHandle::new(spawn(TaskPriority::Low, {
+            let metadata = metadata.clone();
+
+            async move {
+                let mut reader = AvroBlockReader::new(bytes, &metadata);
+                let mut current_row_offset: usize = 0;
+                let mut seq: u64 = 0;
+
+                while let Some(block) = reader.next_block()? {
+                    let n_rows = block.number_of_rows;
+
+                    match SplitSlicePosition::split_slice_at_file(
+                        current_row_offset,
+                        n_rows,
+                        slice_range.clone(),
+                    ) {
+                        SplitSlicePosition::Before => {},
+                        SplitSlicePosition::Overlapping(offset, len) => {
+                            let batch = BlockBatch {
+                                block: block.clone(),
+                                row_offset: current_row_offset,
+                                slice: (offset, len),
+                                seq: MorselSeq::new(seq),
+                            };
+
+                            if block_tx.send(batch).await.is_err() {
+                                break;
+                            }
+
+                            seq += 1;
+                        },
+                        SplitSlicePosition::After => break,
+                    }
+
+                    current_row_offset += n_rows;
+                }
+
+                ApolloResult::Ok(())
+            }
+        }));
+
+        let finishing_handle = spawn(TaskPriority::Low, async move {
+            block_distributor_handle.await?;
+
+            for handle in decode_handles {
+                handle.await?;
+            }
+
+            if verbose {
+                eprintln!("[AvroFileReader]: returning");
+            }
+
+            Ok(())
+        });
+
+        Ok((morsel_rx, finishing_handle))
+    }
+
+    async fn file_schema(&mut self) -> ApolloResult<SchemaRef> {
+        let arrow_schema = &self.init_data.as_ref().unwrap().metadata.arrow_schema;
+        Ok(Arc::new(Schema::from_arrow_schema(arrow_schema.as_ref())))
+    }
+
+    async fn file_arrow_schema(&mut self) -> ApolloResult<Option<ArrowSchemaRef>> {
+        Ok(Some(
+            self.init_data.as_ref().unwrap().metadata.arrow_schema.clone(),
+        ))
+    }
+
+    async fn n_rows_in_file(&mut self) -> ApolloResult<IdxSize> {
+        Ok(self.init_data.as_ref().unwrap().n_rows_in_file)
+    }
+
+    async fn fast_n_rows_in_file(&mut self) -> ApolloResult<Option<IdxSize>> {
+        Ok(Some(self.init_data.as_ref().unwrap().n_rows_in_file))
+    }
+
+    async fn row_position_after_slice(
+        &mut self,
+        pre_slice: Option<Slice>,
+    ) -> ApolloResult<IdxSize> {
+        Ok(calc_row_position_after_slice(
+            self.init_data.as_ref().unwrap().n_rows_in_file,
+            pre_slice,
+        ))
+    }
+}
+
+/// A decompressed data block together with the rows of it that should be output.
+struct BlockBatch {
+    block: AvroBlock,
+    /// Row position of the first row of the block within the file.
+    row_offset: usize,
+    /// (offset, len) within the block.
+    slice: (usize, usize),
+    seq: MorselSeq,
+}
+
+struct BlockDecoder {
+    metadata: Arc<AvroFileMetadata>,
+    projected_names: Arc<[PlSmallStr]>,
+    projection_mask: Arc<[bool]>,
+    row_index: Option<RowIndex>,
+    block_rx: distributor_channel::Receiver<BlockBatch>,
+    morsel_tx: FileReaderOutputSend,
+}
+
+impl BlockDecoder {
+    async fn run(self) -> ApolloResult<()> {
+        let BlockDecoder {
+            metadata,
+            projected_names,
+            projection_mask,
+            row_index,
+            mut block_rx,
+            mut morsel_tx,
+        } = self;
+
+        // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+        let source_token = SourceToken::new();
+
+        while let Ok(BlockBatch {
+            block,
+            row_offset,
+            slice: (offset, len),
+            seq,
+        }) = block_rx.recv().await
+        {
+            // @NOTE: This empty schema code path is relied upon for `select(pl.len())`
+            let mut df = if projected_names.is_empty() {
+                DataFrame::empty_with_height(len)
+            } else {
+                metadata
+                    .decode_block(&block, &projection_mask)?
+                    .select(projected_names.iter().cloned())?
+                    .slice(i64::try_from(offset).unwrap(), len)
+            };
+
+            if let Some(RowIndex { name, offset: ri_offset }) = &row_index {
+                let position = IdxSize::try_from(row_offset + offset).map_err(|_| {
+                    apollo_err!(bigidx, ctx = "avro file", size = row_offset + offset)
+                })?;
+                df = df.with_row_index(name.clone(), Some(*ri_offset + position))?;
+            }
+
+            if morsel_tx
+                .send_morsel(Morsel::new(df, seq, source_token.clone()))
+                .await
+                .is_err()
+            {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
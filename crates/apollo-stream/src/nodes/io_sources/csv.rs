@@ -8,17 +8,20 @@ use apollo_core::prelude::Field;
 use apollo_core::schema::{SchemaExt, SchemaRef};
 use apollo_error::{ApolloResult, apollo_bail, apollo_err, apollo_warn};
 use apollo_io::cloud::CloudOptions;
-use apollo_io::csv::read::streaming::read_until_start_and_infer_schema;
+use apollo_io::csv::read::streaming::read_until_start_and_infer_schema_with_line_offset;
 use apollo_io::prelude::_csv_read_internal::{
     CountLines, NullValuesCompiled, cast_columns, prepare_csv_schema, read_chunk,
+    read_chunk_rejecting,
 };
 use apollo_io::prelude::builder::validate_utf8;
 use apollo_io::prelude::{CsvEncoding, CsvParseOptions, CsvReadOptions};
 use apollo_io::utils::compression::CompressedReader;
 use apollo_io::utils::slice::SplitSlicePosition;
+use apollo_io::utils::{RejectLocation, RejectedRows};
 use apollo_plan::dsl::ScanSource;
 use apollo_utils::IdxSize;
 use apollo_utils::mem::prefetch::prefetch_l2;
+use apollo_utils::pl_str::PlSmallStr;
 use apollo_utils::slice_enum::Slice;
 
 use super::multi_scan::reader_interface::output::FileReaderOutputRecv;
@@ -36,8 +39,10 @@ pub mod builder {
     use std::sync::Arc;
 
     use apollo_core::config;
+    use apollo_error::{ApolloResult, apollo_ensure};
     use apollo_io::cloud::CloudOptions;
     use apollo_io::prelude::CsvReadOptions;
+    use apollo_io::utils::RejectedRows;
     use apollo_plan::dsl::ScanSource;
 
     use super::CsvFileReader;
@@ -45,7 +50,33 @@ pub mod builder {
     use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
     use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
 
-    impl FileReaderBuilder for Arc<CsvReadOptions> {
+    #[derive(Debug)]
+    pub struct CsvReaderBuilder {
+        pub options: Arc<CsvReadOptions>,
+        /// Shared by all files of the scan, opened once per execution.
+        pub reject_rows: Option<RejectedRows>,
+    }
+
+    impl CsvReaderBuilder {
+        pub fn try_new(options: Arc<CsvReadOptions>) -> ApolloResult<Self> {
+            apollo_ensure!(
+                !(options.ignore_errors && options.reject_rows.is_some()),
+                InvalidOperation: "'ignore_errors' cannot be combined with 'reject_rows'"
+            );
+            let reject_rows = options
+                .reject_rows
+                .as_ref()
+                .map(RejectedRows::try_new)
+                .transpose()?;
+
+            Ok(Self {
+                options,
+                reject_rows,
+            })
+        }
+    }
+
+    impl FileReaderBuilder for CsvReaderBuilder {
         fn reader_name(&self) -> &str {
             "csv"
         }
@@ -54,7 +85,7 @@ pub mod builder {
             use ReaderCapabilities as RC;
 
             RC::NEEDS_FILE_CACHE_INIT
-                | if self.parse_options.comment_prefix.is_some() {
+                | if self.options.parse_options.comment_prefix.is_some() {
                     RC::empty()
                 } else {
                     RC::PRE_SLICE
@@ -69,12 +100,13 @@ pub mod builder {
         ) -> Box<dyn FileReader> {
             let scan_source = source;
             let verbose = config::verbose();
-            let options = self.clone();
+            let options = self.options.clone();
 
             let reader = CsvFileReader {
                 scan_source,
                 cloud_options,
                 options,
+                reject_rows: self.reject_rows.clone(),
                 verbose,
                 cached_bytes: None,
             };
@@ -97,6 +129,8 @@ struct LineBatch {
     slice: (usize, usize),
     /// Position of this chunk relative to the start of the file according to CountLines.
     row_offset: usize,
+    /// Number of lines in the file before this chunk. Only tracked if rows can be rejected.
+    line_offset: usize,
     morsel_seq: MorselSeq,
 }
 
@@ -105,6 +139,7 @@ struct CsvFileReader {
     #[expect(unused)] // Will be used when implementing cloud streaming.
     cloud_options: Option<Arc<CloudOptions>>,
     options: Arc<CsvReadOptions>,
+    reject_rows: Option<RejectedRows>,
     // Cached on first access - we may be called multiple times e.g. on negative slice.
     cached_bytes: Option<Buffer<u8>>,
     verbose: bool,
@@ -136,6 +171,7 @@ impl FileReader for CsvFileReader {
             row_index,
             pre_slice,
             predicate: None,
+            runtime_filter: _,
             cast_columns_policy: _,
             num_pipelines,
             disable_morsel_split: _,
@@ -167,15 +203,19 @@ impl FileReader for CsvFileReader {
             _ => {},
         }
 
-        let mut reader = CompressedReader::try_new(self.cached_bytes.clone().unwrap())?;
-
-        let (inferred_schema, base_leftover) = read_until_start_and_infer_schema(
-            &self.options,
-            Some(projected_schema.clone()),
-            None,
-            &mut reader,
+        let mut reader = CompressedReader::try_new_with_encoding(
+            self.cached_bytes.clone().unwrap(),
+            self.options.parse_options.encoding,
         )?;
 
+        let (inferred_schema, base_leftover, base_line_offset) =
+            read_until_start_and_infer_schema_with_line_offset(
+                &self.options,
+                Some(projected_schema.clone()),
+                None,
+                &mut reader,
+            )?;
+
         let used_schema = Arc::new(inferred_schema);
 
         if let Some(tx) = file_schema_tx {
@@ -202,10 +242,17 @@ impl FileReader for CsvFileReader {
 
         let line_counter = CountLines::new(quote_char, eol_char, comment_prefix.clone());
 
+        let path = self
+            .scan_source
+            .as_scan_source_ref()
+            .as_path()
+            .map(|p| PlSmallStr::from_str(p.as_str()));
+
         let chunk_reader = Arc::new(ChunkReader::try_new(
             self.options.clone(),
             used_schema.clone(),
             projection,
+            self.reject_rows.clone().map(|r| (r, path)),
         )?);
 
         let needs_full_row_count = n_rows_in_file_tx.is_some();
@@ -222,6 +269,9 @@ impl FileReader for CsvFileReader {
                 line_batch_tx,
                 pre_slice,
                 needs_full_row_count,
+                track_line_offsets: self.reject_rows.is_some(),
+                base_line_offset,
+                eol_char,
                 verbose,
             }
             .run(),
@@ -249,6 +299,7 @@ impl FileReader for CsvFileReader {
                         n_lines,
                         slice,
                         row_offset,
+                        line_offset,
                         morsel_seq,
                     }) = line_batch_rx.recv().await
                     {
@@ -262,6 +313,7 @@ impl FileReader for CsvFileReader {
                             n_lines,
                             (offset, len),
                             row_offset,
+                            line_offset,
                         )?;
 
                         n_rows_processed = n_rows_processed.saturating_add(n_rows_in_chunk);
@@ -291,6 +343,7 @@ impl FileReader for CsvFileReader {
                             n_lines,
                             slice,
                             row_offset: _,
+                            line_offset: _,
                             morsel_seq: _,
                         }) = line_batch_rx.recv().await
                         {
@@ -345,6 +398,10 @@ struct LineBatchSource {
     line_batch_tx: distributor_channel::Sender<LineBatch>,
     pre_slice: Option<Slice>,
     needs_full_row_count: bool,
+    track_line_offsets: bool,
+    /// Number of lines before the first content row.
+    base_line_offset: usize,
+    eol_char: u8,
     verbose: bool,
 }
 
@@ -358,6 +415,9 @@ impl LineBatchSource {
             mut line_batch_tx,
             pre_slice,
             needs_full_row_count,
+            track_line_offsets,
+            base_line_offset,
+            eol_char,
             verbose,
         } = self;
 
@@ -378,6 +438,7 @@ impl LineBatchSource {
 
         let mut prev_leftover = base_leftover;
         let mut row_offset = 0usize;
+        let mut line_offset = base_line_offset;
         let mut morsel_seq = MorselSeq::default();
         let mut n_rows_skipped: usize = 0;
         let mut read_size = CompressedReader::initial_read_size();
@@ -407,6 +468,11 @@ impl LineBatchSource {
             let prev_row_offset = row_offset;
             row_offset += n_lines;
 
+            let batch_line_offset = line_offset;
+            if track_line_offsets {
+                line_offset += memchr::memchr_iter(eol_char, &batch_slice).count();
+            }
+
             let slice = if let Some(global_slice) = &global_slice {
                 match SplitSlicePosition::split_slice_at_file(
                     prev_row_offset,
@@ -441,6 +507,7 @@ impl LineBatchSource {
                 n_lines,
                 slice,
                 row_offset,
+                line_offset: batch_line_offset,
                 morsel_seq,
             };
 
@@ -470,6 +537,8 @@ struct ChunkReader {
     projection: Vec<usize>,
     null_values: Option<NullValuesCompiled>,
     validate_utf8: bool,
+    /// Sink for rows that fail to parse, with the path of the file being read.
+    reject_rows: Option<(RejectedRows, Option<PlSmallStr>)>,
 }
 
 impl ChunkReader {
@@ -477,6 +546,7 @@ impl ChunkReader {
         options: Arc<CsvReadOptions>,
         mut reader_schema: SchemaRef,
         projection: Vec<usize>,
+        reject_rows: Option<(RejectedRows, Option<PlSmallStr>)>,
     ) -> ApolloResult<Self> {
         let mut fields_to_cast: Vec<Field> = options.fields_to_cast.clone();
         prepare_csv_schema(&mut reader_schema, &mut fields_to_cast)?;
@@ -502,6 +572,7 @@ impl ChunkReader {
             projection,
             null_values,
             validate_utf8,
+            reject_rows,
         })
     }
 
@@ -513,6 +584,47 @@ impl ChunkReader {
         n_lines: usize,
         slice: (usize, usize),
         chunk_row_offset: usize,
+        // Number of lines in the file before the chunk, used to report rejected rows
+        line_offset: usize,
+    ) -> ApolloResult<(DataFrame, usize)> {
+        let result = self.read_chunk_strict(chunk, n_lines, slice, chunk_row_offset);
+
+        match (result, &self.reject_rows) {
+            // Rows are only rejected when the chunk as a whole failed to parse.
+            (Err(_), Some((reject_rows, path))) if !self.projection.is_empty() => {
+                let location = RejectLocation {
+                    path: path.clone(),
+                    line_offset,
+                };
+                let (mut df, rejected) = read_chunk_rejecting(
+                    chunk,
+                    &self.parse_options,
+                    &self.reader_schema,
+                    &self.projection,
+                    self.null_values.as_ref(),
+                    &self.fields_to_cast,
+                    self.validate_utf8,
+                    &location,
+                )?;
+                reject_rows.extend(rejected)?;
+
+                let height = df.height();
+                if slice != NO_SLICE {
+                    df = df.slice(i64::try_from(slice.0).unwrap(), slice.1);
+                }
+
+                Ok((df, height))
+            },
+            (result, _) => result,
+        }
+    }
+
+    fn read_chunk_strict(
+        &self,
+        chunk: &[u8],
+        n_lines: usize,
+        slice: (usize, usize),
+        chunk_row_offset: usize,
     ) -> ApolloResult<(DataFrame, usize)> {
         if self.validate_utf8 && !validate_utf8(chunk) {
             apollo_bail!(ComputeError: "invalid utf-8 sequence")
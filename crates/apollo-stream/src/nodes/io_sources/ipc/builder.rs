@@ -44,7 +44,7 @@ impl FileReaderBuilder for IpcReaderBuilder {
     fn reader_capabilities(&self) -> ReaderCapabilities {
         use ReaderCapabilities as RC;
 
-        RC::ROW_INDEX | RC::PRE_SLICE
+        RC::ROW_INDEX | RC::PRE_SLICE | RC::RUNTIME_FILTER
     }
 
     fn set_execution_state(&self, execution_state: &crate::execute::StreamingExecutionState) {
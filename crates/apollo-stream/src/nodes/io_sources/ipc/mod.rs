@@ -194,6 +194,7 @@ impl FileReader for IpcFileReader {
             row_index,
             pre_slice: pre_slice_arg,
             predicate: None,
+            runtime_filter,
             cast_columns_policy: _,
             num_pipelines,
             disable_morsel_split,
@@ -397,10 +398,15 @@ impl FileReader for IpcFileReader {
                     SplitSlicePosition::Before => continue,
                     SplitSlicePosition::Overlapping(rows_offset, rows_len) => {
                         let record_batch_decoder = record_batch_decoder.clone();
+                        let runtime_filter = runtime_filter.clone();
                         let decode_fut = async_executor::spawn(TaskPriority::High, async move {
-                            record_batch_decoder
+                            let df = record_batch_decoder
                                 .record_batch_data_to_df(record_batch_data, rows_offset, rows_len)
-                                .await
+                                .await?;
+                            match runtime_filter.as_ref().and_then(|slot| slot.get()) {
+                                Some(filter) => filter.filter_df(df),
+                                None => Ok(df),
+                            }
                         });
                         if decode_send.send((decode_fut, permit)).await.is_err() {
                             break;
@@ -1,5 +1,7 @@
 pub mod multi_scan;
 
+#[cfg(feature = "avro")]
+pub mod avro;
 pub mod batch;
 #[cfg(feature = "csv")]
 pub mod csv;
@@ -9,5 +11,7 @@ pub mod ipc;
 pub mod lines;
 #[cfg(any(feature = "json", feature = "scan_lines"))]
 pub mod ndjson;
+#[cfg(feature = "orc")]
+pub mod orc;
 #[cfg(feature = "parquet")]
 pub mod parquet;
@@ -158,6 +158,7 @@ impl DeletionFilesProvider {
                                     row_index: None,
                                     pre_slice: None,
                                     predicate: None,
+                                    runtime_filter: None,
                                     cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
                                     num_pipelines,
                                     disable_morsel_split: false,
@@ -19,6 +19,7 @@ use crate::metrics::IOMetrics;
 use crate::nodes::io_sources::multi_scan::components::forbid_extra_columns::ForbidExtraColumns;
 use crate::nodes::io_sources::multi_scan::components::projection::builder::ProjectionBuilder;
 use crate::nodes::io_sources::multi_scan::reader_interface;
+use crate::nodes::joins::runtime_filter::RuntimeFilterSlot;
 
 // Some parts are called MultiScan for now to avoid conflict with existing MultiScan.
 
@@ -44,6 +45,8 @@ pub struct MultiScanConfig {
     pub forbid_extra_columns: Option<ForbidExtraColumns>,
     pub deletion_files: Option<DeletionFilesList>,
     pub table_statistics: Option<TableStatistics>,
+    /// Filter on the key columns published by the join consuming this scan.
+    pub runtime_filter: Option<Arc<RuntimeFilterSlot>>,
 
     pub num_pipelines: RelaxedCell<usize>,
     /// Number of readers to initialize concurrently. e.g. Parquet will want to fetch metadata in this
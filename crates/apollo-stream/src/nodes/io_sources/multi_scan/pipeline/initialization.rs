@@ -391,6 +391,10 @@ async fn finish_initialize_multi_scan_pipeline(
     let file_projection_builder = config.file_projection_builder.clone();
     let max_concurrent_scans = config.max_concurrent_scans();
     let disable_morsel_split = config.disable_morsel_split;
+    let runtime_filter = config
+        .runtime_filter
+        .clone()
+        .filter(|_| reader_capabilities.contains(ReaderCapabilities::RUNTIME_FILTER));
 
     let (started_reader_tx, started_reader_rx) =
         tokio::sync::mpsc::channel(max_concurrent_scans.max(2) - 1);
@@ -414,6 +418,7 @@ async fn finish_initialize_multi_scan_pipeline(
                 cast_columns_policy,
                 missing_columns_policy,
                 forbid_extra_columns: config.forbid_extra_columns.clone(),
+                runtime_filter,
                 num_pipelines,
                 disable_morsel_split,
                 verbose,
@@ -24,6 +24,7 @@ use crate::nodes::io_sources::multi_scan::components::physical_slice::PhysicalSl
 use crate::nodes::io_sources::multi_scan::components::projection::builder::ProjectionBuilder;
 use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
 use crate::nodes::io_sources::multi_scan::reader_interface::{FileReader, FileReaderCallbacks};
+use crate::nodes::joins::runtime_filter::RuntimeFilterSlot;
 use crate::pipe::PortSender;
 
 pub struct InitializedPipelineState {
@@ -82,6 +83,7 @@ pub(super) struct StartReaderArgsConstant {
     pub(super) cast_columns_policy: CastColumnsPolicy,
     pub(super) missing_columns_policy: MissingColumnsPolicy,
     pub(super) forbid_extra_columns: Option<ForbidExtraColumns>,
+    pub(super) runtime_filter: Option<Arc<RuntimeFilterSlot>>,
     pub(super) num_pipelines: usize,
     pub(super) disable_morsel_split: bool,
     pub(super) verbose: bool,
@@ -408,6 +408,7 @@ async fn start_reader_impl(
         cast_columns_policy,
         missing_columns_policy,
         forbid_extra_columns,
+        runtime_filter,
         num_pipelines,
         disable_morsel_split,
         verbose,
@@ -604,6 +605,7 @@ async fn start_reader_impl(
         row_index,
         pre_slice,
         predicate,
+        runtime_filter,
         cast_columns_policy: cast_columns_policy.clone(),
         num_pipelines,
         disable_morsel_split,
@@ -4,6 +4,8 @@ pub mod builder;
 pub mod capabilities;
 pub mod output;
 
+use std::sync::Arc;
+
 use arrow::datatypes::ArrowSchemaRef;
 use async_trait::async_trait;
 use output::FileReaderOutputRecv;
@@ -17,6 +19,7 @@ use apollo_utils::slice_enum::Slice;
 
 use crate::async_executor::JoinHandle;
 use crate::async_primitives::oneshot_channel;
+use crate::nodes::joins::runtime_filter::RuntimeFilterSlot;
 pub use crate::nodes::io_sources::multi_scan::components::projection::Projection;
 
 /// Interface to read a single file
@@ -141,6 +144,10 @@ pub struct BeginReadArgs {
     pub pre_slice: Option<Slice>,
     pub predicate: Option<ScanIOPredicate>,
 
+    /// Filter on the join keys that may become available while reading. Rows rejected by it
+    /// can be skipped, but the reader is free to ignore it.
+    pub runtime_filter: Option<Arc<RuntimeFilterSlot>>,
+
     /// User-configured policy for when datatypes do not match.
     ///
     /// A reader may wish to use this if it is applying predicates.
@@ -164,6 +171,7 @@ impl Default for BeginReadArgs {
             row_index: None,
             pre_slice: None,
             predicate: None,
+            runtime_filter: None,
             // TODO: Use less restrictive default
             cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
             num_pipelines: 1,
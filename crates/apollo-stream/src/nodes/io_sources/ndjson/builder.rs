@@ -1,7 +1,9 @@
 use std::sync::Arc;
 
 use apollo_core::config;
+use apollo_error::{ApolloResult, apollo_ensure};
 use apollo_io::cloud::CloudOptions;
+use apollo_io::utils::RejectedRows;
 use apollo_plan::dsl::ScanSource;
 
 use super::{FileReader, NDJsonFileReader};
@@ -16,7 +18,35 @@ pub fn ndjson_reader_capabilities() -> ReaderCapabilities {
 }
 
 #[cfg(feature = "json")]
-impl FileReaderBuilder for apollo_plan::dsl::NDJsonReadOptions {
+#[derive(Debug)]
+pub struct NDJsonReaderBuilder {
+    pub options: apollo_plan::dsl::NDJsonReadOptions,
+    /// Shared by all files of the scan, opened once per execution.
+    pub reject_rows: Option<RejectedRows>,
+}
+
+#[cfg(feature = "json")]
+impl NDJsonReaderBuilder {
+    pub fn try_new(options: apollo_plan::dsl::NDJsonReadOptions) -> ApolloResult<Self> {
+        apollo_ensure!(
+            !(options.ignore_errors && options.reject_rows.is_some()),
+            InvalidOperation: "'ignore_errors' cannot be combined with 'reject_rows'"
+        );
+        let reject_rows = options
+            .reject_rows
+            .as_ref()
+            .map(RejectedRows::try_new)
+            .transpose()?;
+
+        Ok(Self {
+            options,
+            reject_rows,
+        })
+    }
+}
+
+#[cfg(feature = "json")]
+impl FileReaderBuilder for NDJsonReaderBuilder {
     fn reader_name(&self) -> &str {
         "ndjson"
     }
@@ -33,7 +63,8 @@ impl FileReaderBuilder for apollo_plan::dsl::NDJsonReadOptions {
     ) -> Box<dyn FileReader> {
         let scan_source = source;
         let chunk_reader_builder = ChunkReaderBuilder::NDJson {
-            ignore_errors: self.ignore_errors,
+            ignore_errors: self.options.ignore_errors,
+            reject_rows: self.reject_rows.clone(),
         };
         let verbose = config::verbose();
 
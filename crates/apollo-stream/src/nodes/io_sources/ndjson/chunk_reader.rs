@@ -1,8 +1,8 @@
 use apollo_core::schema::SchemaRef;
 use apollo_error::ApolloResult;
 use apollo_io::ndjson;
-use apollo_io::prelude::{is_json_line, parse_ndjson};
-#[cfg(feature = "scan_lines")]
+use apollo_io::prelude::{is_json_line, parse_ndjson, parse_ndjson_rejecting};
+use apollo_io::utils::{RejectLocation, RejectedRows};
 use apollo_utils::pl_str::PlSmallStr;
 
 use crate::nodes::compute_node_prelude::*;
@@ -11,6 +11,7 @@ use crate::nodes::compute_node_prelude::*;
 pub enum ChunkReaderBuilder {
     NDJson {
         ignore_errors: bool,
+        reject_rows: Option<RejectedRows>,
     },
     #[cfg(feature = "scan_lines")]
     Lines,
@@ -22,6 +23,8 @@ pub enum ChunkReader {
     NDJson {
         projected_schema: SchemaRef,
         ignore_errors: bool,
+        /// Sink for lines that fail to parse, with the path of the file being read.
+        reject_rows: Option<(RejectedRows, Option<PlSmallStr>)>,
     },
     #[cfg(feature = "scan_lines")]
     Lines {
@@ -31,11 +34,19 @@ pub enum ChunkReader {
 }
 
 impl ChunkReaderBuilder {
-    pub(super) fn build(&self, projected_schema: SchemaRef) -> ChunkReader {
+    pub(super) fn build(
+        &self,
+        projected_schema: SchemaRef,
+        path: Option<PlSmallStr>,
+    ) -> ChunkReader {
         match self {
-            Self::NDJson { ignore_errors } => ChunkReader::NDJson {
+            Self::NDJson {
+                ignore_errors,
+                reject_rows,
+            } => ChunkReader::NDJson {
                 projected_schema,
                 ignore_errors: *ignore_errors,
+                reject_rows: reject_rows.clone().map(|r| (r, path)),
             },
             #[cfg(feature = "scan_lines")]
             Self::Lines => {
@@ -55,6 +66,15 @@ impl ChunkReaderBuilder {
         }
     }
 
+    /// Whether the line batches need to track their line offset in the file.
+    pub(super) fn needs_line_offsets(&self) -> bool {
+        match self {
+            Self::NDJson { reject_rows, .. } => reject_rows.is_some(),
+            #[cfg(feature = "scan_lines")]
+            Self::Lines => false,
+        }
+    }
+
     pub(super) fn is_line_fn(&self) -> fn(&[u8]) -> bool {
         match self {
             Self::NDJson { .. } => is_json_line,
@@ -65,16 +85,34 @@ impl ChunkReaderBuilder {
 }
 
 impl ChunkReader {
-    pub(super) fn read_chunk(&self, chunk: &[u8]) -> ApolloResult<DataFrame> {
+    /// `line_offset` is the number of lines in the file before `chunk`, it is only used to report
+    /// rejected lines.
+    pub(super) fn read_chunk(&self, chunk: &[u8], line_offset: usize) -> ApolloResult<DataFrame> {
         match self {
             Self::NDJson {
                 projected_schema,
                 ignore_errors,
+                reject_rows,
             } => {
                 if projected_schema.is_empty() {
-                    Ok(DataFrame::empty_with_height(ndjson::count_rows(chunk)))
-                } else {
-                    parse_ndjson(chunk, None, projected_schema, *ignore_errors)
+                    return Ok(DataFrame::empty_with_height(ndjson::count_rows(chunk)));
+                }
+
+                let result = parse_ndjson(chunk, None, projected_schema, *ignore_errors);
+
+                match (result, reject_rows) {
+                    // Lines are only rejected when the chunk as a whole failed to parse.
+                    (Err(_), Some((reject_rows, path))) => {
+                        let location = RejectLocation {
+                            path: path.clone(),
+                            line_offset,
+                        };
+                        let (df, rejected) =
+                            parse_ndjson_rejecting(chunk, projected_schema, &location)?;
+                        reject_rows.extend(rejected)?;
+                        Ok(df)
+                    },
+                    (result, _) => result,
                 }
             },
             #[cfg(feature = "scan_lines")]
@@ -16,6 +16,8 @@ pub(super) struct LineBatchDistributor {
     pub(super) reader: CompressedReader,
     pub(super) reverse: bool,
     pub(super) row_skipper: RowSkipper,
+    /// Track the line offset of every batch, used to report rejected lines.
+    pub(super) track_line_offsets: bool,
     pub(super) line_batch_distribute_tx: distributor_channel::Sender<LineBatch>,
 }
 
@@ -26,6 +28,7 @@ impl LineBatchDistributor {
             mut reader,
             reverse,
             mut row_skipper,
+            track_line_offsets,
             mut line_batch_distribute_tx,
         } = self;
 
@@ -66,6 +69,13 @@ impl LineBatchDistributor {
             None
         };
 
+        // Number of lines before the next chunk. When reading in reverse this starts at the total
+        // and is decremented instead.
+        let mut lines_before = match &full_input_opt {
+            Some((full_input, _)) if track_line_offsets => count_lines(full_input),
+            _ => 0,
+        };
+
         let mut read_size = fixed_read_size
             .map(NonZeroUsize::get)
             .unwrap_or_else(CompressedReader::initial_read_size);
@@ -99,6 +109,7 @@ impl LineBatchDistributor {
                 reverse,
                 &mut chunk_idx,
                 &mut row_skipper,
+                track_line_offsets.then_some(&mut lines_before),
                 &mut line_batch_distribute_tx,
             )
             .await;
@@ -141,6 +152,7 @@ async fn process_chunk(
     reverse: bool,
     chunk_idx: &mut usize,
     row_skipper: &mut RowSkipper,
+    lines_before: Option<&mut usize>,
     line_batch_distribute_tx: &mut distributor_channel::Sender<LineBatch>,
 ) -> (Option<usize>, bool) {
     let len = chunk.len();
@@ -168,14 +180,33 @@ async fn process_chunk(
             chunk.sliced(..offset)
         };
 
+        let track_line_offsets = lines_before.is_some();
+        let mut line_offset = 0;
+        if let Some(lines_before) = lines_before {
+            let n_lines = count_lines(&line_chunk);
+            if reverse {
+                *lines_before = lines_before.saturating_sub(n_lines);
+                line_offset = *lines_before;
+            } else {
+                line_offset = *lines_before;
+                *lines_before += n_lines;
+            }
+        }
+
         // Since this path is only executed if at least one line is found or EOF, we guarantee that
         // `skip_rows` will always make progress.
-        let batch_chunk = row_skipper.skip_rows(line_chunk);
+        let batch_chunk = row_skipper.skip_rows(line_chunk.clone());
+
+        if track_line_offsets && !reverse {
+            // Account for the lines skipped from the start of the chunk.
+            line_offset += count_lines(&line_chunk[..line_chunk.len() - batch_chunk.len()]);
+        }
 
         if !batch_chunk.is_empty() {
             let batch = LineBatch {
                 bytes: batch_chunk,
                 chunk_idx: *chunk_idx,
+                line_offset,
             };
             done = line_batch_distribute_tx.send(batch).await.is_err();
             *chunk_idx += 1;
@@ -185,6 +216,10 @@ async fn process_chunk(
     (unconsumed_offset, done)
 }
 
+fn count_lines(bytes: &[u8]) -> usize {
+    memchr::memchr_iter(LF, bytes).count()
+}
+
 pub(super) struct RowSkipper {
     /// Configured number of rows to skip. This MUST NOT be mutated during runtime.
     pub(super) cfg_n_rows_to_skip: usize,
@@ -56,8 +56,13 @@ impl LineBatchProcessor {
         let mut n_rows_processed: usize = 0;
 
         if !matches!(output_port, LineBatchProcessorOutputPort::Closed) {
-            while let Ok(LineBatch { bytes, chunk_idx }) = line_batch_rx.recv().await {
-                let df = chunk_reader.read_chunk(&bytes)?;
+            while let Ok(LineBatch {
+                bytes,
+                chunk_idx,
+                line_offset,
+            }) = line_batch_rx.recv().await
+            {
+                let df = chunk_reader.read_chunk(&bytes, line_offset)?;
 
                 n_rows_processed = n_rows_processed.saturating_add(df.height());
 
@@ -77,6 +82,7 @@ impl LineBatchProcessor {
             while let Ok(LineBatch {
                 bytes,
                 chunk_idx: _,
+                line_offset: _,
             }) = line_batch_rx.recv().await
             {
                 n_rows_processed = n_rows_processed.saturating_add(count_rows_fn(&bytes));
@@ -96,6 +102,8 @@ pub(super) struct LineBatch {
     /// Safety: This is sent between 2 places that both hold a reference to the underlying Buffer.
     pub(super) bytes: Buffer<u8>,
     pub(super) chunk_idx: usize,
+    /// Number of lines in the file before this chunk. Only tracked if rows can be rejected.
+    pub(super) line_offset: usize,
 }
 
 /// We are connected to different outputs depending on query.
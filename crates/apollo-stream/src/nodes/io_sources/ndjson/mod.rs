@@ -13,6 +13,7 @@ use apollo_io::cloud::CloudOptions;
 use apollo_io::utils::compression::CompressedReader;
 use apollo_plan::dsl::ScanSource;
 use apollo_utils::IdxSize;
+use apollo_utils::pl_str::PlSmallStr;
 use apollo_utils::priority::Priority;
 use apollo_utils::slice_enum::Slice;
 use row_index_limit_pass::ApplyRowIndexOrLimit;
@@ -83,6 +84,7 @@ impl FileReader for NDJsonFileReader {
                 },
 
             predicate: None,
+            runtime_filter: _,
             cast_columns_policy: _,
         } = args
         else {
@@ -244,7 +246,12 @@ impl FileReader for NDJsonFileReader {
             None
         };
 
-        let chunk_reader = self.chunk_reader_builder.build(schema);
+        let path = self
+            .scan_source
+            .as_scan_source_ref()
+            .as_path()
+            .map(|p| PlSmallStr::from_str(p.as_str()));
+        let chunk_reader = self.chunk_reader_builder.build(schema, path);
 
         let (line_batch_distribute_tx, line_batch_distribute_receivers) =
             distributor_channel(num_pipelines, 1);
@@ -314,6 +321,7 @@ impl FileReader for NDJsonFileReader {
                 reader,
                 reverse: is_negative_slice,
                 row_skipper,
+                track_line_offsets: self.chunk_reader_builder.needs_line_offsets(),
                 line_batch_distribute_tx,
             }
             .run(),
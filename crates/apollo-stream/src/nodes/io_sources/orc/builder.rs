@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use apollo_core::config;
+use apollo_io::cloud::CloudOptions;
+use apollo_plan::dsl::ScanSource;
+
+use super::OrcFileReader;
+use crate::nodes::io_sources::multi_scan::reader_interface::FileReader;
+use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
+use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
+
+#[derive(Debug)]
+pub struct OrcReaderBuilder {}
+
+impl FileReaderBuilder for OrcReaderBuilder {
+    fn reader_name(&self) -> &str {
+        "orc"
+    }
+
+    fn reader_capabilities(&self) -> ReaderCapabilities {
+        use ReaderCapabilities as RC;
+
+        RC::NEEDS_FILE_CACHE_INIT
+            | RC::ROW_INDEX
+            | RC::PRE_SLICE
+            | RC::NEGATIVE_PRE_SLICE
+            | RC::PARTIAL_FILTER
+            | RC::MAPPED_COLUMN_PROJECTION
+    }
+
+    fn build_file_reader(
+        &self,
+        source: ScanSource,
+        cloud_options: Option<Arc<CloudOptions>>,
+        _scan_source_idx: usize,
+    ) -> Box<dyn FileReader> {
+        let reader = OrcFileReader {
+            scan_source: source,
+            cloud_options,
+            init_data: None,
+            verbose: config::verbose(),
+        };
+
+        Box::new(reader) as _
+    }
+}
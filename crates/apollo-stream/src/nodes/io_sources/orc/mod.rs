@@ -0,0 +1,467 @@
+pub mod builder;
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow::bitmap::Bitmap;
+use arrow::datatypes::ArrowSchemaRef;
+use async_trait::async_trait;
+use apollo_buffer::Buffer;
+use apollo_core::prelude::{Column, CompatLevel, DataFrame};
+use apollo_core::schema::{SchemaExt, SchemaRef};
+use apollo_error::{ApolloResult, apollo_err};
+use apollo_io::RowIndex;
+use apollo_io::cloud::CloudOptions;
+use apollo_io::orc::OrcFileMetadata;
+use apollo_io::predicates::ScanIOPredicate;
+use apollo_io::utils::slice::SplitSlicePosition;
+use apollo_plan::dsl::ScanSource;
+use apollo_utils::IdxSize;
+use apollo_utils::format_pl_smallstr;
+use apollo_utils::pl_str::PlSmallStr;
+use apollo_utils::slice_enum::Slice;
+
+use super::multi_scan::components::column_selector::ColumnSelector;
+use super::multi_scan::components::projection::MappedProjectionRef;
+use super::multi_scan::components::projection::builder::ProjectionBuilder;
+use super::multi_scan::reader_interface::output::{FileReaderOutputRecv, FileReaderOutputSend};
+use super::multi_scan::reader_interface::{
+    BeginReadArgs, FileReader, FileReaderCallbacks, Projection, calc_row_position_after_slice,
+};
+use crate::async_executor::{AbortOnDropHandle, JoinHandle, TaskPriority, spawn};
+use crate::async_primitives::distributor_channel::{self, distributor_channel};
+use crate::morsel::{Morsel, MorselSeq, SourceToken};
+
+pub struct OrcFileReader {
+    pub scan_source: ScanSource,
+    #[expect(unused)] // Will be used when implementing cloud streaming.
+    pub cloud_options: Option<Arc<CloudOptions>>,
+    pub init_data: Option<InitializedState>,
+    pub verbose: bool,
+}
+
+#[derive(Clone)]
+pub struct InitializedState {
+    bytes: Buffer<u8>,
+    metadata: Arc<OrcFileMetadata>,
+    n_rows_in_file: IdxSize,
+}
+
+#[async_trait]
+impl FileReader for OrcFileReader {
+    async fn initialize(&mut self) -> ApolloResult<()> {
+        if self.init_data.is_some() {
+            return Ok(());
+        }
+
+        let bytes = self
+            .scan_source
+            .as_scan_source_ref()
+            .to_buffer_async_assume_latest(self.scan_source.run_async())?;
+
+        let metadata = OrcFileMetadata::try_from_bytes(&bytes)?;
+        let n_rows_in_file = IdxSize::try_from(metadata.num_rows())
+            .map_err(|_| apollo_err!(bigidx, ctx = "orc file", size = metadata.num_rows()))?;
+
+        self.init_data = Some(InitializedState {
+            bytes,
+            metadata: Arc::new(metadata),
+            n_rows_in_file,
+        });
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> ApolloResult<(FileReaderOutputRecv, JoinHandle<ApolloResult<()>>)> {
+        let verbose = self.verbose;
+
+        let InitializedState {
+            bytes,
+            metadata,
+            n_rows_in_file,
+        } = self.init_data.clone().unwrap();
+
+        let BeginReadArgs {
+            projection,
+            row_index,
+            pre_slice,
+            predicate,
+            runtime_filter: _,
+            cast_columns_policy,
+            num_pipelines,
+            disable_morsel_split: _,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args;
+
+        // The row count is known upfront, so all callbacks can be handled immediately.
+        if let Some(tx) = file_schema_tx {
+            _ = tx.send(metadata.schema.clone());
+        }
+
+        if let Some(tx) = n_rows_in_file_tx {
+            _ = tx.send(n_rows_in_file);
+        }
+
+        if let Some(tx) = row_position_on_end_tx {
+            _ = tx.send(calc_row_position_after_slice(
+                n_rows_in_file,
+                pre_slice.clone(),
+            ));
+        }
+
+        let projection: Projection = match projection {
+            Projection::Plain(projected_schema) => {
+                ProjectionBuilder::new(projected_schema, None, None).build_projection(
+                    Some(metadata.schema.as_ref()),
+                    None,
+                    cast_columns_policy,
+                    usize::MAX,
+                )?
+            },
+            Projection::Mapped { .. } => projection,
+        };
+
+        let column_projections: Arc<[OrcColumnProjection]> = projection
+            .iter_non_missing_columns()
+            .map(
+                |MappedProjectionRef {
+                     source_name,
+                     output_name,
+                     output_dtype: _,
+                     resolved_transform,
+                 }| OrcColumnProjection {
+                    source_name: source_name.clone(),
+                    output_name: output_name.clone(),
+                    transform: resolved_transform
+                        .map(|t| t.attach_transforms(ColumnSelector::Position(0))),
+                },
+            )
+            .collect();
+
+        let source_names: Arc<[PlSmallStr]> = column_projections
+            .iter()
+            .map(|p| p.source_name.clone())
+            .collect();
+
+        // Negative slices are resolved against the known row count.
+        let slice_range: Range<usize> = pre_slice.clone().map_or(0..usize::MAX, |slice| {
+            Range::<usize>::from(slice.restrict_to_bounds(n_rows_in_file as usize))
+        });
+
+        if verbose {
+            eprintln!(
+                "[OrcFileReader]: \
+                project: {} / {}, \
+                slice: {:?}, \
+                row_index: {:?}, \
+                predicate: {:?}, \
+                n_rows_in_file: {}, \
+                n_stripes: {}",
+                column_projections.len(),
+                metadata.schema.len(),
+                &slice_range,
+                &row_index,
+                predicate.as_ref().map(|_| "<predicate>"),
+                n_rows_in_file,
+                metadata.num_stripes(),
+            )
+        }
+
+        if slice_range.is_empty() {
+            let (_, rx) = FileReaderOutputSend::new_serial();
+            return Ok((rx, spawn(TaskPriority::Low, async { Ok(()) })));
+        }
+
+        let (mut stripe_tx, stripe_receivers) = distributor_channel(num_pipelines, 1);
+        let (morsel_senders, morsel_rx) = FileReaderOutputSend::new_parallel(num_pipelines);
+
+        let decode_handles = stripe_receivers
+            .into_iter()
+            .zip(morsel_senders)
+            .map(|(stripe_rx, morsel_tx)| {
+                AbortOnDropHandle::new(spawn(
+                    TaskPriority::Low,
+                    StripeDecoder {
+                        bytes: bytes.clone(),
+                        metadata: metadata.clone(),
+                        column_projections: column_projections.clone(),
+                        source_names: source_names.clone(),
+                        row_index: row_index.clone(),
+                        stripe_rx,
+                        morsel_tx,
+                    }
+                    .run(),
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        let stripe_distributor_handle = AbortOnDropHandle::new(spawn(TaskPriority::Low, {
+            let metadata = metadata.clone();
+            let column_projections = column_projections.clone();
+
+            async move {
+                // The predicate is re-applied after the reader, so stripes only have to be
+                // skipped when the statistics prove that none of their rows can match.
+                let skip_stripe_mask = match &predicate {
+                    Some(predicate) if pre_slice.is_none() => calculate_stripe_skip_mask(
+                        predicate,
+                        &metadata,
+                        &column_projections,
+                        verbose,
+                    )?,
+                    _ => None,
+                };
+
+                let mut current_row_offset: usize = 0;
+                let mut seq: u64 = 0;
+
+                for stripe_idx in 0..metadata.num_stripes() {
+                    let n_rows = metadata.stripe_num_rows(stripe_idx);
+
+                    if skip_stripe_mask.as_ref().is_some_and(|m| m.get_bit(stripe_idx)) {
+                        current_row_offset += n_rows;
+                        continue;
+                    }
+
+                    match SplitSlicePosition::split_slice_at_file(
+                        current_row_offset,
+                        n_rows,
+                        slice_range.clone(),
+                    ) {
+                        SplitSlicePosition::Before => {},
+                        SplitSlicePosition::Overlapping(offset, len) => {
+                            let batch = StripeBatch {
+                                stripe_idx,
+                                row_offset: current_row_offset,
+                                slice: (offset, len),
+                                seq: MorselSeq::new(seq),
+                            };
+
+                            if stripe_tx.send(batch).await.is_err() {
+                                break;
+                            }
+
+                            seq += 1;
+                        },
+                        SplitSlicePosition::After => break,
+                    }
+
+                    current_row_offset += n_rows;
+                }
+
+                ApolloResult::Ok(())
+            }
+        }));
+
+        let finishing_handle = spawn(TaskPriority::Low, async move {
+            stripe_distributor_handle.await?;
+
+            for handle in decode_handles {
+                handle.await?;
+            }
+
+            if verbose {
+                eprintln!("[OrcFileReader]: returning");
+            }
+
+            Ok(())
+        });
+
+        Ok((morsel_rx, finishing_handle))
+    }
+
+    async fn file_schema(&mut self) -> ApolloResult<SchemaRef> {
+        Ok(self.init_data.as_ref().unwrap().metadata.schema.clone())
+    }
+
+    async fn file_arrow_schema(&mut self) -> ApolloResult<Option<ArrowSchemaRef>> {
+        let schema = &self.init_data.as_ref().unwrap().metadata.schema;
+        Ok(Some(Arc::new(schema.to_arrow(CompatLevel::newest()))))
+    }
+
+    async fn n_rows_in_file(&mut self) -> ApolloResult<IdxSize> {
+        Ok(self.init_data.as_ref().unwrap().n_rows_in_file)
+    }
+
+    async fn fast_n_rows_in_file(&mut self) -> ApolloResult<Option<IdxSize>> {
+        Ok(Some(self.init_data.as_ref().unwrap().n_rows_in_file))
+    }
+
+    async fn row_position_after_slice(
+        &mut self,
+        pre_slice: Option<Slice>,
+    ) -> ApolloResult<IdxSize> {
+        Ok(calc_row_position_after_slice(
+            self.init_data.as_ref().unwrap().n_rows_in_file,
+            pre_slice,
+        ))
+    }
+}
+
+/// A potentially mapped (i.e. casted and/or renamed) column of the file.
+struct OrcColumnProjection {
+    source_name: PlSmallStr,
+    output_name: PlSmallStr,
+    transform: Option<ColumnSelector>,
+}
+
+impl OrcColumnProjection {
+    fn apply_transform(&self, column: Column) -> ApolloResult<Column> {
+        let Some(transform) = &self.transform else {
+            return Ok(column);
+        };
+
+        let output_height = column.len();
+        Ok(transform
+            .select_from_columns(&[column], output_height)?
+            .with_name(self.output_name.clone()))
+    }
+}
+
+/// Evaluates the skip batch predicate against the stripe statistics. A set bit means that the
+/// stripe can be skipped.
+fn calculate_stripe_skip_mask(
+    predicate: &ScanIOPredicate,
+    metadata: &OrcFileMetadata,
+    column_projections: &[OrcColumnProjection],
+    verbose: bool,
+) -> ApolloResult<Option<Bitmap>> {
+    let Some(sbp) = predicate.skip_batch_predicate.as_ref() else {
+        return Ok(None);
+    };
+
+    let num_stripes = metadata.num_stripes();
+
+    if !metadata.has_stripe_statistics() {
+        return Ok(None);
+    }
+
+    let mut columns = Vec::with_capacity(1 + predicate.live_columns.len() * 3);
+
+    let lengths: Vec<IdxSize> = (0..num_stripes)
+        .map(|i| IdxSize::try_from(metadata.stripe_num_rows(i)).unwrap_or(IdxSize::MAX))
+        .collect();
+    columns.push(Column::new("len".into(), lengths));
+
+    for projection in column_projections.iter() {
+        let c = &projection.output_name;
+
+        if !predicate.live_columns.contains(c) {
+            continue;
+        }
+
+        let statistics =
+            metadata.stripe_column_statistics(&projection.source_name, 0..num_stripes)?;
+
+        columns.extend([
+            projection
+                .apply_transform(statistics.min)?
+                .with_name(format_pl_smallstr!("{c}_min")),
+            projection
+                .apply_transform(statistics.max)?
+                .with_name(format_pl_smallstr!("{c}_max")),
+            statistics.null_count.with_name(format_pl_smallstr!("{c}_nc")),
+        ]);
+    }
+
+    let statistics_df = DataFrame::new(num_stripes, columns)?;
+    let skip_stripe_mask = sbp.evaluate_with_stat_df(&statistics_df)?;
+
+    if verbose {
+        eprintln!(
+            "[OrcFileReader]: Predicate pushdown: \
+            reading {} / {} stripes",
+            skip_stripe_mask.unset_bits(),
+            num_stripes,
+        );
+    }
+
+    Ok(Some(skip_stripe_mask))
+}
+
+/// A stripe together with the rows of it that should be output.
+struct StripeBatch {
+    stripe_idx: usize,
+    /// Row position of the first row of the stripe within the file.
+    row_offset: usize,
+    /// (offset, len) within the stripe.
+    slice: (usize, usize),
+    seq: MorselSeq,
+}
+
+struct StripeDecoder {
+    bytes: Buffer<u8>,
+    metadata: Arc<OrcFileMetadata>,
+    column_projections: Arc<[OrcColumnProjection]>,
+    source_names: Arc<[PlSmallStr]>,
+    row_index: Option<RowIndex>,
+    stripe_rx: distributor_channel::Receiver<StripeBatch>,
+    morsel_tx: FileReaderOutputSend,
+}
+
+impl StripeDecoder {
+    async fn run(self) -> ApolloResult<()> {
+        let StripeDecoder {
+            bytes,
+            metadata,
+            column_projections,
+            source_names,
+            row_index,
+            mut stripe_rx,
+            mut morsel_tx,
+        } = self;
+
+        // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+        let source_token = SourceToken::new();
+
+        while let Ok(StripeBatch {
+            stripe_idx,
+            row_offset,
+            slice: (offset, len),
+            seq,
+        }) = stripe_rx.recv().await
+        {
+            // @NOTE: This empty schema code path is relied upon for `select(pl.len())`
+            let mut df = if column_projections.is_empty() {
+                DataFrame::empty_with_height(len)
+            } else {
+                let decoded = metadata
+                    .decode_stripe(&bytes, stripe_idx, &source_names)?
+                    .slice(i64::try_from(offset).unwrap(), len);
+
+                let columns = column_projections
+                    .iter()
+                    .zip(decoded.into_columns())
+                    .map(|(projection, column)| projection.apply_transform(column))
+                    .collect::<ApolloResult<Vec<_>>>()?;
+
+                DataFrame::new(len, columns)?
+            };
+
+            if let Some(RowIndex { name, offset: ri_offset }) = &row_index {
+                let position = IdxSize::try_from(row_offset + offset).map_err(|_| {
+                    apollo_err!(bigidx, ctx = "orc file", size = row_offset + offset)
+                })?;
+                df = df.with_row_index(name.clone(), Some(*ri_offset + position))?;
+            }
+
+            if morsel_tx
+                .send_morsel(Morsel::new(df, seq, source_token.clone()))
+                .await
+                .is_err()
+            {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -46,7 +46,8 @@ impl FileReaderBuilder for ParquetReaderBuilder {
             | RC::PRE_SLICE
             | RC::NEGATIVE_PRE_SLICE
             | RC::PARTIAL_FILTER
-            | RC::MAPPED_COLUMN_PROJECTION;
+            | RC::MAPPED_COLUMN_PROJECTION
+            | RC::RUNTIME_FILTER;
 
         if matches!(
             self.options.parallel,
@@ -40,6 +40,7 @@ impl ParquetReadImpl {
 
         let row_group_prefetch_size = self.config.row_group_prefetch_size;
         let predicate = self.predicate.clone();
+        let runtime_filter = self.runtime_filter.clone();
         let memory_prefetch_func = self.memory_prefetch_func;
 
         let row_group_decoder = self.init_row_group_decoder();
@@ -137,6 +138,7 @@ impl ParquetReadImpl {
                 projection: projected_arrow_fields.clone(),
                 is_full_projection,
                 predicate,
+                runtime_filter: runtime_filter.clone(),
                 slice_range,
                 memory_prefetch_func,
                 metadata,
@@ -174,8 +176,15 @@ impl ParquetReadImpl {
             while let Some((prefetch_task, permit)) = prefetch_recv.recv().await {
                 let row_group_data = prefetch_task.await.unwrap()?;
                 let row_group_decoder = row_group_decoder.clone();
+                let runtime_filter = runtime_filter.clone();
                 let decode_fut = async_executor::spawn(TaskPriority::High, async move {
-                    row_group_decoder.row_group_data_to_df(row_group_data).await
+                    let df = row_group_decoder
+                        .row_group_data_to_df(row_group_data)
+                        .await?;
+                    match runtime_filter.as_ref().and_then(|slot| slot.get()) {
+                        Some(filter) => filter.filter_df(df),
+                        None => Ok(df),
+                    }
                 });
                 if decode_send.send((decode_fut, permit)).await.is_err() {
                     break;
@@ -6,8 +6,11 @@ use crate::metrics::OptIOMetrics;
 
 /// Read the metadata bytes of a parquet file, does not decode the bytes. If during metadata fetch
 /// the bytes of the entire file are loaded, it is returned in the second return value.
+///
+/// `known_file_size` avoids a request for the size of a remote file if it is already known.
 pub async fn read_parquet_metadata_bytes(
     byte_source: &DynByteSource,
+    known_file_size: Option<usize>,
     verbose: bool,
     io_metrics: &OptIOMetrics,
 ) -> ApolloResult<(Buffer<u8>, Option<Buffer<u8>>)> {
@@ -16,9 +19,14 @@ pub async fn read_parquet_metadata_bytes(
 
     const FOOTER_HEADER_SIZE: usize = apollo_parquet::parquet::FOOTER_SIZE as usize;
 
-    let file_size = io_metrics
-        .record_download(1, byte_source.get_size())
-        .await?;
+    let file_size = match known_file_size {
+        Some(file_size) if matches!(byte_source, DynByteSource::Cloud(_)) => file_size,
+        _ => {
+            io_metrics
+                .record_download(1, byte_source.get_size())
+                .await?
+        },
+    };
 
     if file_size < FOOTER_HEADER_SIZE {
         return Err(ParquetError::OutOfSpec(format!(
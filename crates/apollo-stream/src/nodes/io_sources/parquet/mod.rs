@@ -6,12 +6,13 @@ use apollo_core::prelude::ArrowSchema;
 use apollo_core::schema::{Schema, SchemaExt, SchemaRef};
 use apollo_error::{ApolloResult, apollo_err};
 use apollo_io::cloud::CloudOptions;
+use apollo_io::parquet::metadata_cache;
 use apollo_io::predicates::ScanIOPredicate;
 use apollo_io::prelude::{FileMetadata, ParquetOptions};
 use apollo_io::utils::byte_source::{BufferByteSource, DynByteSource, DynByteSourceBuilder};
 use apollo_io::{RowIndex, pl_async};
 use apollo_parquet::read::schema::infer_schema_with_options;
-use apollo_plan::dsl::ScanSource;
+use apollo_plan::dsl::{ScanSource, ScanSourceRef};
 use apollo_utils::IdxSize;
 use apollo_utils::mem::prefetch::get_memory_prefetch_func;
 use apollo_utils::slice_enum::Slice;
@@ -28,6 +29,7 @@ use crate::nodes::compute_node_prelude::*;
 use crate::nodes::io_sources::parquet::projection::{
     ArrowFieldProjection, resolve_arrow_field_projections,
 };
+use crate::nodes::joins::runtime_filter::RuntimeFilterSlot;
 use crate::nodes::{TaskPriority, io_sources};
 use crate::utils::tokio_handle_ext;
 
@@ -98,17 +100,46 @@ impl FileReader for ParquetFileReader {
 
         let mut byte_source = Arc::new(byte_source);
 
-        let file_metadata = if let Some(v) = self.metadata.clone() {
+        // Version of the file if its metadata can be cached.
+        let cache_key = match self.scan_source.as_scan_source_ref() {
+            ScanSourceRef::Path(path)
+                if self.metadata.is_none() && metadata_cache::metadata_cache_enabled() =>
+            {
+                let path = path.clone();
+                let cloud_options = self.cloud_options.clone();
+
+                let version = pl_async::get_runtime()
+                    .spawn({
+                        let path = path.clone();
+                        async move {
+                            metadata_cache::file_version(&path, cloud_options.as_deref()).await
+                        }
+                    })
+                    .await
+                    .unwrap()?;
+
+                Some((path, version))
+            },
+            _ => None,
+        };
+
+        let cached_metadata = cache_key
+            .as_ref()
+            .and_then(|(path, version)| metadata_cache::get(path, version));
+
+        let file_metadata = if let Some(v) = self.metadata.clone().or(cached_metadata) {
             v
         } else {
             let (metadata_bytes, opt_full_bytes) = {
                 let byte_source = byte_source.clone();
+                let known_file_size = cache_key.as_ref().map(|(_, version)| version.size);
                 let io_metrics = self.io_metrics.clone();
 
                 pl_async::get_runtime()
                     .spawn(async move {
                         metadata_utils::read_parquet_metadata_bytes(
                             &byte_source,
+                            known_file_size,
                             verbose,
                             &io_metrics,
                         )
@@ -122,10 +153,16 @@ impl FileReader for ParquetFileReader {
                 byte_source = Arc::new(DynByteSource::Buffer(BufferByteSource(full_bytes)));
             }
 
-            Arc::new(apollo_parquet::parquet::read::deserialize_metadata(
+            let file_metadata = Arc::new(apollo_parquet::parquet::read::deserialize_metadata(
                 metadata_bytes.as_ref(),
                 metadata_bytes.len() * 2 + 1024,
-            )?)
+            )?);
+
+            if let Some((path, version)) = cache_key {
+                metadata_cache::insert(path, version, file_metadata.clone(), metadata_bytes.len());
+            }
+
+            file_metadata
         };
 
         let file_schema = Arc::new(infer_schema_with_options(&file_metadata, &None)?);
@@ -177,6 +214,7 @@ impl FileReader for ParquetFileReader {
             row_index: None,
             pre_slice,
             predicate: None,
+            runtime_filter: _,
             cast_columns_policy: _,
             num_pipelines: _,
             disable_morsel_split: true,
@@ -205,6 +243,7 @@ impl FileReader for ParquetFileReader {
             row_index,
             pre_slice: pre_slice_arg,
             predicate,
+            runtime_filter,
             cast_columns_policy,
             num_pipelines,
             disable_morsel_split,
@@ -326,6 +365,7 @@ impl FileReader for ParquetFileReader {
             projected_arrow_fields,
             is_full_projection,
             predicate,
+            runtime_filter,
             // TODO: Refactor to avoid full clone
             options: Arc::unwrap_or_clone(self.config.clone()),
             byte_source,
@@ -426,6 +466,7 @@ struct ParquetReadImpl {
     projected_arrow_fields: Arc<[ArrowFieldProjection]>,
     is_full_projection: bool,
     predicate: Option<ScanIOPredicate>,
+    runtime_filter: Option<Arc<RuntimeFilterSlot>>,
     options: ParquetOptions,
     byte_source: Arc<DynByteSource>,
     normalized_pre_slice: Option<(usize, usize)>,
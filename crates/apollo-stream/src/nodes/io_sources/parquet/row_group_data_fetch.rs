@@ -14,6 +14,8 @@ use apollo_utils::pl_str::PlSmallStr;
 
 use crate::metrics::OptIOMetrics;
 use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
+use crate::nodes::io_sources::parquet::statistics::runtime_filter_skips_row_group;
+use crate::nodes::joins::runtime_filter::RuntimeFilterSlot;
 use crate::utils::tokio_handle_ext;
 
 /// Represents byte-data that can be transformed into a DataFrame after some computation.
@@ -30,6 +32,7 @@ pub(super) struct RowGroupDataFetcher {
     pub(super) is_full_projection: bool,
     #[allow(unused)] // TODO: Fix!
     pub(super) predicate: Option<ScanIOPredicate>,
+    pub(super) runtime_filter: Option<Arc<RuntimeFilterSlot>>,
     pub(super) slice_range: Option<Range<usize>>,
     pub(super) memory_prefetch_func: fn(&[u8]) -> (),
     pub(super) metadata: Arc<FileMetadata>,
@@ -79,6 +82,12 @@ impl RowGroupDataFetcher {
                 }
             }
 
+            if let Some(filter) = self.runtime_filter.as_ref().and_then(|slot| slot.get())
+                && runtime_filter_skips_row_group(filter, row_group_metadata, &self.projection)
+            {
+                continue;
+            }
+
             let metadata = self.metadata.clone();
             let current_byte_source = self.byte_source.clone();
             let io_metrics = self.io_metrics.clone();
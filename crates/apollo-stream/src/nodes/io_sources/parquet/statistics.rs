@@ -13,6 +13,7 @@ use apollo_utils::format_pl_smallstr;
 
 use crate::async_executor::{self, TaskPriority};
 use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
+use crate::nodes::joins::runtime_filter::RuntimeJoinFilter;
 
 struct StatisticsColumns {
     min: Column,
@@ -169,6 +170,27 @@ pub(super) async fn calculate_row_group_pred_pushdown_skip_mask(
     Ok(Some(skip_row_group_mask))
 }
 
+/// Whether the statistics of a row group show that none of its rows can pass `filter`.
+pub(super) fn runtime_filter_skips_row_group(
+    filter: &RuntimeJoinFilter,
+    row_group: &RowGroupMetadata,
+    projected_arrow_fields: &[ArrowFieldProjection],
+) -> bool {
+    let excludes = |projection: &ArrowFieldProjection| -> ApolloResult<bool> {
+        let statistics =
+            load_parquet_column_statistics(std::slice::from_ref(row_group), projection)?;
+        let min = projection.apply_transform(statistics.min)?;
+        let max = projection.apply_transform(statistics.max)?;
+        let min = Scalar::new(min.dtype().clone(), min.get(0)?.into_static());
+        let max = Scalar::new(max.dtype().clone(), max.get(0)?.into_static());
+        Ok(filter.excludes_range(projection.output_name(), &min, &max))
+    };
+
+    projected_arrow_fields.iter().any(|projection| {
+        filter.has_column(projection.output_name()) && excludes(projection).unwrap_or(false)
+    })
+}
+
 fn load_parquet_column_statistics(
     row_groups: &[RowGroupMetadata],
     projection: &ArrowFieldProjection,
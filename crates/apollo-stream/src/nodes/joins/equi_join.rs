@@ -30,6 +30,15 @@ use crate::expression::StreamExpr;
 use crate::morsel::{SourceToken, get_ideal_morsel_size};
 use crate::nodes::compute_node_prelude::*;
 use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::nodes::joins::runtime_filter::{RuntimeFilterBuilder, RuntimeFilterSlot};
+
+/// A runtime filter to publish for the scan feeding one of the join inputs, used once the other
+/// input has been chosen as the build side.
+pub struct RuntimeFilterTarget {
+    pub slot: Arc<RuntimeFilterSlot>,
+    /// Names and types of the key columns of the filtered input, in join key order.
+    pub key_columns: Vec<(PlSmallStr, DataType)>,
+}
 
 struct EquiJoinParams {
     left_is_build: Option<bool>,
@@ -46,9 +55,17 @@ struct EquiJoinParams {
     right_payload_schema: Arc<Schema>,
     args: JoinArgs,
     random_state: PlRandomState,
+    /// Runtime filters for the left and right input respectively.
+    runtime_filters: [Option<RuntimeFilterTarget>; 2],
 }
 
 impl EquiJoinParams {
+    /// The runtime filter to build for the probe side, if any.
+    fn probe_runtime_filter(&self) -> Option<&RuntimeFilterTarget> {
+        let probe_idx = if self.left_is_build.unwrap() { 1 } else { 0 };
+        self.runtime_filters[probe_idx].as_ref()
+    }
+
     /// Should we emit unmatched rows from the build side?
     fn emit_unmatched_build(&self) -> bool {
         if self.left_is_build.unwrap() {
@@ -168,17 +185,27 @@ fn select_schema(schema: &Schema, selector: &[Option<PlSmallStr>]) -> Schema {
         .collect()
 }
 
-async fn select_keys(
+async fn evaluate_keys(
     df: &DataFrame,
     key_selectors: &[StreamExpr],
-    params: &EquiJoinParams,
     state: &ExecutionState,
-) -> ApolloResult<HashKeys> {
+) -> ApolloResult<DataFrame> {
     let mut key_columns = Vec::new();
     for selector in key_selectors {
-        key_columns.push(selector.evaluate(df, state).await?.into_column());
+        let key = selector.evaluate(df, state).await?.into_column();
+        // Extension types may define their own equality.
+        key_columns.push(key.to_comparison_key()?);
     }
-    let keys = unsafe { DataFrame::new_unchecked_with_broadcast(df.height(), key_columns)? };
+    unsafe { DataFrame::new_unchecked_with_broadcast(df.height(), key_columns) }
+}
+
+async fn select_keys(
+    df: &DataFrame,
+    key_selectors: &[StreamExpr],
+    params: &EquiJoinParams,
+    state: &ExecutionState,
+) -> ApolloResult<HashKeys> {
+    let keys = evaluate_keys(df, key_selectors, state).await?;
     Ok(HashKeys::from_df(
         &keys,
         params.random_state.clone(),
@@ -424,6 +451,9 @@ struct LocalBuilder {
     // let stop = morsel_idxs_offsets[(i + 1) * num_partitions + p];
     morsel_idxs_values_per_p: Vec<Vec<IdxSize>>,
     morsel_idxs_offsets_per_p: Vec<usize>,
+
+    // The keys seen by this builder, if a runtime filter is built for the probe side.
+    runtime_filter: Option<RuntimeFilterBuilder>,
 }
 
 struct BuildState {
@@ -443,6 +473,7 @@ impl BuildState {
                 sketch_per_p: vec![CardinalitySketch::default(); num_partitions],
                 morsel_idxs_values_per_p: vec![Vec::new(); num_partitions],
                 morsel_idxs_offsets_per_p: vec![0; num_partitions],
+                runtime_filter: None,
             })
             .collect();
         Self {
@@ -471,13 +502,20 @@ impl BuildState {
         while let Ok(morsel) = recv.recv().await {
             // Compute hashed keys and payload. We must rechunk the payload for
             // later gathers.
-            let hash_keys = select_keys(
-                morsel.df(),
-                key_selectors,
-                params,
-                &state.in_memory_exec_state,
-            )
-            .await?;
+            let keys =
+                evaluate_keys(morsel.df(), key_selectors, &state.in_memory_exec_state).await?;
+            if params.probe_runtime_filter().is_some() {
+                local
+                    .runtime_filter
+                    .get_or_insert_with(|| RuntimeFilterBuilder::new(key_selectors.len()))
+                    .insert(keys.columns())?;
+            }
+            let hash_keys = HashKeys::from_df(
+                &keys,
+                params.random_state.clone(),
+                params.args.nulls_equal,
+                false,
+            );
             let mut payload = select_payload(morsel.df().clone(), payload_selector);
             payload.rechunk_mut();
 
@@ -1183,6 +1221,7 @@ impl EquiJoinNode {
         left_key_selectors: Vec<StreamExpr>,
         right_key_selectors: Vec<StreamExpr>,
         args: JoinArgs,
+        runtime_filters: [Option<RuntimeFilterTarget>; 2],
         num_pipelines: usize,
     ) -> ApolloResult<Self> {
         let left_is_build = match args.maintain_order {
@@ -1263,6 +1302,7 @@ impl EquiJoinNode {
                 right_payload_schema,
                 args,
                 random_state: PlRandomState::default(),
+                runtime_filters,
             },
             table: new_idx_table(unique_key_schema),
         })
@@ -1306,6 +1346,23 @@ impl ComputeNode for EquiJoinNode {
         // If we are building and the build input is done, transition to probing.
         if let EquiJoinState::Build(build_state) = &mut self.state {
             if recv[build_idx] == PortState::Done {
+                if let Some(target) = self.params.probe_runtime_filter() {
+                    let builders = build_state
+                        .local_builders
+                        .iter_mut()
+                        .filter_map(|local| local.runtime_filter.take())
+                        .collect();
+                    let filter = RuntimeFilterBuilder::finish(
+                        builders,
+                        &target.key_columns,
+                        self.params.args.nulls_equal,
+                    );
+                    if config::verbose() {
+                        eprintln!("publishing runtime join filter: {filter:?}");
+                    }
+                    target.slot.publish(filter);
+                }
+
                 let probe_state = if self.params.preserve_order_build {
                     build_state.finalize_ordered(&self.params, &*self.table)
                 } else {
@@ -15,6 +15,7 @@ pub mod cross_join;
 pub mod equi_join;
 pub mod in_memory;
 pub mod merge_join;
+pub mod runtime_filter;
 #[cfg(feature = "semi_anti_join")]
 pub mod semi_anti_join;
 
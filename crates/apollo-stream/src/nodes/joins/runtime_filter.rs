@@ -0,0 +1,353 @@
+//! Runtime filters for the probe side of equi-joins.
+//!
+//! Once the build side of an equi-join is complete, every key that can possibly find a match is
+//! known. The join summarizes these keys in a [`RuntimeJoinFilter`] - a min/max range plus either
+//! the exact set of key hashes or a bloom filter - and publishes it through a
+//! [`RuntimeFilterSlot`] shared with the scan that feeds its probe side. The scan can then skip
+//! row groups and rows that can't match before they ever reach the join.
+//!
+//! A runtime filter never rejects a row that could match, but it may accept rows that can't.
+use std::cmp::Ordering;
+use std::sync::{Arc, LazyLock, OnceLock};
+
+use apollo_core::prelude::*;
+use apollo_utils::aliases::PlSeedableRandomStateQuality;
+
+/// Build sides with at most this many distinct key hashes are summarized exactly.
+const EXACT_FILTER_LIMIT: usize = 1 << 12;
+/// Number of bits per key in the bloom filter, this gives a false positive rate of about 1%.
+const BLOOM_BITS_PER_KEY: usize = 10;
+const BLOOM_NUM_PROBES: u64 = 7;
+
+/// Build sides with more rows than this only get a min/max filter.
+static RUNTIME_FILTER_MAX_BUILD_ROWS: LazyLock<usize> = LazyLock::new(|| {
+    std::env::var("APOLLO_RUNTIME_FILTER_MAX_BUILD_ROWS")
+        .map(|limit| limit.parse().unwrap())
+        .unwrap_or(16_000_000)
+});
+
+/// Whether keys of this type can be summarized by a runtime filter.
+pub fn supports_runtime_filter(dtype: &DataType) -> bool {
+    // Extension types may define their own equality, which a filter on the
+    // scanned values can't respect.
+    if dtype.is_categorical() || dtype.is_enum() || dtype.is_extension() {
+        return false;
+    }
+    let physical = dtype.to_physical();
+    physical.is_integer() || matches!(physical, DataType::String | DataType::Binary)
+}
+
+/// Shared between a join and the scan feeding its probe side. The join publishes its filter at
+/// most once; the scan picks it up for whatever data it hasn't read yet.
+#[derive(Default)]
+pub struct RuntimeFilterSlot {
+    filter: OnceLock<Arc<RuntimeJoinFilter>>,
+}
+
+impl RuntimeFilterSlot {
+    pub fn publish(&self, filter: RuntimeJoinFilter) {
+        _ = self.filter.set(Arc::new(filter));
+    }
+
+    pub fn get(&self) -> Option<&Arc<RuntimeJoinFilter>> {
+        self.filter.get()
+    }
+}
+
+impl std::fmt::Debug for RuntimeFilterSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RuntimeFilterSlot")
+            .field("published", &self.filter.get().is_some())
+            .finish()
+    }
+}
+
+struct BloomFilter {
+    bits: Vec<u64>,
+    mask: u64,
+}
+
+impl BloomFilter {
+    fn new(num_keys: usize) -> Self {
+        let num_bits = (num_keys * BLOOM_BITS_PER_KEY).next_power_of_two().max(64);
+        Self {
+            bits: vec![0; num_bits / 64],
+            mask: num_bits as u64 - 1,
+        }
+    }
+
+    fn probes(mask: u64, hash: u64) -> impl Iterator<Item = usize> {
+        let h2 = hash.rotate_left(32) | 1;
+        (0..BLOOM_NUM_PROBES).map(move |i| (hash.wrapping_add(i.wrapping_mul(h2)) & mask) as usize)
+    }
+
+    fn insert(&mut self, hash: u64) {
+        for bit in Self::probes(self.mask, hash) {
+            self.bits[bit / 64] |= 1 << (bit % 64);
+        }
+    }
+
+    fn contains(&self, hash: u64) -> bool {
+        Self::probes(self.mask, hash).all(|bit| self.bits[bit / 64] & (1 << (bit % 64)) != 0)
+    }
+}
+
+enum KeyValues {
+    Exact(PlHashSet<u64>),
+    Bloom(BloomFilter),
+    Any,
+}
+
+impl KeyValues {
+    fn contains(&self, hash: u64) -> bool {
+        match self {
+            Self::Exact(set) => set.contains(&hash),
+            Self::Bloom(bloom) => bloom.contains(hash),
+            Self::Any => true,
+        }
+    }
+}
+
+struct KeyFilter {
+    /// Name of the key column on the probe side.
+    column: PlSmallStr,
+    dtype: DataType,
+    /// The range of the non-null build keys, `None` if there are none.
+    range: Option<(Scalar, Scalar)>,
+    values: KeyValues,
+    keep_nulls: bool,
+}
+
+impl KeyFilter {
+    fn mask(&self, s: &Series) -> ApolloResult<BooleanChunked> {
+        let in_range = match &self.range {
+            None => BooleanChunked::full(PlSmallStr::EMPTY, false, s.len()),
+            Some((min, max)) => {
+                let min = min.clone().into_series(PlSmallStr::EMPTY);
+                let max = max.clone().into_series(PlSmallStr::EMPTY);
+                s.gt_eq(&min)? & s.lt_eq(&max)?
+            },
+        };
+
+        let mut hashes = Vec::new();
+        if !matches!(self.values, KeyValues::Any) {
+            s.to_physical_repr()
+                .vec_hash(PlSeedableRandomStateQuality::fixed(), &mut hashes)?;
+        }
+
+        Ok(in_range
+            .iter()
+            .enumerate()
+            .map(|(i, in_range)| match in_range {
+                None => self.keep_nulls,
+                Some(in_range) => {
+                    in_range && hashes.get(i).is_none_or(|h| self.values.contains(*h))
+                },
+            })
+            .collect())
+    }
+
+    /// Whether no row of a batch whose non-null values lie in `[min, max]` can pass this filter.
+    fn excludes_range(&self, min: &AnyValue, max: &AnyValue) -> bool {
+        if self.keep_nulls {
+            return false;
+        }
+        let Some((lo, hi)) = &self.range else {
+            return true;
+        };
+        max.partial_cmp(lo.value()) == Some(Ordering::Less)
+            || min.partial_cmp(hi.value()) == Some(Ordering::Greater)
+    }
+}
+
+/// Summary of the keys on the build side of an equi-join.
+pub struct RuntimeJoinFilter {
+    keys: Vec<KeyFilter>,
+}
+
+impl RuntimeJoinFilter {
+    /// Removes the rows of `df` that can't match. Key columns missing from `df` or of a different
+    /// type are ignored.
+    pub fn filter_df(&self, df: DataFrame) -> ApolloResult<DataFrame> {
+        let mut mask: Option<BooleanChunked> = None;
+        for key in &self.keys {
+            let Some(column) = df.column(&key.column).ok() else {
+                continue;
+            };
+            if column.dtype() != &key.dtype {
+                continue;
+            }
+            let key_mask = key.mask(column.as_materialized_series())?;
+            mask = Some(match mask {
+                None => key_mask,
+                Some(mask) => mask & key_mask,
+            });
+        }
+
+        match mask {
+            Some(mask) if mask.num_trues() < df.height() => df.filter(&mask),
+            _ => Ok(df),
+        }
+    }
+
+    pub fn has_column(&self, column: &str) -> bool {
+        self.keys.iter().any(|key| key.column == column)
+    }
+
+    /// Whether a batch in which `column` lies within `[min, max]` can be skipped entirely. The
+    /// bounds must have the type of the column.
+    pub fn excludes_range(&self, column: &str, min: &Scalar, max: &Scalar) -> bool {
+        if min.is_null() || max.is_null() {
+            return false;
+        }
+        self.keys.iter().any(|key| {
+            key.column == column
+                && &key.dtype == min.dtype()
+                && &key.dtype == max.dtype()
+                && key.excludes_range(min.value(), max.value())
+        })
+    }
+}
+
+impl std::fmt::Debug for RuntimeJoinFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut list = f.debug_list();
+        for key in &self.keys {
+            let values = match &key.values {
+                KeyValues::Exact(set) => format!("exact({})", set.len()),
+                KeyValues::Bloom(bloom) => format!("bloom({} bits)", bloom.bits.len() * 64),
+                KeyValues::Any => "any".to_string(),
+            };
+            list.entry(&format_args!(
+                "{}: range: {:?}, values: {values}, keep_nulls: {}",
+                key.column,
+                key.range
+                    .as_ref()
+                    .map(|(min, max)| (min.value(), max.value())),
+                key.keep_nulls
+            ));
+        }
+        list.finish()
+    }
+}
+
+#[derive(Default, Clone)]
+struct KeyFilterBuilder {
+    min: Option<Scalar>,
+    max: Option<Scalar>,
+    has_null: bool,
+    hashes: Vec<u64>,
+}
+
+impl KeyFilterBuilder {
+    fn update_bound(bound: &mut Option<Scalar>, value: Scalar, keep: Ordering) {
+        if value.is_null() {
+            return;
+        }
+        match bound {
+            Some(b) if value.value().partial_cmp(b.value()) != Some(keep) => {},
+            _ => *bound = Some(value),
+        }
+    }
+
+    fn insert(&mut self, s: &Series, with_hashes: bool) -> ApolloResult<()> {
+        self.has_null |= s.has_nulls();
+        Self::update_bound(&mut self.min, s.min_reduce()?, Ordering::Less);
+        Self::update_bound(&mut self.max, s.max_reduce()?, Ordering::Greater);
+        if with_hashes {
+            let mut hashes = Vec::new();
+            s.to_physical_repr()
+                .vec_hash(PlSeedableRandomStateQuality::fixed(), &mut hashes)?;
+            self.hashes.extend(hashes);
+        }
+        Ok(())
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.has_null |= other.has_null;
+        if let Some(min) = other.min {
+            Self::update_bound(&mut self.min, min, Ordering::Less);
+        }
+        if let Some(max) = other.max {
+            Self::update_bound(&mut self.max, max, Ordering::Greater);
+        }
+        self.hashes.extend(other.hashes);
+    }
+}
+
+/// Collects the keys seen by a single build-side pipeline.
+#[derive(Default, Clone)]
+pub struct RuntimeFilterBuilder {
+    keys: Vec<KeyFilterBuilder>,
+    num_rows: usize,
+}
+
+impl RuntimeFilterBuilder {
+    pub fn new(num_keys: usize) -> Self {
+        Self {
+            keys: vec![KeyFilterBuilder::default(); num_keys],
+            num_rows: 0,
+        }
+    }
+
+    pub fn insert(&mut self, key_columns: &[Column]) -> ApolloResult<()> {
+        self.num_rows += key_columns.first().map_or(0, |c| c.len());
+        let with_hashes = self.num_rows <= *RUNTIME_FILTER_MAX_BUILD_ROWS;
+        for (builder, column) in self.keys.iter_mut().zip(key_columns) {
+            builder.insert(column.as_materialized_series(), with_hashes)?;
+            if !with_hashes {
+                builder.hashes = Vec::new();
+            }
+        }
+        Ok(())
+    }
+
+    /// Combines the keys of all build-side pipelines into a filter on the probe-side columns
+    /// `probe_columns`.
+    pub fn finish(
+        builders: Vec<Self>,
+        probe_columns: &[(PlSmallStr, DataType)],
+        nulls_equal: bool,
+    ) -> RuntimeJoinFilter {
+        let num_rows: usize = builders.iter().map(|b| b.num_rows).sum();
+        let with_hashes = num_rows <= *RUNTIME_FILTER_MAX_BUILD_ROWS;
+
+        let mut merged = vec![KeyFilterBuilder::default(); probe_columns.len()];
+        for builder in builders {
+            for (m, key) in merged.iter_mut().zip(builder.keys) {
+                m.merge(key);
+            }
+        }
+
+        let keys = merged
+            .into_iter()
+            .zip(probe_columns)
+            .map(|(builder, (column, dtype))| {
+                let values = if !with_hashes {
+                    KeyValues::Any
+                } else {
+                    let set: PlHashSet<u64> = builder.hashes.into_iter().collect();
+                    if set.len() <= EXACT_FILTER_LIMIT {
+                        KeyValues::Exact(set)
+                    } else {
+                        let mut bloom = BloomFilter::new(set.len());
+                        for h in set {
+                            bloom.insert(h);
+                        }
+                        KeyValues::Bloom(bloom)
+                    }
+                };
+
+                KeyFilter {
+                    column: column.clone(),
+                    dtype: dtype.clone(),
+                    range: builder.min.zip(builder.max),
+                    values,
+                    keep_nulls: nulls_equal && builder.has_null,
+                }
+            })
+            .collect();
+
+        RuntimeJoinFilter { keys }
+    }
+}
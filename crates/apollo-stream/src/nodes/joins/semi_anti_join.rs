@@ -25,7 +25,9 @@ async fn select_keys(
 ) -> ApolloResult<HashKeys> {
     let mut key_columns = Vec::new();
     for selector in key_selectors {
-        key_columns.push(selector.evaluate(df, state).await?.into_column());
+        let key = selector.evaluate(df, state).await?.into_column();
+        // Extension types may define their own equality.
+        key_columns.push(key.to_comparison_key()?);
     }
     let keys = unsafe { DataFrame::new_unchecked_with_broadcast(df.height(), key_columns) }?;
     Ok(HashKeys::from_df(
@@ -0,0 +1,110 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use parking_lot::Mutex;
+use apollo_core::schema::Schema;
+use apollo_core::utils::accumulate_dataframes_vertical_unchecked;
+use apollo_plan::plans::ResultCacheStore;
+
+use super::compute_node_prelude::*;
+use crate::utils::in_memory_linearize::linearize;
+
+/// Passes its input through unchanged and stores it in the result cache once the input is
+/// exhausted.
+///
+/// Nothing is stored if the output is not fully consumed, e.g. below a slice, or if the result
+/// grows larger than the cache.
+pub struct ResultCacheStoreNode {
+    store: ResultCacheStore,
+    schema: Arc<Schema>,
+    max_size: usize,
+    size: AtomicUsize,
+    /// Set if the output stopped being consumed before the input was exhausted.
+    stopped_early: AtomicBool,
+    morsels_per_pipe: Mutex<Vec<Vec<(MorselSeq, DataFrame)>>>,
+    finished: bool,
+}
+
+impl ResultCacheStoreNode {
+    pub fn new(store: ResultCacheStore, schema: Arc<Schema>) -> Self {
+        Self {
+            max_size: store.max_size(),
+            store,
+            schema,
+            size: AtomicUsize::new(0),
+            stopped_early: AtomicBool::new(false),
+            morsels_per_pipe: Mutex::default(),
+            finished: false,
+        }
+    }
+}
+
+impl ComputeNode for ResultCacheStoreNode {
+    fn name(&self) -> &str {
+        "result-cache-store"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> ApolloResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        let input_done = recv[0] == PortState::Done;
+        if !self.finished && (input_done || send[0] == PortState::Done) {
+            self.finished = true;
+            let morsels_per_pipe = core::mem::take(self.morsels_per_pipe.get_mut());
+            let complete = input_done && !*self.stopped_early.get_mut();
+            if complete && *self.size.get_mut() <= self.max_size {
+                let dataframes = linearize(morsels_per_pipe);
+                let df = if dataframes.is_empty() {
+                    DataFrame::empty_with_schema(&self.schema)
+                } else {
+                    accumulate_dataframes_vertical_unchecked(dataframes)
+                };
+                self.store.store(df);
+            }
+        }
+
+        recv.swap_with_slice(send);
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<ApolloResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+        let receivers = recv_ports[0].take().unwrap().parallel();
+        let senders = send_ports[0].take().unwrap().parallel();
+
+        for (mut recv, mut send) in receivers.into_iter().zip(senders) {
+            let slf = &*self;
+            join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                let mut morsels = Vec::new();
+                while let Ok(morsel) = recv.recv().await {
+                    let size = morsel.df().estimated_size();
+                    let total = slf.size.fetch_add(size, Ordering::Relaxed) + size;
+                    if total <= slf.max_size {
+                        morsels.push((morsel.seq(), morsel.df().clone()));
+                    } else {
+                        morsels.clear();
+                    }
+                    if send.send(morsel).await.is_err() {
+                        slf.stopped_early.store(true, Ordering::Relaxed);
+                        break;
+                    }
+                }
+
+                slf.morsels_per_pipe.lock().push(morsels);
+                Ok(())
+            }));
+        }
+    }
+}
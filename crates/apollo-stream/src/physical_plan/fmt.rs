@@ -153,6 +153,7 @@ fn visualize_plan_rec(
     node_key: PhysNodeKey,
     phys_sm: &SlotMap<PhysNodeKey, PhysNode>,
     expr_arena: &Arena<AExpr>,
+    annotations: &SecondaryMap<PhysNodeKey, String>,
     visited: &mut SecondaryMap<PhysNodeKey, ()>,
     out: &mut Vec<String>,
 ) {
@@ -179,7 +180,7 @@ fn visualize_plan_rec(
         PhysNodeKind::PythonScan { .. } => ("python-scan".to_string(), &[][..]),
         PhysNodeKind::SinkMultiple { sinks } => {
             for sink in sinks {
-                visualize_plan_rec(*sink, phys_sm, expr_arena, visited, out);
+                visualize_plan_rec(*sink, phys_sm, expr_arena, annotations, visited, out);
             }
             return;
         },
@@ -276,6 +277,10 @@ fn visualize_plan_rec(
             FileWriteFormat::Csv(_) => ("csv-sink".to_string(), from_ref(input)),
             #[cfg(feature = "json")]
             FileWriteFormat::NDJson(_) => ("ndjson-sink".to_string(), from_ref(input)),
+            #[cfg(feature = "avro")]
+            FileWriteFormat::Avro(_) => ("avro-sink".to_string(), from_ref(input)),
+            #[cfg(feature = "orc")]
+            FileWriteFormat::Orc(_) => ("orc-sink".to_string(), from_ref(input)),
             #[allow(unreachable_patterns)]
             _ => todo!(),
         },
@@ -283,6 +288,7 @@ fn visualize_plan_rec(
             let variant = match options.partition_strategy {
                 PartitionStrategyIR::Keyed { .. } => "partition-keyed",
                 PartitionStrategyIR::FileSize => "partition-file-size",
+                PartitionStrategyIR::Bucketed { .. } => "partition-bucketed",
             };
 
             match options.file_format {
@@ -294,6 +300,10 @@ fn visualize_plan_rec(
                 FileWriteFormat::Csv(_) => (format!("{variant}[csv]"), from_ref(input)),
                 #[cfg(feature = "json")]
                 FileWriteFormat::NDJson(_) => (format!("{variant}[ndjson]"), from_ref(input)),
+                #[cfg(feature = "avro")]
+                FileWriteFormat::Avro(_) => (format!("{variant}[avro]"), from_ref(input)),
+                #[cfg(feature = "orc")]
+                FileWriteFormat::Orc(_) => (format!("{variant}[orc]"), from_ref(input)),
                 #[allow(unreachable_patterns)]
                 _ => todo!(),
             }
@@ -328,6 +338,9 @@ fn visualize_plan_rec(
             }
             (label, from_ref(input))
         },
+        PhysNodeKind::ResultCacheStore { input, store: _ } => {
+            ("result-cache-store".to_owned(), from_ref(input))
+        },
         PhysNodeKind::SortedGroupBy {
             input,
             key,
@@ -732,6 +745,11 @@ fn visualize_plan_rec(
         PhysNodeKind::EwmStd { input, options: _ } => ("ewm-std".to_string(), &[*input][..]),
     };
 
+    let label = match annotations.get(node_key) {
+        Some(annotation) => format!(r"{label}\n---\n{}", escape_graphviz(annotation)),
+        None => label,
+    };
+
     let node_id = node_key.data().as_ffi();
     let style = NodeStyle::for_node_kind(kind);
 
@@ -741,7 +759,7 @@ fn visualize_plan_rec(
         out.push(format!("{node_id} [label=\"{label}\"];"));
     }
     for input in inputs {
-        visualize_plan_rec(input.node, phys_sm, expr_arena, visited, out);
+        visualize_plan_rec(input.node, phys_sm, expr_arena, annotations, visited, out);
         out.push(format!(
             "{} -> {};",
             input.node.data().as_ffi(),
@@ -754,12 +772,37 @@ pub fn visualize_plan(
     root: PhysNodeKey,
     phys_sm: &SlotMap<PhysNodeKey, PhysNode>,
     expr_arena: &Arena<AExpr>,
+) -> String {
+    visualize_plan_annotated(root, phys_sm, expr_arena, &SecondaryMap::new(), None)
+}
+
+/// Visualizes the physical plan, appending the annotation of a node (if any) to its label. The
+/// `summary` is shown as a separate note.
+pub fn visualize_plan_annotated(
+    root: PhysNodeKey,
+    phys_sm: &SlotMap<PhysNodeKey, PhysNode>,
+    expr_arena: &Arena<AExpr>,
+    annotations: &SecondaryMap<PhysNodeKey, String>,
+    summary: Option<&str>,
 ) -> String {
     let mut visited: SecondaryMap<PhysNodeKey, ()> = SecondaryMap::new();
-    let mut out = Vec::with_capacity(phys_sm.len() + 3);
+    let mut out = Vec::with_capacity(phys_sm.len() + 4);
     out.push("digraph apollo {\nrankdir=\"BT\"\nnode [fontname=\"Monospace\"]".to_string());
     out.push(NodeStyle::legend());
-    visualize_plan_rec(root, phys_sm, expr_arena, &mut visited, &mut out);
+    if let Some(summary) = summary {
+        out.push(format!(
+            "summary [shape=note,label=\"{}\"];",
+            escape_graphviz(summary)
+        ));
+    }
+    visualize_plan_rec(
+        root,
+        phys_sm,
+        expr_arena,
+        annotations,
+        &mut visited,
+        &mut out,
+    );
     out.push("}".to_string());
     out.join("\n")
 }
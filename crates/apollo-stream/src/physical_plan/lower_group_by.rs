@@ -293,6 +293,12 @@ fn try_lower_elementwise_scalar_agg_expr(
             ..
         } => Some(replace_agg_uniq!(expr)),
 
+        #[cfg(feature = "approx_quantile")]
+        AExpr::Function {
+            function: IRFunctionExpr::ApproxQuantile { .. },
+            ..
+        } => Some(replace_agg_uniq!(expr)),
+
         AExpr::Function {
             function:
                 IRFunctionExpr::Boolean(IRBooleanFunction::Any { .. } | IRBooleanFunction::All { .. })
@@ -439,19 +445,46 @@ fn try_build_streaming_group_by(
         return Ok(None);
     }
 
+    // Extension types may define their own equality, so we group on the row encoding of their
+    // comparison key and recover the original values with a first aggregate.
+    let input_schema = phys_sm[input.node].output_schema.clone();
+    let mut key_storage = Vec::with_capacity(keys.len());
+    let mut ext_key_idxs = Vec::new();
+    let mut ext_key_aggs = Vec::new();
+    for (i, key) in keys.iter().enumerate() {
+        let dtype = key.dtype(&input_schema, expr_arena)?.clone();
+        if !dtype.is_extension() {
+            key_storage.push(key.clone());
+            continue;
+        }
+        let first_agg_node = expr_arena.add(AExpr::Agg(IRAggExpr::First(key.node())));
+        ext_key_aggs.push(ExprIR::new(
+            first_agg_node,
+            OutputName::Alias(unique_column_name()),
+        ));
+        ext_key_idxs.push(i);
+        let encoded_key = AExprBuilder::function(
+            vec![key.clone()],
+            IRFunctionExpr::RowEncode(vec![dtype], RowEncodingVariant::Unordered),
+            expr_arena,
+        )
+        .expr_ir(key.output_name().clone());
+        key_storage.push(encoded_key);
+    }
+    let keys = key_storage.as_slice();
+    let num_aggs = aggs.len();
+
     // Augment with row index if maintaining order.
     let row_idx_name = unique_column_name();
     let row_idx_node = expr_arena.add(AExpr::Column(row_idx_name.clone()));
-    let mut agg_storage;
-    let aggs = if maintain_order {
+    let mut agg_storage = aggs.to_vec();
+    agg_storage.extend(ext_key_aggs);
+    if maintain_order {
         input = build_row_idx_stream(input, row_idx_name.clone(), None, phys_sm);
         let first_agg_node = expr_arena.add(AExpr::Agg(IRAggExpr::First(row_idx_node)));
-        agg_storage = aggs.to_vec();
         agg_storage.push(ExprIR::from_node(first_agg_node, expr_arena));
-        &agg_storage
-    } else {
-        aggs
-    };
+    }
+    let aggs = agg_storage.as_slice();
 
     // Fill all expressions into the merger, letting us extract common subexpressions later.
     let mut expr_merger = NaiveExprMerger::default();
@@ -675,6 +708,16 @@ fn try_build_streaming_group_by(
         PhysStream::first(agg_node)
     };
 
+    // Output the original values of the extension keys instead of their encoded comparison keys.
+    let ext_key_start = keys.len() + num_aggs;
+    let ext_key_firsts: Vec<_> = trans_output_exprs
+        .drain(ext_key_start..ext_key_start + ext_key_idxs.len())
+        .collect();
+    for (key_idx, first) in ext_key_idxs.into_iter().zip(ext_key_firsts) {
+        let output_name = trans_output_exprs[key_idx].output_name().clone();
+        trans_output_exprs[key_idx] = ExprIR::new(first.node(), OutputName::Alias(output_name));
+    }
+
     let post_select = build_select_stream(
         post_select_input,
         &trans_output_exprs,
@@ -716,7 +759,7 @@ pub fn try_build_sorted_group_by(
         || (!are_keys_sorted && maintain_order)
         || keys.iter().any(|k| {
             k.dtype(input_schema, expr_arena)
-                .is_ok_and(|dtype| dtype.contains_unknown())
+                .is_ok_and(|dtype| dtype.contains_unknown() || dtype.is_extension())
         })
     {
         return Ok(None);
@@ -389,6 +389,11 @@ pub fn lower_ir(
                     offset,
                 },
 
+                FunctionIR::ResultCacheStore(store) => PhysNodeKind::ResultCacheStore {
+                    input: phys_input,
+                    store,
+                },
+
                 function if function.is_streamable() => {
                     let map = Arc::new(move |df| function.evaluate(df));
                     let format_str = ctx.prepare_visualization.then(|| {
@@ -663,11 +668,31 @@ pub fn lower_ir(
                         io_metrics: std::sync::OnceLock::new(),
                     }) as _,
 
+                    #[cfg(feature = "avro")]
+                    FileScanIR::Avro => {
+                        Arc::new(crate::nodes::io_sources::avro::builder::AvroReaderBuilder {})
+                            as _
+                    },
+
+                    #[cfg(feature = "orc")]
+                    FileScanIR::Orc => {
+                        Arc::new(crate::nodes::io_sources::orc::builder::OrcReaderBuilder {})
+                            as _
+                    },
+
                     #[cfg(feature = "csv")]
-                    FileScanIR::Csv { options } => Arc::new(Arc::clone(options)) as _,
+                    FileScanIR::Csv { options } => Arc::new(
+                        crate::nodes::io_sources::csv::builder::CsvReaderBuilder::try_new(
+                            Arc::clone(options),
+                        )?,
+                    ) as _,
 
                     #[cfg(feature = "json")]
-                    FileScanIR::NDJson { options } => Arc::new(options.clone()) as _,
+                    FileScanIR::NDJson { options } => Arc::new(
+                        crate::nodes::io_sources::ndjson::builder::NDJsonReaderBuilder::try_new(
+                            options.clone(),
+                        )?,
+                    ) as _,
 
                     #[cfg(feature = "python")]
                     FileScanIR::PythonDataset {
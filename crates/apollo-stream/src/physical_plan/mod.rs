@@ -15,7 +15,7 @@ use apollo_plan::dsl::{
 };
 use apollo_plan::plans::expr_ir::ExprIR;
 use apollo_plan::plans::hive::HivePartitionsDf;
-use apollo_plan::plans::{AExpr, DataFrameUdf, IR};
+use apollo_plan::plans::{AExpr, DataFrameUdf, IR, ResultCacheStore};
 
 mod fmt;
 mod io;
@@ -24,7 +24,7 @@ mod lower_group_by;
 mod lower_ir;
 mod to_graph;
 
-pub use fmt::{NodeStyle, visualize_plan};
+pub use fmt::{NodeStyle, visualize_plan, visualize_plan_annotated};
 use apollo_plan::prelude::PlanCallback;
 #[cfg(feature = "dynamic_group_by")]
 use apollo_time::DynamicGroupOptions;
@@ -219,6 +219,12 @@ pub enum PhysNodeKind {
         format_str: Option<String>,
     },
 
+    /// Passes its input through and stores it in the cross-query result cache.
+    ResultCacheStore {
+        input: PhysStream,
+        store: ResultCacheStore,
+    },
+
     SortedGroupBy {
         input: PhysStream,
         key: PlSmallStr,
@@ -453,6 +459,7 @@ fn visit_node_inputs_mut(
             | PhysNodeKind::InMemoryMap { input, .. }
             | PhysNodeKind::SortedGroupBy { input, .. }
             | PhysNodeKind::Map { input, .. }
+            | PhysNodeKind::ResultCacheStore { input, .. }
             | PhysNodeKind::Sort { input, .. }
             | PhysNodeKind::Multiplexer { input }
             | PhysNodeKind::GatherEvery { input, .. }
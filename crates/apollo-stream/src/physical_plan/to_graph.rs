@@ -12,6 +12,7 @@ use apollo_expr::reduce::into_reduction;
 use apollo_expr::state::ExecutionState;
 use apollo_mem_engine::create_physical_plan;
 use apollo_mem_engine::scan_predicate::create_scan_predicate;
+use apollo_ops::frame::JoinType;
 use apollo_plan::dsl::{
     FileSinkOptions, JoinOptionsIR, PartitionStrategyIR, PartitionedSinkOptionsIR, ScanSources,
 };
@@ -27,7 +28,7 @@ use apollo_utils::relaxed_cell::RelaxedCell;
 use recursive::recursive;
 use slotmap::{SecondaryMap, SlotMap};
 
-use super::{PhysNode, PhysNodeKey, PhysNodeKind};
+use super::{PhysNode, PhysNodeKey, PhysNodeKind, PhysStream};
 use crate::execute::StreamingExecutionState;
 use crate::expression::StreamExpr;
 use crate::graph::{Graph, GraphNodeKey};
@@ -36,7 +37,9 @@ use crate::nodes;
 use crate::nodes::io_sources::multi_scan::config::MultiScanConfig;
 use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
 use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
+use crate::nodes::joins::equi_join::RuntimeFilterTarget;
 use crate::nodes::joins::merge_join::MergeJoinNode;
+use crate::nodes::joins::runtime_filter::{RuntimeFilterSlot, supports_runtime_filter};
 use crate::physical_plan::lower_expr::compute_output_schema;
 use crate::utils::late_materialized_df::LateMaterializedDataFrame;
 
@@ -49,6 +52,61 @@ fn has_potential_recurring_entrance(node: Node, arena: &Arena<AExpr>) -> bool {
     })
 }
 
+/// Finds the scan feeding `input` into which a runtime filter on the join keys `on` can be pushed,
+/// and registers a slot for it.
+fn runtime_filter_target(
+    input: PhysStream,
+    on: &[ExprIR],
+    ctx: &mut GraphConversionContext<'_>,
+) -> Option<RuntimeFilterTarget> {
+    let input_schema = &ctx.phys_sm[input.node].output_schema;
+    let key_columns = on
+        .iter()
+        .map(|e| match ctx.expr_arena.get(e.node()) {
+            AExpr::Column(name) => {
+                let dtype = input_schema.get(name)?;
+                supports_runtime_filter(dtype).then(|| (name.clone(), dtype.clone()))
+            },
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    // Filtering and projecting columns commutes with the runtime filter. Scans with a row index or
+    // slice don't, as these depend on the rows that are read.
+    let mut node = input.node;
+    loop {
+        match &ctx.phys_sm[node].kind {
+            PhysNodeKind::Filter { input, .. } | PhysNodeKind::SimpleProjection { input, .. } => {
+                node = input.node
+            },
+            PhysNodeKind::MultiScan {
+                file_reader_builder,
+                output_schema,
+                row_index: None,
+                pre_slice: None,
+                deletion_files: None,
+                ..
+            } => {
+                if ctx.phys_to_graph.contains_key(node)
+                    || !file_reader_builder
+                        .reader_capabilities()
+                        .contains(ReaderCapabilities::RUNTIME_FILTER)
+                    || key_columns
+                        .iter()
+                        .any(|(name, dtype)| output_schema.get(name) != Some(dtype))
+                {
+                    return None;
+                }
+
+                let slot = Arc::new(RuntimeFilterSlot::default());
+                ctx.runtime_filter_slots.insert(node, slot.clone());
+                return Some(RuntimeFilterTarget { slot, key_columns });
+            },
+            _ => return None,
+        }
+    }
+}
+
 fn create_stream_expr(
     expr_ir: &ExprIR,
     ctx: &mut GraphConversionContext<'_>,
@@ -71,6 +129,8 @@ struct GraphConversionContext<'a> {
     phys_to_graph: SecondaryMap<PhysNodeKey, GraphNodeKey>,
     expr_conversion_state: ExpressionConversionState,
     num_pipelines: usize,
+    /// Runtime filters to attach to scans that haven't been converted yet.
+    runtime_filter_slots: SecondaryMap<PhysNodeKey, Arc<RuntimeFilterSlot>>,
 }
 
 pub fn physical_plan_to_graph(
@@ -87,6 +147,7 @@ pub fn physical_plan_to_graph(
         phys_to_graph: SecondaryMap::with_capacity(phys_sm.len()),
         expr_conversion_state: ExpressionConversionState::new(false),
         num_pipelines,
+        runtime_filter_slots: SecondaryMap::new(),
     };
 
     to_graph_rec(root, &mut ctx)?;
@@ -362,12 +423,15 @@ fn to_graph_rec<'a>(
                     unified_sink_args,
                     max_rows_per_file,
                     approximate_bytes_per_file,
+                    manifest_path,
                 },
         } => {
             use crate::nodes::io_sinks::IOSinkNode;
             use crate::nodes::io_sinks::components::exclude_keys_projection::ExcludeKeysProjection;
             use crate::nodes::io_sinks::components::hstack_columns::HStackColumns;
-            use crate::nodes::io_sinks::components::partitioner::{KeyedPartitioner, Partitioner};
+            use crate::nodes::io_sinks::components::partitioner::{
+                BucketedPartitioner, KeyedPartitioner, Partitioner,
+            };
             use crate::nodes::io_sinks::components::size::{
                 NonZeroRowCountAndSize, RowCountAndSize,
             };
@@ -454,6 +518,24 @@ fn to_graph_rec<'a>(
                     file_schema = input_schema.clone();
                     Partitioner::FileSize
                 },
+                PartitionStrategyIR::Bucketed {
+                    keys,
+                    num_buckets,
+                    hash,
+                } => {
+                    file_schema = input_schema.clone();
+
+                    let key_exprs = keys
+                        .iter()
+                        .map(|e| create_stream_expr(e, ctx, &input_schema))
+                        .collect::<ApolloResult<_>>()?;
+
+                    Partitioner::Bucketed(BucketedPartitioner {
+                        key_exprs,
+                        num_buckets: *num_buckets,
+                        hash: *hash,
+                    })
+                },
             };
 
             let mut file_size_limit = RowCountAndSize::MAX;
@@ -477,6 +559,7 @@ fn to_graph_rec<'a>(
                 include_keys_in_file,
                 file_schema,
                 file_size_limit,
+                manifest_path: manifest_path.clone(),
             }));
 
             let config = IOSinkNodeConfig {
@@ -515,6 +598,15 @@ fn to_graph_rec<'a>(
             )
         },
 
+        ResultCacheStore { input, store } => {
+            let input_schema = ctx.phys_sm[input.node].output_schema.clone();
+            let input_key = to_graph_rec(input.node, ctx)?;
+            ctx.graph.add_node(
+                nodes::result_cache_store::ResultCacheStoreNode::new(store.clone(), input_schema),
+                [(input_key, input.port)],
+            )
+        },
+
         SortedGroupBy {
             input,
             key,
@@ -793,6 +885,7 @@ fn to_graph_rec<'a>(
                     cast_columns_policy,
                     deletion_files,
                     table_statistics,
+                    runtime_filter: ctx.runtime_filter_slots.remove(phys_node_key),
                     // Initialized later
                     num_pipelines: RelaxedCell::new_usize(0),
                     n_readers_pre_init: RelaxedCell::new_usize(0),
@@ -1020,6 +1113,25 @@ fn to_graph_rec<'a>(
             output_bool: _,
         } => {
             let args = args.clone();
+
+            // Rows of an input may only be dropped early if its unmatched rows aren't part of the
+            // output.
+            let mut runtime_filters = [None, None];
+            if matches!(node.kind, EquiJoin { .. }) {
+                let inputs = [(input_left, left_on), (input_right, right_on)];
+                for (side, (input, on)) in inputs.into_iter().enumerate() {
+                    let filterable = match args.how {
+                        JoinType::Inner => true,
+                        JoinType::Left => side == 1,
+                        JoinType::Right => side == 0,
+                        _ => false,
+                    };
+                    if filterable {
+                        runtime_filters[side] = runtime_filter_target(*input, on, ctx);
+                    }
+                }
+            }
+
             let left_input_key = to_graph_rec(input_left.node, ctx)?;
             let right_input_key = to_graph_rec(input_right.node, ctx)?;
             let left_input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
@@ -1093,6 +1205,7 @@ fn to_graph_rec<'a>(
                         left_key_selectors,
                         right_key_selectors,
                         args,
+                        runtime_filters,
                         ctx.num_pipelines,
                     )?,
                     [
@@ -1385,6 +1498,7 @@ fn to_graph_rec<'a>(
                     cast_columns_policy,
                     deletion_files,
                     table_statistics,
+                    runtime_filter: None,
                     // Initialized later
                     num_pipelines: RelaxedCell::new_usize(0),
                     n_readers_pre_init: RelaxedCell::new_usize(0),
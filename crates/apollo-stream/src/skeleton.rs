@@ -1,5 +1,6 @@
 #![allow(unused)] // TODO: remove me
 use std::cmp::Reverse;
+use std::fmt::Write;
 use std::time::{Duration, Instant};
 
 use parking_lot::Mutex;
@@ -11,10 +12,10 @@ use apollo_plan::prelude::AExpr;
 use apollo_plan::prelude::expr_ir::ExprIR;
 use apollo_utils::arena::{Arena, Node};
 use apollo_utils::relaxed_cell::RelaxedCell;
-use slotmap::{SecondaryMap, SlotMap};
+use slotmap::{Key, SecondaryMap, SlotMap};
 
 use crate::graph::{Graph, GraphNodeKey};
-use crate::metrics::GraphMetrics;
+use crate::metrics::{GraphMetrics, PeakMemorySampler};
 use crate::physical_plan::{PhysNode, PhysNodeKey, PhysNodeKind, StreamingLowerIRContext};
 
 /// Executes the IR with the streaming engine.
@@ -55,6 +56,166 @@ pub fn visualize_physical_plan(
     Ok(out)
 }
 
+/// The outcome of running a query with [`explain_analyze`].
+pub struct QueryProfile {
+    /// The physical plan as a dot graph, with the metrics of each node added to its label.
+    pub plan: String,
+    /// A row for the whole query followed by a row per node of the physical plan. Times are in
+    /// microseconds.
+    pub metrics: DataFrame,
+    pub elapsed: Duration,
+    /// Peak resident memory of the process in bytes while the query ran, if it can be determined.
+    pub peak_memory: Option<u64>,
+}
+
+/// Enables task metric tracking, restoring the previous setting when dropped.
+struct TrackTaskMetricsGuard {
+    was_tracking: bool,
+}
+
+impl TrackTaskMetricsGuard {
+    fn enable() -> Self {
+        let was_tracking = crate::async_executor::is_tracking_task_metrics();
+        crate::async_executor::track_task_metrics(true);
+        Self { was_tracking }
+    }
+}
+
+impl Drop for TrackTaskMetricsGuard {
+    fn drop(&mut self) {
+        crate::async_executor::track_task_metrics(self.was_tracking);
+    }
+}
+
+/// Executes the IR with the streaming engine, collecting the metrics of every node in the physical
+/// plan. The results of the query are discarded.
+pub fn explain_analyze(
+    node: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> ApolloResult<QueryProfile> {
+    let mut query = StreamingQuery::build(node, ir_arena, expr_arena)?;
+    let _track_metrics = TrackTaskMetricsGuard::enable();
+    let metrics = query.metrics.get_or_insert_default().clone();
+
+    let sampler = PeakMemorySampler::start();
+    let query_start = Instant::now();
+    let result = crate::execute::execute_graph(&mut query.graph, Some(metrics.clone()));
+    let elapsed = query_start.elapsed();
+    let peak_memory = sampler.finish();
+    result?;
+
+    let us = |ns: u64| ns / 1000;
+    let mut ids = vec![None];
+    let mut names = vec!["query".to_string()];
+    let mut total_time = vec![elapsed.as_micros() as u64];
+    let mut poll_time = vec![None];
+    let mut polls = vec![None];
+    let mut update_time = vec![None];
+    let mut updates = vec![None];
+    let mut morsels_received = vec![None];
+    let mut rows_received = vec![None];
+    let mut morsels_sent = vec![None];
+    let mut rows_sent = vec![None];
+    let mut io_time = vec![None];
+    let mut io_bytes_requested = vec![None];
+    let mut io_bytes_received = vec![None];
+
+    let mut annotations = SecondaryMap::new();
+    let m = metrics.lock();
+    for (phys_node_key, graph_node_key) in query.phys_to_graph.iter() {
+        let Some(node_metrics) = m.get(*graph_node_key) else {
+            continue;
+        };
+        let total_ns = node_metrics.total_poll_time_ns + node_metrics.total_state_update_time_ns;
+
+        let mut annotation = format!(
+            "time: {:.2?} (poll: {:.2?}, update: {:.2?})\n\
+             rows: {} in, {} out\n\
+             morsels: {} in, {} out",
+            Duration::from_nanos(total_ns),
+            Duration::from_nanos(node_metrics.total_poll_time_ns),
+            Duration::from_nanos(node_metrics.total_state_update_time_ns),
+            node_metrics.rows_received,
+            node_metrics.rows_sent,
+            node_metrics.morsels_received,
+            node_metrics.morsels_sent,
+        );
+        if node_metrics.io_total_bytes_requested > 0 {
+            write!(
+                annotation,
+                "\nio: {} bytes in {:.2?}",
+                node_metrics.io_total_bytes_received,
+                Duration::from_nanos(node_metrics.io_total_active_ns)
+            )
+            .unwrap();
+        }
+        annotations.insert(phys_node_key, annotation);
+
+        ids.push(Some(phys_node_key.data().as_ffi()));
+        names.push(
+            query.graph.nodes[*graph_node_key]
+                .compute
+                .name()
+                .to_string(),
+        );
+        total_time.push(us(total_ns));
+        poll_time.push(Some(us(node_metrics.total_poll_time_ns)));
+        polls.push(Some(node_metrics.total_polls));
+        update_time.push(Some(us(node_metrics.total_state_update_time_ns)));
+        updates.push(Some(node_metrics.total_state_updates));
+        morsels_received.push(Some(node_metrics.morsels_received));
+        rows_received.push(Some(node_metrics.rows_received));
+        morsels_sent.push(Some(node_metrics.morsels_sent));
+        rows_sent.push(Some(node_metrics.rows_sent));
+        io_time.push(Some(us(node_metrics.io_total_active_ns)));
+        io_bytes_requested.push(Some(node_metrics.io_total_bytes_requested));
+        io_bytes_received.push(Some(node_metrics.io_total_bytes_received));
+    }
+    drop(m);
+
+    let mut peak_memory_bytes = vec![None; ids.len()];
+    peak_memory_bytes[0] = peak_memory;
+
+    let columns = vec![
+        Column::new("id".into(), ids),
+        Column::new("node".into(), names),
+        Column::new("total_time".into(), total_time),
+        Column::new("poll_time".into(), poll_time),
+        Column::new("polls".into(), polls),
+        Column::new("state_update_time".into(), update_time),
+        Column::new("state_updates".into(), updates),
+        Column::new("morsels_received".into(), morsels_received),
+        Column::new("rows_received".into(), rows_received),
+        Column::new("morsels_sent".into(), morsels_sent),
+        Column::new("rows_sent".into(), rows_sent),
+        Column::new("io_time".into(), io_time),
+        Column::new("io_bytes_requested".into(), io_bytes_requested),
+        Column::new("io_bytes_received".into(), io_bytes_received),
+        Column::new("peak_memory".into(), peak_memory_bytes),
+    ];
+    let metrics = DataFrame::new_infer_height(columns)?;
+
+    let summary = match peak_memory {
+        Some(peak_memory) => format!("total: {elapsed:.2?}\npeak memory: {peak_memory} bytes"),
+        None => format!("total: {elapsed:.2?}\npeak memory: unknown"),
+    };
+    let plan = crate::physical_plan::visualize_plan_annotated(
+        query.root_phys_node,
+        &query.phys_sm,
+        expr_arena,
+        &annotations,
+        Some(&summary),
+    );
+
+    Ok(QueryProfile {
+        plan,
+        metrics,
+        elapsed,
+        peak_memory,
+    })
+}
+
 pub struct StreamingQuery {
     top_ir: IR,
     pub graph: Graph,
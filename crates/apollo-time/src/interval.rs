@@ -0,0 +1,71 @@
+use std::borrow::Cow;
+
+use apollo_core::prelude::*;
+
+use crate::Duration;
+
+/// Parse a string into an interval.
+///
+/// The string is a whitespace-separated sequence of duration parts, each of
+/// which may carry its own sign, such as `"1mo 2d 3h"` or `"1y -2d"`. Verbose
+/// units are accepted too, and a number may be separated from its unit, as in
+/// `"1 year 2 months"`.
+pub fn parse_interval(s: &str) -> ApolloResult<months_days_ns> {
+    let mut tokens = s.split_whitespace();
+    let (mut months, mut days, mut ns) = (0i64, 0i64, 0i64);
+    let mut found_part = false;
+
+    while let Some(token) = tokens.next() {
+        // a bare number takes its unit from the next token, eg: "3 days"
+        let part = if token
+            .trim_start_matches(['+', '-'])
+            .bytes()
+            .all(|b| b.is_ascii_digit())
+        {
+            match tokens.next() {
+                Some(unit) => Cow::Owned(format!("{token}{unit}")),
+                None => apollo_bail!(
+                    InvalidOperation: "expected a unit to follow '{}' in interval string '{}'", token, s
+                ),
+            }
+        } else {
+            Cow::Borrowed(token)
+        };
+        // accept the formatted output of intervals and durations
+        let part = part.trim_end_matches(',').replace("µs", "us");
+        let v = Duration::try_parse_interval(&part)?.to_months_days_ns()?;
+        months += v.months() as i64;
+        days += v.days() as i64;
+        ns = ns.checked_add(v.ns()).ok_or_else(
+            || apollo_err!(InvalidOperation: "interval string '{}' is out of range", s),
+        )?;
+        found_part = true;
+    }
+    apollo_ensure!(found_part, InvalidOperation: "empty interval string");
+
+    match (i32::try_from(months), i32::try_from(days)) {
+        (Ok(months), Ok(days)) => Ok(months_days_ns::new(months, days, ns)),
+        _ => apollo_bail!(InvalidOperation: "interval string '{}' is out of range", s),
+    }
+}
+
+/// Parse every string of `ca` into an interval.
+///
+/// If `strict`, an error is raised for strings that can't be parsed, otherwise
+/// they become null.
+pub fn str_to_interval(ca: &StringChunked, strict: bool) -> ApolloResult<IntervalChunked> {
+    let out: Int128Chunked = if strict {
+        ca.iter()
+            .map(|opt_s| {
+                opt_s
+                    .map(|s| parse_interval(s).map(interval_to_i128))
+                    .transpose()
+            })
+            .collect::<ApolloResult<_>>()?
+    } else {
+        ca.iter()
+            .map(|opt_s| opt_s.and_then(|s| parse_interval(s).ok().map(interval_to_i128)))
+            .collect()
+    };
+    Ok(out.with_name(ca.name().clone()).into_interval())
+}
@@ -58,7 +58,54 @@ fn apply_offsets_to_datetime(
     }
 }
 
+/// Offset `t` by an interval, applying the months, days and nanoseconds in turn.
+///
+/// The components of an interval can have different signs, so they can't be
+/// combined into a single [`Duration`].
+#[cfg(feature = "dtype-interval")]
+fn add_interval(
+    offset_fn: fn(&Duration, i64, Option<&Tz>) -> ApolloResult<i64>,
+    interval: months_days_ns,
+    t: i64,
+    time_zone: Option<&Tz>,
+) -> ApolloResult<i64> {
+    let t = offset_fn(
+        &Duration::from_months(interval.months() as i64),
+        t,
+        time_zone,
+    )?;
+    let t = offset_fn(&Duration::from_days(interval.days() as i64), t, time_zone)?;
+    offset_fn(&Duration::from_nsecs(interval.ns()), t, time_zone)
+}
+
+#[cfg(feature = "dtype-interval")]
+fn apply_intervals_to_datetime(
+    datetime: &Logical<DatetimeType, Int64Type>,
+    offsets: &IntervalChunked,
+    time_zone: Option<&Tz>,
+) -> ApolloResult<Int64Chunked> {
+    let offset_fn = match datetime.time_unit() {
+        TimeUnit::Milliseconds => Duration::add_ms,
+        TimeUnit::Microseconds => Duration::add_us,
+        TimeUnit::Nanoseconds => Duration::add_ns,
+    };
+    broadcast_try_binary_elementwise(
+        datetime.physical(),
+        offsets.physical(),
+        |timestamp_opt, offset_opt| match (timestamp_opt, offset_opt) {
+            (Some(timestamp), Some(offset)) => {
+                add_interval(offset_fn, i128_to_interval(offset), timestamp, time_zone).map(Some)
+            },
+            _ => Ok(None),
+        },
+    )
+}
+
 pub fn impl_offset_by(ts: &Series, offsets: &Series) -> ApolloResult<Series> {
+    #[cfg(feature = "dtype-interval")]
+    if offsets.dtype().is_interval() {
+        return impl_offset_by_interval(ts, offsets.interval()?);
+    }
     let offsets = offsets.str()?;
 
     apollo_ensure!(
@@ -128,3 +175,44 @@ pub fn impl_offset_by(ts: &Series, offsets: &Series) -> ApolloResult<Series> {
         })
     }
 }
+
+/// Offset a `Date`/`Datetime` series by intervals, calendar-aware.
+#[cfg(feature = "dtype-interval")]
+fn impl_offset_by_interval(ts: &Series, offsets: &IntervalChunked) -> ApolloResult<Series> {
+    apollo_ensure!(
+        ts.len() == offsets.len() || offsets.len() == 1 || ts.len() == 1,
+        length_mismatch = "dt.offset_by",
+        ts.len(),
+        offsets.len()
+    );
+
+    let mut out = match ts.dtype() {
+        DataType::Date => {
+            let ts = ts
+                .cast(&DataType::Datetime(TimeUnit::Microseconds, None))
+                .unwrap();
+            let datetime = ts.datetime().unwrap();
+            let out = apply_intervals_to_datetime(datetime, offsets, None)?;
+            out.cast(&DataType::Datetime(TimeUnit::Microseconds, None))
+                .unwrap()
+                .cast(&DataType::Date)?
+        },
+        DataType::Datetime(tu, tz) => {
+            let datetime = ts.datetime().unwrap();
+
+            let out = match tz {
+                #[cfg(feature = "timezones")]
+                Some(tz) => {
+                    apply_intervals_to_datetime(datetime, offsets, tz.parse::<Tz>().ok().as_ref())?
+                },
+                _ => apply_intervals_to_datetime(datetime, offsets, None)?,
+            };
+            out.cast(&DataType::Datetime(*tu, tz.clone()))?
+        },
+        dt => apollo_bail!(
+            ComputeError: "cannot use 'offset_by' on Series of datatype {}", dt,
+        ),
+    };
+    out.set_sorted_flag(IsSorted::Not);
+    Ok(out)
+}
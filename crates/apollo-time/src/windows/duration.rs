@@ -15,6 +15,8 @@ use apollo_core::prelude::{
     ApolloResult, TimeZone, datetime_to_timestamp_ms, datetime_to_timestamp_ns,
     datetime_to_timestamp_us, apollo_bail,
 };
+#[cfg(feature = "dtype-interval")]
+use apollo_core::prelude::months_days_ns;
 use apollo_error::apollo_ensure;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -436,6 +438,23 @@ impl Duration {
         }
     }
 
+    /// Converts the [`Duration`] to the months, days and nanoseconds of an interval.
+    ///
+    /// Weeks are converted to days.
+    #[cfg(feature = "dtype-interval")]
+    pub fn to_months_days_ns(&self) -> ApolloResult<months_days_ns> {
+        let sign = if self.negative { -1 } else { 1 };
+        let days = self.weeks * 7 + self.days;
+        match (i32::try_from(self.months), i32::try_from(days)) {
+            (Ok(months), Ok(days)) => Ok(months_days_ns::new(
+                sign * months,
+                sign * days,
+                sign as i64 * self.nsecs,
+            )),
+            _ => apollo_bail!(ComputeError: "duration '{}' is out of range for an interval", self),
+        }
+    }
+
     /// `true` if zero duration.
     pub fn is_zero(&self) -> bool {
         self.months == 0 && self.weeks == 0 && self.days == 0 && self.nsecs == 0
@@ -84,6 +84,14 @@ impl<K, V, S> LruCache<K, V, S> {
 }
 
 impl<K: Hash + Eq, V, S: BuildHasher> LruCache<K, V, S> {
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
     fn lru_list_unlink(&mut self, lru_key: LruKey) {
         let list = self.elements[lru_key].list;
         if let Some(more_recent) = self.elements.get_mut(list.more_recent) {
@@ -141,6 +149,22 @@ impl<K: Hash + Eq, V, S: BuildHasher> LruCache<K, V, S> {
         Some(&lru_node.value)
     }
 
+    /// Returns the removed value, if any.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let hash = self.build_hasher.hash_one(key);
+        let (lru_key, _) = self
+            .table
+            .find_entry(hash, |lru_key| self.elements[*lru_key].key.borrow() == key)
+            .ok()?
+            .remove();
+        self.lru_list_unlink(lru_key);
+        Some(self.elements.remove(lru_key).unwrap().value)
+    }
+
     /// Returns the old value, if any.
     pub fn insert(&mut self, key: K, value: V) -> Option<V> {
         let hash = self.build_hasher.hash_one(&key);
@@ -386,6 +386,7 @@ impl_cloud_scheme! {
     Https = "https",
     S3 = "s3",
     S3a = "s3a",
+    Sftp = "sftp",
 }
 
 impl CloudScheme {
@@ -1,6 +1,6 @@
 use std::sync::{LazyLock, Mutex};
 
-use sysinfo::System;
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System};
 
 /// Startup system is expensive, so we do it once
 pub struct MemInfo {
@@ -17,6 +17,18 @@ impl MemInfo {
             None => sys.available_memory(),
         }
     }
+
+    /// Resident memory of the current process in bytes, `None` if it can't be determined.
+    pub fn process_memory(&self) -> Option<u64> {
+        let pid = sysinfo::get_current_pid().ok()?;
+        let mut sys = self.sys.lock().unwrap();
+        sys.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&[pid]),
+            false,
+            ProcessRefreshKind::nothing().with_memory(),
+        );
+        sys.process(pid).map(|process| process.memory())
+    }
 }
 
 pub static MEMINFO: LazyLock<MemInfo> = LazyLock::new(|| MemInfo {
@@ -1401,3 +1401,46 @@ fn test_single_column_leading_empty_line_25166() -> ApolloResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_reject_rows() -> ApolloResult<()> {
+    let csv = "# exported\nid,name\n1,a\nx,b\n3,\"c\nd\"\n4,e,extra\n5,f\n";
+    let tmp_dir = tempfile::tempdir()?;
+    let reject_path = tmp_dir.path().join("rejects.csv");
+
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .with_skip_lines(1)
+        .with_schema_overwrite(Some(Arc::new(Schema::from_iter([Field::new(
+            "id".into(),
+            DataType::Int64,
+        )]))))
+        .with_reject_rows(Some(PlRefPath::try_from_path(&reject_path)?))
+        .into_reader_with_file_handle(file)
+        .finish()?;
+
+    assert_eq!(
+        df.column("id")?.i64()?.into_no_null_iter().collect::<Vec<_>>(),
+        [1, 3, 5]
+    );
+
+    let rejected = CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(reject_path))?
+        .finish()?
+        .sort(["line_number"], Default::default())?;
+    assert_eq!(
+        rejected
+            .column("line_number")?
+            .i64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [4, 7]
+    );
+    assert_eq!(rejected.column("raw_line")?.str()?.get(0), Some("x,b"));
+    assert_eq!(
+        rejected.column("raw_line")?.str()?.get(1),
+        Some("4,e,extra")
+    );
+
+    Ok(())
+}
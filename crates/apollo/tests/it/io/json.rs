@@ -102,3 +102,47 @@ fn read_ndjson_with_trailing_newline() {
     .unwrap();
     assert!(expected.equals(&df));
 }
+
+#[test]
+fn read_ndjson_reject_rows() -> ApolloResult<()> {
+    let data = "{\"a\":1}\n{\"a\":\n{\"a\":3}\n{\"a\":4}\n{\"a\":\n{\"a\":6}\n";
+    let tmp_dir = tempfile::tempdir()?;
+    let reject_path = tmp_dir.path().join("rejects.csv");
+
+    let df = JsonReader::new(Cursor::new(data))
+        .with_json_format(JsonFormat::JsonLines)
+        .with_schema(Arc::new(Schema::from_iter([Field::new(
+            "a".into(),
+            DataType::Int64,
+        )])))
+        .with_reject_rows(Some(PlRefPath::try_from_path(&reject_path)?))
+        .with_path(Some("data.ndjson".into()))
+        .finish()?;
+
+    assert_eq!(
+        df.column("a")?
+            .i64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [1, 3, 4, 6]
+    );
+
+    let rejected = CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(reject_path))?
+        .finish()?
+        .sort(["line_number"], Default::default())?;
+    assert_eq!(
+        rejected
+            .column("line_number")?
+            .i64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [2, 5]
+    );
+    assert_eq!(
+        rejected.column("file_path")?.str()?.get(0),
+        Some("data.ndjson")
+    );
+
+    Ok(())
+}
@@ -97,7 +97,10 @@ from apollo.datatypes import (
     Int32,
     Int64,
     Int128,
+    Interval,
+    JSON,
     List,
+    Map,
     Null,
     Object,
     String,
@@ -108,6 +111,8 @@ from apollo.datatypes import (
     UInt32,
     UInt64,
     UInt128,
+    UUID,
+    Union,
     Unknown,
     Utf8,
 )
@@ -152,6 +157,7 @@ from apollo.functions import (
     datetime,
     datetime_range,
     datetime_ranges,
+    digest_rows,
     dtype_of,
     duration,
     element,
@@ -163,6 +169,7 @@ from apollo.functions import (
     fold,
     format,
     from_epoch,
+    generate_uuid,
     groups,
     head,
     implode,
@@ -215,6 +222,8 @@ from apollo.io import (
     PartitionBy,
     ScanCastOptions,
     defer,
+    invalidate_parquet_metadata_cache,
+    prewarm_parquet_metadata_cache,
     read_avro,
     read_clipboard,
     read_csv,
@@ -230,17 +239,21 @@ from apollo.io import (
     read_lines,
     read_ndjson,
     read_ods,
+    read_orc,
     read_parquet,
     read_parquet_metadata,
     read_parquet_schema,
+    scan_avro,
     scan_csv,
     scan_delta,
     scan_iceberg,
     scan_ipc,
     scan_lines,
     scan_ndjson,
+    scan_orc,
     scan_parquet,
     scan_pyarrow_dataset,
+    sniff_csv_dialect,
 )
 from apollo.io.cloud import (
     CredentialProvider,
@@ -311,7 +324,10 @@ __all__ = [
     "Int32",
     "Int64",
     "Int128",
+    "Interval",
+    "JSON",
     "List",
+    "Map",
     "Null",
     "Object",
     "String",
@@ -322,6 +338,8 @@ __all__ = [
     "UInt32",
     "UInt64",
     "UInt128",
+    "UUID",
+    "Union",
     "Unknown",
     "Utf8",
     # datatypes.extension
@@ -333,6 +351,8 @@ __all__ = [
     "FileProviderArgs",
     "PartitionBy",
     "ScanCastOptions",
+    "invalidate_parquet_metadata_cache",
+    "prewarm_parquet_metadata_cache",
     "read_avro",
     "read_clipboard",
     "read_csv",
@@ -348,17 +368,21 @@ __all__ = [
     "read_lines",
     "read_ndjson",
     "read_ods",
+    "read_orc",
     "read_parquet",
     "read_parquet_metadata",
     "read_parquet_schema",
+    "scan_avro",
     "scan_csv",
     "scan_delta",
     "scan_iceberg",
     "scan_ipc",
     "scan_lines",
     "scan_ndjson",
+    "scan_orc",
     "scan_parquet",
     "scan_pyarrow_dataset",
+    "sniff_csv_dialect",
     "Catalog",
     # apollo.io.cloud
     "CredentialProvider",
@@ -431,6 +455,7 @@ __all__ = [
     "cum_reduce",
     "date",
     "datetime",
+    "digest_rows",
     "duration",
     "exclude",
     "explain_all",
@@ -468,6 +493,7 @@ __all__ = [
     # apollo.functions.len
     "len",
     # apollo.functions.random
+    "generate_uuid",
     "set_random_seed",
     # apollo.convert
     "from_arrow",
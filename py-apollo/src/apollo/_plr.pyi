@@ -18,6 +18,8 @@ RUNTIME_REPR: str
 CompatLevel: TypeAlias = int | bool
 BufferInfo: TypeAlias = tuple[int, int, int]
 UnicodeForm: TypeAlias = Literal["NFC", "NFKC", "NFD", "NFKD"]
+HashAlgorithm: TypeAlias = Literal["md5", "sha1", "sha256", "xxhash64", "murmur3_32"]
+VectorMetric: TypeAlias = Literal["dot", "cosine", "euclidean", "manhattan"]
 KeyValueMetadata: TypeAlias = Sequence[tuple[str, str]] | Any
 TimeZone: TypeAlias = str | None
 UpcastOrForbid: TypeAlias = Literal["upcast", "forbid"]
@@ -51,7 +53,15 @@ StartBy: TypeAlias = Literal[
 ]
 ClosedWindow: TypeAlias = Literal["left", "right", "both", "none"]
 RoundMode: TypeAlias = Literal["half_to_even", "half_away_from_zero"]
-CsvEncoding: TypeAlias = Literal["utf8", "utf8-lossy"]
+CsvEncoding: TypeAlias = Literal[
+    "utf8",
+    "utf8-lossy",
+    "latin1",
+    "windows-1252",
+    "shift_jis",
+    "utf-16-le",
+    "utf-16-be",
+]
 IpcCompression: TypeAlias = Literal["uncompressed", "lz4", "zstd"]
 JoinType: TypeAlias = Literal["inner", "left", "right", "full", "semi", "anti", "cross"]
 Label: TypeAlias = Literal["left", "right", "datapoint"]
@@ -758,6 +768,8 @@ class PyDataFrame:
         truncate_ragged_lines: bool,
         decimal_comma: bool,
         schema: Any | None,
+        sniff_dialect: int | None,
+        reject_rows: str | None,
     ) -> PyDataFrame: ...
     @staticmethod
     def read_json(
@@ -791,11 +803,22 @@ class PyDataFrame:
         projection: Sequence[int] | None,
         n_rows: int | None,
     ) -> PyDataFrame: ...
+    @staticmethod
+    def read_orc(
+        py_f: Any,
+        columns: Sequence[str] | None,
+        projection: Sequence[int] | None,
+        n_rows: int | None,
+        row_index: tuple[str, int] | None,
+    ) -> PyDataFrame: ...
     def write_json(self, py_f: Any) -> None: ...
     def write_ipc_stream(
         self, py_f: Any, compression: Any, compat_level: Any
     ) -> None: ...
     def write_avro(self, py_f: Any, compression: Any, name: str) -> None: ...
+    def write_orc(
+        self, py_f: Any, compression: Any, stripe_size: int | None
+    ) -> None: ...
 
     # serde
     def serialize_binary(self, py_f: Any) -> None: ...
@@ -830,6 +853,7 @@ class PyLazyFrame:
         include_file_paths: str | None,
         cloud_options: dict[str, Any] | None,
         credential_provider: Any | None,
+        reject_rows: str | None,
     ) -> PyLazyFrame: ...
     @staticmethod
     def new_from_csv(
@@ -864,6 +888,8 @@ class PyLazyFrame:
         cloud_options: dict[str, Any] | None,
         credential_provider: Any | None,
         include_file_paths: str | None,
+        sniff_dialect: int | None,
+        reject_rows: str | None,
     ) -> PyLazyFrame: ...
     @staticmethod
     def new_from_parquet(
@@ -881,6 +907,16 @@ class PyLazyFrame:
         scan_options: ScanOptions,
     ) -> PyLazyFrame: ...
     @staticmethod
+    def new_from_avro(
+        sources: Any,
+        scan_options: ScanOptions,
+    ) -> PyLazyFrame: ...
+    @staticmethod
+    def new_from_orc(
+        sources: Any,
+        scan_options: ScanOptions,
+    ) -> PyLazyFrame: ...
+    @staticmethod
     def new_from_scan_lines(
         sources: Any,
         *,
@@ -914,6 +950,7 @@ class PyLazyFrame:
     def describe_optimized_plan_tree(self) -> str: ...
     def to_dot(self, optimized: bool) -> str: ...
     def to_dot_streaming_phys(self, optimized: bool) -> str: ...
+    def explain_analyze(self) -> tuple[str, PyDataFrame]: ...
     def sort(
         self,
         by_column: str,
@@ -972,6 +1009,7 @@ class PyLazyFrame:
         target: SinkTarget,
         sink_options: Any,
         include_bom: bool,
+        encoding: CsvEncoding,
         compression: Literal["uncompressed", "gzip", "zstd"],
         compression_level: int | None,
         check_extension: bool,
@@ -997,6 +1035,20 @@ class PyLazyFrame:
         check_extension: bool,
         sink_options: Any,
     ) -> PyLazyFrame: ...
+    def sink_avro(
+        self,
+        target: SinkTarget,
+        compression: Literal["uncompressed", "snappy", "deflate"],
+        name: str,
+        sink_options: Any,
+    ) -> PyLazyFrame: ...
+    def sink_orc(
+        self,
+        target: SinkTarget,
+        compression: Literal["uncompressed", "zlib", "snappy", "zstd"],
+        stripe_size: int | None,
+        sink_options: Any,
+    ) -> PyLazyFrame: ...
     def sink_batches(
         self,
         function: Callable[[PyDataFrame], bool],
@@ -1292,6 +1344,7 @@ class PyExpr:
         self, other: PyExpr, abs_tol: float, rel_tol: float, nans_equal: bool
     ) -> PyExpr: ...
     def approx_n_unique(self) -> PyExpr: ...
+    def approx_quantile(self, quantile: float, accuracy: int) -> PyExpr: ...
     def is_first_distinct(self) -> PyExpr: ...
     def is_last_distinct(self) -> PyExpr: ...
     def explode(self, *, empty_as_null: bool, keep_nulls: bool) -> PyExpr: ...
@@ -1460,6 +1513,9 @@ class PyExpr:
     def arr_join(self, separator: PyExpr, ignore_nulls: bool) -> PyExpr: ...
     def arr_contains(self, other: PyExpr, nulls_equal: bool) -> PyExpr: ...
     def arr_count_matches(self, expr: PyExpr) -> PyExpr: ...
+    def arr_vector_metric(self, other: PyExpr, metric: VectorMetric) -> PyExpr: ...
+    def arr_normalize(self) -> PyExpr: ...
+    def arr_nearest(self, query: PyExpr, k: PyExpr, metric: VectorMetric) -> PyExpr: ...
     def arr_to_struct(self, name_gen: Any | None = None) -> PyExpr: ...
     def arr_slice(
         self, offset: PyExpr, length: PyExpr | None = None, as_array: bool = False
@@ -1479,6 +1535,7 @@ class PyExpr:
     def bin_hex_encode(self) -> PyExpr: ...
     def bin_base64_encode(self) -> PyExpr: ...
     def bin_reinterpret(self, dtype: PyDataTypeExpr, kind: str) -> PyExpr: ...
+    def bin_digest(self, algorithm: HashAlgorithm, hex: bool) -> PyExpr: ...
     def bin_size_bytes(self) -> PyExpr: ...
     def bin_slice(self, offset: PyExpr, length: PyExpr) -> PyExpr: ...
     def bin_head(self, n: PyExpr) -> PyExpr: ...
@@ -1842,6 +1899,7 @@ class PyExpr:
         strict: bool = True,
         cache: bool = True,
     ) -> PyExpr: ...
+    def str_to_interval(self, strict: bool) -> PyExpr: ...
     def str_strip_chars(self, matches: PyExpr) -> PyExpr: ...
     def str_strip_chars_start(self, matches: PyExpr) -> PyExpr: ...
     def str_strip_chars_end(self, matches: PyExpr) -> PyExpr: ...
@@ -1861,6 +1919,23 @@ class PyExpr:
     def str_replace_all(self, pat: PyExpr, val: PyExpr, literal: bool) -> PyExpr: ...
     def str_normalize(self, form: UnicodeForm) -> PyExpr: ...
     def str_reverse(self) -> PyExpr: ...
+    def str_digest(self, algorithm: HashAlgorithm, hex: bool) -> PyExpr: ...
+    def str_levenshtein_distance(
+        self, other: PyExpr, max_distance: int | None
+    ) -> PyExpr: ...
+    def str_damerau_levenshtein_distance(
+        self, other: PyExpr, max_distance: int | None
+    ) -> PyExpr: ...
+    def str_hamming_distance(self, other: PyExpr) -> PyExpr: ...
+    def str_jaro_winkler_similarity(self, other: PyExpr) -> PyExpr: ...
+    def str_jaccard_similarity(self, other: PyExpr, q: int) -> PyExpr: ...
+    def str_parse_url(self) -> PyExpr: ...
+    def str_url_query_param(self, name: str) -> PyExpr: ...
+    def str_ipv4_to_int(self) -> PyExpr: ...
+    def str_ipv6_to_int(self) -> PyExpr: ...
+    def str_ip_to_binary(self) -> PyExpr: ...
+    def str_ip_in_cidr(self, cidr: PyExpr) -> PyExpr: ...
+    def str_ip_mask(self, ipv4_prefix: int, ipv6_prefix: int) -> PyExpr: ...
     def str_pad_start(self, length: PyExpr, fill_char: str) -> PyExpr: ...
     def str_pad_end(self, length: PyExpr, fill_char: str) -> PyExpr: ...
     def str_zfill(self, length: PyExpr) -> PyExpr: ...
@@ -1937,6 +2012,18 @@ class PyExpr:
     def ext_to(self, dtype: PyDataTypeExpr) -> PyExpr: ...
     def ext_storage(self) -> PyExpr: ...
 
+    # map
+    def map_get(self, key: PyExpr) -> PyExpr: ...
+    def map_keys(self) -> PyExpr: ...
+    def map_values(self) -> PyExpr: ...
+    def map_contains_key(self, key: PyExpr) -> PyExpr: ...
+    def map_from_lists(self, values: PyExpr) -> PyExpr: ...
+    def map_to_struct(self, keys: Sequence[str]) -> PyExpr: ...
+
+    # union
+    def union_tag(self) -> PyExpr: ...
+    def union_field(self, name: str) -> PyExpr: ...
+
 class PyDataTypeExpr:
     def __init__(self, inner: Any) -> None: ...
     @staticmethod
@@ -2086,6 +2173,14 @@ class PyOptFlags:
     @fast_projection.setter
     def fast_projection(self, value: bool) -> None: ...
     @property
+    def join_reorder(self) -> bool: ...
+    @join_reorder.setter
+    def join_reorder(self, value: bool) -> None: ...
+    @property
+    def result_cache(self) -> bool: ...
+    @result_cache.setter
+    def result_cache(self, value: bool) -> None: ...
+    @property
     def eager(self) -> bool: ...
     @eager.setter
     def eager(self, value: bool) -> None: ...
@@ -2232,6 +2327,17 @@ def read_ipc_schema(py_f: Any) -> dict[str, Any]: ...
 def read_parquet_metadata(
     py_f: Any, storage_options: Any, credential_provider: Any
 ) -> dict[str, str]: ...
+def prewarm_parquet_metadata_cache(
+    paths: list[str], storage_options: Any, credential_provider: Any
+) -> None: ...
+def invalidate_parquet_metadata_cache(path: str | None = None) -> None: ...
+def sniff_csv_dialect(
+    py_f: Any,
+    sample_lines: int,
+    encoding: Any,
+    eol_char: str,
+    comment_prefix: str | None,
+) -> dict[str, Any]: ...
 def read_clipboard_string() -> str: ...
 def write_clipboard_string(s: str) -> None: ...
 
@@ -2489,6 +2595,7 @@ def _known_timezones() -> list[str]: ...
 # extension
 def _register_extension_type(name: str, cls: Any | None) -> None: ...
 def _unregister_extension_type(name: str) -> None: ...
+def generate_uuid(n: PyExpr, version: int) -> PyExpr: ...
 
 # cloud_client
 def prepare_cloud_plan(
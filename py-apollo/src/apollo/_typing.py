@@ -100,9 +100,18 @@ ColumnNameOrSelector: TypeAlias = Union["str", SelectorType]
 # The following all have an equivalent Rust enum with the same name
 Ambiguous: TypeAlias = Literal["earliest", "latest", "raise", "null"]
 AvroCompression: TypeAlias = Literal["uncompressed", "snappy", "deflate"]
+OrcCompression: TypeAlias = Literal["uncompressed", "zlib", "snappy", "zstd"]
 CsvQuoteStyle: TypeAlias = Literal["necessary", "always", "non_numeric", "never"]
 CategoricalOrdering: TypeAlias = Literal["physical", "lexical"]
-CsvEncoding: TypeAlias = Literal["utf8", "utf8-lossy"]
+CsvEncoding: TypeAlias = Literal[
+    "utf8",
+    "utf8-lossy",
+    "latin1",
+    "windows-1252",
+    "shift_jis",
+    "utf-16-le",
+    "utf-16-be",
+]
 ColumnMapping: TypeAlias = tuple[
     Literal["iceberg-column-mapping"],
     # This is "pa.Schema". Not typed as that causes pyright strict type checking
@@ -207,6 +216,8 @@ Orientation: TypeAlias = Literal["col", "row"]
 SearchSortedSide: TypeAlias = Literal["any", "left", "right"]
 TorchExportType: TypeAlias = Literal["tensor", "dataset", "dict"]
 TransferEncoding: TypeAlias = Literal["hex", "base64"]
+HashAlgorithm: TypeAlias = Literal["md5", "sha1", "sha256", "xxhash64", "murmur3_32"]
+VectorMetric: TypeAlias = Literal["dot", "cosine", "euclidean", "manhattan"]
 WindowMappingStrategy: TypeAlias = Literal["group_to_rows", "join", "explode"]
 ExplainFormat: TypeAlias = Literal["plain", "tree"]
 
@@ -382,6 +393,7 @@ __all__ = [
     "FloatFmt",
     "FrameInitTypes",
     "FrameType",
+    "HashAlgorithm",
     "IndexOrder",
     "InterpolationMethod",
     "IntoExpr",
@@ -403,6 +415,7 @@ __all__ = [
     "NullBehavior",
     "NumericLiteral",
     "OneOrMoreDataTypes",
+    "OrcCompression",
     "Orientation",
     "ParallelStrategy",
     "ParametricProfileNames",
@@ -437,6 +450,7 @@ __all__ = [
     "UnicodeForm",
     "UniqueKeepStrategy",
     "UnstackDirection",
+    "VectorMetric",
     "WindowMappingStrategy",
 ]
 
@@ -73,6 +73,8 @@ _APOLLO_CFG_ENV_VARS: Final[set[str]] = {
     "APOLLO_FMT_TABLE_HIDE_DATAFRAME_SHAPE_INFORMATION",
     "APOLLO_FMT_TABLE_INLINE_COLUMN_DATA_TYPE",
     "APOLLO_FMT_TABLE_ROUNDED_CORNERS",
+    "APOLLO_PARQUET_METADATA_CACHE_SIZE",
+    "APOLLO_RESULT_CACHE_SIZE",
     "APOLLO_STREAMING_CHUNK_SIZE",
     "APOLLO_TABLE_WIDTH",
     "APOLLO_VERBOSE",
@@ -106,6 +108,8 @@ class ConfigParameters(TypedDict, total=False):
     fmt_float: FloatFmt | None
     fmt_str_lengths: int | None
     fmt_table_cell_list_len: int | None
+    parquet_metadata_cache_size: int | None
+    result_cache_size: int | None
     streaming_chunk_size: int | None
     tbl_cell_alignment: Literal["LEFT", "CENTER", "RIGHT"] | None
     tbl_cell_numeric_alignment: Literal["LEFT", "CENTER", "RIGHT"] | None
@@ -131,6 +135,8 @@ class ConfigParameters(TypedDict, total=False):
     set_fmt_float: FloatFmt | None
     set_fmt_str_lengths: int | None
     set_fmt_table_cell_list_len: int | None
+    set_parquet_metadata_cache_size: int | None
+    set_result_cache_size: int | None
     set_streaming_chunk_size: int | None
     set_tbl_cell_alignment: Literal["LEFT", "CENTER", "RIGHT"] | None
     set_tbl_cell_numeric_alignment: Literal["LEFT", "CENTER", "RIGHT"] | None
@@ -873,6 +879,75 @@ class Config(contextlib.ContextDecorator):
             os.environ["APOLLO_FMT_TABLE_CELL_LIST_LEN"] = str(n)
         return cls
 
+    @classmethod
+    def set_parquet_metadata_cache_size(cls, size: int | None) -> type[Config]:
+        """
+        Set the size of the Parquet metadata cache.
+
+        When enabled, the decoded metadata (footer) of scanned Parquet files is kept
+        in memory and reused by subsequent scans of the same files, which avoids
+        fetching and decoding the footer again. Cached metadata is only used if the
+        file is unchanged (same ETag, or same modification time and size). Once the
+        cache exceeds its size, the least recently used entries are evicted.
+
+        See :func:`prewarm_parquet_metadata_cache` and
+        :func:`invalidate_parquet_metadata_cache` to manage the cache.
+
+        Parameters
+        ----------
+        size
+            Maximum size of the cache in bytes, measured as the size of the encoded
+            footers. Set to `None` or `0` to disable the cache (default).
+
+        Examples
+        --------
+        >>> pl.Config.set_parquet_metadata_cache_size(64 * 1024**2)  # doctest: +SKIP
+        """
+        if size is None:
+            os.environ.pop("APOLLO_PARQUET_METADATA_CACHE_SIZE", None)
+        else:
+            if size < 0:
+                msg = "parquet metadata cache size must be >= 0"
+                raise ValueError(msg)
+
+            os.environ["APOLLO_PARQUET_METADATA_CACHE_SIZE"] = str(size)
+        return cls
+
+    @classmethod
+    def set_result_cache_size(cls, size: int | None) -> type[Config]:
+        """
+        Set the size of the cross-query result cache.
+
+        When enabled, the results of cacheable subplans (e.g. filtered scans and
+        aggregations over files) are kept in memory and reused by subsequent queries
+        that contain the same subplan. A result is only reused if none of the scanned
+        files has been modified since. Once the cache exceeds its size, the least
+        recently used results are evicted.
+
+        Subplans that depend on in-memory data, user-defined functions or random
+        sampling are never cached. Individual queries can bypass the cache with
+        `QueryOptFlags(result_cache=False)`.
+
+        Parameters
+        ----------
+        size
+            Maximum size of the cache in bytes. Set to `None` or `0` to disable the
+            cache (default).
+
+        Examples
+        --------
+        >>> pl.Config.set_result_cache_size(512 * 1024**2)  # doctest: +SKIP
+        """
+        if size is None:
+            os.environ.pop("APOLLO_RESULT_CACHE_SIZE", None)
+        else:
+            if size < 0:
+                msg = "result cache size must be >= 0"
+                raise ValueError(msg)
+
+            os.environ["APOLLO_RESULT_CACHE_SIZE"] = str(size)
+        return cls
+
     @classmethod
     def set_streaming_chunk_size(cls, size: int | None) -> type[Config]:
         """
@@ -153,6 +153,7 @@ if TYPE_CHECKING:
         ComparisonOperator,
         ConditionalFormatDict,
         ConnectionOrCursor,
+        CsvEncoding,
         CsvQuoteStyle,
         DbWriteEngine,
         EngineType,
@@ -170,6 +171,7 @@ if TYPE_CHECKING:
         MultiColSelector,
         MultiIndexSelector,
         OneOrMoreDataTypes,
+        OrcCompression,
         Orientation,
         ParquetCompression,
         ParquetMetadata,
@@ -2996,6 +2998,7 @@ class DataFrame:
         file: None = None,
         *,
         include_bom: bool = ...,
+        encoding: CsvEncoding = ...,
         compression: Literal["uncompressed", "gzip", "zstd"] = ...,
         compression_level: int | None = None,
         check_extension: bool = ...,
@@ -3023,6 +3026,7 @@ class DataFrame:
         file: str | Path | IO[str] | IO[bytes],
         *,
         include_bom: bool = ...,
+        encoding: CsvEncoding = ...,
         compression: Literal["uncompressed", "gzip", "zstd"] = ...,
         compression_level: int | None = None,
         check_extension: bool = ...,
@@ -3049,6 +3053,7 @@ class DataFrame:
         file: str | Path | IO[str] | IO[bytes] | None = None,
         *,
         include_bom: bool = False,
+        encoding: CsvEncoding = "utf8",
         compression: Literal["uncompressed", "gzip", "zstd"] = "uncompressed",
         compression_level: int | None = None,
         check_extension: bool = True,
@@ -3080,7 +3085,11 @@ class DataFrame:
             File path or writable file-like object to which the result will be written.
             If set to `None` (default), the output is returned as a string instead.
         include_bom
-            Whether to include UTF-8 BOM in the CSV output.
+            Whether to include the byte order mark of `encoding` in the CSV output.
+            Has no effect for encodings without a byte order mark.
+        encoding : {'utf8', 'latin1', 'windows-1252', 'shift_jis', 'utf-16-le', 'utf-16-be'}
+            Text encoding of the CSV output. Raises an error if a value cannot be
+            represented in the chosen encoding.
         compression
             What compression format to use.
 
@@ -3167,6 +3176,10 @@ class DataFrame:
             * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
             * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
             `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+            * SFTP (`sftp://`): Accepts `username`, `password`, `private_key_path`, \
+            `private_key_passphrase`, `known_hosts_path` and `skip_host_key_check`. \
+            Only available if Apollo was built with the `sftp` feature.
+            * HTTP(S): Accepts `bearer_token`, or `username` and `password`.
 
             If `storage_options` is not provided, Apollo will try to infer the
             information from environment variables.
@@ -3222,6 +3235,7 @@ class DataFrame:
         self.lazy().sink_csv(
             target,
             include_bom=include_bom,
+            encoding=encoding,
             compression=compression,
             compression_level=compression_level,
             check_extension=check_extension,
@@ -3246,7 +3260,9 @@ class DataFrame:
         )
 
         if should_return_buffer:
-            return str(target.getvalue(), encoding="utf-8")  # type: ignore[union-attr]
+            # All encodings other than UTF-8 share their name with a Python codec.
+            codec = "utf-8" if encoding in ("utf8", "utf8-lossy") else encoding
+            return str(target.getvalue(), encoding=codec)  # type: ignore[union-attr]
 
         return None
 
@@ -3312,6 +3328,50 @@ class DataFrame:
 
         self._df.write_avro(file, compression, name)
 
+    def write_orc(
+        self,
+        file: str | Path | IO[bytes],
+        compression: OrcCompression = "zstd",
+        stripe_size: int | None = None,
+    ) -> None:
+        """
+        Write to Apache ORC file.
+
+        Parameters
+        ----------
+        file
+            File path or writable file-like object to which the data will be written.
+        compression : {'uncompressed', 'zlib', 'snappy', 'zstd'}
+            Compression method of the column streams. Defaults to "zstd".
+        stripe_size
+            Maximum number of rows per stripe. Defaults to 1024^2 rows.
+
+        Notes
+        -----
+        Nested and decimal columns cannot be written. Unsigned integers are written
+        as the next larger signed integer type, categoricals and enums as strings.
+
+        Examples
+        --------
+        >>> import pathlib
+        >>>
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "foo": [1, 2, 3, 4, 5],
+        ...         "bar": [6, 7, 8, 9, 10],
+        ...         "ham": ["a", "b", "c", "d", "e"],
+        ...     }
+        ... )
+        >>> path: pathlib.Path = dirpath / "new_file.orc"
+        >>> df.write_orc(path)
+        """
+        if compression is None:
+            compression = "uncompressed"
+        if isinstance(file, (str, Path)):
+            file = normalize_filepath(file)
+
+        self._df.write_orc(file, compression, stripe_size)
+
     def write_excel(
         self,
         workbook: str | Workbook | IO[bytes] | Path | None = None,
@@ -3968,6 +4028,10 @@ class DataFrame:
             * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
             * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
             `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+            * SFTP (`sftp://`): Accepts `username`, `password`, `private_key_path`, \
+            `private_key_passphrase`, `known_hosts_path` and `skip_host_key_check`. \
+            Only available if Apollo was built with the `sftp` feature.
+            * HTTP(S): Accepts `bearer_token`, or `username` and `password`.
 
             If `storage_options` is not provided, Apollo will try to infer the
             information from environment variables.
@@ -4192,6 +4256,10 @@ class DataFrame:
             * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
             * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
             `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+            * SFTP (`sftp://`): Accepts `username`, `password`, `private_key_path`, \
+            `private_key_passphrase`, `known_hosts_path` and `skip_host_key_check`. \
+            Only available if Apollo was built with the `sftp` feature.
+            * HTTP(S): Accepts `bearer_token`, or `username` and `password`.
 
             If `storage_options` is not provided, Apollo will try to infer the
             information from environment variables.
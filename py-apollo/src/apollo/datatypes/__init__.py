@@ -29,7 +29,10 @@ from apollo.datatypes.classes import (
     Int64,
     Int128,
     IntegerType,
+    Interval,
+    JSON,
     List,
+    Map,
     Null,
     Object,
     String,
@@ -41,6 +44,8 @@ from apollo.datatypes.classes import (
     UInt32,
     UInt64,
     UInt128,
+    UUID,
+    Union,
     Unknown,
     Utf8,
 )
@@ -92,7 +97,10 @@ __all__ = [
     "Int64",
     "Int8",
     "IntegerType",
+    "Interval",
+    "JSON",
     "List",
+    "Map",
     "Null",
     "Object",
     "String",
@@ -104,6 +112,8 @@ __all__ = [
     "UInt32",
     "UInt64",
     "UInt8",
+    "UUID",
+    "Union",
     "Unknown",
     "Utf8",
     # constants
@@ -660,6 +660,22 @@ class Duration(TemporalType):
         return f"{class_name}(time_unit={self.time_unit!r})"
 
 
+class Interval(TemporalType):
+    """
+    Data type representing a calendar interval.
+
+    An interval consists of a number of months, days, and nanoseconds. Unlike a
+    :class:`Duration`, months and days have no fixed length: adding an interval to
+    a :class:`Date` or :class:`Datetime` respects month ends, leap years, and
+    daylight saving time.
+
+    Notes
+    -----
+    Values are represented in Python as a `(months, days, nanoseconds)` tuple.
+    The components may have different signs.
+    """
+
+
 class Categories:
     """
     A named collection of categories for :py:class:`Categorical`.
@@ -1315,6 +1331,122 @@ class Struct(NestedType):
         return OrderedDict(self)
 
 
+class Map(NestedType):
+    """
+    Map type, a variable length collection of key-value pairs.
+
+    A map is stored as a list of `{key, value}` structs and round-trips as a
+    map through Parquet, IPC and Avro. Maps written by other tools, such as
+    PyArrow or Iceberg, are read as a list of `{key, value}` structs; cast them
+    to `Map` to use the `map` namespace.
+
+    Parameters
+    ----------
+    key
+        The `DataType` of the keys. Keys cannot be null.
+    value
+        The `DataType` of the values.
+
+    Examples
+    --------
+    >>> pl.Map(pl.String, pl.Int64)
+    Map(String, Int64)
+
+    Build a map column from a list of keys and a list of values.
+
+    >>> df = pl.DataFrame({"k": [["a", "b"], ["c"]], "v": [[1, 2], [3]]})
+    >>> df.select(pl.col("k").map.from_lists(pl.col("v"))).schema["k"]
+    Map(String, Int64)
+    """
+
+    key: ApolloDataType
+    value: ApolloDataType
+
+    def __init__(
+        self,
+        key: ApolloDataType | PythonDataType,
+        value: ApolloDataType | PythonDataType,
+    ) -> None:
+        self.key = apollo.datatypes.parse_into_dtype(key)
+        self.value = apollo.datatypes.parse_into_dtype(value)
+
+    def __eq__(self, other: ApolloDataType) -> bool:  # type: ignore[override]
+        # allow comparing object instances to class (see the List type)
+        if type(other) is DataTypeClass and issubclass(other, Map):
+            return True
+        elif isinstance(other, Map):
+            return self.key == other.key and self.value == other.value
+        else:
+            return False
+
+    def __hash__(self) -> int:
+        return hash((self.__class__, self.key, self.value))
+
+    def __repr__(self) -> str:
+        class_name = self.__class__.__name__
+        return f"{class_name}({self.key!r}, {self.value!r})"
+
+
+class Union(NestedType):
+    """
+    Union type, every value is of exactly one of the member types.
+
+    A union is imported from and exported to Arrow sparse and dense unions
+    without loss, including the type ids of the members.
+
+    Parameters
+    ----------
+    fields
+        The members of the union. Can be either a sequence of Field objects or
+        a mapping of member names to data types.
+
+    Examples
+    --------
+    >>> pl.Union({"i": pl.Int64, "s": pl.String})
+    Union({'i': Int64, 's': String})
+
+    Extract the members as nullable columns.
+
+    >>> import pyarrow as pa
+    >>> arr = pa.UnionArray.from_sparse(
+    ...     pa.array([0, 1], pa.int8()),
+    ...     [pa.array([1, None]), pa.array([None, "a"])],
+    ...     field_names=["i", "s"],
+    ... )
+    >>> df = pl.from_arrow(arr).to_frame("u")
+    >>> df.select(pl.col("u").union.field("s")).to_series().to_list()
+    [None, 'a']
+    """
+
+    fields: list[Field]
+
+    def __init__(self, fields: Sequence[Field] | SchemaDict) -> None:
+        if isinstance(fields, Mapping):
+            self.fields = [Field(name, dtype) for name, dtype in fields.items()]
+        else:
+            self.fields = list(fields)
+
+    def __eq__(self, other: ApolloDataType) -> bool:  # type: ignore[override]
+        # allow comparing object instances to class (see the List type)
+        if isclass(other) and issubclass(other, Union):
+            return True
+        elif isinstance(other, Union):
+            return self.fields == other.fields
+        else:
+            return False
+
+    def __hash__(self) -> int:
+        return hash((self.__class__, tuple(self.fields)))
+
+    def __iter__(self) -> Iterator[tuple[str, ApolloDataType]]:
+        for fld in self.fields:
+            yield fld.name, fld.dtype
+
+    def __repr__(self) -> str:
+        class_name = self.__class__.__name__
+        return f"{class_name}({dict(self)})"
+
+
 class BaseExtension(DataType):
     """
     Base class for extension data types.
@@ -1376,6 +1508,53 @@ class BaseExtension(DataType):
         else:
             return s[:10] + ".."
 
+    # Optional hooks, overridden by subclasses that need custom value semantics.
+    def ext_format_value(self, value: Any) -> str | None:
+        """
+        Format a single non-null value for display.
+
+        `value` is the value in its storage representation. Return `None` to
+        format it like the storage type would.
+        """
+        return None
+
+    def ext_cast_to(
+        self, s: Series, dtype: ApolloDataType, strict: bool
+    ) -> Series | None:
+        """
+        Cast the storage `s` of a Series of this extension type to `dtype`.
+
+        Return `None` to cast the storage to `dtype` instead.
+        """
+        return None
+
+    def ext_cast_from(self, s: Series, strict: bool) -> Series | None:
+        """
+        Cast a Series `s` of another data type to the storage of this type.
+
+        Return `None` to cast `s` to the storage type instead.
+        """
+        return None
+
+    def ext_comparison_key(self, s: Series) -> Series | None:
+        """
+        Compute the key that defines equality and ordering of the values.
+
+        `s` is the storage of a Series of this extension type. The key must have
+        the same length and is used when comparing, sorting, grouping and
+        joining. Return `None` to use the storage itself.
+        """
+        return None
+
+    def ext_validate(self, s: Series) -> None:
+        """
+        Check that the storage `s` holds valid values for this extension type.
+
+        Called when a Series of this type is created from its storage, for
+        example through `ext.to`, a cast or when reading a file. Raise an
+        exception for invalid values.
+        """
+
     def __repr__(self) -> str:
         md = self.ext_metadata()
         if md is not None:
@@ -1427,3 +1606,68 @@ class Extension(BaseExtension):
     BaseExtension
     apollo.register_extension_type
     """
+
+
+class UUID(BaseExtension):
+    """
+    UUID data type, the canonical `arrow.uuid` extension type.
+
+    Values are stored as 16 bytes of :class:`Binary` and are displayed and cast
+    to and from :class:`String` in their hyphenated form. They are ordered by
+    their bytes, which for version 7 UUIDs is their order of creation.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed at any
+        point without it being considered a breaking change.
+
+    See Also
+    --------
+    apollo.generate_uuid
+    """
+
+    def __init__(self) -> None:
+        super().__init__("arrow.uuid", Binary())
+
+    @classmethod
+    def ext_from_params(
+        cls, name: str, storage: ApolloDataType, metadata: str | None
+    ) -> UUID:
+        """Creates a UUID type instance from its parameters."""
+        return cls()
+
+    def _string_repr(self) -> str:
+        return "uuid"
+
+    def __repr__(self) -> str:
+        return f"{self.__class__.__name__}()"
+
+
+class JSON(BaseExtension):
+    """
+    JSON data type, the canonical `arrow.json` extension type.
+
+    Values are JSON documents stored as :class:`String`. Casting a String
+    column to this type validates every document; invalid documents raise in a
+    strict cast and become null otherwise. The `str.json_decode` and
+    `str.json_path_match` expressions accept columns of this type directly.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed at any
+        point without it being considered a breaking change.
+    """
+
+    def __init__(self) -> None:
+        super().__init__("arrow.json", String())
+
+    @classmethod
+    def ext_from_params(
+        cls, name: str, storage: ApolloDataType, metadata: str | None
+    ) -> JSON:
+        """Creates a JSON type instance from its parameters."""
+        return cls()
+
+    def _string_repr(self) -> str:
+        return "json"
+
+    def __repr__(self) -> str:
+        return f"{self.__class__.__name__}()"
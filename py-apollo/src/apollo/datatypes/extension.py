@@ -8,7 +8,11 @@ from apollo._utils.unstable import unstable
 with contextlib.suppress(ImportError):  # Module not available when building docs
     from apollo._plr import _register_extension_type, _unregister_extension_type
 
-_REGISTRY: dict[str, str | type[dt.BaseExtension]] = {}
+# The canonical extension types are implemented and registered natively.
+_REGISTRY: dict[str, str | type[dt.BaseExtension]] = {
+    "arrow.uuid": dt.UUID,
+    "arrow.json": dt.JSON,
+}
 
 
 @unstable()
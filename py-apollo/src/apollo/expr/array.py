@@ -10,7 +10,7 @@ if TYPE_CHECKING:
     from collections.abc import Callable
 
     from apollo import Expr
-    from apollo._typing import IntoExpr, IntoExprColumn
+    from apollo._typing import IntoExpr, IntoExprColumn, VectorMetric
 
 
 class ExprArrayNameSpace:
@@ -867,6 +867,242 @@ class ExprArrayNameSpace:
         element_pyexpr = parse_into_expression(element, str_as_lit=True)
         return wrap_expr(self._pyexpr.arr_count_matches(element_pyexpr))
 
+    def dot(self, other: IntoExpr) -> Expr:
+        """
+        Compute the dot product of every subarray with the subarray of `other`.
+
+        Arrays of :class:`Float32` are computed in `Float32`, all other numeric
+        arrays in `Float64`. Subarrays that are null or contain a null give null.
+
+        Parameters
+        ----------
+        other
+            An array expression of the same width, or a single vector such as a
+            list of numbers, which is compared with every subarray.
+
+        See Also
+        --------
+        cosine_similarity
+
+        Notes
+        -----
+        Element-wise arithmetic between arrays uses the regular operators, for
+        example `pl.col("a") - pl.col("b")`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1.0, 2.0], [3.0, 4.0]], "b": [[2.0, 0.0], [1.0, 1.0]]},
+        ...     schema={"a": pl.Array(pl.Float64, 2), "b": pl.Array(pl.Float64, 2)},
+        ... )
+        >>> df.with_columns(dot=pl.col("a").arr.dot("b"))
+        shape: (2, 3)
+        ┌───────────────┬───────────────┬─────┐
+        │ a             ┆ b             ┆ dot │
+        │ ---           ┆ ---           ┆ --- │
+        │ array[f64, 2] ┆ array[f64, 2] ┆ f64 │
+        ╞═══════════════╪═══════════════╪═════╡
+        │ [1.0, 2.0]    ┆ [2.0, 0.0]    ┆ 2.0 │
+        │ [3.0, 4.0]    ┆ [1.0, 1.0]    ┆ 7.0 │
+        └───────────────┴───────────────┴─────┘
+        """
+        other_pyexpr = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.arr_vector_metric(other_pyexpr, "dot"))
+
+    def cosine_similarity(self, other: IntoExpr) -> Expr:
+        """
+        Compute the cosine similarity of every subarray with the subarray of `other`.
+
+        The cosine similarity is the dot product of the vectors divided by the
+        product of their lengths. It is NaN if either vector has zero length.
+
+        Parameters
+        ----------
+        other
+            An array expression of the same width, or a single vector such as a
+            list of numbers, which is compared with every subarray.
+
+        See Also
+        --------
+        dot
+        nearest
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[3.0, 4.0], [0.0, 2.0], [-1.0, 0.0]]},
+        ...     schema={"a": pl.Array(pl.Float64, 2)},
+        ... )
+        >>> df.with_columns(sim=pl.col("a").arr.cosine_similarity([1.0, 0.0]))
+        shape: (3, 2)
+        ┌───────────────┬──────┐
+        │ a             ┆ sim  │
+        │ ---           ┆ ---  │
+        │ array[f64, 2] ┆ f64  │
+        ╞═══════════════╪══════╡
+        │ [3.0, 4.0]    ┆ 0.6  │
+        │ [0.0, 2.0]    ┆ 0.0  │
+        │ [-1.0, 0.0]   ┆ -1.0 │
+        └───────────────┴──────┘
+        """
+        other_pyexpr = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.arr_vector_metric(other_pyexpr, "cosine"))
+
+    def euclidean_distance(self, other: IntoExpr) -> Expr:
+        """
+        Compute the Euclidean (L2) distance between every subarray and `other`.
+
+        Parameters
+        ----------
+        other
+            An array expression of the same width, or a single vector such as a
+            list of numbers, which is compared with every subarray.
+
+        See Also
+        --------
+        manhattan_distance
+        nearest
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[3.0, 4.0], [0.0, 1.0]]}, schema={"a": pl.Array(pl.Float64, 2)}
+        ... )
+        >>> df.with_columns(dist=pl.col("a").arr.euclidean_distance([0.0, 0.0]))
+        shape: (2, 2)
+        ┌───────────────┬──────┐
+        │ a             ┆ dist │
+        │ ---           ┆ ---  │
+        │ array[f64, 2] ┆ f64  │
+        ╞═══════════════╪══════╡
+        │ [3.0, 4.0]    ┆ 5.0  │
+        │ [0.0, 1.0]    ┆ 1.0  │
+        └───────────────┴──────┘
+        """
+        other_pyexpr = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.arr_vector_metric(other_pyexpr, "euclidean"))
+
+    def manhattan_distance(self, other: IntoExpr) -> Expr:
+        """
+        Compute the Manhattan (L1) distance between every subarray and `other`.
+
+        Parameters
+        ----------
+        other
+            An array expression of the same width, or a single vector such as a
+            list of numbers, which is compared with every subarray.
+
+        See Also
+        --------
+        euclidean_distance
+        nearest
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[3.0, 4.0], [0.0, -1.0]]}, schema={"a": pl.Array(pl.Float64, 2)}
+        ... )
+        >>> df.with_columns(dist=pl.col("a").arr.manhattan_distance([0.0, 0.0]))
+        shape: (2, 2)
+        ┌───────────────┬──────┐
+        │ a             ┆ dist │
+        │ ---           ┆ ---  │
+        │ array[f64, 2] ┆ f64  │
+        ╞═══════════════╪══════╡
+        │ [3.0, 4.0]    ┆ 7.0  │
+        │ [0.0, -1.0]   ┆ 1.0  │
+        └───────────────┴──────┘
+        """
+        other_pyexpr = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.arr_vector_metric(other_pyexpr, "manhattan"))
+
+    def normalize(self) -> Expr:
+        """
+        Scale every subarray to unit (L2) length.
+
+        Subarrays of zero length give NaN values.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[3.0, 4.0], [0.0, 2.0]]}, schema={"a": pl.Array(pl.Float64, 2)}
+        ... )
+        >>> df.with_columns(normalized=pl.col("a").arr.normalize())
+        shape: (2, 2)
+        ┌───────────────┬───────────────┐
+        │ a             ┆ normalized    │
+        │ ---           ┆ ---           │
+        │ array[f64, 2] ┆ array[f64, 2] │
+        ╞═══════════════╪═══════════════╡
+        │ [3.0, 4.0]    ┆ [0.6, 0.8]    │
+        │ [0.0, 2.0]    ┆ [0.0, 1.0]    │
+        └───────────────┴───────────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_normalize())
+
+    def nearest(
+        self,
+        query: IntoExpr,
+        k: int | IntoExprColumn = 5,
+        *,
+        metric: VectorMetric = "cosine",
+    ) -> Expr:
+        """
+        Get the row indices of the `k` subarrays closest to `query`.
+
+        The indices are sorted from closest to farthest. For the `"dot"` and
+        `"cosine"` metrics the closest subarrays are those with the largest
+        value, for the distance metrics those with the smallest. Subarrays with a
+        null or NaN score are never returned, so fewer than `k` indices are
+        returned if there aren't enough valid subarrays.
+
+        Parameters
+        ----------
+        query
+            The vector to search for, such as a list of numbers.
+        k
+            Number of indices to return.
+        metric : {'cosine', 'dot', 'euclidean', 'manhattan'}
+            How to measure the closeness of two vectors.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32` with at most `k` rows.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]},
+        ...     schema={"a": pl.Array(pl.Float64, 2)},
+        ... )
+        >>> df.select(pl.col("a").arr.nearest([1.0, 0.1], k=2).alias("idx"))
+        shape: (2, 1)
+        ┌─────┐
+        │ idx │
+        │ --- │
+        │ u32 │
+        ╞═════╡
+        │ 0   │
+        │ 2   │
+        └─────┘
+
+        Use the indices to get the nearest rows.
+
+        >>> df.select(pl.col("a").gather(pl.col("a").arr.nearest([0.0, 2.0], k=1)))
+        shape: (1, 1)
+        ┌───────────────┐
+        │ a             │
+        │ ---           │
+        │ array[f64, 2] │
+        ╞═══════════════╡
+        │ [0.0, 1.0]    │
+        └───────────────┘
+        """
+        query_pyexpr = parse_into_expression(query)
+        k_pyexpr = parse_into_expression(k)
+        return wrap_expr(self._pyexpr.arr_nearest(query_pyexpr, k_pyexpr, metric))
+
     def to_struct(
         self, fields: Sequence[str] | Callable[[int], str] | None = None
     ) -> Expr:
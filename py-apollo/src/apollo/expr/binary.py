@@ -11,6 +11,7 @@ if TYPE_CHECKING:
     from apollo import DataTypeExpr, Expr
     from apollo._typing import (
         Endianness,
+        HashAlgorithm,
         IntoExpr,
         ApolloDataType,
         SizeUnit,
@@ -260,6 +261,44 @@ class ExprBinaryNameSpace:
             msg = f"`encoding` must be one of {{'hex', 'base64'}}, got {encoding!r}"
             raise ValueError(msg)
 
+    def digest(self, algorithm: HashAlgorithm = "sha256", *, hex: bool = True) -> Expr:
+        r"""
+        Compute a stable digest of each binary value.
+
+        Unlike :meth:`Expr.hash`, the output is fixed by the specification of the
+        algorithm and is the same on every platform and version.
+
+        Parameters
+        ----------
+        algorithm : {'md5', 'sha1', 'sha256', 'xxhash64', 'murmur3_32'}
+            The hash algorithm to use. `xxhash64` and `murmur3_32` (MurmurHash3
+            x86_32) use seed 0 and give their digest in big-endian byte order.
+        hex
+            Return the digests as lowercase hexadecimal strings instead of binary
+            values.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String` if `hex` is set, otherwise
+            :class:`Binary`.
+
+        See Also
+        --------
+        apollo.digest_rows
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"data": [b"abc", None]})
+        >>> df.select(pl.col("data").bin.digest("md5")).to_series().to_list()
+        ['900150983cd24fb0d6963f7d28e17f72', None]
+        >>> df.select(
+        ...     pl.col("data").bin.digest("murmur3_32", hex=False)
+        ... ).to_series().to_list()
+        [b'\xb3\xdd\x93\xfa', None]
+        """
+        return wrap_expr(self._pyexpr.bin_digest(algorithm, hex))
+
     def size(self, unit: SizeUnit = "b") -> Expr:
         r"""
         Get the size of binary values in the given unit.
@@ -63,10 +63,12 @@ from apollo.expr.categorical import ExprCatNameSpace
 from apollo.expr.datetime import ExprDateTimeNameSpace
 from apollo.expr.ext import ExprExtensionNameSpace
 from apollo.expr.list import ExprListNameSpace
+from apollo.expr.map import ExprMapNameSpace
 from apollo.expr.meta import ExprMetaNameSpace
 from apollo.expr.name import ExprNameNameSpace
 from apollo.expr.string import ExprStringNameSpace
 from apollo.expr.struct import ExprStructNameSpace
+from apollo.expr.union import ExprUnionNameSpace
 from apollo.meta import thread_pool_size
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
@@ -144,10 +146,12 @@ class Expr:
         "dt",
         "ext",
         "list",
+        "map",
         "meta",
         "name",
         "str",
         "struct",
+        "union",
     }
 
     @property
@@ -291,6 +295,24 @@ class Expr:
         """
         return ExprExtensionNameSpace(self)
 
+    @property
+    def map(self) -> ExprMapNameSpace:
+        """
+        Create an object namespace of all map related methods.
+
+        See the individual method pages for full details.
+        """
+        return ExprMapNameSpace(self)
+
+    @property
+    def union(self) -> ExprUnionNameSpace:
+        """
+        Create an object namespace of all union related methods.
+
+        See the individual method pages for full details.
+        """
+        return ExprUnionNameSpace(self)
+
     @classmethod
     def _from_pyexpr(cls, pyexpr: PyExpr) -> Expr:
         expr = cls.__new__(cls)
@@ -4353,6 +4375,55 @@ class Expr:
         quantile_pyexpr = parse_into_expression(quantile)
         return wrap_expr(self._pyexpr.quantile(quantile_pyexpr, interpolation))
 
+    def approx_quantile(self, quantile: float, accuracy: int = 100) -> Expr:
+        """
+        Approximate quantile value.
+
+        This is done using a t-digest, a sketch that can be computed in a single pass
+        and merged across partitions of the data, which makes it well suited for
+        large or streaming group-bys. The result interpolates linearly between the
+        values and is exact for small inputs.
+
+        Null and NaN values are ignored.
+
+        Parameters
+        ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+        accuracy
+            Compression of the t-digest. Higher values give more accurate estimates at
+            the cost of memory.
+
+        See Also
+        --------
+        quantile
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [0, 1, 2, 3, 4, 5]})
+        >>> df.select(pl.col("a").approx_quantile(0.3))
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 1.5 │
+        └─────┘
+        >>> df = pl.DataFrame({"g": ["a", "a", "b", "b", "b"], "v": [1, 2, 3, 4, 10]})
+        >>> df.group_by("g", maintain_order=True).agg(pl.col("v").approx_quantile(0.5))
+        shape: (2, 2)
+        ┌─────┬─────┐
+        │ g   ┆ v   │
+        │ --- ┆ --- │
+        │ str ┆ f64 │
+        ╞═════╪═════╡
+        │ a   ┆ 1.5 │
+        │ b   ┆ 4.0 │
+        └─────┴─────┘
+        """
+        return wrap_expr(self._pyexpr.approx_quantile(quantile, accuracy))
+
     @unstable()
     def cut(
         self,
@@ -0,0 +1,133 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from apollo._utils.parse import parse_into_expression
+from apollo._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+
+    from apollo import Expr
+    from apollo._typing import IntoExpr
+
+
+class ExprMapNameSpace:
+    """Namespace for map related expressions."""
+
+    _accessor = "map"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    def get(self, key: IntoExpr) -> Expr:
+        """
+        Get the value of `key` in every map.
+
+        Maps that do not hold the key give null. If a map holds the same key
+        multiple times, the value of the last entry is returned.
+
+        Parameters
+        ----------
+        key
+            The key to look up. Accepts expression input, strings are parsed as
+            literals.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"k": [["a", "b"], ["b"]], "v": [[1, 2], [3]]})
+        >>> m = pl.col("k").map.from_lists(pl.col("v"))
+        >>> df.select(m.map.get("a")).to_series().to_list()
+        [1, None]
+        """
+        key_pyexpr = parse_into_expression(key, str_as_lit=True)
+        return wrap_expr(self._pyexpr.map_get(key_pyexpr))
+
+    def keys(self) -> Expr:
+        """
+        Get the keys of every map as a list.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"k": [["a", "b"], ["b"]], "v": [[1, 2], [3]]})
+        >>> m = pl.col("k").map.from_lists(pl.col("v"))
+        >>> df.select(m.map.keys()).to_series().to_list()
+        [['a', 'b'], ['b']]
+        """
+        return wrap_expr(self._pyexpr.map_keys())
+
+    def values(self) -> Expr:
+        """
+        Get the values of every map as a list.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"k": [["a", "b"], ["b"]], "v": [[1, 2], [3]]})
+        >>> m = pl.col("k").map.from_lists(pl.col("v"))
+        >>> df.select(m.map.values()).to_series().to_list()
+        [[1, 2], [3]]
+        """
+        return wrap_expr(self._pyexpr.map_values())
+
+    def contains_key(self, key: IntoExpr) -> Expr:
+        """
+        Check whether every map holds `key`.
+
+        Parameters
+        ----------
+        key
+            The key to look for. Accepts expression input, strings are parsed as
+            literals.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"k": [["a", "b"], ["b"]], "v": [[1, 2], [3]]})
+        >>> m = pl.col("k").map.from_lists(pl.col("v"))
+        >>> df.select(m.map.contains_key("a")).to_series().to_list()
+        [True, False]
+        """
+        key_pyexpr = parse_into_expression(key, str_as_lit=True)
+        return wrap_expr(self._pyexpr.map_contains_key(key_pyexpr))
+
+    def from_lists(self, values: IntoExpr) -> Expr:
+        """
+        Build maps from this list of keys and a list of `values`.
+
+        The lists must be of equal length in every row and the keys cannot be
+        null. A row is null if either of the lists is null.
+
+        Parameters
+        ----------
+        values
+            The values of the maps. Accepts expression input, strings are parsed
+            as column names.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"k": [["a", "b"], ["b"]], "v": [[1, 2], [3]]})
+        >>> df.select(pl.col("k").map.from_lists("v")).schema["k"]
+        Map(String, Int64)
+        """
+        values_pyexpr = parse_into_expression(values)
+        return wrap_expr(self._pyexpr.map_from_lists(values_pyexpr))
+
+    def to_struct(self, keys: Sequence[str]) -> Expr:
+        """
+        Convert maps with `String` keys into a struct with a field per key.
+
+        Keys that are not in `keys` are dropped and keys that a map does not
+        hold give null.
+
+        Parameters
+        ----------
+        keys
+            The known keys, which become the fields of the struct.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"k": [["a", "b"], ["b"]], "v": [[1, 2], [3]]})
+        >>> m = pl.col("k").map.from_lists(pl.col("v"))
+        >>> df.select(m.map.to_struct(["a", "b"])).to_series().to_list()
+        [{'a': 1, 'b': 2}, {'a': None, 'b': 3}]
+        """
+        return wrap_expr(self._pyexpr.map_to_struct(list(keys)))
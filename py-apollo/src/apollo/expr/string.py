@@ -25,6 +25,7 @@ if TYPE_CHECKING:
     from apollo import Expr
     from apollo._typing import (
         Ambiguous,
+        HashAlgorithm,
         IntoExpr,
         IntoExprColumn,
         ApolloDataType,
@@ -377,6 +378,42 @@ class ExprStringNameSpace:
         """
         return wrap_expr(self._pyexpr.str_to_decimal(scale=scale))
 
+    @unstable()
+    def to_interval(self, *, strict: bool = True) -> Expr:
+        """
+        Convert a String column into an Interval column.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Strings are a sequence of number-unit pairs, such as `"1mo 2d 3h"`. Each
+        pair may carry its own sign, and verbose units such as `"1 year 2 months"`
+        are accepted as well.
+
+        Parameters
+        ----------
+        strict
+            Raise an error if a string can't be parsed. If set to `False`, such
+            strings are converted to null.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"interval": ["1mo 2d", "1y -12h", "3 weeks"]})
+        >>> df.with_columns(parsed=pl.col("interval").str.to_interval())
+        shape: (3, 2)
+        ┌──────────┬───────────┐
+        │ interval ┆ parsed    │
+        │ ---      ┆ ---       │
+        │ str      ┆ interval  │
+        ╞══════════╪═══════════╡
+        │ 1mo 2d   ┆ 1mo 2d    │
+        │ 1y -12h  ┆ 12mo -12h │
+        │ 3 weeks  ┆ 21d       │
+        └──────────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.str_to_interval(strict))
+
     def len_bytes(self) -> Expr:
         """
         Return the length of each string as the number of bytes.
@@ -2266,6 +2303,370 @@ class ExprStringNameSpace:
         """
         return wrap_expr(self._pyexpr.str_reverse())
 
+    def digest(self, algorithm: HashAlgorithm = "sha256", *, hex: bool = True) -> Expr:
+        """
+        Compute a stable digest of the UTF-8 bytes of each string.
+
+        Unlike :meth:`Expr.hash`, the output is fixed by the specification of the
+        algorithm and is the same on every platform and version.
+
+        Parameters
+        ----------
+        algorithm : {'md5', 'sha1', 'sha256', 'xxhash64', 'murmur3_32'}
+            The hash algorithm to use. `xxhash64` and `murmur3_32` (MurmurHash3
+            x86_32) use seed 0 and give their digest in big-endian byte order.
+        hex
+            Return the digests as lowercase hexadecimal strings instead of binary
+            values.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String` if `hex` is set, otherwise
+            :class:`Binary`.
+
+        See Also
+        --------
+        apollo.digest_rows
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"s": ["hello", None]})
+        >>> df.select(pl.col("s").str.digest("xxhash64")).to_series().to_list()
+        ['26c7827d889f6da3', None]
+        >>> df.select(pl.col("s").str.digest("md5", hex=False)).schema
+        Schema({'s': Binary})
+        """
+        return wrap_expr(self._pyexpr.str_digest(algorithm, hex))
+
+    def levenshtein_distance(
+        self, other: IntoExpr, *, max_distance: int | None = None
+    ) -> Expr:
+        """
+        Compute the Levenshtein distance to another string.
+
+        The distance is the minimum number of single character insertions,
+        deletions and substitutions needed to change one string into the other.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with. Accepts expression input, strings are
+            parsed as literals.
+        max_distance
+            Only compute distances up to this bound, larger distances are
+            reported as `max_distance + 1`. This is much faster when most
+            strings are far apart.
+
+        See Also
+        --------
+        damerau_levenshtein_distance
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": ["kitten", "flaw", None], "b": ["sitting", "lawn", "x"]}
+        ... )
+        >>> df.select(
+        ...     pl.col("a").str.levenshtein_distance(pl.col("b"))
+        ... ).to_series().to_list()
+        [3, 2, None]
+        >>> df.select(
+        ...     pl.col("a").str.levenshtein_distance(pl.col("b"), max_distance=1)
+        ... ).to_series().to_list()
+        [2, 2, None]
+        """
+        other_pyexpr = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(
+            self._pyexpr.str_levenshtein_distance(other_pyexpr, max_distance)
+        )
+
+    def damerau_levenshtein_distance(
+        self, other: IntoExpr, *, max_distance: int | None = None
+    ) -> Expr:
+        """
+        Compute the Damerau-Levenshtein distance to another string.
+
+        Like the Levenshtein distance, but swapping two adjacent characters
+        counts as a single edit.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with. Accepts expression input, strings are
+            parsed as literals.
+        max_distance
+            Only compute distances up to this bound, larger distances are
+            reported as `max_distance + 1`.
+
+        See Also
+        --------
+        levenshtein_distance
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": ["ca", "abcd"]})
+        >>> df.select(
+        ...     pl.col("a").str.damerau_levenshtein_distance(pl.Series(["abc", "acbd"]))
+        ... ).to_series().to_list()
+        [2, 1]
+        """
+        other_pyexpr = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(
+            self._pyexpr.str_damerau_levenshtein_distance(other_pyexpr, max_distance)
+        )
+
+    def hamming_distance(self, other: IntoExpr) -> Expr:
+        """
+        Compute the Hamming distance to another string.
+
+        The distance is the number of positions at which the characters differ,
+        strings of different lengths give null.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with. Accepts expression input, strings are
+            parsed as literals.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": ["karolin", "abc"]})
+        >>> df.select(pl.col("a").str.hamming_distance("kathrin")).to_series().to_list()
+        [3, None]
+        """
+        other_pyexpr = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_hamming_distance(other_pyexpr))
+
+    def jaro_winkler_similarity(self, other: IntoExpr) -> Expr:
+        """
+        Compute the Jaro-Winkler similarity to another string.
+
+        The similarity is between 0 (nothing in common) and 1 (equal strings),
+        and favours strings that share a prefix.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with. Accepts expression input, strings are
+            parsed as literals.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": ["martha", "dixon"], "b": ["marhta", "dicksonx"]})
+        >>> df.select(
+        ...     pl.col("a").str.jaro_winkler_similarity(pl.col("b")).round(4)
+        ... ).to_series().to_list()
+        [0.9611, 0.8133]
+        """
+        other_pyexpr = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_jaro_winkler_similarity(other_pyexpr))
+
+    def jaccard_similarity(self, other: IntoExpr, *, q: int = 2) -> Expr:
+        """
+        Compute the Jaccard similarity of the q-grams of another string.
+
+        The similarity is the number of distinct q-grams, substrings of `q`
+        characters, the strings have in common divided by the number of distinct
+        q-grams in either string. A string shorter than `q` is a single q-gram.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with. Accepts expression input, strings are
+            parsed as literals.
+        q
+            The number of characters in a q-gram.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": ["night", "nacht"]})
+        >>> df.select(
+        ...     pl.col("a").str.jaccard_similarity("nacht").round(4)
+        ... ).to_series().to_list()
+        [0.1429, 1.0]
+        """
+        other_pyexpr = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_jaccard_similarity(other_pyexpr, q))
+
+    def parse_url(self) -> Expr:
+        """
+        Parse URLs into their components.
+
+        URLs are split into their components following the generic syntax of
+        RFC 3986, without validating or normalizing them. Components that are
+        absent are null, except the path, which may be empty. The userinfo is
+        dropped, as is the port when it is not a valid port number.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Struct` with fields `scheme`, `host`,
+            `port` (:class:`UInt16`), `path`, `query` and `fragment`.
+
+        See Also
+        --------
+        url_query_param
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"url": ["https://apollo.rs:443/docs?v=1#top"]})
+        >>> df.select(pl.col("url").str.parse_url()).unnest("url").row(0)
+        ('https', 'apollo.rs', 443, '/docs', 'v=1', 'top')
+        """
+        return wrap_expr(self._pyexpr.str_parse_url())
+
+    def url_query_param(self, name: str) -> Expr:
+        """
+        Extract the value of a query parameter from URLs.
+
+        The query is decoded as `application/x-www-form-urlencoded`: `+` is
+        decoded as a space, and percent-encoded bytes are decoded as UTF-8. If a
+        parameter occurs more than once, the first value is returned.
+
+        Parameters
+        ----------
+        name
+            The name of the query parameter.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`. Null if the URL has no such
+            parameter, and an empty string if the parameter has no value.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"url": ["/search?q=hello+world&page=2", "/search?page=3", "/?q"]}
+        ... )
+        >>> df.select(pl.col("url").str.url_query_param("q")).to_series().to_list()
+        ['hello world', None, '']
+        """
+        return wrap_expr(self._pyexpr.str_url_query_param(name))
+
+    def ipv4_to_int(self) -> Expr:
+        """
+        Parse IPv4 addresses into integers.
+
+        Values that are not valid IPv4 addresses are null.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"ip": ["192.168.0.1", "::1", "10.0.0.256"]})
+        >>> df.select(pl.col("ip").str.ipv4_to_int()).to_series().to_list()
+        [3232235521, None, None]
+        """
+        return wrap_expr(self._pyexpr.str_ipv4_to_int())
+
+    def ipv6_to_int(self) -> Expr:
+        """
+        Parse IP addresses into 128-bit integers.
+
+        IPv4 addresses are mapped into the IPv6 address space as
+        `::ffff:a.b.c.d`. Values that are not valid IP addresses are null.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt128`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"ip": ["::1", "2001:db8::", "0.0.0.1"]})
+        >>> df.select(pl.col("ip").str.ipv6_to_int()).to_series().to_list()
+        [1, 42540766411282592856903984951653826560, 281470681743361]
+        """
+        return wrap_expr(self._pyexpr.str_ipv6_to_int())
+
+    def ip_to_binary(self) -> Expr:
+        """
+        Parse IP addresses into their bytes in network order.
+
+        IPv4 addresses give 4 bytes and IPv6 addresses give 16 bytes. Values that
+        are not valid IP addresses are null.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"ip": ["192.168.0.1", "::1", "nope"]})
+        >>> df.select(
+        ...     pl.col("ip").str.ip_to_binary().bin.encode("hex")
+        ... ).to_series().to_list()
+        ['c0a80001', '00000000000000000000000000000001', None]
+        """
+        return wrap_expr(self._pyexpr.str_ip_to_binary())
+
+    def ip_in_cidr(self, cidr: IntoExpr) -> Expr:
+        """
+        Check if IP addresses are in a network.
+
+        Addresses are never in a network of the other IP version. Values that are
+        not valid IP addresses give null.
+
+        Parameters
+        ----------
+        cidr
+            The network in CIDR notation, e.g. `"10.0.0.0/8"`. Accepts expression
+            input, strings are parsed as literals. A network without a prefix
+            length contains only the given address.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Raises
+        ------
+        InvalidOperationError
+            If a network is not in valid CIDR notation.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"ip": ["10.1.2.3", "192.168.0.1", "::1", "nope"]})
+        >>> df.select(pl.col("ip").str.ip_in_cidr("10.0.0.0/8")).to_series().to_list()
+        [True, False, False, None]
+        """
+        cidr_pyexpr = parse_into_expression(cidr, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_ip_in_cidr(cidr_pyexpr))
+
+    def ip_mask(self, ipv4_prefix: int = 24, ipv6_prefix: int = 48) -> Expr:
+        """
+        Mask IP addresses to their network.
+
+        Only the first bits of each address, the network prefix, are kept. This is
+        commonly used to anonymize IP addresses. Values that are not valid IP
+        addresses are null.
+
+        Parameters
+        ----------
+        ipv4_prefix
+            The number of bits to keep of IPv4 addresses, at most 32.
+        ipv6_prefix
+            The number of bits to keep of IPv6 addresses, at most 128.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"ip": ["192.168.123.45", "2001:db8:abcd:12::1"]})
+        >>> df.select(pl.col("ip").str.ip_mask()).to_series().to_list()
+        ['192.168.123.0', '2001:db8:abcd::']
+        """
+        return wrap_expr(self._pyexpr.str_ip_mask(ipv4_prefix, ipv6_prefix))
+
     def slice(
         self, offset: int | IntoExprColumn, length: int | IntoExprColumn | None = None
     ) -> Expr:
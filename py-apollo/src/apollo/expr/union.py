@@ -0,0 +1,63 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from apollo._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from apollo import Expr
+
+
+class ExprUnionNameSpace:
+    """Namespace for union related expressions."""
+
+    _accessor = "union"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    def tag(self) -> Expr:
+        """
+        Get the type id of the member selected in every row.
+
+        The type ids are the ones of the Arrow union the column was imported
+        from, null rows give null.
+
+        Examples
+        --------
+        >>> import pyarrow as pa
+        >>> arr = pa.UnionArray.from_sparse(
+        ...     pa.array([0, 1, 0], pa.int8()),
+        ...     [pa.array([1, None, 3]), pa.array([None, "b", None])],
+        ...     field_names=["i", "s"],
+        ... )
+        >>> df = pl.from_arrow(arr).to_frame("u")
+        >>> df.select(pl.col("u").union.tag()).to_series().to_list()
+        [0, 1, 0]
+        """
+        return wrap_expr(self._pyexpr.union_tag())
+
+    def field(self, name: str) -> Expr:
+        """
+        Get the values of the member `name`.
+
+        Rows where another member is selected give null.
+
+        Parameters
+        ----------
+        name
+            The name of the member.
+
+        Examples
+        --------
+        >>> import pyarrow as pa
+        >>> arr = pa.UnionArray.from_sparse(
+        ...     pa.array([0, 1, 0], pa.int8()),
+        ...     [pa.array([1, None, 3]), pa.array([None, "b", None])],
+        ...     field_names=["i", "s"],
+        ... )
+        >>> df = pl.from_arrow(arr).to_frame("u")
+        >>> df.select(pl.col("u").union.field("i")).to_series().to_list()
+        [1, None, 3]
+        """
+        return wrap_expr(self._pyexpr.union_field(name))
@@ -45,6 +45,7 @@ from apollo.functions.lazy import (
     cum_count,
     cum_fold,
     cum_reduce,
+    digest_rows,
     element,
     exclude,
     explain_all,
@@ -75,7 +76,7 @@ from apollo.functions.lazy import (
 )
 from apollo.functions.len import len
 from apollo.functions.lit import lit
-from apollo.functions.random import set_random_seed
+from apollo.functions.random import generate_uuid, set_random_seed
 from apollo.functions.range import (
     arange,
     date_range,
@@ -148,6 +149,7 @@ __all__ = [
     "cum_reduce",
     "date",  # named date_, see import above
     "datetime",  # named datetime_, see import above
+    "digest_rows",
     "duration",
     "exclude",
     "explain_all",
@@ -178,6 +180,7 @@ __all__ = [
     "rolling_cov",
     "row_index",
     "select",
+    "generate_uuid",
     "set_random_seed",
     "std",
     "struct",
@@ -44,6 +44,7 @@ if TYPE_CHECKING:
         CorrelationMethod,
         EngineType,
         EpochTimeUnit,
+        HashAlgorithm,
         IntoExpr,
         ApolloDataType,
         QuantileMethod,
@@ -1309,6 +1310,58 @@ def map_groups(
     )
 
 
+def digest_rows(
+    exprs: IntoExpr | Iterable[IntoExpr],
+    *more_exprs: IntoExpr,
+    algorithm: HashAlgorithm = "sha256",
+    hex: bool = True,
+) -> Expr:
+    """
+    Compute a deterministic digest of each row.
+
+    The digest is taken over the row encoding of the values, so rows only get the
+    same digest if their values and data types are equal. Extension types are
+    encoded through their comparison key. Nulls are part of the encoding, so a row
+    digest is never null. Unlike :meth:`DataFrame.hash_rows`, the output is the same
+    on every platform and in every process. The row encoding is internal and may
+    change between versions, so digests should not be persisted and compared across
+    versions.
+
+    Categorical columns are rejected, as their encoding depends on the order in which
+    the categories were seen. Cast them to String first.
+
+    Parameters
+    ----------
+    exprs
+        Column(s) to include in the digest. Accepts expression input. Strings are
+        parsed as column names.
+    *more_exprs
+        Additional columns to include, specified as positional arguments.
+    algorithm : {'md5', 'sha1', 'sha256', 'xxhash64', 'murmur3_32'}
+        The hash algorithm to use.
+    hex
+        Return the digests as lowercase hexadecimal strings instead of binary values.
+
+    See Also
+    --------
+    Expr.bin.digest
+    Expr.str.digest
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"a": [1, 2, 1], "b": ["x", None, "x"]})
+    >>> df.select(pl.digest_rows("a", "b").str.len_chars()).to_series().to_list()
+    [64, 64, 64]
+    >>> df.select(
+    ...     pl.digest_rows(pl.all(), algorithm="xxhash64").is_first_distinct()
+    ... ).to_series().to_list()
+    [True, True, False]
+    """
+    pyexprs = parse_into_list_of_expressions(exprs, *more_exprs)
+    pyexpr = plr.PyExpr.row_encode_unordered(pyexprs)
+    return wrap_expr(pyexpr.bin_digest(algorithm, hex))
+
+
 def _row_encode(
     exprs: pl.Selector | pl.Expr | Sequence[str | pl.Expr],
     *,
@@ -1,10 +1,22 @@
 from __future__ import annotations
 
 import contextlib
+from typing import TYPE_CHECKING
+
+from apollo import functions as F
+from apollo._utils.parse import parse_into_expression
+from apollo._utils.unstable import unstable
+from apollo._utils.wrap import wrap_expr
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
     import apollo._plr as plr
 
+if TYPE_CHECKING:
+    from typing import Literal
+
+    from apollo import Expr
+    from apollo._typing import IntoExpr
+
 
 def set_random_seed(seed: int) -> None:
     r"""
@@ -20,3 +32,36 @@ def set_random_seed(seed: int) -> None:
         random number generator.
     """
     plr.set_random_seed(seed)
+
+
+@unstable()
+def generate_uuid(n: IntoExpr | None = None, *, version: Literal[4, 7] = 4) -> Expr:
+    """
+    Generate a column of new UUIDs.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed at any
+        point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    n
+        The number of UUIDs to generate. Defaults to the number of rows in the
+        context.
+    version : {4, 7}
+        The UUID version. Version 4 UUIDs are random, version 7 UUIDs start with
+        a millisecond timestamp and sort in order of creation.
+
+    Returns
+    -------
+    Expr
+        Column of data type :class:`UUID`.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"a": [1, 2, 3]})
+    >>> df.with_columns(id=pl.generate_uuid(version=7)).schema
+    Schema({'a': Int64, 'id': UUID()})
+    """
+    n_pyexpr = parse_into_expression(F.len() if n is None else n)
+    return wrap_expr(plr.generate_uuid(n_pyexpr, version))
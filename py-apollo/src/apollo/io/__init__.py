@@ -1,8 +1,13 @@
 """Functions for reading data."""
 
-from apollo.io.avro import read_avro
+from apollo.io.avro import read_avro, scan_avro
 from apollo.io.clipboard import read_clipboard
-from apollo.io.csv import read_csv, read_csv_batched, scan_csv
+from apollo.io.csv import (
+    read_csv,
+    read_csv_batched,
+    scan_csv,
+    sniff_csv_dialect,
+)
 from apollo.io.database import read_database, read_database_uri
 from apollo.io.delta import read_delta, scan_delta
 from apollo.io.iceberg import scan_iceberg
@@ -10,7 +15,10 @@ from apollo.io.ipc import read_ipc, read_ipc_schema, read_ipc_stream, scan_ipc
 from apollo.io.json import read_json
 from apollo.io.lines import read_lines, scan_lines
 from apollo.io.ndjson import read_ndjson, scan_ndjson
+from apollo.io.orc import read_orc, scan_orc
 from apollo.io.parquet import (
+    invalidate_parquet_metadata_cache,
+    prewarm_parquet_metadata_cache,
     read_parquet,
     read_parquet_metadata,
     read_parquet_schema,
@@ -29,6 +37,8 @@ __all__ = [
     "defer",
     "FileProviderArgs",
     "PartitionBy",
+    "invalidate_parquet_metadata_cache",
+    "prewarm_parquet_metadata_cache",
     "read_avro",
     "read_clipboard",
     "read_csv",
@@ -44,16 +54,20 @@ __all__ = [
     "read_lines",
     "read_ndjson",
     "read_ods",
+    "read_orc",
     "read_parquet",
     "read_parquet_metadata",
     "read_parquet_schema",
+    "scan_avro",
     "scan_csv",
     "scan_delta",
     "scan_iceberg",
     "scan_ipc",
     "scan_lines",
     "scan_ndjson",
+    "scan_orc",
     "scan_parquet",
     "scan_pyarrow_dataset",
+    "sniff_csv_dialect",
     "ScanCastOptions",
 ]
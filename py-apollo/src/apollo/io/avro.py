@@ -2,17 +2,23 @@ from __future__ import annotations
 
 import contextlib
 from pathlib import Path
-from typing import IO, TYPE_CHECKING
+from typing import IO, TYPE_CHECKING, Literal
 
 from apollo._utils.various import normalize_filepath
-from apollo._utils.wrap import wrap_df
-from apollo.io._utils import parse_columns_arg
+from apollo._utils.wrap import wrap_df, wrap_ldf
+from apollo.io._utils import get_sources, parse_columns_arg
+from apollo.io.cloud.credential_provider._builder import (
+    _init_credential_provider_builder,
+)
+from apollo.io.scan_options._options import ScanOptions
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
-    from apollo._plr import PyDataFrame
+    from apollo._plr import PyDataFrame, PyLazyFrame
 
 if TYPE_CHECKING:
-    from apollo import DataFrame
+    from apollo import DataFrame, LazyFrame
+    from apollo._typing import SchemaDict, StorageOptionsDict
+    from apollo.io.cloud import CredentialProviderFunction
 
 
 def read_avro(
@@ -47,3 +53,119 @@ def read_avro(
 
     pydf = PyDataFrame.read_avro(source, column_names, projection, n_rows)
     return wrap_df(pydf)
+
+
+def scan_avro(
+    source: (
+        str
+        | Path
+        | IO[bytes]
+        | bytes
+        | list[str]
+        | list[Path]
+        | list[IO[bytes]]
+        | list[bytes]
+    ),
+    *,
+    n_rows: int | None = None,
+    cache: bool = True,
+    rechunk: bool = False,
+    row_index_name: str | None = None,
+    row_index_offset: int = 0,
+    glob: bool = True,
+    storage_options: StorageOptionsDict | None = None,
+    credential_provider: CredentialProviderFunction | Literal["auto"] | None = "auto",
+    hive_partitioning: bool | None = None,
+    hive_schema: SchemaDict | None = None,
+    try_parse_hive_dates: bool = True,
+    include_file_paths: str | None = None,
+) -> LazyFrame:
+    """
+    Lazily read from an Apache Avro file or multiple files via glob patterns.
+
+    The data blocks are streamed, and only the projected columns are decoded. Both
+    `deflate` and `snappy` compressed files are supported, the codec is read from
+    the file header.
+
+    Parameters
+    ----------
+    source
+        Path(s) to a file or directory
+        When needing to authenticate for scanning cloud locations, see the
+        `storage_options` parameter.
+    n_rows
+        Stop reading from Apache Avro file after reading `n_rows`.
+    cache
+        Cache the result after reading.
+    rechunk
+        Reallocate to contiguous memory when all chunks/ files are parsed.
+    row_index_name
+        If not None, this will insert a row index column with give name into the
+        DataFrame
+    row_index_offset
+        Offset to start the row index column (only use if the name is set)
+    glob
+        Expand path given via globbing rules.
+    storage_options
+        Options that indicate how to connect to a cloud provider.
+
+        If `storage_options` is not provided, Apollo will try to infer the information
+        from environment variables.
+    credential_provider
+        Provide a function that can be called to provide cloud storage
+        credentials. The function is expected to return a dictionary of
+        credential keys along with an optional credential expiry time.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    hive_partitioning
+        Infer statistics and schema from Hive partitioned URL and use them
+        to prune reads. This is unset by default (i.e. `None`), meaning it is
+        automatically enabled when a single directory is passed, and otherwise
+        disabled.
+    hive_schema
+        The column names and data types of the columns by which the data is partitioned.
+        If set to `None` (default), the schema of the Hive partitions is inferred.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    try_parse_hive_dates
+        Whether to try parsing hive values as date/datetime types.
+    include_file_paths
+        Include the path of the source file(s) as a column with this name.
+
+    Examples
+    --------
+    >>> pl.scan_avro("kafka/*.avro").select("key", "value")  # doctest: +SKIP
+    """
+    sources = get_sources(source)
+
+    credential_provider_builder = _init_credential_provider_builder(
+        credential_provider, sources, storage_options, "scan_avro"
+    )
+    del credential_provider
+
+    pylf = PyLazyFrame.new_from_avro(
+        sources=sources,
+        scan_options=ScanOptions(
+            row_index=(
+                (row_index_name, row_index_offset)
+                if row_index_name is not None
+                else None
+            ),
+            pre_slice=(0, n_rows) if n_rows is not None else None,
+            include_file_paths=include_file_paths,
+            glob=glob,
+            hive_partitioning=hive_partitioning,
+            hive_schema=hive_schema,
+            try_parse_hive_dates=try_parse_hive_dates,
+            rechunk=rechunk,
+            cache=cache,
+            storage_options=storage_options,
+            credential_provider=credential_provider_builder,
+        ),
+    )
+
+    return wrap_ldf(pylf)
@@ -1,9 +1,15 @@
 from apollo.io.csv.batched_reader import BatchedCsvReader
-from apollo.io.csv.functions import read_csv, read_csv_batched, scan_csv
+from apollo.io.csv.functions import (
+    read_csv,
+    read_csv_batched,
+    scan_csv,
+    sniff_csv_dialect,
+)
 
 __all__ = [
     "BatchedCsvReader",
     "read_csv",
     "read_csv_batched",
     "scan_csv",
+    "sniff_csv_dialect",
 ]
@@ -5,7 +5,7 @@ import os
 from collections.abc import Sequence
 from io import BytesIO, StringIO
 from pathlib import Path
-from typing import IO, TYPE_CHECKING, Literal
+from typing import IO, TYPE_CHECKING, Any, Literal
 
 import apollo._reexport as pl
 import apollo.functions as F
@@ -37,6 +37,7 @@ from apollo.io.csv.batched_reader import BatchedCsvReader
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
     from apollo._plr import PyDataFrame, PyLazyFrame
+    from apollo._plr import sniff_csv_dialect as _sniff_csv_dialect
 
 if TYPE_CHECKING:
     from collections.abc import Callable, Mapping
@@ -51,6 +52,20 @@ if TYPE_CHECKING:
     from apollo.io.cloud import CredentialProviderFunction
     from apollo.io.cloud.credential_provider._builder import CredentialProviderBuilder
 
+# Number of lines that are sampled by `sniff_dialect=True`.
+_SNIFF_SAMPLE_LINES = 100
+
+# Encodings that are decoded by the native CSV reader.
+_NATIVE_ENCODINGS = {
+    "utf8",
+    "utf8-lossy",
+    "latin1",
+    "windows-1252",
+    "shift_jis",
+    "utf-16-le",
+    "utf-16-be",
+}
+
 
 @deprecate_renamed_parameter("dtypes", "schema_overrides", version="0.20.31")
 @deprecate_renamed_parameter("row_count_name", "row_index_name", version="0.20.4")
@@ -93,6 +108,8 @@ def read_csv(
     truncate_ragged_lines: bool = False,
     decimal_comma: bool = False,
     glob: bool = True,
+    sniff_dialect: bool = False,
+    reject_rows: str | Path | None = None,
 ) -> DataFrame:
     r"""
     Read a CSV file into a DataFrame.
@@ -192,11 +209,12 @@ def read_csv(
         Stop reading from CSV file after reading `n_rows`.
         During multi-threaded parsing, an upper bound of `n_rows`
         rows cannot be guaranteed.
-    encoding : {'utf8', 'utf8-lossy', 'windows-1252', 'windows-1252-lossy', ...}
+    encoding : {'utf8', 'utf8-lossy', 'latin1', 'windows-1252', 'shift_jis', 'utf-16-le', 'utf-16-be', ...}
         Lossy means that invalid utf8 values are replaced with `�`
-        characters. When using other encodings than `utf8` or
-        `utf8-lossy`, the input is first decoded in memory with
-        python. Defaults to `utf8`.
+        characters. The listed encodings are decoded natively; a UTF-16
+        byte order mark takes precedence over the given encoding. Other
+        encodings (such as `windows-1252-lossy`) are first decoded in
+        memory with python. Defaults to `utf8`.
     low_memory
         Reduce memory pressure at the expense of performance.
     rechunk
@@ -240,6 +258,24 @@ def read_csv(
         Parse floats using a comma as the decimal separator instead of a period.
     glob
         Expand path given via globbing rules.
+    sniff_dialect
+        Detect `separator`, `quote_char`, `has_header` and `skip_lines` from the
+        first lines of the file, overriding the given values. Use
+        :func:`sniff_csv_dialect` to inspect and pin the detected dialect. An
+        error is raised if the detected separator is longer than a single byte.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    reject_rows
+        Leave rows that fail to parse out of the result and write them to a CSV file
+        at this path, instead of raising an error. The file has the columns
+        `file_path`, `line_number` (1-based), `raw_line` and `error`, and is
+        overwritten on every read. Cannot be combined with `ignore_errors`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
     Returns
     -------
@@ -311,6 +347,8 @@ def read_csv(
 
     if (
         use_pyarrow
+        and not sniff_dialect
+        and reject_rows is None
         and schema_overrides is None
         and n_rows is None
         and n_threads is None
@@ -471,7 +509,7 @@ def read_csv(
 
     # TODO: scan_csv doesn't support a "dtype slice" (i.e. list[DataType])
     schema_overrides_is_list = isinstance(schema_overrides, Sequence)
-    encoding_supported_in_lazy = encoding in {"utf8", "utf8-lossy"}
+    encoding_supported_in_lazy = encoding in _NATIVE_ENCODINGS
 
     new_streaming = (
         os.getenv("APOLLO_FORCE_NEW_STREAMING") == "1"
@@ -540,6 +578,8 @@ def read_csv(
             truncate_ragged_lines=truncate_ragged_lines,
             decimal_comma=decimal_comma,
             glob=glob,
+            sniff_dialect=sniff_dialect,
+            reject_rows=reject_rows,
         )
 
         if columns:
@@ -552,7 +592,7 @@ def read_csv(
     else:
         with prepare_file_arg(
             source,
-            encoding=encoding,
+            encoding=None if encoding_supported_in_lazy else encoding,
             use_pyarrow=False,
             raise_if_empty=raise_if_empty,
             storage_options=storage_options,
@@ -576,7 +616,7 @@ def read_csv(
                 infer_schema_length=infer_schema_length,
                 batch_size=batch_size,
                 n_rows=n_rows,
-                encoding=encoding if encoding == "utf8-lossy" else "utf8",
+                encoding=encoding if encoding_supported_in_lazy else "utf8",  # type: ignore[arg-type]
                 low_memory=low_memory,
                 rechunk=rechunk,
                 skip_rows_after_header=skip_rows_after_header,
@@ -587,6 +627,8 @@ def read_csv(
                 truncate_ragged_lines=truncate_ragged_lines,
                 decimal_comma=decimal_comma,
                 glob=glob,
+                sniff_dialect=sniff_dialect,
+                reject_rows=reject_rows,
             )
 
     if new_columns:
@@ -626,6 +668,8 @@ def _read_csv_impl(
     truncate_ragged_lines: bool = False,
     decimal_comma: bool = False,
     glob: bool = True,
+    sniff_dialect: bool = False,
+    reject_rows: str | Path | None = None,
 ) -> DataFrame:
     path: str | None
     if isinstance(source, (str, Path)):
@@ -691,6 +735,7 @@ def _read_csv_impl(
             truncate_ragged_lines=truncate_ragged_lines,
             decimal_comma=decimal_comma,
             glob=glob,
+            reject_rows=reject_rows,
         )
         if columns is None:
             return scan.collect()
@@ -736,6 +781,10 @@ def _read_csv_impl(
         truncate_ragged_lines=truncate_ragged_lines,
         decimal_comma=decimal_comma,
         schema=schema,
+        sniff_dialect=_SNIFF_SAMPLE_LINES if sniff_dialect else None,
+        reject_rows=(
+            normalize_filepath(reject_rows) if reject_rows is not None else None
+        ),
     )
     return wrap_df(pydf)
 
@@ -1118,6 +1167,8 @@ def scan_csv(
     retries: int | None = None,
     file_cache_ttl: int | None = None,
     include_file_paths: str | None = None,
+    sniff_dialect: bool = False,
+    reject_rows: str | Path | None = None,
 ) -> LazyFrame:
     r"""
     Lazily read from a CSV file or multiple files via glob patterns.
@@ -1200,9 +1251,11 @@ def scan_csv(
         `pl.String`.
     n_rows
         Stop reading from CSV file after reading `n_rows`.
-    encoding : {'utf8', 'utf8-lossy'}
+    encoding : {'utf8', 'utf8-lossy', 'latin1', 'windows-1252', 'shift_jis', 'utf-16-le', 'utf-16-be'}
         Lossy means that invalid utf8 values are replaced with `�`
-        characters. Defaults to "utf8".
+        characters. Other encodings are decoded to UTF-8 while reading; a
+        UTF-16 byte order mark takes precedence over the given encoding.
+        Defaults to "utf8".
     low_memory
         Reduce memory pressure at the expense of performance.
     rechunk
@@ -1246,6 +1299,10 @@ def scan_csv(
         * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
         * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
           `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+        * SFTP (`sftp://`): Accepts `username`, `password`, `private_key_path`, \
+          `private_key_passphrase`, `known_hosts_path` and `skip_host_key_check`. \
+          Only available if Apollo was built with the `sftp` feature.
+        * HTTP(S): Accepts `bearer_token`, or `username` and `password`.
 
         If `storage_options` is not provided, Apollo will try to infer the information
         from environment variables.
@@ -1271,6 +1328,26 @@ def scan_csv(
             Pass {"file_cache_ttl": n} via `storage_options` instead.
     include_file_paths
         Include the path of the source file(s) as a column with this name.
+    sniff_dialect
+        Detect `separator`, `quote_char`, `has_header` and `skip_lines` from the
+        first lines of the first file, overriding the given values. The detected
+        dialect is pinned into the query plan and applies to all files. Use
+        :func:`sniff_csv_dialect` to inspect the detected dialect. An error is
+        raised if the detected separator is longer than a single byte.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    reject_rows
+        Leave rows that fail to parse out of the result and write them to a CSV file
+        at this path, instead of raising an error. The file has the columns
+        `file_path`, `line_number` (1-based), `raw_line` and `error`, and is
+        overwritten every time the query is executed. Cannot be combined with
+        `ignore_errors`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
     Returns
     -------
@@ -1423,6 +1500,8 @@ def scan_csv(
         storage_options=storage_options,
         credential_provider=credential_provider_builder,
         include_file_paths=include_file_paths,
+        sniff_dialect=sniff_dialect,
+        reject_rows=reject_rows,
     )
 
 
@@ -1467,6 +1546,8 @@ def _scan_csv_impl(
     storage_options: StorageOptionsDict | None = None,
     credential_provider: CredentialProviderBuilder | None = None,
     include_file_paths: str | None = None,
+    sniff_dialect: bool = False,
+    reject_rows: str | Path | None = None,
 ) -> LazyFrame:
     dtype_list: list[tuple[str, ApolloDataType]] | None = None
     if schema_overrides is not None:
@@ -1516,5 +1597,81 @@ def _scan_csv_impl(
         cloud_options=storage_options,
         credential_provider=credential_provider,
         include_file_paths=include_file_paths,
+        sniff_dialect=_SNIFF_SAMPLE_LINES if sniff_dialect else None,
+        reject_rows=(
+            normalize_filepath(reject_rows) if reject_rows is not None else None
+        ),
     )
     return wrap_ldf(pylf)
+
+
+def sniff_csv_dialect(
+    source: str | Path | IO[bytes] | bytes,
+    *,
+    sample_lines: int = _SNIFF_SAMPLE_LINES,
+    encoding: CsvEncoding = "utf8",
+    eol_char: str = "\n",
+    comment_prefix: str | None = None,
+) -> dict[str, Any]:
+    r"""
+    Detect the dialect of a CSV file from its first lines.
+
+    Detects the separator (including tabs), the quote character, whether the
+    first row is a header, and the number of preamble lines before the header.
+    The result can be logged, and passed to :func:`read_csv` or :func:`scan_csv`
+    to pin the dialect.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    source
+        Path to a file or a file-like object, or the CSV data itself. Compressed
+        data is decompressed before sniffing.
+    sample_lines
+        Number of lines at the start of the file to sample.
+    encoding : {'utf8', 'utf8-lossy', 'latin1', 'windows-1252', 'shift_jis', 'utf-16-le', 'utf-16-be'}
+        Encoding of the file.
+    eol_char
+        Single byte end of line character.
+    comment_prefix
+        Lines starting with this prefix are ignored while sniffing.
+
+    Returns
+    -------
+    dict
+        Dictionary with the `separator`, `quote_char`, `has_header` and
+        `skip_lines` parameters of :func:`read_csv`. The separator can be longer
+        than a single byte, such as `||`; such data cannot be read with
+        :func:`read_csv`, and its header is not detected.
+
+    Examples
+    --------
+    >>> source = b"exported 2024-06-01\nid;name\n1;a\n2;b\n"
+    >>> dialect = pl.sniff_csv_dialect(source)
+    >>> dialect
+    {'separator': ';', 'quote_char': '"', 'has_header': True, 'skip_lines': 1}
+    >>> pl.read_csv(source, **dialect)
+    shape: (2, 2)
+    ┌─────┬──────┐
+    │ id  ┆ name │
+    │ --- ┆ ---  │
+    │ i64 ┆ str  │
+    ╞═════╪══════╡
+    │ 1   ┆ a    │
+    │ 2   ┆ b    │
+    └─────┴──────┘
+    """
+    _check_arg_is_1byte("eol_char", eol_char, can_be_empty=False)
+
+    if isinstance(source, (str, Path)):
+        source = normalize_filepath(source)
+    elif isinstance(source, BytesIO):
+        source = source.getvalue()
+
+    return _sniff_csv_dialect(
+        source, sample_lines, encoding, eol_char, comment_prefix
+    )
+
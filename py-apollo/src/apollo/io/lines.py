@@ -81,6 +81,10 @@ def read_lines(
         * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
         * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
           `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+        * SFTP (`sftp://`): Accepts `username`, `password`, `private_key_path`, \
+          `private_key_passphrase`, `known_hosts_path` and `skip_host_key_check`. \
+          Only available if Apollo was built with the `sftp` feature.
+        * HTTP(S): Accepts `bearer_token`, or `username` and `password`.
 
         If `storage_options` is not provided, Apollo will try to infer the information
         from environment variables.
@@ -183,6 +187,10 @@ def scan_lines(
         * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
         * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
           `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+        * SFTP (`sftp://`): Accepts `username`, `password`, `private_key_path`, \
+          `private_key_passphrase`, `known_hosts_path` and `skip_host_key_check`. \
+          Only available if Apollo was built with the `sftp` feature.
+        * HTTP(S): Accepts `bearer_token`, or `username` and `password`.
 
         If `storage_options` is not provided, Apollo will try to infer the information
         from environment variables.
@@ -51,6 +51,7 @@ def read_ndjson(
     retries: int | None = None,
     file_cache_ttl: int | None = None,
     include_file_paths: str | None = None,
+    reject_rows: str | Path | None = None,
 ) -> DataFrame:
     r"""
     Read into a DataFrame from a newline delimited JSON file.
@@ -104,6 +105,10 @@ def read_ndjson(
         * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
         * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
           `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+        * SFTP (`sftp://`): Accepts `username`, `password`, `private_key_path`, \
+          `private_key_passphrase`, `known_hosts_path` and `skip_host_key_check`. \
+          Only available if Apollo was built with the `sftp` feature.
+        * HTTP(S): Accepts `bearer_token`, or `username` and `password`.
 
         If `storage_options` is not provided, Apollo will try to infer the information
         from environment variables.
@@ -129,6 +134,15 @@ def read_ndjson(
             Pass {"file_cache_ttl": n} via `storage_options` instead.
     include_file_paths
         Include the path of the source file(s) as a column with this name.
+    reject_rows
+        Leave lines that fail to parse out of the result and write them to a CSV
+        file at this path, instead of raising an error. The file has the columns
+        `file_path`, `line_number` (1-based), `raw_line` and `error`, and is
+        overwritten on every read. Cannot be combined with `ignore_errors`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
     See Also
     --------
@@ -180,6 +194,7 @@ def read_ndjson(
         storage_options=storage_options,
         credential_provider=credential_provider_builder,  # type: ignore[arg-type]
         file_cache_ttl=file_cache_ttl,
+        reject_rows=reject_rows,
     ).collect()
 
 
@@ -213,6 +228,7 @@ def scan_ndjson(
     retries: int | None = None,
     file_cache_ttl: int | None = None,
     include_file_paths: str | None = None,
+    reject_rows: str | Path | None = None,
 ) -> LazyFrame:
     """
     Lazily read from a newline delimited JSON file or multiple files via glob patterns.
@@ -270,6 +286,10 @@ def scan_ndjson(
         * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
         * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
           `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+        * SFTP (`sftp://`): Accepts `username`, `password`, `private_key_path`, \
+          `private_key_passphrase`, `known_hosts_path` and `skip_host_key_check`. \
+          Only available if Apollo was built with the `sftp` feature.
+        * HTTP(S): Accepts `bearer_token`, or `username` and `password`.
 
         If `storage_options` is not provided, Apollo will try to infer the information
         from environment variables.
@@ -295,6 +315,16 @@ def scan_ndjson(
             Pass {"file_cache_ttl": n} via `storage_options` instead.
     include_file_paths
         Include the path of the source file(s) as a column with this name.
+    reject_rows
+        Leave lines that fail to parse out of the result and write them to a CSV
+        file at this path, instead of raising an error. The file has the columns
+        `file_path`, `line_number` (1-based), `raw_line` and `error`, and is
+        overwritten every time the query is executed. Cannot be combined with
+        `ignore_errors`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
     """
     sources: list[str] | list[Path] | list[IO[str]] | list[IO[bytes]] = []
     if isinstance(source, (str, Path)):
@@ -347,5 +377,8 @@ def scan_ndjson(
         include_file_paths=include_file_paths,
         cloud_options=storage_options,
         credential_provider=credential_provider_builder,
+        reject_rows=(
+            normalize_filepath(reject_rows) if reject_rows is not None else None
+        ),
     )
     return wrap_ldf(pylf)
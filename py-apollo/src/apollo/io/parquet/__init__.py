@@ -1,4 +1,6 @@
 from apollo.io.parquet.functions import (
+    invalidate_parquet_metadata_cache,
+    prewarm_parquet_metadata_cache,
     read_parquet,
     read_parquet_metadata,
     read_parquet_schema,
@@ -6,6 +8,8 @@ from apollo.io.parquet.functions import (
 )
 
 __all__ = [
+    "invalidate_parquet_metadata_cache",
+    "prewarm_parquet_metadata_cache",
     "read_parquet",
     "read_parquet_metadata",
     "read_parquet_schema",
@@ -30,6 +30,12 @@ from apollo.io.scan_options._options import ScanOptions
 
 with contextlib.suppress(ImportError):
     from apollo._plr import PyLazyFrame
+    from apollo._plr import (
+        invalidate_parquet_metadata_cache as _invalidate_parquet_metadata_cache,
+    )
+    from apollo._plr import (
+        prewarm_parquet_metadata_cache as _prewarm_parquet_metadata_cache,
+    )
     from apollo._plr import read_parquet_metadata as _read_parquet_metadata
 
 if TYPE_CHECKING:
@@ -154,6 +160,10 @@ def read_parquet(
         * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
         * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
           `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+        * SFTP (`sftp://`): Accepts `username`, `password`, `private_key_path`, \
+          `private_key_passphrase`, `known_hosts_path` and `skip_host_key_check`. \
+          Only available if Apollo was built with the `sftp` feature.
+        * HTTP(S): Accepts `bearer_token`, or `username` and `password`.
 
         If `storage_options` is not provided, Apollo will try to infer the information
         from environment variables.
@@ -406,6 +416,10 @@ def read_parquet_metadata(
         * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
         * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
           `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+        * SFTP (`sftp://`): Accepts `username`, `password`, `private_key_path`, \
+          `private_key_passphrase`, `known_hosts_path` and `skip_host_key_check`. \
+          Only available if Apollo was built with the `sftp` feature.
+        * HTTP(S): Accepts `bearer_token`, or `username` and `password`.
 
         If `storage_options` is not provided, Apollo will try to infer the information
         from environment variables.
@@ -449,6 +463,92 @@ def read_parquet_metadata(
     )
 
 
+def prewarm_parquet_metadata_cache(
+    source: str | Path | Sequence[str | Path],
+    *,
+    storage_options: StorageOptionsDict | None = None,
+    credential_provider: CredentialProviderFunction | Literal["auto"] | None = "auto",
+) -> None:
+    """
+    Load the metadata of Parquet files into the Parquet metadata cache.
+
+    Subsequent scans of these files reuse the cached metadata instead of fetching
+    and decoding the footers again, for as long as the files are unchanged. Files
+    whose metadata is already cached are skipped.
+
+    The cache must be enabled with :meth:`Config.set_parquet_metadata_cache_size`.
+
+    .. warning::
+        This functionality is considered **experimental**. It may be removed or
+        changed at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    source
+        Path(s) to the files. Glob patterns are not expanded.
+    storage_options
+        Options that indicate how to connect to a cloud provider.
+        See :func:`scan_parquet` for the supported keys.
+    credential_provider
+        Provide a function that can be called to provide cloud storage
+        credentials. The function is expected to return a dictionary of
+        credential keys along with an optional credential expiry time.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+    Examples
+    --------
+    >>> pl.Config.set_parquet_metadata_cache_size(64 * 1024**2)  # doctest: +SKIP
+    >>> pl.prewarm_parquet_metadata_cache(
+    ...     ["s3://bucket/a.parquet", "s3://bucket/b.parquet"]
+    ... )  # doctest: +SKIP
+    """
+    sources = [source] if isinstance(source, (str, Path)) else list(source)
+    sources = [normalize_filepath(s, check_not_directory=False) for s in sources]
+
+    if not sources:
+        return
+
+    credential_provider_builder = _init_credential_provider_builder(
+        credential_provider, sources[0], storage_options, "scan_parquet"
+    )
+    del credential_provider
+
+    _prewarm_parquet_metadata_cache(
+        sources,
+        storage_options=storage_options,
+        credential_provider=credential_provider_builder,
+    )
+
+
+def invalidate_parquet_metadata_cache(source: str | Path | None = None) -> None:
+    """
+    Remove entries from the Parquet metadata cache.
+
+    Changed files are detected automatically, so this is only needed to release
+    memory or to force the metadata to be fetched again.
+
+    .. warning::
+        This functionality is considered **experimental**. It may be removed or
+        changed at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    source
+        Path of the file whose metadata to remove. Removes all entries if `None`.
+
+    Examples
+    --------
+    >>> pl.invalidate_parquet_metadata_cache()
+    """
+    if source is not None:
+        source = normalize_filepath(source, check_not_directory=False)
+
+    _invalidate_parquet_metadata_cache(source)
+
+
 @deprecate_renamed_parameter("row_count_name", "row_index_name", version="0.20.4")
 @deprecate_renamed_parameter("row_count_offset", "row_index_offset", version="0.20.4")
 def scan_parquet(
@@ -576,6 +676,10 @@ def scan_parquet(
         * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
         * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
           `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+        * SFTP (`sftp://`): Accepts `username`, `password`, `private_key_path`, \
+          `private_key_passphrase`, `known_hosts_path` and `skip_host_key_check`. \
+          Only available if Apollo was built with the `sftp` feature.
+        * HTTP(S): Accepts `bearer_token`, or `username` and `password`.
 
         If `storage_options` is not provided, Apollo will try to infer the information
         from environment variables.
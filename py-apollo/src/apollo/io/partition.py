@@ -42,12 +42,39 @@ class PartitionBy:
         Expressions to partition by.
     include_key
         Include the partition key expression outputs in the output files.
+    num_buckets
+        Hash the `key` into this many buckets, and write one set of files per bucket
+        instead of one per distinct key. Rows are assigned to buckets with the hash
+        given by `bucket_hash`, and the files are named after their bucket such that
+        the output can be read as a bucketed table. The `partition_keys` passed to
+        `file_path_provider` hold the bucket number in a `bucket` column.
+    bucket_hash : {'spark', 'hive'}
+        The hash used to assign rows to buckets.
+
+        - `'spark'`: Spark's Murmur3 hash (default).
+        - `'hive'`: Hive's original hash of tables with `bucketing_version=1`. The
+          Murmur3 based hash of `bucketing_version=2`, the default since Hive 3, is
+          not supported.
+    sort_by
+        Sort the rows within every partition by these columns, so that every output
+        file is sorted. This gives readers tight statistics to skip data with.
+    sort_descending
+        Sort in descending order. Can be a single boolean or a sequence of booleans,
+        one per `sort_by` column.
+    sort_nulls_last
+        Place null values last. Can be a single boolean or a sequence of booleans,
+        one per `sort_by` column.
     max_rows_per_file
         Maximum number of rows to write for each file. Note that files may have
         less than this amount of rows.
     approximate_bytes_per_file
         Approximate number of bytes to write to each file. This is measured as
         the estimated size of the DataFrame in memory.
+    manifest_path
+        Write a JSON manifest of the written files to this path once the sink has
+        finished. It lists the path, partition key values, row count, size in bytes
+        and per-column `min`, `max` and `null_count` of every file, and can be
+        loaded with :func:`read_json`.
 
     Examples
     --------
@@ -65,6 +92,12 @@ class PartitionBy:
     ...     )
     ... )  # doctest: +SKIP
 
+    Split to 16 buckets by user id, with every file sorted by timestamp:
+
+    >>> pl.LazyFrame({"user_id": [1, 2, 3], "ts": [3, 2, 1]}).sink_parquet(
+    ...     pl.PartitionBy("data/", key="user_id", num_buckets=16, sort_by="ts")
+    ... )  # doctest: +SKIP
+
     Split to multiple files partitioned by year, with limits on individual file sizes:
 
     >>> pl.LazyFrame({"year": [2026, 2027, 1970], "month": [0, 0, 0]}).sink_parquet(
@@ -87,8 +120,14 @@ class PartitionBy:
         | None = None,
         key: str | Expr | Sequence[str | Expr] | Mapping[str, Expr] | None = None,
         include_key: bool | None = None,
+        num_buckets: int | None = None,
+        bucket_hash: Literal["spark", "hive"] | None = None,
+        sort_by: str | Expr | Sequence[str | Expr] | None = None,
+        sort_descending: bool | Sequence[bool] = False,
+        sort_nulls_last: bool | Sequence[bool] = False,
         max_rows_per_file: int | None = None,
         approximate_bytes_per_file: int | Literal["auto"] | None = "auto",
+        manifest_path: str | Path | None = None,
     ) -> None:
         msg = "`PartitionBy` functionality is considered unstable"
         issue_unstable_warning(msg)
@@ -109,6 +148,26 @@ class PartitionBy:
             msg = "cannot use 'include_key' without specifying 'key'"
             raise ValueError(msg)
 
+        if num_buckets is None and bucket_hash is not None:
+            msg = "cannot use 'bucket_hash' without specifying 'num_buckets'"
+            raise ValueError(msg)
+
+        if num_buckets is not None:
+            if key is None:
+                msg = "cannot use 'num_buckets' without specifying 'key'"
+                raise ValueError(msg)
+
+            if include_key is not None:
+                msg = (
+                    "cannot use 'include_key' with 'num_buckets', "
+                    "bucketed files always contain the key columns"
+                )
+                raise ValueError(msg)
+
+            if num_buckets <= 0:
+                msg = f"'num_buckets' must be greater than 0, got {num_buckets}"
+                raise ValueError(msg)
+
         base_path = str(base_path)
 
         if approximate_bytes_per_file == "auto":
@@ -124,8 +183,24 @@ class PartitionBy:
             file_path_provider=file_path_provider,
             key=_parse_to_pyexpr_list(key) if key is not None else None,
             include_key=include_key,
+            num_buckets=num_buckets,
+            bucket_hash=bucket_hash if bucket_hash is not None else "spark",
+            sort_by=(
+                parse_into_list_of_expressions(sort_by) if sort_by is not None else None
+            ),
+            sort_descending=(
+                [sort_descending]
+                if isinstance(sort_descending, bool)
+                else list(sort_descending)
+            ),
+            sort_nulls_last=(
+                [sort_nulls_last]
+                if isinstance(sort_nulls_last, bool)
+                else list(sort_nulls_last)
+            ),
             max_rows_per_file=max_rows_per_file,
             approximate_bytes_per_file=approximate_bytes_per_file,
+            manifest_path=str(manifest_path) if manifest_path is not None else None,
         )
 
 
@@ -157,8 +232,14 @@ class _PartitionByInner:
     )
     key: list[PyExpr] | None
     include_key: bool | None
+    num_buckets: int | None
+    bucket_hash: Literal["spark", "hive"]
+    sort_by: list[PyExpr] | None
+    sort_descending: list[bool]
+    sort_nulls_last: list[bool]
     max_rows_per_file: int | None
     approximate_bytes_per_file: int
+    manifest_path: str | None
 
 
 @dataclass
@@ -171,6 +252,7 @@ class _SinkOptions:
 
     mkdir: bool
     maintain_order: bool
+    atomic: bool = False
     sync_on_close: SyncOnCloseMethod | None = None
 
     # Cloud
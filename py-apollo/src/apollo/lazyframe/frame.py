@@ -129,8 +129,10 @@ if TYPE_CHECKING:
     from apollo._typing import (
         ArrowSchemaExportable,
         AsofJoinStrategy,
+        AvroCompression,
         ClosedInterval,
         ColumnNameOrSelector,
+        CsvEncoding,
         CsvQuoteStyle,
         EngineType,
         ExplainFormat,
@@ -144,6 +146,7 @@ if TYPE_CHECKING:
         JoinValidation,
         Label,
         MaintainOrderJoin,
+        OrcCompression,
         Orientation,
         ParquetMetadata,
         PivotAgg,
@@ -1406,6 +1409,57 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         else:
             return self._ldf.describe_plan()
 
+    @unstable()
+    def explain_analyze(
+        self,
+        *,
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> tuple[str, DataFrame]:
+        """
+        Run the query on the streaming engine and report metrics for every node.
+
+        The result of the query is discarded.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed at any
+            point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        optimizations
+            The optimization passes done during query optimization.
+
+        Returns
+        -------
+        tuple[str, DataFrame]
+            The physical plan in graphviz dot format, where the label of every node
+            shows the time spent in it and the number of rows and morsels it received
+            and sent, and a DataFrame with these metrics. The first row of the
+            DataFrame covers the whole query and holds its wall time and the peak
+            memory usage of the process in bytes. Times are in microseconds.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [1, 2, 3], "b": [4, 5, 6]})
+        >>> plan, metrics = lf.filter(pl.col("a") > 1).explain_analyze()
+        >>> metrics.select("node", "rows_received", "rows_sent")  # doctest: +SKIP
+        shape: (4, 3)
+        ┌───────────────────┬───────────────┬───────────┐
+        │ node              ┆ rows_received ┆ rows_sent │
+        │ ---               ┆ ---           ┆ ---       │
+        │ str               ┆ u64           ┆ u64       │
+        ╞═══════════════════╪═══════════════╪═══════════╡
+        │ query             ┆ null          ┆ null      │
+        │ in-memory-source  ┆ 0             ┆ 3         │
+        │ filter            ┆ 3             ┆ 2         │
+        │ in-memory-sink    ┆ 2             ┆ 0         │
+        └───────────────────┴───────────────┴───────────┘
+        """
+        optimizations = optimizations.__copy__()
+        ldf = self._ldf.with_optimizations(optimizations._pyoptflags)
+        plan, metrics = ldf.explain_analyze()
+        return plan, wrap_df(metrics)
+
     @deprecate_streaming_parameter()
     @forward_old_opt_flags()
     def show_graph(
@@ -2615,6 +2669,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int | None = None,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        atomic: bool = False,
         lazy: Literal[False] = ...,
         engine: EngineType = "auto",
         metadata: ParquetMetadata | None = None,
@@ -2640,6 +2695,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int | None = None,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        atomic: bool = False,
         lazy: Literal[True],
         engine: EngineType = "auto",
         metadata: ParquetMetadata | None = None,
@@ -2666,6 +2722,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         metadata: ParquetMetadata | None = None,
         arrow_schema: ArrowSchemaExportable | None = None,
         mkdir: bool = False,
+        atomic: bool = False,
         lazy: bool = False,
         engine: EngineType = "auto",
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
@@ -2732,6 +2789,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
             * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
             `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+            * SFTP (`sftp://`): Accepts `username`, `password`, `private_key_path`, \
+            `private_key_passphrase`, `known_hosts_path` and `skip_host_key_check`. \
+            Only available if Apollo was built with the `sftp` feature.
+            * HTTP(S): Accepts `bearer_token`, or `username` and `password`.
 
             If `storage_options` is not provided, Apollo will try to infer the
             information from environment variables.
@@ -2776,6 +2837,16 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         mkdir: bool
             Recursively create all the directories in the path.
 
+            .. warning::
+                This functionality is considered **unstable**. It may be changed at any
+                point without it being considered a breaking change.
+        atomic: bool
+            Write to a hidden staging location first, and only move the output
+            to `path` once all data was written successfully. If the query fails
+            or is cancelled, the staged output is removed, so that readers never
+            observe partially written files. Has no effect when writing to a
+            file-like object.
+
             .. warning::
                 This functionality is considered **unstable**. It may be changed at any
                 point without it being considered a breaking change.
@@ -2884,6 +2955,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         sink_options = _SinkOptions(
             mkdir=mkdir,
+            atomic=atomic,
             maintain_order=maintain_order,
             sync_on_close=sync_on_close,
             storage_options=storage_options,
@@ -3228,6 +3300,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int | None = None,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        atomic: bool = False,
         lazy: Literal[False] = ...,
         engine: EngineType = "auto",
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
@@ -3250,6 +3323,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int | None = None,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        atomic: bool = False,
         lazy: Literal[True],
         engine: EngineType = "auto",
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
@@ -3271,6 +3345,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int | None = None,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        atomic: bool = False,
         lazy: bool = False,
         engine: EngineType = "auto",
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
@@ -3315,6 +3390,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
             * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
             `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+            * SFTP (`sftp://`): Accepts `username`, `password`, `private_key_path`, \
+            `private_key_passphrase`, `known_hosts_path` and `skip_host_key_check`. \
+            Only available if Apollo was built with the `sftp` feature.
+            * HTTP(S): Accepts `bearer_token`, or `username` and `password`.
 
             If `storage_options` is not provided, Apollo will try to infer the
             information from environment variables.
@@ -3344,6 +3423,16 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         mkdir: bool
             Recursively create all the directories in the path.
 
+            .. warning::
+                This functionality is considered **unstable**. It may be changed at any
+                point without it being considered a breaking change.
+        atomic: bool
+            Write to a hidden staging location first, and only move the output
+            to `path` once all data was written successfully. If the query fails
+            or is cancelled, the staged output is removed, so that readers never
+            observe partially written files. Has no effect when writing to a
+            file-like object.
+
             .. warning::
                 This functionality is considered **unstable**. It may be changed at any
                 point without it being considered a breaking change.
@@ -3434,6 +3523,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         sink_options = _SinkOptions(
             mkdir=mkdir,
+            atomic=atomic,
             maintain_order=maintain_order,
             sync_on_close=sync_on_close,
             storage_options=storage_options,
@@ -3462,6 +3552,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         path: str | Path | IO[bytes] | IO[str] | PartitionBy,
         *,
         include_bom: bool = False,
+        encoding: CsvEncoding = "utf8",
         compression: Literal["uncompressed", "gzip", "zstd"] = "uncompressed",
         compression_level: int | None = None,
         check_extension: bool = True,
@@ -3486,6 +3577,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int | None = None,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        atomic: bool = False,
         lazy: Literal[False] = ...,
         engine: EngineType = "auto",
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
@@ -3497,6 +3589,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         path: str | Path | IO[bytes] | IO[str] | PartitionBy,
         *,
         include_bom: bool = False,
+        encoding: CsvEncoding = "utf8",
         compression: Literal["uncompressed", "gzip", "zstd"] = "uncompressed",
         compression_level: int | None = None,
         check_extension: bool = True,
@@ -3521,6 +3614,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int | None = None,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        atomic: bool = False,
         lazy: Literal[True],
         engine: EngineType = "auto",
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
@@ -3531,6 +3625,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         path: str | Path | IO[bytes] | IO[str] | PartitionBy,
         *,
         include_bom: bool = False,
+        encoding: CsvEncoding = "utf8",
         compression: Literal["uncompressed", "gzip", "zstd"] = "uncompressed",
         compression_level: int | None = None,
         check_extension: bool = True,
@@ -3555,6 +3650,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int | None = None,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        atomic: bool = False,
         lazy: bool = False,
         engine: EngineType = "auto",
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
@@ -3569,7 +3665,11 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         path
             File path to which the file should be written.
         include_bom
-            Whether to include UTF-8 BOM in the CSV output.
+            Whether to include the byte order mark of `encoding` in the CSV output.
+            Has no effect for encodings without a byte order mark.
+        encoding : {'utf8', 'latin1', 'windows-1252', 'shift_jis', 'utf-16-le', 'utf-16-be'}
+            Text encoding of the CSV output. Raises an error if a value cannot be
+            represented in the chosen encoding.
         compression
             What compression format to use.
 
@@ -3663,6 +3763,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
             * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
             `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+            * SFTP (`sftp://`): Accepts `username`, `password`, `private_key_path`, \
+            `private_key_passphrase`, `known_hosts_path` and `skip_host_key_check`. \
+            Only available if Apollo was built with the `sftp` feature.
+            * HTTP(S): Accepts `bearer_token`, or `username` and `password`.
 
             If `storage_options` is not provided, Apollo will try to infer the
             information from environment variables.
@@ -3692,6 +3796,16 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         mkdir: bool
             Recursively create all the directories in the path.
 
+            .. warning::
+                This functionality is considered **unstable**. It may be changed at any
+                point without it being considered a breaking change.
+        atomic: bool
+            Write to a hidden staging location first, and only move the output
+            to `path` once all data was written successfully. If the query fails
+            or is cancelled, the staged output is removed, so that readers never
+            observe partially written files. Has no effect when writing to a
+            file-like object.
+
             .. warning::
                 This functionality is considered **unstable**. It may be changed at any
                 point without it being considered a breaking change.
@@ -3773,6 +3887,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         sink_options = _SinkOptions(
             mkdir=mkdir,
+            atomic=atomic,
             maintain_order=maintain_order,
             sync_on_close=sync_on_close,
             storage_options=storage_options,
@@ -3783,6 +3898,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             target=target,
             sink_options=sink_options,
             include_bom=include_bom,
+            encoding=encoding,
             compression=compression,
             compression_level=compression_level,
             check_extension=check_extension,
@@ -3824,6 +3940,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int | None = None,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        atomic: bool = False,
         lazy: Literal[False] = ...,
         engine: EngineType = "auto",
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
@@ -3845,6 +3962,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int | None = None,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        atomic: bool = False,
         lazy: Literal[True],
         engine: EngineType = "auto",
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
@@ -3865,6 +3983,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int | None = None,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        atomic: bool = False,
         lazy: bool = False,
         engine: EngineType = "auto",
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
@@ -3919,6 +4038,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
             * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
             `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+            * SFTP (`sftp://`): Accepts `username`, `password`, `private_key_path`, \
+            `private_key_passphrase`, `known_hosts_path` and `skip_host_key_check`. \
+            Only available if Apollo was built with the `sftp` feature.
+            * HTTP(S): Accepts `bearer_token`, or `username` and `password`.
 
             If `storage_options` is not provided, Apollo will try to infer the
             information from environment variables.
@@ -3948,6 +4071,16 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         mkdir: bool
             Recursively create all the directories in the path.
 
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        atomic: bool
+            Write to a hidden staging location first, and only move the output
+            to `path` once all data was written successfully. If the query fails
+            or is cancelled, the staged output is removed, so that readers never
+            observe partially written files. Has no effect when writing to a
+            file-like object.
+
             .. warning::
                 This functionality is considered **unstable**. It may be changed
                 at any point without it being considered a breaking change.
@@ -4023,6 +4156,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         sink_options = _SinkOptions(
             mkdir=mkdir,
+            atomic=atomic,
             maintain_order=maintain_order,
             sync_on_close=sync_on_close,
             storage_options=storage_options,
@@ -4044,6 +4178,391 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             return None
         return LazyFrame._from_pyldf(ldf_py)
 
+    @overload
+    def sink_avro(
+        self,
+        path: str | Path | IO[bytes] | PartitionBy,
+        *,
+        compression: AvroCompression = "uncompressed",
+        name: str = "",
+        maintain_order: bool = True,
+        storage_options: StorageOptionsDict | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        sync_on_close: SyncOnCloseMethod | None = None,
+        mkdir: bool = False,
+        atomic: bool = False,
+        lazy: Literal[False] = ...,
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> None: ...
+
+    @overload
+    def sink_avro(
+        self,
+        path: str | Path | IO[bytes] | PartitionBy,
+        *,
+        compression: AvroCompression = "uncompressed",
+        name: str = "",
+        maintain_order: bool = True,
+        storage_options: StorageOptionsDict | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        sync_on_close: SyncOnCloseMethod | None = None,
+        mkdir: bool = False,
+        atomic: bool = False,
+        lazy: Literal[True],
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> LazyFrame: ...
+
+    def sink_avro(
+        self,
+        path: str | Path | IO[bytes] | PartitionBy,
+        *,
+        compression: AvroCompression = "uncompressed",
+        name: str = "",
+        maintain_order: bool = True,
+        storage_options: StorageOptionsDict | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        sync_on_close: SyncOnCloseMethod | None = None,
+        mkdir: bool = False,
+        atomic: bool = False,
+        lazy: bool = False,
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> LazyFrame | None:
+        """
+        Evaluate the query in streaming mode and write to an Apache Avro file.
+
+        This allows streaming results that are larger than RAM to be written to disk.
+        Every morsel is written as a separate data block.
+
+        Parameters
+        ----------
+        path
+            File path to which the file should be written.
+        compression : {'uncompressed', 'snappy', 'deflate'}
+            Compression method. Defaults to "uncompressed".
+        name
+            Schema name. Defaults to empty string.
+        maintain_order
+            Maintain the order in which data is processed.
+            Setting this to `False` will be slightly faster.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        storage_options
+            Options that indicate how to connect to a cloud provider.
+
+            If `storage_options` is not provided, Apollo will try to infer the
+            information from environment variables.
+        credential_provider
+            Provide a function that can be called to provide cloud storage
+            credentials. The function is expected to return a dictionary of
+            credential keys along with an optional credential expiry time.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        sync_on_close: { None, 'data', 'all' }
+            Sync to disk when before closing a file.
+
+            * `None` does not sync.
+            * `data` syncs the file contents.
+            * `all` syncs the file contents and metadata.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        mkdir: bool
+            Recursively create all the directories in the path.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        atomic: bool
+            Write to a hidden staging location first, and only move the output
+            to `path` once all data was written successfully. If the query fails
+            or is cancelled, the staged output is removed, so that readers never
+            observe partially written files. Has no effect when writing to a
+            file-like object.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        lazy: bool
+            Wait to start execution until `collect` is called.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        engine
+            Select the engine used to process the query, optional.
+            At the moment, if set to `"auto"` (default), the query is run
+            using the apollo streaming engine. Apollo will also
+            attempt to use the engine set by the `APOLLO_ENGINE_AFFINITY`
+            environment variable. If it cannot run the query using the
+            selected engine, the query is run using the apollo streaming
+            engine.
+        optimizations
+            The optimization passes done during query optimization.
+
+            This has no effect if `lazy` is set to `True`.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+
+        Returns
+        -------
+        DataFrame
+
+        Examples
+        --------
+        >>> lf = pl.scan_csv("/path/to/my_larger_than_ram_file.csv")  # doctest: +SKIP
+        >>> lf.sink_avro("out.avro", compression="snappy")  # doctest: +SKIP
+
+        See Also
+        --------
+        PartitionBy
+        """
+        engine = _select_engine(engine)
+
+        from apollo.io.cloud.credential_provider._builder import (
+            _init_credential_provider_builder,
+        )
+
+        credential_provider_builder = _init_credential_provider_builder(
+            credential_provider, path, storage_options, "sink_avro"
+        )
+        del credential_provider
+
+        target = _to_sink_target(path)
+
+        from apollo.io.partition import _SinkOptions
+
+        sink_options = _SinkOptions(
+            mkdir=mkdir,
+            atomic=atomic,
+            maintain_order=maintain_order,
+            sync_on_close=sync_on_close,
+            storage_options=storage_options,
+            credential_provider=credential_provider_builder,
+        )
+
+        ldf_py = self._ldf.sink_avro(
+            target=target,
+            compression=compression,
+            name=name,
+            sink_options=sink_options,
+        )
+
+        if not lazy:
+            ldf_py = ldf_py.with_optimizations(optimizations._pyoptflags)
+            ldf = LazyFrame._from_pyldf(ldf_py)
+            ldf.collect(engine=engine)
+            return None
+        return LazyFrame._from_pyldf(ldf_py)
+
+    @overload
+    def sink_orc(
+        self,
+        path: str | Path | IO[bytes] | PartitionBy,
+        *,
+        compression: OrcCompression = "zstd",
+        stripe_size: int | None = None,
+        maintain_order: bool = True,
+        storage_options: StorageOptionsDict | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        sync_on_close: SyncOnCloseMethod | None = None,
+        mkdir: bool = False,
+        atomic: bool = False,
+        lazy: Literal[False] = ...,
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> None: ...
+
+    @overload
+    def sink_orc(
+        self,
+        path: str | Path | IO[bytes] | PartitionBy,
+        *,
+        compression: OrcCompression = "zstd",
+        stripe_size: int | None = None,
+        maintain_order: bool = True,
+        storage_options: StorageOptionsDict | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        sync_on_close: SyncOnCloseMethod | None = None,
+        mkdir: bool = False,
+        atomic: bool = False,
+        lazy: Literal[True],
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> LazyFrame: ...
+
+    def sink_orc(
+        self,
+        path: str | Path | IO[bytes] | PartitionBy,
+        *,
+        compression: OrcCompression = "zstd",
+        stripe_size: int | None = None,
+        maintain_order: bool = True,
+        storage_options: StorageOptionsDict | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        sync_on_close: SyncOnCloseMethod | None = None,
+        mkdir: bool = False,
+        atomic: bool = False,
+        lazy: bool = False,
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> LazyFrame | None:
+        """
+        Evaluate the query in streaming mode and write to an Apache ORC file.
+
+        This allows streaming results that are larger than RAM to be written to disk.
+        Stripes are encoded in parallel, the file footer with the stripe statistics is
+        written once all stripes are written.
+
+        Parameters
+        ----------
+        path
+            File path to which the file should be written.
+        compression : {'uncompressed', 'zlib', 'snappy', 'zstd'}
+            Compression method of the column streams. Defaults to "zstd".
+        stripe_size
+            Maximum number of rows per stripe. Defaults to 1024^2 rows.
+        maintain_order
+            Maintain the order in which data is processed.
+            Setting this to `False` will be slightly faster.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        storage_options
+            Options that indicate how to connect to a cloud provider.
+
+            If `storage_options` is not provided, Apollo will try to infer the
+            information from environment variables.
+        credential_provider
+            Provide a function that can be called to provide cloud storage
+            credentials. The function is expected to return a dictionary of
+            credential keys along with an optional credential expiry time.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        sync_on_close: { None, 'data', 'all' }
+            Sync to disk when before closing a file.
+
+            * `None` does not sync.
+            * `data` syncs the file contents.
+            * `all` syncs the file contents and metadata.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        mkdir: bool
+            Recursively create all the directories in the path.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        atomic: bool
+            Write to a hidden staging location first, and only move the output
+            to `path` once all data was written successfully. If the query fails
+            or is cancelled, the staged output is removed, so that readers never
+            observe partially written files. Has no effect when writing to a
+            file-like object.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        lazy: bool
+            Wait to start execution until `collect` is called.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        engine
+            Select the engine used to process the query, optional.
+            At the moment, if set to `"auto"` (default), the query is run
+            using the apollo streaming engine. Apollo will also
+            attempt to use the engine set by the `APOLLO_ENGINE_AFFINITY`
+            environment variable. If it cannot run the query using the
+            selected engine, the query is run using the apollo streaming
+            engine.
+        optimizations
+            The optimization passes done during query optimization.
+
+            This has no effect if `lazy` is set to `True`.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+
+        Returns
+        -------
+        DataFrame
+
+        Examples
+        --------
+        >>> lf = pl.scan_csv("/path/to/my_larger_than_ram_file.csv")  # doctest: +SKIP
+        >>> lf.sink_orc("out.orc", compression="zlib")  # doctest: +SKIP
+
+        See Also
+        --------
+        PartitionBy
+        """
+        engine = _select_engine(engine)
+
+        from apollo.io.cloud.credential_provider._builder import (
+            _init_credential_provider_builder,
+        )
+
+        credential_provider_builder = _init_credential_provider_builder(
+            credential_provider, path, storage_options, "sink_orc"
+        )
+        del credential_provider
+
+        target = _to_sink_target(path)
+
+        from apollo.io.partition import _SinkOptions
+
+        sink_options = _SinkOptions(
+            mkdir=mkdir,
+            atomic=atomic,
+            maintain_order=maintain_order,
+            sync_on_close=sync_on_close,
+            storage_options=storage_options,
+            credential_provider=credential_provider_builder,
+        )
+
+        ldf_py = self._ldf.sink_orc(
+            target=target,
+            compression=compression,
+            stripe_size=stripe_size,
+            sink_options=sink_options,
+        )
+
+        if not lazy:
+            ldf_py = ldf_py.with_optimizations(optimizations._pyoptflags)
+            ldf = LazyFrame._from_pyldf(ldf_py)
+            ldf.collect(engine=engine)
+            return None
+        return LazyFrame._from_pyldf(ldf_py)
+
     @overload
     def sink_batches(
         self,
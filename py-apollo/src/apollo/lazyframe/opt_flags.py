@@ -43,6 +43,8 @@ class QueryOptFlags:
         collapse_joins: None | bool = None,
         check_order_observe: None | bool = None,
         fast_projection: None | bool = None,
+        join_reorder: None | bool = None,
+        result_cache: None | bool = None,
     ) -> None:
         self._pyoptflags = PyOptFlags.default()
         self.update(
@@ -56,6 +58,8 @@ class QueryOptFlags:
             collapse_joins=collapse_joins,
             check_order_observe=check_order_observe,
             fast_projection=fast_projection,
+            join_reorder=join_reorder,
+            result_cache=result_cache,
         )
 
     @classmethod
@@ -77,6 +81,8 @@ class QueryOptFlags:
         collapse_joins: None | bool = None,
         check_order_observe: None | bool = None,
         fast_projection: None | bool = None,
+        join_reorder: None | bool = None,
+        result_cache: None | bool = None,
     ) -> QueryOptFlags:
         """Create new empty set off optimizations."""
         optflags = QueryOptFlags()
@@ -92,6 +98,8 @@ class QueryOptFlags:
             collapse_joins=collapse_joins,
             check_order_observe=check_order_observe,
             fast_projection=fast_projection,
+            join_reorder=join_reorder,
+            result_cache=result_cache,
         )
 
     def update(
@@ -107,6 +115,8 @@ class QueryOptFlags:
         collapse_joins: None | bool = None,
         check_order_observe: None | bool = None,
         fast_projection: None | bool = None,
+        join_reorder: None | bool = None,
+        result_cache: None | bool = None,
     ) -> QueryOptFlags:
         """Update the current optimization flags."""
         if predicate_pushdown is not None:
@@ -135,6 +145,10 @@ class QueryOptFlags:
             self.check_order_observe = check_order_observe
         if fast_projection is not None:
             self.fast_projection = fast_projection
+        if join_reorder is not None:
+            self.join_reorder = join_reorder
+        if result_cache is not None:
+            self.result_cache = result_cache
 
         return self
 
@@ -238,6 +252,29 @@ class QueryOptFlags:
     def fast_projection(self, value: bool) -> None:
         self._pyoptflags.fast_projection = value
 
+    @property
+    def join_reorder(self) -> bool:
+        """Reorder trees of inner joins based on the estimated cardinalities of their inputs."""  # noqa: W505
+        return self._pyoptflags.join_reorder
+
+    @join_reorder.setter
+    def join_reorder(self, value: bool) -> None:
+        self._pyoptflags.join_reorder = value
+
+    @property
+    def result_cache(self) -> bool:
+        """
+        Look up and store subplan results in the cross-query result cache.
+
+        This has no effect unless the cache is enabled with
+        :meth:`Config.set_result_cache_size`.
+        """
+        return self._pyoptflags.result_cache
+
+    @result_cache.setter
+    def result_cache(self, value: bool) -> None:
+        self._pyoptflags.result_cache = value
+
     def __str__(self) -> str:
         return f"""
 QueryOptFlags {{
@@ -253,6 +290,8 @@ QueryOptFlags {{
     cluster_with_columns: {self.cluster_with_columns}
     check_order_observe: {self.check_order_observe}
     fast_projection: {self.fast_projection}
+    join_reorder: {self.join_reorder}
+    result_cache: {self.result_cache}
 
     eager: {self._pyoptflags.eager}
     streaming: {self._pyoptflags.streaming}
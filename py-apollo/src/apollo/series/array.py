@@ -11,7 +11,7 @@ if TYPE_CHECKING:
 
     from apollo import Series
     from apollo._plr import PySeries
-    from apollo._typing import IntoExpr, IntoExprColumn
+    from apollo._typing import IntoExpr, IntoExprColumn, VectorMetric
     from apollo.expr.expr import Expr
 
 
@@ -707,6 +707,165 @@ class ArrayNameSpace:
 
         """
 
+    def dot(self, other: IntoExpr) -> Series:
+        """
+        Compute the dot product of every subarray with the subarray of `other`.
+
+        Arrays of :class:`Float32` are computed in `Float32`, all other numeric
+        arrays in `Float64`. Subarrays that are null or contain a null give null.
+
+        Parameters
+        ----------
+        other
+            An array Series of the same width, or a single vector such as a list
+            of numbers, which is compared with every subarray.
+
+        Notes
+        -----
+        Element-wise arithmetic between arrays uses the regular operators, for
+        example `s - other`.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1.0, 2.0], [3.0, 4.0]], dtype=pl.Array(pl.Float64, 2))
+        >>> s.arr.dot([2.0, 1.0])
+        shape: (2,)
+        Series: 'a' [f64]
+        [
+            4.0
+            10.0
+        ]
+        """
+
+    def cosine_similarity(self, other: IntoExpr) -> Series:
+        """
+        Compute the cosine similarity of every subarray with the subarray of `other`.
+
+        The cosine similarity is the dot product of the vectors divided by the
+        product of their lengths. It is NaN if either vector has zero length.
+
+        Parameters
+        ----------
+        other
+            An array Series of the same width, or a single vector such as a list
+            of numbers, which is compared with every subarray.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[3.0, 4.0], [0.0, 2.0]], dtype=pl.Array(pl.Float64, 2))
+        >>> s.arr.cosine_similarity([1.0, 0.0])
+        shape: (2,)
+        Series: 'a' [f64]
+        [
+            0.6
+            0.0
+        ]
+        """
+
+    def euclidean_distance(self, other: IntoExpr) -> Series:
+        """
+        Compute the Euclidean (L2) distance between every subarray and `other`.
+
+        Parameters
+        ----------
+        other
+            An array Series of the same width, or a single vector such as a list
+            of numbers, which is compared with every subarray.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[3.0, 4.0], [0.0, 1.0]], dtype=pl.Array(pl.Float64, 2))
+        >>> s.arr.euclidean_distance([0.0, 0.0])
+        shape: (2,)
+        Series: 'a' [f64]
+        [
+            5.0
+            1.0
+        ]
+        """
+
+    def manhattan_distance(self, other: IntoExpr) -> Series:
+        """
+        Compute the Manhattan (L1) distance between every subarray and `other`.
+
+        Parameters
+        ----------
+        other
+            An array Series of the same width, or a single vector such as a list
+            of numbers, which is compared with every subarray.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[3.0, 4.0], [0.0, 1.0]], dtype=pl.Array(pl.Float64, 2))
+        >>> s.arr.manhattan_distance([0.0, 0.0])
+        shape: (2,)
+        Series: 'a' [f64]
+        [
+            7.0
+            1.0
+        ]
+        """
+
+    def normalize(self) -> Series:
+        """
+        Scale every subarray to unit (L2) length.
+
+        Subarrays of zero length give NaN values.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[3.0, 4.0], [0.0, 2.0]], dtype=pl.Array(pl.Float64, 2))
+        >>> s.arr.normalize()
+        shape: (2,)
+        Series: 'a' [array[f64, 2]]
+        [
+            [0.6, 0.8]
+            [0.0, 1.0]
+        ]
+        """
+
+    def nearest(
+        self,
+        query: IntoExpr,
+        k: int | IntoExprColumn = 5,
+        *,
+        metric: VectorMetric = "cosine",
+    ) -> Series:
+        """
+        Get the row indices of the `k` subarrays closest to `query`.
+
+        The indices are sorted from closest to farthest. For the `"dot"` and
+        `"cosine"` metrics the closest subarrays are those with the largest
+        value, for the distance metrics those with the smallest. Subarrays with a
+        null or NaN score are never returned, so fewer than `k` indices are
+        returned if there aren't enough valid subarrays.
+
+        Parameters
+        ----------
+        query
+            The vector to search for, such as a list of numbers.
+        k
+            Number of indices to return.
+        metric : {'cosine', 'dot', 'euclidean', 'manhattan'}
+            How to measure the closeness of two vectors.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "a", [[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]], dtype=pl.Array(pl.Float64, 2)
+        ... )
+        >>> s.arr.nearest([1.0, 0.1], k=2)
+        shape: (2,)
+        Series: 'a' [u32]
+        [
+            0
+            2
+        ]
+        """
+        s = wrap_s(self._s)
+        expr = F.col(s.name).arr.nearest(query, k, metric=metric)
+        return s.to_frame().select(expr).to_series().alias(s.name)
+
     def to_struct(
         self,
         fields: Callable[[int], str] | Sequence[str] | None = None,
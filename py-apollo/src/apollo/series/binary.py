@@ -9,6 +9,7 @@ if TYPE_CHECKING:
     from apollo._plr import PySeries
     from apollo._typing import (
         Endianness,
+        HashAlgorithm,
         IntoExpr,
         ApolloDataType,
         SizeUnit,
@@ -192,6 +193,37 @@ class BinaryNameSpace:
         ]
         """
 
+    def digest(
+        self, algorithm: HashAlgorithm = "sha256", *, hex: bool = True
+    ) -> Series:
+        r"""
+        Compute a stable digest of each binary value.
+
+        Unlike :meth:`Series.hash`, the output is fixed by the specification of the
+        algorithm and is the same on every platform and version.
+
+        Parameters
+        ----------
+        algorithm : {'md5', 'sha1', 'sha256', 'xxhash64', 'murmur3_32'}
+            The hash algorithm to use. `xxhash64` and `murmur3_32` (MurmurHash3
+            x86_32) use seed 0 and give their digest in big-endian byte order.
+        hex
+            Return the digests as lowercase hexadecimal strings instead of binary
+            values.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String` if `hex` is set, otherwise
+            :class:`Binary`.
+
+        Examples
+        --------
+        >>> s = pl.Series("data", [b"abc", None])
+        >>> s.bin.digest("sha1").to_list()
+        ['a9993e364706816aba3e25717850c26c9cd0d89d', None]
+        """
+
     def size(self, unit: SizeUnit = "b") -> Series:
         r"""
         Get the size of the binary values in a Series in the given unit.
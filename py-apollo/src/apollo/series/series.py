@@ -81,6 +81,7 @@ from apollo.datatypes import (
     Float64,
     Int32,
     Int64,
+    Interval,
     List,
     Null,
     Object,
@@ -1226,6 +1227,9 @@ class Series:
             return F.lit(self) + other
         if self.dtype.is_decimal() and isinstance(other, (float, int)):
             return self.to_frame().select(F.col(self.name) + other).to_series()
+        if isinstance(other, Series) and Interval in (self.dtype, other.dtype):
+            # calendar-aware interval arithmetic is resolved by the query engine
+            return self.to_frame().select(F.col(self.name) + F.lit(other)).to_series()
         return self._arithmetic(other, "add", "add_<>")
 
     @overload
@@ -1239,6 +1243,9 @@ class Series:
             return F.lit(self) - other
         if self.dtype.is_decimal() and isinstance(other, (float, int)):
             return self.to_frame().select(F.col(self.name) - other).to_series()
+        if isinstance(other, Series) and Interval in (self.dtype, other.dtype):
+            # calendar-aware interval arithmetic is resolved by the query engine
+            return self.to_frame().select(F.col(self.name) - F.lit(other)).to_series()
         return self._arithmetic(other, "sub", "sub_<>")
 
     def _recursive_cast_to_dtype(self, leaf_dtype: ApolloDataType) -> Series:
@@ -2441,6 +2448,32 @@ class Series:
         """  # noqa: W505
         return self._s.quantile(quantile, interpolation)
 
+    def approx_quantile(self, quantile: float, accuracy: int = 100) -> float | None:
+        """
+        Get an approximate quantile value of this Series.
+
+        This is done using a t-digest, see :meth:`Expr.approx_quantile`.
+
+        Parameters
+        ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+        accuracy
+            Compression of the t-digest. Higher values give more accurate estimates at
+            the cost of memory.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 2, 3, 4])
+        >>> s.approx_quantile(0.5)
+        2.5
+        """
+        return (
+            self.to_frame()
+            .select_seq(F.col(self.name).approx_quantile(quantile, accuracy))
+            .item()
+        )
+
     def to_dummies(
         self,
         *,
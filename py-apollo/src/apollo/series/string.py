@@ -21,6 +21,7 @@ if TYPE_CHECKING:
     from apollo._plr import PySeries
     from apollo._typing import (
         Ambiguous,
+        HashAlgorithm,
         IntoExpr,
         IntoExprColumn,
         ApolloDataType,
@@ -400,6 +401,38 @@ class StringNameSpace:
                 self._s.str_to_decimal_infer(inference_length=inference_length)
             )
 
+    @unstable()
+    def to_interval(self, *, strict: bool = True) -> Series:
+        """
+        Convert a String column into an Interval column.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Strings are a sequence of number-unit pairs, such as `"1mo 2d 3h"`. Each
+        pair may carry its own sign, and verbose units such as `"1 year 2 months"`
+        are accepted as well.
+
+        Parameters
+        ----------
+        strict
+            Raise an error if a string can't be parsed. If set to `False`, such
+            strings are converted to null.
+
+        Examples
+        --------
+        >>> s = pl.Series(["1mo 2d", "1y -12h", "3 weeks"])
+        >>> s.str.to_interval()
+        shape: (3,)
+        Series: '' [interval]
+        [
+            1mo 2d
+            12mo -12h
+            21d
+        ]
+        """
+
     def len_bytes(self) -> Series:
         """
         Return the length of each string as the number of bytes.
@@ -1719,6 +1752,284 @@ class StringNameSpace:
         ]
         """
 
+    def digest(
+        self, algorithm: HashAlgorithm = "sha256", *, hex: bool = True
+    ) -> Series:
+        """
+        Compute a stable digest of the UTF-8 bytes of each string.
+
+        Unlike :meth:`Series.hash`, the output is fixed by the specification of the
+        algorithm and is the same on every platform and version.
+
+        Parameters
+        ----------
+        algorithm : {'md5', 'sha1', 'sha256', 'xxhash64', 'murmur3_32'}
+            The hash algorithm to use. `xxhash64` and `murmur3_32` (MurmurHash3
+            x86_32) use seed 0 and give their digest in big-endian byte order.
+        hex
+            Return the digests as lowercase hexadecimal strings instead of binary
+            values.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String` if `hex` is set, otherwise
+            :class:`Binary`.
+
+        Examples
+        --------
+        >>> s = pl.Series("s", ["hello", None])
+        >>> s.str.digest("md5").to_list()
+        ['5d41402abc4b2a76b9719d911017c592', None]
+        """
+
+    def levenshtein_distance(
+        self, other: IntoExpr, *, max_distance: int | None = None
+    ) -> Series:
+        """
+        Compute the Levenshtein distance to another string.
+
+        The distance is the minimum number of single character insertions,
+        deletions and substitutions needed to change one string into the other.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with. Accepts expression input, strings are
+            parsed as literals.
+        max_distance
+            Only compute distances up to this bound, larger distances are
+            reported as `max_distance + 1`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["kitten", "flaw", None])
+        >>> s.str.levenshtein_distance(pl.Series(["sitting", "lawn", "x"])).to_list()
+        [3, 2, None]
+        """
+
+    def damerau_levenshtein_distance(
+        self, other: IntoExpr, *, max_distance: int | None = None
+    ) -> Series:
+        """
+        Compute the Damerau-Levenshtein distance to another string.
+
+        Like the Levenshtein distance, but swapping two adjacent characters
+        counts as a single edit.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with. Accepts expression input, strings are
+            parsed as literals.
+        max_distance
+            Only compute distances up to this bound, larger distances are
+            reported as `max_distance + 1`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["ca", "abcd"])
+        >>> s.str.damerau_levenshtein_distance(pl.Series(["abc", "acbd"])).to_list()
+        [2, 1]
+        """
+
+    def hamming_distance(self, other: IntoExpr) -> Series:
+        """
+        Compute the Hamming distance to another string.
+
+        Strings of different lengths give null.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with. Accepts expression input, strings are
+            parsed as literals.
+
+        Examples
+        --------
+        >>> s = pl.Series(["karolin", "abc"])
+        >>> s.str.hamming_distance("kathrin").to_list()
+        [3, None]
+        """
+
+    def jaro_winkler_similarity(self, other: IntoExpr) -> Series:
+        """
+        Compute the Jaro-Winkler similarity to another string.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with. Accepts expression input, strings are
+            parsed as literals.
+
+        Examples
+        --------
+        >>> s = pl.Series(["martha", "dixon"])
+        >>> s.str.jaro_winkler_similarity(pl.Series(["marhta", "dicksonx"])).round(4)
+        shape: (2,)
+        Series: '' [f64]
+        [
+            0.9611
+            0.8133
+        ]
+        """
+
+    def jaccard_similarity(self, other: IntoExpr, *, q: int = 2) -> Series:
+        """
+        Compute the Jaccard similarity of the q-grams of another string.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with. Accepts expression input, strings are
+            parsed as literals.
+        q
+            The number of characters in a q-gram.
+
+        Examples
+        --------
+        >>> s = pl.Series(["night", "nacht"])
+        >>> s.str.jaccard_similarity("nacht").round(4).to_list()
+        [0.1429, 1.0]
+        """
+
+    def parse_url(self) -> Series:
+        """
+        Parse URLs into their components.
+
+        Components that are absent are null, except the path, which may be empty.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Struct` with fields `scheme`, `host`,
+            `port` (:class:`UInt16`), `path`, `query` and `fragment`.
+
+        Examples
+        --------
+        >>> s = pl.Series("url", ["https://apollo.rs:443/docs?v=1#top"])
+        >>> s.str.parse_url().struct.unnest().row(0)
+        ('https', 'apollo.rs', 443, '/docs', 'v=1', 'top')
+        """
+
+    def url_query_param(self, name: str) -> Series:
+        """
+        Extract the value of a query parameter from URLs.
+
+        Parameters
+        ----------
+        name
+            The name of the query parameter.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`. Null if the URL has no such
+            parameter, and an empty string if the parameter has no value.
+
+        Examples
+        --------
+        >>> s = pl.Series(["/search?q=hello+world&page=2", "/search?page=3", "/?q"])
+        >>> s.str.url_query_param("q").to_list()
+        ['hello world', None, '']
+        """
+
+    def ipv4_to_int(self) -> Series:
+        """
+        Parse IPv4 addresses into integers.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["192.168.0.1", "::1", "10.0.0.256"])
+        >>> s.str.ipv4_to_int().to_list()
+        [3232235521, None, None]
+        """
+
+    def ipv6_to_int(self) -> Series:
+        """
+        Parse IP addresses into 128-bit integers.
+
+        IPv4 addresses are mapped into the IPv6 address space as
+        `::ffff:a.b.c.d`.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt128`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["::1", "2001:db8::", "0.0.0.1"])
+        >>> s.str.ipv6_to_int().to_list()
+        [1, 42540766411282592856903984951653826560, 281470681743361]
+        """
+
+    def ip_to_binary(self) -> Series:
+        """
+        Parse IP addresses into their bytes in network order.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["192.168.0.1", "::1", "nope"])
+        >>> s.str.ip_to_binary().bin.encode("hex").to_list()
+        ['c0a80001', '00000000000000000000000000000001', None]
+        """
+
+    def ip_in_cidr(self, cidr: IntoExpr) -> Series:
+        """
+        Check if IP addresses are in a network.
+
+        Parameters
+        ----------
+        cidr
+            The network in CIDR notation, e.g. `"10.0.0.0/8"`. Accepts expression
+            input, strings are parsed as literals.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["10.1.2.3", "192.168.0.1", "::1", "nope"])
+        >>> s.str.ip_in_cidr("10.0.0.0/8").to_list()
+        [True, False, False, None]
+        """
+
+    def ip_mask(self, ipv4_prefix: int = 24, ipv6_prefix: int = 48) -> Series:
+        """
+        Mask IP addresses to their network.
+
+        Parameters
+        ----------
+        ipv4_prefix
+            The number of bits to keep of IPv4 addresses, at most 32.
+        ipv6_prefix
+            The number of bits to keep of IPv6 addresses, at most 128.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["192.168.123.45", "2001:db8:abcd:12::1"])
+        >>> s.str.ip_mask().to_list()
+        ['192.168.123.0', '2001:db8:abcd::']
+        """
+
     def slice(
         self, offset: int | IntoExprColumn, length: int | IntoExprColumn | None = None
     ) -> Series:
@@ -0,0 +1,150 @@
+from __future__ import annotations
+
+import io
+import uuid
+
+import pyarrow as pa
+import pytest
+
+import apollo as pl
+from apollo.exceptions import ComputeError, InvalidOperationError
+from apollo.testing import assert_frame_equal
+
+UUIDS = [
+    "00000000-0000-4000-8000-000000000000",
+    "f81d4fae-7dec-41d0-a765-00a0c91e6bf6",
+    None,
+    "0189d7a4-21d5-7b2f-9c1e-4a5b6c7d8e9f",
+]
+
+
+@pytest.fixture
+def uuid_df() -> pl.DataFrame:
+    return pl.DataFrame({"id": UUIDS}).with_columns(pl.col("id").cast(pl.UUID()))
+
+
+@pytest.fixture
+def json_df() -> pl.DataFrame:
+    docs = ['{"a": 1, "b": [1, 2]}', None, '{"a": 2, "b": []}']
+    return pl.DataFrame({"doc": docs}).with_columns(pl.col("doc").cast(pl.JSON()))
+
+
+def test_uuid_dtype(uuid_df: pl.DataFrame) -> None:
+    assert uuid_df.schema == {"id": pl.UUID()}
+    assert pl.UUID().ext_name() == "arrow.uuid"
+    assert pl.UUID().ext_storage() == pl.Binary
+    assert pl.get_extension_type("arrow.uuid") is pl.UUID
+
+
+def test_uuid_string_roundtrip(uuid_df: pl.DataFrame) -> None:
+    s = uuid_df["id"]
+    assert s.cast(pl.String).to_list() == UUIDS
+    expected = uuid.UUID("f81d4fae-7dec-41d0-a765-00a0c91e6bf6").bytes
+    assert s.ext.storage().to_list()[1] == expected
+    assert "f81d4fae-7dec-41d0-a765-00a0c91e6bf6" in str(s)
+
+
+def test_uuid_parse_invalid() -> None:
+    s = pl.Series(["f81d4fae-7dec-41d0-a765-00a0c91e6bf6", "not-a-uuid"])
+    with pytest.raises(InvalidOperationError, match="invalid UUID"):
+        s.cast(pl.UUID())
+    assert s.cast(pl.UUID(), strict=False).null_count() == 1
+
+    with pytest.raises(ComputeError, match="16 bytes"):
+        pl.Series([b"\x00" * 15]).ext.to(pl.UUID())
+
+
+def test_uuid_ordering(uuid_df: pl.DataFrame) -> None:
+    out = uuid_df.sort("id", nulls_last=True)["id"].cast(pl.String)
+    assert out.to_list() == sorted(u for u in UUIDS if u is not None) + [None]
+
+    a, b = uuid_df["id"][:2], uuid_df["id"][1:3]
+    assert (a == b).to_list() == [False, None]
+
+
+@pytest.mark.parametrize("version", [4, 7])
+def test_generate_uuid(version: int) -> None:
+    df = pl.DataFrame({"x": range(1000)}).with_columns(
+        id=pl.generate_uuid(version=version)  # type: ignore[arg-type]
+    )
+    assert df.schema["id"] == pl.UUID()
+    ids = [uuid.UUID(u) for u in df["id"].cast(pl.String)]
+    assert len(set(ids)) == 1000
+    assert {u.version for u in ids} == {version}
+    if version == 7:
+        assert ids == sorted(ids)
+
+    assert pl.select(pl.generate_uuid(3)).height == 3
+    with pytest.raises(ValueError, match="version"):
+        pl.generate_uuid(version=5)  # type: ignore[arg-type]
+
+
+def test_uuid_parquet_roundtrip(uuid_df: pl.DataFrame) -> None:
+    df = uuid_df
+    buffer = io.BytesIO()
+    df.write_parquet(buffer)
+    buffer.seek(0)
+    df_read = pl.read_parquet(buffer)
+    assert_frame_equal(df, df_read)
+
+
+def test_uuid_ipc_roundtrip(uuid_df: pl.DataFrame) -> None:
+    df = uuid_df
+    buffer = io.BytesIO()
+    df.write_ipc(buffer)
+    buffer.seek(0)
+    df_read = pl.read_ipc(buffer)
+    assert_frame_equal(df, df_read)
+
+
+def test_uuid_to_arrow(uuid_df: pl.DataFrame) -> None:
+    field = uuid_df.to_arrow().schema.field("id")
+    storage = getattr(field.type, "storage_type", field.type)
+    assert storage == pa.binary(16)
+
+
+def test_json_dtype(json_df: pl.DataFrame) -> None:
+    assert json_df.schema == {"doc": pl.JSON()}
+    assert pl.JSON().ext_name() == "arrow.json"
+    assert pl.JSON().ext_storage() == pl.String
+
+
+def test_json_validation() -> None:
+    s = pl.Series(['{"a": 1}', "{not json", "[1, 2]"])
+    with pytest.raises(ComputeError, match="invalid JSON"):
+        s.cast(pl.JSON())
+    assert s.cast(pl.JSON(), strict=False).ext.storage().to_list() == [
+        '{"a": 1}',
+        None,
+        "[1, 2]",
+    ]
+
+
+def test_json_string_functions(json_df: pl.DataFrame) -> None:
+    out = json_df.select(
+        a=pl.col("doc").str.json_path_match("$.a"),
+        decoded=pl.col("doc").str.json_decode(
+            pl.Struct({"a": pl.Int64, "b": pl.List(pl.Int64)})
+        ),
+    )
+    assert out["a"].to_list() == ["1", None, "2"]
+    assert out["decoded"].to_list() == [{"a": 1, "b": [1, 2]}, None, {"a": 2, "b": []}]
+    assert json_df["doc"].str.json_decode().struct.field("a").to_list() == [1, None, 2]
+
+
+def test_json_parquet_roundtrip(json_df: pl.DataFrame) -> None:
+    df = json_df
+    buffer = io.BytesIO()
+    df.write_parquet(buffer)
+    buffer.seek(0)
+    df_read = pl.read_parquet(buffer)
+    assert_frame_equal(df, df_read)
+
+
+def test_json_ipc_roundtrip(json_df: pl.DataFrame) -> None:
+    df = json_df
+    buffer = io.BytesIO()
+    df.write_ipc(buffer)
+    buffer.seek(0)
+    df_read = pl.read_ipc(buffer)
+    assert_frame_equal(df, df_read)
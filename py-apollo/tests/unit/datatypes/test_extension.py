@@ -2,7 +2,7 @@ from __future__ import annotations
 
 import io
 import pickle
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Any
 
 import pytest
 
@@ -158,3 +158,174 @@ def test_extension_join_payload(how: JoinStrategy) -> None:
     )
     print(result, expected)
     assert_frame_equal(result, expected, check_row_order=False)
+
+
+class Money(pl.datatypes.BaseExtension):
+    """Non-negative amounts stored in cents, displayed in dollars."""
+
+    def __init__(self) -> None:
+        super().__init__(name="testing.money", storage=pl.Int64)
+
+    def ext_format_value(self, value: Any) -> str | None:
+        return f"${value // 100}.{value % 100:02}"
+
+    def ext_cast_to(
+        self, s: pl.Series, dtype: ApolloDataType, strict: bool
+    ) -> pl.Series | None:
+        if dtype != pl.String:
+            return None
+        cents = (pl.first() % 100).cast(pl.String).str.zfill(2)
+        out = s.to_frame().select(pl.format("${}.{}", pl.first() // 100, cents))
+        return out.to_series().alias(s.name)
+
+    def ext_cast_from(self, s: pl.Series, strict: bool) -> pl.Series | None:
+        if s.dtype != pl.String:
+            return None
+        dollars = s.str.strip_prefix("$").cast(pl.Float64, strict=strict)
+        return (dollars * 100).round().cast(pl.Int64)
+
+    def ext_validate(self, s: pl.Series) -> None:
+        if (s < 0).any():
+            msg = "amounts can't be negative"
+            raise ValueError(msg)
+
+
+class CaseInsensitiveString(pl.datatypes.BaseExtension):
+    """Strings that compare equal regardless of case."""
+
+    def __init__(self) -> None:
+        super().__init__(name="testing.case_insensitive_string", storage=pl.String)
+
+    def ext_comparison_key(self, s: pl.Series) -> pl.Series | None:
+        return s.str.to_lowercase()
+
+
+class BrokenComparisonKey(pl.datatypes.BaseExtension):
+    """Strings whose comparison key can't be computed."""
+
+    def __init__(self) -> None:
+        super().__init__(name="testing.broken_comparison_key", storage=pl.String)
+
+    def ext_comparison_key(self, s: pl.Series) -> pl.Series | None:
+        msg = "no comparison key"
+        raise ValueError(msg)
+
+
+pl.register_extension_type("testing.money", Money)
+pl.register_extension_type("testing.case_insensitive_string", CaseInsensitiveString)
+pl.register_extension_type("testing.broken_comparison_key", BrokenComparisonKey)
+
+
+def test_extension_format_value() -> None:
+    s = pl.Series("m", [150, 5, None], dtype=Money())
+    assert "$1.50" in str(s)
+    assert "$0.05" in str(s)
+
+    out = str(s.to_frame())
+    assert "$1.50" in out
+    assert "$0.05" in out
+    assert "150" not in out
+
+
+def test_extension_cast_hooks() -> None:
+    s = pl.Series("m", [150, 5, None], dtype=Money())
+    assert s.cast(pl.String).to_list() == ["$1.50", "$0.05", None]
+    # Casts without a hook go through the storage.
+    assert s.cast(pl.Float64).to_list() == [150.0, 5.0, None]
+
+    out = pl.Series("m", ["$2.25", "$0.10"]).cast(Money())
+    assert out.dtype == Money()
+    assert out.ext.storage().to_list() == [225, 10]
+    assert pl.Series("m", [1, 2]).cast(Money()).ext.storage().to_list() == [1, 2]
+
+
+def test_extension_validate() -> None:
+    with pytest.raises(ValueError, match="can't be negative"):
+        pl.Series("m", [1, -1], dtype=Money())
+    with pytest.raises(ValueError, match="can't be negative"):
+        pl.Series("m", [-100]).cast(Money())
+    with pytest.raises(ValueError, match="can't be negative"):
+        pl.Series("m", [-5]).ext.to(Money())
+
+
+def test_extension_comparison_key() -> None:
+    s = pl.Series("s", ["b", "A", "a", "B", "c"], dtype=CaseInsensitiveString())
+    other = pl.Series("s", ["B", "a", "A", "b", "C"], dtype=CaseInsensitiveString())
+
+    assert (s == other).all()
+    assert s.n_unique() == 3
+    assert s.unique(maintain_order=True).ext.storage().to_list() == ["b", "A", "c"]
+    assert s.sort().ext.storage().to_list()[-1] == "c"
+    assert s.arg_sort().to_list()[:2] in ([1, 2], [2, 1])
+
+    df = pl.DataFrame({"s": s, "v": range(5)})
+    out = df.group_by("s").agg(pl.col("v").sum()).sort("v")
+    assert out["v"].to_list() == [3, 3, 4]
+
+
+def test_extension_comparison_key_multiple_keys() -> None:
+    df = pl.DataFrame(
+        {
+            "s": pl.Series(["a", "A", "b", "B", "a"], dtype=CaseInsensitiveString()),
+            "i": [1, 1, 1, 1, 2],
+            "v": range(5),
+        }
+    )
+    out = df.group_by("s", "i").agg(pl.col("v").sum()).sort("v")
+    assert out["v"].to_list() == [1, 4, 5]
+    assert out["i"].to_list() == [1, 2, 1]
+    assert df.unique(["s", "i"]).height == 3
+    assert df.select("s", "i").n_unique() == 3
+
+
+def test_extension_comparison_key_streaming_group_by() -> None:
+    s = pl.Series("s", ["b", "A", "a", "B", "c"], dtype=CaseInsensitiveString())
+    lf = pl.LazyFrame({"s": s, "v": range(5)})
+
+    out = lf.group_by("s").agg(pl.col("v").sum()).collect(engine="streaming")
+    assert out.schema == pl.Schema({"s": CaseInsensitiveString(), "v": pl.Int64})
+    out = out.sort("v")
+    assert out["v"].to_list() == [3, 3, 4]
+    assert out["s"].ext.storage().str.to_lowercase().sort().to_list() == ["a", "b", "c"]
+
+    out = (
+        lf.group_by("s", maintain_order=True)
+        .agg(pl.col("v").sum())
+        .collect(engine="streaming")
+    )
+    assert out["s"].ext.storage().to_list() == ["b", "A", "c"]
+    assert out["v"].to_list() == [3, 3, 4]
+
+
+def test_extension_comparison_key_error() -> None:
+    s = pl.Series("s", ["a", "b"], dtype=BrokenComparisonKey())
+    with pytest.raises(ValueError, match="no comparison key"):
+        s.n_unique()
+    with pytest.raises(ValueError, match="no comparison key"):
+        s.to_frame().group_by("s").len()
+
+
+def test_extension_comparison_key_error_sort() -> None:
+    s = pl.Series("s", ["b", "a"], dtype=BrokenComparisonKey())
+    df = pl.DataFrame({"s": s, "i": [1, 2]})
+    with pytest.raises(ValueError, match="no comparison key"):
+        s.sort()
+    with pytest.raises(ValueError, match="no comparison key"):
+        s.arg_sort()
+    with pytest.raises(ValueError, match="no comparison key"):
+        df.sort("s")
+    with pytest.raises(ValueError, match="no comparison key"):
+        df.sort("i", "s")
+    with pytest.raises(ValueError, match="no comparison key"):
+        df.select(pl.col("i").sort_by("s"))
+
+
+def test_extension_comparison_key_join() -> None:
+    left = pl.DataFrame(
+        {"k": pl.Series(["Foo", "bar"], dtype=CaseInsensitiveString()), "a": [1, 2]}
+    )
+    right = pl.DataFrame(
+        {"k": pl.Series(["BAR", "foo"], dtype=CaseInsensitiveString()), "b": [3, 4]}
+    )
+    out = left.join(right, on="k", how="inner").sort("a")
+    assert out.select("a", "b").rows() == [(1, 4), (2, 3)]
@@ -0,0 +1,159 @@
+from __future__ import annotations
+
+import io
+from datetime import date, datetime, timedelta
+from zoneinfo import ZoneInfo
+
+import pytest
+
+import apollo as pl
+from apollo.exceptions import InvalidOperationError
+from apollo.testing import assert_frame_equal, assert_series_equal
+
+
+@pytest.fixture
+def interval_df() -> pl.DataFrame:
+    return pl.DataFrame({"i": ["1mo", "1y 2d", "-3h", None]}).select(
+        pl.col("i").str.to_interval()
+    )
+
+
+def test_interval_dtype() -> None:
+    dtype = pl.Interval()
+    assert dtype == pl.Interval
+    assert dtype != pl.Duration
+    assert repr(dtype) == "Interval"
+    assert dtype.is_temporal()
+
+
+def test_str_to_interval(interval_df: pl.DataFrame) -> None:
+    assert interval_df.schema == {"i": pl.Interval}
+    assert interval_df["i"].to_list() == [
+        (1, 0, 0),
+        (12, 2, 0),
+        (0, 0, -3 * 3_600_000_000_000),
+        None,
+    ]
+    assert interval_df["i"].cast(pl.String).to_list() == [
+        "1mo",
+        "12mo 2d",
+        "-3h",
+        None,
+    ]
+
+
+def test_str_to_interval_verbose_units() -> None:
+    s = pl.Series(["1 year 2 months", "3 weeks, 4 days", "5 hours 6 minutes"])
+    assert s.str.to_interval().to_list() == [
+        (14, 0, 0),
+        (0, 25, 0),
+        (0, 0, (5 * 60 + 6) * 60_000_000_000),
+    ]
+
+
+def test_str_to_interval_strict() -> None:
+    s = pl.Series(["1mo", "bogus", ""])
+    with pytest.raises(InvalidOperationError):
+        s.str.to_interval()
+    assert s.str.to_interval(strict=False).to_list() == [(1, 0, 0), None, None]
+
+
+def test_date_add_interval() -> None:
+    df = pl.DataFrame(
+        {
+            "d": [date(2024, 1, 31), date(2023, 1, 31), date(2024, 2, 29)],
+            "i": ["1mo", "1mo", "1y"],
+        }
+    ).with_columns(pl.col("i").str.to_interval())
+
+    out = df.select(
+        plus=pl.col("d") + pl.col("i"),
+        minus=pl.col("d") - pl.col("i"),
+        swapped=pl.col("i") + pl.col("d"),
+    )
+    expected = pl.DataFrame(
+        {
+            "plus": [date(2024, 2, 29), date(2023, 2, 28), date(2025, 2, 28)],
+            "minus": [date(2023, 12, 31), date(2022, 12, 31), date(2023, 2, 28)],
+            "swapped": [date(2024, 2, 29), date(2023, 2, 28), date(2025, 2, 28)],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_datetime_add_interval_mixed_signs() -> None:
+    s = pl.Series([datetime(2024, 1, 31, 12)])
+    interval = pl.Series(["1mo -1d 2h"]).str.to_interval()
+    assert (s + interval).to_list() == [datetime(2024, 2, 28, 14)]
+    assert (s - interval).to_list() == [datetime(2023, 12, 31, 22)]
+
+
+def test_datetime_tz_add_interval_across_dst() -> None:
+    tz = "Europe/Amsterdam"
+    df = pl.DataFrame(
+        {"dt": [datetime(2024, 3, 30, 12)] * 2, "i": ["1d", "24h"]}
+    ).with_columns(
+        pl.col("dt").dt.replace_time_zone(tz),
+        pl.col("i").str.to_interval(),
+    )
+    out = df.select(pl.col("dt") + pl.col("i"))["dt"]
+    assert out.dtype == pl.Datetime("us", tz)
+    assert out.to_list() == [
+        datetime(2024, 3, 31, 12, tzinfo=ZoneInfo(tz)),
+        datetime(2024, 3, 31, 13, tzinfo=ZoneInfo(tz)),
+    ]
+
+
+def test_interval_arithmetic() -> None:
+    df = pl.DataFrame({"a": ["1mo 2d"], "b": ["3d 4h"]}).select(
+        pl.all().str.to_interval()
+    )
+    out = df.select(
+        add=pl.col("a") + pl.col("b"),
+        sub=pl.col("a") - pl.col("b"),
+        mul=pl.col("a") * 3,
+        dur=pl.col("a") + pl.lit(timedelta(hours=1)),
+    )
+    assert out.schema == dict.fromkeys(["add", "sub", "mul", "dur"], pl.Interval)
+    assert out.row(0) == (
+        (1, 5, 4 * 3_600_000_000_000),
+        (1, -1, -4 * 3_600_000_000_000),
+        (3, 6, 0),
+        (1, 2, 3_600_000_000_000),
+    )
+
+
+def test_interval_comparison() -> None:
+    s = pl.Series(["1mo", "30d"]).str.to_interval()
+    assert (s == s).to_list() == [True, True]
+    assert (s == s.reverse()).to_list() == [False, False]
+    with pytest.raises(InvalidOperationError):
+        _ = s < s.reverse()
+
+
+def test_interval_cast() -> None:
+    s = pl.Series([timedelta(days=1, hours=2), None])
+    out = s.cast(pl.Interval)
+    assert out.to_list() == [(0, 0, 26 * 3_600_000_000_000), None]
+    assert_series_equal(out.cast(pl.Duration("us")), s)
+
+    with pytest.raises(InvalidOperationError, match="no fixed length"):
+        pl.Series(["1mo"]).str.to_interval().cast(pl.Duration)
+
+
+def test_interval_parquet_roundtrip(interval_df: pl.DataFrame) -> None:
+    df = interval_df
+    buffer = io.BytesIO()
+    df.write_parquet(buffer)
+    buffer.seek(0)
+    df_read = pl.read_parquet(buffer)
+    assert_frame_equal(df, df_read)
+
+
+def test_interval_ipc_roundtrip(interval_df: pl.DataFrame) -> None:
+    df = interval_df
+    buffer = io.BytesIO()
+    df.write_ipc(buffer)
+    buffer.seek(0)
+    df_read = pl.read_ipc(buffer)
+    assert_frame_equal(df, df_read)
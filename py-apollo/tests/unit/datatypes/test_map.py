@@ -0,0 +1,160 @@
+from __future__ import annotations
+
+import io
+from typing import TYPE_CHECKING
+
+import pyarrow as pa
+import pyarrow.parquet as pq
+import pytest
+
+import apollo as pl
+from apollo.exceptions import ComputeError, InvalidOperationError, ShapeError
+from apollo.testing import assert_frame_equal, assert_series_equal
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+
+
+@pytest.fixture
+def map_df() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "k": [["a", "b"], ["b", "b"], [], None],
+            "v": [[1, 2], [3, 4], [], [5]],
+        }
+    ).select(m=pl.col("k").map.from_lists("v"))
+
+
+def test_map_dtype() -> None:
+    dtype = pl.Map(pl.String, pl.Int64)
+    assert dtype.key == pl.String
+    assert dtype.value == pl.Int64
+    assert dtype == pl.Map
+    assert dtype == pl.Map(pl.String, pl.Int64)
+    assert dtype != pl.Map(pl.String, pl.Int32)
+    assert dtype != pl.List(pl.Int64)
+    assert repr(dtype) == "Map(String, Int64)"
+    assert dtype.is_nested()
+
+
+def test_map_from_lists(map_df: pl.DataFrame) -> None:
+    assert map_df.schema == {"m": pl.Map(pl.String, pl.Int64)}
+    assert map_df.get_column("m").null_count() == 1
+
+
+def test_map_from_lists_errors() -> None:
+    df = pl.DataFrame({"k": [["a", "b"]], "v": [[1]]})
+    with pytest.raises(ShapeError):
+        df.select(pl.col("k").map.from_lists("v"))
+
+    df = pl.DataFrame({"k": [["a", None]], "v": [[1, 2]]})
+    with pytest.raises(ComputeError, match="cannot be null"):
+        df.select(pl.col("k").map.from_lists("v"))
+
+
+def test_map_get(map_df: pl.DataFrame) -> None:
+    out = map_df.select(
+        a=pl.col("m").map.get("a"),
+        b=pl.col("m").map.get("b"),
+    )
+    expected = pl.DataFrame({"a": [1, None, None, None], "b": [2, 4, None, None]})
+    assert_frame_equal(out, expected)
+
+
+def test_map_get_per_row_key(map_df: pl.DataFrame) -> None:
+    out = map_df.with_columns(key=pl.Series(["b", "a", "a", "a"])).select(
+        pl.col("m").map.get(pl.col("key"))
+    )
+    assert out.to_series().to_list() == [2, None, None, None]
+
+
+def test_map_contains_key(map_df: pl.DataFrame) -> None:
+    out = map_df.select(pl.col("m").map.contains_key("a"))
+    assert out.to_series().to_list() == [True, False, False, None]
+
+
+def test_map_keys_values(map_df: pl.DataFrame) -> None:
+    out = map_df.select(
+        keys=pl.col("m").map.keys(),
+        values=pl.col("m").map.values(),
+    )
+    assert out.schema == {"keys": pl.List(pl.String), "values": pl.List(pl.Int64)}
+    assert out.to_dict(as_series=False) == {
+        "keys": [["a", "b"], ["b", "b"], [], None],
+        "values": [[1, 2], [3, 4], [], None],
+    }
+
+
+def test_map_to_struct(map_df: pl.DataFrame) -> None:
+    out = map_df.select(pl.col("m").map.to_struct(["a", "c"]))
+    assert out.schema == {"m": pl.Struct({"a": pl.Int64, "c": pl.Int64})}
+    assert out.to_series().to_list() == [
+        {"a": 1, "c": None},
+        {"a": None, "c": None},
+        {"a": None, "c": None},
+        None,
+    ]
+
+
+def test_map_namespace_requires_map() -> None:
+    df = pl.DataFrame({"a": [[1, 2]]})
+    with pytest.raises(InvalidOperationError, match="expected: Map"):
+        df.select(pl.col("a").map.keys())
+
+
+@pytest.mark.parametrize(
+    ("write", "read"),
+    [
+        (pl.DataFrame.write_parquet, pl.read_parquet),
+        (pl.DataFrame.write_ipc, pl.read_ipc),
+        (pl.DataFrame.write_avro, pl.read_avro),
+    ],
+)
+def test_map_roundtrip(
+    map_df: pl.DataFrame,
+    write: Callable[[pl.DataFrame, io.BytesIO], None],
+    read: Callable[[io.BytesIO], pl.DataFrame],
+) -> None:
+    f = io.BytesIO()
+    write(map_df, f)
+    f.seek(0)
+    out = read(f)
+    assert out.schema == map_df.schema
+    assert_series_equal(
+        out.select(pl.col("m").map.get("b")).to_series(),
+        map_df.select(pl.col("m").map.get("b")).to_series(),
+    )
+
+
+def test_map_explode(map_df: pl.DataFrame) -> None:
+    entries = pl.Struct({"key": pl.String, "value": pl.Int64})
+    expected = pl.Series(
+        "m",
+        [
+            {"key": "a", "value": 1},
+            {"key": "b", "value": 2},
+            {"key": "b", "value": 3},
+            {"key": "b", "value": 4},
+            None,
+            None,
+        ],
+        dtype=entries,
+    )
+    assert_series_equal(map_df.explode("m").to_series(), expected)
+    assert_series_equal(map_df.select(pl.col("m").explode()).to_series(), expected)
+    assert map_df.lazy().explode("m").collect_schema() == {"m": entries}
+
+
+def test_map_read_foreign_parquet() -> None:
+    xs = pa.array([[(0, 5), (1, 10)]], pa.map_(pa.int32(), pa.int32()))
+    table = pa.table({"x": xs})
+    f = io.BytesIO()
+    pq.write_table(table, f)
+    f.seek(0)
+
+    df = pl.read_parquet(f)
+    entries = pl.Struct({"key": pl.Int32, "value": pl.Int32})
+    assert df.schema == {"x": pl.List(entries)}
+
+    out = df.select(pl.col("x").cast(pl.Map(pl.Int32, pl.Int32)).map.keys())
+    assert out.to_series().to_list() == [[0, 1]]
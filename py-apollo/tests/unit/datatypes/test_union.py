@@ -0,0 +1,103 @@
+from __future__ import annotations
+
+import pyarrow as pa
+import pytest
+
+import apollo as pl
+from apollo.exceptions import InvalidOperationError, StructFieldNotFoundError
+from apollo.testing import assert_frame_equal
+
+
+@pytest.fixture
+def sparse_arr() -> pa.UnionArray:
+    return pa.UnionArray.from_sparse(
+        pa.array([0, 1, 0, 1], pa.int8()),
+        [pa.array([1, None, 3, None]), pa.array([None, "b", None, "d"])],
+        field_names=["i", "s"],
+    )
+
+
+@pytest.fixture
+def dense_arr() -> pa.UnionArray:
+    return pa.UnionArray.from_dense(
+        pa.array([5, 7, 5, 5], pa.int8()),
+        pa.array([0, 0, 1, 2], pa.int32()),
+        [pa.array([1, 2, 3]), pa.array(["b"])],
+        field_names=["i", "s"],
+        type_codes=[5, 7],
+    )
+
+
+def test_union_dtype() -> None:
+    dtype = pl.Union({"i": pl.Int64, "s": pl.String})
+    assert dtype.fields == [pl.Field("i", pl.Int64), pl.Field("s", pl.String)]
+    assert dtype == pl.Union
+    assert dtype == pl.Union([pl.Field("i", pl.Int64), pl.Field("s", pl.String)])
+    assert dtype != pl.Union({"i": pl.Int32, "s": pl.String})
+    assert dtype != pl.Struct({"i": pl.Int64, "s": pl.String})
+    assert repr(dtype) == "Union({'i': Int64, 's': String})"
+    assert dtype.is_nested()
+
+
+@pytest.mark.parametrize("arr_fixture", ["sparse_arr", "dense_arr"])
+def test_union_from_arrow(arr_fixture: str, request: pytest.FixtureRequest) -> None:
+    s = pl.from_arrow(request.getfixturevalue(arr_fixture))
+    assert isinstance(s, pl.Series)
+    assert s.dtype == pl.Union({"i": pl.Int64, "s": pl.String})
+    assert s.len() == 4
+    assert "union[2]" in str(s)
+
+
+def test_union_to_list(sparse_arr: pa.UnionArray) -> None:
+    s = pl.from_arrow(sparse_arr)
+    assert s.to_list() == [1, "b", 3, "d"]  # type: ignore[union-attr]
+
+
+@pytest.mark.parametrize("arr_fixture", ["sparse_arr", "dense_arr"])
+def test_union_arrow_roundtrip(
+    arr_fixture: str, request: pytest.FixtureRequest
+) -> None:
+    arr = request.getfixturevalue(arr_fixture)
+    s = pl.from_arrow(arr)
+    assert s.to_arrow().equals(arr)  # type: ignore[union-attr]
+
+
+def test_union_filter_gather(dense_arr: pa.UnionArray) -> None:
+    df = pl.from_arrow(dense_arr).to_frame("u")  # type: ignore[union-attr]
+
+    out = df.filter(pl.Series([True, False, True, True]))
+    assert out.schema == df.schema
+    assert out.get_column("u").to_list() == [1, 2, 3]
+
+    out = df[[3, 1, 0]]
+    assert out.get_column("u").to_list() == [3, "b", 1]
+
+    exported = out.get_column("u").to_arrow()
+    assert exported.type.mode == "dense"
+    assert exported.type.type_codes == [5, 7]
+    assert exported.to_pylist() == [3, "b", 1]
+
+
+def test_union_tag(dense_arr: pa.UnionArray) -> None:
+    df = pl.from_arrow(dense_arr).to_frame("u")  # type: ignore[union-attr]
+    out = df.select(pl.col("u").union.tag())
+    assert out.schema == {"u": pl.Int8}
+    assert out.get_column("u").to_list() == [5, 7, 5, 5]
+
+
+def test_union_field(sparse_arr: pa.UnionArray) -> None:
+    df = pl.from_arrow(sparse_arr).to_frame("u")  # type: ignore[union-attr]
+    out = df.select(pl.col("u").union.field("i"), pl.col("u").union.field("s"))
+    expected = pl.DataFrame(
+        {"i": [1, None, 3, None], "s": [None, "b", None, "d"]},
+    )
+    assert_frame_equal(out, expected)
+
+    with pytest.raises(StructFieldNotFoundError):
+        df.select(pl.col("u").union.field("x"))
+
+
+def test_union_tag_wrong_dtype() -> None:
+    df = pl.DataFrame({"a": [1, 2]})
+    with pytest.raises(InvalidOperationError, match="expected Union type"):
+        df.select(pl.col("a").union.tag())
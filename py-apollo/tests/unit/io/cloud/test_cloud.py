@@ -1,14 +1,26 @@
 from __future__ import annotations
 
 import contextlib
+import getpass
+import shutil
+import socket
 import subprocess
 import sys
+import threading
+import time
 from functools import partial
+from http.server import BaseHTTPRequestHandler, ThreadingHTTPServer
+from typing import TYPE_CHECKING, Any
 
 import pytest
 
 import apollo as pl
 from apollo.io.cloud._utils import _is_aws_cloud
+from apollo.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+    from pathlib import Path
 
 
 @pytest.mark.slow
@@ -167,3 +179,202 @@ max_retries: 0, \
 retry_timeout: 23ms"""
         in capture
     )
+
+
+class _PutServerHandler(BaseHTTPRequestHandler):
+    """Stand-in for a WebDAV server that stores uploaded files in memory."""
+
+    files: dict[str, bytes]
+    authorization: list[str | None]
+
+    def log_message(self, *args: Any) -> None:
+        pass
+
+    def _send_headers(self, status: int, length: int) -> None:
+        self.send_response(status)
+        self.send_header("Content-Length", str(length))
+        self.send_header("ETag", f'"{length}"')
+        self.send_header("Last-Modified", "Mon, 19 Oct 2026 00:00:00 GMT")
+        self.end_headers()
+
+    def do_PUT(self) -> None:
+        self.authorization.append(self.headers.get("Authorization"))
+        length = int(self.headers.get("Content-Length", 0))
+        self.files[self.path] = self.rfile.read(length)
+        self._send_headers(201, 0)
+
+    def do_MKCOL(self) -> None:
+        self._send_headers(201, 0)
+
+    def do_HEAD(self) -> None:
+        if self.path not in self.files:
+            self._send_headers(404, 0)
+            return
+        self._send_headers(200, len(self.files[self.path]))
+
+    def do_GET(self) -> None:
+        if self.path not in self.files:
+            self._send_headers(404, 0)
+            return
+
+        data = self.files[self.path]
+        status = 200
+        if (range_header := self.headers.get("Range")) is not None:
+            start, _, end = range_header.removeprefix("bytes=").partition("-")
+            data = data[int(start) : int(end) + 1 if end else None]
+            status = 206
+
+        self._send_headers(status, len(data))
+        self.wfile.write(data)
+
+
+def test_http_put_write() -> None:
+    handler = type(
+        "Handler", (_PutServerHandler,), {"files": {}, "authorization": []}
+    )
+    server = ThreadingHTTPServer(("127.0.0.1", 0), handler)
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+
+    try:
+        url = f"http://127.0.0.1:{server.server_port}/data/out.csv"
+        df = pl.DataFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+
+        df.lazy().sink_csv(url, storage_options={"bearer_token": "secret"})
+
+        assert handler.files["/data/out.csv"] == df.write_csv().encode()
+        assert handler.authorization == ["Bearer secret"]
+
+        assert_frame_equal(pl.read_csv(url), df)
+
+        df.write_csv(url, storage_options={"username": "user", "password": "pw"})
+        assert handler.authorization[-1] == "Basic dXNlcjpwdw=="
+    finally:
+        server.shutdown()
+        server.server_close()
+
+
+def _sftp_enabled() -> bool:
+    # Fails before connecting, with a different error if the feature is missing.
+    try:
+        pl.scan_csv("sftp://host/data.csv", storage_options={"x": "x"}).collect()
+    except pl.exceptions.ComputeError as e:
+        return "unknown configuration key for SFTP" in str(e)
+    return False
+
+
+requires_sftp = pytest.mark.skipif(
+    not _sftp_enabled(), reason="apollo was built without the 'sftp' feature"
+)
+
+
+@pytest.fixture
+def sftp_server(tmp_path: Path) -> Iterator[tuple[str, dict[str, str]]]:
+    """Run an SSH server for the current user on localhost."""
+    sshd = shutil.which("sshd") or shutil.which("sshd", path="/usr/sbin")
+    if sshd is None or shutil.which("ssh-keygen") is None:
+        pytest.skip("OpenSSH is not installed")
+
+    keys_dir = tmp_path / "ssh"
+    keys_dir.mkdir()
+    host_key = keys_dir / "host_key"
+    user_key = keys_dir / "user_key"
+    for key in (host_key, user_key):
+        subprocess.run(
+            ["ssh-keygen", "-q", "-t", "ed25519", "-N", "", "-f", str(key)],
+            check=True,
+        )
+
+    with socket.socket() as s:
+        s.bind(("127.0.0.1", 0))
+        port = s.getsockname()[1]
+
+    config = keys_dir / "sshd_config"
+    config.write_text(
+        f"""\
+ListenAddress 127.0.0.1
+Port {port}
+HostKey {host_key}
+AuthorizedKeysFile {user_key}.pub
+PidFile {keys_dir / "sshd.pid"}
+PasswordAuthentication no
+StrictModes no
+UsePAM no
+Subsystem sftp internal-sftp
+"""
+    )
+
+    process = subprocess.Popen(
+        [sshd, "-D", "-e", "-f", str(config)], stderr=subprocess.DEVNULL
+    )
+    try:
+        for _ in range(100):
+            with contextlib.suppress(OSError), socket.create_connection(
+                ("127.0.0.1", port), timeout=1
+            ):
+                break
+            time.sleep(0.1)
+        else:
+            pytest.skip("could not start sshd")
+
+        yield (
+            f"sftp://{getpass.getuser()}@127.0.0.1:{port}",
+            {"private_key_path": str(user_key), "skip_host_key_check": "true"},
+        )
+    finally:
+        process.terminate()
+        process.wait()
+
+
+@requires_sftp
+@pytest.mark.write_disk
+def test_sftp_roundtrip(
+    sftp_server: tuple[str, dict[str, str]], tmp_path: Path
+) -> None:
+    url, storage_options = sftp_server
+    # The server runs locally, so paths on the server are local paths.
+    data_dir = tmp_path / "data" / "nested"
+
+    df1 = pl.DataFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+    df2 = pl.DataFrame({"a": [4, 5], "b": [None, "w"]})
+
+    df1.write_parquet(f"{url}{data_dir}/1.parquet", storage_options=storage_options)
+    df2.lazy().sink_parquet(
+        f"{url}{data_dir}/2.parquet", storage_options=storage_options
+    )
+    df1.write_csv(f"{url}{data_dir}/1.csv", storage_options=storage_options)
+
+    assert sorted(p.name for p in data_dir.iterdir()) == [
+        "1.csv",
+        "1.parquet",
+        "2.parquet",
+    ]
+    assert_frame_equal(pl.read_parquet(data_dir / "2.parquet"), df2)
+
+    assert_frame_equal(
+        pl.read_parquet(f"{url}{data_dir}/1.parquet", storage_options=storage_options),
+        df1,
+    )
+    assert_frame_equal(
+        pl.read_csv(f"{url}{data_dir}/1.csv", storage_options=storage_options), df1
+    )
+    assert_frame_equal(
+        pl.scan_parquet(
+            f"{url}{data_dir}/*.parquet", storage_options=storage_options
+        ).collect(),
+        pl.concat([df1, df2]),
+    )
+
+    # Overwriting replaces the file.
+    df2.write_parquet(f"{url}{data_dir}/1.parquet", storage_options=storage_options)
+    assert_frame_equal(pl.read_parquet(data_dir / "1.parquet"), df2)
+
+
+@requires_sftp
+def test_sftp_storage_options() -> None:
+    with pytest.raises(pl.exceptions.ComputeError, match="unknown configuration key"):
+        pl.scan_csv(
+            "sftp://host/data.csv", storage_options={"aws_region": "x"}
+        ).collect()
+
+    with pytest.raises(pl.exceptions.ComputeError, match="passwords in SFTP URLs"):
+        pl.scan_csv("sftp://user:pw@host/data.csv").collect()
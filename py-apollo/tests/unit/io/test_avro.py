@@ -89,3 +89,44 @@ def test_with_name() -> None:
     read_df = pl.read_json(raw[raw.find(b"{") : raw.rfind(b"}") + 1])
 
     assert_frame_equal(expected, read_df)
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("compression", COMPRESSIONS)
+def test_sink_scan_roundtrip(compression: AvroCompression, tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {"a": range(10_000), "b": [str(i) for i in range(10_000)]},
+    )
+
+    path = tmp_path / "data.avro"
+    df.lazy().sink_avro(path, compression=compression)
+
+    assert_frame_equal(pl.read_avro(path), df)
+    assert_frame_equal(pl.scan_avro(path).collect(), df)
+
+
+@pytest.mark.write_disk
+def test_scan_avro_projection_slice_row_index(tmp_path: Path) -> None:
+    df = pl.DataFrame({"a": [1, 2, 3, 4, 5], "b": ["x", "y", "z", "u", "v"]})
+    path = tmp_path / "data.avro"
+    df.write_avro(path)
+
+    q = pl.scan_avro(path, row_index_name="idx").select("b", "idx")
+    assert_frame_equal(q.collect(), df.with_row_index("idx").select("b", "idx"))
+
+    assert_frame_equal(pl.scan_avro(path).slice(1, 2).collect(), df.slice(1, 2))
+    assert_frame_equal(pl.scan_avro(path).tail(2).collect(), df.tail(2))
+    assert pl.scan_avro(path).select(pl.len()).collect().item() == 5
+
+
+@pytest.mark.write_disk
+def test_scan_avro_glob(tmp_path: Path) -> None:
+    dfs = [pl.DataFrame({"a": [i, i + 1]}) for i in range(0, 6, 2)]
+
+    for i, df in enumerate(dfs):
+        df.write_avro(tmp_path / f"{i}.avro")
+
+    assert_frame_equal(
+        pl.scan_avro(tmp_path / "*.avro").collect(),
+        pl.concat(dfs),
+    )
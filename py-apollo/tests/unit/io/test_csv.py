@@ -25,7 +25,7 @@ from apollo.testing import assert_frame_equal, assert_series_equal
 if TYPE_CHECKING:
     from pathlib import Path
 
-    from apollo._typing import CsvQuoteStyle, TimeUnit
+    from apollo._typing import CsvEncoding, CsvQuoteStyle, TimeUnit
 
 
 @pytest.fixture
@@ -542,6 +542,51 @@ def test_read_csv_encoding_lossy(tmp_path: Path) -> None:
         )
 
 
+@pytest.mark.parametrize(
+    ("encoding", "python_codec"),
+    [
+        ("latin1", "latin-1"),
+        ("windows-1252", "cp1252"),
+        ("shift_jis", "shift_jis"),
+        ("utf-16-le", "utf-16-le"),
+        ("utf-16-be", "utf-16-be"),
+    ],
+)
+def test_csv_native_encoding_roundtrip(encoding: CsvEncoding, python_codec: str) -> None:
+    values = ["abc", "déjà vu"] if encoding != "shift_jis" else ["abc", "東京"]
+    df = pl.DataFrame({"name": values, "x": [1, 2]})
+
+    f = io.BytesIO()
+    df.write_csv(f, encoding=encoding)
+    assert f.getvalue() == f"name,x\nabc,1\n{values[1]},2\n".encode(python_codec)
+
+    f.seek(0)
+    assert_frame_equal(pl.read_csv(f, encoding=encoding), df)
+    f.seek(0)
+    assert_frame_equal(pl.scan_csv(f, encoding=encoding).collect(), df)
+
+
+def test_csv_utf16_bom_detection() -> None:
+    df = pl.DataFrame({"a": ["ü", "€"], "b": [1, 2]})
+
+    f = io.BytesIO()
+    df.write_csv(f, encoding="utf-16-be", include_bom=True)
+    assert f.getvalue().startswith(b"\xfe\xff")
+
+    # The byte order mark takes precedence over the given encoding.
+    f.seek(0)
+    assert_frame_equal(pl.read_csv(f), df)
+
+
+def test_write_csv_unmappable_character() -> None:
+    df = pl.DataFrame({"a": ["€"]})
+
+    with pytest.raises(ComputeError, match="cannot be encoded as latin-1"):
+        df.write_csv(io.BytesIO(), encoding="latin1")
+
+    assert df.write_csv(encoding="windows-1252") == "a\n€\n"
+
+
 @pytest.mark.may_fail_auto_streaming  # read->scan_csv dispatch
 def test_column_rename_and_schema_overrides() -> None:
     csv = textwrap.dedent(
@@ -3079,3 +3124,84 @@ def test_provided_schema_mismatch_truncate(read_fn: str) -> None:
     )
     expected = [pl.Series("A", [1])]
     assert_frame_equal(df, pl.DataFrame(expected))
+
+
+def test_csv_sniff_dialect() -> None:
+    data = (
+        b"report generated 2024-06-01\n"
+        b"source: sales\n"
+        b"id;name;amount\n"
+        b'1;"a;b";1.5\n'
+        b"2;c;2.0\n"
+    )
+    expected = pl.DataFrame(
+        {"id": [1, 2], "name": ["a;b", "c"], "amount": [1.5, 2.0]}
+    )
+
+    dialect = pl.sniff_csv_dialect(data)
+    assert dialect == {
+        "separator": ";",
+        "quote_char": '"',
+        "has_header": True,
+        "skip_lines": 2,
+    }
+    assert_frame_equal(pl.read_csv(data, **dialect), expected)
+    assert_frame_equal(pl.read_csv(data, sniff_dialect=True), expected)
+    assert_frame_equal(
+        pl.scan_csv(io.BytesIO(data), sniff_dialect=True).collect(), expected
+    )
+
+
+def test_csv_sniff_dialect_multiple_files(tmp_path: Path) -> None:
+    # The dialect is sniffed from the first file and applied to all of them.
+    (tmp_path / "a.csv").write_bytes(b"exported\nid;name\n1;a\n2;b\n")
+    (tmp_path / "b.csv").write_bytes(b"exported\nid;name\n3;c\n")
+
+    lf = pl.scan_csv(tmp_path / "*.csv", sniff_dialect=True)
+    expected = pl.DataFrame({"id": [1, 2, 3], "name": ["a", "b", "c"]})
+    assert_frame_equal(lf.collect(), expected)
+
+
+def test_csv_sniff_dialect_multi_byte_separator() -> None:
+    data = b"a||b\n1||x|y\n3||4\n"
+    assert pl.sniff_csv_dialect(data)["separator"] == "||"
+    # Empty fields are not mistaken for a doubled separator.
+    assert pl.sniff_csv_dialect(b"1,,3\n4,,6\n")["separator"] == ","
+
+    with pytest.raises(ComputeError, match="multi-byte CSV separator"):
+        pl.read_csv(data, sniff_dialect=True)
+    with pytest.raises(ComputeError, match="multi-byte CSV separator"):
+        pl.scan_csv(io.BytesIO(data), sniff_dialect=True).collect()
+
+
+def test_csv_reject_rows(tmp_path: Path) -> None:
+    data = b"a,b\n1,x\ny,2\n3,z\n4,5,6\n"
+    path = tmp_path / "rejects.csv"
+    path.write_bytes(data)
+
+    expected = pl.DataFrame({"a": [1, 3], "b": ["x", "z"]})
+    schema_overrides = {"a": pl.Int64}
+
+    rejected = tmp_path / "rejected.csv"
+    df = pl.read_csv(data, schema_overrides=schema_overrides, reject_rows=rejected)
+    assert_frame_equal(df, expected)
+
+    out = pl.read_csv(rejected).sort("line_number")
+    assert out.columns == ["file_path", "line_number", "raw_line", "error"]
+    assert out["line_number"].to_list() == [3, 5]
+    assert out["raw_line"].to_list() == ["y,2", "4,5,6"]
+    assert out["file_path"].null_count() == 2
+
+    # The rejected rows are overwritten on every execution.
+    lf = pl.scan_csv(path, schema_overrides=schema_overrides, reject_rows=rejected)
+    for _ in range(2):
+        assert_frame_equal(lf.collect(), expected)
+        out = pl.read_csv(rejected).sort("line_number")
+        assert out["line_number"].to_list() == [3, 5]
+        assert out["file_path"].to_list() == [str(path)] * 2
+
+    with pytest.raises(
+        InvalidOperationError,
+        match="'ignore_errors' cannot be combined with 'reject_rows'",
+    ):
+        pl.read_csv(data, ignore_errors=True, reject_rows=rejected)
@@ -331,3 +331,34 @@ def test_scan_ndjson_schema_overwrite_22514() -> None:
     q = pl.scan_ndjson(buf, schema_overrides={"a": pl.String})
     assert q.collect_schema() == {"a": pl.String}
     assert_frame_equal(q.collect(), pl.DataFrame({"a": "1"}))
+
+
+def test_scan_ndjson_reject_rows(tmp_path: Path) -> None:
+    buf = b"""\
+{"a": 1}
+{"a": "AAAA"}
+{"a": 3}
+not json
+{"a": 5}
+"""
+
+    rejected = tmp_path / "rejected.csv"
+    lf = pl.scan_ndjson(buf, schema={"a": pl.Int64}, reject_rows=rejected)
+
+    # The rejected lines are overwritten on every execution.
+    for _ in range(2):
+        assert_frame_equal(lf.collect(), pl.DataFrame({"a": [1, 3, 5]}))
+
+        out = pl.read_csv(rejected).sort("line_number")
+        assert out.columns == ["file_path", "line_number", "raw_line", "error"]
+        assert out["line_number"].to_list() == [2, 4]
+        assert out["raw_line"].to_list() == ['{"a": "AAAA"}', "not json"]
+        assert out["file_path"].null_count() == 2
+
+    with pytest.raises(
+        pl.exceptions.InvalidOperationError,
+        match="'ignore_errors' cannot be combined with 'reject_rows'",
+    ):
+        pl.scan_ndjson(
+            buf, schema={"a": pl.Int64}, ignore_errors=True, reject_rows=rejected
+        ).collect()
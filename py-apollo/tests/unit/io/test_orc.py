@@ -0,0 +1,214 @@
+from __future__ import annotations
+
+import io
+from datetime import date, datetime
+from decimal import Decimal
+from typing import TYPE_CHECKING
+
+import pytest
+
+import apollo as pl
+from apollo.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+    from apollo._typing import OrcCompression
+
+
+COMPRESSIONS = ["uncompressed", "zlib", "snappy", "zstd"]
+
+
+@pytest.fixture
+def example_df() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "bool": [True, None, False],
+            "i8": pl.Series([1, -2, None], dtype=pl.Int8),
+            "i32": pl.Series([None, 2**31 - 1, -(2**31)], dtype=pl.Int32),
+            "i64": [1, 2, 3],
+            "f32": pl.Series([0.5, None, -1.5], dtype=pl.Float32),
+            "f64": [0.1, 0.2, float("nan")],
+            "str": ["a", None, "ccc"],
+            "bin": [b"x", b"", None],
+            "date": [date(1969, 12, 31), date(2024, 2, 29), None],
+            "dt": pl.Series(
+                [datetime(1960, 1, 1, 0, 0, 0, 1), None, datetime(2030, 5, 6, 7, 8, 9)],
+                dtype=pl.Datetime("ns"),
+            ),
+        }
+    )
+
+
+@pytest.mark.parametrize("compression", COMPRESSIONS)
+def test_from_to_buffer(example_df: pl.DataFrame, compression: OrcCompression) -> None:
+    buf = io.BytesIO()
+    example_df.write_orc(buf, compression=compression)
+    buf.seek(0)
+
+    assert_frame_equal(pl.read_orc(buf), example_df)
+
+
+def test_read_orc_columns_n_rows_row_index() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3], "b": [True, False, True], "c": ["a", "b", "c"]})
+
+    f = io.BytesIO()
+    df.write_orc(f, stripe_size=2)
+
+    f.seek(0)
+    assert_frame_equal(pl.read_orc(f, columns=["c", "a"]), df.select("c", "a"))
+    f.seek(0)
+    assert_frame_equal(pl.read_orc(f, columns=[1]), df.select("b"))
+    f.seek(0)
+    assert_frame_equal(
+        pl.read_orc(f, n_rows=2, row_index_name="idx", row_index_offset=10),
+        df.head(2).with_row_index("idx", offset=10),
+    )
+
+
+def test_dictionary_encoded_strings() -> None:
+    df = pl.DataFrame({"s": ["a", "b", "a", None] * 1000})
+
+    f = io.BytesIO()
+    df.write_orc(f)
+    f.seek(0)
+
+    assert_frame_equal(pl.read_orc(f), df)
+
+
+def test_write_orc_unsupported_dtype() -> None:
+    df = pl.DataFrame({"a": [[1, 2]]})
+
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        df.write_orc(io.BytesIO())
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("compression", COMPRESSIONS)
+def test_sink_scan_roundtrip(compression: OrcCompression, tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {"a": range(10_000), "b": [str(i % 7) for i in range(10_000)]},
+    )
+
+    path = tmp_path / "data.orc"
+    df.lazy().sink_orc(path, compression=compression, stripe_size=1_000)
+
+    assert_frame_equal(pl.read_orc(path), df)
+    assert_frame_equal(pl.scan_orc(path).collect(), df)
+
+
+@pytest.mark.write_disk
+def test_scan_orc_projection_slice_row_index(tmp_path: Path) -> None:
+    df = pl.DataFrame({"a": [1, 2, 3, 4, 5], "b": ["x", "y", "z", "u", "v"]})
+    path = tmp_path / "data.orc"
+    df.write_orc(path, stripe_size=2)
+
+    q = pl.scan_orc(path, row_index_name="idx").select("b", "idx")
+    assert_frame_equal(q.collect(), df.with_row_index("idx").select("b", "idx"))
+
+    assert_frame_equal(pl.scan_orc(path).slice(1, 3).collect(), df.slice(1, 3))
+    assert_frame_equal(pl.scan_orc(path).tail(2).collect(), df.tail(2))
+    assert pl.scan_orc(path).select(pl.len()).collect().item() == 5
+
+
+@pytest.mark.write_disk
+def test_scan_orc_stripe_pruning(
+    tmp_path: Path, capfd: pytest.CaptureFixture[str], monkeypatch: pytest.MonkeyPatch
+) -> None:
+    df = pl.DataFrame({"a": range(100)})
+    path = tmp_path / "data.orc"
+    df.write_orc(path, stripe_size=10)
+
+    monkeypatch.setenv("APOLLO_VERBOSE", "1")
+    capfd.readouterr()
+
+    out = pl.scan_orc(path).filter(pl.col("a").is_between(25, 34)).collect()
+    assert_frame_equal(out, df.filter(pl.col("a").is_between(25, 34)))
+
+    assert "reading 2 / 10 stripes" in capfd.readouterr().err
+
+
+@pytest.mark.write_disk
+def test_scan_orc_glob(tmp_path: Path) -> None:
+    dfs = [pl.DataFrame({"a": [i, i + 1]}) for i in range(0, 6, 2)]
+
+    for i, df in enumerate(dfs):
+        df.write_orc(tmp_path / f"{i}.orc")
+
+    assert_frame_equal(
+        pl.scan_orc(tmp_path / "*.orc").collect(),
+        pl.concat(dfs),
+    )
+
+
+def test_decimal_roundtrip() -> None:
+    df = pl.DataFrame(
+        {"d": [Decimal("1.25"), None, Decimal("-300.5")]},
+        schema={"d": pl.Decimal(10, 2)},
+    )
+
+    f = io.BytesIO()
+    df.write_orc(f)
+    f.seek(0)
+
+    assert_frame_equal(pl.read_orc(f), df)
+
+
+@pytest.mark.write_disk
+def test_read_orc_from_pyarrow_nested_not_projected(tmp_path: Path) -> None:
+    pa = pytest.importorskip("pyarrow")
+    orc = pytest.importorskip("pyarrow.orc")
+
+    table = pa.table(
+        {
+            "a": [1, 2, None],
+            "d": pa.array(
+                [Decimal("1.5"), None, Decimal("-2.25")], pa.decimal128(5, 2)
+            ),
+            "l": [[1, 2], None, []],
+        }
+    )
+    path = tmp_path / "data.orc"
+    orc.write_table(table, path)
+
+    expected = pl.DataFrame(
+        {"a": [1, 2, None], "d": [Decimal("1.5"), None, Decimal("-2.25")]},
+        schema={"a": pl.Int64, "d": pl.Decimal(5, 2)},
+    )
+    assert pl.scan_orc(path).collect_schema() == {
+        "a": pl.Int64,
+        "d": pl.Decimal(5, 2),
+        "l": pl.List(pl.Int64),
+    }
+    assert_frame_equal(pl.read_orc(path, columns=["a", "d"]), expected)
+    assert_frame_equal(pl.scan_orc(path).select("a", "d").collect(), expected)
+
+    with pytest.raises(pl.exceptions.ComputeError, match="column 'l' is not supported"):
+        pl.read_orc(path)
+
+
+@pytest.mark.write_disk
+def test_read_orc_timestamp_instant(tmp_path: Path) -> None:
+    pa = pytest.importorskip("pyarrow")
+    orc = pytest.importorskip("pyarrow.orc")
+
+    # pyarrow writes timestamps with a time zone as TIMESTAMP_INSTANT.
+    values = [datetime(2024, 3, 1, 12, 30, 15, 123456), None, datetime(1969, 7, 20)]
+    table = pa.table(
+        {
+            "local": pa.array(values, pa.timestamp("ns")),
+            "instant": pa.array(values, pa.timestamp("ns", tz="UTC")),
+        }
+    )
+    path = tmp_path / "data.orc"
+    orc.write_table(table, path)
+
+    expected = pl.DataFrame(
+        {"local": values, "instant": values},
+        schema={
+            "local": pl.Datetime("ns"),
+            "instant": pl.Datetime("ns", time_zone="UTC"),
+        },
+    )
+    assert pl.scan_orc(path).collect_schema() == expected.schema
+    assert_frame_equal(pl.read_orc(path), expected)
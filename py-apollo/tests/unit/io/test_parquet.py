@@ -3867,3 +3867,29 @@ def test_parquet_nested_dictionary_multipage(tmp_path: Path) -> None:
 
     assert dict_pages == 1, f"Expected 1 dict page, got {dict_pages}"
     assert data_pages == 2, f"Expected 2 data pages, got {data_pages}"
+
+
+def test_parquet_metadata_cache(tmp_path: Path) -> None:
+    path = tmp_path / "a.parquet"
+    df = pl.DataFrame({"a": [1, 2, 3]})
+    df.write_parquet(path)
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="disabled"):
+        pl.prewarm_parquet_metadata_cache(path)
+
+    try:
+        with pl.Config(parquet_metadata_cache_size=1024**2):
+            pl.prewarm_parquet_metadata_cache([path, str(path)])
+            assert_frame_equal(pl.scan_parquet(path).collect(), df)
+            assert_frame_equal(pl.read_parquet(path), df)
+
+            # A changed file is not served stale metadata.
+            df = pl.DataFrame({"a": [4, 5, 6, 7], "b": ["x", "y", "z", "w"]})
+            df.write_parquet(path)
+            assert_frame_equal(pl.scan_parquet(path).collect(), df)
+            assert_frame_equal(pl.scan_parquet(path).select("b").collect(), df[["b"]])
+
+            pl.invalidate_parquet_metadata_cache(path)
+            assert_frame_equal(pl.scan_parquet(path).collect(), df)
+    finally:
+        pl.invalidate_parquet_metadata_cache()
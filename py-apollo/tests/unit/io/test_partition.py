@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import io
+from datetime import datetime
 from pathlib import Path
 from typing import TYPE_CHECKING, Any, TypedDict
 
@@ -84,6 +85,22 @@ def test_partition_by_api() -> None:
         == 1024
     )
 
+    with pytest.raises(
+        ValueError, match="cannot use 'num_buckets' without specifying 'key'"
+    ):
+        pl.PartitionBy("", num_buckets=4, max_rows_per_file=1)
+
+    with pytest.raises(ValueError, match="cannot use 'include_key' with 'num_buckets'"):
+        pl.PartitionBy("", key="key", include_key=False, num_buckets=4)
+
+    with pytest.raises(ValueError, match="'num_buckets' must be greater than 0"):
+        pl.PartitionBy("", key="key", num_buckets=0)
+
+    with pytest.raises(
+        ValueError, match="cannot use 'bucket_hash' without specifying 'num_buckets'"
+    ):
+        pl.PartitionBy("", key="key", bucket_hash="hive")
+
 
 @pytest.mark.parametrize("io_type", io_types)
 @pytest.mark.parametrize("engine", engines)
@@ -530,3 +547,176 @@ def test_partition_by_diff_expr_26370(tmp_path: Path) -> None:
     q.sink_parquet(pl.PartitionBy(tmp_path, key="z"))
 
     assert_frame_equal(pl.scan_parquet(tmp_path).collect(), q.collect())
+
+
+@pytest.mark.parametrize("io_type", io_types)
+@pytest.mark.write_disk
+def test_partition_atomic(tmp_path: Path, io_type: IOType) -> None:
+    df = pl.DataFrame({"k": [1, 1, 2], "a": [1, 2, 3]})
+    root = tmp_path / "partitioned"
+
+    (io_type["sink"])(
+        df.lazy(), pl.PartitionBy(root, key="k", include_key=False), atomic=True
+    )
+
+    assert sorted(p.name for p in root.iterdir()) == ["k=1", "k=2"]
+    assert_frame_equal(
+        (io_type["scan"])(root / "**" / f"*.{io_type['ext']}")
+        .select("a")
+        .sort("a")
+        .collect(),
+        df.select("a"),
+    )
+
+    # A failing query leaves no partial partitions behind.
+    failed_root = tmp_path / "failed"
+    lf = df.lazy().with_columns(b=pl.lit("x").str.to_integer())
+    with pytest.raises(pl.exceptions.ApolloError):
+        (io_type["sink"])(lf, pl.PartitionBy(failed_root, key="k"), atomic=True)
+
+    assert not failed_root.exists() or list(failed_root.iterdir()) == []
+
+
+@pytest.mark.write_disk
+def test_partition_bucketed(tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {"a": [1, 2, 3, 4, 5, 6], "b": ["x", "y", "z", "x", "y", "z"]},
+        schema={"a": pl.Int32, "b": pl.String},
+    )
+
+    df.lazy().sink_parquet(pl.PartitionBy(tmp_path, key="a", num_buckets=4))
+
+    # Buckets match Spark, e.g. `SELECT pmod(hash(CAST(1 AS INT)), 4)` is 3.
+    assert {
+        p.name: pl.read_parquet(p).get_column("a").sort().to_list()
+        for p in tmp_path.iterdir()
+    } == {
+        "00000000_00001.parquet": [6],
+        "00000000_00002.parquet": [2, 4, 5],
+        "00000000_00003.parquet": [1, 3],
+    }
+
+    # Multiple keys, the files contain the key columns.
+    root = tmp_path / "multiple_keys"
+    df.lazy().sink_parquet(pl.PartitionBy(root, key=["b", "a"], num_buckets=2))
+
+    files = sorted(root.iterdir())
+    assert 1 <= len(files) <= 2
+    assert all(p.name.endswith(("_00000.parquet", "_00001.parquet")) for p in files)
+    assert_frame_equal(pl.scan_parquet(files).collect(), df, check_row_order=False)
+
+
+@pytest.mark.write_disk
+def test_partition_bucketed_hive(tmp_path: Path) -> None:
+    df = pl.DataFrame({"a": [1, 2, 3, 4, 5, 6, -5]}, schema={"a": pl.Int32})
+
+    df.lazy().sink_parquet(
+        pl.PartitionBy(tmp_path, key="a", num_buckets=4, bucket_hash="hive")
+    )
+
+    # Hive hashes an int as itself, the bucket is `(hash & INT_MAX) % num_buckets`.
+    assert {
+        p.name: pl.read_parquet(p).get_column("a").sort().to_list()
+        for p in tmp_path.iterdir()
+    } == {
+        "000000_0.parquet": [4],
+        "000001_0.parquet": [1, 5],
+        "000002_0.parquet": [2, 6],
+        "000003_0.parquet": [-5, 3],
+    }
+
+    with pytest.raises(InvalidOperationError, match="with the Hive hash"):
+        pl.LazyFrame({"a": [datetime(2020, 1, 1)]}).sink_parquet(
+            pl.PartitionBy(
+                tmp_path / "dt", key="a", num_buckets=4, bucket_hash="hive"
+            )
+        )
+
+
+@pytest.mark.parametrize(
+    "partition_by",
+    [
+        {"key": "k"},
+        {"key": "k", "num_buckets": 3},
+        {"max_rows_per_file": 4},
+    ],
+)
+@pytest.mark.write_disk
+def test_partition_sort_by(tmp_path: Path, partition_by: dict[str, Any]) -> None:
+    df = pl.DataFrame(
+        {
+            "k": [i % 3 for i in range(12)],
+            "v": [5, None, 3, 11, 0, 7, 2, 9, None, 1, 8, 4],
+        }
+    )
+
+    df.lazy().sink_parquet(
+        pl.PartitionBy(
+            tmp_path,
+            sort_by="v",
+            sort_descending=True,
+            sort_nulls_last=True,
+            **partition_by,
+        ),
+        maintain_order=False,
+    )
+
+    files = list(tmp_path.glob("**/*.parquet"))
+    assert len(files) >= 2
+
+    for p in files:
+        v = pl.read_parquet(p, hive_partitioning=False).get_column("v")
+        assert_series_equal(v, v.sort(descending=True, nulls_last=True))
+
+    assert_frame_equal(
+        pl.scan_parquet(files, hive_partitioning=False).select(df.columns).collect(),
+        df,
+        check_row_order=False,
+    )
+
+
+@pytest.mark.write_disk
+def test_partition_manifest(tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {
+            "k": ["a", "a", "b", "b", "b"],
+            "x": [3, None, 1, 5, 2],
+            "s": ["p", "q", "r", None, "t"],
+        }
+    )
+    manifest_path = tmp_path / "manifest.json"
+
+    df.lazy().sink_parquet(
+        pl.PartitionBy(
+            tmp_path / "out",
+            key="k",
+            include_key=False,
+            max_rows_per_file=2,
+            manifest_path=manifest_path,
+        )
+    )
+
+    manifest = pl.read_json(manifest_path).unnest("partition_keys")
+
+    assert manifest.columns == ["path", "k", "num_rows", "num_bytes", "statistics"]
+    assert manifest.height == 3
+
+    for row in manifest.iter_rows(named=True):
+        path = Path(row["path"])
+        assert path.stat().st_size == row["num_bytes"]
+
+        file_df = pl.read_parquet(path)
+        assert file_df.height == row["num_rows"]
+        assert path.parent.name == f"k={row['k']}"
+
+        stats = row["statistics"]
+        assert stats.keys() == {"x", "s"}
+        for name in ["x", "s"]:
+            assert stats[name]["min"] == file_df.get_column(name).min()
+            assert stats[name]["max"] == file_df.get_column(name).max()
+            assert stats[name]["null_count"] == file_df.get_column(name).null_count()
+
+    assert manifest.group_by("k").agg(pl.col("num_rows").sum()).sort("k").rows() == [
+        ("a", 2),
+        ("b", 3),
+    ]
@@ -37,6 +37,32 @@ def test_mkdir(tmp_path: Path, scan: Any, sink: Any, engine: EngineType) -> None
     assert_frame_equal(scan(f).collect(), df)
 
 
+
+@pytest.mark.parametrize(("scan", "sink"), SINKS)
+@pytest.mark.write_disk
+def test_sink_atomic(tmp_path: Path, scan: Any, sink: Any) -> None:
+    df = pl.DataFrame({"a": ["1", "2", "3"]})
+    f = tmp_path / "file"
+
+    sink(df.lazy(), f, atomic=True)
+    assert_frame_equal(scan(f).collect(), df)
+    assert os.listdir(tmp_path) == ["file"]
+
+    # A failing query neither leaves a partial file behind nor replaces the
+    # existing file.
+    lf = pl.LazyFrame({"a": ["1", "x"]}).select(pl.col("a").str.to_integer())
+    with pytest.raises(pl.exceptions.ApolloError):
+        sink(lf, f, atomic=True)
+
+    assert_frame_equal(scan(f).collect(), df)
+    assert os.listdir(tmp_path) == ["file"]
+
+    with pytest.raises(pl.exceptions.ApolloError):
+        sink(lf, tmp_path / "file2", atomic=True)
+
+    assert os.listdir(tmp_path) == ["file"]
+
+
 def test_write_mkdir(tmp_path: Path) -> None:
     df = pl.DataFrame(
         {
@@ -1254,3 +1254,59 @@ def test_csee_streaming() -> None:
         b=expr * 100,
     )
     assert "__APOLLO_CSER" not in q.explain(engine="streaming")
+
+
+@pytest.mark.write_disk
+def test_result_cache_across_queries(
+    tmp_path: Path, capfd: Any, monkeypatch: Any
+) -> None:
+    monkeypatch.setenv("APOLLO_VERBOSE", "1")
+    path = tmp_path / "data.parquet"
+    pl.DataFrame({"a": [1, 1, 2], "b": [1, 2, 3]}).write_parquet(path)
+
+    q = (
+        pl.scan_parquet(path)
+        .filter(pl.col("b") > 1)
+        .group_by("a")
+        .agg(pl.col("b").sum())
+        .sort("a")
+    )
+
+    with pl.Config(result_cache_size=1024**2):
+        # Explaining a query doesn't look at the cache.
+        assert "RESULT CACHE" not in q.explain()
+        expected = pl.DataFrame({"a": [1, 2], "b": [2, 3]})
+        assert_frame_equal(q.collect(), expected)
+        capfd.readouterr()
+
+        assert_frame_equal(q.collect(), expected)
+        assert "result cache hit" in capfd.readouterr().err
+
+        # The cache can be bypassed per query.
+        optimizations = pl.QueryOptFlags(result_cache=False)
+        assert_frame_equal(q.collect(optimizations=optimizations), expected)
+        assert "result cache hit" not in capfd.readouterr().err
+
+        # Modifying the file invalidates the cached result.
+        pl.DataFrame({"a": [1, 1, 2, 2], "b": [1, 2, 3, 4]}).write_parquet(path)
+        assert_frame_equal(q.collect(), pl.DataFrame({"a": [1, 2], "b": [2, 7]}))
+        assert "result cache hit" not in capfd.readouterr().err
+
+
+@pytest.mark.write_disk
+def test_result_cache_streaming(tmp_path: Path, capfd: Any, monkeypatch: Any) -> None:
+    monkeypatch.setenv("APOLLO_VERBOSE", "1")
+    path = tmp_path / "data.parquet"
+    pl.DataFrame({"a": [1, 1, 2], "b": [1, 2, 3]}).write_parquet(path)
+
+    q = pl.scan_parquet(path).group_by("a").agg(pl.col("b").sum()).sort("a")
+    expected = pl.DataFrame({"a": [1, 2], "b": [3, 3]})
+
+    with pl.Config(result_cache_size=1024**2):
+        out = tmp_path / "out.parquet"
+        q.sink_parquet(out)
+        assert_frame_equal(pl.read_parquet(out), expected)
+        capfd.readouterr()
+
+        assert_frame_equal(q.collect(engine="streaming"), expected)
+        assert "result cache hit" in capfd.readouterr().err
@@ -991,6 +991,13 @@ def test_warn_unstable(recwarn: pytest.WarningsRecorder) -> None:
             True,
             "1",
         ),
+        (
+            "APOLLO_PARQUET_METADATA_CACHE_SIZE",
+            "set_parquet_metadata_cache_size",
+            1024,
+            "1024",
+        ),
+        ("APOLLO_RESULT_CACHE_SIZE", "set_result_cache_size", 1024, "1024"),
         ("APOLLO_STREAMING_CHUNK_SIZE", "set_streaming_chunk_size", 100, "100"),
         ("APOLLO_TABLE_WIDTH", "set_tbl_width_chars", 80, "80"),
         ("APOLLO_VERBOSE", "set_verbose", True, "1"),
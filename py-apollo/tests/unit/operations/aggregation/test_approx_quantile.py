@@ -0,0 +1,89 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pytest
+
+import apollo as pl
+from apollo.exceptions import ComputeError, InvalidOperationError
+from apollo.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from apollo._typing import EngineType
+
+
+def test_approx_quantile_exact_for_small_input() -> None:
+    s = pl.Series("a", [3, None, 0, 5, 1, 2, 4])
+    assert s.approx_quantile(0.0) == 0.0
+    assert s.approx_quantile(0.3) == 1.5
+    assert s.approx_quantile(0.5) == 2.5
+    assert s.approx_quantile(1.0) == 5.0
+    assert s.approx_quantile(0.3) == s.quantile(0.3, interpolation="linear")
+
+
+def test_approx_quantile_dtypes() -> None:
+    df = pl.DataFrame(
+        {"i": [1, 2], "f32": [1.0, 2.0], "null": [None, None]},
+        schema={"i": pl.Int32, "f32": pl.Float32, "null": pl.Null},
+    )
+    out = df.lazy().select(pl.all().approx_quantile(0.5))
+    expected = pl.DataFrame(
+        {"i": [1.5], "f32": [1.5], "null": [None]},
+        schema={"i": pl.Float64, "f32": pl.Float32, "null": pl.Float64},
+    )
+    assert out.collect_schema() == expected.schema
+    assert_frame_equal(out.collect(), expected)
+
+
+def test_approx_quantile_ignores_nan() -> None:
+    s = pl.Series([float("nan"), 1.0, 2.0, float("nan")])
+    assert s.approx_quantile(1.0) == 2.0
+    assert pl.Series([float("nan")]).approx_quantile(0.5) is None
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_approx_quantile_accuracy(engine: EngineType) -> None:
+    n = 100_000
+    lf = pl.LazyFrame({"a": pl.int_range(n, eager=True).shuffle(seed=0)})
+    quantiles = [0.01, 0.1, 0.5, 0.9, 0.99]
+    exprs = [pl.col("a").approx_quantile(q).alias(str(q)) for q in quantiles]
+    out = lf.select(exprs).collect(engine=engine)
+    for q in quantiles:
+        assert abs(out[str(q)].item() - q * (n - 1)) <= 0.01 * n
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_approx_quantile_group_by(engine: EngineType) -> None:
+    lf = pl.LazyFrame(
+        {
+            "g": ["a", "b", "a", "b", "b", "c"],
+            "v": [1.0, 3.0, 2.0, 10.0, 4.0, None],
+        }
+    )
+    out = (
+        lf.group_by("g")
+        .agg(
+            median=pl.col("v").approx_quantile(0.5),
+            p90=pl.col("v").approx_quantile(0.9, accuracy=10),
+        )
+        .sort("g")
+        .collect(engine=engine)
+    )
+    expected = pl.DataFrame(
+        {
+            "g": ["a", "b", "c"],
+            "median": [1.5, 4.0, None],
+            "p90": [1.9, 8.8, None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_approx_quantile_invalid() -> None:
+    s = pl.Series([1.0, 2.0])
+    with pytest.raises(ComputeError, match="`quantile` should be between"):
+        s.approx_quantile(1.5)
+    with pytest.raises(ComputeError, match="`accuracy` should be positive"):
+        s.approx_quantile(0.5, accuracy=0)
+    with pytest.raises(InvalidOperationError, match="not supported for dtype"):
+        pl.Series(["a", "b"]).approx_quantile(0.5)
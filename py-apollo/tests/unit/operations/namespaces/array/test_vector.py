@@ -0,0 +1,150 @@
+from __future__ import annotations
+
+import math
+
+import pytest
+
+import apollo as pl
+from apollo.exceptions import InvalidOperationError, ShapeError
+from apollo.testing import assert_frame_equal, assert_series_equal
+
+
+@pytest.fixture
+def df() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "a": [[3.0, 4.0], [0.0, 1.0], None, [1.0, None]],
+            "b": [[1.0, 0.0], [0.0, 2.0], [1.0, 1.0], [1.0, 1.0]],
+        },
+        schema={"a": pl.Array(pl.Float64, 2), "b": pl.Array(pl.Float64, 2)},
+    )
+
+
+def test_array_vector_metrics(df: pl.DataFrame) -> None:
+    out = df.select(
+        dot=pl.col("a").arr.dot("b"),
+        cosine=pl.col("a").arr.cosine_similarity("b"),
+        euclidean=pl.col("a").arr.euclidean_distance("b"),
+        manhattan=pl.col("a").arr.manhattan_distance("b"),
+    )
+    expected = pl.DataFrame(
+        {
+            "dot": [3.0, 2.0, None, None],
+            "cosine": [0.6, 1.0, None, None],
+            "euclidean": [math.sqrt(20.0), 1.0, None, None],
+            "manhattan": [6.0, 1.0, None, None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_array_vector_metric_literal_vector(df: pl.DataFrame) -> None:
+    out = df.select(pl.col("a").arr.euclidean_distance([0.0, 0.0]))
+    expected = pl.DataFrame({"a": [5.0, 1.0, None, None]})
+    assert_frame_equal(out, expected)
+
+    s = pl.Series("s", [[0.0, 0.0]], dtype=pl.Array(pl.Float64, 2))
+    out = df.select(pl.lit(s).arr.manhattan_distance(pl.col("a")))
+    expected = pl.DataFrame({"s": [7.0, 1.0, None, None]})
+    assert_frame_equal(out, expected)
+
+
+def test_array_vector_metric_dtypes() -> None:
+    s = pl.Series([[1, 2], [3, 4]], dtype=pl.Array(pl.Int32, 2))
+    assert s.arr.dot([1, 1]).dtype == pl.Float64
+    assert s.arr.normalize().dtype == pl.Array(pl.Float64, 2)
+
+    s = s.cast(pl.Array(pl.Float32, 2))
+    assert s.arr.dot([1, 1]).dtype == pl.Float32
+    assert s.arr.normalize().dtype == pl.Array(pl.Float32, 2)
+
+    assert_series_equal(
+        s.arr.dot(pl.Series([[1, 1], [1, 1]], dtype=pl.Array(pl.Int64, 2))),
+        pl.Series([3.0, 7.0], dtype=pl.Float32),
+    )
+
+
+def test_array_vector_metric_errors() -> None:
+    s = pl.Series([[1.0, 2.0]], dtype=pl.Array(pl.Float64, 2))
+    with pytest.raises(ShapeError):
+        s.arr.dot([1.0, 2.0, 3.0])
+
+    other = pl.Series([[1.0, 2.0]] * 3, dtype=pl.Array(pl.Float64, 2))
+    with pytest.raises(ShapeError):
+        pl.select(pl.lit(other.head(2)).arr.dot(pl.lit(other)))
+
+    s = pl.Series([["a", "b"]], dtype=pl.Array(pl.String, 2))
+    with pytest.raises(InvalidOperationError):
+        s.arr.dot(["a", "b"])
+
+
+def test_array_normalize() -> None:
+    s = pl.Series("a", [[3.0, 4.0], [0.0, -2.0], None], dtype=pl.Array(pl.Float64, 2))
+    expected = pl.Series(
+        "a", [[0.6, 0.8], [0.0, -1.0], None], dtype=pl.Array(pl.Float64, 2)
+    )
+    assert_series_equal(s.arr.normalize(), expected)
+
+    out = pl.Series([[0.0, 0.0]], dtype=pl.Array(pl.Float64, 2)).arr.normalize()
+    assert all(math.isnan(x) for x in out[0])
+
+
+def test_array_nearest() -> None:
+    s = pl.Series(
+        "a",
+        [[0.0, 1.0], None, [1.0, 0.0], [2.0, 2.0], [-1.0, 0.0]],
+        dtype=pl.Array(pl.Float64, 2),
+    )
+    assert s.arr.nearest([1.0, 0.1], k=2).to_list() == [2, 3]
+    assert s.arr.nearest([1.0, 0.1], k=5).to_list() == [2, 3, 0, 4]
+    assert s.arr.nearest([1.0, 0.0], k=2, metric="euclidean").to_list() == [2, 0]
+    assert s.arr.nearest([1.0, 1.0], k=1, metric="dot").to_list() == [3]
+    assert s.arr.nearest([-1.0, 0.0], k=1, metric="manhattan").to_list() == [4]
+    assert s.arr.nearest([1.0, 0.0]).name == "a"
+
+    with pytest.raises(ValueError, match="`metric` must be one of"):
+        s.arr.nearest([1.0, 0.0], metric="hamming")  # type: ignore[arg-type]
+
+
+def test_array_nearest_skips_invalid_scores() -> None:
+    # The zero vector has a NaN cosine similarity, the null subarray a null one.
+    s = pl.Series(
+        "a",
+        [[0.0, 0.0], [1.0, 0.0], None, [0.0, 1.0], [1.0, float("nan")]],
+        dtype=pl.Array(pl.Float64, 2),
+    )
+    assert s.arr.nearest([1.0, 0.0], k=5).to_list() == [1, 3]
+    assert s.arr.nearest([1.0, 0.0], k=5, metric="euclidean").to_list() == [1, 0, 3]
+
+
+def test_array_nearest_filtered() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 2, 2],
+            "v": [[1.0, 0.0], [0.0, 1.0], [0.0, 1.0], [1.0, 0.1]],
+        },
+        schema={"g": pl.Int64, "v": pl.Array(pl.Float64, 2)},
+    )
+    out = df.select(
+        pl.col("v").filter(pl.col("g") == 2).arr.nearest([1.0, 0.0], k=2)
+    )
+    assert out.to_series().to_list() == [1, 0]
+
+
+def test_array_nearest_group_by() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 2, 2],
+            "v": [[1.0, 0.0], [0.0, 1.0], [0.0, 1.0], [1.0, 0.1]],
+        },
+        schema={"g": pl.Int64, "v": pl.Array(pl.Float64, 2)},
+    )
+    out = (
+        df.group_by("g", maintain_order=True)
+        .agg(pl.col("v").arr.nearest([1.0, 0.0], k=1).alias("idx"))
+        .explode("idx")
+    )
+    expected = pl.DataFrame(
+        {"g": [1, 2], "idx": [0, 1]}, schema={"g": pl.Int64, "idx": pl.UInt32}
+    )
+    assert_frame_equal(out, expected)
@@ -0,0 +1,95 @@
+from __future__ import annotations
+
+import ipaddress
+
+import pytest
+
+import apollo as pl
+from apollo.exceptions import InvalidOperationError
+from apollo.testing import assert_series_equal
+
+ADDRESSES = ["0.0.0.0", "192.168.0.1", "255.255.255.255", "::", "::1", "2001:db8::ff"]
+
+
+def test_ipv4_to_int() -> None:
+    s = pl.Series("ip", ["192.168.0.1", "0.0.0.0", "::1", "1.2.3", "1.2.3.256", None])
+    expected = pl.Series("ip", [3232235521, 0, None, None, None, None], pl.UInt32)
+    assert_series_equal(s.str.ipv4_to_int(), expected)
+
+
+def test_ipv6_to_int() -> None:
+    s = pl.Series([*ADDRESSES, "nope", None])
+    out = s.str.ipv6_to_int()
+    assert out.dtype == pl.UInt128
+
+    def mapped(ip: str) -> int:
+        addr = ipaddress.ip_address(ip)
+        if isinstance(addr, ipaddress.IPv4Address):
+            return int(ipaddress.IPv6Address(f"::ffff:{addr}"))
+        return int(addr)
+
+    assert out.to_list() == [*(mapped(ip) for ip in ADDRESSES), None, None]
+
+
+def test_ip_to_binary() -> None:
+    s = pl.Series([*ADDRESSES, "nope", None])
+    out = s.str.ip_to_binary()
+    assert out.dtype == pl.Binary
+    expected = [ipaddress.ip_address(ip).packed for ip in ADDRESSES]
+    assert out.to_list() == [*expected, None, None]
+
+
+def test_ip_in_cidr() -> None:
+    df = pl.DataFrame(
+        {
+            "ip": ["10.1.2.3", "10.2.0.1", "2001:db8::1", "10.1.0.1", "nope", None],
+            "cidr": [
+                "10.1.0.0/16",
+                "10.1.0.0/16",
+                "2001:db8::/32",
+                "::/0",
+                "::/0",
+                "::/0",
+            ],
+        }
+    )
+    out = df.select(pl.col("ip").str.ip_in_cidr(pl.col("cidr")))
+    assert out.to_series().to_list() == [True, False, True, False, None, None]
+
+    out = df.select(pl.col("ip").str.ip_in_cidr("10.0.0.0/8"))
+    assert out.to_series().to_list() == [True, True, False, True, None, None]
+
+    s = pl.Series(["1.2.3.4", "1.2.3.5"])
+    assert s.str.ip_in_cidr("1.2.3.4").to_list() == [True, False]
+    assert s.str.ip_in_cidr("0.0.0.0/0").to_list() == [True, True]
+    assert s.str.ip_in_cidr(pl.lit(None, pl.String)).to_list() == [None, None]
+
+
+@pytest.mark.parametrize("cidr", ["10.0.0.0/33", "10.0.0/8", "::/129", "10.0.0.0/"])
+def test_ip_in_cidr_invalid(cidr: str) -> None:
+    with pytest.raises(InvalidOperationError, match="invalid CIDR network"):
+        pl.Series(["10.0.0.1"]).str.ip_in_cidr(cidr)
+
+
+def test_ip_mask() -> None:
+    s = pl.Series(["192.168.123.45", "2001:db8:abcd:12::1", "nope", None])
+    assert s.str.ip_mask().to_list() == [
+        "192.168.123.0",
+        "2001:db8:abcd::",
+        None,
+        None,
+    ]
+    assert s.str.ip_mask(16, 128).to_list() == [
+        "192.168.0.0",
+        "2001:db8:abcd:12::1",
+        None,
+        None,
+    ]
+    assert s.str.ip_mask(0, 0).to_list() == ["0.0.0.0", "::", None, None]
+
+
+def test_ip_mask_invalid_prefix() -> None:
+    with pytest.raises(InvalidOperationError, match="IPv4 prefix length"):
+        pl.Series(["1.2.3.4"]).str.ip_mask(ipv4_prefix=33)
+    with pytest.raises(InvalidOperationError, match="IPv6 prefix length"):
+        pl.Series(["::1"]).str.ip_mask(ipv6_prefix=129)
@@ -0,0 +1,115 @@
+from __future__ import annotations
+
+import pytest
+
+import apollo as pl
+from apollo.exceptions import InvalidOperationError, ShapeError
+from apollo.testing import assert_series_equal
+
+
+@pytest.fixture
+def df() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "a": ["kitten", "flaw", "", None, "abc"],
+            "b": ["sitting", "lawn", "abc", "x", None],
+        }
+    )
+
+
+def test_levenshtein_distance(df: pl.DataFrame) -> None:
+    out = df.select(pl.col("a").str.levenshtein_distance(pl.col("b")))
+    expected = pl.Series("a", [3, 2, 3, None, None], dtype=pl.UInt32)
+    assert_series_equal(out.to_series(), expected)
+
+    out = df.select(pl.col("a").str.levenshtein_distance("kitten"))
+    expected = pl.Series("a", [0, 6, 6, None, 6], dtype=pl.UInt32)
+    assert_series_equal(out.to_series(), expected)
+
+
+def test_levenshtein_distance_chars() -> None:
+    s = pl.Series(["mañana", "mañana"])
+    assert s.str.levenshtein_distance("manana").to_list() == [1, 1]
+    assert s.str.levenshtein_distance(pl.Series(["mañana", "ñ"])).to_list() == [0, 5]
+
+
+@pytest.mark.parametrize(
+    ("max_distance", "expected"),
+    [
+        (0, [1, 1, 1, None, None]),
+        (1, [2, 2, 2, None, None]),
+        (2, [3, 2, 3, None, None]),
+        (10, [3, 2, 3, None, None]),
+    ],
+)
+def test_levenshtein_distance_bounded(
+    df: pl.DataFrame, max_distance: int, expected: list[int | None]
+) -> None:
+    out = df.select(
+        pl.col("a").str.levenshtein_distance(pl.col("b"), max_distance=max_distance),
+        pl.col("a")
+        .str.damerau_levenshtein_distance(pl.col("b"), max_distance=max_distance)
+        .alias("d"),
+    )
+    assert out["a"].to_list() == expected
+    assert out["d"].to_list() == expected
+
+
+def test_damerau_levenshtein_distance() -> None:
+    df = pl.DataFrame({"a": ["ca", "abcd", "abc", None], "b": ["abc", "acbd", "", "x"]})
+    out = df.select(
+        lev=pl.col("a").str.levenshtein_distance(pl.col("b")),
+        dam=pl.col("a").str.damerau_levenshtein_distance(pl.col("b")),
+    )
+    assert out.schema == {"lev": pl.UInt32, "dam": pl.UInt32}
+    assert out["lev"].to_list() == [3, 2, 3, None]
+    assert out["dam"].to_list() == [2, 1, 3, None]
+
+
+def test_hamming_distance() -> None:
+    s = pl.Series("a", ["karolin", "1011101", "abc", None])
+    other = pl.Series(["kathrin", "1001001", "ab", "x"])
+    expected = pl.Series("a", [3, 2, None, None], dtype=pl.UInt32)
+    assert_series_equal(s.str.hamming_distance(other), expected)
+
+    out = s.str.hamming_distance("karolin")
+    assert out.to_list() == [0, 7, None, None]
+
+
+def test_jaro_winkler_similarity() -> None:
+    df = pl.DataFrame(
+        {
+            "a": ["martha", "dixon", "abc", "abc", None],
+            "b": ["marhta", "dicksonx", "abc", "xyz", "abc"],
+        }
+    )
+    out = df.select(pl.col("a").str.jaro_winkler_similarity(pl.col("b")))
+    assert out.schema == {"a": pl.Float64}
+    result = out.to_series().to_list()
+    assert result[:4] == pytest.approx([0.961111, 0.813333, 1.0, 0.0], abs=1e-6)
+    assert result[4] is None
+
+
+def test_jaccard_similarity() -> None:
+    s = pl.Series(["night", "ab", "ab", None])
+    out = s.str.jaccard_similarity(pl.Series(["nacht", "ba", "ab", "ab"]))
+    assert out.dtype == pl.Float64
+    assert out.to_list()[:3] == pytest.approx([1 / 7, 0.0, 1.0])
+    assert out[3] is None
+
+    assert s.str.jaccard_similarity("ba", q=1).to_list()[:3] == [0.0, 1.0, 1.0]
+
+    # strings shorter than `q` form a single gram
+    out = pl.Series(["ab", "ab"]).str.jaccard_similarity(pl.Series(["ab", "abc"]), q=3)
+    assert out.to_list() == [1.0, 0.0]
+
+
+def test_jaccard_similarity_invalid_q() -> None:
+    with pytest.raises(InvalidOperationError, match="q-gram size"):
+        pl.Series(["a"]).str.jaccard_similarity("a", q=0)
+
+
+def test_similarity_length_mismatch() -> None:
+    df = pl.DataFrame({"a": ["a", "b", "c"]})
+    with pytest.raises(ShapeError):
+        df.select(pl.col("a").str.levenshtein_distance(pl.Series(["a", "b"])))
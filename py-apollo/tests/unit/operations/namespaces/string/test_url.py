@@ -0,0 +1,72 @@
+from __future__ import annotations
+
+import apollo as pl
+from apollo.testing import assert_frame_equal
+
+
+def test_parse_url() -> None:
+    s = pl.Series(
+        "url",
+        [
+            "https://user:pw@example.com:8080/a/b?x=1&y=2#top",
+            "http://[::1]/",
+            "mailto:someone@example.com",
+            "/relative/path?q",
+            "http://host:http/",
+            "",
+            None,
+        ],
+    )
+    out = s.str.parse_url()
+    assert out.dtype == pl.Struct(
+        {
+            "scheme": pl.String,
+            "host": pl.String,
+            "port": pl.UInt16,
+            "path": pl.String,
+            "query": pl.String,
+            "fragment": pl.String,
+        }
+    )
+    expected = pl.DataFrame(
+        {
+            "scheme": ["https", "http", "mailto", None, "http", None, None],
+            "host": ["example.com", "::1", None, None, "host", None, None],
+            "port": [8080, None, None, None, None, None, None],
+            "path": [
+                "/a/b",
+                "/",
+                "someone@example.com",
+                "/relative/path",
+                "/",
+                "",
+                None,
+            ],
+            "query": ["x=1&y=2", None, None, "q", None, None, None],
+            "fragment": ["top", None, None, None, None, None, None],
+        },
+        schema_overrides={"port": pl.UInt16},
+    )
+    assert_frame_equal(out.struct.unnest(), expected)
+    assert out.is_null().to_list() == [False] * 6 + [True]
+
+
+def test_parse_url_expr() -> None:
+    df = pl.DataFrame({"url": ["https://apollo.rs/docs", None]})
+    out = df.select(host=pl.col("url").str.parse_url().struct.field("host"))
+    assert out["host"].to_list() == ["apollo.rs", None]
+
+
+def test_url_query_param() -> None:
+    s = pl.Series(
+        [
+            "https://example.com/?a=1&b=hello+world&c&a=2",
+            "https://example.com/?d%20e=%C3%B1#a=3",
+            "https://example.com/#?a=1",
+            None,
+        ]
+    )
+    assert s.str.url_query_param("a").to_list() == ["1", None, None, None]
+    assert s.str.url_query_param("b").to_list() == ["hello world", None, None, None]
+    assert s.str.url_query_param("c").to_list() == ["", None, None, None]
+    assert s.str.url_query_param("d e").to_list() == [None, "ñ", None, None]
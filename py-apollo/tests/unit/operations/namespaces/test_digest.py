@@ -0,0 +1,135 @@
+from __future__ import annotations
+
+import hashlib
+from datetime import date
+from typing import TYPE_CHECKING
+
+import pytest
+
+import apollo as pl
+from apollo.exceptions import InvalidOperationError, SchemaError
+from apollo.testing import assert_series_equal
+
+if TYPE_CHECKING:
+    from apollo._typing import HashAlgorithm
+
+VALUES = ["", "abc", "hello", "mañana", "The quick brown fox jumps over the lazy dog"]
+
+
+@pytest.mark.parametrize("algorithm", ["md5", "sha1", "sha256"])
+def test_digest_matches_hashlib(algorithm: HashAlgorithm) -> None:
+    s = pl.Series("s", [*VALUES, None])
+    expected = [hashlib.new(algorithm, v.encode()).hexdigest() for v in VALUES]
+
+    assert s.str.digest(algorithm).to_list() == [*expected, None]
+    b = s.str.encode("hex").str.decode("hex")
+    assert b.bin.digest(algorithm).to_list() == [*expected, None]
+
+    out = s.str.digest(algorithm, hex=False)
+    assert out.dtype == pl.Binary
+    assert out.to_list() == [*(bytes.fromhex(e) for e in expected), None]
+
+
+def test_digest_non_cryptographic() -> None:
+    s = pl.Series(["", "abc", "test", "Hello, world!"])
+    assert s.str.digest("xxhash64").to_list() == [
+        "ef46db3751d8e999",
+        "44bc2cf5ad770999",
+        "4fdcca5ddb678139",
+        "f58336a78b6f9476",
+    ]
+    assert s.str.digest("murmur3_32").to_list() == [
+        "00000000",
+        "b3dd93fa",
+        "ba6bd213",
+        "c0363e43",
+    ]
+
+
+def test_digest_expr() -> None:
+    df = pl.DataFrame({"s": ["abc", None], "b": [b"abc", None]})
+    out = df.select(
+        pl.col("s").str.digest("md5"),
+        pl.col("b").bin.digest("md5"),
+        pl.col("s").str.digest("md5", hex=False).alias("raw"),
+    )
+    assert out.schema == {"s": pl.String, "b": pl.String, "raw": pl.Binary}
+    assert_series_equal(out["s"], out["b"], check_names=False)
+    assert out["s"].to_list() == ["900150983cd24fb0d6963f7d28e17f72", None]
+
+
+def test_digest_invalid_algorithm() -> None:
+    with pytest.raises(ValueError, match="`algorithm` must be one of"):
+        pl.Series(["a"]).str.digest("crc32")  # type: ignore[arg-type]
+
+
+def test_digest_wrong_dtype() -> None:
+    with pytest.raises(SchemaError):
+        pl.Series([1, 2]).to_frame().select(pl.first().bin.digest())
+
+
+def test_digest_rows() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, 2, 1, None, 1],
+            "b": ["x", None, "x", None, "y"],
+        }
+    )
+    out = df.select(pl.digest_rows("a", "b"))
+    assert out.schema == {"a": pl.String}
+    digests = out.to_series().to_list()
+    assert all(len(d) == 64 for d in digests)
+    assert digests[0] == digests[2]
+    assert len(set(digests)) == 4
+
+    # the digest only depends on the values and their data types
+    reversed_digests = df.reverse().select(pl.digest_rows(pl.all())).to_series()
+    assert reversed_digests.reverse().to_list() == digests
+    casted = df.with_columns(pl.col("a").cast(pl.Int32))
+    assert casted.select(pl.digest_rows("a", "b")).to_series()[0] != digests[0]
+
+    out = df.select(pl.digest_rows(pl.all(), algorithm="murmur3_32", hex=False))
+    assert out.to_series().dtype == pl.Binary
+    assert out.to_series().bin.size().to_list() == [4] * 5
+
+
+def test_digest_rows_golden() -> None:
+    # Guards against accidental changes of the row encoding, which change the digests.
+    df = pl.DataFrame(
+        {
+            "i": [1, None, -3],
+            "s": ["x", None, ""],
+            "b": [True, None, False],
+            "f": [2.5, None, -1.0],
+            "d": [date(2024, 1, 1), None, date(1970, 1, 1)],
+        }
+    )
+    assert df.select(pl.digest_rows(pl.all())).to_series().to_list() == [
+        "4983eff7845d18ad2772aaa9022d61fead45a58425fbfbf4f6c6471a4b2333e2",
+        "1a9d08fc2514fd703cd5bd8168a7b49b506648dc630375df5a5658feef82d0aa",
+        "cb368a373cf7baf247fbb7c224ca7312816b9f7a231b6e043607c82556748074",
+    ]
+    out = df.select(pl.digest_rows(pl.all(), algorithm="md5"))
+    assert out.to_series().to_list() == [
+        "63bac64ba989c73f4eabd5e12fd8c95d",
+        "80e6d63193e18a8a9dd2855141c82639",
+        "bea8e2366ed7fd5a827660edf2913155",
+    ]
+    assert df.head(1).select(pl.digest_rows("i")).item() == (
+        "8f7f71cb7053c6312b5ae6c6cd794b55838aec5e07a9b3ce471b4ff8ab969e7b"
+    )
+    assert df.head(1).select(pl.digest_rows("s")).item() == (
+        "23c86a8aded0ad81f8111bb07e6ec0ffb00ce5bfef001417c07243f43fd70b2f"
+    )
+
+
+def test_digest_rows_categorical() -> None:
+    df = pl.DataFrame({"c": ["a", "b"]}, schema={"c": pl.Categorical})
+    with pytest.raises(InvalidOperationError, match="cast it to String first"):
+        df.select(pl.digest_rows("c"))
+    with pytest.raises(InvalidOperationError, match="cast it to String first"):
+        df.select(pl.digest_rows(pl.struct("c")))
+
+    out = df.select(pl.digest_rows(pl.col("c").cast(pl.String)))
+    expected = pl.DataFrame({"c": ["a", "b"]}).select(pl.digest_rows("c"))
+    assert_series_equal(out.to_series(), expected.to_series())
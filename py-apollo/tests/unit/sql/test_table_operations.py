@@ -131,6 +131,18 @@ def test_explain_query(test_frame: pl.LazyFrame) -> None:
         )
 
 
+def test_explain_analyze_query(test_frame: pl.LazyFrame) -> None:
+    # 'explain analyze' runs the query and returns the metrics of each node
+    with pl.SQLContext(frame=test_frame) as ctx:
+        metrics = ctx.execute("EXPLAIN ANALYZE SELECT * FROM frame").collect()
+
+    assert metrics["node"][0] == "query"
+    assert metrics.height > 1
+    assert {"total_time", "rows_received", "rows_sent", "peak_memory"} <= set(
+        metrics.columns
+    )
+
+
 def test_show_tables(test_frame: pl.LazyFrame) -> None:
     # 'show tables' lists all tables registered with the sql context in sorted order
     with pl.SQLContext(
@@ -385,3 +385,23 @@ def test_i128_sum_reduction() -> None:
         .item()
         == 6
     )
+
+
+def test_explain_analyze() -> None:
+    lf = pl.LazyFrame({"a": range(1000), "b": range(1000)})
+    plan, metrics = (
+        lf.filter(pl.col("a") % 10 == 0).group_by("b").agg(pl.len()).explain_analyze()
+    )
+
+    assert plan.startswith("digraph apollo")
+    assert "peak memory" in plan
+    assert "rows:" in plan
+
+    assert metrics["node"][0] == "query"
+    assert metrics["id"][0] is None
+    assert metrics["id"][1:].null_count() == 0
+    assert metrics["peak_memory"][1:].null_count() == metrics.height - 1
+    assert metrics["total_time"].null_count() == 0
+
+    sink = metrics.filter(pl.col("node") == "in-memory-sink")
+    assert sink["rows_received"].item() == 100
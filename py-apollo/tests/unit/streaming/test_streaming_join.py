@@ -599,3 +599,52 @@ def test_merge_join_applicable(
     else:
         assert "merge-join" not in typing.cast("str", dot)
     assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+@pytest.mark.parametrize("how", ["inner", "left", "right"])
+@pytest.mark.parametrize("file_format", ["parquet", "ipc"])
+@pytest.mark.parametrize("nulls_equal", [False, True])
+def test_streaming_join_runtime_filter(
+    tmp_path: Path,
+    how: JoinStrategy,
+    file_format: Literal["parquet", "ipc"],
+    nulls_equal: bool,
+) -> None:
+    n = 50_000
+    probe = pl.DataFrame(
+        {
+            "key": pl.Series(np.arange(n), dtype=pl.Int64).set(
+                pl.Series(np.arange(n) % 1000 == 0), None
+            ),
+            "name": pl.Series(np.arange(n)).cast(pl.String),
+            "value": np.arange(n),
+        }
+    )
+    build = pl.DataFrame(
+        {
+            "key": [10, 20_020, None, 49_999, 60_000],
+            "name": ["10", "20020", None, "49999", "x"],
+            "other": [1, 2, 3, 4, 5],
+        }
+    )
+
+    path = tmp_path / f"probe.{file_format}"
+    if file_format == "parquet":
+        probe.write_parquet(path, row_group_size=1000)
+        scan = pl.scan_parquet
+    else:
+        probe.write_ipc(path, record_batch_size=1000)
+        scan = pl.scan_ipc
+
+    for on in ["key", ["key", "name"]]:
+        q = scan(path).join(
+            build.lazy(),
+            on=on,
+            how=how,
+            nulls_equal=nulls_equal,
+            maintain_order="left_right",
+        )
+        assert_frame_equal(
+            q.collect(engine="streaming"),
+            q.collect(engine="in-memory"),
+        )